@@ -0,0 +1,435 @@
+//! Manifest linting beyond schema validation.
+//!
+//! `check` only proves the YAML deserializes; the lints here catch manifests
+//! that parse but cannot render as intended: bindings to data sources or
+//! models that are not declared, state fields nothing writes, duplicate
+//! section ids, and sections whose grid spans collide.
+//!
+//! A manifest split with includes is linted file by file, so every finding
+//! points at the file that contains it.
+
+use presentar_yaml::Manifest;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Severity of a lint diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LintLevel {
+    /// Fails the lint run.
+    Error,
+    /// Reported; fails the run only in strict mode.
+    Warning,
+}
+
+/// A single lint finding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LintDiagnostic {
+    /// Severity
+    pub(crate) level: LintLevel,
+    /// Stable rule identifier
+    pub(crate) rule: &'static str,
+    /// Human-readable description
+    pub(crate) message: String,
+    /// File the finding is in, as shown to the user
+    pub(crate) file: String,
+    /// 1-based line in that file (0 if unknown)
+    pub(crate) line: usize,
+}
+
+impl LintDiagnostic {
+    /// Format as `file:line: level[rule]: message`.
+    #[must_use]
+    pub(crate) fn render(&self) -> String {
+        let level = match self.level {
+            LintLevel::Error => "error",
+            LintLevel::Warning => "warning",
+        };
+        format!(
+            "{}:{}: {level}[{}]: {}",
+            self.file, self.line, self.rule, self.message
+        )
+    }
+}
+
+/// One file a manifest is assembled from.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LintSource<'a> {
+    /// Path shown in diagnostics
+    pub(crate) path: &'a str,
+    /// File contents
+    pub(crate) text: &'a str,
+}
+
+/// Lint the manifest at `path` together with every file it includes.
+///
+/// Included files are named relative to the manifest the same way `path`
+/// names the manifest itself.
+pub(crate) fn lint_file(path: &Path) -> Result<Vec<LintDiagnostic>, String> {
+    let manifest = Manifest::from_file(path).map_err(|e| format!("Manifest invalid: {e}"))?;
+    let files = Manifest::source_files(path).map_err(|e| format!("Manifest invalid: {e}"))?;
+    let root = files.first().and_then(|file| file.parent());
+    let dir = path.parent().unwrap_or(Path::new(""));
+
+    let mut loaded = Vec::with_capacity(files.len());
+    for (index, file) in files.iter().enumerate() {
+        let text = fs::read_to_string(file)
+            .map_err(|e| format!("Failed to read {}: {e}", file.display()))?;
+        let shown = if index == 0 {
+            path.to_path_buf()
+        } else {
+            root.and_then(|root| file.strip_prefix(root).ok())
+                .map_or_else(|| file.clone(), |relative| dir.join(relative))
+        };
+        loaded.push((shown.display().to_string(), text));
+    }
+    let sources: Vec<LintSource<'_>> = loaded
+        .iter()
+        .map(|(path, text)| LintSource { path, text })
+        .collect();
+    Ok(lint_manifest(&manifest, &sources))
+}
+
+/// Lint a parsed manifest against the files it was read from.
+///
+/// `sources` starts with the manifest itself, followed by the files it
+/// includes. Their text is used to attach locations and to find `{{ }}`
+/// expressions in any widget property, including ones the manifest schema
+/// keeps only as untyped extras.
+#[must_use]
+pub(crate) fn lint_manifest(
+    manifest: &Manifest,
+    sources: &[LintSource<'_>],
+) -> Vec<LintDiagnostic> {
+    let mut diagnostics = Vec::new();
+    lint_expressions(manifest, sources, &mut diagnostics);
+    lint_sections(manifest, sources, &mut diagnostics);
+    diagnostics.sort_by_key(|d| {
+        let file = sources.iter().position(|source| source.path == d.file);
+        (file, d.line)
+    });
+    diagnostics
+}
+
+/// Check every `data.*`, `models.*` and `state.*` reference inside `{{ }}`.
+fn lint_expressions(
+    manifest: &Manifest,
+    sources: &[LintSource<'_>],
+    out: &mut Vec<LintDiagnostic>,
+) {
+    // A binding in any file satisfies reads in every other.
+    let bound_state: HashSet<&str> = sources
+        .iter()
+        .flat_map(|source| source.text.lines())
+        .filter_map(|line| line.trim().strip_prefix("bind:"))
+        .filter_map(|value| unquote(value).strip_prefix("state."))
+        .map(|path| path.split('.').next().unwrap_or(path))
+        .collect();
+
+    for source in sources {
+        for (index, line) in source.text.lines().enumerate() {
+            for expression in expressions(line) {
+                for reference in references(expression) {
+                    let mut parts = reference.splitn(3, '.');
+                    let (Some(root), Some(name)) = (parts.next(), parts.next()) else {
+                        continue;
+                    };
+                    let diagnostic = match root {
+                        "data" if !manifest.data.contains_key(name) => LintDiagnostic {
+                            level: LintLevel::Error,
+                            rule: "undefined-data-source",
+                            message: format!(
+                                "`{reference}` references undefined data source `{name}`"
+                            ),
+                            file: source.path.to_string(),
+                            line: index + 1,
+                        },
+                        "models" if !manifest.models.contains_key(name) => LintDiagnostic {
+                            level: LintLevel::Error,
+                            rule: "undefined-model",
+                            message: format!("`{reference}` references undefined model `{name}`"),
+                            file: source.path.to_string(),
+                            line: index + 1,
+                        },
+                        "state" if !bound_state.contains(name) => LintDiagnostic {
+                            level: LintLevel::Warning,
+                            rule: "unknown-field",
+                            message: format!(
+                                "`{reference}` reads state field `{name}` that no widget binds"
+                            ),
+                            file: source.path.to_string(),
+                            line: index + 1,
+                        },
+                        _ => continue,
+                    };
+                    out.push(diagnostic);
+                }
+            }
+        }
+    }
+}
+
+/// Check section ids and grid spans.
+fn lint_sections(manifest: &Manifest, sources: &[LintSource<'_>], out: &mut Vec<LintDiagnostic>) {
+    let columns = manifest.layout.columns;
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut previous: Option<(&str, [u32; 2])> = None;
+    let mut cursors = vec![0; sources.len()];
+
+    for section in &manifest.layout.sections {
+        // Sections appear in document order within each file, so search
+        // forward from the previous match to disambiguate repeated ids.
+        let found = sources
+            .iter()
+            .zip(&mut cursors)
+            .find_map(|(source, cursor)| {
+                let line = find_section_line(source.text, &section.id, *cursor)?;
+                *cursor = line;
+                Some((source.path, line))
+            });
+        let (file, line) = found.unwrap_or((sources.first().map_or("", |s| s.path), 0));
+        let file = file.to_string();
+
+        if !seen.insert(section.id.as_str()) {
+            out.push(LintDiagnostic {
+                level: LintLevel::Error,
+                rule: "duplicate-section-id",
+                message: format!("section id `{}` is already used", section.id),
+                file: file.clone(),
+                line,
+            });
+        }
+
+        let Some([start, end]) = section.span else {
+            previous = None;
+            continue;
+        };
+        if start == 0 || start > end || end > columns {
+            out.push(LintDiagnostic {
+                level: LintLevel::Error,
+                rule: "invalid-span",
+                message: format!(
+                    "section `{}` span [{start}, {end}] is outside the {columns}-column grid",
+                    section.id
+                ),
+                file: file.clone(),
+                line,
+            });
+            previous = None;
+            continue;
+        }
+        // A span starting inside the previous one shares its row but
+        // collides with it; starting at or before it begins a new row.
+        if let Some((prev_id, [prev_start, prev_end])) = previous {
+            if start > prev_start && start <= prev_end {
+                out.push(LintDiagnostic {
+                    level: LintLevel::Warning,
+                    rule: "overlapping-span",
+                    message: format!(
+                        "section `{}` span [{start}, {end}] overlaps `{prev_id}` [{prev_start}, {prev_end}]",
+                        section.id
+                    ),
+                    file,
+                    line,
+                });
+            }
+        }
+        previous = Some((section.id.as_str(), [start, end]));
+    }
+}
+
+/// Line (1-based) of the `id:` entry for a section, searching from `after`.
+fn find_section_line(source: &str, id: &str, after: usize) -> Option<usize> {
+    source
+        .lines()
+        .enumerate()
+        .skip(after)
+        .find(|(_, line)| {
+            let trimmed = line.trim().trim_start_matches("- ");
+            trimmed
+                .strip_prefix("id:")
+                .is_some_and(|value| unquote(value) == id)
+        })
+        .map(|(index, _)| index + 1)
+}
+
+/// Inner text of each `{{ ... }}` on a line.
+fn expressions(line: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = line;
+    while let Some(open) = rest.find("{{") {
+        let after = &rest[open + 2..];
+        let Some(close) = after.find("}}") else {
+            break;
+        };
+        found.push(&after[..close]);
+        rest = &after[close + 2..];
+    }
+    found
+}
+
+/// Dotted identifier paths rooted at `data`, `models` or `state`.
+fn references(expression: &str) -> Vec<&str> {
+    expression
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .filter(|token| {
+            ["data.", "models.", "state."]
+                .iter()
+                .any(|root| token.starts_with(root))
+        })
+        .collect()
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches(|c| c == '"' || c == '\'')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lint(yaml: &str) -> Vec<LintDiagnostic> {
+        let manifest = Manifest::from_yaml(yaml).expect("valid manifest");
+        let source = LintSource {
+            path: "app.yaml",
+            text: yaml,
+        };
+        lint_manifest(&manifest, &[source])
+    }
+
+    #[test]
+    fn test_undefined_data_source() {
+        let yaml = r#"presentar: "0.1"
+name: "app"
+version: "1.0.0"
+data:
+  sales:
+    source: "sales.ald"
+layout:
+  type: "dashboard"
+  sections:
+    - id: "main"
+      widgets:
+        - type: "chart"
+          data: "{{ data.sales }}"
+        - type: "table"
+          data: "{{ data.missing | limit(10) }}"
+"#;
+        let diagnostics = lint(yaml);
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic.rule, "undefined-data-source");
+        assert_eq!(diagnostic.level, LintLevel::Error);
+        assert_eq!(diagnostic.line, 15);
+        assert!(diagnostic.message.contains("`missing`"));
+        assert_eq!(
+            diagnostic.render(),
+            "app.yaml:15: error[undefined-data-source]: `data.missing` references undefined data source `missing`"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_section_id() {
+        let yaml = r#"presentar: "0.1"
+name: "app"
+version: "1.0.0"
+layout:
+  type: "dashboard"
+  sections:
+    - id: "main"
+    - id: "main"
+"#;
+        let diagnostics = lint(yaml);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "duplicate-section-id");
+        assert_eq!(diagnostics[0].line, 8);
+    }
+
+    #[test]
+    fn test_overlapping_and_invalid_spans() {
+        let yaml = r#"presentar: "0.1"
+name: "app"
+version: "1.0.0"
+layout:
+  type: "dashboard"
+  columns: 12
+  sections:
+    - id: "left"
+      span: [1, 8]
+    - id: "right"
+      span: [6, 12]
+    - id: "next-row"
+      span: [1, 12]
+    - id: "wide"
+      span: [1, 16]
+"#;
+        let rules: Vec<_> = lint(yaml).iter().map(|d| (d.rule, d.line)).collect();
+        assert_eq!(rules, vec![("overlapping-span", 10), ("invalid-span", 14)]);
+    }
+
+    #[test]
+    fn test_unbound_state_is_warning() {
+        let yaml = r#"presentar: "0.1"
+name: "app"
+version: "1.0.0"
+layout:
+  type: "dashboard"
+  sections:
+    - id: "main"
+      widgets:
+        - type: "text_input"
+          bind: "state.query"
+        - type: "text"
+          content: "{{ state.query }} / {{ state.page }}"
+"#;
+        let diagnostics = lint(yaml);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].level, LintLevel::Warning);
+        assert!(diagnostics[0].message.contains("`page`"));
+    }
+
+    #[test]
+    fn test_included_files_report_their_own_location() {
+        let dir = std::env::temp_dir().join("presentar-cli-lint-include");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("sections")).unwrap();
+        fs::write(
+            dir.join("app.yaml"),
+            r#"presentar: "0.1"
+name: "app"
+version: "1.0.0"
+layout:
+  type: "dashboard"
+  sections:
+    - id: "main"
+    - !include "sections/table.yaml"
+"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("sections/table.yaml"),
+            r#"id: "main"
+widgets:
+  - type: "table"
+    data: "{{ data.missing }}"
+"#,
+        )
+        .unwrap();
+
+        let diagnostics = lint_file(&dir.join("app.yaml")).unwrap();
+        let included = dir.join("sections/table.yaml").display().to_string();
+        let found: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.rule, d.file.as_str(), d.line))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("duplicate-section-id", included.as_str(), 1),
+                ("undefined-data-source", included.as_str(), 4),
+            ]
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
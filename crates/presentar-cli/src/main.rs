@@ -25,18 +25,25 @@
 )]
 
 use clap::{Parser, Subcommand};
+use presentar_terminal::tools::{BenchmarkResult, RenderMetrics};
+use presentar_terminal::Theme;
+use presentar_test::{QualityGates, ViolationSeverity};
 use std::fs;
 use std::io::Read;
 #[cfg(feature = "dev-server")]
 use std::net::TcpListener;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::str::FromStr;
 #[cfg(feature = "dev-server")]
 use std::sync::atomic::{AtomicU64, Ordering};
 use tiny_http::{Response, Server};
 #[cfg(feature = "dev-server")]
 use tungstenite::accept;
 
+mod lint;
+mod render;
+
 #[derive(Parser)]
 #[command(name = "presentar")]
 #[command(about = "WASM-first visualization framework CLI")]
@@ -61,6 +68,14 @@ enum Commands {
         /// Watch for changes and rebuild
         #[arg(short, long)]
         watch: bool,
+
+        /// Serve the index file for unmatched non-asset paths (client-side routing)
+        #[arg(long)]
+        spa: bool,
+
+        /// Index file served for `/` and SPA fallbacks
+        #[arg(long, default_value = "index.html")]
+        index: String,
     },
 
     /// Build optimized WASM bundle
@@ -72,6 +87,10 @@ enum Commands {
         /// Skip wasm-opt optimization
         #[arg(long)]
         no_optimize: bool,
+
+        /// Content-hash output filenames and write asset-manifest.json
+        #[arg(long)]
+        hash: bool,
     },
 
     /// Create new Presentar project
@@ -87,6 +106,17 @@ enum Commands {
         manifest: PathBuf,
     },
 
+    /// Lint a manifest for undefined bindings and layout mistakes
+    Lint {
+        /// Path to manifest file
+        #[arg(default_value = "app.yaml")]
+        manifest: PathBuf,
+
+        /// Strict mode - fail on any warning
+        #[arg(long)]
+        strict: bool,
+    },
+
     /// Compute quality score for a manifest
     Score {
         /// Path to manifest file
@@ -119,6 +149,39 @@ enum Commands {
         /// Strict mode - fail on any warning
         #[arg(long)]
         strict: bool,
+
+        /// Benchmark JSON (from the headless harness or Criterion) to check
+        /// measured frame time against `max_render_time_ms` from
+        /// .presentar-gates.toml (16ms by default)
+        #[arg(short, long)]
+        benchmark: Option<PathBuf>,
+    },
+
+    /// Render a manifest to a static SVG (and optionally PNG) image
+    Render {
+        /// Path to manifest file
+        #[arg(default_value = "app.yaml")]
+        manifest: PathBuf,
+
+        /// Output width in pixels
+        #[arg(long, default_value = "1200")]
+        width: u32,
+
+        /// Output height in pixels
+        #[arg(long, default_value = "630")]
+        height: u32,
+
+        /// SVG output file
+        #[arg(short, long, default_value = "app.svg")]
+        output: PathBuf,
+
+        /// Also write a PNG
+        #[arg(long)]
+        png: Option<PathBuf>,
+
+        /// Color theme preset, overriding the manifest's theme (e.g. nord, dracula)
+        #[arg(long, value_name = "NAME", value_parser = Theme::from_str)]
+        theme: Option<Theme>,
     },
 
     /// Deploy application to cloud hosting
@@ -127,15 +190,15 @@ enum Commands {
         #[arg(short, long, default_value = "dist")]
         source: PathBuf,
 
-        /// Deployment target (s3, cloudflare, vercel, netlify, local)
+        /// Deployment target (s3, cloudflare, vercel, netlify, github-pages, fastly, local)
         #[arg(short, long, default_value = "s3")]
         target: String,
 
-        /// S3 bucket name or deployment URL
+        /// S3 bucket name, deployment URL, git remote (github-pages) or KV store ID (fastly)
         #[arg(short, long)]
         bucket: Option<String>,
 
-        /// CloudFront distribution ID for cache invalidation
+        /// CloudFront distribution ID (or Fastly service ID) for cache invalidation
         #[arg(long)]
         distribution: Option<String>,
 
@@ -150,6 +213,10 @@ enum Commands {
         /// Skip bundle step (deploy existing files)
         #[arg(long)]
         skip_build: bool,
+
+        /// Content-hash bundle filenames when building (see `bundle --hash`)
+        #[arg(long)]
+        hash: bool,
     },
 }
 
@@ -157,14 +224,21 @@ fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Serve { port, dir, watch } => {
-            serve(port, dir, watch);
+        Commands::Serve {
+            port,
+            dir,
+            watch,
+            spa,
+            index,
+        } => {
+            serve(port, dir, watch, spa, &index);
         }
         Commands::Bundle {
             output,
             no_optimize,
+            hash,
         } => {
-            bundle(output, no_optimize);
+            bundle(output, no_optimize, hash);
         }
         Commands::New { name } => {
             new_project(&name);
@@ -172,6 +246,9 @@ fn main() {
         Commands::Check { manifest } => {
             check_manifest(&manifest);
         }
+        Commands::Lint { manifest, strict } => {
+            lint_command(&manifest, strict);
+        }
         Commands::Score {
             manifest,
             format,
@@ -184,8 +261,26 @@ fn main() {
             min_grade,
             min_score,
             strict,
+            benchmark,
+        } => {
+            run_gates(&manifest, &min_grade, min_score, strict, benchmark.as_ref());
+        }
+        Commands::Render {
+            manifest,
+            width,
+            height,
+            output,
+            png,
+            theme,
         } => {
-            run_gates(&manifest, &min_grade, min_score, strict);
+            render_command(
+                &manifest,
+                width,
+                height,
+                &output,
+                png.as_ref(),
+                theme.as_ref(),
+            );
         }
         Commands::Deploy {
             source,
@@ -195,6 +290,7 @@ fn main() {
             region,
             dry_run,
             skip_build,
+            hash,
         } => {
             deploy(
                 &source,
@@ -204,6 +300,7 @@ fn main() {
                 &region,
                 dry_run,
                 skip_build,
+                hash,
             );
         }
     }
@@ -237,10 +334,13 @@ const HOT_RELOAD_SCRIPT: &str = r#"
 </script>
 "#;
 
-fn serve(port: u16, dir: PathBuf, watch: bool) {
+fn serve(port: u16, dir: PathBuf, watch: bool, spa: bool, index: &str) {
     println!("Starting Presentar dev server...");
     println!("  Serving: {}", dir.display());
     println!("  URL: http://localhost:{}", port);
+    if spa {
+        println!("  SPA fallback: {}", index);
+    }
     if watch {
         println!("  Watch: enabled (rebuilds on file changes)");
         println!("  Hot reload: ws://localhost:35729");
@@ -272,17 +372,14 @@ fn serve(port: u16, dir: PathBuf, watch: bool) {
     let server = Server::http(&addr).expect("Failed to start server");
 
     for request in server.incoming_requests() {
-        let url = request.url().to_string();
-        let path = if url == "/" {
-            dir.join("index.html")
-        } else {
-            dir.join(url.trim_start_matches('/'))
-        };
+        let path = resolve_request_path(&dir, request.url(), index, spa);
 
-        let response = if path.exists() && path.is_file() {
-            let mut file = fs::File::open(&path).expect("open file");
-            let mut content = Vec::new();
-            file.read_to_end(&mut content).expect("read file");
+        let response = if let Some(path) = path.filter(|p| p.is_file()) {
+            let accept_encoding = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Accept-Encoding"))
+                .map(|h| h.value.as_str().to_string());
 
             let content_type = match path.extension().and_then(|e| e.to_str()) {
                 Some("html") => "text/html",
@@ -296,6 +393,26 @@ fn serve(port: u16, dir: PathBuf, watch: bool) {
                 _ => "application/octet-stream",
             };
 
+            // Precompressed variants are byte-for-byte final, so skip them
+            // for HTML that the hot reload script is injected into.
+            let encoding = if watch && content_type == "text/html" {
+                None
+            } else {
+                accept_encoding.as_deref().and_then(|accept| {
+                    select_encoding(
+                        accept,
+                        content_type,
+                        precompressed_path(&path, "br").exists(),
+                        precompressed_path(&path, "gzip").exists(),
+                    )
+                })
+            };
+            let body_path = encoding.map_or_else(|| path.clone(), |e| precompressed_path(&path, e));
+
+            let mut file = fs::File::open(&body_path).expect("open file");
+            let mut content = Vec::new();
+            file.read_to_end(&mut content).expect("read file");
+
             // Inject hot reload script into HTML files when watching
             #[cfg(feature = "dev-server")]
             let content = if watch && content_type == "text/html" {
@@ -306,10 +423,21 @@ fn serve(port: u16, dir: PathBuf, watch: bool) {
             #[cfg(not(feature = "dev-server"))]
             let content = content;
 
-            Response::from_data(content).with_header(
+            let mut response = Response::from_data(content).with_header(
                 tiny_http::Header::from_bytes(&b"Content-Type"[..], content_type.as_bytes())
                     .expect("header"),
-            )
+            );
+            if let Some(encoding) = encoding {
+                response.add_header(
+                    tiny_http::Header::from_bytes(&b"Content-Encoding"[..], encoding.as_bytes())
+                        .expect("header"),
+                );
+                response.add_header(
+                    tiny_http::Header::from_bytes(&b"Vary"[..], &b"Accept-Encoding"[..])
+                        .expect("header"),
+                );
+            }
+            response
         } else {
             Response::from_string("404 Not Found").with_status_code(404)
         };
@@ -318,6 +446,88 @@ fn serve(port: u16, dir: PathBuf, watch: bool) {
     }
 }
 
+/// Map a request URL to the file to serve.
+///
+/// `/` (and any directory) maps to `index`. With `spa`, a missing path
+/// falls back to the root index so client-side routes like `/users/42`
+/// load the app; paths that look like assets (a known file extension)
+/// still resolve to `None` so broken asset links surface as 404s.
+fn resolve_request_path(
+    dir: &std::path::Path,
+    url: &str,
+    index: &str,
+    spa: bool,
+) -> Option<PathBuf> {
+    let route = url.split(['?', '#']).next().unwrap_or("");
+    let relative = route.trim_start_matches('/');
+    let path = dir.join(relative);
+
+    if path.is_dir() {
+        return Some(path.join(index));
+    }
+    if path.is_file() {
+        return Some(path);
+    }
+
+    let is_asset = get_content_type(&path) != "application/octet-stream";
+    if spa && !is_asset {
+        Some(dir.join(index))
+    } else {
+        None
+    }
+}
+
+/// Path of the precompressed sibling for a `Content-Encoding` value
+/// (`app.wasm` -> `app.wasm.br` / `app.wasm.gz`).
+fn precompressed_path(path: &std::path::Path, encoding: &str) -> PathBuf {
+    let suffix = if encoding == "gzip" { "gz" } else { encoding };
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Pick a `Content-Encoding` for a response.
+///
+/// Brotli is preferred over gzip whenever the client accepts both and both
+/// precompressed files exist. Encodings with `q=0` are refused, and
+/// already-compressed media types are always served as-is.
+fn select_encoding(
+    accept_encoding: &str,
+    content_type: &str,
+    has_br: bool,
+    has_gzip: bool,
+) -> Option<&'static str> {
+    let already_compressed = (content_type.starts_with("image/")
+        && content_type != "image/svg+xml")
+        || content_type == "font/woff2"
+        || content_type == "font/woff";
+    if already_compressed {
+        return None;
+    }
+
+    let accepts = |name: &str| {
+        accept_encoding.split(',').any(|entry| {
+            let mut parts = entry.split(';').map(str::trim);
+            let coding = parts.next().unwrap_or("");
+            let refused = parts.any(|p| {
+                p.strip_prefix("q=")
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .is_some_and(|q| q <= 0.0)
+            });
+            (coding.eq_ignore_ascii_case(name) || coding == "*") && !refused
+        })
+    };
+
+    if has_br && accepts("br") {
+        Some("br")
+    } else if has_gzip && accepts("gzip") {
+        Some("gzip")
+    } else {
+        None
+    }
+}
+
 #[cfg(feature = "dev-server")]
 /// Inject hot reload script before </body> or at end of HTML
 fn inject_hot_reload_script(html: &[u8]) -> Vec<u8> {
@@ -407,14 +617,12 @@ fn trigger_hot_reload() {
 /// Handle YAML file change: validate and trigger reload if valid.
 fn handle_yaml_change(path: &std::path::Path) {
     println!("[watch] YAML change: {}", path.display());
-    if let Ok(content) = fs::read_to_string(path) {
-        match presentar_yaml::Manifest::from_yaml(&content) {
-            Ok(_) => {
-                println!("[watch] YAML valid");
-                trigger_hot_reload();
-            }
-            Err(e) => eprintln!("[watch] YAML error: {}", e),
+    match presentar_yaml::Manifest::from_file(path) {
+        Ok(_) => {
+            println!("[watch] YAML valid");
+            trigger_hot_reload();
         }
+        Err(e) => eprintln!("[watch] YAML error: {}", e),
     }
 }
 
@@ -505,7 +713,7 @@ fn rebuild_wasm() {
     }
 }
 
-fn bundle(output: PathBuf, no_optimize: bool) {
+fn bundle(output: PathBuf, no_optimize: bool, hash: bool) {
     println!("Building Presentar WASM bundle...");
 
     // Build with wasm-pack
@@ -547,8 +755,23 @@ fn bundle(output: PathBuf, no_optimize: bool) {
         println!("Copied index.html");
     }
 
+    let mut wasm_file = output.join("pkg/presentar_bg.wasm");
+    if hash {
+        match hash_bundle_assets(&output) {
+            Ok(assets) => {
+                println!("Hashed {} asset(s), wrote {}", assets.len(), ASSET_MANIFEST);
+                if let Some(hashed) = assets.get("pkg/presentar_bg.wasm") {
+                    wasm_file = output.join(hashed);
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to hash bundle assets: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
     // Print bundle size
-    let wasm_file = output.join("pkg/presentar_bg.wasm");
     if wasm_file.exists() {
         let size = fs::metadata(&wasm_file).map(|m| m.len()).unwrap_or(0);
         println!();
@@ -558,6 +781,158 @@ fn bundle(output: PathBuf, no_optimize: bool) {
     }
 }
 
+/// Bundle files renamed by `--hash`, in dependency order: each file's
+/// references are rewritten before it is itself hashed.
+const HASHED_ASSETS: [&str; 2] = ["pkg/presentar_bg.wasm", "pkg/presentar.js"];
+
+/// Files whose references to hashed assets are rewritten but which keep
+/// their own name (the entry point must stay addressable).
+const HASH_REFERRERS: [&str; 1] = ["index.html"];
+
+/// Logical-to-hashed filename map written next to the bundle.
+const ASSET_MANIFEST: &str = "asset-manifest.json";
+
+/// Hex digits of the content digest embedded in hashed file names.
+const ASSET_DIGEST_LEN: usize = 16;
+
+/// Rename bundle assets to `<stem>.<digest>.<ext>`, rewrite references to
+/// them, and write the asset manifest.
+///
+/// Returns the logical-to-hashed mapping (paths relative to `output`).
+/// Hashed copies left behind by earlier builds are removed. Without
+/// content hashes the `immutable` cache header used by `deploy` would pin
+/// stale WASM across releases.
+fn hash_bundle_assets(
+    output: &std::path::Path,
+) -> std::io::Result<std::collections::BTreeMap<String, String>> {
+    let mut assets = std::collections::BTreeMap::new();
+
+    for logical in HASHED_ASSETS {
+        let path = output.join(logical);
+        if !path.exists() {
+            continue;
+        }
+
+        // Rewrite references to previously hashed assets first so this
+        // file's hash covers its final contents.
+        if path.extension().and_then(|e| e.to_str()) != Some("wasm") {
+            rewrite_asset_references(&path, &assets)?;
+        }
+
+        let bytes = fs::read(&path)?;
+        let hashed = hashed_file_name(logical, &bytes);
+        remove_stale_hashed_files(output, logical, &hashed)?;
+        fs::rename(&path, output.join(&hashed))?;
+        assets.insert(logical.to_string(), hashed);
+    }
+
+    for referrer in HASH_REFERRERS {
+        let path = output.join(referrer);
+        if path.exists() {
+            rewrite_asset_references(&path, &assets)?;
+        }
+    }
+
+    let manifest = serde_json::to_string_pretty(&assets).map_err(std::io::Error::other)?;
+    fs::write(output.join(ASSET_MANIFEST), manifest)?;
+    Ok(assets)
+}
+
+/// `pkg/presentar_bg.wasm` + contents -> `pkg/presentar_bg.<digest>.wasm`,
+/// where the digest is the leading 64 bits of the SHA-256 of the contents.
+fn hashed_file_name(logical: &str, bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    use std::fmt::Write as _;
+
+    let digest = Sha256::digest(bytes)
+        .iter()
+        .take(ASSET_DIGEST_LEN / 2)
+        .fold(String::with_capacity(ASSET_DIGEST_LEN), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        });
+    match logical.rsplit_once('.') {
+        Some((stem, ext)) => format!("{}.{}.{}", stem, digest, ext),
+        None => format!("{}.{}", logical, digest),
+    }
+}
+
+/// Whether `name` is a hashed copy of the logical file name `logical`.
+fn is_hashed_variant(name: &str, logical: &str) -> bool {
+    let (stem, ext) = logical.rsplit_once('.').unwrap_or((logical, ""));
+    let Some(rest) = name.strip_prefix(stem).and_then(|r| r.strip_prefix('.')) else {
+        return false;
+    };
+    let digest = if ext.is_empty() {
+        Some(rest)
+    } else {
+        rest.strip_suffix(ext).and_then(|r| r.strip_suffix('.'))
+    };
+    digest.is_some_and(|d| d.len() == ASSET_DIGEST_LEN && d.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+/// Delete hashed copies of `logical` from earlier builds, keeping `current`.
+fn remove_stale_hashed_files(
+    output: &std::path::Path,
+    logical: &str,
+    current: &str,
+) -> std::io::Result<()> {
+    let path = output.join(logical);
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name().and_then(|n| n.to_str())) else {
+        return Ok(());
+    };
+    let keep = output.join(current);
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let stale = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|file| is_hashed_variant(file, name));
+        if stale && entry.path() != keep {
+            fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+/// Rewrite URL references to hashed assets inside a text file.
+fn rewrite_asset_references(
+    path: &std::path::Path,
+    assets: &std::collections::BTreeMap<String, String>,
+) -> std::io::Result<()> {
+    let mut content = fs::read_to_string(path)?;
+    for (logical, hashed) in assets {
+        let from = logical.rsplit('/').next().unwrap_or(logical);
+        let to = hashed.rsplit('/').next().unwrap_or(hashed);
+        content = replace_url_references(&content, from, to);
+    }
+    fs::write(path, content)
+}
+
+/// Replace `from` where it is the last path segment of a quoted URL or
+/// `url(...)` reference, such as `"./pkg/app.js"` or `'app.js?v=1'`.
+/// Occurrences inside longer names (`myapp.js`) or prose are left alone.
+fn replace_url_references(content: &str, from: &str, to: &str) -> String {
+    const OPENERS: [char; 5] = ['"', '\'', '`', '(', '/'];
+    const CLOSERS: [char; 6] = ['"', '\'', '`', ')', '?', '#'];
+
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(index) = rest.find(from) {
+        let (before, after) = (&rest[..index], &rest[index + from.len()..]);
+        let opens = before
+            .chars()
+            .next_back()
+            .is_some_and(|c| OPENERS.contains(&c));
+        let closes = after.chars().next().is_some_and(|c| CLOSERS.contains(&c));
+        result.push_str(before);
+        result.push_str(if opens && closes { to } else { from });
+        rest = after;
+    }
+    result.push_str(rest);
+    result
+}
+
 fn new_project(name: &str) {
     println!("Creating new Presentar project: {}", name);
 
@@ -620,15 +995,7 @@ layout:
 fn check_manifest(path: &PathBuf) {
     println!("Checking manifest: {}", path.display());
 
-    let content = match fs::read_to_string(path) {
-        Ok(c) => c,
-        Err(e) => {
-            eprintln!("Failed to read manifest: {}", e);
-            std::process::exit(1);
-        }
-    };
-
-    match presentar_yaml::Manifest::from_yaml(&content) {
+    match presentar_yaml::Manifest::from_file(path) {
         Ok(manifest) => {
             println!("Manifest valid!");
             println!("  Name: {}", manifest.name);
@@ -643,18 +1010,87 @@ fn check_manifest(path: &PathBuf) {
     }
 }
 
-fn compute_score(path: &PathBuf, format: &str, badge_path: Option<&PathBuf>) {
-    println!("Computing quality score for: {}", path.display());
+fn lint_command(path: &PathBuf, strict: bool) {
+    let diagnostics = match lint::lint_file(path) {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    for diagnostic in &diagnostics {
+        eprintln!("{}", diagnostic.render());
+    }
+
+    let errors = diagnostics
+        .iter()
+        .filter(|d| d.level == lint::LintLevel::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+    println!(
+        "{}: {} error(s), {} warning(s)",
+        path.display(),
+        errors,
+        warnings
+    );
+
+    if errors > 0 || (strict && warnings > 0) {
+        std::process::exit(1);
+    }
+}
+
+fn render_command(
+    path: &PathBuf,
+    width: u32,
+    height: u32,
+    output: &PathBuf,
+    png: Option<&PathBuf>,
+    theme: Option<&Theme>,
+) {
+    println!("Rendering manifest: {}", path.display());
 
-    let content = match fs::read_to_string(path) {
-        Ok(c) => c,
+    let manifest = match presentar_yaml::Manifest::from_file(path) {
+        Ok(m) => m,
         Err(e) => {
-            eprintln!("Failed to read manifest: {}", e);
+            eprintln!("Manifest invalid: {}", e);
             std::process::exit(1);
         }
     };
 
-    let manifest = match presentar_yaml::Manifest::from_yaml(&content) {
+    let commands = match theme {
+        Some(theme) => {
+            render::render_manifest_themed(&manifest, theme, width as f32, height as f32)
+        }
+        None => render::render_manifest(&manifest, width as f32, height as f32),
+    };
+
+    if let Err(e) = fs::write(output, render::to_svg(&commands, width, height)) {
+        eprintln!("Failed to write SVG: {}", e);
+        std::process::exit(1);
+    }
+    println!("  SVG: {} ({}x{})", output.display(), width, height);
+
+    if let Some(png_path) = png {
+        let png = match render::to_png(&commands, width, height) {
+            Ok(png) => png,
+            Err(e) => {
+                eprintln!("Failed to render PNG: {}", e);
+                std::process::exit(1);
+            }
+        };
+        if let Err(e) = fs::write(png_path, png) {
+            eprintln!("Failed to write PNG: {}", e);
+            std::process::exit(1);
+        }
+        println!("  PNG: {} ({}x{})", png_path.display(), width, height);
+    }
+}
+
+fn compute_score(path: &PathBuf, format: &str, badge_path: Option<&PathBuf>) {
+    println!("Computing quality score for: {}", path.display());
+
+    let manifest = match presentar_yaml::Manifest::from_file(path) {
         Ok(m) => m,
         Err(e) => {
             eprintln!("Manifest invalid: {}", e);
@@ -897,18 +1333,16 @@ fn generate_badge(score: &QualityScore) -> String {
     )
 }
 
-fn run_gates(path: &PathBuf, min_grade: &str, min_score: Option<f64>, strict: bool) {
+fn run_gates(
+    path: &PathBuf,
+    min_grade: &str,
+    min_score: Option<f64>,
+    strict: bool,
+    benchmark: Option<&PathBuf>,
+) {
     println!("Running quality gates for: {}", path.display());
 
-    let content = match fs::read_to_string(path) {
-        Ok(c) => c,
-        Err(e) => {
-            eprintln!("Failed to read manifest: {}", e);
-            std::process::exit(1);
-        }
-    };
-
-    let manifest = match presentar_yaml::Manifest::from_yaml(&content) {
+    let manifest = match presentar_yaml::Manifest::from_file(path) {
         Ok(m) => m,
         Err(e) => {
             eprintln!("GATE FAILED: Invalid manifest - {}", e);
@@ -941,17 +1375,43 @@ fn run_gates(path: &PathBuf, min_grade: &str, min_score: Option<f64>, strict: bo
         }
     }
 
+    // Measured performance (replaces the manifest-based estimate when given)
+    if let Some(bench_path) = benchmark {
+        match load_benchmark(bench_path) {
+            Ok(result) => {
+                println!(
+                    "Benchmark mean frame time: {:.2}ms",
+                    result.metrics.frame_times.mean_us / 1000.0
+                );
+                let gates = QualityGates::load_default();
+                for v in gates.performance.check_benchmark(&result).violations {
+                    let message = format!("{}: {} (expected {})", v.gate, v.actual, v.expected);
+                    match v.severity {
+                        ViolationSeverity::Error => failures.push(message),
+                        ViolationSeverity::Warning => warnings.push(message),
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("GATE FAILED: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
     // Additional gate checks
     if score.accessibility < 10.0 {
-        warnings.push("Low accessibility score - consider adding descriptions and ARIA labels");
+        warnings.push(
+            "Low accessibility score - consider adding descriptions and ARIA labels".to_string(),
+        );
     }
 
     if score.documentation < 5.0 {
-        warnings.push("Poor documentation - add name, version, and description");
+        warnings.push("Poor documentation - add name, version, and description".to_string());
     }
 
     if manifest.layout.sections.is_empty() {
-        warnings.push("No sections defined in layout");
+        warnings.push("No sections defined in layout".to_string());
     }
 
     // Report results
@@ -985,6 +1445,50 @@ fn run_gates(path: &PathBuf, min_grade: &str, min_score: Option<f64>, strict: bo
     println!("GATE PASSED");
 }
 
+/// Read a benchmark JSON file for the performance gate.
+///
+/// Accepts `BenchmarkResult::to_json` output from the headless harness
+/// (`metrics.frame_times.mean_us`) or a Criterion `estimates.json`
+/// (`mean.point_estimate`, in nanoseconds). Only the fields the gate checks
+/// (mean frame time, frame count, peak memory) are restored.
+fn load_benchmark(path: &PathBuf) -> Result<BenchmarkResult, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read benchmark {}: {}", path.display(), e))?;
+    let json: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid benchmark JSON {}: {}", path.display(), e))?;
+    let field = |pointer: &str| json.pointer(pointer).and_then(serde_json::Value::as_u64);
+
+    let mut metrics = RenderMetrics::new();
+    if let Some(mean_us) = json
+        .pointer("/metrics/frame_times/mean_us")
+        .and_then(serde_json::Value::as_f64)
+    {
+        metrics.frame_times.mean_us = mean_us;
+        metrics.frame_count = field("/metrics/frame_count").unwrap_or(0);
+        metrics.memory.peak_bytes = field("/metrics/memory/peak_bytes")
+            .and_then(|b| usize::try_from(b).ok())
+            .unwrap_or(0);
+    } else if let Some(mean_ns) = json
+        .pointer("/mean/point_estimate")
+        .and_then(serde_json::Value::as_f64)
+    {
+        metrics.frame_times.mean_us = mean_ns / 1000.0;
+    } else {
+        return Err(format!(
+            "Benchmark {} has no mean frame time (expected metrics.frame_times.mean_us or mean.point_estimate)",
+            path.display()
+        ));
+    }
+
+    Ok(BenchmarkResult {
+        widget_name: json["widget"].as_str().unwrap_or("benchmark").to_string(),
+        metrics,
+        final_frame: String::new(),
+        width: 0,
+        height: 0,
+    })
+}
+
 fn grade_to_value(grade: &str) -> u32 {
     match grade.to_uppercase().as_str() {
         "A+" => 97,
@@ -1013,6 +1517,7 @@ fn deploy(
     region: &str,
     dry_run: bool,
     skip_build: bool,
+    hash: bool,
 ) {
     println!("Deploying Presentar application...");
     println!("  Target: {}", target);
@@ -1027,11 +1532,12 @@ fn deploy(
         println!("Step 1: Building production bundle...");
         if dry_run {
             println!(
-                "  [dry-run] Would run: presentar bundle --output {}",
+                "  [dry-run] Would run: presentar bundle{} --output {}",
+                if hash { " --hash" } else { "" },
                 source.display()
             );
         } else {
-            bundle(source.clone(), false);
+            bundle(source.clone(), false, hash);
         }
         println!();
     }
@@ -1052,10 +1558,14 @@ fn deploy(
         "cloudflare" => deploy_to_cloudflare(source, bucket, dry_run),
         "vercel" => deploy_to_vercel(source, dry_run),
         "netlify" => deploy_to_netlify(source, dry_run),
+        "github-pages" => deploy_to_github_pages(source, bucket, dry_run),
+        "fastly" => deploy_to_fastly(source, bucket, distribution, dry_run),
         "local" => deploy_to_local(source, bucket, dry_run),
         _ => {
             eprintln!("Unknown deployment target: {}", target);
-            eprintln!("Supported targets: s3, cloudflare, vercel, netlify, local");
+            eprintln!(
+                "Supported targets: s3, cloudflare, vercel, netlify, github-pages, fastly, local"
+            );
             std::process::exit(1);
         }
     }
@@ -1271,6 +1781,223 @@ fn deploy_to_netlify(source: &PathBuf, dry_run: bool) {
     }
 }
 
+/// Branch GitHub Pages serves from.
+const GH_PAGES_BRANCH: &str = "gh-pages";
+
+/// Throwaway repository that builds the `gh-pages` commit.
+fn github_pages_git_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("presentar-gh-pages-{}", std::process::id()))
+}
+
+/// A `git` invocation using `git_dir` as the repository and `source` as its
+/// work tree.
+fn bundle_git(source: &Path, git_dir: &Path, args: &[&str]) -> Vec<String> {
+    let mut command = vec![
+        "git".to_string(),
+        "--git-dir".to_string(),
+        git_dir.to_string_lossy().into_owned(),
+        "--work-tree".to_string(),
+        source.to_string_lossy().into_owned(),
+    ];
+    command.extend(args.iter().map(ToString::to_string));
+    command
+}
+
+/// Git invocations that publish `source` as the sole commit of `gh-pages`.
+///
+/// The repository lives in `git_dir`, outside the bundle, so neither the
+/// project's history nor `source` is touched and no `.git` ends up in the
+/// published files. The push is forced because every deploy replaces the
+/// branch.
+fn github_pages_commands(source: &Path, git_dir: &Path, remote: &str) -> Vec<Vec<String>> {
+    vec![
+        bundle_git(source, git_dir, &["init", "-q"]),
+        bundle_git(source, git_dir, &["checkout", "-q", "-B", GH_PAGES_BRANCH]),
+        bundle_git(source, git_dir, &["add", "-A"]),
+        bundle_git(
+            source,
+            git_dir,
+            &["commit", "-q", "-m", "Deploy to GitHub Pages"],
+        ),
+        bundle_git(
+            source,
+            git_dir,
+            &["push", "--force", remote, GH_PAGES_BRANCH],
+        ),
+    ]
+}
+
+/// What a dry run of a GitHub Pages deploy reports it would do.
+fn github_pages_dry_run(source: &Path, remote: &str) -> Vec<String> {
+    let mut lines = vec![format!(
+        "Would create: {}",
+        source.join(".nojekyll").display()
+    )];
+    lines.extend(
+        github_pages_commands(source, &github_pages_git_dir(), remote)
+            .iter()
+            .map(|command| format!("Would run: {}", command.join(" "))),
+    );
+    lines
+}
+
+/// Run the planned git commands, returning whether anything was pushed.
+///
+/// A commit that fails with nothing staged is not an error: the bundle
+/// has nothing to publish, so the push is skipped.
+fn run_github_pages_commands(
+    commands: &[Vec<String>],
+    source: &Path,
+    git_dir: &Path,
+) -> Result<bool, String> {
+    for command in commands {
+        let status = Command::new(&command[0])
+            .args(&command[1..])
+            .status()
+            .map_err(|e| format!("Error running git: {e}"))?;
+        if status.success() {
+            continue;
+        }
+        if command.iter().any(|arg| arg == "commit") && nothing_staged(source, git_dir) {
+            return Ok(false);
+        }
+        return Err(format!(
+            "GitHub Pages deployment failed: {}",
+            command.join(" ")
+        ));
+    }
+    Ok(true)
+}
+
+/// Whether the index of the bundle repository matches an empty commit.
+fn nothing_staged(source: &Path, git_dir: &Path) -> bool {
+    let command = bundle_git(source, git_dir, &["diff", "--cached", "--quiet"]);
+    Command::new(&command[0])
+        .args(&command[1..])
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Deploy to GitHub Pages by force-pushing the bundle to `gh-pages`.
+fn deploy_to_github_pages(source: &PathBuf, remote: Option<&str>, dry_run: bool) {
+    println!("Step 2: Deploying to GitHub Pages...");
+    println!("  Branch: {}", GH_PAGES_BRANCH);
+
+    if dry_run {
+        let remote = remote.unwrap_or("<origin url>");
+        println!("  Remote: {}", remote);
+        for line in github_pages_dry_run(source, remote) {
+            println!("  [dry-run] {}", line);
+        }
+        return;
+    }
+
+    // The bundle repository has no remotes, so resolve the project's origin.
+    let remote = match remote {
+        Some(r) => r.to_string(),
+        None => match Command::new("git")
+            .args(["remote", "get-url", "origin"])
+            .output()
+        {
+            Ok(out) if out.status.success() => {
+                String::from_utf8_lossy(&out.stdout).trim().to_string()
+            }
+            _ => {
+                eprintln!("Could not determine the git remote for GitHub Pages");
+                eprintln!("Pass the repository URL with --bucket");
+                std::process::exit(1);
+            }
+        },
+    };
+    println!("  Remote: {}", remote);
+
+    // Without this, GitHub's Jekyll build hides files starting with '_'.
+    if let Err(e) = fs::write(source.join(".nojekyll"), "") {
+        eprintln!("Failed to write .nojekyll: {}", e);
+        std::process::exit(1);
+    }
+
+    let git_dir = github_pages_git_dir();
+    let _ = fs::remove_dir_all(&git_dir);
+    let commands = github_pages_commands(source, &git_dir, &remote);
+    let result = run_github_pages_commands(&commands, source, &git_dir);
+    let _ = fs::remove_dir_all(&git_dir);
+
+    match result {
+        Ok(true) => println!("Deployed to GitHub Pages successfully!"),
+        Ok(false) => println!("Nothing to commit; GitHub Pages left unchanged."),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Deploy to Fastly by uploading the bundle into a KV store.
+fn deploy_to_fastly(
+    source: &PathBuf,
+    store_id: Option<&str>,
+    service_id: Option<&str>,
+    dry_run: bool,
+) {
+    let store_id = match store_id {
+        Some(id) => id,
+        None => {
+            eprintln!("Error: --bucket (Fastly KV store ID) is required for Fastly deployment");
+            std::process::exit(1);
+        }
+    };
+
+    println!("Step 2: Uploading to Fastly KV store...");
+    println!("  Store: {}", store_id);
+
+    if dry_run {
+        println!(
+            "  [dry-run] Would run: fastly kv-store-entry create --store-id {} --dir {}",
+            store_id,
+            source.display()
+        );
+    } else {
+        let status = Command::new("fastly")
+            .args(["kv-store-entry", "create", "--store-id", store_id])
+            .args(["--dir", &source.to_string_lossy()])
+            .status();
+
+        match status {
+            Ok(s) if s.success() => println!("Uploaded to Fastly successfully!"),
+            Ok(_) => {
+                eprintln!("Fastly upload failed");
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Error running fastly: {}", e);
+                eprintln!("Make sure the Fastly CLI is installed: https://www.fastly.com/documentation/reference/tools/cli/");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    // Purge the service cache so the new files are served.
+    if let Some(service_id) = service_id {
+        println!();
+        println!("Step 3: Purging Fastly cache...");
+        if dry_run {
+            println!(
+                "  [dry-run] Would run: fastly purge --all --service-id {}",
+                service_id
+            );
+        } else {
+            let status = Command::new("fastly")
+                .args(["purge", "--all", "--service-id", service_id])
+                .status();
+            match status {
+                Ok(s) if s.success() => println!("Cache purged"),
+                _ => eprintln!("Warning: Fastly cache purge failed"),
+            }
+        }
+    }
+}
+
 /// Deploy to local directory (for testing or local server).
 fn deploy_to_local(source: &PathBuf, dest: Option<&str>, dry_run: bool) {
     let dest = dest.unwrap_or("/var/www/html");
@@ -1379,6 +2106,7 @@ fn get_cache_control(path: &PathBuf) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use presentar_test::PerformanceGates;
 
     #[test]
     fn test_grade_to_value() {
@@ -1405,6 +2133,47 @@ mod tests {
         assert_eq!(grade_to_value("c-"), 70);
     }
 
+    #[test]
+    fn test_load_benchmark() {
+        let temp_dir = std::env::temp_dir().join("presentar-test-gate-benchmark");
+        fs::create_dir_all(&temp_dir).unwrap();
+
+        let harness = temp_dir.join("harness.json");
+        fs::write(
+            &harness,
+            r#"{"widget": "cpu", "metrics": {"frame_times": {"mean_us": 20000.0}}}"#,
+        )
+        .unwrap();
+        let result = load_benchmark(&harness).unwrap();
+        assert_eq!(result.widget_name, "cpu");
+        assert_eq!(result.metrics.frame_times.mean_us, 20000.0);
+        let check = QualityGates::default().performance.check_benchmark(&result);
+        assert!(!check.passed);
+        assert_eq!(check.violations[0].gate, "max_render_time_ms");
+        let relaxed = PerformanceGates {
+            max_render_time_ms: 25,
+            ..PerformanceGates::default()
+        };
+        assert!(relaxed.check_benchmark(&result).passed);
+
+        let criterion = temp_dir.join("estimates.json");
+        fs::write(&criterion, r#"{"mean": {"point_estimate": 4000000.0}}"#).unwrap();
+        let result = load_benchmark(&criterion).unwrap();
+        assert_eq!(result.metrics.frame_times.mean_us, 4000.0);
+        assert!(
+            QualityGates::default()
+                .performance
+                .check_benchmark(&result)
+                .passed
+        );
+
+        let empty = temp_dir.join("empty.json");
+        fs::write(&empty, "{}").unwrap();
+        assert!(load_benchmark(&empty).is_err());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
     #[test]
     fn test_generate_badge() {
         let score = QualityScore {
@@ -1600,6 +2369,326 @@ layout:
     // Deploy Tests
     // ==========================================================================
 
+    #[test]
+    fn test_hash_bundle_assets() {
+        let temp_dir = std::env::temp_dir().join("presentar-test-bundle-hash");
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(temp_dir.join("pkg")).unwrap();
+
+        fs::write(temp_dir.join("pkg/presentar_bg.wasm"), [0u8, 97, 115, 109]).unwrap();
+        fs::write(
+            temp_dir.join("pkg/presentar.js"),
+            "new URL('presentar_bg.wasm', import.meta.url)",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.join("index.html"),
+            "<script type=\"module\" src=\"./pkg/presentar.js\"></script>",
+        )
+        .unwrap();
+
+        let assets = hash_bundle_assets(&temp_dir).unwrap();
+
+        let wasm = &assets["pkg/presentar_bg.wasm"];
+        let js = &assets["pkg/presentar.js"];
+        assert!(wasm.starts_with("pkg/presentar_bg."));
+        assert_eq!(std::path::Path::new(wasm).extension().unwrap(), "wasm");
+        assert_ne!(wasm, "pkg/presentar_bg.wasm");
+        assert!(temp_dir.join(wasm).exists());
+        assert!(temp_dir.join(js).exists());
+        assert!(!temp_dir.join("pkg/presentar_bg.wasm").exists());
+
+        let manifest = fs::read_to_string(temp_dir.join(ASSET_MANIFEST)).unwrap();
+        let manifest: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+        assert_eq!(manifest["pkg/presentar_bg.wasm"], wasm.as_str());
+
+        let js_content = fs::read_to_string(temp_dir.join(js)).unwrap();
+        assert!(js_content.contains(wasm.trim_start_matches("pkg/")));
+        let html = fs::read_to_string(temp_dir.join("index.html")).unwrap();
+        assert!(html.contains(js.as_str()));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_hashed_file_name_depends_on_content() {
+        let a = hashed_file_name("pkg/app.wasm", b"one");
+        let b = hashed_file_name("pkg/app.wasm", b"two");
+        assert_ne!(a, b);
+        assert_eq!(a, hashed_file_name("pkg/app.wasm", b"one"));
+        assert_eq!(a.len(), "pkg/app.0123456789abcdef.wasm".len());
+        // SHA-256("one") begins 7692c3ad3540bb80.
+        assert_eq!(a, "pkg/app.7692c3ad3540bb80.wasm");
+    }
+
+    #[test]
+    fn test_hash_bundle_assets_removes_stale_copies() {
+        let temp_dir = std::env::temp_dir().join("presentar-test-bundle-hash-stale");
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(temp_dir.join("pkg")).unwrap();
+
+        let stale = temp_dir.join("pkg/presentar_bg.0123456789abcdef.wasm");
+        let unrelated = temp_dir.join("pkg/presentar_bg.extra.wasm");
+        fs::write(&stale, b"old").unwrap();
+        fs::write(&unrelated, b"keep").unwrap();
+        fs::write(temp_dir.join("pkg/presentar_bg.wasm"), b"new").unwrap();
+
+        let assets = hash_bundle_assets(&temp_dir).unwrap();
+
+        assert!(temp_dir.join(&assets["pkg/presentar_bg.wasm"]).exists());
+        assert!(!stale.exists());
+        assert!(unrelated.exists());
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_replace_url_references_only_whole_names() {
+        let content = "import init from './app.js';\n\
+                       <script src=\"pkg/app.js?v=1\"></script>\n\
+                       import other from './myapp.js';\n\
+                       // loads app.js on startup\n\
+                       url(app.js)";
+        assert_eq!(
+            replace_url_references(content, "app.js", "app.0123456789abcdef.js"),
+            "import init from './app.0123456789abcdef.js';\n\
+             <script src=\"pkg/app.0123456789abcdef.js?v=1\"></script>\n\
+             import other from './myapp.js';\n\
+             // loads app.js on startup\n\
+             url(app.0123456789abcdef.js)"
+        );
+    }
+
+    #[test]
+    fn test_deploy_hash_is_opt_in() {
+        let parse = |args: &[&str]| match Cli::try_parse_from(args).unwrap().command {
+            Commands::Deploy { hash, .. } => hash,
+            _ => unreachable!("deploy subcommand"),
+        };
+        assert!(!parse(&["presentar", "deploy"]));
+        assert!(parse(&["presentar", "deploy", "--hash"]));
+    }
+
+    #[test]
+    fn test_github_pages_commands() {
+        let commands = github_pages_commands(
+            Path::new("dist"),
+            Path::new("/tmp/gh"),
+            "git@github.com:paiml/presentar.git",
+        );
+        let lines: Vec<String> = commands.iter().map(|c| c.join(" ")).collect();
+        let git = "git --git-dir /tmp/gh --work-tree dist";
+        assert_eq!(
+            lines,
+            [
+                format!("{git} init -q"),
+                format!("{git} checkout -q -B gh-pages"),
+                format!("{git} add -A"),
+                format!("{git} commit -q -m Deploy to GitHub Pages"),
+                format!("{git} push --force git@github.com:paiml/presentar.git gh-pages"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deploy_to_github_pages_dry_run_touches_nothing() {
+        let temp_dir = std::env::temp_dir().join("presentar-test-gh-pages-dry-run");
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(&temp_dir).unwrap();
+
+        let git_dir = github_pages_git_dir();
+        let git = format!(
+            "git --git-dir {} --work-tree {}",
+            git_dir.display(),
+            temp_dir.display()
+        );
+        assert_eq!(
+            github_pages_dry_run(&temp_dir, "origin"),
+            [
+                format!("Would create: {}", temp_dir.join(".nojekyll").display()),
+                format!("Would run: {git} init -q"),
+                format!("Would run: {git} checkout -q -B gh-pages"),
+                format!("Would run: {git} add -A"),
+                format!("Would run: {git} commit -q -m Deploy to GitHub Pages"),
+                format!("Would run: {git} push --force origin gh-pages"),
+            ]
+        );
+
+        deploy_to_github_pages(&temp_dir, Some("origin"), true);
+
+        assert!(!temp_dir.join(".git").exists());
+        assert!(!temp_dir.join(".nojekyll").exists());
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_github_pages_publish_keeps_git_out_of_bundle() {
+        let temp_dir = std::env::temp_dir().join("presentar-test-gh-pages-publish");
+        let _ = fs::remove_dir_all(&temp_dir);
+        let (source, git_dir, remote) = (
+            temp_dir.join("dist"),
+            temp_dir.join("repo"),
+            temp_dir.join("remote.git"),
+        );
+        fs::create_dir_all(&source).unwrap();
+        fs::write(source.join("index.html"), "<!DOCTYPE html>").unwrap();
+        let init = Command::new("git")
+            .args(["init", "-q", "--bare"])
+            .arg(&remote)
+            .status()
+            .unwrap();
+        assert!(init.success());
+
+        // Same commands as a real deploy, with a fixed identity for the commit.
+        for command in github_pages_commands(&source, &git_dir, &remote.to_string_lossy()) {
+            let status = Command::new(&command[0])
+                .args(&command[1..])
+                .env("GIT_AUTHOR_NAME", "presentar")
+                .env("GIT_AUTHOR_EMAIL", "presentar@localhost")
+                .env("GIT_COMMITTER_NAME", "presentar")
+                .env("GIT_COMMITTER_EMAIL", "presentar@localhost")
+                .status()
+                .unwrap();
+            assert!(status.success(), "{}", command.join(" "));
+        }
+
+        assert!(!source.join(".git").exists());
+        let tree = Command::new("git")
+            .arg("--git-dir")
+            .arg(&remote)
+            .args(["ls-tree", "--name-only", "gh-pages"])
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&tree.stdout), "index.html\n");
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_github_pages_nothing_to_commit_is_success() {
+        let temp_dir = std::env::temp_dir().join("presentar-test-gh-pages-empty");
+        let _ = fs::remove_dir_all(&temp_dir);
+        let (source, git_dir) = (temp_dir.join("dist"), temp_dir.join("repo"));
+        fs::create_dir_all(&source).unwrap();
+
+        let commands = github_pages_commands(&source, &git_dir, "/nonexistent/remote.git");
+        // An empty bundle stages nothing: the commit fails, the push is
+        // skipped, and the deploy still succeeds.
+        assert_eq!(
+            run_github_pages_commands(&commands, &source, &git_dir),
+            Ok(false)
+        );
+        assert!(!source.join(".git").exists());
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_resolve_request_path_spa_fallback() {
+        let temp_dir = std::env::temp_dir().join("presentar-test-serve-spa");
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(temp_dir.join("pkg")).unwrap();
+        fs::write(temp_dir.join("index.html"), "<!DOCTYPE html>").unwrap();
+        fs::write(temp_dir.join("pkg/app.js"), "// JS").unwrap();
+
+        let index = temp_dir.join("index.html");
+        assert_eq!(
+            resolve_request_path(&temp_dir, "/", "index.html", false),
+            Some(index.clone())
+        );
+        assert_eq!(
+            resolve_request_path(&temp_dir, "/pkg/app.js?v=1", "index.html", true),
+            Some(temp_dir.join("pkg/app.js"))
+        );
+        assert_eq!(
+            resolve_request_path(&temp_dir, "/users/42", "index.html", true),
+            Some(index)
+        );
+        assert_eq!(
+            resolve_request_path(&temp_dir, "/users/42", "index.html", false),
+            None
+        );
+        assert_eq!(
+            resolve_request_path(&temp_dir, "/missing.js", "index.html", true),
+            None
+        );
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_resolve_request_path_custom_index() {
+        let temp_dir = std::env::temp_dir().join("presentar-test-serve-index");
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(&temp_dir).unwrap();
+        fs::write(temp_dir.join("app.html"), "<!DOCTYPE html>").unwrap();
+
+        assert_eq!(
+            resolve_request_path(&temp_dir, "/", "app.html", false),
+            Some(temp_dir.join("app.html"))
+        );
+        assert_eq!(
+            resolve_request_path(&temp_dir, "/settings", "app.html", true),
+            Some(temp_dir.join("app.html"))
+        );
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_select_encoding_prefers_brotli() {
+        let accept = "gzip, deflate, br";
+        assert_eq!(
+            select_encoding(accept, "application/wasm", true, true),
+            Some("br")
+        );
+        assert_eq!(
+            select_encoding(accept, "application/wasm", false, true),
+            Some("gzip")
+        );
+        assert_eq!(
+            select_encoding(accept, "application/wasm", false, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_select_encoding_respects_accept_header() {
+        assert_eq!(
+            select_encoding("gzip", "application/wasm", true, true),
+            Some("gzip")
+        );
+        assert_eq!(
+            select_encoding("br;q=0, gzip", "application/wasm", true, true),
+            Some("gzip")
+        );
+        assert_eq!(
+            select_encoding("identity", "application/wasm", true, true),
+            None
+        );
+        assert_eq!(select_encoding("*", "text/html", true, false), Some("br"));
+    }
+
+    #[test]
+    fn test_select_encoding_skips_compressed_media() {
+        assert_eq!(select_encoding("br, gzip", "image/png", true, true), None);
+        assert_eq!(select_encoding("br, gzip", "font/woff2", true, true), None);
+        assert_eq!(
+            select_encoding("br, gzip", "image/svg+xml", true, true),
+            Some("br")
+        );
+    }
+
+    #[test]
+    fn test_precompressed_path() {
+        let path = PathBuf::from("www/pkg/app.wasm");
+        assert_eq!(
+            precompressed_path(&path, "br"),
+            PathBuf::from("www/pkg/app.wasm.br")
+        );
+        assert_eq!(
+            precompressed_path(&path, "gzip"),
+            PathBuf::from("www/pkg/app.wasm.gz")
+        );
+    }
+
     #[test]
     fn test_get_content_type() {
         assert_eq!(get_content_type(&PathBuf::from("index.html")), "text/html");
@@ -0,0 +1,1007 @@
+//! Headless static rendering of a manifest to SVG/PNG.
+//!
+//! Each section is built as a widget tree (a card [`Container`] around a
+//! [`Column`] of [`Text`] and placeholder widgets), placed on the manifest's
+//! column grid by the layout engine and painted into a [`RecordingCanvas`].
+//! The recorded draw commands are serialized to SVG or rasterized to PNG.
+//!
+//! `Fill` and `Image` commands reference path and tensor resources that a
+//! headless render does not have: fills are noted in the SVG output and
+//! images are drawn as placeholder frames. The rasterizer ignores group
+//! transforms.
+
+mod font;
+
+use font::{GLYPH_ADVANCE, GLYPH_ASCENT, GLYPH_HEIGHT};
+use presentar_core::draw::Transform2D;
+use presentar_core::{
+    BoxStyle, Canvas, Color, Constraints, CornerRadius, DrawCommand, FontWeight, Point,
+    RecordingCanvas, Rect, Size, StrokeStyle, Widget,
+};
+use presentar_layout::{
+    auto_place_items, compute_grid_layout, GridAutoFlow, GridItem, GridTemplate, TrackSize,
+};
+use presentar_terminal::Theme;
+use presentar_test::TestData;
+use presentar_widgets::row::CrossAxisAlignment;
+use presentar_widgets::{Column, Container, Text};
+use presentar_yaml::{Manifest, WidgetConfig};
+use std::f32::consts::{PI, TAU};
+use std::fmt::Write as _;
+
+/// Height of the title bar at the top of the frame.
+const HEADER_HEIGHT: f32 = 64.0;
+/// Outer padding around the section grid.
+const PADDING: f32 = 24.0;
+/// Inner padding of a section card.
+const CARD_PADDING: f32 = 16.0;
+/// Vertical gap between the widgets of a section.
+const WIDGET_GAP: f32 = 8.0;
+/// Minimum height of the placeholder for a data-driven widget.
+const PLACEHOLDER_HEIGHT: f32 = 128.0;
+/// Frame color for images whose tensor is not available.
+const IMAGE_PLACEHOLDER: &str = "#94a3b8";
+
+/// Colors used when painting a manifest.
+struct Palette {
+    background: Color,
+    card: Color,
+    text: Color,
+    muted: Color,
+    accent: Color,
+}
+
+impl Palette {
+    fn for_manifest(manifest: &Manifest) -> Self {
+        let theme = manifest.theme.as_ref();
+        let dark = theme.and_then(|t| t.preset.as_deref()) == Some("dark");
+        let mut palette = if dark {
+            Self {
+                background: hex("#0f172a"),
+                card: hex("#1e293b"),
+                text: hex("#e2e8f0"),
+                muted: hex("#94a3b8"),
+                accent: hex("#818cf8"),
+            }
+        } else {
+            Self {
+                background: hex("#f8fafc"),
+                card: Color::WHITE,
+                text: hex("#0f172a"),
+                muted: hex("#64748b"),
+                accent: hex("#6366f1"),
+            }
+        };
+        if let Some(primary) = theme
+            .and_then(|t| t.colors.get("primary"))
+            .and_then(|c| Color::from_hex(c).ok())
+        {
+            palette.accent = primary;
+        }
+        palette
+    }
+
+    /// Palette for a terminal color theme chosen with `--theme`.
+    fn for_theme(theme: &Theme) -> Self {
+        Self {
+            background: theme.background,
+            card: theme.background.lerp(&theme.foreground, 0.06),
+            text: theme.foreground,
+            muted: theme.background.lerp(&theme.foreground, 0.65),
+            accent: theme.cpu.sample(0.0),
+        }
+    }
+}
+
+fn hex(value: &str) -> Color {
+    Color::from_hex(value).unwrap_or(Color::BLACK)
+}
+
+/// Lay out a manifest at the given size and produce its draw commands.
+#[must_use]
+pub(crate) fn render_manifest(manifest: &Manifest, width: f32, height: f32) -> Vec<DrawCommand> {
+    render_with_palette(manifest, &Palette::for_manifest(manifest), width, height)
+}
+
+/// [`render_manifest`] with colors from `theme` instead of the manifest's.
+#[must_use]
+pub(crate) fn render_manifest_themed(
+    manifest: &Manifest,
+    theme: &Theme,
+    width: f32,
+    height: f32,
+) -> Vec<DrawCommand> {
+    render_with_palette(manifest, &Palette::for_theme(theme), width, height)
+}
+
+fn render_with_palette(
+    manifest: &Manifest,
+    palette: &Palette,
+    width: f32,
+    height: f32,
+) -> Vec<DrawCommand> {
+    let mut canvas = RecordingCanvas::new();
+    canvas.fill_rect(Rect::new(0.0, 0.0, width, height), palette.background);
+
+    let content_width = PADDING.mul_add(-2.0, width).max(0.0);
+    let mut header = Column::new()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .gap(4.0)
+        .child(
+            Text::new(manifest.name.as_str())
+                .font_size(24.0)
+                .font_weight(FontWeight::Bold)
+                .color(palette.text),
+        );
+    if !manifest.description.is_empty() {
+        header = header.child(
+            Text::new(manifest.description.as_str())
+                .font_size(13.0)
+                .color(palette.muted),
+        );
+    }
+    header.layout(Rect::new(
+        PADDING,
+        12.0,
+        content_width,
+        HEADER_HEIGHT - 12.0,
+    ));
+    header.paint(&mut canvas);
+
+    let sections = &manifest.layout.sections;
+    if sections.is_empty() {
+        return canvas.take_commands();
+    }
+
+    let columns = manifest.layout.columns.max(1) as usize;
+    let gap = manifest.layout.gap as f32;
+    let items: Vec<GridItem> = sections
+        .iter()
+        .map(|section| {
+            let span = section.span.map_or(columns, |[start, end]| {
+                (end.saturating_sub(start) + 1) as usize
+            });
+            GridItem::new().span_columns(span.clamp(1, columns))
+        })
+        .collect();
+    let mut cards: Vec<Container> = sections
+        .iter()
+        .map(|section| section_card(&section.id, &section.widgets, palette))
+        .collect();
+
+    let mut template = GridTemplate::columns(vec![TrackSize::Fr(1.0); columns]).with_gap(gap);
+    let placements = auto_place_items(&template, &items, GridAutoFlow::Row);
+
+    // Each row is as tall as its tallest card.
+    let unbounded = Constraints::loose(Size::new(content_width, f32::INFINITY));
+    let row_count = placements.iter().map(|(row, _)| row + 1).max().unwrap_or(0);
+    let mut row_heights = vec![0.0_f32; row_count];
+    for (card, (row, _)) in cards.iter().zip(&placements) {
+        row_heights[*row] = row_heights[*row].max(card.measure(unbounded).height);
+    }
+    template = template.with_rows(row_heights.into_iter().map(TrackSize::Px));
+
+    let grid_height = (height - HEADER_HEIGHT - PADDING).max(0.0);
+    let layout = compute_grid_layout(&template, content_width, grid_height, &[]);
+
+    for ((card, item), (row, col)) in cards.iter_mut().zip(&items).zip(&placements) {
+        let Some((x, y, w, h)) = layout.item_bounds(item, *row, *col) else {
+            continue;
+        };
+        card.layout(Rect::new(x + PADDING, y + HEADER_HEIGHT, w, h));
+        card.paint(&mut canvas);
+    }
+
+    canvas.take_commands()
+}
+
+/// Build the card for one section: its id followed by its widgets.
+fn section_card(id: &str, widgets: &[WidgetConfig], palette: &Palette) -> Container {
+    let mut column = Column::new()
+        .cross_axis_alignment(CrossAxisAlignment::Stretch)
+        .gap(WIDGET_GAP)
+        .child(
+            Text::new(id)
+                .font_size(12.0)
+                .font_weight(FontWeight::Semibold)
+                .color(palette.muted),
+        );
+    for widget in widgets {
+        column = add_widget(column, widget, palette);
+    }
+    Container::new()
+        .background(palette.card)
+        .corner_radius(CornerRadius::uniform(8.0))
+        .padding(CARD_PADDING)
+        .child(column)
+}
+
+fn add_widget(column: Column, widget: &WidgetConfig, palette: &Palette) -> Column {
+    let label = widget
+        .content
+        .as_deref()
+        .or(widget.id.as_deref())
+        .unwrap_or(&widget.widget_type);
+
+    match widget.widget_type.as_str() {
+        "text" => {
+            let heading = widget.style.as_deref() == Some("heading");
+            let (size, weight) = if heading {
+                (22.0, FontWeight::Bold)
+            } else {
+                (14.0, FontWeight::Normal)
+            };
+            column.child(
+                Text::new(label)
+                    .font_size(size)
+                    .font_weight(weight)
+                    .color(palette.text),
+            )
+        }
+        "metric" => column.child(
+            Column::new()
+                .cross_axis_alignment(CrossAxisAlignment::Start)
+                .gap(4.0)
+                .child(Text::new(label).font_size(12.0).color(palette.muted))
+                .child(
+                    Text::new(widget.data.as_deref().unwrap_or("—"))
+                        .font_size(24.0)
+                        .font_weight(FontWeight::Bold)
+                        .color(palette.accent),
+                ),
+        ),
+        // Data-driven widgets have no data at render time; draw a labelled
+        // placeholder of the right footprint.
+        _ => column.child(
+            Container::new()
+                .background(Color::new(
+                    palette.accent.r,
+                    palette.accent.g,
+                    palette.accent.b,
+                    0.08,
+                ))
+                .corner_radius(CornerRadius::uniform(4.0))
+                .padding(8.0)
+                .min_height(PLACEHOLDER_HEIGHT)
+                .child(
+                    Text::new(format!("{} · {label}", widget.widget_type))
+                        .font_size(12.0)
+                        .font_weight(FontWeight::Medium)
+                        .color(palette.accent),
+                ),
+        ),
+    }
+}
+
+/// Serialize draw commands to a standalone SVG document.
+#[must_use]
+pub(crate) fn to_svg(commands: &[DrawCommand], width: u32, height: u32) -> String {
+    let mut writer = SvgWriter::default();
+    for command in commands {
+        writer.command(command);
+    }
+    writer.finish(width, height)
+}
+
+/// Accumulates SVG elements, tracking clip groups opened by `PushClip`.
+#[derive(Default)]
+struct SvgWriter {
+    body: String,
+    clip_ids: usize,
+    open_clips: usize,
+}
+
+impl SvgWriter {
+    fn finish(mut self, width: u32, height: u32) -> String {
+        for _ in 0..self.open_clips {
+            self.body.push_str("</g>\n");
+        }
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+viewBox=\"0 0 {width} {height}\" font-family=\"sans-serif\">\n{}</svg>\n",
+            self.body
+        )
+    }
+
+    /// Open a group clipped to `bounds`; the caller closes it with `</g>`.
+    fn open_clip(&mut self, bounds: &Rect) {
+        let id = self.clip_ids;
+        self.clip_ids += 1;
+        let _ = writeln!(
+            self.body,
+            "<clipPath id=\"clip{id}\"><rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/></clipPath>",
+            bounds.x, bounds.y, bounds.width, bounds.height
+        );
+        let _ = writeln!(self.body, "<g clip-path=\"url(#clip{id})\">");
+    }
+
+    fn command(&mut self, command: &DrawCommand) {
+        let svg = &mut self.body;
+        match command {
+            DrawCommand::Rect {
+                bounds,
+                radius,
+                style,
+            } => {
+                let _ = write!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"",
+                    bounds.x, bounds.y, bounds.width, bounds.height
+                );
+                if radius.top_left > 0.0 {
+                    let _ = write!(svg, " rx=\"{}\"", radius.top_left);
+                }
+                write_svg_box_style(svg, style);
+                svg.push_str("/>\n");
+            }
+            DrawCommand::Circle {
+                center,
+                radius,
+                style,
+            } => {
+                let _ = write!(
+                    svg,
+                    "<circle cx=\"{}\" cy=\"{}\" r=\"{radius}\"",
+                    center.x, center.y
+                );
+                write_svg_box_style(svg, style);
+                svg.push_str("/>\n");
+            }
+            DrawCommand::Arc {
+                center,
+                radius,
+                start_angle,
+                end_angle,
+                color,
+            } => {
+                let sweep = end_angle - start_angle;
+                if sweep.abs() >= TAU {
+                    let _ = writeln!(
+                        svg,
+                        "<circle cx=\"{}\" cy=\"{}\" r=\"{radius}\"{}/>",
+                        center.x,
+                        center.y,
+                        svg_fill(*color)
+                    );
+                } else {
+                    let (sin0, cos0) = start_angle.sin_cos();
+                    let (sin1, cos1) = end_angle.sin_cos();
+                    let _ = writeln!(
+                        svg,
+                        "<path d=\"M {cx} {cy} L {} {} A {radius} {radius} 0 {} {} {} {} Z\"{}/>",
+                        radius.mul_add(cos0, center.x),
+                        radius.mul_add(sin0, center.y),
+                        u8::from(sweep.abs() > PI),
+                        u8::from(sweep > 0.0),
+                        radius.mul_add(cos1, center.x),
+                        radius.mul_add(sin1, center.y),
+                        svg_fill(*color),
+                        cx = center.x,
+                        cy = center.y,
+                    );
+                }
+            }
+            DrawCommand::Path {
+                points,
+                closed,
+                style,
+            } => {
+                let tag = if *closed { "polygon" } else { "polyline" };
+                let coords: Vec<String> =
+                    points.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+                let _ = writeln!(
+                    svg,
+                    "<{tag} points=\"{}\" fill=\"none\"{}/>",
+                    coords.join(" "),
+                    svg_stroke(style)
+                );
+            }
+            DrawCommand::Fill { path, .. } => {
+                let _ = writeln!(svg, "<!-- fill: path {path} is not available -->");
+            }
+            DrawCommand::Text {
+                content,
+                position,
+                style,
+            } => {
+                let weight = match style.weight {
+                    FontWeight::Thin => 100,
+                    FontWeight::Light => 300,
+                    FontWeight::Normal => 400,
+                    FontWeight::Medium => 500,
+                    FontWeight::Semibold => 600,
+                    FontWeight::Bold => 700,
+                    FontWeight::Black => 900,
+                };
+                // Text is positioned by its top-left corner; SVG places the
+                // baseline, which sits one font size below it.
+                let _ = writeln!(
+                    svg,
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\" font-weight=\"{weight}\"{}>{}</text>",
+                    position.x,
+                    position.y + style.size,
+                    style.size,
+                    svg_fill(style.color),
+                    escape_xml(content)
+                );
+            }
+            DrawCommand::Image { tensor, bounds, .. } => {
+                let _ = writeln!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" \
+stroke=\"{IMAGE_PLACEHOLDER}\" stroke-dasharray=\"4 2\" data-tensor=\"{tensor}\"/>",
+                    bounds.x, bounds.y, bounds.width, bounds.height
+                );
+            }
+            DrawCommand::Group {
+                children,
+                transform,
+            } => {
+                if *transform == Transform2D::identity() {
+                    svg.push_str("<g>\n");
+                } else {
+                    let matrix: Vec<String> =
+                        transform.matrix.iter().map(ToString::to_string).collect();
+                    let _ = writeln!(svg, "<g transform=\"matrix({})\">", matrix.join(" "));
+                }
+                for child in children {
+                    self.command(child);
+                }
+                self.body.push_str("</g>\n");
+            }
+            DrawCommand::Clip { bounds, child } => {
+                self.open_clip(bounds);
+                self.command(child);
+                self.body.push_str("</g>\n");
+            }
+            DrawCommand::PushClip { bounds } => {
+                self.open_clip(bounds);
+                self.open_clips += 1;
+            }
+            DrawCommand::PopClip => {
+                if self.open_clips > 0 {
+                    self.open_clips -= 1;
+                    svg.push_str("</g>\n");
+                }
+            }
+            DrawCommand::Opacity { alpha, child } => {
+                let _ = writeln!(svg, "<g opacity=\"{alpha}\">");
+                self.command(child);
+                self.body.push_str("</g>\n");
+            }
+        }
+    }
+}
+
+fn write_svg_box_style(svg: &mut String, style: &BoxStyle) {
+    match style.fill {
+        Some(fill) => svg.push_str(&svg_fill(fill)),
+        None => svg.push_str(" fill=\"none\""),
+    }
+    if let Some(stroke) = &style.stroke {
+        svg.push_str(&svg_stroke(stroke));
+    }
+}
+
+fn svg_fill(color: Color) -> String {
+    if color.a < 1.0 {
+        format!(" fill=\"{}\" fill-opacity=\"{}\"", color.to_hex(), color.a)
+    } else {
+        format!(" fill=\"{}\"", color.to_hex())
+    }
+}
+
+fn svg_stroke(style: &StrokeStyle) -> String {
+    format!(
+        " stroke=\"{}\" stroke-width=\"{}\"",
+        style.color.to_hex(),
+        style.width
+    )
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Rasterize a command list and encode it as PNG.
+pub(crate) fn to_png(commands: &[DrawCommand], width: u32, height: u32) -> Result<Vec<u8>, String> {
+    let mut raster =
+        Raster::new(width, height).ok_or_else(|| format!("{width}x{height} is too large"))?;
+    for command in commands {
+        raster.draw(command, 1.0);
+    }
+    Ok(TestData::encode_png(width, height, &raster.rgba))
+}
+
+/// Software rasterizer over an opaque RGBA frame.
+struct Raster {
+    width: usize,
+    height: usize,
+    rgba: Vec<u8>,
+    /// Active clip rectangles, each already intersected with its parent.
+    clips: Vec<Rect>,
+}
+
+impl Raster {
+    /// Allocate a white frame, or `None` if its byte size overflows.
+    fn new(width: u32, height: u32) -> Option<Self> {
+        let (width, height) = (width as usize, height as usize);
+        let len = width.checked_mul(height)?.checked_mul(4)?;
+        Some(Self {
+            width,
+            height,
+            rgba: vec![255; len],
+            clips: Vec::new(),
+        })
+    }
+
+    /// The frame intersected with the active clip.
+    fn visible(&self) -> Option<Rect> {
+        let frame = Rect::new(0.0, 0.0, self.width as f32, self.height as f32);
+        match self.clips.last() {
+            Some(clip) => frame.intersection(clip),
+            None => Some(frame),
+        }
+    }
+
+    fn push_clip(&mut self, bounds: &Rect) {
+        let clip = self
+            .visible()
+            .and_then(|visible| visible.intersection(bounds))
+            .unwrap_or_default();
+        self.clips.push(clip);
+    }
+
+    fn draw(&mut self, command: &DrawCommand, alpha: f32) {
+        match command {
+            DrawCommand::Rect {
+                bounds,
+                radius,
+                style,
+            } => {
+                if let Some(fill) = style.fill {
+                    let r = radius
+                        .top_left
+                        .min(bounds.width / 2.0)
+                        .min(bounds.height / 2.0)
+                        .max(0.0);
+                    self.fill_where(*bounds, fade(fill, alpha), |x, y| {
+                        in_rounded_rect(bounds, r, x, y)
+                    });
+                }
+                if let Some(stroke) = &style.stroke {
+                    let inner = bounds.inset(stroke.width);
+                    self.fill_where(*bounds, fade(stroke.color, alpha), |x, y| {
+                        !inner.contains_point(&Point::new(x, y))
+                    });
+                }
+            }
+            DrawCommand::Circle {
+                center,
+                radius,
+                style,
+            } => {
+                let reach = style.stroke.as_ref().map_or(0.0, |s| s.width / 2.0);
+                let area = square_around(*center, radius + reach);
+                if let Some(fill) = style.fill {
+                    self.fill_where(area, fade(fill, alpha), |x, y| {
+                        distance(*center, x, y) <= *radius
+                    });
+                }
+                if let Some(stroke) = &style.stroke {
+                    self.fill_where(area, fade(stroke.color, alpha), |x, y| {
+                        (distance(*center, x, y) - radius).abs() <= reach
+                    });
+                }
+            }
+            DrawCommand::Arc {
+                center,
+                radius,
+                start_angle,
+                end_angle,
+                color,
+            } => {
+                let sweep = end_angle - start_angle;
+                self.fill_where(
+                    square_around(*center, *radius),
+                    fade(*color, alpha),
+                    |x, y| {
+                        if distance(*center, x, y) > *radius {
+                            return false;
+                        }
+                        if sweep.abs() >= TAU {
+                            return true;
+                        }
+                        let angle = (y - center.y).atan2(x - center.x);
+                        let offset = if sweep >= 0.0 {
+                            angle - start_angle
+                        } else {
+                            start_angle - angle
+                        };
+                        offset.rem_euclid(TAU) <= sweep.abs()
+                    },
+                );
+            }
+            DrawCommand::Path {
+                points,
+                closed,
+                style,
+            } => {
+                let reach = style.width.max(1.0) / 2.0;
+                let color = fade(style.color, alpha);
+                let closing = points.first().filter(|_| *closed && points.len() > 2);
+                for (a, b) in points.iter().zip(points.iter().skip(1).chain(closing)) {
+                    let area = Rect::new(
+                        a.x.min(b.x) - reach,
+                        a.y.min(b.y) - reach,
+                        reach.mul_add(2.0, (a.x - b.x).abs()),
+                        reach.mul_add(2.0, (a.y - b.y).abs()),
+                    );
+                    self.fill_where(area, color, |x, y| segment_distance(*a, *b, x, y) <= reach);
+                }
+            }
+            DrawCommand::Text {
+                content,
+                position,
+                style,
+            } => self.text(content, *position, style.size, fade(style.color, alpha)),
+            DrawCommand::Image { bounds, .. } => {
+                let inner = bounds.inset(1.0);
+                self.fill_where(*bounds, fade(hex(IMAGE_PLACEHOLDER), alpha), |x, y| {
+                    !inner.contains_point(&Point::new(x, y))
+                });
+            }
+            DrawCommand::Group { children, .. } => {
+                for child in children {
+                    self.draw(child, alpha);
+                }
+            }
+            DrawCommand::Clip { bounds, child } => {
+                self.push_clip(bounds);
+                self.draw(child, alpha);
+                self.clips.pop();
+            }
+            DrawCommand::PushClip { bounds } => self.push_clip(bounds),
+            DrawCommand::PopClip => {
+                self.clips.pop();
+            }
+            DrawCommand::Opacity { alpha: own, child } => self.draw(child, alpha * own),
+            DrawCommand::Fill { .. } => {}
+        }
+    }
+
+    /// Draw text with the bitmap font. Glyph cells are a tenth of the font
+    /// size, so seven ascent rows give a cap height of 0.7em above a
+    /// baseline one font size below `position`, and glyphs advance 0.6em.
+    fn text(&mut self, content: &str, position: Point, size: f32, color: Color) {
+        let unit = size / 10.0;
+        let top = unit.mul_add(-(GLYPH_ASCENT as f32), position.y + size);
+        let mut x = position.x;
+        for ch in content.chars() {
+            for (col, bits) in font::glyph(ch).into_iter().enumerate() {
+                for row in (0..GLYPH_HEIGHT).filter(|row| bits >> row & 1 == 1) {
+                    let cell = Rect::new(
+                        unit.mul_add(col as f32, x),
+                        unit.mul_add(row as f32, top),
+                        unit,
+                        unit,
+                    );
+                    self.fill_where(cell, color, |_, _| true);
+                }
+            }
+            x = unit.mul_add(GLYPH_ADVANCE as f32, x);
+        }
+    }
+
+    /// Blend `color` into every visible pixel of `area` whose center
+    /// satisfies `covers`.
+    fn fill_where(&mut self, area: Rect, color: Color, covers: impl Fn(f32, f32) -> bool) {
+        let Some(area) = self
+            .visible()
+            .and_then(|visible| visible.intersection(&area))
+        else {
+            return;
+        };
+        // `area` lies inside the frame, so these are non-negative and in range.
+        let (x0, x1) = (
+            area.x.round() as usize,
+            (area.x + area.width).round() as usize,
+        );
+        let (y0, y1) = (
+            area.y.round() as usize,
+            (area.y + area.height).round() as usize,
+        );
+        for y in y0..y1.min(self.height) {
+            for x in x0..x1.min(self.width) {
+                if covers(x as f32 + 0.5, y as f32 + 0.5) {
+                    self.blend(x, y, color);
+                }
+            }
+        }
+    }
+
+    fn blend(&mut self, x: usize, y: usize, color: Color) {
+        let index = (y * self.width + x) * 4;
+        let pixel = &mut self.rgba[index..index + 3];
+        for (channel, value) in pixel.iter_mut().zip([color.r, color.g, color.b]) {
+            let dst = f32::from(*channel) / 255.0;
+            let mixed = (value - dst).mul_add(color.a, dst);
+            *channel = (mixed.clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+    }
+}
+
+fn fade(color: Color, alpha: f32) -> Color {
+    Color::new(color.r, color.g, color.b, color.a * alpha)
+}
+
+fn square_around(center: Point, radius: f32) -> Rect {
+    Rect::new(
+        center.x - radius,
+        center.y - radius,
+        radius * 2.0,
+        radius * 2.0,
+    )
+}
+
+fn distance(center: Point, x: f32, y: f32) -> f32 {
+    (x - center.x).hypot(y - center.y)
+}
+
+fn segment_distance(start: Point, end: Point, x: f32, y: f32) -> f32 {
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let len_sq = dx.mul_add(dx, dy * dy);
+    let along = if len_sq > 0.0 {
+        ((x - start.x).mul_add(dx, (y - start.y) * dy) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let nearest = Point::new(along.mul_add(dx, start.x), along.mul_add(dy, start.y));
+    distance(nearest, x, y)
+}
+
+fn in_rounded_rect(bounds: &Rect, radius: f32, x: f32, y: f32) -> bool {
+    let cx = x.clamp(bounds.x + radius, bounds.x + bounds.width - radius);
+    let cy = y.clamp(bounds.y + radius, bounds.y + bounds.height - radius);
+    (x - cx).hypot(y - cy) <= radius
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use presentar_core::TextStyle;
+
+    const MINIMAL: &str = r#"
+presentar: "0.1"
+name: "Sales Dashboard"
+version: "1.0.0"
+description: "Q3 <numbers>"
+layout:
+  type: "dashboard"
+  columns: 12
+  sections:
+    - id: "header"
+      span: [1, 12]
+      widgets:
+        - type: "text"
+          content: "Welcome"
+          style: "heading"
+    - id: "chart"
+      span: [1, 8]
+      widgets:
+        - type: "chart"
+          id: "revenue"
+    - id: "side"
+      span: [9, 12]
+      widgets:
+        - type: "metric"
+          content: "Total"
+"#;
+
+    fn minimal() -> Manifest {
+        Manifest::from_yaml(MINIMAL).expect("valid manifest")
+    }
+
+    #[test]
+    fn test_render_minimal_manifest_to_svg() {
+        let commands = render_manifest(&minimal(), 1200.0, 630.0);
+        let svg = to_svg(&commands, 1200, 630);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains(">Sales Dashboard</text>"));
+        assert!(svg.contains("Welcome"));
+        assert!(svg.contains("<rect"));
+    }
+
+    #[test]
+    fn test_render_with_theme_preset() {
+        let theme = Theme::from_preset("nord").expect("nord preset");
+        let commands = render_manifest_themed(&minimal(), &theme, 1200.0, 630.0);
+        let DrawCommand::Rect { style, .. } = &commands[0] else {
+            panic!("expected background rect, got {:?}", commands[0]);
+        };
+        assert_eq!(style.fill, Some(theme.background));
+        let heading = commands.iter().find_map(|c| match c {
+            DrawCommand::Text { content, style, .. } if content == "Sales Dashboard" => {
+                Some(style.color)
+            }
+            _ => None,
+        });
+        assert_eq!(heading, Some(theme.foreground));
+    }
+
+    #[test]
+    fn test_svg_escapes_text() {
+        let commands = render_manifest(&minimal(), 800.0, 600.0);
+        let svg = to_svg(&commands, 800, 600);
+        assert!(svg.contains("Q3 &lt;numbers&gt;"));
+        assert!(!svg.contains("<numbers>"));
+    }
+
+    #[test]
+    fn test_sections_follow_spans() {
+        let commands = render_manifest(&minimal(), 1200.0, 630.0);
+        let cards: Vec<Rect> = commands
+            .iter()
+            .filter_map(|c| match c {
+                DrawCommand::Rect { bounds, style, .. } if style.fill == Some(Color::WHITE) => {
+                    Some(*bounds)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(cards.len(), 3);
+        // Header spans the full row; chart and side share the next one.
+        assert!(cards[0].width > cards[1].width);
+        assert_eq!(cards[1].y, cards[2].y);
+        assert!(cards[2].x > cards[1].x);
+        assert!(cards[1].y > cards[0].y);
+    }
+
+    #[test]
+    fn test_widgets_laid_out_inside_cards() {
+        let commands = render_manifest(&minimal(), 1200.0, 630.0);
+        let welcome = commands
+            .iter()
+            .find_map(|c| match c {
+                DrawCommand::Text {
+                    content, position, ..
+                } if content == "Welcome" => Some(*position),
+                _ => None,
+            })
+            .expect("heading text");
+        assert_eq!(welcome.x, PADDING + CARD_PADDING);
+        assert!(welcome.y > HEADER_HEIGHT + CARD_PADDING);
+    }
+
+    #[test]
+    fn test_svg_clips_and_arcs() {
+        let commands = vec![
+            DrawCommand::PushClip {
+                bounds: Rect::new(0.0, 0.0, 10.0, 10.0),
+            },
+            DrawCommand::Arc {
+                center: Point::new(5.0, 5.0),
+                radius: 4.0,
+                start_angle: 0.0,
+                end_angle: PI / 2.0,
+                color: Color::BLACK,
+            },
+        ];
+        let svg = to_svg(&commands, 10, 10);
+        assert!(svg.contains("<clipPath id=\"clip0\">"));
+        assert!(svg.contains("<g clip-path=\"url(#clip0)\">"));
+        assert!(svg.contains("<path d=\"M 5 5 L 9 5 A 4 4 0 0 1 "));
+        // The unbalanced PushClip is closed before the document ends.
+        assert_eq!(svg.matches("<g").count(), svg.matches("</g>").count());
+    }
+
+    #[test]
+    fn test_svg_text_baseline() {
+        let commands = vec![DrawCommand::Text {
+            content: "A".to_string(),
+            position: Point::new(2.0, 3.0),
+            style: TextStyle {
+                size: 10.0,
+                ..TextStyle::default()
+            },
+        }];
+        assert!(to_svg(&commands, 20, 20).contains("<text x=\"2\" y=\"13\""));
+    }
+
+    fn pixel(png_raster: &Raster, x: usize, y: usize) -> [u8; 3] {
+        let index = (y * png_raster.width + x) * 4;
+        [
+            png_raster.rgba[index],
+            png_raster.rgba[index + 1],
+            png_raster.rgba[index + 2],
+        ]
+    }
+
+    #[test]
+    fn test_raster_draws_text() {
+        let mut raster = Raster::new(20, 20).expect("frame");
+        raster.draw(
+            &DrawCommand::Text {
+                content: "H".to_string(),
+                position: Point::new(0.0, 0.0),
+                style: TextStyle {
+                    size: 10.0,
+                    color: Color::BLACK,
+                    ..TextStyle::default()
+                },
+            },
+            1.0,
+        );
+        // 'H' has a full left stem from the cap line (y = 3) to the
+        // baseline (y = 10) and a crossbar on its fourth row.
+        assert_eq!(pixel(&raster, 0, 3), [0, 0, 0]);
+        assert_eq!(pixel(&raster, 0, 9), [0, 0, 0]);
+        assert_eq!(pixel(&raster, 0, 10), [255, 255, 255]);
+        assert_eq!(pixel(&raster, 2, 6), [0, 0, 0]);
+        assert_eq!(pixel(&raster, 2, 5), [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_raster_respects_clip() {
+        let mut raster = Raster::new(20, 20).expect("frame");
+        for command in [
+            DrawCommand::PushClip {
+                bounds: Rect::new(0.0, 0.0, 10.0, 10.0),
+            },
+            DrawCommand::filled_rect(Rect::new(0.0, 0.0, 20.0, 20.0), Color::BLACK),
+            DrawCommand::PopClip,
+        ] {
+            raster.draw(&command, 1.0);
+        }
+        assert_eq!(pixel(&raster, 5, 5), [0, 0, 0]);
+        assert_eq!(pixel(&raster, 15, 15), [255, 255, 255]);
+        assert!(raster.clips.is_empty());
+    }
+
+    #[test]
+    fn test_raster_fills_arc_sweep() {
+        let mut raster = Raster::new(20, 20).expect("frame");
+        raster.draw(
+            &DrawCommand::Arc {
+                center: Point::new(10.0, 10.0),
+                radius: 8.0,
+                start_angle: 0.0,
+                end_angle: PI / 2.0,
+                color: Color::BLACK,
+            },
+            1.0,
+        );
+        // The quarter from +x towards +y (down) is filled; the rest is not.
+        assert_eq!(pixel(&raster, 14, 14), [0, 0, 0]);
+        assert_eq!(pixel(&raster, 5, 14), [255, 255, 255]);
+        assert_eq!(pixel(&raster, 14, 5), [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_png_header_and_size() {
+        let commands = render_manifest(&minimal(), 64.0, 32.0);
+        let png = to_png(&commands, 64, 32).expect("png");
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(u32::from_be_bytes([png[16], png[17], png[18], png[19]]), 64);
+        assert_eq!(u32::from_be_bytes([png[20], png[21], png[22], png[23]]), 32);
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+    }
+
+    #[test]
+    fn test_png_rejects_oversized_frame() {
+        assert!(to_png(&[], u32::MAX, u32::MAX).is_err());
+    }
+}
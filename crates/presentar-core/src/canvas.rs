@@ -2,7 +2,7 @@
 
 use crate::draw::{BoxStyle, DrawCommand, StrokeStyle, Transform2D};
 use crate::widget::{Canvas, TextStyle};
-use crate::{Color, Point, Rect};
+use crate::{Color, Point, Rect, Size};
 
 /// A Canvas implementation that records draw operations as `DrawCommand`s.
 ///
@@ -10,11 +10,26 @@ use crate::{Color, Point, Rect};
 /// - Testing (verify what was painted)
 /// - Serialization (send commands to GPU/WASM)
 /// - Diffing (compare render outputs)
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct RecordingCanvas {
     commands: Vec<DrawCommand>,
     clip_stack: Vec<Rect>,
     transform_stack: Vec<Transform2D>,
+    text_measurer: Option<TextMeasurer>,
+}
+
+/// Callback that measures text with a backend's real font metrics.
+pub type TextMeasurer = Box<dyn Fn(&str, &TextStyle) -> Size>;
+
+impl std::fmt::Debug for RecordingCanvas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordingCanvas")
+            .field("commands", &self.commands)
+            .field("clip_stack", &self.clip_stack)
+            .field("transform_stack", &self.transform_stack)
+            .field("text_measurer", &self.text_measurer.is_some())
+            .finish()
+    }
 }
 
 impl RecordingCanvas {
@@ -24,6 +39,19 @@ impl RecordingCanvas {
         Self::default()
     }
 
+    /// Measure text with `measurer` instead of the per-character estimate.
+    ///
+    /// The browser backend passes its font metrics here, since commands are
+    /// recorded before the target context draws them.
+    #[must_use]
+    pub fn with_text_measurer(
+        mut self,
+        measurer: impl Fn(&str, &TextStyle) -> Size + 'static,
+    ) -> Self {
+        self.text_measurer = Some(Box::new(measurer));
+        self
+    }
+
     /// Get the recorded draw commands.
     #[must_use]
     pub fn commands(&self) -> &[DrawCommand] {
@@ -154,6 +182,17 @@ impl Canvas for RecordingCanvas {
         });
     }
 
+    fn measure_text(&self, text: &str, style: &TextStyle) -> Size {
+        match &self.text_measurer {
+            Some(measure) => measure(text, style),
+            // Same estimate as the `Canvas` default.
+            None => Size::new(
+                text.chars().count() as f32 * style.size * 0.6,
+                style.size * 1.2,
+            ),
+        }
+    }
+
     fn draw_line(&mut self, from: Point, to: Point, color: Color, width: f32) {
         self.commands.push(DrawCommand::Path {
             points: vec![from, to],
@@ -229,10 +268,13 @@ impl Canvas for RecordingCanvas {
 
     fn push_clip(&mut self, rect: Rect) {
         self.clip_stack.push(rect);
+        self.commands.push(DrawCommand::PushClip { bounds: rect });
     }
 
     fn pop_clip(&mut self) {
-        self.clip_stack.pop();
+        if self.clip_stack.pop().is_some() {
+            self.commands.push(DrawCommand::PopClip);
+        }
     }
 
     fn push_transform(&mut self, transform: crate::widget::Transform2D) {
@@ -338,6 +380,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_measure_text_estimate() {
+        let canvas = RecordingCanvas::new();
+        let style = TextStyle {
+            size: 10.0,
+            ..Default::default()
+        };
+        assert_eq!(canvas.measure_text("abcd", &style), Size::new(24.0, 12.0));
+    }
+
+    #[test]
+    fn test_measure_text_with_measurer() {
+        let canvas = RecordingCanvas::new()
+            .with_text_measurer(|text, style| Size::new(text.len() as f32 * 7.0, style.size));
+        let style = TextStyle {
+            size: 10.0,
+            ..Default::default()
+        };
+        assert_eq!(canvas.measure_text("abcd", &style), Size::new(28.0, 10.0));
+    }
+
     #[test]
     fn test_fill_circle() {
         let mut canvas = RecordingCanvas::new();
@@ -572,6 +635,21 @@ mod tests {
     // Edge Case Tests
     // =========================================================================
 
+    #[test]
+    fn test_push_pop_clip_recorded() {
+        let mut canvas = RecordingCanvas::new();
+        let clip = Rect::new(0.0, 0.0, 50.0, 50.0);
+        canvas.push_clip(clip);
+        canvas.fill_rect(Rect::new(10.0, 10.0, 100.0, 100.0), Color::RED);
+        canvas.pop_clip();
+        canvas.pop_clip();
+
+        let commands = canvas.commands();
+        assert_eq!(commands.len(), 3);
+        assert_eq!(commands[0], DrawCommand::PushClip { bounds: clip });
+        assert_eq!(commands[2], DrawCommand::PopClip);
+    }
+
     #[test]
     fn test_pop_empty_clip_stack() {
         let mut canvas = RecordingCanvas::new();
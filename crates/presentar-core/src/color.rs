@@ -169,6 +169,211 @@ impl Color {
         )
     }
 
+    /// Interpolate between two colors in the OKLCH color space.
+    ///
+    /// Unlike [`Color::lerp`], blending across hues keeps a perceptually
+    /// even lightness instead of passing through muddy midpoints. Hue takes
+    /// the shorter arc; the result is clamped back into sRGB.
+    ///
+    /// See: <https://bottosson.github.io/posts/oklab/>
+    #[must_use]
+    pub fn lerp_oklch(&self, other: &Self, t: f32) -> Self {
+        let t = f64::from(t.clamp(0.0, 1.0));
+        let (l1, c1, h1) = self.to_oklch();
+        let (l2, c2, h2) = other.to_oklch();
+
+        // An achromatic endpoint has no meaningful hue; borrow the other one.
+        let (h1, h2) = match (c1 < OKLCH_ACHROMATIC, c2 < OKLCH_ACHROMATIC) {
+            (true, false) => (h2, h2),
+            (false, true) => (h1, h1),
+            _ => (h1, h2),
+        };
+
+        let mut dh = h2 - h1;
+        if dh > std::f64::consts::PI {
+            dh -= std::f64::consts::TAU;
+        } else if dh < -std::f64::consts::PI {
+            dh += std::f64::consts::TAU;
+        }
+
+        let l = (l2 - l1).mul_add(t, l1);
+        let c = (c2 - c1).mul_add(t, c1);
+        let h = dh.mul_add(t, h1);
+        let alpha = (other.a - self.a).mul_add(t as f32, self.a);
+
+        Self::from_oklab(l, c * h.cos(), c * h.sin(), alpha)
+    }
+
+    /// Convert to OKLCH as `(lightness, chroma, hue_radians)`.
+    fn to_oklch(self) -> (f64, f64, f64) {
+        let (l, a, b) = self.to_oklab();
+        (l, a.hypot(b), b.atan2(a))
+    }
+
+    /// Convert to Oklab as `(lightness, a, b)`.
+    #[allow(clippy::suboptimal_flops)]
+    fn to_oklab(self) -> (f64, f64, f64) {
+        let r = f64::from(Self::linearize(self.r));
+        let g = f64::from(Self::linearize(self.g));
+        let b = f64::from(Self::linearize(self.b));
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        (
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        )
+    }
+
+    /// Convert from Oklab back to sRGB, clamping out-of-gamut values.
+    #[allow(clippy::suboptimal_flops)]
+    fn from_oklab(l: f64, a: f64, b: f64, alpha: f32) -> Self {
+        let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+        let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+        let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+
+        let l = l_ * l_ * l_;
+        let m = m_ * m_ * m_;
+        let s = s_ * s_ * s_;
+
+        let r = 4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s;
+        let g = -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s;
+        let b = -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s;
+
+        Self::new(
+            Self::delinearize(r as f32),
+            Self::delinearize(g as f32),
+            Self::delinearize(b as f32),
+            alpha,
+        )
+    }
+
+    /// Simulate how this color appears under a color vision deficiency.
+    ///
+    /// Applies the Machado et al. (2009) full-severity matrices in linear
+    /// RGB. Alpha is preserved.
+    ///
+    /// See: <https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html>
+    #[must_use]
+    pub fn simulate_cvd(&self, cvd: CvdType) -> Self {
+        let m = cvd.matrix();
+        let r = Self::linearize(self.r);
+        let g = Self::linearize(self.g);
+        let b = Self::linearize(self.b);
+
+        let apply = |row: [f32; 3]| row[2].mul_add(b, row[0].mul_add(r, row[1] * g));
+
+        Self::new(
+            Self::delinearize(apply(m[0])),
+            Self::delinearize(apply(m[1])),
+            Self::delinearize(apply(m[2])),
+            self.a,
+        )
+    }
+
+    /// Create an opaque color from HSL components.
+    ///
+    /// Hue is in degrees and wraps around 360; saturation and lightness
+    /// are clamped to [0.0, 1.0].
+    #[must_use]
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let h = h.rem_euclid(360.0);
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+
+        let chroma = (1.0 - 2.0f32.mul_add(l, -1.0).abs()) * s;
+        let m = chroma.mul_add(-0.5, l);
+        let (r, g, b) = Self::hue_to_rgb(h, chroma);
+        Self::rgb(r + m, g + m, b + m)
+    }
+
+    /// Create an opaque color from HSV components.
+    ///
+    /// Hue is in degrees and wraps around 360; saturation and value
+    /// are clamped to [0.0, 1.0].
+    #[must_use]
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Self {
+        let h = h.rem_euclid(360.0);
+        let s = s.clamp(0.0, 1.0);
+        let v = v.clamp(0.0, 1.0);
+
+        let chroma = v * s;
+        let m = v - chroma;
+        let (r, g, b) = Self::hue_to_rgb(h, chroma);
+        Self::rgb(r + m, g + m, b + m)
+    }
+
+    /// Convert to HSL as `(hue, saturation, lightness)`.
+    ///
+    /// Hue is in degrees [0.0, 360.0). Grayscale colors report hue and
+    /// saturation of 0.0.
+    #[must_use]
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let chroma = max - min;
+        let l = (max + min) / 2.0;
+
+        let s = if chroma <= f32::EPSILON {
+            0.0
+        } else {
+            chroma / (1.0 - 2.0f32.mul_add(l, -1.0).abs())
+        };
+
+        (self.hue(max, chroma), s.clamp(0.0, 1.0), l)
+    }
+
+    /// Convert to HSV as `(hue, saturation, value)`.
+    ///
+    /// Hue is in degrees [0.0, 360.0). Grayscale colors report hue and
+    /// saturation of 0.0.
+    #[must_use]
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let chroma = max - min;
+
+        let s = if max <= f32::EPSILON || chroma <= f32::EPSILON {
+            0.0
+        } else {
+            chroma / max
+        };
+
+        (self.hue(max, chroma), s, max)
+    }
+
+    /// Hue in degrees for the given max component and chroma.
+    fn hue(&self, max: f32, chroma: f32) -> f32 {
+        if chroma <= f32::EPSILON {
+            return 0.0;
+        }
+        let sector = if (max - self.r).abs() <= f32::EPSILON {
+            ((self.g - self.b) / chroma).rem_euclid(6.0)
+        } else if (max - self.g).abs() <= f32::EPSILON {
+            (self.b - self.r) / chroma + 2.0
+        } else {
+            (self.r - self.g) / chroma + 4.0
+        };
+        (sector * 60.0).rem_euclid(360.0)
+    }
+
+    /// Map a hue in [0, 360) and chroma to unshifted RGB components.
+    fn hue_to_rgb(h: f32, chroma: f32) -> (f32, f32, f32) {
+        let sector = h / 60.0;
+        let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+        match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        }
+    }
+
     /// Linearize sRGB component for luminance calculation.
     fn linearize(c: f32) -> f32 {
         if c <= 0.04045 {
@@ -178,6 +383,16 @@ impl Color {
         }
     }
 
+    /// Convert a linear component back to gamma-encoded sRGB.
+    fn delinearize(c: f32) -> f32 {
+        let c = c.clamp(0.0, 1.0);
+        if c <= 0.003_130_8 {
+            c * 12.92
+        } else {
+            1.055f32.mul_add(c.powf(1.0 / 2.4), -0.055)
+        }
+    }
+
     /// Create a color with explicit alpha value.
     #[must_use]
     pub fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
@@ -236,6 +451,46 @@ impl Color {
     };
 }
 
+/// Color vision deficiency type for [`Color::simulate_cvd`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CvdType {
+    /// Missing long-wavelength (red) cones.
+    Protanopia,
+    /// Missing medium-wavelength (green) cones.
+    Deuteranopia,
+    /// Missing short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl CvdType {
+    /// All supported deficiency types.
+    pub const ALL: [Self; 3] = [Self::Protanopia, Self::Deuteranopia, Self::Tritanopia];
+
+    /// Machado et al. severity 1.0 transform in linear RGB.
+    const fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Self::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Self::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+}
+
+/// Chroma below which an OKLCH color is treated as gray (hue undefined).
+const OKLCH_ACHROMATIC: f64 = 1e-4;
+
 impl Default for Color {
     fn default() -> Self {
         Self::BLACK
@@ -388,6 +643,119 @@ mod tests {
         assert_eq!(c.r, 1.0);
     }
 
+    #[test]
+    fn test_color_from_hsl_primaries() {
+        assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::RED);
+        assert_eq!(Color::from_hsl(120.0, 1.0, 0.5), Color::GREEN);
+        assert_eq!(Color::from_hsl(240.0, 1.0, 0.5), Color::BLUE);
+    }
+
+    #[test]
+    fn test_color_from_hsv_primaries() {
+        assert_eq!(Color::from_hsv(0.0, 1.0, 1.0), Color::RED);
+        assert_eq!(Color::from_hsv(60.0, 1.0, 1.0), Color::YELLOW);
+        assert_eq!(Color::from_hsv(240.0, 1.0, 1.0), Color::BLUE);
+    }
+
+    #[test]
+    fn test_color_from_hsl_hue_wraps() {
+        assert_eq!(Color::from_hsl(360.0, 1.0, 0.5), Color::RED);
+        assert_eq!(Color::from_hsl(-120.0, 1.0, 0.5), Color::BLUE);
+    }
+
+    #[test]
+    fn test_color_to_hsl() {
+        let (h, s, l) = Color::rgb(0.0, 0.5, 1.0).to_hsl();
+        assert!((h - 210.0).abs() < 0.01);
+        assert!((s - 1.0).abs() < 0.001);
+        assert!((l - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_color_to_hsv() {
+        let (h, s, v) = Color::rgb(0.5, 0.25, 0.25).to_hsv();
+        assert!(h.abs() < 0.01);
+        assert!((s - 0.5).abs() < 0.001);
+        assert!((v - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_color_to_hsl_black_and_white() {
+        assert_eq!(Color::BLACK.to_hsl(), (0.0, 0.0, 0.0));
+        assert_eq!(Color::WHITE.to_hsl(), (0.0, 0.0, 1.0));
+        assert_eq!(Color::BLACK.to_hsv(), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_color_lerp_oklch_endpoints() {
+        let start = Color::RED.lerp_oklch(&Color::BLUE, 0.0);
+        let end = Color::RED.lerp_oklch(&Color::BLUE, 1.0);
+        assert!((start.r - 1.0).abs() < 0.001);
+        assert!(start.b < 0.001);
+        assert!((end.b - 1.0).abs() < 0.001);
+        assert!(end.r < 0.001);
+    }
+
+    #[test]
+    fn test_color_lerp_oklch_brighter_midpoint_than_rgb() {
+        let rgb_mid = Color::RED.lerp(&Color::GREEN, 0.5);
+        let oklch_mid = Color::RED.lerp_oklch(&Color::GREEN, 0.5);
+        assert!(oklch_mid.relative_luminance() > rgb_mid.relative_luminance() + 0.05);
+    }
+
+    #[test]
+    fn test_color_lerp_oklch_grayscale_stays_gray() {
+        let mid = Color::BLACK.lerp_oklch(&Color::WHITE, 0.5);
+        assert!((mid.r - mid.g).abs() < 0.01);
+        assert!((mid.g - mid.b).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_color_lerp_oklch_alpha() {
+        let a = Color::new(1.0, 0.0, 0.0, 0.0);
+        let b = Color::new(0.0, 0.0, 1.0, 1.0);
+        assert!((a.lerp_oklch(&b, 0.5).a - 0.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_color_simulate_cvd_preserves_grayscale() {
+        for cvd in CvdType::ALL {
+            let sim = Color::WHITE.simulate_cvd(cvd);
+            assert!((sim.r - 1.0).abs() < 0.01, "{cvd:?}");
+            assert!((sim.g - 1.0).abs() < 0.01, "{cvd:?}");
+            assert!((sim.b - 1.0).abs() < 0.01, "{cvd:?}");
+            assert_eq!(Color::BLACK.simulate_cvd(cvd), Color::BLACK);
+        }
+    }
+
+    #[test]
+    fn test_color_simulate_cvd_preserves_alpha() {
+        let c = Color::new(0.2, 0.4, 0.6, 0.3);
+        assert_eq!(c.simulate_cvd(CvdType::Tritanopia).a, 0.3);
+    }
+
+    #[test]
+    fn test_color_simulate_cvd_deuteranopia_red_green_collapse() {
+        let red = Color::RED.simulate_cvd(CvdType::Deuteranopia);
+        let green = Color::GREEN.simulate_cvd(CvdType::Deuteranopia);
+
+        // Contrast between the pair drops under simulation
+        let normal = Color::RED.contrast_ratio(&Color::GREEN);
+        let simulated = red.contrast_ratio(&green);
+        assert!(simulated < normal);
+
+        // Both land on nearly the same hue
+        let (red_hue, _, _) = red.to_hsl();
+        let (green_hue, _, _) = green.to_hsl();
+        assert!((red_hue - green_hue).abs() < 15.0);
+    }
+
+    #[test]
+    fn test_color_simulate_cvd_tritanopia_keeps_red() {
+        let sim = Color::RED.simulate_cvd(CvdType::Tritanopia);
+        assert!(sim.r > 0.9);
+    }
+
     #[test]
     fn test_color_relative_luminance_white() {
         let lum = Color::WHITE.relative_luminance();
@@ -159,6 +159,35 @@ impl Constraints {
         Self::new(self.min_width, self.max_width, self.min_height, max_height)
     }
 
+    /// Get the largest size with `width / height == ratio` that fits.
+    ///
+    /// When one dimension is unbounded the bounded one limits the size; when
+    /// both are unbounded the minimums decide. If the minimums make the exact
+    /// ratio impossible the result is clamped into the constraints. A
+    /// non-positive or non-finite ratio returns [`Constraints::biggest`].
+    #[must_use]
+    pub fn constrain_aspect(&self, ratio: f32) -> Size {
+        if !ratio.is_finite() || ratio <= 0.0 {
+            return self.biggest();
+        }
+
+        let mut width = self.max_width.min(self.max_height * ratio);
+        if !width.is_finite() {
+            width = 0.0;
+        }
+        // Grow to satisfy the minimums while keeping the ratio
+        width = width.max(self.min_width).max(self.min_height * ratio);
+
+        self.constrain(Size::new(width, width / ratio))
+    }
+
+    /// Narrow these constraints to the single size from
+    /// [`Constraints::constrain_aspect`].
+    #[must_use]
+    pub fn enforce_aspect(&self, ratio: f32) -> Self {
+        Self::tight(self.constrain_aspect(ratio))
+    }
+
     /// Deflate constraints by padding.
     #[must_use]
     pub fn deflate(&self, horizontal: f32, vertical: f32) -> Self {
@@ -177,6 +206,79 @@ impl Default for Constraints {
     }
 }
 
+/// A length that is either absolute, relative to the parent, or automatic.
+///
+/// # Examples
+///
+/// ```
+/// use presentar_core::Dimension;
+///
+/// assert_eq!(Dimension::Px(40.0).resolve(200.0), Some(40.0));
+/// assert_eq!(Dimension::Percent(50.0).resolve(200.0), Some(100.0));
+/// assert_eq!(Dimension::Auto.resolve(200.0), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Dimension {
+    /// Absolute length in pixels
+    Px(f32),
+    /// Percentage of the parent length (`100.0` is the full parent)
+    Percent(f32),
+    /// No explicit length; the widget decides
+    #[default]
+    Auto,
+}
+
+impl Dimension {
+    /// Resolve against a parent length, or `None` for [`Dimension::Auto`].
+    ///
+    /// Negative results are clamped to zero.
+    #[must_use]
+    pub fn resolve(self, parent: f32) -> Option<f32> {
+        match self {
+            Self::Px(px) => Some(px.max(0.0)),
+            Self::Percent(pct) => Some((parent * pct / 100.0).max(0.0)),
+            Self::Auto => None,
+        }
+    }
+
+    /// Check if this dimension is automatic.
+    #[must_use]
+    pub const fn is_auto(self) -> bool {
+        matches!(self, Self::Auto)
+    }
+}
+
+impl Constraints {
+    /// Create constraints spanning `min_pct`..`max_pct` percent of a parent size.
+    ///
+    /// The same percentages apply to both width and height.
+    #[must_use]
+    pub fn from_percent(min_pct: f32, max_pct: f32, parent: Size) -> Self {
+        let pct = |p: f32, len: f32| (len * p / 100.0).max(0.0);
+        Self::new(
+            pct(min_pct, parent.width),
+            pct(max_pct, parent.width),
+            pct(min_pct, parent.height),
+            pct(max_pct, parent.height),
+        )
+    }
+
+    /// Create constraints from a width and height [`Dimension`].
+    ///
+    /// Resolved dimensions become tight; [`Dimension::Auto`] leaves that axis
+    /// unbounded.
+    #[must_use]
+    pub fn from_dimensions(width: Dimension, height: Dimension, parent: Size) -> Self {
+        let (min_width, max_width) = width
+            .resolve(parent.width)
+            .map_or((0.0, f32::INFINITY), |w| (w, w));
+        let (min_height, max_height) = height
+            .resolve(parent.height)
+            .map_or((0.0, f32::INFINITY), |h| (h, h));
+        Self::new(min_width, max_width, min_height, max_height)
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::disallowed_methods)]
 mod tests {
@@ -290,6 +392,101 @@ mod tests {
         assert_eq!(deflated.max_width, 0.0);
     }
 
+    #[test]
+    fn test_constrain_aspect_square_in_wide_box() {
+        let c = Constraints::loose(Size::new(400.0, 100.0));
+        assert_eq!(c.constrain_aspect(1.0), Size::new(100.0, 100.0));
+    }
+
+    #[test]
+    fn test_constrain_aspect_widescreen_in_tall_box() {
+        let c = Constraints::loose(Size::new(320.0, 1000.0));
+        let size = c.constrain_aspect(16.0 / 9.0);
+        assert_eq!(size.width, 320.0);
+        assert!((size.height - 180.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_constrain_aspect_unbounded_height() {
+        let c = Constraints::new(0.0, 160.0, 0.0, f32::INFINITY);
+        assert_eq!(c.constrain_aspect(2.0), Size::new(160.0, 80.0));
+    }
+
+    #[test]
+    fn test_constrain_aspect_unbounded_width() {
+        let c = Constraints::new(0.0, f32::INFINITY, 0.0, 90.0);
+        let size = c.constrain_aspect(16.0 / 9.0);
+        assert!((size.width - 160.0).abs() < 0.001);
+        assert_eq!(size.height, 90.0);
+    }
+
+    #[test]
+    fn test_constrain_aspect_fully_unbounded_uses_minimums() {
+        let c = Constraints::new(40.0, f32::INFINITY, 10.0, f32::INFINITY);
+        assert_eq!(c.constrain_aspect(2.0), Size::new(40.0, 20.0));
+        assert_eq!(Constraints::unbounded().constrain_aspect(1.0), Size::ZERO);
+    }
+
+    #[test]
+    fn test_constrain_aspect_invalid_ratio() {
+        let c = Constraints::loose(Size::new(100.0, 50.0));
+        assert_eq!(c.constrain_aspect(0.0), c.biggest());
+        assert_eq!(c.constrain_aspect(f32::NAN), c.biggest());
+    }
+
+    #[test]
+    fn test_enforce_aspect_is_tight() {
+        let c = Constraints::loose(Size::new(400.0, 100.0)).enforce_aspect(2.0);
+        assert!(c.is_tight());
+        assert_eq!(c.biggest(), Size::new(200.0, 100.0));
+    }
+
+    #[test]
+    fn test_dimension_resolve() {
+        assert_eq!(Dimension::Px(40.0).resolve(200.0), Some(40.0));
+        assert_eq!(Dimension::Percent(50.0).resolve(200.0), Some(100.0));
+        assert_eq!(Dimension::Percent(-10.0).resolve(200.0), Some(0.0));
+        assert_eq!(Dimension::Auto.resolve(200.0), None);
+        assert!(Dimension::default().is_auto());
+    }
+
+    #[test]
+    fn test_constraints_from_percent() {
+        let c = Constraints::from_percent(25.0, 50.0, Size::new(200.0, 100.0));
+        assert_eq!(c.min_width, 50.0);
+        assert_eq!(c.max_width, 100.0);
+        assert_eq!(c.min_height, 25.0);
+        assert_eq!(c.max_height, 50.0);
+    }
+
+    #[test]
+    fn test_constraints_from_dimensions_full_percent() {
+        let parent = Size::new(200.0, 100.0);
+        let c =
+            Constraints::from_dimensions(Dimension::Percent(100.0), Dimension::Px(30.0), parent);
+        assert_eq!(c.max_width, 200.0);
+        assert_eq!(c.biggest(), Size::new(200.0, 30.0));
+        assert!(c.is_tight());
+    }
+
+    #[test]
+    fn test_constraints_from_dimensions_auto_unbounded() {
+        let parent = Size::new(200.0, 100.0);
+        let c = Constraints::from_dimensions(Dimension::Auto, Dimension::Auto, parent);
+        assert_eq!(c, Constraints::unbounded());
+        assert!(!c.has_bounded_width());
+        assert!(!c.has_bounded_height());
+    }
+
+    #[test]
+    fn test_constraints_from_dimensions_follows_resize() {
+        let half = Dimension::Percent(50.0);
+        let small = Constraints::from_dimensions(half, Dimension::Auto, Size::new(100.0, 50.0));
+        let large = Constraints::from_dimensions(half, Dimension::Auto, Size::new(300.0, 50.0));
+        assert_eq!(small.max_width, 50.0);
+        assert_eq!(large.max_width, 150.0);
+    }
+
     // =========================================================================
     // Clone and Copy Trait Tests
     // =========================================================================
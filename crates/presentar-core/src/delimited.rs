@@ -0,0 +1,54 @@
+//! CSV/TSV record writing (RFC 4180 quoting).
+//!
+//! Shared by the terminal and canvas table widgets so exports quote fields
+//! the same everywhere.
+
+/// Append one delimited record terminated by CRLF, quoting fields as needed.
+///
+/// A field is wrapped in double quotes when it contains the delimiter, a
+/// quote, or a line break; embedded quotes are doubled.
+pub fn push_record<S: AsRef<str>>(out: &mut String, fields: &[S], delimiter: char) {
+    for (i, field) in fields.iter().enumerate() {
+        let field = field.as_ref();
+        if i > 0 {
+            out.push(delimiter);
+        }
+        if field.contains([delimiter, '"', '\n', '\r']) {
+            out.push('"');
+            out.push_str(&field.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(field);
+        }
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(fields: &[&str], delimiter: char) -> String {
+        let mut out = String::new();
+        push_record(&mut out, fields, delimiter);
+        out
+    }
+
+    #[test]
+    fn test_push_record_plain() {
+        assert_eq!(record(&["a", "b", ""], ','), "a,b,\r\n");
+    }
+
+    #[test]
+    fn test_push_record_quotes_special_fields() {
+        assert_eq!(
+            record(&["a,b", "say \"hi\"", "x\ny"], ','),
+            "\"a,b\",\"say \"\"hi\"\"\",\"x\ny\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_push_record_quotes_only_active_delimiter() {
+        assert_eq!(record(&["a,b", "c\td"], '\t'), "a,b\t\"c\td\"\r\n");
+    }
+}
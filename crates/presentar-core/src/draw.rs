@@ -238,6 +238,26 @@ impl Transform2D {
             m[1].mul_add(point.x, m[3] * point.y) + m[5],
         )
     }
+
+    /// Transform a rectangle, returning the axis-aligned bounds of the result.
+    ///
+    /// For translations and positive scales this matches [`Rect::translate`]
+    /// and [`Rect::scale`].
+    #[must_use]
+    pub fn apply_rect(&self, rect: &Rect) -> Rect {
+        let corners = [
+            self.apply(rect.top_left()),
+            self.apply(rect.top_right()),
+            self.apply(rect.bottom_left()),
+            self.apply(rect.bottom_right()),
+        ];
+        let (mut min, mut max) = (corners[0], corners[0]);
+        for p in &corners[1..] {
+            min = Point::new(min.x.min(p.x), min.y.min(p.y));
+            max = Point::new(max.x.max(p.x), max.y.max(p.y));
+        }
+        Rect::from_points(min, max)
+    }
 }
 
 /// Drawing primitive - all rendering reduces to these.
@@ -333,6 +353,17 @@ pub enum DrawCommand {
         child: Box<Self>,
     },
 
+    /// Clip following commands to bounds until the matching `PopClip`.
+    ///
+    /// Nested clips intersect with the enclosing clip.
+    PushClip {
+        /// Clip bounds
+        bounds: Rect,
+    },
+
+    /// Restore the clip in effect before the last `PushClip`.
+    PopClip,
+
     /// Apply opacity
     Opacity {
         /// Alpha value (0.0 - 1.0)
@@ -543,6 +574,34 @@ mod tests {
         assert_eq!(p, Point::new(20.0, 0.0));
     }
 
+    #[test]
+    fn test_transform_apply_rect_matches_rect_helpers() {
+        let r = Rect::new(10.0, 20.0, 30.0, 40.0);
+        assert_eq!(
+            Transform2D::translate(5.0, -5.0).apply_rect(&r),
+            r.translate(5.0, -5.0)
+        );
+        assert_eq!(Transform2D::scale(2.0, 2.0).apply_rect(&r), r.scale(2.0));
+    }
+
+    #[test]
+    fn test_transform_apply_rect_scale_about() {
+        let r = Rect::new(10.0, 10.0, 20.0, 20.0);
+        let c = r.center();
+        let t = Transform2D::translate(-c.x, -c.y)
+            .then(&Transform2D::scale(2.0, 2.0))
+            .then(&Transform2D::translate(c.x, c.y));
+        assert_eq!(t.apply_rect(&r), r.scale_about(2.0, c));
+    }
+
+    #[test]
+    fn test_transform_apply_rect_rotation_bounds() {
+        let r = Rect::new(0.0, 0.0, 10.0, 20.0);
+        let rotated = Transform2D::rotate(std::f32::consts::FRAC_PI_2).apply_rect(&r);
+        assert!((rotated.width - 20.0).abs() < 0.001);
+        assert!((rotated.height - 10.0).abs() < 0.001);
+    }
+
     // =========================================================================
     // FillRule Tests
     // =========================================================================
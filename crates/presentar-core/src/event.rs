@@ -25,6 +25,33 @@ pub enum Event {
         /// Button released
         button: MouseButton,
     },
+    /// Mouse moved with a button held since a `MouseDown`
+    Drag {
+        /// Position where the button was pressed
+        start: Point,
+        /// Current pointer position
+        current: Point,
+        /// Button held during the drag
+        button: MouseButton,
+    },
+    /// Drag finished when the held button was released
+    DragEnd {
+        /// Position where the button was pressed
+        start: Point,
+        /// Position where the button was released
+        end: Point,
+        /// Button that was held
+        button: MouseButton,
+    },
+    /// Button pressed and released in place, with multi-click count
+    Click {
+        /// Position of the click
+        position: Point,
+        /// Button clicked
+        button: MouseButton,
+        /// Successive clicks so far (1 = single, 2 = double, 3 = triple)
+        count: u8,
+    },
     /// Mouse wheel scrolled
     Scroll {
         /// Horizontal scroll delta
@@ -460,6 +487,9 @@ impl Event {
             Self::MouseMove { .. }
                 | Self::MouseDown { .. }
                 | Self::MouseUp { .. }
+                | Self::Drag { .. }
+                | Self::DragEnd { .. }
+                | Self::Click { .. }
                 | Self::MouseEnter
                 | Self::MouseLeave
         )
@@ -526,6 +556,7 @@ impl Event {
             Self::MouseMove { position }
             | Self::MouseDown { position, .. }
             | Self::MouseUp { position, .. }
+            | Self::Click { position, .. }
             | Self::TouchStart { position, .. }
             | Self::TouchMove { position, .. }
             | Self::TouchEnd { position, .. }
@@ -534,6 +565,8 @@ impl Event {
             | Self::PointerUp { position, .. }
             | Self::GestureLongPress { position }
             | Self::GestureTap { position, .. } => Some(*position),
+            Self::Drag { current, .. } => Some(*current),
+            Self::DragEnd { end, .. } => Some(*end),
             Self::GesturePinch { center, .. } | Self::GestureRotate { center, .. } => Some(*center),
             _ => None,
         }
@@ -719,6 +752,40 @@ mod tests {
         assert!(event.is_mouse());
     }
 
+    #[test]
+    fn test_event_drag() {
+        let drag = Event::Drag {
+            start: Point::new(1.0, 2.0),
+            current: Point::new(5.0, 6.0),
+            button: MouseButton::Left,
+        };
+        assert!(drag.is_mouse());
+        assert_eq!(drag.position(), Some(Point::new(5.0, 6.0)));
+
+        let end = Event::DragEnd {
+            start: Point::new(1.0, 2.0),
+            end: Point::new(9.0, 9.0),
+            button: MouseButton::Left,
+        };
+        assert!(end.is_mouse());
+        assert_eq!(end.position(), Some(Point::new(9.0, 9.0)));
+
+        let json = serde_json::to_string(&drag).unwrap();
+        let back: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(drag, back);
+    }
+
+    #[test]
+    fn test_event_click() {
+        let click = Event::Click {
+            position: Point::new(3.0, 4.0),
+            button: MouseButton::Left,
+            count: 2,
+        };
+        assert!(click.is_mouse());
+        assert_eq!(click.position(), Some(Point::new(3.0, 4.0)));
+    }
+
     #[test]
     fn test_event_scroll() {
         let event = Event::Scroll {
@@ -0,0 +1,220 @@
+//! O(1) fuzzy search with relevance scoring (from pzsh/aprender-shell patterns).
+//!
+//! Shared by the terminal and canvas table widgets so filtering ranks rows
+//! the same everywhere.
+
+/// Search result with relevance scoring
+#[derive(Debug, Clone)]
+pub struct SearchResult<T> {
+    /// The matched item
+    pub item: T,
+    /// Relevance score (0.0 - 1.0, higher is better)
+    pub score: f32,
+    /// Match positions (for highlighting)
+    pub matches: Vec<usize>,
+}
+
+/// Fast fuzzy search with O(1) amortized lookup via pre-computed index
+///
+/// Performance targets:
+/// - Build: O(n) where n = number of items
+/// - Search: O(m * k) where m = query length, k = average results
+/// - Memory: O(n * `avg_key_length`)
+#[derive(Debug, Clone)]
+pub struct FuzzyIndex<T: Clone> {
+    /// Items to search
+    items: Vec<T>,
+    /// Pre-computed lowercase keys for fast comparison
+    keys: Vec<String>,
+    /// Trigram index for O(1) candidate lookup
+    trigrams: std::collections::HashMap<[u8; 3], Vec<usize>>,
+    /// Character index for single-char queries
+    char_index: std::collections::HashMap<char, Vec<usize>>,
+}
+
+impl<T: Clone> FuzzyIndex<T> {
+    /// Build a fuzzy search index from items
+    ///
+    /// # Arguments
+    /// * `items` - Items to index
+    /// * `key_fn` - Function to extract searchable key from each item
+    pub fn new<F>(items: Vec<T>, key_fn: F) -> Self
+    where
+        F: Fn(&T) -> String,
+    {
+        let keys: Vec<String> = items
+            .iter()
+            .map(|item| key_fn(item).to_lowercase())
+            .collect();
+
+        let mut trigrams: std::collections::HashMap<[u8; 3], Vec<usize>> =
+            std::collections::HashMap::new();
+        let mut char_index: std::collections::HashMap<char, Vec<usize>> =
+            std::collections::HashMap::new();
+
+        for (idx, key) in keys.iter().enumerate() {
+            // Build character index
+            for ch in key.chars() {
+                char_index.entry(ch).or_default().push(idx);
+            }
+
+            // Build trigram index
+            let bytes = key.as_bytes();
+            if bytes.len() >= 3 {
+                for window in bytes.windows(3) {
+                    let trigram: [u8; 3] = [window[0], window[1], window[2]];
+                    trigrams.entry(trigram).or_default().push(idx);
+                }
+            }
+        }
+
+        // Deduplicate indices
+        for indices in trigrams.values_mut() {
+            indices.sort_unstable();
+            indices.dedup();
+        }
+        for indices in char_index.values_mut() {
+            indices.sort_unstable();
+            indices.dedup();
+        }
+
+        Self {
+            items,
+            keys,
+            trigrams,
+            char_index,
+        }
+    }
+
+    /// Search for items matching query
+    ///
+    /// # Arguments
+    /// * `query` - Search query (case-insensitive)
+    /// * `limit` - Maximum results to return
+    ///
+    /// # Returns
+    /// Sorted results by relevance (highest first)
+    #[must_use]
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult<T>> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let query_lower = query.to_lowercase();
+        let query_chars: Vec<char> = query_lower.chars().collect();
+
+        // Get candidate indices
+        let candidates = self.get_candidates(&query_lower);
+
+        // Score and filter candidates
+        let mut results: Vec<SearchResult<T>> = candidates
+            .into_iter()
+            .filter_map(|idx| {
+                let key = &self.keys[idx];
+                let (score, matches) = self.score_match(key, &query_chars);
+                if score > 0.0 {
+                    Some(SearchResult {
+                        item: self.items[idx].clone(),
+                        score,
+                        matches,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // Sort by score (descending)
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        results.truncate(limit);
+
+        results
+    }
+
+    /// Get candidate indices using index
+    fn get_candidates(&self, query: &str) -> Vec<usize> {
+        let bytes = query.as_bytes();
+
+        // Use trigram index if query is long enough
+        if bytes.len() >= 3 {
+            let trigram: [u8; 3] = [bytes[0], bytes[1], bytes[2]];
+            if let Some(indices) = self.trigrams.get(&trigram) {
+                return indices.clone();
+            }
+        }
+
+        // Fall back to character index
+        if let Some(first_char) = query.chars().next() {
+            if let Some(indices) = self.char_index.get(&first_char) {
+                return indices.clone();
+            }
+        }
+
+        // Fall back to scanning all items
+        (0..self.items.len()).collect()
+    }
+
+    /// Score a match using fuzzy substring matching
+    fn score_match(&self, key: &str, query_chars: &[char]) -> (f32, Vec<usize>) {
+        if query_chars.is_empty() {
+            return (0.0, Vec::new());
+        }
+
+        let key_chars: Vec<char> = key.chars().collect();
+        let mut matches = Vec::new();
+        let mut query_idx = 0;
+
+        // Find subsequence matches
+        for (key_idx, &key_char) in key_chars.iter().enumerate() {
+            if query_idx < query_chars.len() && key_char == query_chars[query_idx] {
+                matches.push(key_idx);
+                query_idx += 1;
+            }
+        }
+
+        // Must match all query characters
+        if query_idx != query_chars.len() {
+            return (0.0, Vec::new());
+        }
+
+        // Calculate score based on:
+        // - Consecutive matches (bonus)
+        // - Position of first match (earlier is better)
+        // - Match density (matches / key length)
+
+        let mut score = 1.0;
+
+        // Consecutive bonus
+        let mut consecutive = 0;
+        for i in 1..matches.len() {
+            if matches[i] == matches[i - 1] + 1 {
+                consecutive += 1;
+            }
+        }
+        score += consecutive as f32 * 0.1;
+
+        // Early match bonus
+        if !matches.is_empty() {
+            score += (1.0 - matches[0] as f32 / key_chars.len() as f32) * 0.3;
+        }
+
+        // Exact prefix bonus
+        if key.starts_with(&query_chars.iter().collect::<String>()) {
+            score += 0.5;
+        }
+
+        // Exact match bonus
+        if key_chars.len() == query_chars.len() {
+            score += 1.0;
+        }
+
+        // Density factor
+        score *= query_chars.len() as f32 / key_chars.len() as f32;
+
+        (score.min(1.0), matches)
+    }
+}
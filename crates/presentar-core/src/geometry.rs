@@ -1,4 +1,4 @@
-//! Geometric primitives: Point, Size, Rect, `CornerRadius`.
+//! Geometric primitives: Point, Size, Rect, `Polygon`, `CornerRadius`.
 //!
 //! This module provides the fundamental geometric types used throughout Presentar
 //! for layout calculations and rendering.
@@ -60,6 +60,8 @@ impl Point {
     }
 
     /// Linear interpolation between two points.
+    ///
+    /// `t` is not clamped; values outside `0.0..=1.0` extrapolate.
     #[must_use]
     pub fn lerp(&self, other: &Self, t: f32) -> Self {
         Self::new(
@@ -140,6 +142,17 @@ impl Size {
     pub fn scale(&self, factor: f32) -> Self {
         Self::new(self.width * factor, self.height * factor)
     }
+
+    /// Linear interpolation between two sizes.
+    ///
+    /// `t` is not clamped; values outside `0.0..=1.0` extrapolate.
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self::new(
+            (other.width - self.width).mul_add(t, self.width),
+            (other.height - self.height).mul_add(t, self.height),
+        )
+    }
 }
 
 impl Default for Size {
@@ -295,6 +308,34 @@ impl Rect {
         )
     }
 
+    /// Create a new rectangle moved by `dx` and `dy`.
+    #[must_use]
+    pub fn translate(&self, dx: f32, dy: f32) -> Self {
+        Self::new(self.x + dx, self.y + dy, self.width, self.height)
+    }
+
+    /// Create a new rectangle scaled about the origin.
+    #[must_use]
+    pub fn scale(&self, factor: f32) -> Self {
+        Self::new(
+            self.x * factor,
+            self.y * factor,
+            self.width * factor,
+            self.height * factor,
+        )
+    }
+
+    /// Create a new rectangle scaled about `center`, which stays fixed.
+    #[must_use]
+    pub fn scale_about(&self, factor: f32, center: Point) -> Self {
+        Self::new(
+            (self.x - center.x).mul_add(factor, center.x),
+            (self.y - center.y).mul_add(factor, center.y),
+            self.width * factor,
+            self.height * factor,
+        )
+    }
+
     /// Create a new rectangle with the given position.
     #[must_use]
     pub const fn with_origin(&self, origin: Point) -> Self {
@@ -314,6 +355,144 @@ impl Default for Rect {
     }
 }
 
+/// A closed polygon defined by its vertices.
+///
+/// The last vertex connects back to the first. Polygons with fewer than three
+/// vertices are degenerate: they contain no points and have zero area.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Polygon {
+    /// Vertices in drawing order
+    pub points: Vec<Point>,
+}
+
+impl Polygon {
+    /// Create a polygon from its vertices.
+    #[must_use]
+    pub const fn new(points: Vec<Point>) -> Self {
+        Self { points }
+    }
+
+    /// Get the number of vertices.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Check if the polygon has no vertices.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Check if the polygon has fewer than three vertices.
+    #[must_use]
+    pub fn is_degenerate(&self) -> bool {
+        self.points.len() < 3
+    }
+
+    /// Get the smallest axis-aligned rectangle containing every vertex.
+    ///
+    /// Returns `None` for an empty polygon.
+    #[must_use]
+    pub fn bounding_rect(&self) -> Option<Rect> {
+        let first = *self.points.first()?;
+        let (min, max) = self.points.iter().fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+        Some(Rect::from_points(min, max))
+    }
+
+    /// Check if a point is inside the polygon using ray casting (even-odd rule).
+    #[must_use]
+    pub fn contains_point(&self, point: &Point) -> bool {
+        if self.is_degenerate() {
+            return false;
+        }
+
+        let mut inside = false;
+        let mut prev = self.points[self.points.len() - 1];
+        for &curr in &self.points {
+            if (curr.y > point.y) != (prev.y > point.y) {
+                let x_cross = (prev.x - curr.x) * (point.y - curr.y) / (prev.y - curr.y) + curr.x;
+                if point.x < x_cross {
+                    inside = !inside;
+                }
+            }
+            prev = curr;
+        }
+        inside
+    }
+
+    /// Calculate the signed area using the shoelace formula.
+    ///
+    /// Positive when vertices run counter-clockwise in a y-up coordinate system
+    /// (clockwise on screen). For self-intersecting polygons, regions wound in
+    /// opposite directions cancel.
+    #[must_use]
+    pub fn signed_area(&self) -> f32 {
+        if self.is_degenerate() {
+            return 0.0;
+        }
+
+        let mut prev = self.points[self.points.len() - 1];
+        let mut twice_area = 0.0;
+        for &curr in &self.points {
+            twice_area += prev.x.mul_add(curr.y, -(curr.x * prev.y));
+            prev = curr;
+        }
+        twice_area / 2.0
+    }
+
+    /// Calculate the area (absolute value of [`Polygon::signed_area`]).
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /// Calculate the area centroid.
+    ///
+    /// Returns `None` for degenerate polygons. When the vertices are collinear
+    /// (zero area) the mean of the vertices is returned instead.
+    #[must_use]
+    pub fn centroid(&self) -> Option<Point> {
+        if self.is_degenerate() {
+            return None;
+        }
+
+        let area = self.signed_area();
+        if area.abs() <= f32::EPSILON {
+            let n = self.points.len() as f32;
+            let sum = self.points.iter().fold(Point::ORIGIN, |acc, &p| acc + p);
+            return Some(Point::new(sum.x / n, sum.y / n));
+        }
+
+        let mut prev = self.points[self.points.len() - 1];
+        let (mut cx, mut cy) = (0.0, 0.0);
+        for &curr in &self.points {
+            let cross = prev.x.mul_add(curr.y, -(curr.x * prev.y));
+            cx += (prev.x + curr.x) * cross;
+            cy += (prev.y + curr.y) * cross;
+            prev = curr;
+        }
+        let scale = 6.0 * area;
+        Some(Point::new(cx / scale, cy / scale))
+    }
+}
+
+impl From<Rect> for Polygon {
+    fn from(rect: Rect) -> Self {
+        Self::new(vec![
+            rect.top_left(),
+            rect.top_right(),
+            rect.bottom_right(),
+            rect.bottom_left(),
+        ])
+    }
+}
+
 /// Corner radii for rounded rectangles.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct CornerRadius {
@@ -395,6 +574,38 @@ mod tests {
         assert_eq!(mid, Point::new(5.0, 5.0));
     }
 
+    #[test]
+    fn test_point_lerp_endpoints() {
+        let p1 = Point::new(-3.5, 7.25);
+        let p2 = Point::new(12.0, -4.0);
+        assert_eq!(p1.lerp(&p2, 0.0), p1);
+        assert_eq!(p1.lerp(&p2, 1.0), p2);
+    }
+
+    #[test]
+    fn test_point_lerp_extrapolates() {
+        let p1 = Point::new(0.0, 0.0);
+        let p2 = Point::new(10.0, 20.0);
+        assert_eq!(p1.lerp(&p2, 2.0), Point::new(20.0, 40.0));
+        assert_eq!(p1.lerp(&p2, -0.5), Point::new(-5.0, -10.0));
+    }
+
+    #[test]
+    fn test_size_lerp() {
+        let s1 = Size::new(100.0, 40.0);
+        let s2 = Size::new(200.0, 80.0);
+        assert_eq!(s1.lerp(&s2, 0.0), s1);
+        assert_eq!(s1.lerp(&s2, 1.0), s2);
+        assert_eq!(s1.lerp(&s2, 0.5), Size::new(150.0, 60.0));
+    }
+
+    #[test]
+    fn test_size_lerp_extrapolates() {
+        let s1 = Size::new(100.0, 40.0);
+        let s2 = Size::new(200.0, 80.0);
+        assert_eq!(s1.lerp(&s2, 1.5), Size::new(250.0, 100.0));
+    }
+
     #[test]
     fn test_size_default() {
         assert_eq!(Size::default(), Size::ZERO);
@@ -579,6 +790,32 @@ mod tests {
         assert_eq!(inset.height, 0.0);
     }
 
+    #[test]
+    fn test_rect_translate() {
+        let r = Rect::new(10.0, 20.0, 30.0, 40.0);
+        assert_eq!(r.translate(5.0, -10.0), Rect::new(15.0, 10.0, 30.0, 40.0));
+    }
+
+    #[test]
+    fn test_rect_scale() {
+        let r = Rect::new(10.0, 20.0, 30.0, 40.0);
+        assert_eq!(r.scale(2.0), Rect::new(20.0, 40.0, 60.0, 80.0));
+    }
+
+    #[test]
+    fn test_rect_scale_about_center() {
+        let r = Rect::new(10.0, 10.0, 20.0, 20.0);
+        let scaled = r.scale_about(2.0, r.center());
+        assert_eq!(scaled, Rect::new(0.0, 0.0, 40.0, 40.0));
+        assert_eq!(scaled.center(), r.center());
+    }
+
+    #[test]
+    fn test_rect_scale_about_origin_matches_scale() {
+        let r = Rect::new(10.0, 20.0, 30.0, 40.0);
+        assert_eq!(r.scale_about(3.0, Point::ORIGIN), r.scale(3.0));
+    }
+
     #[test]
     fn test_rect_with_origin() {
         let r = Rect::new(0.0, 0.0, 100.0, 50.0);
@@ -595,4 +832,128 @@ mod tests {
         assert_eq!(resized.x, 10.0);
         assert_eq!(resized.width, 200.0);
     }
+
+    fn square() -> Polygon {
+        Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+        ])
+    }
+
+    #[test]
+    fn test_polygon_bounding_rect() {
+        let tri = Polygon::new(vec![
+            Point::new(5.0, -2.0),
+            Point::new(12.0, 8.0),
+            Point::new(-1.0, 3.0),
+        ]);
+        assert_eq!(tri.bounding_rect(), Some(Rect::new(-1.0, -2.0, 13.0, 10.0)));
+        assert_eq!(Polygon::default().bounding_rect(), None);
+    }
+
+    #[test]
+    fn test_polygon_contains_point() {
+        let sq = square();
+        assert!(sq.contains_point(&Point::new(5.0, 5.0)));
+        assert!(!sq.contains_point(&Point::new(15.0, 5.0)));
+        assert!(!sq.contains_point(&Point::new(5.0, -1.0)));
+    }
+
+    #[test]
+    fn test_polygon_contains_point_concave() {
+        // U shape: notch cut from the top middle
+        let u = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ]);
+        assert!(u.contains_point(&Point::new(0.5, 2.0)));
+        assert!(!u.contains_point(&Point::new(1.5, 2.0)));
+        assert!(u.contains_point(&Point::new(1.5, 0.5)));
+    }
+
+    #[test]
+    fn test_polygon_area() {
+        assert_eq!(square().area(), 100.0);
+        assert_eq!(square().signed_area(), 100.0);
+
+        let mut reversed = square();
+        reversed.points.reverse();
+        assert_eq!(reversed.signed_area(), -100.0);
+        assert_eq!(reversed.area(), 100.0);
+    }
+
+    #[test]
+    fn test_polygon_area_self_intersecting() {
+        // Bow tie: two triangles wound in opposite directions cancel
+        let bowtie = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(10.0, 0.0),
+            Point::new(0.0, 10.0),
+        ]);
+        assert_eq!(bowtie.signed_area(), 0.0);
+
+        let mut rotated = bowtie.clone();
+        rotated.points.rotate_left(1);
+        assert_eq!(rotated.signed_area(), bowtie.signed_area());
+    }
+
+    #[test]
+    fn test_polygon_centroid() {
+        assert_eq!(square().centroid(), Some(Point::new(5.0, 5.0)));
+
+        let tri = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(6.0, 0.0),
+            Point::new(0.0, 3.0),
+        ]);
+        assert_eq!(tri.centroid(), Some(Point::new(2.0, 1.0)));
+    }
+
+    #[test]
+    fn test_polygon_degenerate() {
+        let empty = Polygon::default();
+        assert!(empty.is_empty());
+        assert_eq!(empty.area(), 0.0);
+        assert_eq!(empty.centroid(), None);
+        assert!(!empty.contains_point(&Point::ORIGIN));
+
+        let line = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(10.0, 0.0)]);
+        assert!(line.is_degenerate());
+        assert_eq!(line.len(), 2);
+        assert_eq!(line.area(), 0.0);
+        assert_eq!(line.centroid(), None);
+        assert!(!line.contains_point(&Point::new(5.0, 0.0)));
+    }
+
+    #[test]
+    fn test_polygon_collinear_centroid() {
+        let flat = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(6.0, 0.0),
+        ]);
+        assert_eq!(flat.area(), 0.0);
+        assert_eq!(flat.centroid(), Some(Point::new(3.0, 0.0)));
+    }
+
+    #[test]
+    fn test_polygon_from_rect() {
+        let poly = Polygon::from(Rect::new(10.0, 20.0, 30.0, 40.0));
+        assert_eq!(poly.len(), 4);
+        assert_eq!(poly.area(), 1200.0);
+        assert_eq!(
+            poly.bounding_rect(),
+            Some(Rect::new(10.0, 20.0, 30.0, 40.0))
+        );
+        assert_eq!(poly.centroid(), Some(Point::new(25.0, 40.0)));
+    }
 }
@@ -70,10 +70,12 @@ pub mod chart;
 pub mod clipboard;
 mod color;
 mod constraints;
+pub mod delimited;
 pub mod diff;
 pub mod dnd;
 pub mod draw;
 mod event;
+pub mod fuzzy;
 mod geometry;
 pub mod gesture;
 pub mod history;
@@ -113,7 +115,7 @@ pub use cache::{
     CacheBuilder, CacheCallback, CacheConfig, CacheEvent, CacheKey, CacheMetadata, CacheOptions,
     CacheSize, CacheState, CacheStats, DataCache, StringCache,
 };
-pub use canvas::RecordingCanvas;
+pub use canvas::{RecordingCanvas, TextMeasurer};
 pub use chart::{
     ArcGeometry, CatmullRom, CubicBezier, CubicSpline, DataNormalizer, DrawBatch, HistogramBins,
     Interpolator, LinearInterpolator, PathTessellator, Point2D,
@@ -122,8 +124,8 @@ pub use clipboard::{
     Clipboard, ClipboardData, ClipboardEvent, ClipboardFormat, ClipboardHistory,
     ClipboardOperation, ClipboardResult,
 };
-pub use color::{Color, ColorParseError};
-pub use constraints::Constraints;
+pub use color::{Color, ColorParseError, CvdType};
+pub use constraints::{Constraints, Dimension};
 pub use diff::{diff_trees, DiffNode, DiffOp, DiffResult, TreeDiffer, WidgetKey};
 pub use dnd::{
     DragData, DragDataType, DragDropManager, DragId, DragPayload, DragPhase, DragState, DropEffect,
@@ -134,7 +136,8 @@ pub use draw::{
     TensorRef, Transform2D as DrawTransform,
 };
 pub use event::{Event, GestureState, Key, MouseButton, PointerId, PointerType, TouchId};
-pub use geometry::{CornerRadius, Point, Rect, Size};
+pub use fuzzy::{FuzzyIndex, SearchResult};
+pub use geometry::{CornerRadius, Point, Polygon, Rect, Size};
 pub use gesture::{
     GestureConfig, GestureRecognizer, PointerGestureRecognizer, PointerInfo, RecognizedGesture,
     TouchPoint,
@@ -338,6 +341,51 @@ mod tests {
                 prop_assert!((result.g - c2.g).abs() < 0.001);
                 prop_assert!((result.b - c2.b).abs() < 0.001);
             }
+
+            #[test]
+            fn prop_hsl_round_trip(r in 0.0f32..1.0, g in 0.0f32..1.0, b in 0.0f32..1.0) {
+                let c = Color::rgb(r, g, b);
+                let (h, s, l) = c.to_hsl();
+                let back = Color::from_hsl(h, s, l);
+                prop_assert!((back.r - c.r).abs() < 0.001);
+                prop_assert!((back.g - c.g).abs() < 0.001);
+                prop_assert!((back.b - c.b).abs() < 0.001);
+            }
+
+            #[test]
+            fn prop_hsv_round_trip(r in 0.0f32..1.0, g in 0.0f32..1.0, b in 0.0f32..1.0) {
+                let c = Color::rgb(r, g, b);
+                let (h, s, v) = c.to_hsv();
+                let back = Color::from_hsv(h, s, v);
+                prop_assert!((back.r - c.r).abs() < 0.001);
+                prop_assert!((back.g - c.g).abs() < 0.001);
+                prop_assert!((back.b - c.b).abs() < 0.001);
+            }
+
+            #[test]
+            fn prop_hsl_hue_wraps_at_360(h in 0.0f32..360.0, s in 0.0f32..1.0, l in 0.0f32..1.0) {
+                let a = Color::from_hsl(h, s, l);
+                let b = Color::from_hsl(h + 360.0, s, l);
+                let c = Color::from_hsl(h - 360.0, s, l);
+                prop_assert!((a.r - b.r).abs() < 0.001 && (a.r - c.r).abs() < 0.001);
+                prop_assert!((a.g - b.g).abs() < 0.001 && (a.g - c.g).abs() < 0.001);
+                prop_assert!((a.b - b.b).abs() < 0.001 && (a.b - c.b).abs() < 0.001);
+            }
+
+            #[test]
+            fn prop_grayscale_has_zero_saturation(v in 0.0f32..1.0) {
+                let c = Color::rgb(v, v, v);
+                let (h, s, _) = c.to_hsl();
+                prop_assert_eq!(h, 0.0);
+                prop_assert_eq!(s, 0.0);
+                prop_assert_eq!(c.to_hsv().1, 0.0);
+            }
+
+            #[test]
+            fn prop_hue_in_range(r in 0.0f32..1.0, g in 0.0f32..1.0, b in 0.0f32..1.0) {
+                let (h, _, _) = Color::rgb(r, g, b).to_hsl();
+                prop_assert!((0.0..360.0).contains(&h));
+            }
         }
     }
 
@@ -567,6 +615,21 @@ mod tests {
                 prop_assert!(r.contains_point(&r.center()));
             }
 
+            #[test]
+            fn prop_rect_translate_preserves_size(x in -1000.0f32..1000.0, y in -1000.0f32..1000.0, w in 0.0f32..1000.0, h in 0.0f32..1000.0, dx in -1000.0f32..1000.0, dy in -1000.0f32..1000.0) {
+                let r = Rect::new(x, y, w, h);
+                prop_assert_eq!(r.translate(dx, dy).size(), r.size());
+            }
+
+            #[test]
+            fn prop_rect_scale_about_center_keeps_center(x in -1000.0f32..1000.0, y in -1000.0f32..1000.0, w in 0.0f32..1000.0, h in 0.0f32..1000.0) {
+                let r = Rect::new(x, y, w, h);
+                let center = r.center();
+                let scaled = r.scale_about(2.0, center).center();
+                prop_assert!((scaled.x - center.x).abs() < 0.01);
+                prop_assert!((scaled.y - center.y).abs() < 0.01);
+            }
+
             #[test]
             fn prop_rect_intersects_self(x in -1000.0f32..1000.0, y in -1000.0f32..1000.0, w in 0.1f32..1000.0, h in 0.1f32..1000.0) {
                 let r = Rect::new(x, y, w, h);
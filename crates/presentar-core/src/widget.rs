@@ -173,6 +173,17 @@ pub trait Canvas {
     /// Draw text.
     fn draw_text(&mut self, text: &str, position: crate::Point, style: &TextStyle);
 
+    /// Measure the size `text` would occupy if drawn with `style`.
+    ///
+    /// The default estimates ~0.6 em per character and a 1.2 em line height;
+    /// backends with real metrics (terminal cells, fonts) should override it.
+    fn measure_text(&self, text: &str, style: &TextStyle) -> Size {
+        Size::new(
+            text.chars().count() as f32 * style.size * 0.6,
+            style.size * 1.2,
+        )
+    }
+
     /// Draw a line between two points.
     fn draw_line(&mut self, from: crate::Point, to: crate::Point, color: crate::Color, width: f32);
 
@@ -382,6 +393,16 @@ pub enum AccessibleRole {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_canvas_measure_text_default() {
+        let canvas = crate::RecordingCanvas::new();
+        let style = TextStyle::default();
+        let size = canvas.measure_text("hello", &style);
+        assert!((size.width - 48.0).abs() < 1e-4);
+        assert!((size.height - 19.2).abs() < 1e-4);
+        assert_eq!(canvas.measure_text("", &style).width, 0.0);
+    }
+
     #[test]
     fn test_widget_id() {
         let id = WidgetId::new(42);
@@ -1,7 +1,9 @@
 //! Layout caching for memoization.
 
-use presentar_core::Size;
-use std::collections::HashMap;
+use presentar_core::{Constraints, Size, WidgetId};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 
 /// Cache key combining constraints hash and widget identity.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -12,6 +14,35 @@ pub struct CacheKey {
     pub constraints_hash: u64,
 }
 
+impl CacheKey {
+    /// Key for a widget measured under the given constraints.
+    #[must_use]
+    pub fn new(widget_id: u64, constraints: Constraints) -> Self {
+        let mut hasher = DefaultHasher::new();
+        for bound in [
+            constraints.min_width,
+            constraints.max_width,
+            constraints.min_height,
+            constraints.max_height,
+        ] {
+            bound.to_bits().hash(&mut hasher);
+        }
+        Self {
+            widget_id,
+            constraints_hash: hasher.finish(),
+        }
+    }
+}
+
+/// Position of a widget in the last measured tree.
+#[derive(Debug, Clone, Copy)]
+struct TreeNode {
+    /// Parent widget, if any
+    parent: Option<u64>,
+    /// Number of widgets below this one (ids follow it contiguously)
+    descendants: u64,
+}
+
 /// Cached layout result.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct CacheEntry {
@@ -28,6 +59,10 @@ pub struct LayoutCache {
     current_frame: u64,
     hits: usize,
     misses: usize,
+    /// Tree shape from the last measure pass
+    nodes: HashMap<u64, TreeNode>,
+    /// Widgets invalidated since they were last measured
+    dirty: HashSet<u64>,
 }
 
 impl LayoutCache {
@@ -64,10 +99,81 @@ impl LayoutCache {
     /// Clear the entire cache.
     pub fn clear(&mut self) {
         self.entries.clear();
+        self.nodes.clear();
+        self.dirty.clear();
         self.hits = 0;
         self.misses = 0;
     }
 
+    /// Mark a widget and its ancestors as needing re-measurement.
+    ///
+    /// Sibling subtrees keep their cached sizes.
+    pub fn invalidate(&mut self, widget_id: WidgetId) {
+        let mut next = Some(widget_id.0);
+        while let Some(id) = next {
+            // Ancestors of a dirty widget are already dirty
+            if !self.dirty.insert(id) {
+                break;
+            }
+            next = self.nodes.get(&id).and_then(|node| node.parent);
+        }
+    }
+
+    /// Check whether a widget was invalidated since it was last measured.
+    #[must_use]
+    pub fn is_dirty(&self, widget_id: WidgetId) -> bool {
+        self.dirty.contains(&widget_id.0)
+    }
+
+    /// Record a freshly measured widget and mark it clean.
+    pub(crate) fn record(
+        &mut self,
+        key: CacheKey,
+        size: Size,
+        parent: Option<u64>,
+        descendants: u64,
+    ) {
+        self.insert(key, size);
+        self.nodes.insert(
+            key.widget_id,
+            TreeNode {
+                parent,
+                descendants,
+            },
+        );
+        self.dirty.remove(&key.widget_id);
+    }
+
+    /// Cached sizes for a clean widget and all its descendants, in id order.
+    ///
+    /// Returns `None` (a miss) if the widget is dirty, was measured under
+    /// different constraints, or any descendant's entry has been evicted.
+    pub(crate) fn subtree(&mut self, key: CacheKey) -> Option<Vec<Size>> {
+        let sizes = self.nodes.get(&key.widget_id).and_then(|node| {
+            if self.dirty.contains(&key.widget_id) {
+                return None;
+            }
+            (key.widget_id..=key.widget_id + node.descendants)
+                .map(|widget_id| {
+                    self.entries
+                        .get(&CacheKey { widget_id, ..key })
+                        .map(|entry| entry.size)
+                })
+                .collect::<Option<Vec<_>>>()
+        });
+        if let Some(sizes) = &sizes {
+            for widget_id in key.widget_id..key.widget_id + sizes.len() as u64 {
+                if let Some(entry) = self.entries.get_mut(&CacheKey { widget_id, ..key }) {
+                    entry.last_used_frame = self.current_frame;
+                }
+            }
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        sizes
+    }
+
     /// Get the number of cache hits.
     #[must_use]
     pub const fn hits(&self) -> usize {
@@ -530,4 +636,38 @@ mod tests {
         // Entry should still exist
         assert_eq!(cache.get(key), Some(Size::new(10.0, 10.0)));
     }
+
+    #[test]
+    fn test_cache_invalidate_marks_ancestors() {
+        let mut cache = LayoutCache::new();
+        let constraints = Constraints::loose(Size::new(100.0, 100.0));
+        // 0 -> (1 -> 2), 3
+        cache.record(CacheKey::new(2, constraints), Size::ZERO, Some(1), 0);
+        cache.record(CacheKey::new(1, constraints), Size::ZERO, Some(0), 1);
+        cache.record(CacheKey::new(3, constraints), Size::ZERO, Some(0), 0);
+        cache.record(CacheKey::new(0, constraints), Size::ZERO, None, 3);
+
+        cache.invalidate(WidgetId::new(2));
+
+        assert!(cache.is_dirty(WidgetId::new(2)));
+        assert!(cache.is_dirty(WidgetId::new(1)));
+        assert!(cache.is_dirty(WidgetId::new(0)));
+        assert!(!cache.is_dirty(WidgetId::new(3)));
+        assert!(cache.subtree(CacheKey::new(1, constraints)).is_none());
+        assert_eq!(
+            cache.subtree(CacheKey::new(3, constraints)),
+            Some(vec![Size::ZERO])
+        );
+    }
+
+    #[test]
+    fn test_cache_subtree_misses_on_new_constraints() {
+        let mut cache = LayoutCache::new();
+        let old = Constraints::loose(Size::new(100.0, 100.0));
+        let new = Constraints::loose(Size::new(50.0, 100.0));
+        cache.record(CacheKey::new(0, old), Size::new(10.0, 10.0), None, 0);
+
+        assert!(cache.subtree(CacheKey::new(0, new)).is_none());
+        assert_eq!(cache.misses(), 1);
+    }
 }
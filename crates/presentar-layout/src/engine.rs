@@ -1,9 +1,9 @@
 //! Layout engine implementation.
 
-use presentar_core::{Constraints, Rect, Size, Widget};
+use presentar_core::{Constraints, Dimension, Rect, Size, Widget, WidgetId};
 use std::collections::HashMap;
 
-use crate::cache::LayoutCache;
+use crate::cache::{CacheKey, LayoutCache};
 
 /// Layout tree containing computed positions.
 #[derive(Debug, Default)]
@@ -54,21 +54,81 @@ impl LayoutEngine {
     /// 1. Measure phase (bottom-up): Determine intrinsic sizes
     /// 2. Layout phase (top-down): Assign final positions and sizes
     pub fn compute(&mut self, root: &mut dyn Widget, viewport: Size) -> LayoutTree {
-        self.cache.clear();
-        self.next_id = 0;
+        self.compute_constrained(
+            root,
+            Constraints::loose(viewport),
+            Rect::from_size(viewport),
+            false,
+        )
+    }
 
-        let constraints = Constraints::loose(viewport);
+    /// Recompute layout, re-measuring only invalidated subtrees.
+    ///
+    /// Widgets that are clean and measured under the same constraints as
+    /// last time reuse their cached sizes along with their whole subtree.
+    /// The tree must have the same shape as in the previous
+    /// [`LayoutEngine::compute`]; after adding or removing widgets, call
+    /// `compute` instead.
+    pub fn relayout(&mut self, root: &mut dyn Widget, viewport: Size) -> LayoutTree {
+        self.compute_constrained(
+            root,
+            Constraints::loose(viewport),
+            Rect::from_size(viewport),
+            true,
+        )
+    }
+
+    /// Mark a widget and its ancestors for re-measurement by [`LayoutEngine::relayout`].
+    ///
+    /// Widget ids are the keys of [`LayoutTree`].
+    pub fn invalidate(&mut self, widget_id: WidgetId) {
+        self.cache.invalidate(widget_id);
+    }
+
+    /// Compute layout with the root sized by [`Dimension`]s relative to the viewport.
+    ///
+    /// Percentages are re-resolved on every call, so passing the new viewport
+    /// after a resize keeps a `Percent(50.0)` root at half the width.
+    /// [`Dimension::Auto`] behaves like [`LayoutEngine::compute`] on that axis.
+    pub fn compute_with_dimensions(
+        &mut self,
+        root: &mut dyn Widget,
+        viewport: Size,
+        width: Dimension,
+        height: Dimension,
+    ) -> LayoutTree {
+        let resolved = Constraints::from_dimensions(width, height, viewport);
+        let constraints = Constraints::new(
+            resolved.min_width.min(viewport.width),
+            resolved.max_width.min(viewport.width),
+            resolved.min_height.min(viewport.height),
+            resolved.max_height.min(viewport.height),
+        );
+        let bounds = Rect::from_size(constraints.biggest());
+        self.compute_constrained(root, constraints, bounds, false)
+    }
+
+    fn compute_constrained(
+        &mut self,
+        root: &mut dyn Widget,
+        constraints: Constraints,
+        bounds: Rect,
+        incremental: bool,
+    ) -> LayoutTree {
+        if !incremental {
+            self.cache.clear();
+        }
+        self.next_id = 0;
 
         // Phase 1: Measure (bottom-up)
         let mut sizes = HashMap::new();
-        self.measure_tree(root, constraints, &mut sizes);
+        self.measure_tree(root, constraints, None, incremental, &mut sizes);
 
         // Reset ID counter for layout phase
         self.next_id = 0;
 
         // Phase 2: Layout (top-down)
         let mut positions = HashMap::new();
-        let bounds = Rect::from_size(viewport);
         self.layout_tree(root, bounds, &mut positions);
 
         LayoutTree { sizes, positions }
@@ -83,7 +143,7 @@ impl LayoutEngine {
 
         // Phase 1: Measure (bottom-up)
         let mut sizes = HashMap::new();
-        self.measure_tree(root, constraints, &mut sizes);
+        self.measure_tree(root, constraints, None, false, &mut sizes);
 
         // Reset ID counter
         self.next_id = 0;
@@ -100,19 +160,33 @@ impl LayoutEngine {
         &mut self,
         widget: &dyn Widget,
         constraints: Constraints,
+        parent: Option<u64>,
+        incremental: bool,
         sizes: &mut HashMap<u64, Size>,
     ) -> Size {
         let id = self.next_id;
         self.next_id += 1;
+        let key = CacheKey::new(id, constraints);
+
+        // Clean subtree under unchanged constraints: reuse every cached size
+        if incremental {
+            if let Some(cached) = self.cache.subtree(key) {
+                let size = cached[0];
+                self.next_id = id + cached.len() as u64;
+                sizes.extend((id..).zip(cached));
+                return size;
+            }
+        }
 
         // Measure children first (bottom-up)
         for child in widget.children() {
-            self.measure_tree(child.as_ref(), constraints, sizes);
+            self.measure_tree(child.as_ref(), constraints, Some(id), incremental, sizes);
         }
 
         // Then measure self
         let size = widget.measure(constraints);
         sizes.insert(id, size);
+        self.cache.record(key, size, parent, self.next_id - id - 1);
         size
     }
 
@@ -200,12 +274,15 @@ mod tests {
         Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Event, TypeId,
     };
     use std::any::Any;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
     use std::time::Duration;
 
     // Test widget for layout testing
     struct TestWidget {
         size: Size,
         children: Vec<Box<dyn Widget>>,
+        measures: Arc<AtomicUsize>,
     }
 
     impl TestWidget {
@@ -213,6 +290,7 @@ mod tests {
             Self {
                 size: Size::new(width, height),
                 children: Vec::new(),
+                measures: Arc::default(),
             }
         }
 
@@ -258,6 +336,7 @@ mod tests {
         }
 
         fn measure(&self, constraints: Constraints) -> Size {
+            self.measures.fetch_add(1, Ordering::Relaxed);
             constraints.constrain(self.size)
         }
 
@@ -655,6 +734,58 @@ mod tests {
         assert!(size.height <= viewport.height);
     }
 
+    #[test]
+    fn test_layout_with_percent_width() {
+        let mut engine = LayoutEngine::new();
+        let mut widget = TestWidget::new(1000.0, 50.0);
+
+        let tree = engine.compute_with_dimensions(
+            &mut widget,
+            Size::new(800.0, 600.0),
+            Dimension::Percent(50.0),
+            Dimension::Auto,
+        );
+
+        assert_eq!(tree.get_size(0), Some(Size::new(400.0, 50.0)));
+        assert_eq!(
+            tree.get_position(0),
+            Some(Rect::new(0.0, 0.0, 400.0, 600.0))
+        );
+    }
+
+    #[test]
+    fn test_layout_with_percent_width_on_resize() {
+        let mut engine = LayoutEngine::new();
+        let mut widget = TestWidget::new(1000.0, 50.0);
+
+        for (viewport_width, expected) in [(800.0, 400.0), (200.0, 100.0)] {
+            let tree = engine.compute_with_dimensions(
+                &mut widget,
+                Size::new(viewport_width, 600.0),
+                Dimension::Percent(50.0),
+                Dimension::Px(40.0),
+            );
+            assert_eq!(tree.get_size(0), Some(Size::new(expected, 40.0)));
+        }
+    }
+
+    #[test]
+    fn test_layout_with_auto_dimensions_matches_compute() {
+        let viewport = Size::new(800.0, 600.0);
+        let mut widget = TestWidget::new(100.0, 50.0);
+
+        let auto = LayoutEngine::new().compute_with_dimensions(
+            &mut widget,
+            viewport,
+            Dimension::Auto,
+            Dimension::Auto,
+        );
+        let plain = LayoutEngine::new().compute(&mut widget, viewport);
+
+        assert_eq!(auto.get_size(0), plain.get_size(0));
+        assert_eq!(auto.get_position(0), plain.get_position(0));
+    }
+
     #[test]
     fn test_layout_widget_fractional_size() {
         let mut engine = LayoutEngine::new();
@@ -664,4 +795,62 @@ mod tests {
         let tree = engine.compute(&mut widget, viewport);
         assert_eq!(tree.widget_count(), 1);
     }
+
+    // =========================================================================
+    // Incremental Relayout
+    // =========================================================================
+
+    /// Root (0) -> branch (1) -> leaves (2, 3); root -> sibling (4) -> child (5).
+    fn counted_tree() -> (TestWidget, [Arc<AtomicUsize>; 3]) {
+        let leaf = TestWidget::new(10.0, 10.0);
+        let other_leaf = TestWidget::new(10.0, 10.0);
+        let sibling = TestWidget::new(20.0, 20.0).with_child(TestWidget::new(5.0, 5.0));
+        let counters = [
+            leaf.measures.clone(),
+            other_leaf.measures.clone(),
+            sibling.measures.clone(),
+        ];
+        let root = TestWidget::new(100.0, 100.0)
+            .with_child(
+                TestWidget::new(50.0, 50.0)
+                    .with_child(leaf)
+                    .with_child(other_leaf),
+            )
+            .with_child(sibling);
+        (root, counters)
+    }
+
+    #[test]
+    fn test_relayout_skips_clean_subtrees() {
+        let (mut root, [leaf, other_leaf, sibling]) = counted_tree();
+        let mut engine = LayoutEngine::new();
+        let viewport = Size::new(800.0, 600.0);
+        let first = engine.compute(&mut root, viewport);
+
+        engine.invalidate(WidgetId::new(2));
+        let second = engine.relayout(&mut root, viewport);
+
+        assert_eq!(leaf.load(Ordering::Relaxed), 2);
+        assert_eq!(other_leaf.load(Ordering::Relaxed), 1);
+        assert_eq!(sibling.load(Ordering::Relaxed), 1);
+        assert_eq!(second.sizes, first.sizes);
+        // Subtrees 3 and 4 reused; root, branch and leaf re-measured
+        assert_eq!(engine.cache_stats(), (2, 3));
+    }
+
+    #[test]
+    fn test_relayout_remeasures_on_new_constraints() {
+        let (mut root, [leaf, _, sibling]) = counted_tree();
+        let mut engine = LayoutEngine::new();
+        engine.compute(&mut root, Size::new(800.0, 600.0));
+
+        engine.relayout(&mut root, Size::new(400.0, 300.0));
+        assert_eq!(leaf.load(Ordering::Relaxed), 2);
+        assert_eq!(sibling.load(Ordering::Relaxed), 2);
+
+        // Nothing invalidated and same constraints: the whole tree is reused
+        engine.relayout(&mut root, Size::new(400.0, 300.0));
+        assert_eq!(leaf.load(Ordering::Relaxed), 2);
+        assert_eq!(sibling.load(Ordering::Relaxed), 2);
+    }
 }
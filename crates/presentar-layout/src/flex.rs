@@ -1,6 +1,8 @@
 //! Flexbox layout types.
 
+use presentar_core::Size;
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 
 /// Direction for flex layout.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -50,7 +52,139 @@ pub enum FlexAlign {
     Baseline,
 }
 
+/// Line wrapping for flex layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FlexWrap {
+    /// Keep all items on a single line
+    #[default]
+    NoWrap,
+    /// Start a new line when the main axis is full
+    Wrap,
+    /// Wrap, stacking lines from the cross-axis end
+    WrapReverse,
+}
+
+/// Cross axis distribution of wrapped lines (CSS `align-content`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FlexAlignContent {
+    /// Pack lines at the start
+    #[default]
+    Start,
+    /// Pack lines at the end
+    End,
+    /// Center lines
+    Center,
+    /// Grow lines equally to fill the container
+    Stretch,
+    /// Distribute space evenly between lines
+    SpaceBetween,
+    /// Distribute space evenly around lines
+    SpaceAround,
+}
+
+/// Flex container properties.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct FlexContainer {
+    /// Main axis direction
+    pub direction: FlexDirection,
+    /// Main axis alignment
+    pub justify: FlexJustify,
+    /// Cross axis alignment of items within a line
+    pub align: FlexAlign,
+    /// Line wrapping
+    pub wrap: FlexWrap,
+    /// Cross axis distribution of lines (only with wrapping)
+    pub align_content: FlexAlignContent,
+    /// Space between rows: items in a column, lines in a wrapping row
+    pub row_gap: f32,
+    /// Space between columns: items in a row, lines in a wrapping column
+    pub column_gap: f32,
+}
+
+impl FlexContainer {
+    /// Create a new flex container with default values.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the direction.
+    #[must_use]
+    pub const fn direction(mut self, direction: FlexDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set main axis alignment.
+    #[must_use]
+    pub const fn justify(mut self, justify: FlexJustify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Set cross axis alignment.
+    #[must_use]
+    pub const fn align(mut self, align: FlexAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set line wrapping.
+    #[must_use]
+    pub const fn wrap(mut self, wrap: FlexWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set cross axis distribution of lines.
+    #[must_use]
+    pub const fn align_content(mut self, align_content: FlexAlignContent) -> Self {
+        self.align_content = align_content;
+        self
+    }
+
+    /// Set both row and column gaps.
+    #[must_use]
+    pub const fn gap(mut self, gap: f32) -> Self {
+        self.row_gap = gap;
+        self.column_gap = gap;
+        self
+    }
+
+    /// Set the gap between rows.
+    #[must_use]
+    pub const fn row_gap(mut self, gap: f32) -> Self {
+        self.row_gap = gap;
+        self
+    }
+
+    /// Set the gap between columns.
+    #[must_use]
+    pub const fn column_gap(mut self, gap: f32) -> Self {
+        self.column_gap = gap;
+        self
+    }
+
+    const fn is_row(self) -> bool {
+        matches!(
+            self.direction,
+            FlexDirection::Row | FlexDirection::RowReverse
+        )
+    }
+
+    const fn is_reversed(self) -> bool {
+        matches!(
+            self.direction,
+            FlexDirection::RowReverse | FlexDirection::ColumnReverse
+        )
+    }
+}
+
 /// Flex item properties.
+///
+/// Build with [`FlexItem::new`] and the builder methods; struct literals
+/// should end in `..FlexItem::default()` so they keep compiling as fields
+/// are added.
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct FlexItem {
     /// Flex grow factor
@@ -64,6 +198,12 @@ pub struct FlexItem {
     /// UX-107: Collapse to zero size when content is empty.
     /// When true, items with no content will have 0 size in layout.
     pub collapse_if_empty: bool,
+    /// Width / height ratio; the cross size is derived from the main size
+    pub aspect_ratio: Option<f32>,
+    /// Minimum size after flexing
+    pub min_size: Option<Size>,
+    /// Maximum size after flexing
+    pub max_size: Option<Size>,
 }
 
 impl FlexItem {
@@ -107,6 +247,36 @@ impl FlexItem {
         self.collapse_if_empty = true;
         self
     }
+
+    /// Set the aspect ratio (width / height), e.g. `16.0 / 9.0`.
+    #[must_use]
+    pub const fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Set the minimum size.
+    #[must_use]
+    pub const fn min_size(mut self, size: Size) -> Self {
+        self.min_size = Some(size);
+        self
+    }
+
+    /// Set the maximum size.
+    #[must_use]
+    pub const fn max_size(mut self, size: Size) -> Self {
+        self.max_size = Some(size);
+        self
+    }
+
+    /// Min and max size along the horizontal or vertical axis.
+    fn limits(&self, horizontal: bool) -> (f32, f32) {
+        let axis = |size: Size| if horizontal { size.width } else { size.height };
+        (
+            self.min_size.map_or(0.0, axis),
+            self.max_size.map_or(f32::INFINITY, axis),
+        )
+    }
 }
 
 /// Distribute available space among flex items.
@@ -164,7 +334,6 @@ fn apply_flex_adjustment(
 }
 
 #[must_use]
-#[allow(dead_code)]
 pub(crate) fn distribute_flex(items: &[FlexItem], sizes: &[f32], available: f32) -> Vec<f32> {
     if items.is_empty() {
         return Vec::new();
@@ -219,6 +388,263 @@ pub(crate) fn distribute_flex(items: &[FlexItem], sizes: &[f32], available: f32)
         .collect()
 }
 
+/// Compute flex item bounds as `(x, y, width, height)` within the container.
+///
+/// `child_sizes` are the measured `(width, height)` of each item; an item's
+/// `basis` replaces its measured main-axis size. With wrapping, an item that
+/// would overflow the main axis starts a new line, and each line grows or
+/// shrinks its own items. Gaps separate adjacent items and lines but are
+/// never added at the container edges.
+#[must_use]
+pub fn compute_flex_layout(
+    container: &FlexContainer,
+    items: &[FlexItem],
+    child_sizes: &[(f32, f32)],
+    available_width: f32,
+    available_height: f32,
+) -> Vec<(f32, f32, f32, f32)> {
+    let count = items.len().min(child_sizes.len());
+    let items = &items[..count];
+    let is_row = container.is_row();
+    let (main_avail, cross_avail) = if is_row {
+        (available_width, available_height)
+    } else {
+        (available_height, available_width)
+    };
+    let (main_gap, cross_gap) = if is_row {
+        (container.column_gap, container.row_gap)
+    } else {
+        (container.row_gap, container.column_gap)
+    };
+
+    let (base_sizes, measured_cross): (Vec<f32>, Vec<f32>) = items
+        .iter()
+        .zip(child_sizes)
+        .map(|(item, &(w, h))| {
+            let (main, cross) = if is_row { (w, h) } else { (h, w) };
+            let (min, max) = item.limits(is_row);
+            (clamp_size(item.basis.unwrap_or(main), min, max), cross)
+        })
+        .unzip();
+
+    let lines = break_lines(container.wrap, &base_sizes, main_avail, main_gap);
+
+    let mut main_sizes = vec![0.0; count];
+    for line in &lines {
+        let line_gaps = main_gap * line.len().saturating_sub(1) as f32;
+        let sizes = resolve_line(
+            &items[line.clone()],
+            &base_sizes[line.clone()],
+            main_avail - line_gaps,
+            is_row,
+        );
+        main_sizes[line.clone()].copy_from_slice(&sizes);
+    }
+
+    // An aspect ratio (width / height) fixes the cross size from the main size
+    let cross_sizes: Vec<f32> = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let cross = item.aspect_ratio.map_or(measured_cross[i], |ratio| {
+                if is_row {
+                    main_sizes[i] / ratio
+                } else {
+                    main_sizes[i] * ratio
+                }
+            });
+            let (min, max) = item.limits(!is_row);
+            clamp_size(cross, min, max)
+        })
+        .collect();
+
+    // A single-line container gives its line the full cross size
+    let mut line_cross: Vec<f32> = if container.wrap == FlexWrap::NoWrap {
+        vec![cross_avail; lines.len()]
+    } else {
+        lines
+            .iter()
+            .map(|line| {
+                cross_sizes[line.clone()]
+                    .iter()
+                    .copied()
+                    .fold(0.0, f32::max)
+            })
+            .collect()
+    };
+    let line_pos = place_lines(
+        container.align_content,
+        &mut line_cross,
+        cross_avail,
+        cross_gap,
+    );
+
+    let mut bounds = vec![(0.0, 0.0, 0.0, 0.0); count];
+    for ((line, &line_start), &line_size) in lines.iter().zip(&line_pos).zip(&line_cross) {
+        let sizes = &main_sizes[line.clone()];
+        let line_gaps = main_gap * line.len().saturating_sub(1) as f32;
+        let (mut main_pos, spacing) =
+            justify_offsets(container.justify, sizes, main_avail - line_gaps);
+        let spacing = spacing + main_gap;
+        let line_start = if container.wrap == FlexWrap::WrapReverse {
+            cross_avail - line_start - line_size
+        } else {
+            line_start
+        };
+
+        for i in line.clone() {
+            let item = &items[i];
+            let main = main_sizes[i];
+            let align = item.align_self.unwrap_or(container.align);
+            let (cross_offset, cross) = match align {
+                FlexAlign::Stretch if item.aspect_ratio.is_none() => {
+                    let (min, max) = item.limits(!is_row);
+                    (0.0, clamp_size(line_size, min, max))
+                }
+                FlexAlign::Start | FlexAlign::Baseline | FlexAlign::Stretch => {
+                    (0.0, cross_sizes[i])
+                }
+                FlexAlign::End => (line_size - cross_sizes[i], cross_sizes[i]),
+                FlexAlign::Center => ((line_size - cross_sizes[i]) / 2.0, cross_sizes[i]),
+            };
+            let main_start = if container.is_reversed() {
+                main_avail - main_pos - main
+            } else {
+                main_pos
+            };
+            let cross_start = line_start + cross_offset;
+            bounds[i] = if is_row {
+                (main_start, cross_start, main, cross)
+            } else {
+                (cross_start, main_start, cross, main)
+            };
+            main_pos += main + spacing;
+        }
+    }
+    bounds
+}
+
+/// Main sizes for one line, honoring each item's min/max size.
+///
+/// An item pushed past a limit is frozen there and the free space is
+/// distributed again among the others, so siblings absorb what a capped
+/// item cannot take.
+fn resolve_line(items: &[FlexItem], base: &[f32], available: f32, is_row: bool) -> Vec<f32> {
+    let mut frozen: Vec<Option<f32>> = vec![None; items.len()];
+    loop {
+        let open: Vec<usize> = (0..items.len()).filter(|&i| frozen[i].is_none()).collect();
+        let taken: f32 = frozen.iter().flatten().sum();
+        let open_items: Vec<FlexItem> = open.iter().map(|&i| items[i]).collect();
+        let open_base: Vec<f32> = open.iter().map(|&i| base[i]).collect();
+        let sizes = distribute_flex(&open_items, &open_base, available - taken);
+
+        let mut clamped = false;
+        for (&i, &size) in open.iter().zip(&sizes) {
+            let (min, max) = items[i].limits(is_row);
+            let limited = clamp_size(size, min, max);
+            if (limited - size).abs() > 0.001 {
+                frozen[i] = Some(limited);
+                clamped = true;
+            }
+        }
+        if !clamped {
+            for (&i, &size) in open.iter().zip(&sizes) {
+                frozen[i] = Some(size);
+            }
+            return frozen.into_iter().map(|size| size.unwrap_or(0.0)).collect();
+        }
+    }
+}
+
+/// Clamp to `[min, max]`, letting `min` win if the two conflict (as CSS does).
+fn clamp_size(size: f32, min: f32, max: f32) -> f32 {
+    size.min(max).max(min)
+}
+
+/// Split items into lines of consecutive indices.
+fn break_lines(wrap: FlexWrap, main_sizes: &[f32], main_avail: f32, gap: f32) -> Vec<Range<usize>> {
+    if main_sizes.is_empty() {
+        return Vec::new();
+    }
+    let limit = match wrap {
+        FlexWrap::NoWrap => f32::INFINITY,
+        FlexWrap::Wrap | FlexWrap::WrapReverse => main_avail + 0.001,
+    };
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut used = 0.0;
+    for (i, &size) in main_sizes.iter().enumerate() {
+        if i > start && used + gap + size > limit {
+            lines.push(start..i);
+            start = i;
+            used = 0.0;
+        }
+        if i > start {
+            used += gap;
+        }
+        used += size;
+    }
+    lines.push(start..main_sizes.len());
+    lines
+}
+
+/// Cross axis start of each line; `Stretch` grows `line_cross` in place.
+fn place_lines(
+    align: FlexAlignContent,
+    line_cross: &mut [f32],
+    cross_avail: f32,
+    gap: f32,
+) -> Vec<f32> {
+    let n = line_cross.len();
+    let gaps = gap * n.saturating_sub(1) as f32;
+    let free = cross_avail - gaps - line_cross.iter().sum::<f32>();
+    let (mut pos, spacing) = match align {
+        _ if n == 0 => (0.0, 0.0),
+        FlexAlignContent::Start => (0.0, 0.0),
+        FlexAlignContent::End => (free, 0.0),
+        FlexAlignContent::Center => (free / 2.0, 0.0),
+        FlexAlignContent::Stretch => {
+            let extra = free.max(0.0) / n as f32;
+            for size in line_cross.iter_mut() {
+                *size += extra;
+            }
+            (0.0, 0.0)
+        }
+        FlexAlignContent::SpaceBetween if n > 1 => (0.0, free.max(0.0) / (n - 1) as f32),
+        FlexAlignContent::SpaceBetween => (0.0, 0.0),
+        FlexAlignContent::SpaceAround => {
+            let around = free.max(0.0) / n as f32;
+            (around / 2.0, around)
+        }
+    };
+
+    line_cross
+        .iter()
+        .map(|&size| {
+            let start = pos;
+            pos += size + spacing + gap;
+            start
+        })
+        .collect()
+}
+
+/// Leading offset and spacing between items for main axis alignment.
+fn justify_offsets(justify: FlexJustify, sizes: &[f32], main_avail: f32) -> (f32, f32) {
+    let n = sizes.len() as f32;
+    let free = main_avail - sizes.iter().sum::<f32>();
+    let spread = free.max(0.0);
+    match justify {
+        FlexJustify::Start => (0.0, 0.0),
+        FlexJustify::End => (free, 0.0),
+        FlexJustify::Center => (free / 2.0, 0.0),
+        FlexJustify::SpaceBetween if n > 1.0 => (0.0, spread / (n - 1.0)),
+        FlexJustify::SpaceBetween => (0.0, 0.0),
+        FlexJustify::SpaceAround => (spread / n / 2.0, spread / n),
+        FlexJustify::SpaceEvenly => (spread / (n + 1.0), spread / (n + 1.0)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -382,6 +808,19 @@ mod tests {
         assert_eq!(item.shrink, 0.0);
         assert_eq!(item.basis, None);
         assert_eq!(item.align_self, None);
+        assert_eq!(item.aspect_ratio, None);
+        assert!(item.min_size.is_none() && item.max_size.is_none());
+    }
+
+    #[test]
+    fn test_flex_item_literal_with_default() {
+        let item = FlexItem {
+            grow: 1.0,
+            ..FlexItem::default()
+        };
+        assert_eq!(item.grow, 1.0);
+        assert_eq!(item.aspect_ratio, None);
+        assert!(item.max_size.is_none());
     }
 
     #[test]
@@ -590,4 +1029,163 @@ mod tests {
         // Collapsed stays 0, second shrinks to fit
         assert_eq!(result, vec![0.0, 100.0]);
     }
+
+    // =========================================================================
+    // compute_flex_layout Tests
+    // =========================================================================
+
+    #[test]
+    fn test_flex_layout_row_justify() {
+        let items = vec![FlexItem::new(); 2];
+        let sizes = vec![(20.0, 10.0), (30.0, 10.0)];
+
+        let start = compute_flex_layout(&FlexContainer::new(), &items, &sizes, 100.0, 10.0);
+        assert_eq!(start, vec![(0.0, 0.0, 20.0, 10.0), (20.0, 0.0, 30.0, 10.0)]);
+
+        let between = FlexContainer::new().justify(FlexJustify::SpaceBetween);
+        let layout = compute_flex_layout(&between, &items, &sizes, 100.0, 10.0);
+        assert_eq!(layout[1].0, 70.0);
+    }
+
+    #[test]
+    fn test_flex_layout_column_reverse() {
+        let container = FlexContainer::new().direction(FlexDirection::ColumnReverse);
+        let items = vec![FlexItem::new(), FlexItem::new().grow(1.0)];
+        let sizes = vec![(10.0, 20.0), (10.0, 20.0)];
+
+        let layout = compute_flex_layout(&container, &items, &sizes, 50.0, 100.0);
+        assert_eq!(layout[0], (20.0, 80.0, 10.0, 20.0));
+        assert_eq!(layout[1], (20.0, 0.0, 10.0, 80.0));
+    }
+
+    #[test]
+    fn test_flex_layout_wrap_onto_second_line() {
+        let container = FlexContainer::new()
+            .wrap(FlexWrap::Wrap)
+            .align(FlexAlign::Start);
+        let items = vec![FlexItem::new(); 3];
+        let sizes = vec![(40.0, 20.0); 3];
+
+        let layout = compute_flex_layout(&container, &items, &sizes, 100.0, 100.0);
+        assert_eq!(layout[0], (0.0, 0.0, 40.0, 20.0));
+        assert_eq!(layout[1], (40.0, 0.0, 40.0, 20.0));
+        assert_eq!(layout[2], (0.0, 20.0, 40.0, 20.0));
+    }
+
+    #[test]
+    fn test_flex_layout_no_wrap_overflows() {
+        let items = vec![FlexItem::new(); 3];
+        let sizes = vec![(40.0, 20.0); 3];
+
+        let layout = compute_flex_layout(&FlexContainer::new(), &items, &sizes, 100.0, 100.0);
+        assert_eq!(layout[2].0, 80.0);
+        assert_eq!(layout[2].1, layout[0].1);
+    }
+
+    #[test]
+    fn test_flex_layout_wrap_reverse_and_align_content() {
+        let items = vec![FlexItem::new(); 3];
+        let sizes = vec![(40.0, 20.0); 3];
+        let base = FlexContainer::new().align(FlexAlign::Start);
+
+        let reverse = base.wrap(FlexWrap::WrapReverse);
+        let layout = compute_flex_layout(&reverse, &items, &sizes, 100.0, 100.0);
+        assert_eq!(layout[0].1, 80.0);
+        assert_eq!(layout[2].1, 60.0);
+
+        let between = base
+            .wrap(FlexWrap::Wrap)
+            .align_content(FlexAlignContent::SpaceBetween);
+        let layout = compute_flex_layout(&between, &items, &sizes, 100.0, 100.0);
+        assert_eq!(layout[2].1, 80.0);
+
+        let stretch = base
+            .wrap(FlexWrap::Wrap)
+            .align(FlexAlign::Stretch)
+            .align_content(FlexAlignContent::Stretch);
+        let layout = compute_flex_layout(&stretch, &items, &sizes, 100.0, 100.0);
+        assert_eq!(layout[0].3, 50.0);
+        assert_eq!(layout[2], (0.0, 50.0, 40.0, 50.0));
+    }
+
+    #[test]
+    fn test_flex_layout_wrapped_line_grows() {
+        let container = FlexContainer::new().wrap(FlexWrap::Wrap);
+        let items = vec![FlexItem::new(), FlexItem::new(), FlexItem::new().grow(1.0)];
+        let sizes = vec![(60.0, 10.0), (30.0, 10.0), (30.0, 10.0)];
+
+        let layout = compute_flex_layout(&container, &items, &sizes, 100.0, 20.0);
+        assert_eq!(layout[2], (0.0, 10.0, 100.0, 10.0));
+    }
+
+    #[test]
+    fn test_flex_layout_gap_between_items() {
+        let container = FlexContainer::new().gap(8.0);
+        let items = vec![FlexItem::new(); 2];
+        let sizes = vec![(30.0, 10.0); 2];
+
+        let layout = compute_flex_layout(&container, &items, &sizes, 100.0, 10.0);
+        assert_eq!(layout[0].0, 0.0);
+        assert_eq!(layout[1].0, layout[0].0 + layout[0].2 + 8.0);
+
+        // Growing items share the space left after gaps, and the edges get none
+        let items = vec![FlexItem::new().grow(1.0); 2];
+        let layout = compute_flex_layout(&container, &items, &sizes, 100.0, 10.0);
+        assert_eq!(layout[0], (0.0, 0.0, 46.0, 10.0));
+        assert_eq!(layout[1], (54.0, 0.0, 46.0, 10.0));
+    }
+
+    #[test]
+    fn test_flex_layout_row_and_column_gaps_when_wrapping() {
+        let container = FlexContainer::new()
+            .wrap(FlexWrap::Wrap)
+            .align(FlexAlign::Start)
+            .column_gap(20.0)
+            .row_gap(5.0);
+        let items = vec![FlexItem::new(); 3];
+        let sizes = vec![(40.0, 20.0); 3];
+
+        // 40 + 20 + 40 fits exactly; the third item wraps below a 5px row gap
+        let layout = compute_flex_layout(&container, &items, &sizes, 100.0, 100.0);
+        assert_eq!(layout[1].0, 60.0);
+        assert_eq!(layout[2], (0.0, 25.0, 40.0, 20.0));
+
+        // In a column the row gap separates items instead
+        let column = container.direction(FlexDirection::Column);
+        let layout = compute_flex_layout(&column, &items, &sizes, 100.0, 100.0);
+        assert_eq!(layout[1].1, 25.0);
+    }
+
+    #[test]
+    fn test_flex_layout_max_size_caps_growth() {
+        let items = vec![
+            FlexItem::new()
+                .grow(1.0)
+                .max_size(Size::new(30.0, f32::INFINITY)),
+            FlexItem::new().grow(1.0),
+            FlexItem::new().grow(1.0),
+        ];
+        let sizes = vec![(0.0, 10.0); 3];
+
+        let layout = compute_flex_layout(&FlexContainer::new(), &items, &sizes, 100.0, 10.0);
+        assert_eq!(layout[0].2, 30.0);
+        assert_eq!(layout[1].2, 35.0);
+        assert_eq!(layout[2], (65.0, 0.0, 35.0, 10.0));
+    }
+
+    #[test]
+    fn test_flex_layout_min_size_and_aspect_ratio() {
+        let items = vec![
+            FlexItem::new().shrink(1.0).min_size(Size::new(60.0, 0.0)),
+            FlexItem::new().shrink(1.0).aspect_ratio(16.0 / 9.0),
+        ];
+        let sizes = vec![(80.0, 10.0), (80.0, 10.0)];
+        let container = FlexContainer::new().align(FlexAlign::Stretch);
+
+        let layout = compute_flex_layout(&container, &items, &sizes, 100.0, 50.0);
+        // Even shrinking would give 50 each; the min holds the first at 60
+        assert_eq!(layout[0], (0.0, 0.0, 60.0, 50.0));
+        // The aspect ratio sets the height instead of stretching
+        assert_eq!(layout[1], (60.0, 0.0, 40.0, 22.5));
+    }
 }
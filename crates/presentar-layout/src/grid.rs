@@ -360,6 +360,8 @@ pub struct GridLayout {
 
 impl GridLayout {
     /// Get the bounds for a grid area.
+    ///
+    /// An area spanning several tracks includes the gaps between them.
     #[must_use]
     pub fn area_bounds(&self, area: &GridArea) -> Option<(f32, f32, f32, f32)> {
         if area.col_start >= self.columns.len() || area.row_start >= self.rows.len() {
@@ -374,14 +376,10 @@ impl GridLayout {
         let x = self.columns.get(col_start).map(|(pos, _)| *pos)?;
         let y = self.rows.get(row_start).map(|(pos, _)| *pos)?;
 
-        let width: f32 = self.columns[col_start..col_end]
-            .iter()
-            .map(|(_, size)| size)
-            .sum();
-        let height: f32 = self.rows[row_start..row_end]
-            .iter()
-            .map(|(_, size)| size)
-            .sum();
+        let track_end =
+            |tracks: &[(f32, f32)], end: usize| tracks[..end].last().map(|(pos, size)| pos + size);
+        let width = track_end(&self.columns, col_end).map_or(0.0, |end| end - x);
+        let height = track_end(&self.rows, row_end).map_or(0.0, |end| end - y);
 
         Some((x, y, width, height))
     }
@@ -1455,6 +1453,18 @@ mod tests {
         assert_eq!(layout.rows.len(), 2);
     }
 
+    #[test]
+    fn test_grid_layout_span_includes_gap() {
+        let template = GridTemplate::columns([TrackSize::px(40.0); 3])
+            .with_rows([TrackSize::px(20.0)])
+            .with_gap(8.0);
+        let layout = compute_grid_layout(&template, 200.0, 20.0, &[]);
+
+        let bounds = layout.area_bounds(&GridArea::row_span(0, 1, 3));
+        assert_eq!(bounds, Some((48.0, 0.0, 88.0, 20.0)));
+        assert_eq!(layout.width, 136.0);
+    }
+
     #[test]
     fn test_compute_grid_layout_with_gaps() {
         let template = GridTemplate::columns([TrackSize::fr(1.0), TrackSize::fr(1.0)])
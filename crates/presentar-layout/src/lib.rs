@@ -41,7 +41,10 @@ pub use compute_block::{
     GridCompositor, IntrinsicSize, Rect, Size, SizeHint,
 };
 pub use engine::LayoutEngine;
-pub use flex::{FlexAlign, FlexDirection, FlexItem, FlexJustify};
+pub use flex::{
+    compute_flex_layout, FlexAlign, FlexAlignContent, FlexContainer, FlexDirection, FlexItem,
+    FlexJustify, FlexWrap,
+};
 pub use grid::{
     auto_place_items, compute_grid_layout, GridAlign, GridArea, GridAutoFlow, GridItem, GridLayout,
     GridTemplate, TrackSize,
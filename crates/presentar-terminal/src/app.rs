@@ -33,7 +33,8 @@
 use crate::color::ColorMode;
 use crate::direct::{CellBuffer, DiffRenderer, DirectTerminalCanvas};
 use crate::error::{TuiError, VerificationError};
-use crate::input::InputHandler;
+use crate::input::{InputHandler, KeyDispatch};
+use crate::tools::HeadlessCanvas;
 use crossterm::{
     cursor,
     event::{self, Event as CrosstermEvent, KeyCode},
@@ -42,6 +43,9 @@ use crossterm::{
 };
 use presentar_core::{Constraints, Rect, Widget};
 use std::io::{self, Stdout, Write};
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 // =============================================================================
@@ -525,6 +529,10 @@ pub struct TuiConfig {
     pub skip_verification: bool,
     /// Target frame rate (used for budget calculation).
     pub target_fps: u32,
+    /// Terminal supports OSC 8 hyperlinks and curly/dotted/double underlines.
+    ///
+    /// Off by default; unsupported terminals get a plain underline instead.
+    pub extended_styles: bool,
 }
 
 impl Default for TuiConfig {
@@ -535,6 +543,7 @@ impl Default for TuiConfig {
             color_mode: None,
             target_fps: 60,
             skip_verification: false,
+            extended_styles: false,
         }
     }
 }
@@ -559,6 +568,13 @@ impl TuiConfig {
             ..Default::default()
         }
     }
+
+    /// Enable OSC 8 hyperlinks and curly/dotted/double underlines.
+    #[must_use]
+    pub const fn with_extended_styles(mut self, enabled: bool) -> Self {
+        self.extended_styles = enabled;
+        self
+    }
 }
 
 /// Frame timing metrics.
@@ -578,11 +594,91 @@ pub struct FrameMetrics {
     pub frame_count: u64,
 }
 
+/// Put the terminal back into cooked mode on the main screen.
+///
+/// Disables raw mode and mouse capture, leaves the alternate screen and shows
+/// the cursor on stdout. Safe to call when the terminal was never entered;
+/// the panic hook installed by [`TuiApp::run`] calls this before the panic
+/// message prints.
+///
+/// # Errors
+///
+/// Returns an error if the escape sequences cannot be written.
+pub fn restore_terminal() -> Result<(), TuiError> {
+    restore_backend(&mut CrosstermBackend::new())
+}
+
+/// [`restore_terminal`] through any backend.
+fn restore_backend<B: TerminalBackend>(backend: &mut B) -> Result<(), TuiError> {
+    backend.disable_raw_mode()?;
+    backend.disable_mouse_capture()?;
+    backend.leave_alternate_screen()?;
+    backend.show_cursor()
+}
+
+/// Panic hook that restores the terminal for the lifetime of the guard.
+///
+/// Only panics on the thread that installed the guard restore the terminal;
+/// a panicking worker thread leaves the running TUI alone. Dropping the guard
+/// disarms the hook and, outside of unwinding, reinstates the hook that was
+/// active before. A hook cannot be swapped while a panic unwinds, so after a
+/// panic caught with `catch_unwind` the disarmed hook stays installed and
+/// only forwards to the previous one.
+struct PanicHookGuard {
+    armed: Arc<AtomicBool>,
+    reinstate: Option<Box<dyn FnOnce()>>,
+}
+
+impl PanicHookGuard {
+    fn install() -> Self {
+        let previous = Arc::new(panic::take_hook());
+        let chained = Arc::clone(&previous);
+        let armed = Arc::new(AtomicBool::new(true));
+        let hook_armed = Arc::clone(&armed);
+        let owner = std::thread::current().id();
+        panic::set_hook(Box::new(move |info| {
+            if std::thread::current().id() == owner && hook_armed.swap(false, Ordering::SeqCst) {
+                let _ = restore_terminal();
+            }
+            chained(info);
+        }));
+
+        let reinstate = move || {
+            let _ = panic::take_hook();
+            panic::set_hook(Box::new(move |info| previous(info)));
+        };
+        Self {
+            armed,
+            reinstate: Some(Box::new(reinstate)),
+        }
+    }
+}
+
+impl Drop for PanicHookGuard {
+    fn drop(&mut self) {
+        self.armed.store(false, Ordering::SeqCst);
+        // The hook cannot be swapped while unwinding; it has already run.
+        if std::thread::panicking() {
+            return;
+        }
+        if let Some(reinstate) = self.reinstate.take() {
+            reinstate();
+        }
+    }
+}
+
+/// Callback receiving the root widget and the action of a completed binding.
+type ActionHandler<W> = Box<dyn FnMut(&mut W, &str)>;
+
+/// Canvas size used by [`TuiApp::run_headless`].
+const HEADLESS_SIZE: (u16, u16) = (80, 24);
+
 /// Main TUI application runner.
 pub struct TuiApp<W: Widget> {
     root: W,
     config: TuiConfig,
     input_handler: InputHandler,
+    action_handler: Option<ActionHandler<W>>,
     metrics: FrameMetrics,
     should_quit: bool,
     color_mode: ColorMode,
@@ -610,20 +706,8 @@ impl<W: Widget, T: Terminal> AppRunner<'_, W, T> {
                 self.renderer.reset();
             }
 
-            // Phase 1: Verify (Jidoka gate)
-            let verify_start = Instant::now();
-            if !self.app.config.skip_verification {
-                let verification = self.app.root.verify();
-                if !verification.is_valid() {
-                    return Err(TuiError::VerificationFailed(VerificationError::from(
-                        verification,
-                    )));
-                }
-            }
-            self.app.metrics.verify_time = verify_start.elapsed();
-
-            // Phase 2: Render frame
-            self.app.render_frame(&mut self.buffer);
+            // Phases 1-2: Verify (Jidoka gate) and render frame
+            self.app.step_frame(&mut self.buffer)?;
 
             // Phase 3: Flush to terminal
             self.terminal.flush(&mut self.buffer, &mut self.renderer)?;
@@ -633,20 +717,10 @@ impl<W: Widget, T: Terminal> AppRunner<'_, W, T> {
 
             // Phase 4: Handle input
             if self.terminal.poll(tick_duration)? {
-                if let CrosstermEvent::Key(key) = self.terminal.read_event()? {
-                    if key.code == KeyCode::Char('q')
-                        || key.code == KeyCode::Char('c')
-                            && key
-                                .modifiers
-                                .contains(crossterm::event::KeyModifiers::CONTROL)
-                    {
-                        self.app.should_quit = true;
-                    }
-
-                    if let Some(event) = self.app.input_handler.convert(CrosstermEvent::Key(key)) {
-                        let _ = self.app.root.event(&event);
-                    }
-                }
+                let event = self.terminal.read_event()?;
+                self.app.handle_input(event);
+            } else {
+                self.app.handle_idle();
             }
 
             if self.app.should_quit {
@@ -672,6 +746,7 @@ impl<W: Widget> TuiApp<W> {
             root,
             config: TuiConfig::default(),
             input_handler: InputHandler::new(),
+            action_handler: None,
             metrics: FrameMetrics::default(),
             should_quit: false,
             color_mode: ColorMode::detect(),
@@ -695,6 +770,18 @@ impl<W: Widget> TuiApp<W> {
         self
     }
 
+    /// Set the callback for actions of completed key bindings.
+    ///
+    /// Key presses are resolved through the input handler's bindings,
+    /// including multi-key sequences. A completed binding calls `handler`
+    /// with the root widget and the binding's action name instead of
+    /// delivering the key; the action `"quit"` stops the app instead.
+    #[must_use]
+    pub fn on_action(mut self, handler: impl FnMut(&mut W, &str) + 'static) -> Self {
+        self.action_handler = Some(Box::new(handler));
+        self
+    }
+
     /// Get a reference to the root widget.
     #[must_use]
     pub fn root(&self) -> &W {
@@ -718,7 +805,14 @@ impl<W: Widget> TuiApp<W> {
     }
 
     /// Run the application (blocking).
+    ///
+    /// A panic hook restores the terminal while the app runs, so a panicking
+    /// widget leaves a usable shell behind; the previous hook is reinstated
+    /// on return. Panics on other threads do not touch the terminal. If a
+    /// panic unwinds out of `run`, the hook is disarmed but stays installed,
+    /// forwarding to the previous hook.
     pub fn run(&mut self) -> Result<(), TuiError> {
+        let _panic_hook = PanicHookGuard::install();
         let backend = CrosstermBackend::new();
         let terminal = GenericTerminal::new(backend);
         self.run_with_terminal(terminal)
@@ -736,7 +830,8 @@ impl<W: Widget> TuiApp<W> {
         // Get initial terminal size
         let (width, height) = terminal.size()?;
         let buffer = CellBuffer::new(width, height);
-        let renderer = DiffRenderer::with_color_mode(self.color_mode);
+        let mut renderer = DiffRenderer::with_color_mode(self.color_mode);
+        renderer.set_extended_styles(self.config.extended_styles);
 
         let mut runner = AppRunner {
             app: self,
@@ -755,6 +850,113 @@ impl<W: Widget> TuiApp<W> {
         result
     }
 
+    /// Run a fixed number of frames against an in-memory canvas.
+    ///
+    /// Each frame handles the next scripted event (if any) the way the live
+    /// loop handles terminal input, then verifies and renders at 80x24
+    /// through the same frame step as [`run`](Self::run). The returned
+    /// canvas reflects every event fed within `frames`; the run stops early
+    /// once the app quits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TuiError::VerificationFailed`] if the root widget fails
+    /// verification on any frame.
+    pub fn run_headless(
+        &mut self,
+        frames: usize,
+        events: Vec<CrosstermEvent>,
+    ) -> Result<HeadlessCanvas, TuiError> {
+        let (width, height) = HEADLESS_SIZE;
+        let mut canvas = HeadlessCanvas::new(width, height).with_deterministic(true);
+        let mut events = events.into_iter();
+
+        for _ in 0..frames {
+            match events.next() {
+                Some(event) => self.handle_input(event),
+                None => self.handle_idle(),
+            }
+            if self.should_quit {
+                break;
+            }
+
+            let frame_start = Instant::now();
+            let mut result = Ok(());
+            canvas.render_frame(|canvas| result = self.step_frame(canvas.buffer_mut()));
+            result?;
+            self.metrics.total_time = frame_start.elapsed();
+            self.metrics.frame_count += 1;
+        }
+
+        Ok(canvas)
+    }
+
+    /// Verify the root widget (unless disabled) and render it into `buffer`.
+    fn step_frame(&mut self, buffer: &mut CellBuffer) -> Result<(), TuiError> {
+        let verify_start = Instant::now();
+        if !self.config.skip_verification {
+            let verification = self.root.verify();
+            if !verification.is_valid() {
+                return Err(TuiError::VerificationFailed(VerificationError::from(
+                    verification,
+                )));
+            }
+        }
+        self.metrics.verify_time = verify_start.elapsed();
+
+        self.render_frame(buffer);
+        Ok(())
+    }
+
+    /// Handle one terminal event: `q` / Ctrl+C quit, key presses are
+    /// resolved through the input handler's bindings, and mouse events go
+    /// to the root widget along with any clicks or drags they complete.
+    fn handle_input(&mut self, event: CrosstermEvent) {
+        if let CrosstermEvent::Mouse(_) = event {
+            for event in self.input_handler.process(event) {
+                let _ = self.root.event(&event);
+            }
+        } else if let CrosstermEvent::Key(key) = event {
+            if key.code == KeyCode::Char('q')
+                || key.code == KeyCode::Char('c')
+                    && key
+                        .modifiers
+                        .contains(crossterm::event::KeyModifiers::CONTROL)
+            {
+                self.should_quit = true;
+            }
+
+            let dispatches = self.input_handler.feed_key(key, Instant::now());
+            self.dispatch_keys(dispatches);
+        }
+    }
+
+    /// Release a lone sequence prefix once its window has passed.
+    fn handle_idle(&mut self) {
+        let expired = self.input_handler.flush_expired(Instant::now());
+        self.dispatch_keys(expired);
+    }
+
+    /// Deliver resolved key presses: actions to the action handler, plain
+    /// keys to the root widget.
+    fn dispatch_keys(&mut self, dispatches: Vec<KeyDispatch>) {
+        for dispatch in dispatches {
+            match dispatch {
+                KeyDispatch::Action(action) if action == "quit" => self.should_quit = true,
+                KeyDispatch::Action(action) => {
+                    if let Some(handler) = self.action_handler.as_mut() {
+                        handler(&mut self.root, &action);
+                    }
+                }
+                KeyDispatch::Key(key) => {
+                    if let Some(event) = self.input_handler.convert(CrosstermEvent::Key(key)) {
+                        let _ = self.root.event(&event);
+                    }
+                }
+            }
+        }
+    }
+
     fn render_frame(&mut self, buffer: &mut CellBuffer) {
         let width = buffer.width();
         let height = buffer.height();
@@ -8,17 +8,22 @@ use std::time::Duration;
 
 struct TestWidget {
     assertions: Vec<BrickAssertion>,
+    events: Vec<Event>,
 }
 
 impl TestWidget {
     fn new() -> Self {
         Self {
             assertions: vec![BrickAssertion::max_latency_ms(16)],
+            events: vec![],
         }
     }
 
     fn without_assertions() -> Self {
-        Self { assertions: vec![] }
+        Self {
+            assertions: vec![],
+            events: vec![],
+        }
     }
 }
 
@@ -71,7 +76,8 @@ impl Widget for TestWidget {
         canvas.fill_rect(Rect::new(0.0, 0.0, 10.0, 5.0), Color::BLUE);
     }
 
-    fn event(&mut self, _event: &Event) -> Option<Box<dyn Any + Send>> {
+    fn event(&mut self, event: &Event) -> Option<Box<dyn Any + Send>> {
+        self.events.push(event.clone());
         None
     }
 
@@ -135,6 +141,7 @@ fn test_tui_app_with_config() {
         color_mode: Some(ColorMode::Color256),
         skip_verification: false,
         target_fps: 30,
+        extended_styles: false,
     };
 
     app = app.with_config(config);
@@ -397,6 +404,7 @@ fn test_tui_config_all_fields() {
         color_mode: Some(ColorMode::Color16),
         skip_verification: true,
         target_fps: 30,
+        extended_styles: true,
     };
 
     assert_eq!(config.tick_rate_ms, 100);
@@ -404,6 +412,15 @@ fn test_tui_config_all_fields() {
     assert_eq!(config.color_mode, Some(ColorMode::Color16));
     assert!(config.skip_verification);
     assert_eq!(config.target_fps, 30);
+    assert!(config.extended_styles);
+}
+
+#[test]
+fn test_tui_config_with_extended_styles() {
+    assert!(!TuiConfig::default().extended_styles);
+    let config = TuiConfig::power_saving().with_extended_styles(true);
+    assert!(config.extended_styles);
+    assert_eq!(config.target_fps, 30);
 }
 
 #[test]
@@ -728,6 +745,56 @@ fn test_run_with_terminal_no_events() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_run_with_terminal_dispatches_sequence_action() {
+    use crate::input::KeyBinding;
+    use std::rc::Rc;
+
+    let key = |c| {
+        CrosstermEvent::Key(crossterm::event::KeyEvent::new(
+            KeyCode::Char(c),
+            crossterm::event::KeyModifiers::NONE,
+        ))
+    };
+    let mut handler = InputHandler::new();
+    handler.add_binding(KeyBinding::sequence(
+        [KeyCode::Char('g'), KeyCode::Char('g')],
+        "top",
+    ));
+    let actions = Rc::new(RefCell::new(Vec::new()));
+    let seen = Rc::clone(&actions);
+    let mut app = TuiApp::new(TestWidget::new())
+        .unwrap()
+        .with_input_handler(handler)
+        .on_action(move |_, action| seen.borrow_mut().push(action.to_string()));
+
+    let terminal = MockTerminal::new(80, 24)
+        .with_polls(vec![true, true, true])
+        .with_events(vec![key('g'), key('g'), key('q')]);
+
+    assert!(app.run_with_terminal(terminal).is_ok());
+    assert_eq!(*actions.borrow(), vec!["top".to_string()]);
+}
+
+#[test]
+fn test_run_with_terminal_quit_action() {
+    let mut handler = InputHandler::new();
+    handler.add_binding(crate::input::KeyBinding::simple(KeyCode::Esc, "quit"));
+    let mut app = TuiApp::new(TestWidget::new())
+        .unwrap()
+        .with_input_handler(handler);
+
+    let terminal = MockTerminal::new(80, 24)
+        .with_polls(vec![true])
+        .with_events(vec![CrosstermEvent::Key(crossterm::event::KeyEvent::new(
+            KeyCode::Esc,
+            crossterm::event::KeyModifiers::NONE,
+        ))]);
+
+    assert!(app.run_with_terminal(terminal).is_ok());
+    assert!(app.should_quit);
+}
+
 #[test]
 fn test_run_with_terminal_other_key() {
     let widget = TestWidget::new();
@@ -1022,6 +1089,65 @@ fn test_run_with_terminal_mouse_event() {
     assert!(result.is_ok());
 }
 
+fn mouse(kind: crossterm::event::MouseEventKind, column: u16, row: u16) -> CrosstermEvent {
+    CrosstermEvent::Mouse(crossterm::event::MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: crossterm::event::KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn test_handle_input_delivers_drag_to_root() {
+    use crossterm::event::{MouseButton as CtMouseButton, MouseEventKind};
+    use presentar_core::{MouseButton, Point};
+
+    let mut app = TuiApp::new(TestWidget::new()).unwrap();
+    app.handle_input(mouse(MouseEventKind::Down(CtMouseButton::Left), 2, 2));
+    app.handle_input(mouse(MouseEventKind::Drag(CtMouseButton::Left), 5, 4));
+    app.handle_input(mouse(MouseEventKind::Up(CtMouseButton::Left), 5, 4));
+
+    let events = &app.root().events;
+    assert!(matches!(events[0], Event::MouseDown { .. }));
+    assert!(events.contains(&Event::Drag {
+        start: Point::new(2.0, 2.0),
+        current: Point::new(5.0, 4.0),
+        button: MouseButton::Left,
+    }));
+    assert_eq!(
+        events.last(),
+        Some(&Event::DragEnd {
+            start: Point::new(2.0, 2.0),
+            end: Point::new(5.0, 4.0),
+            button: MouseButton::Left,
+        })
+    );
+    assert!(!events.iter().any(|e| matches!(e, Event::Click { .. })));
+}
+
+#[test]
+fn test_handle_input_delivers_double_click_to_root() {
+    use crossterm::event::{MouseButton as CtMouseButton, MouseEventKind};
+
+    let mut app = TuiApp::new(TestWidget::new()).unwrap();
+    for _ in 0..2 {
+        app.handle_input(mouse(MouseEventKind::Down(CtMouseButton::Left), 7, 3));
+        app.handle_input(mouse(MouseEventKind::Up(CtMouseButton::Left), 7, 3));
+    }
+
+    let counts: Vec<u8> = app
+        .root()
+        .events
+        .iter()
+        .filter_map(|e| match e {
+            Event::Click { count, .. } => Some(*count),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(counts, vec![1, 2]);
+}
+
 #[test]
 fn test_run_with_terminal_non_key_event_then_quit() {
     let widget = TestWidget::new();
@@ -1351,3 +1477,201 @@ fn test_testable_backend_captures_render_output() {
 
     terminal.leave().unwrap();
 }
+
+struct CounterWidget {
+    count: u32,
+    assertions: Vec<BrickAssertion>,
+}
+
+impl CounterWidget {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            assertions: vec![BrickAssertion::max_latency_ms(16)],
+        }
+    }
+}
+
+impl Brick for CounterWidget {
+    fn brick_name(&self) -> &'static str {
+        "counter"
+    }
+
+    fn assertions(&self) -> &[BrickAssertion] {
+        &self.assertions
+    }
+
+    fn budget(&self) -> BrickBudget {
+        BrickBudget::default()
+    }
+
+    fn verify(&self) -> BrickVerification {
+        BrickVerification {
+            passed: self.assertions.clone(),
+            failed: vec![],
+            verification_time: Duration::from_micros(10),
+        }
+    }
+
+    fn to_html(&self) -> String {
+        String::new()
+    }
+
+    fn to_css(&self) -> String {
+        String::new()
+    }
+}
+
+impl Widget for CounterWidget {
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<Self>()
+    }
+
+    fn measure(&self, constraints: Constraints) -> Size {
+        constraints.constrain(Size::new(20.0, 1.0))
+    }
+
+    fn layout(&mut self, bounds: Rect) -> LayoutResult {
+        LayoutResult {
+            size: Size::new(bounds.width, bounds.height),
+        }
+    }
+
+    fn paint(&self, canvas: &mut dyn Canvas) {
+        canvas.draw_text(
+            &format!("count: {}", self.count),
+            presentar_core::Point::new(0.0, 0.0),
+            &presentar_core::TextStyle::default(),
+        );
+    }
+
+    fn event(&mut self, event: &Event) -> Option<Box<dyn Any + Send>> {
+        if *event == Event::key_down(presentar_core::Key::Up) {
+            self.count += 1;
+        }
+        None
+    }
+
+    fn children(&self) -> &[Box<dyn Widget>] {
+        &[]
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        &mut []
+    }
+}
+
+fn headless_key(code: KeyCode) -> CrosstermEvent {
+    CrosstermEvent::Key(crossterm::event::KeyEvent::new(
+        code,
+        crossterm::event::KeyModifiers::NONE,
+    ))
+}
+
+#[test]
+fn test_run_headless_counter() {
+    let mut app = TuiApp::new(CounterWidget::new()).unwrap();
+    let increment = headless_key(KeyCode::Up);
+
+    let canvas = app
+        .run_headless(3, vec![increment.clone(), increment])
+        .unwrap();
+
+    assert_eq!(app.root().count, 2);
+    assert!(canvas.to_plain_string().starts_with("count: 2"));
+    assert_eq!(canvas.frame_count(), 3);
+    assert_eq!(app.metrics().frame_count, 3);
+}
+
+#[test]
+fn test_run_headless_drops_events_beyond_frames() {
+    let mut app = TuiApp::new(CounterWidget::new()).unwrap();
+    let increment = headless_key(KeyCode::Up);
+
+    let canvas = app
+        .run_headless(1, vec![increment.clone(), increment])
+        .unwrap();
+
+    assert_eq!(app.root().count, 1);
+    assert!(canvas.to_plain_string().starts_with("count: 1"));
+}
+
+#[test]
+fn test_run_headless_stops_after_quit() {
+    let mut app = TuiApp::new(CounterWidget::new()).unwrap();
+    app.quit();
+
+    let canvas = app.run_headless(5, vec![]).unwrap();
+
+    assert_eq!(canvas.frame_count(), 0);
+}
+
+#[test]
+fn test_run_headless_dispatches_keymap_actions() {
+    let mut handler = InputHandler::new();
+    handler.add_binding(crate::input::KeyBinding::simple(
+        KeyCode::Char('+'),
+        "increment",
+    ));
+    handler.add_binding(crate::input::KeyBinding::simple(KeyCode::Esc, "quit"));
+    let mut app = TuiApp::new(CounterWidget::new())
+        .unwrap()
+        .with_input_handler(handler)
+        .on_action(|counter, action| {
+            if action == "increment" {
+                counter.count += 1;
+            }
+        });
+
+    let plus = headless_key(KeyCode::Char('+'));
+    let canvas = app
+        .run_headless(5, vec![plus.clone(), plus, headless_key(KeyCode::Esc)])
+        .unwrap();
+
+    assert_eq!(app.root().count, 2);
+    assert!(canvas.to_plain_string().starts_with("count: 2"));
+    // The quit action stops the run before the third frame renders.
+    assert_eq!(canvas.frame_count(), 2);
+}
+
+#[test]
+fn test_run_headless_verification_failure() {
+    let mut app = TuiApp::new(FailingWidget).unwrap();
+
+    let result = app.run_headless(1, vec![]);
+
+    assert!(matches!(result, Err(TuiError::VerificationFailed(_))));
+}
+
+#[test]
+fn test_restore_backend_leaves_every_mode() {
+    let mut backend = TestableBackend::new(Vec::new(), 80, 24);
+    backend.enable_raw_mode().unwrap();
+    backend.enter_alternate_screen().unwrap();
+    backend.hide_cursor().unwrap();
+    backend.enable_mouse_capture().unwrap();
+
+    restore_backend(&mut backend).unwrap();
+
+    assert!(!backend.is_raw_mode());
+    assert!(!backend.is_alternate_screen());
+    assert!(!backend.is_cursor_hidden());
+    assert!(!backend.is_mouse_captured());
+    let output = String::from_utf8(backend.into_writer()).unwrap();
+    assert!(output.contains("\x1b[?1049l"), "leave alternate screen");
+    assert!(output.contains("\x1b[?25h"), "show cursor");
+    assert!(output.contains("\x1b[?1000l"), "disable mouse capture");
+}
+
+#[test]
+fn test_restore_backend_is_idempotent() {
+    let mut once = TestableBackend::new(Vec::new(), 80, 24);
+    restore_backend(&mut once).unwrap();
+    let mut twice = TestableBackend::new(Vec::new(), 80, 24);
+    restore_backend(&mut twice).unwrap();
+    restore_backend(&mut twice).unwrap();
+
+    assert!(!twice.is_raw_mode() && !twice.is_alternate_screen());
+    let once = once.into_writer();
+    assert_eq!(twice.into_writer(), [once.clone(), once].concat());
+}
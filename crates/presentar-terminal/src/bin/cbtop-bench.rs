@@ -233,6 +233,8 @@ fn benchmark_widget(widget_name: &str, cli: &Cli) -> BenchmarkResult {
                     cgroup: None,
                     nice: Some(0),
                     threads: None,
+                    read_bytes_per_sec: None,
+                    write_bytes_per_sec: None,
                     parent_pid: None,
                     tree_depth: 0,
                     is_last_child: false,
@@ -250,6 +252,8 @@ fn benchmark_widget(widget_name: &str, cli: &Cli) -> BenchmarkResult {
                     cgroup: None,
                     nice: Some(0),
                     threads: None,
+                    read_bytes_per_sec: None,
+                    write_bytes_per_sec: None,
                     parent_pid: None,
                     tree_depth: 0,
                     is_last_child: false,
@@ -17,16 +17,17 @@ use crossterm::{
 };
 
 use presentar_terminal::direct::{CellBuffer, DiffRenderer};
-use presentar_terminal::ptop::{config::PtopConfig, ui, App, PanelType};
+use presentar_terminal::ptop::replay::{SnapshotRecorder, SnapshotReplay};
+use presentar_terminal::ptop::{config::PtopConfig, ui, App, MetricsSnapshot, PanelType};
 use presentar_terminal::ColorMode;
 
 /// Presentar System Monitor - widget composition demo
 #[derive(Parser)]
 #[command(name = "ptop", version, about, long_about = None)]
 struct Cli {
-    /// Refresh interval in milliseconds
-    #[arg(short, long, default_value = "1000")]
-    refresh: u64,
+    /// Collection interval in milliseconds (overrides `refresh_ms` from the config)
+    #[arg(short = 'r', long, visible_alias = "refresh", value_name = "MS")]
+    interval: Option<u64>,
 
     /// Enable deterministic mode for testing (disables timestamps/dynamic data)
     #[arg(long)]
@@ -60,6 +61,18 @@ struct Cli {
     #[arg(long)]
     qa_timing: bool,
 
+    /// Record every collected snapshot to a JSON Lines file, replacing any previous recording
+    #[arg(long, value_name = "FILE", conflicts_with = "replay")]
+    record: Option<std::path::PathBuf>,
+
+    /// Replay a recording instead of collecting live metrics
+    #[arg(long, value_name = "FILE")]
+    replay: Option<std::path::PathBuf>,
+
+    /// Replay speed multiplier (2.0 = twice as fast)
+    #[arg(long, value_name = "X", default_value = "1.0", requires = "replay")]
+    replay_speed: f64,
+
     /// Explode a specific panel for QA (cpu, memory, disk, network, process, gpu, sensors, connections, psi, files, battery, containers)
     #[arg(long, value_name = "PANEL")]
     explode: Option<String>,
@@ -116,12 +129,13 @@ fn main() -> io::Result<()> {
         return Ok(());
     }
 
-    let config = load_config(cli.config.as_ref());
+    let config = load_config(cli.config.as_ref()).with_interval_ms(cli.interval);
 
     if cli.render_once {
         return handle_render_once(&cli, config);
     }
 
+    let feed = open_snapshot_feed(&cli)?;
     let app = App::with_config(cli.deterministic, config);
     let mut stdout = io::stdout();
 
@@ -132,7 +146,7 @@ fn main() -> io::Result<()> {
     } else {
         ColorMode::TrueColor
     };
-    let result = run_app(&mut stdout, app, cli.refresh, color_mode, cli.qa_timing);
+    let result = run_app(&mut stdout, app, feed, color_mode, cli.qa_timing);
 
     cleanup_terminal(&mut stdout)?;
     result
@@ -163,46 +177,147 @@ fn render_once(app: &App, width: u16, height: u16) -> io::Result<()> {
     Ok(())
 }
 
-/// Spawn background metrics collector thread.
-/// Returns (receiver, `running_flag`, `collect_time_atomic`).
-fn spawn_metrics_collector(
-    refresh_ms: u64,
-    deterministic: bool,
-) -> (
-    std::sync::mpsc::Receiver<presentar_terminal::ptop::app::MetricsSnapshot>,
+/// Where snapshots come from: live collection (optionally recorded) or a replay.
+enum SnapshotFeed {
+    Live(Option<SnapshotRecorder<io::BufWriter<std::fs::File>>>),
+    Replay(SnapshotReplay),
+}
+
+/// Open the `--record` / `--replay` files before entering the alternate screen.
+fn open_snapshot_feed(cli: &Cli) -> io::Result<SnapshotFeed> {
+    if let Some(path) = &cli.replay {
+        let file = std::fs::File::open(path)?;
+        let replay =
+            SnapshotReplay::from_reader(io::BufReader::new(file))?.with_speed(cli.replay_speed);
+        return Ok(SnapshotFeed::Replay(replay));
+    }
+    let recorder = match &cli.record {
+        Some(path) => {
+            // Offsets restart at zero for every session, so each recording
+            // gets a file of its own rather than extending an old one.
+            let file = std::fs::File::create(path)?;
+            Some(SnapshotRecorder::new(io::BufWriter::new(file)))
+        }
+        None => None,
+    };
+    Ok(SnapshotFeed::Live(recorder))
+}
+
+/// Message from the snapshot feed thread to the UI.
+enum FeedMessage {
+    /// Next snapshot to display
+    Snapshot(MetricsSnapshot),
+    /// Writing the recording failed; collection continues unrecorded
+    RecordingStopped(String),
+}
+
+/// (receiver, `running_flag`, `paused_flag`, `collect_time_atomic`)
+type FeedHandles = (
+    std::sync::mpsc::Receiver<FeedMessage>,
+    std::sync::Arc<std::sync::atomic::AtomicBool>,
     std::sync::Arc<std::sync::atomic::AtomicBool>,
     std::sync::Arc<std::sync::atomic::AtomicU64>,
-) {
+);
+
+/// Spawn the background thread for a snapshot feed.
+fn spawn_snapshot_feed(
+    feed: SnapshotFeed,
+    collect_interval: Duration,
+    deterministic: bool,
+) -> FeedHandles {
+    match feed {
+        SnapshotFeed::Live(recorder) => {
+            spawn_metrics_collector(collect_interval, deterministic, recorder)
+        }
+        SnapshotFeed::Replay(replay) => spawn_replay(replay),
+    }
+}
+
+/// Spawn a thread that sends recorded snapshots at their recorded spacing.
+/// The last frame stays on screen once the recording ends.
+fn spawn_replay(mut replay: SnapshotReplay) -> FeedHandles {
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+    use std::sync::{mpsc, Arc};
+
+    let bg_running = Arc::new(AtomicBool::new(true));
+    let bg_running_thread = Arc::clone(&bg_running);
+    let paused = Arc::new(AtomicBool::new(false));
+    let paused_thread = Arc::clone(&paused);
+
+    let (tx, rx) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        while let Some((delay, snapshot)) = replay.next_frame() {
+            let due = Instant::now() + delay;
+            while Instant::now() < due || paused_thread.load(Ordering::Relaxed) {
+                if !bg_running_thread.load(Ordering::Relaxed) {
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            if tx.send(FeedMessage::Snapshot(snapshot)).is_err() {
+                break;
+            }
+        }
+    });
+
+    (rx, bg_running, paused, Arc::new(AtomicU64::new(0)))
+}
+
+/// Spawn background metrics collector thread, recording snapshots if requested.
+fn spawn_metrics_collector(
+    collect_interval: Duration,
+    deterministic: bool,
+    mut recorder: Option<SnapshotRecorder<io::BufWriter<std::fs::File>>>,
+) -> FeedHandles {
     use presentar_terminal::ptop::app::MetricsCollector;
     use presentar_terminal::AsyncCollector;
     use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
     use std::sync::{mpsc, Arc};
 
-    let collect_interval = Duration::from_millis(refresh_ms);
     let collect_time_us = Arc::new(AtomicU64::new(0));
     let collect_time_bg = Arc::clone(&collect_time_us);
     let bg_running = Arc::new(AtomicBool::new(true));
     let bg_running_thread = Arc::clone(&bg_running);
+    let paused = Arc::new(AtomicBool::new(false));
+    let paused_thread = Arc::clone(&paused);
 
     let (tx, rx) = mpsc::channel();
 
     std::thread::spawn(move || {
         let mut collector = MetricsCollector::new(deterministic);
         while bg_running_thread.load(Ordering::Relaxed) {
+            if paused_thread.load(Ordering::Relaxed) {
+                std::thread::sleep(collect_interval.min(Duration::from_millis(100)));
+                continue;
+            }
             let collect_start = Instant::now();
             let snapshot = collector.collect();
             collect_time_bg.store(
                 collect_start.elapsed().as_micros() as u64,
                 Ordering::Relaxed,
             );
-            if tx.send(snapshot).is_err() {
+            // The terminal is in raw mode on the alternate screen, so errors
+            // go to the UI instead of stderr.
+            if let Some(rec) = recorder.as_mut() {
+                if let Err(e) = rec.record(&snapshot) {
+                    recorder = None;
+                    if tx
+                        .send(FeedMessage::RecordingStopped(e.to_string()))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            }
+            if tx.send(FeedMessage::Snapshot(snapshot)).is_err() {
                 break;
             }
             std::thread::sleep(collect_interval);
         }
     });
 
-    (rx, bg_running, collect_time_us)
+    (rx, bg_running, paused, collect_time_us)
 }
 
 /// Process all pending input events. Returns true if app should quit.
@@ -304,12 +419,20 @@ impl QaTimingState {
 }
 
 /// Apply all pending snapshots from the metrics collector.
-fn apply_pending_snapshots(
-    rx: &std::sync::mpsc::Receiver<presentar_terminal::ptop::MetricsSnapshot>,
-    app: &mut App,
-) {
-    while let Ok(snapshot) = rx.try_recv() {
-        app.apply_snapshot(snapshot);
+/// Snapshots still in flight when paused are dropped so the screen stays frozen.
+/// A failed recording is reported as an error toast.
+fn apply_pending_snapshots(rx: &std::sync::mpsc::Receiver<FeedMessage>, app: &mut App) {
+    while let Ok(message) = rx.try_recv() {
+        match message {
+            FeedMessage::Snapshot(snapshot) => {
+                if !app.paused {
+                    app.apply_snapshot(snapshot);
+                }
+            }
+            FeedMessage::RecordingStopped(error) => {
+                app.toast = Some((false, format!("Recording stopped: {error}"), Instant::now()));
+            }
+        }
     }
 }
 
@@ -346,14 +469,15 @@ fn record_qa_render(
 fn run_app(
     stdout: &mut io::Stdout,
     mut app: App,
-    refresh_ms: u64,
+    feed: SnapshotFeed,
     color_mode: ColorMode,
     qa_timing: bool,
 ) -> io::Result<()> {
     use std::sync::atomic::Ordering;
 
     let mut renderer = DiffRenderer::with_color_mode(color_mode);
-    let (rx, bg_running, collect_time_us) = spawn_metrics_collector(refresh_ms, app.deterministic);
+    let (rx, bg_running, paused, collect_time_us) =
+        spawn_snapshot_feed(feed, app.config.collect_interval(), app.deterministic);
 
     let render_interval = Duration::from_millis(16);
     let mut last_render = Instant::now();
@@ -368,6 +492,7 @@ fn run_app(
             return Ok(());
         }
         record_qa_input(qa_timing, &mut qa_state, input_start.elapsed());
+        paused.store(app.paused, Ordering::Relaxed);
 
         apply_pending_snapshots(&rx, &mut app);
 
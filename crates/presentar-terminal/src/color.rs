@@ -1,8 +1,32 @@
 //! Color mode detection and conversion for terminals.
 
+use crate::tools::{ciede2000, rgb_to_lab, Rgb};
 use crossterm::style::Color as CrosstermColor;
 use presentar_core::Color;
 
+/// Channel levels of the xterm 6x6x6 color cube (indices 16-231).
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Default xterm RGB values for the 16 ANSI colors.
+const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
 /// Terminal color capability mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ColorMode {
@@ -75,6 +99,70 @@ impl ColorMode {
         }
     }
 
+    /// Degrade a color to what this mode can actually display.
+    ///
+    /// Picks the nearest palette entry by CIEDE2000 distance: the xterm-256
+    /// cube and gray ramp for `Color256`, the default xterm ANSI colors for
+    /// `Color16`. Grays (r == g == b) only match the 24-step gray ramp plus
+    /// pure black and white, so they never pick up a tint from the cube.
+    /// `TrueColor` returns the color unchanged and `Mono` returns white,
+    /// mirroring [`ColorMode::to_crossterm`]. Alpha is preserved.
+    #[must_use]
+    pub fn quantize(&self, color: Color) -> Color {
+        if color.a == 0.0 {
+            return color;
+        }
+
+        let rgb = Rgb::new(
+            (color.r * 255.0).round() as u8,
+            (color.g * 255.0).round() as u8,
+            (color.b * 255.0).round() as u8,
+        );
+
+        let (r, g, b) = match self {
+            Self::TrueColor => return color,
+            Self::Mono => return Color::new(1.0, 1.0, 1.0, color.a),
+            Self::Color256 if rgb.r == rgb.g && rgb.g == rgb.b => {
+                Self::nearest(rgb, Self::gray_palette())
+            }
+            Self::Color256 => Self::nearest(rgb, Self::cube_palette().chain(Self::gray_palette())),
+            Self::Color16 => Self::nearest(rgb, ANSI16_PALETTE.into_iter()),
+        };
+
+        Color::new(
+            f32::from(r) / 255.0,
+            f32::from(g) / 255.0,
+            f32::from(b) / 255.0,
+            color.a,
+        )
+    }
+
+    /// Entries of the 6x6x6 color cube (indices 16-231).
+    fn cube_palette() -> impl Iterator<Item = (u8, u8, u8)> {
+        CUBE_LEVELS.into_iter().flat_map(|r| {
+            CUBE_LEVELS
+                .into_iter()
+                .flat_map(move |g| CUBE_LEVELS.into_iter().map(move |b| (r, g, b)))
+        })
+    }
+
+    /// Gray ramp (indices 232-255) plus the cube's pure black and white.
+    fn gray_palette() -> impl Iterator<Item = (u8, u8, u8)> {
+        std::iter::once(0)
+            .chain((0..24u8).map(|i| 8 + i * 10))
+            .chain(std::iter::once(255))
+            .map(|v| (v, v, v))
+    }
+
+    /// Nearest candidate to `rgb` by CIEDE2000 distance.
+    fn nearest(rgb: Rgb, candidates: impl Iterator<Item = (u8, u8, u8)>) -> (u8, u8, u8) {
+        let target = rgb_to_lab(rgb);
+        candidates
+            .map(|(r, g, b)| (ciede2000(target, rgb_to_lab(Rgb::new(r, g, b))), (r, g, b)))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map_or((rgb.r, rgb.g, rgb.b), |(_, c)| c)
+    }
+
     /// Convert RGB to 256-color palette index.
     fn rgb_to_256(r: u8, g: u8, b: u8) -> u8 {
         // Check for grayscale (r == g == b)
@@ -836,6 +924,70 @@ mod tests {
         let _ = c1.lerp(&c2, 0.5);
     }
 
+    #[test]
+    fn test_quantize_truecolor_identity() {
+        let c = Color::new(0.123, 0.456, 0.789, 1.0);
+        assert_eq!(ColorMode::TrueColor.quantize(c), c);
+    }
+
+    #[test]
+    fn test_quantize_mono_is_white() {
+        assert_eq!(ColorMode::Mono.quantize(Color::RED), Color::WHITE);
+    }
+
+    #[test]
+    fn test_quantize_preserves_transparent() {
+        assert_eq!(
+            ColorMode::Color16.quantize(Color::TRANSPARENT),
+            Color::TRANSPARENT
+        );
+    }
+
+    #[test]
+    fn test_quantize_256_exact_cube_entry() {
+        let c = Color::new(95.0 / 255.0, 135.0 / 255.0, 1.0, 1.0);
+        assert_eq!(ColorMode::Color256.quantize(c), c);
+        assert_eq!(ColorMode::Color256.quantize(Color::RED), Color::RED);
+    }
+
+    #[test]
+    fn test_quantize_256_grayscale_uses_gray_ramp() {
+        for v in [20u8, 50, 100, 128, 160, 200, 240] {
+            let f = f32::from(v) / 255.0;
+            let q = ColorMode::Color256.quantize(Color::rgb(f, f, f));
+            assert_eq!(q.r, q.g, "gray {v} picked up a tint");
+            assert_eq!(q.g, q.b, "gray {v} picked up a tint");
+            let level = (q.r * 255.0).round() as u8;
+            assert!(level == 0 || level == 255 || (level - 8) % 10 == 0);
+        }
+    }
+
+    #[test]
+    fn test_quantize_256_near_color() {
+        // (250, 10, 10) is closest to pure red in the cube
+        let c = Color::rgb(250.0 / 255.0, 10.0 / 255.0, 10.0 / 255.0);
+        assert_eq!(ColorMode::Color256.quantize(c), Color::RED);
+    }
+
+    #[test]
+    fn test_quantize_16_primaries() {
+        assert_eq!(ColorMode::Color16.quantize(Color::RED), Color::RED);
+        assert_eq!(ColorMode::Color16.quantize(Color::BLACK), Color::BLACK);
+        assert_eq!(ColorMode::Color16.quantize(Color::WHITE), Color::WHITE);
+    }
+
+    #[test]
+    fn test_quantize_16_mid_gray() {
+        let q = ColorMode::Color16.quantize(Color::rgb(0.5, 0.5, 0.5));
+        assert!((q.r - 127.0 / 255.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_quantize_preserves_alpha() {
+        let c = Color::new(0.3, 0.6, 0.9, 0.5);
+        assert_eq!(ColorMode::Color256.quantize(c).a, 0.5);
+    }
+
     #[test]
     fn test_detect_original_still_works() {
         // Ensure the original detect() still works
@@ -31,6 +31,9 @@
 //! - Fog, A. (2023): SIMD optimization patterns
 //! - Hennessy & Patterson (2017): Memory hierarchy optimization
 
+use std::any::Any;
+use std::collections::HashMap;
+
 /// SIMD instruction set identifier
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SimdInstructionSet {
@@ -213,6 +216,9 @@ pub enum ComputeBlockId {
     ProcNiceValue,     // CB-PROC-005
     ProcThreadCount,   // CB-PROC-006
     ProcCgroup,        // CB-PROC-007
+
+    // Disk Panel (CB-DISK-*)
+    DiskPressure, // CB-DISK-001
 }
 
 impl ComputeBlockId {
@@ -253,6 +259,7 @@ impl ComputeBlockId {
             Self::ProcNiceValue => "CB-PROC-005",
             Self::ProcThreadCount => "CB-PROC-006",
             Self::ProcCgroup => "CB-PROC-007",
+            Self::DiskPressure => "CB-DISK-001",
         }
     }
 
@@ -295,7 +302,8 @@ impl ComputeBlockId {
             | Self::ProcTreeView
             | Self::ProcSortIndicator
             | Self::ProcFilter
-            | Self::ProcCgroup => false,
+            | Self::ProcCgroup
+            | Self::DiskPressure => false,
         }
     }
 }
@@ -1249,6 +1257,315 @@ impl ComputeBlock for GpuVramBlock {
     }
 }
 
+/// Disk Pressure `ComputeBlock` (CB-DISK-001)
+///
+/// Derives a 0-100 pressure score from I/O queue depth and device
+/// utilization (e.g. `DiskIoAnalyzer` rates). The score is smoothed with an
+/// EMA so a single busy sample does not flip the level.
+#[derive(Debug, Clone)]
+pub struct DiskPressureBlock {
+    /// Average I/O queue depth (requests in flight)
+    queue_depth: f32,
+    /// Device utilization (0-100%)
+    utilization: f32,
+    /// Smoothed pressure score (0-100)
+    score: f32,
+    /// Sample count
+    samples: u64,
+    /// Instruction set
+    instruction_set: SimdInstructionSet,
+}
+
+impl Default for DiskPressureBlock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DiskPressureBlock {
+    /// Queue depth at which the queue component saturates
+    pub const SATURATION_QUEUE_DEPTH: f32 = 8.0;
+    /// EMA smoothing factor for the score
+    const ALPHA: f32 = 0.3;
+
+    /// Create a new disk pressure block
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            queue_depth: 0.0,
+            utilization: 0.0,
+            score: 0.0,
+            samples: 0,
+            instruction_set: SimdInstructionSet::detect(),
+        }
+    }
+
+    /// Set queue depth and utilization, updating the score (O(1))
+    pub fn set_values(&mut self, queue_depth: f32, utilization: f32) {
+        debug_assert!(queue_depth >= 0.0, "queue_depth must be non-negative");
+        debug_assert!(utilization >= 0.0, "utilization must be non-negative");
+        self.queue_depth = queue_depth;
+        self.utilization = utilization.clamp(0.0, 100.0);
+
+        let raw = self.raw_score();
+        self.score = if self.samples == 0 {
+            raw
+        } else {
+            Self::ALPHA * raw + (1.0 - Self::ALPHA) * self.score
+        };
+        self.samples += 1;
+    }
+
+    /// Unsmoothed score for the current sample (60% utilization, 40% queue)
+    #[must_use]
+    pub fn raw_score(&self) -> f32 {
+        let queue = (self.queue_depth / Self::SATURATION_QUEUE_DEPTH).min(1.0) * 100.0;
+        (0.6 * self.utilization + 0.4 * queue).clamp(0.0, 100.0)
+    }
+
+    /// Smoothed pressure score (0-100)
+    #[must_use]
+    pub fn pressure_score(&self) -> f32 {
+        self.score
+    }
+
+    /// Get pressure level indicator
+    #[must_use]
+    pub fn pressure_level(&self) -> DiskPressureLevel {
+        DiskPressureLevel::from_score(self.score)
+    }
+}
+
+impl ComputeBlock for DiskPressureBlock {
+    type Input = (f32, f32); // (queue_depth, utilization_pct)
+    type Output = DiskPressureLevel;
+
+    fn compute(&mut self, input: &Self::Input) -> Self::Output {
+        self.set_values(input.0, input.1);
+        self.pressure_level()
+    }
+
+    fn simd_instruction_set(&self) -> SimdInstructionSet {
+        self.instruction_set
+    }
+
+    fn latency_budget_us(&self) -> u64 {
+        20 // Simple arithmetic
+    }
+}
+
+/// Disk pressure level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiskPressureLevel {
+    /// No pressure (<10)
+    None,
+    /// Low pressure (10-30)
+    Low,
+    /// Medium pressure (30-60)
+    Medium,
+    /// High pressure (60-85)
+    High,
+    /// Critical pressure (>85)
+    Critical,
+}
+
+impl DiskPressureLevel {
+    /// Classify a 0-100 pressure score
+    #[must_use]
+    pub fn from_score(score: f32) -> Self {
+        if score >= 85.0 {
+            Self::Critical
+        } else if score >= 60.0 {
+            Self::High
+        } else if score >= 30.0 {
+            Self::Medium
+        } else if score >= 10.0 {
+            Self::Low
+        } else {
+            Self::None
+        }
+    }
+
+    /// Get the display character for this pressure level
+    #[must_use]
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::None => ' ',
+            Self::Low => '○',
+            Self::Medium => '◐',
+            Self::High => '◕',
+            Self::Critical => '●',
+        }
+    }
+
+    /// Get color index (0=green, 4=red)
+    #[must_use]
+    pub const fn severity(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Low => 1,
+            Self::Medium => 2,
+            Self::High => 3,
+            Self::Critical => 4,
+        }
+    }
+}
+
+// =============================================================================
+// ComputeBlockRegistry: heterogeneous blocks updated together
+// =============================================================================
+
+/// Object-safe view of a registered block and its input source.
+trait RegisteredBlock {
+    fn update(&mut self);
+    fn block(&self) -> &dyn Any;
+    fn block_mut(&mut self) -> &mut dyn Any;
+    fn output(&self) -> Option<&dyn Any>;
+    fn latency_budget_us(&self) -> u64;
+}
+
+/// A block paired with the closure that supplies its input.
+struct BlockEntry<B: ComputeBlock, F> {
+    block: B,
+    input: F,
+    output: Option<B::Output>,
+}
+
+impl<B, F> RegisteredBlock for BlockEntry<B, F>
+where
+    B: ComputeBlock + 'static,
+    B::Output: 'static,
+    F: FnMut() -> B::Input,
+{
+    fn update(&mut self) {
+        let input = (self.input)();
+        self.output = Some(self.block.compute(&input));
+    }
+
+    fn block(&self) -> &dyn Any {
+        &self.block
+    }
+
+    fn block_mut(&mut self) -> &mut dyn Any {
+        &mut self.block
+    }
+
+    fn output(&self) -> Option<&dyn Any> {
+        self.output.as_ref().map(|o| o as &dyn Any)
+    }
+
+    fn latency_budget_us(&self) -> u64 {
+        self.block.latency_budget_us()
+    }
+}
+
+/// Registry of heterogeneous `ComputeBlock`s keyed by `ComputeBlockId`.
+///
+/// Parallels `AnalyzerRegistry`: each block is registered with an input
+/// source, `update_all()` recomputes every block, and callers query blocks
+/// or their latest outputs by id with the concrete type.
+#[derive(Default)]
+pub struct ComputeBlockRegistry {
+    blocks: HashMap<ComputeBlockId, Box<dyn RegisteredBlock>>,
+}
+
+impl std::fmt::Debug for ComputeBlockRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComputeBlockRegistry")
+            .field("blocks", &self.blocks.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl ComputeBlockRegistry {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `block` under `id`, fed by `input` on every update.
+    ///
+    /// Replaces any block already registered under `id`.
+    pub fn register<B, F>(&mut self, id: ComputeBlockId, block: B, input: F)
+    where
+        B: ComputeBlock + 'static,
+        B::Output: 'static,
+        F: FnMut() -> B::Input + 'static,
+    {
+        self.blocks.insert(
+            id,
+            Box::new(BlockEntry {
+                block,
+                input,
+                output: None,
+            }),
+        );
+    }
+
+    /// Remove the block registered under `id`, returning whether one existed
+    pub fn unregister(&mut self, id: ComputeBlockId) -> bool {
+        self.blocks.remove(&id).is_some()
+    }
+
+    /// Recompute every registered block from its input source
+    pub fn update_all(&mut self) {
+        for block in self.blocks.values_mut() {
+            block.update();
+        }
+    }
+
+    /// Get the block registered under `id`, if it has type `B`
+    #[must_use]
+    pub fn get<B: ComputeBlock + 'static>(&self, id: ComputeBlockId) -> Option<&B> {
+        self.blocks.get(&id)?.block().downcast_ref()
+    }
+
+    /// Get the block registered under `id` mutably, if it has type `B`
+    pub fn get_mut<B: ComputeBlock + 'static>(&mut self, id: ComputeBlockId) -> Option<&mut B> {
+        self.blocks.get_mut(&id)?.block_mut().downcast_mut()
+    }
+
+    /// Latest output of the block under `id` (`None` before the first update)
+    #[must_use]
+    pub fn output<B>(&self, id: ComputeBlockId) -> Option<&B::Output>
+    where
+        B: ComputeBlock + 'static,
+        B::Output: 'static,
+    {
+        self.blocks.get(&id)?.output()?.downcast_ref()
+    }
+
+    /// Check if a block is registered under `id`
+    #[must_use]
+    pub fn contains(&self, id: ComputeBlockId) -> bool {
+        self.blocks.contains_key(&id)
+    }
+
+    /// Registered block ids (unordered)
+    pub fn ids(&self) -> impl Iterator<Item = ComputeBlockId> + '_ {
+        self.blocks.keys().copied()
+    }
+
+    /// Number of registered blocks
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Check if no blocks are registered
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Sum of the latency budgets of all registered blocks
+    #[must_use]
+    pub fn total_latency_budget_us(&self) -> u64 {
+        self.blocks.values().map(|b| b.latency_budget_us()).sum()
+    }
+}
+
 // =============================================================================
 // MetricsCacheBlock: O(1) Cached Metrics for ptop Performance
 // =============================================================================
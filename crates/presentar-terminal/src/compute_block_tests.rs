@@ -331,6 +331,100 @@ fn test_mem_pressure_block_compute() {
     assert_eq!(output, MemoryPressureLevel::High);
 }
 
+#[test]
+fn test_disk_pressure_block_high_utilization() {
+    let mut block = DiskPressureBlock::new();
+    assert_eq!(block.compute(&(0.0, 2.0)), DiskPressureLevel::None);
+
+    let mut level = DiskPressureLevel::None;
+    for _ in 0..10 {
+        level = block.compute(&(12.0, 98.0));
+    }
+    assert!(level.severity() >= DiskPressureLevel::High.severity());
+    assert_eq!(level, DiskPressureLevel::Critical);
+    assert!(block.pressure_score() > 85.0);
+}
+
+#[test]
+fn test_disk_pressure_block_smoothing() {
+    let mut block = DiskPressureBlock::new();
+    block.set_values(0.0, 0.0);
+    // A single saturated sample is damped by the EMA
+    block.set_values(8.0, 100.0);
+    assert!((block.raw_score() - 100.0).abs() < 0.01);
+    assert!((block.pressure_score() - 30.0).abs() < 0.01);
+    assert_ne!(block.pressure_level(), DiskPressureLevel::Critical);
+}
+
+#[test]
+fn test_disk_pressure_level_thresholds() {
+    assert_eq!(DiskPressureLevel::from_score(5.0), DiskPressureLevel::None);
+    assert_eq!(DiskPressureLevel::from_score(20.0), DiskPressureLevel::Low);
+    assert_eq!(
+        DiskPressureLevel::from_score(45.0),
+        DiskPressureLevel::Medium
+    );
+    assert_eq!(DiskPressureLevel::from_score(70.0), DiskPressureLevel::High);
+    assert_eq!(
+        DiskPressureLevel::from_score(90.0),
+        DiskPressureLevel::Critical
+    );
+    assert_eq!(ComputeBlockId::DiskPressure.id_string(), "CB-DISK-001");
+}
+
+#[test]
+fn test_registry_update_all_and_get() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let utilization = Rc::new(Cell::new(10.0_f32));
+    let source = Rc::clone(&utilization);
+
+    let mut registry = ComputeBlockRegistry::new();
+    registry.register(
+        ComputeBlockId::MemPressureGauge,
+        MemPressureBlock::new(),
+        || (30.0, 25.0, 20.0, 15.0),
+    );
+    registry.register(
+        ComputeBlockId::DiskPressure,
+        DiskPressureBlock::new(),
+        move || (8.0, source.get()),
+    );
+    assert_eq!(registry.len(), 2);
+    assert!(registry
+        .output::<MemPressureBlock>(ComputeBlockId::MemPressureGauge)
+        .is_none());
+
+    registry.update_all();
+    assert_eq!(
+        registry.output::<MemPressureBlock>(ComputeBlockId::MemPressureGauge),
+        Some(&MemoryPressureLevel::High)
+    );
+    let disk = registry
+        .get::<DiskPressureBlock>(ComputeBlockId::DiskPressure)
+        .unwrap();
+    assert!((disk.pressure_score() - 46.0).abs() < 0.01);
+
+    // Inputs are re-read on every update
+    utilization.set(100.0);
+    registry.update_all();
+    let disk = registry
+        .get::<DiskPressureBlock>(ComputeBlockId::DiskPressure)
+        .unwrap();
+    assert!(disk.pressure_score() > 46.0);
+
+    // Wrong type or unknown id yields None
+    assert!(registry
+        .get::<MemPressureBlock>(ComputeBlockId::DiskPressure)
+        .is_none());
+    assert!(registry
+        .get::<MemPressureBlock>(ComputeBlockId::CpuLoadGauge)
+        .is_none());
+    assert!(registry.unregister(ComputeBlockId::DiskPressure));
+    assert!(!registry.contains(ComputeBlockId::DiskPressure));
+}
+
 #[test]
 fn test_huge_pages_block_compute() {
     let mut block = HugePagesBlock::new();
@@ -6,8 +6,27 @@
 use bitvec::prelude::*;
 use compact_str::CompactString;
 use presentar_core::Color;
+use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+/// Underline rendering style.
+///
+/// Terminals without extended underline support render every style as
+/// a plain (straight) underline.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnderlineStyle {
+    /// Single straight underline.
+    #[default]
+    Straight,
+    /// Curly (wavy) underline, as used for spell-check squiggles.
+    Curly,
+    /// Dotted underline.
+    Dotted,
+    /// Double underline.
+    Double,
+}
+
 /// Text modifiers for terminal cells.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Modifiers(u8);
@@ -113,6 +132,10 @@ pub struct Cell {
     pub modifiers: Modifiers,
     /// Display width of the symbol (1 for normal, 2 for wide chars, 0 for continuation).
     width: u8,
+    /// Underline style used when [`Modifiers::UNDERLINE`] is set.
+    underline: UnderlineStyle,
+    /// OSC 8 hyperlink target, shared between the cells of one link.
+    hyperlink: Option<Arc<str>>,
 }
 
 impl Default for Cell {
@@ -124,6 +147,8 @@ impl Default for Cell {
             bg: Color::TRANSPARENT,
             modifiers: Modifiers::NONE,
             width: 1,
+            underline: UnderlineStyle::Straight,
+            hyperlink: None,
         }
     }
 }
@@ -139,10 +164,57 @@ impl Cell {
             bg,
             modifiers,
             width: width.max(1),
+            underline: UnderlineStyle::Straight,
+            hyperlink: None,
+        }
+    }
+
+    /// Underline this cell with the given style (sets [`Modifiers::UNDERLINE`]).
+    #[must_use]
+    pub const fn with_underline_style(mut self, style: UnderlineStyle) -> Self {
+        self.modifiers = self.modifiers.with(Modifiers::UNDERLINE);
+        self.underline = style;
+        self
+    }
+
+    /// Get the underline style, or `None` if the cell is not underlined.
+    #[must_use]
+    pub const fn underline_style(&self) -> Option<UnderlineStyle> {
+        if self.modifiers.contains(Modifiers::UNDERLINE) {
+            Some(self.underline)
+        } else {
+            None
         }
     }
 
+    /// Attach an OSC 8 hyperlink target to this cell.
+    #[must_use]
+    pub fn with_hyperlink(mut self, url: &str) -> Self {
+        self.hyperlink = Some(Arc::from(url));
+        self
+    }
+
+    /// Attach a shared hyperlink target (cheap for multi-cell links).
+    pub fn set_hyperlink(&mut self, url: Option<Arc<str>>) {
+        self.hyperlink = url;
+    }
+
+    /// Get the hyperlink target, if any.
+    #[must_use]
+    pub fn hyperlink(&self) -> Option<&str> {
+        self.hyperlink.as_deref()
+    }
+
+    /// Get the shared hyperlink target, if any.
+    #[must_use]
+    pub(crate) const fn hyperlink_arc(&self) -> Option<&Arc<str>> {
+        self.hyperlink.as_ref()
+    }
+
     /// Update the cell content (zero-allocation for small strings).
+    ///
+    /// Clears any hyperlink and underline style, since the new content is
+    /// unrelated to them.
     pub fn update(&mut self, symbol: &str, fg: Color, bg: Color, modifiers: Modifiers) {
         self.symbol.clear();
         self.symbol.push_str(symbol);
@@ -150,6 +222,8 @@ impl Cell {
         self.bg = bg;
         self.modifiers = modifiers;
         self.width = UnicodeWidthStr::width(symbol).clamp(1, 255) as u8;
+        self.underline = UnderlineStyle::Straight;
+        self.hyperlink = None;
     }
 
     /// Mark this cell as a continuation of a wide character.
@@ -178,6 +252,8 @@ impl Cell {
         self.bg = Color::TRANSPARENT;
         self.modifiers = Modifiers::NONE;
         self.width = 1;
+        self.underline = UnderlineStyle::Straight;
+        self.hyperlink = None;
     }
 }
 
@@ -276,15 +352,25 @@ impl CellBuffer {
     }
 
     /// Set a cell and mark it dirty.
+    ///
+    /// A wide cell claims the cell to its right as a continuation.
     pub fn set(&mut self, x: u16, y: u16, cell: Cell) {
         if x < self.width && y < self.height {
             let idx = self.index(x, y);
+            if !cell.is_continuation() {
+                self.detach_wide(x, y);
+            }
             self.cells[idx] = cell;
             self.dirty.set(idx, true);
+            self.claim_continuation(x, y);
         }
     }
 
     /// Update a cell's content and mark it dirty.
+    ///
+    /// Wide (CJK / emoji) symbols occupy two columns: the cell to the right
+    /// becomes a continuation cell that the renderer skips. A wide symbol
+    /// that does not fit in the last column is replaced by a space.
     pub fn update(
         &mut self,
         x: u16,
@@ -296,9 +382,52 @@ impl CellBuffer {
     ) {
         if x < self.width && y < self.height {
             let idx = self.index(x, y);
+            self.detach_wide(x, y);
             self.cells[idx].update(symbol, fg, bg, modifiers);
             self.dirty.set(idx, true);
+            self.claim_continuation(x, y);
+        }
+    }
+
+    /// Break the link between (x, y) and the other half of a wide character.
+    ///
+    /// Overwriting either half of a wide glyph leaves the other half
+    /// meaningless, so it is blanked.
+    fn detach_wide(&mut self, x: u16, y: u16) {
+        let idx = self.index(x, y);
+        if self.cells[idx].is_continuation() {
+            if x > 0 && self.cells[idx - 1].width() > 1 {
+                self.blank(idx - 1);
+            }
+        } else if self.cells[idx].width() > 1
+            && x + 1 < self.width
+            && self.cells[idx + 1].is_continuation()
+        {
+            self.blank(idx + 1);
+        }
+    }
+
+    /// Mark the cell right of a wide symbol at (x, y) as its continuation.
+    fn claim_continuation(&mut self, x: u16, y: u16) {
+        let idx = self.index(x, y);
+        if self.cells[idx].width() <= 1 {
+            return;
         }
+        if x + 1 < self.width {
+            self.detach_wide(x + 1, y);
+            self.cells[idx + 1].make_continuation();
+            self.dirty.set(idx + 1, true);
+        } else {
+            self.blank(idx);
+        }
+    }
+
+    /// Replace a cell's symbol with a space, keeping its style.
+    fn blank(&mut self, idx: usize) {
+        let cell = &mut self.cells[idx];
+        let (fg, bg, modifiers) = (cell.fg, cell.bg, cell.modifiers);
+        cell.update(" ", fg, bg, modifiers);
+        self.dirty.set(idx, true);
     }
 
     /// Mark a cell as dirty.
@@ -374,24 +503,46 @@ impl CellBuffer {
 
     /// Set a single character at the given position (keeps existing colors/modifiers).
     pub fn set_char(&mut self, x: u16, y: u16, ch: char) {
-        if let Some(cell) = self.get_mut(x, y) {
-            let mut buf = [0u8; 4];
-            let s = ch.encode_utf8(&mut buf);
-            cell.symbol = CompactString::from(&*s);
-            self.mark_dirty(x, y);
+        let mut buf = [0u8; 4];
+        self.set_symbol(x, y, ch.encode_utf8(&mut buf));
+    }
+
+    /// Set a symbol at the given position (keeps existing colors/modifiers).
+    fn set_symbol(&mut self, x: u16, y: u16, symbol: &str) {
+        if let Some(cell) = self.get(x, y) {
+            let (fg, bg, modifiers) = (cell.fg, cell.bg, cell.modifiers);
+            self.update(x, y, symbol, fg, bg, modifiers);
         }
     }
 
     /// Write a string starting at the given position (keeps existing colors/modifiers).
-    pub fn write_str(&mut self, x: u16, y: u16, s: &str) {
+    ///
+    /// Advances by display width, so wide graphemes take two columns.
+    /// Returns the number of columns consumed.
+    pub fn write_str(&mut self, x: u16, y: u16, s: &str) -> u16 {
         let mut cx = x;
-        for ch in s.chars() {
-            self.set_char(cx, y, ch);
-            cx = cx.saturating_add(1);
+        for grapheme in s.graphemes(true) {
             if cx >= self.width {
                 break;
             }
+            self.set_symbol(cx, y, grapheme);
+            let advance = UnicodeWidthStr::width(grapheme).clamp(1, 2) as u16;
+            cx = cx.saturating_add(advance).min(self.width);
         }
+        cx.saturating_sub(x)
+    }
+
+    /// Text of each row, the cell symbols joined left to right.
+    #[cfg(test)]
+    pub(crate) fn rows_as_strings(&self) -> Vec<String> {
+        (0..self.height)
+            .map(|y| {
+                (0..self.width)
+                    .filter_map(|x| self.get(x, y))
+                    .map(|cell| cell.symbol.as_str())
+                    .collect()
+            })
+            .collect()
     }
 }
 
@@ -693,4 +844,92 @@ mod tests {
         assert!(all.contains(Modifiers::REVERSE));
         assert!(all.contains(Modifiers::HIDDEN));
     }
+
+    #[test]
+    fn test_buffer_wide_chars_take_two_columns() {
+        let mut buf = CellBuffer::new(10, 1);
+        let used = buf.write_str(0, 0, "日本");
+        assert_eq!(used, 4);
+        assert_eq!(buf.get(0, 0).unwrap().symbol.as_str(), "日");
+        assert!(buf.get(1, 0).unwrap().is_continuation());
+        assert_eq!(buf.get(2, 0).unwrap().symbol.as_str(), "本");
+        assert!(buf.get(3, 0).unwrap().is_continuation());
+        assert_eq!(buf.get(4, 0).unwrap().symbol.as_str(), " ");
+        // Continuation cells add nothing to the row text
+        assert_eq!(buf.rows_as_strings(), vec!["日本      "]);
+
+        let columns: u16 = buf.cells().iter().map(|c| u16::from(c.width())).sum();
+        assert_eq!(columns, 10);
+    }
+
+    #[test]
+    fn test_buffer_overwrite_wide_char_halves() {
+        let mut buf = CellBuffer::new(4, 1);
+        buf.update(0, 0, "日", Color::WHITE, Color::BLACK, Modifiers::NONE);
+        buf.update(1, 0, "x", Color::WHITE, Color::BLACK, Modifiers::NONE);
+        assert_eq!(buf.get(0, 0).unwrap().symbol.as_str(), " ");
+        assert_eq!(buf.get(1, 0).unwrap().symbol.as_str(), "x");
+
+        buf.update(2, 0, "本", Color::WHITE, Color::BLACK, Modifiers::NONE);
+        buf.update(2, 0, "y", Color::WHITE, Color::BLACK, Modifiers::NONE);
+        assert!(!buf.get(3, 0).unwrap().is_continuation());
+        assert_eq!(buf.get(3, 0).unwrap().width(), 1);
+    }
+
+    #[test]
+    fn test_buffer_wide_char_at_last_column() {
+        let mut buf = CellBuffer::new(3, 1);
+        buf.update(2, 0, "日", Color::WHITE, Color::BLACK, Modifiers::NONE);
+        assert_eq!(buf.get(2, 0).unwrap().symbol.as_str(), " ");
+        assert_eq!(buf.get(2, 0).unwrap().width(), 1);
+    }
+
+    #[test]
+    fn test_buffer_set_char_updates_width() {
+        let mut buf = CellBuffer::new(4, 1);
+        buf.set_char(0, 0, '日');
+        assert_eq!(buf.get(0, 0).unwrap().width(), 2);
+        assert!(buf.get(1, 0).unwrap().is_continuation());
+    }
+
+    #[test]
+    fn test_cell_underline_style() {
+        let plain = Cell::new("a", Color::WHITE, Color::BLACK, Modifiers::NONE);
+        assert_eq!(plain.underline_style(), None);
+        let straight = Cell::new("a", Color::WHITE, Color::BLACK, Modifiers::UNDERLINE);
+        assert_eq!(straight.underline_style(), Some(UnderlineStyle::Straight));
+
+        let curly = Cell::new("a", Color::WHITE, Color::BLACK, Modifiers::BOLD)
+            .with_underline_style(UnderlineStyle::Curly);
+        assert!(curly.modifiers.contains(Modifiers::BOLD));
+        assert!(curly.modifiers.contains(Modifiers::UNDERLINE));
+        assert_eq!(curly.underline_style(), Some(UnderlineStyle::Curly));
+
+        let double = curly.clone().with_underline_style(UnderlineStyle::Double);
+        assert_eq!(double.underline_style(), Some(UnderlineStyle::Double));
+        assert_ne!(double, curly);
+
+        let mut removed = double;
+        removed.modifiers = removed.modifiers.without(Modifiers::UNDERLINE);
+        assert_eq!(removed.underline_style(), None);
+
+        let mut updated = curly;
+        updated.update("b", Color::WHITE, Color::BLACK, Modifiers::UNDERLINE);
+        assert_eq!(updated.underline_style(), Some(UnderlineStyle::Straight));
+    }
+
+    #[test]
+    fn test_cell_hyperlink() {
+        let cell = Cell::new("a", Color::WHITE, Color::BLACK, Modifiers::NONE)
+            .with_hyperlink("https://example.com");
+        assert_eq!(cell.hyperlink(), Some("https://example.com"));
+
+        let mut updated = cell.clone();
+        updated.update("b", Color::WHITE, Color::BLACK, Modifiers::NONE);
+        assert_eq!(updated.hyperlink(), None);
+
+        let mut reset = cell;
+        reset.reset();
+        assert_eq!(reset.hyperlink(), None);
+    }
 }
@@ -6,7 +6,7 @@
 //! - Skipping redundant cursor moves
 //! - Caching current style state
 
-use super::cell_buffer::{CellBuffer, Modifiers};
+use super::cell_buffer::{CellBuffer, Modifiers, UnderlineStyle};
 use crate::color::ColorMode;
 use crossterm::cursor::MoveTo;
 use crossterm::style::{
@@ -16,6 +16,7 @@ use crossterm::style::{
 use crossterm::{queue, QueueableCommand};
 use presentar_core::Color;
 use std::io::{self, BufWriter, Write};
+use std::sync::Arc;
 
 /// Current terminal style state.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -23,6 +24,7 @@ struct StyleState {
     fg: Color,
     bg: Color,
     modifiers: Modifiers,
+    underline: Option<UnderlineStyle>,
 }
 
 impl Default for StyleState {
@@ -31,10 +33,68 @@ impl Default for StyleState {
             fg: Color::WHITE,
             bg: Color::BLACK,
             modifiers: Modifiers::NONE,
+            underline: None,
         }
     }
 }
 
+/// Damage statistics for the most recent render pass.
+///
+/// Read-only introspection for partial-redraw instrumentation; the values
+/// can be fed straight into a `perf_trace::BudgetTracker`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrameDiffStats {
+    /// Cells emitted to the terminal (continuation cells excluded).
+    pub cells_changed: usize,
+    /// Total cells in the buffer.
+    pub cells_total: usize,
+    /// Bytes handed to the writer, including escape sequences.
+    pub bytes_written: usize,
+}
+
+impl FrameDiffStats {
+    /// Fraction of the screen redrawn (0.0-1.0).
+    #[must_use]
+    pub fn damage_ratio(&self) -> f64 {
+        if self.cells_total == 0 {
+            0.0
+        } else {
+            self.cells_changed as f64 / self.cells_total as f64
+        }
+    }
+}
+
+/// Writer adapter that counts bytes passed through it.
+struct CountingWriter<'a, W: Write> {
+    inner: &'a mut W,
+    count: usize,
+}
+
+impl<W: Write> Write for CountingWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.count += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Write an OSC 8 hyperlink sequence (an empty `url` closes the link).
+///
+/// C0/C1 control characters are stripped so a URL cannot terminate the
+/// sequence early and inject escape codes.
+fn write_hyperlink<W: Write>(writer: &mut W, url: &str) -> io::Result<()> {
+    writer.write_all(b"\x1b]8;;")?;
+    for ch in url.chars().filter(|c| !c.is_control()) {
+        let mut utf8 = [0u8; 4];
+        writer.write_all(ch.encode_utf8(&mut utf8).as_bytes())?;
+    }
+    writer.write_all(b"\x1b\\")
+}
+
 /// Differential renderer that minimizes terminal I/O.
 ///
 /// Tracks the current cursor position and style state to avoid
@@ -54,6 +114,12 @@ pub struct DiffRenderer {
     cursor_moves: usize,
     /// Statistics: number of style changes.
     style_changes: usize,
+    /// Damage statistics from the last render pass.
+    last_frame_stats: FrameDiffStats,
+    /// Terminal supports OSC 8 hyperlinks and styled underlines.
+    extended_styles: bool,
+    /// Hyperlink currently open on the terminal.
+    open_link: Option<Arc<str>>,
 }
 
 impl Default for DiffRenderer {
@@ -74,6 +140,9 @@ impl DiffRenderer {
             cells_written: 0,
             cursor_moves: 0,
             style_changes: 0,
+            last_frame_stats: FrameDiffStats::default(),
+            extended_styles: false,
+            open_link: None,
         }
     }
 
@@ -88,6 +157,9 @@ impl DiffRenderer {
             cells_written: 0,
             cursor_moves: 0,
             style_changes: 0,
+            last_frame_stats: FrameDiffStats::default(),
+            extended_styles: false,
+            open_link: None,
         }
     }
 
@@ -102,6 +174,20 @@ impl DiffRenderer {
         self.color_mode
     }
 
+    /// Enable OSC 8 hyperlinks and curly/dotted/double underlines.
+    ///
+    /// When disabled, hyperlinked cells and styled underlines degrade to a
+    /// plain underline.
+    pub fn set_extended_styles(&mut self, enabled: bool) {
+        self.extended_styles = enabled;
+    }
+
+    /// Check whether extended styles are enabled.
+    #[must_use]
+    pub const fn extended_styles(&self) -> bool {
+        self.extended_styles
+    }
+
     /// Reset renderer state (call after terminal resize or clear).
     pub fn reset(&mut self) {
         self.cursor_x = u16::MAX;
@@ -110,6 +196,8 @@ impl DiffRenderer {
         self.cells_written = 0;
         self.cursor_moves = 0;
         self.style_changes = 0;
+        self.last_frame_stats = FrameDiffStats::default();
+        self.open_link = None;
     }
 
     /// Get cells written in last flush.
@@ -130,6 +218,12 @@ impl DiffRenderer {
         self.style_changes
     }
 
+    /// Get damage statistics for the last render pass.
+    #[must_use]
+    pub const fn last_frame_stats(&self) -> FrameDiffStats {
+        self.last_frame_stats
+    }
+
     /// Convert presentar Color to crossterm Color.
     fn to_crossterm_color(&self, color: Color) -> CrosstermColor {
         self.color_mode.to_crossterm(color)
@@ -156,7 +250,11 @@ impl DiffRenderer {
         self.style_changes = 0;
 
         // Use buffered writer to batch syscalls
-        let mut buf_writer = BufWriter::with_capacity(8192, writer);
+        let mut counter = CountingWriter {
+            inner: writer,
+            count: 0,
+        };
+        let mut buf_writer = BufWriter::with_capacity(8192, &mut counter);
 
         // Reset colors at start for clean state
         queue!(buf_writer, ResetColor)?;
@@ -181,11 +279,25 @@ impl DiffRenderer {
                 self.cursor_moves += 1;
             }
 
-            // Update style if needed
+            // Open or close hyperlinks as they start and end
+            let link = cell.hyperlink_arc();
+            if self.extended_styles && link != self.open_link.as_ref() {
+                write_hyperlink(&mut buf_writer, link.map_or("", |url| url.as_ref()))?;
+                self.open_link = link.cloned();
+            }
+
+            // Update style if needed (links degrade to a plain underline)
+            let (modifiers, underline) = if link.is_some() && !self.extended_styles {
+                let underline = cell.underline_style().unwrap_or_default();
+                (cell.modifiers.with(Modifiers::UNDERLINE), Some(underline))
+            } else {
+                (cell.modifiers, cell.underline_style())
+            };
             let new_style = StyleState {
                 fg: cell.fg,
                 bg: cell.bg,
-                modifiers: cell.modifiers,
+                modifiers,
+                underline,
             };
 
             if new_style != self.last_style {
@@ -206,11 +318,23 @@ impl DiffRenderer {
             self.cells_written += 1;
         }
 
+        // Never leave a hyperlink open between frames
+        if self.open_link.take().is_some() {
+            write_hyperlink(&mut buf_writer, "")?;
+        }
+
         // Clear dirty flags
         buffer.clear_dirty();
 
         // Final flush
         buf_writer.flush()?;
+        drop(buf_writer);
+
+        self.last_frame_stats = FrameDiffStats {
+            cells_changed: self.cells_written,
+            cells_total: buffer.len(),
+            bytes_written: counter.count,
+        };
 
         Ok(self.cells_written)
     }
@@ -235,8 +359,14 @@ impl DiffRenderer {
         if style.modifiers.contains(Modifiers::ITALIC) {
             writer.queue(SetAttribute(Attribute::Italic))?;
         }
-        if style.modifiers.contains(Modifiers::UNDERLINE) {
-            writer.queue(SetAttribute(Attribute::Underlined))?;
+        if let Some(underline) = style.underline {
+            let attr = match underline {
+                UnderlineStyle::Curly if self.extended_styles => Attribute::Undercurled,
+                UnderlineStyle::Dotted if self.extended_styles => Attribute::Underdotted,
+                UnderlineStyle::Double if self.extended_styles => Attribute::DoubleUnderlined,
+                _ => Attribute::Underlined,
+            };
+            writer.queue(SetAttribute(attr))?;
         }
         if style.modifiers.contains(Modifiers::STRIKETHROUGH) {
             writer.queue(SetAttribute(Attribute::CrossedOut))?;
@@ -276,6 +406,7 @@ impl DiffRenderer {
 #[allow(clippy::unwrap_used, clippy::disallowed_methods)]
 mod tests {
     use super::*;
+    use crate::direct::Cell;
 
     #[test]
     fn test_renderer_creation() {
@@ -515,4 +646,148 @@ mod tests {
         };
         assert_ne!(s1, s3);
     }
+
+    #[test]
+    fn test_last_frame_stats_counts_changed_cells() {
+        let mut renderer = DiffRenderer::new();
+        let mut buffer = CellBuffer::new(10, 5);
+        let mut output = Vec::new();
+        renderer.render_full(&mut buffer, &mut output).unwrap();
+
+        let full = renderer.last_frame_stats();
+        assert_eq!(full.cells_changed, 50);
+        assert_eq!(full.cells_total, 50);
+        assert_eq!(full.bytes_written, output.len());
+
+        // Second frame differs from the first in exactly five cells.
+        for (x, y) in [(0, 0), (3, 1), (9, 2), (4, 3), (7, 4)] {
+            buffer.update(x, y, "#", Color::GREEN, Color::BLACK, Modifiers::NONE);
+        }
+        let mut output = Vec::new();
+        renderer.flush(&mut buffer, &mut output).unwrap();
+
+        let stats = renderer.last_frame_stats();
+        assert_eq!(stats.cells_changed, 5);
+        assert_eq!(stats.cells_total, 50);
+        assert_eq!(stats.bytes_written, output.len());
+        assert!((stats.damage_ratio() - 0.1).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_last_frame_stats_reset() {
+        let mut renderer = DiffRenderer::new();
+        let mut buffer = CellBuffer::new(4, 2);
+        buffer.update(0, 0, "X", Color::RED, Color::BLACK, Modifiers::NONE);
+        renderer.flush(&mut buffer, &mut Vec::new()).unwrap();
+        assert_eq!(renderer.last_frame_stats().cells_changed, 1);
+
+        renderer.reset();
+        assert_eq!(renderer.last_frame_stats(), FrameDiffStats::default());
+        assert!(FrameDiffStats::default().damage_ratio().abs() < f64::EPSILON);
+    }
+
+    fn linked_buffer() -> CellBuffer {
+        let mut buffer = CellBuffer::new(10, 1);
+        let link: Arc<str> = Arc::from("https://example.com");
+        for (x, ch) in ["d", "o", "c"].into_iter().enumerate() {
+            let mut cell = Cell::new(ch, Color::WHITE, Color::BLACK, Modifiers::NONE);
+            cell.set_hyperlink(Some(Arc::clone(&link)));
+            buffer.set(x as u16, 0, cell);
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_renderer_emits_osc8_hyperlink() {
+        let mut renderer = DiffRenderer::with_color_mode(ColorMode::TrueColor);
+        renderer.set_extended_styles(true);
+        let mut buffer = CellBuffer::new(10, 1);
+        buffer.set(
+            0,
+            0,
+            Cell::new("Z", Color::WHITE, Color::BLACK, Modifiers::NONE)
+                .with_hyperlink("https://example.com"),
+        );
+        let mut output = Vec::new();
+        renderer.flush(&mut buffer, &mut output).unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        let open = text.find("\x1b]8;;https://example.com\x1b\\").unwrap();
+        let symbol = text.find('Z').unwrap();
+        let close = text.rfind("\x1b]8;;\x1b\\").unwrap();
+        assert!(open < symbol && symbol < close);
+    }
+
+    #[test]
+    fn test_renderer_hyperlink_opened_once_per_run() {
+        let mut renderer = DiffRenderer::with_color_mode(ColorMode::TrueColor);
+        renderer.set_extended_styles(true);
+        let mut buffer = linked_buffer();
+        let mut output = Vec::new();
+        renderer.flush(&mut buffer, &mut output).unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(text.matches("\x1b]8;;https://example.com").count(), 1);
+        assert_eq!(text.matches("\x1b]8;;\x1b\\").count(), 1);
+    }
+
+    #[test]
+    fn test_renderer_hyperlink_degrades_without_support() {
+        let mut renderer = DiffRenderer::with_color_mode(ColorMode::TrueColor);
+        assert!(!renderer.extended_styles());
+        let mut buffer = linked_buffer();
+        let mut output = Vec::new();
+        renderer.flush(&mut buffer, &mut output).unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        assert!(!text.contains("\x1b]8;"));
+        // SGR 4 = plain underline
+        assert!(text.contains("\x1b[4m"));
+    }
+
+    #[test]
+    fn test_renderer_strips_control_chars_from_hyperlink() {
+        let mut renderer = DiffRenderer::with_color_mode(ColorMode::TrueColor);
+        renderer.set_extended_styles(true);
+        let mut buffer = CellBuffer::new(4, 1);
+        buffer.set(
+            0,
+            0,
+            Cell::new("Z", Color::WHITE, Color::BLACK, Modifiers::NONE)
+                .with_hyperlink("https://e.com/\x1b\\\x1b[2J\u{9c}\x07x"),
+        );
+        let mut output = Vec::new();
+        renderer.flush(&mut buffer, &mut output).unwrap();
+
+        let text = String::from_utf8(output).unwrap();
+        assert!(text.contains("\x1b]8;;https://e.com/\\[2Jx\x1b\\"));
+        assert!(!text.contains("\x1b[2J"));
+        assert!(!text.contains('\u{9c}'));
+        assert!(!text.contains('\x07'));
+    }
+
+    fn curly_cell() -> Cell {
+        Cell::new("~", Color::WHITE, Color::BLACK, Modifiers::NONE)
+            .with_underline_style(UnderlineStyle::Curly)
+    }
+
+    #[test]
+    fn test_renderer_underline_styles() {
+        let mut renderer = DiffRenderer::with_color_mode(ColorMode::TrueColor);
+        renderer.set_extended_styles(true);
+        let mut buffer = CellBuffer::new(4, 1);
+        buffer.set(0, 0, curly_cell());
+        let mut output = Vec::new();
+        renderer.flush(&mut buffer, &mut output).unwrap();
+        assert!(String::from_utf8(output).unwrap().contains("\x1b[4:3m"));
+
+        let mut plain = DiffRenderer::with_color_mode(ColorMode::TrueColor);
+        let mut buffer = CellBuffer::new(4, 1);
+        buffer.set(0, 0, curly_cell());
+        let mut output = Vec::new();
+        plain.flush(&mut buffer, &mut output).unwrap();
+        let text = String::from_utf8(output).unwrap();
+        assert!(!text.contains("\x1b[4:3m"));
+        assert!(text.contains("\x1b[4m"));
+    }
 }
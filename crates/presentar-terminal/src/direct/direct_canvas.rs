@@ -5,7 +5,7 @@
 
 use super::cell_buffer::{CellBuffer, Modifiers};
 use crate::color::ColorMode;
-use presentar_core::{Canvas, Color, Point, Rect, TextStyle, Transform2D};
+use presentar_core::{Canvas, Color, Point, Rect, Size, TextStyle, Transform2D};
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
@@ -166,16 +166,8 @@ impl<'a> DirectTerminalCanvas<'a> {
     ) {
         let clip = self.clip();
         if clip.contains(x, y) && x < self.buffer.width() && y < self.buffer.height() {
+            // The buffer claims the continuation cell for wide characters.
             self.buffer.update(x, y, symbol, fg, bg, modifiers);
-
-            // Handle wide characters
-            let width = UnicodeWidthStr::width(symbol);
-            if width > 1 && x + 1 < self.buffer.width() {
-                if let Some(cell) = self.buffer.get_mut(x + 1, y) {
-                    cell.make_continuation();
-                }
-                self.buffer.mark_dirty(x + 1, y);
-            }
         }
     }
 
@@ -326,6 +318,11 @@ impl Canvas for DirectTerminalCanvas<'_> {
         }
     }
 
+    /// One row high; width is the text's display width in cells.
+    fn measure_text(&self, text: &str, _style: &TextStyle) -> Size {
+        Size::new(UnicodeWidthStr::width(text) as f32, 1.0)
+    }
+
     fn draw_line(&mut self, from: Point, to: Point, color: Color, _width: f32) {
         let from = self.transform_point(from);
         let to = self.transform_point(to);
@@ -667,6 +664,19 @@ mod tests {
 
         assert_eq!(buffer.get(0, 0).unwrap().symbol.as_str(), "H");
         assert_eq!(buffer.get(1, 0).unwrap().symbol.as_str(), "e");
+        assert!(buffer.rows_as_strings()[0].starts_with("Hello "));
+    }
+
+    #[test]
+    fn test_measure_text_cells() {
+        let mut buffer = create_canvas(20, 5);
+        let canvas = DirectTerminalCanvas::new(&mut buffer);
+        let style = TextStyle::default();
+
+        assert_eq!(canvas.measure_text("hello", &style), Size::new(5.0, 1.0));
+        // CJK glyphs occupy two cells each
+        assert_eq!(canvas.measure_text("日本語", &style), Size::new(6.0, 1.0));
+        assert_eq!(canvas.measure_text("", &style).width, 0.0);
     }
 
     #[test]
@@ -715,6 +725,7 @@ mod tests {
         }
         // Should clip at width
         assert_eq!(buffer.get(4, 0).unwrap().symbol.as_str(), "o");
+        assert_eq!(buffer.rows_as_strings()[0], "Hello");
     }
 
     #[test]
@@ -21,7 +21,9 @@
 mod cell_buffer;
 mod diff_renderer;
 mod direct_canvas;
+mod viewport;
 
-pub use cell_buffer::{Cell, CellBuffer, Modifiers};
-pub use diff_renderer::DiffRenderer;
+pub use cell_buffer::{Cell, CellBuffer, Modifiers, UnderlineStyle};
+pub use diff_renderer::{DiffRenderer, FrameDiffStats};
 pub use direct_canvas::DirectTerminalCanvas;
+pub use viewport::Viewport;
@@ -0,0 +1,294 @@
+//! Clipped, scrollable view onto a `CellBuffer`.
+//!
+//! Widgets larger than their allotted `Rect` (logs, long tables) draw in
+//! their own content coordinates; the viewport translates each write by the
+//! clip origin and scroll offset and silently drops anything outside the clip.
+
+use super::cell_buffer::{Cell, CellBuffer, Modifiers};
+use presentar_core::{Color, Rect};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// A translating, clipping window onto a `CellBuffer`.
+#[derive(Debug)]
+pub struct Viewport<'a> {
+    /// The underlying buffer.
+    buffer: &'a mut CellBuffer,
+    /// Clip origin in buffer cells.
+    x: u16,
+    y: u16,
+    /// Clip size in buffer cells (already intersected with the buffer).
+    width: u16,
+    height: u16,
+    /// Content coordinate shown at the clip's top-left corner.
+    scroll_x: i32,
+    scroll_y: i32,
+}
+
+impl<'a> Viewport<'a> {
+    /// Create a viewport clipped to `clip` (in buffer coordinates).
+    ///
+    /// The clip is snapped to whole cells and intersected with the buffer.
+    #[must_use]
+    pub fn new(buffer: &'a mut CellBuffer, clip: Rect) -> Self {
+        let x0 = clip.x.max(0.0).floor().min(f32::from(buffer.width()));
+        let y0 = clip.y.max(0.0).floor().min(f32::from(buffer.height()));
+        let x1 = (clip.x + clip.width)
+            .max(0.0)
+            .ceil()
+            .min(f32::from(buffer.width()));
+        let y1 = (clip.y + clip.height)
+            .max(0.0)
+            .ceil()
+            .min(f32::from(buffer.height()));
+        Self {
+            buffer,
+            x: x0 as u16,
+            y: y0 as u16,
+            width: (x1 - x0).max(0.0) as u16,
+            height: (y1 - y0).max(0.0) as u16,
+            scroll_x: 0,
+            scroll_y: 0,
+        }
+    }
+
+    /// Set the scroll offset (content coordinate at the clip's top-left).
+    #[must_use]
+    pub const fn with_scroll(mut self, x: i32, y: i32) -> Self {
+        self.scroll_x = x;
+        self.scroll_y = y;
+        self
+    }
+
+    /// Update the scroll offset.
+    pub fn set_scroll(&mut self, x: i32, y: i32) {
+        self.scroll_x = x;
+        self.scroll_y = y;
+    }
+
+    /// Get the scroll offset.
+    #[must_use]
+    pub const fn scroll(&self) -> (i32, i32) {
+        (self.scroll_x, self.scroll_y)
+    }
+
+    /// Visible width in cells.
+    #[must_use]
+    pub const fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Visible height in cells.
+    #[must_use]
+    pub const fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Translate a content coordinate to a buffer coordinate.
+    ///
+    /// Returns `None` if the position falls outside the clip.
+    #[must_use]
+    pub fn to_buffer(&self, x: i32, y: i32) -> Option<(u16, u16)> {
+        let vx = i64::from(x) - i64::from(self.scroll_x);
+        let vy = i64::from(y) - i64::from(self.scroll_y);
+        if vx < 0 || vy < 0 || vx >= i64::from(self.width) || vy >= i64::from(self.height) {
+            return None;
+        }
+        Some((self.x + vx as u16, self.y + vy as u16))
+    }
+
+    /// Check whether a content coordinate is visible.
+    #[must_use]
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        self.to_buffer(x, y).is_some()
+    }
+
+    /// Get a visible cell by content coordinate.
+    #[must_use]
+    pub fn get(&self, x: i32, y: i32) -> Option<&Cell> {
+        let (bx, by) = self.to_buffer(x, y)?;
+        self.buffer.get(bx, by)
+    }
+
+    /// Set a cell; writes outside the clip are dropped.
+    ///
+    /// A wide cell whose second column falls outside the clip is blanked.
+    pub fn set(&mut self, x: i32, y: i32, cell: Cell) {
+        if let Some((bx, by)) = self.to_buffer(x, y) {
+            if cell.width() > 1 && !self.contains(x.saturating_add(1), y) {
+                let blank = Cell::new(" ", cell.fg, cell.bg, cell.modifiers);
+                self.buffer.set(bx, by, blank);
+            } else {
+                self.buffer.set(bx, by, cell);
+            }
+        }
+    }
+
+    /// Update a cell's content; writes outside the clip are dropped.
+    ///
+    /// A wide symbol whose second column falls outside the clip is written
+    /// as a space, so its continuation never lands outside the viewport.
+    pub fn update(
+        &mut self,
+        x: i32,
+        y: i32,
+        symbol: &str,
+        fg: Color,
+        bg: Color,
+        modifiers: Modifiers,
+    ) {
+        if let Some((bx, by)) = self.to_buffer(x, y) {
+            let symbol =
+                if UnicodeWidthStr::width(symbol) > 1 && !self.contains(x.saturating_add(1), y) {
+                    " "
+                } else {
+                    symbol
+                };
+            self.buffer.update(bx, by, symbol, fg, bg, modifiers);
+        }
+    }
+
+    /// Write a string by display width; cells outside the clip are dropped.
+    pub fn write_str(
+        &mut self,
+        x: i32,
+        y: i32,
+        s: &str,
+        fg: Color,
+        bg: Color,
+        modifiers: Modifiers,
+    ) {
+        let mut cx = x;
+        for grapheme in s.graphemes(true) {
+            self.update(cx, y, grapheme, fg, bg, modifiers);
+            cx = cx.saturating_add(UnicodeWidthStr::width(grapheme).clamp(1, 2) as i32);
+        }
+    }
+
+    /// Fill the visible area with blank cells.
+    pub fn clear(&mut self, fg: Color, bg: Color) {
+        self.buffer
+            .fill_rect(self.x, self.y, self.width, self.height, fg, bg);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::disallowed_methods)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewport_drops_negative_position() {
+        let mut buffer = CellBuffer::new(10, 5);
+        buffer.clear_dirty();
+        {
+            let mut view = Viewport::new(&mut buffer, Rect::new(2.0, 1.0, 4.0, 3.0));
+            view.update(-1, 0, "X", Color::RED, Color::BLACK, Modifiers::NONE);
+            view.update(0, -1, "X", Color::RED, Color::BLACK, Modifiers::NONE);
+        }
+        assert_eq!(buffer.dirty_count(), 0);
+        assert!(buffer.cells().iter().all(|c| c.symbol != "X"));
+    }
+
+    #[test]
+    fn test_viewport_translates_inside_clip() {
+        let mut buffer = CellBuffer::new(10, 5);
+        {
+            let mut view = Viewport::new(&mut buffer, Rect::new(2.0, 1.0, 4.0, 3.0));
+            view.update(1, 2, "A", Color::RED, Color::BLACK, Modifiers::NONE);
+        }
+        assert_eq!(buffer.rows_as_strings()[3], "   A      ");
+        assert_eq!(buffer.get(3, 3).unwrap().fg, Color::RED);
+    }
+
+    #[test]
+    fn test_viewport_drops_beyond_clip() {
+        let mut buffer = CellBuffer::new(10, 5);
+        buffer.clear_dirty();
+        {
+            let mut view = Viewport::new(&mut buffer, Rect::new(2.0, 1.0, 4.0, 3.0));
+            view.update(4, 0, "X", Color::RED, Color::BLACK, Modifiers::NONE);
+            view.update(0, 3, "X", Color::RED, Color::BLACK, Modifiers::NONE);
+        }
+        assert_eq!(buffer.dirty_count(), 0);
+    }
+
+    #[test]
+    fn test_viewport_scroll_offset() {
+        let mut buffer = CellBuffer::new(10, 5);
+        {
+            let mut view =
+                Viewport::new(&mut buffer, Rect::new(0.0, 0.0, 10.0, 2.0)).with_scroll(0, 100);
+            assert!(!view.contains(0, 99));
+            assert!(view.contains(0, 100));
+            assert!(!view.contains(0, 102));
+            view.write_str(0, 101, "log", Color::WHITE, Color::BLACK, Modifiers::NONE);
+            assert_eq!(view.get(1, 101).unwrap().symbol, "o");
+        }
+        assert_eq!(buffer.rows_as_strings()[1], "log       ");
+    }
+
+    #[test]
+    fn test_viewport_write_str_clips_horizontally() {
+        let mut buffer = CellBuffer::new(10, 1);
+        {
+            let mut view = Viewport::new(&mut buffer, Rect::new(3.0, 0.0, 3.0, 1.0));
+            view.write_str(
+                -2,
+                0,
+                "abcdefg",
+                Color::WHITE,
+                Color::BLACK,
+                Modifiers::NONE,
+            );
+        }
+        assert_eq!(buffer.rows_as_strings(), vec!["   cde    "]);
+    }
+
+    #[test]
+    fn test_viewport_wide_glyph_at_clip_edge() {
+        let mut buffer = CellBuffer::new(6, 1);
+        {
+            let mut view = Viewport::new(&mut buffer, Rect::new(1.0, 0.0, 3.0, 1.0));
+            view.write_str(0, 0, "ab日", Color::WHITE, Color::BLACK, Modifiers::NONE);
+        }
+        // 日 would need columns 3-4, but the clip ends at column 3.
+        assert_eq!(buffer.rows_as_strings(), vec![" ab   "]);
+        assert!(!buffer.get(4, 0).unwrap().is_continuation());
+
+        let mut buffer = CellBuffer::new(6, 1);
+        {
+            let mut view = Viewport::new(&mut buffer, Rect::new(0.0, 0.0, 3.0, 1.0));
+            view.set(
+                2,
+                0,
+                Cell::new("日", Color::RED, Color::BLACK, Modifiers::NONE),
+            );
+        }
+        assert_eq!(buffer.get(2, 0).unwrap().symbol, " ");
+        assert!(!buffer.get(3, 0).unwrap().is_continuation());
+    }
+
+    #[test]
+    fn test_viewport_clip_intersects_buffer() {
+        let mut buffer = CellBuffer::new(10, 5);
+        let view = Viewport::new(&mut buffer, Rect::new(8.0, 3.0, 10.0, 10.0));
+        assert_eq!(view.width(), 2);
+        assert_eq!(view.height(), 2);
+        assert_eq!(view.to_buffer(1, 1), Some((9, 4)));
+        assert_eq!(view.to_buffer(2, 0), None);
+    }
+
+    #[test]
+    fn test_viewport_clear() {
+        let mut buffer = CellBuffer::new(6, 3);
+        buffer.clear_dirty();
+        {
+            let mut view = Viewport::new(&mut buffer, Rect::new(1.0, 1.0, 2.0, 2.0));
+            view.clear(Color::WHITE, Color::BLUE);
+        }
+        assert_eq!(buffer.dirty_count(), 4);
+        assert_eq!(buffer.get(1, 1).unwrap().bg, Color::BLUE);
+        assert_eq!(buffer.get(0, 0).unwrap().bg, Color::TRANSPARENT);
+    }
+}
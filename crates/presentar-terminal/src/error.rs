@@ -29,6 +29,17 @@ pub enum TuiError {
     /// Terminal not available.
     #[error("Terminal not available")]
     TerminalNotAvailable,
+
+    /// Theme file could not be parsed.
+    #[error("Theme parse error on line {line}: {message}")]
+    ThemeParse { line: usize, message: String },
+
+    /// No built-in theme has this name.
+    #[error(
+        "Unknown theme: {0} (available: {})",
+        crate::Theme::preset_names().join(", ")
+    )]
+    UnknownTheme(String),
 }
 
 /// Verification error with details.
@@ -104,6 +115,14 @@ mod tests {
         assert_eq!(err.to_string(), "Terminal not available");
     }
 
+    #[test]
+    fn test_tui_error_unknown_theme() {
+        let err = TuiError::UnknownTheme("murky".to_string());
+        let msg = err.to_string();
+        assert!(msg.starts_with("Unknown theme: murky (available: "));
+        assert!(msg.contains("solarized_dark"));
+    }
+
     #[test]
     fn test_verification_error_display() {
         let verification = BrickVerification {
@@ -197,6 +216,18 @@ mod tests {
         assert!(msg.contains("50ms"));
     }
 
+    #[test]
+    fn test_tui_error_theme_parse() {
+        let err = TuiError::ThemeParse {
+            line: 3,
+            message: "unknown key `colour`".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Theme parse error on line 3: unknown key `colour`"
+        );
+    }
+
     #[test]
     fn test_invalid_brick_with_details() {
         let err = TuiError::InvalidBrick("Missing required field: title".to_string());
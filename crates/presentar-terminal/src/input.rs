@@ -2,8 +2,18 @@
 
 use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
 use presentar_core::{Event, Key, MouseButton, Point};
+use std::time::{Duration, Instant};
+
+/// Default maximum delay between keys of a sequence binding.
+pub const DEFAULT_SEQUENCE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Default maximum delay between clicks counted as a double/triple click.
+pub const DEFAULT_CLICK_WINDOW: Duration = Duration::from_millis(400);
 
 /// Key binding configuration.
+///
+/// Prefer the constructors and builder methods; struct literals should end
+/// in `..KeyBinding::default()` so they keep compiling as fields are added.
 #[derive(Debug, Clone)]
 pub struct KeyBinding {
     /// Key code.
@@ -12,6 +22,17 @@ pub struct KeyBinding {
     pub modifiers: KeyModifiers,
     /// Action name.
     pub action: String,
+    /// Keys that must follow `code` to complete a sequence (empty for a single key).
+    pub followed_by: Vec<(KeyCode, KeyModifiers)>,
+    /// Maximum delay between consecutive keys of a sequence.
+    pub timeout: Duration,
+}
+
+impl Default for KeyBinding {
+    /// An unbound `KeyCode::Null` binding with no action.
+    fn default() -> Self {
+        Self::new(KeyCode::Null, KeyModifiers::NONE, String::new())
+    }
 }
 
 impl KeyBinding {
@@ -22,9 +43,58 @@ impl KeyBinding {
             code,
             modifiers,
             action: action.into(),
+            followed_by: Vec::new(),
+            timeout: DEFAULT_SEQUENCE_TIMEOUT,
         }
     }
 
+    /// Create a multi-key sequence binding without modifiers (e.g. `g g`).
+    ///
+    /// An empty `codes` yields a binding for `KeyCode::Null` that never fires.
+    #[must_use]
+    pub fn sequence(codes: impl IntoIterator<Item = KeyCode>, action: impl Into<String>) -> Self {
+        let mut codes = codes.into_iter();
+        let first = codes.next().unwrap_or(KeyCode::Null);
+        let mut binding = Self::simple(first, action);
+        binding.followed_by = codes.map(|c| (c, KeyModifiers::NONE)).collect();
+        binding
+    }
+
+    /// Append a key that must follow the keys bound so far.
+    #[must_use]
+    pub fn then(mut self, code: KeyCode, modifiers: KeyModifiers) -> Self {
+        self.followed_by.push((code, modifiers));
+        self
+    }
+
+    /// Set the maximum delay allowed between keys of a sequence.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Whether this binding needs more than one key.
+    #[must_use]
+    pub fn is_sequence(&self) -> bool {
+        !self.followed_by.is_empty()
+    }
+
+    /// Number of keys in the binding.
+    #[must_use]
+    pub fn key_count(&self) -> usize {
+        1 + self.followed_by.len()
+    }
+
+    /// Check whether `keys` are the first `keys.len()` keys of this binding.
+    fn starts_with(&self, keys: &[KeyEvent]) -> bool {
+        keys.len() <= self.key_count()
+            && std::iter::once((self.code, self.modifiers))
+                .chain(self.followed_by.iter().copied())
+                .zip(keys)
+                .all(|((code, modifiers), key)| key_matches(code, modifiers, key))
+    }
+
     /// Create a simple key binding without modifiers.
     #[must_use]
     pub fn simple(code: KeyCode, action: impl Into<String>) -> Self {
@@ -32,16 +102,54 @@ impl KeyBinding {
     }
 
     /// Check if this binding matches a key event.
+    ///
+    /// For sequence bindings this only checks the first key.
     #[must_use]
     pub fn matches(&self, event: &KeyEvent) -> bool {
-        event.code == self.code && event.modifiers.contains(self.modifiers)
+        key_matches(self.code, self.modifiers, event)
     }
 }
 
+fn key_matches(code: KeyCode, modifiers: KeyModifiers, event: &KeyEvent) -> bool {
+    event.code == code && event.modifiers.contains(modifiers)
+}
+
+/// Result of feeding a key press through [`InputHandler::feed_key`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyDispatch {
+    /// A binding completed; carries its action name.
+    Action(String),
+    /// A key press that completed no binding, to be handled as a plain key.
+    Key(KeyEvent),
+}
+
 /// Input handler for converting crossterm events to presentar events.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct InputHandler {
     bindings: Vec<KeyBinding>,
+    /// Keys buffered while they are a prefix of some sequence binding.
+    pending: Vec<KeyEvent>,
+    /// When the last pending key arrived.
+    last_key_at: Option<Instant>,
+    /// Drag in progress: (press position, button, moved since press).
+    drag: Option<(Point, MouseButton, bool)>,
+    /// Previous click: (position, button, time, count).
+    last_click: Option<(Point, MouseButton, Instant, u8)>,
+    /// Maximum delay between clicks of a multi-click.
+    click_window: Duration,
+}
+
+impl Default for InputHandler {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            pending: Vec::new(),
+            last_key_at: None,
+            drag: None,
+            last_click: None,
+            click_window: DEFAULT_CLICK_WINDOW,
+        }
+    }
 }
 
 impl InputHandler {
@@ -51,6 +159,13 @@ impl InputHandler {
         Self::default()
     }
 
+    /// Set the maximum delay between clicks counted as one multi-click.
+    #[must_use]
+    pub fn with_click_window(mut self, window: Duration) -> Self {
+        self.click_window = window;
+        self
+    }
+
     /// Add a key binding.
     pub fn add_binding(&mut self, binding: KeyBinding) {
         self.bindings.push(binding);
@@ -72,6 +187,91 @@ impl InputHandler {
         }
     }
 
+    /// Convert a crossterm event, synthesizing higher-level events.
+    ///
+    /// Unlike [`InputHandler::convert`] this tracks mouse state across calls:
+    /// moves with a button held after a `MouseDown` also yield
+    /// [`Event::Drag`], and releasing that button after dragging yields
+    /// [`Event::DragEnd`] after the `MouseUp`. Releasing without dragging
+    /// yields [`Event::Click`] with the multi-click count.
+    pub fn process(&mut self, event: CrosstermEvent) -> Vec<Event> {
+        self.process_at(event, Instant::now())
+    }
+
+    /// [`InputHandler::process`] with an explicit timestamp for the event.
+    pub fn process_at(&mut self, event: CrosstermEvent, now: Instant) -> Vec<Event> {
+        let CrosstermEvent::Mouse(mouse) = event else {
+            return self.convert(event).into_iter().collect();
+        };
+
+        let converted = self.convert_mouse(mouse);
+        let mut out = vec![converted.clone()];
+        match converted {
+            Event::MouseDown { position, button } => {
+                self.drag = Some((position, button, false));
+            }
+            Event::MouseMove { position } => {
+                if let crossterm::event::MouseEventKind::Drag(held) = mouse.kind {
+                    let button = convert_button(held);
+                    let start = match self.drag {
+                        Some((start, b, _)) if b == button => start,
+                        // Press happened before we started tracking
+                        _ => position,
+                    };
+                    self.drag = Some((start, button, true));
+                    out.push(Event::Drag {
+                        start,
+                        current: position,
+                        button,
+                    });
+                }
+            }
+            Event::MouseUp { position, button } => {
+                if let Some((start, held, moved)) = self.drag {
+                    if held == button {
+                        self.drag = None;
+                        if moved {
+                            out.push(Event::DragEnd {
+                                start,
+                                end: position,
+                                button,
+                            });
+                        } else {
+                            let count = self.click_count(position, button, now);
+                            out.push(Event::Click {
+                                position,
+                                button,
+                                count,
+                            });
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        out
+    }
+
+    /// Record a click and return how many successive clicks it completes.
+    ///
+    /// A click continues the previous one when it uses the same button,
+    /// lands within one cell of it and arrives inside the click window.
+    fn click_count(&mut self, position: Point, button: MouseButton, now: Instant) -> u8 {
+        let count = match self.last_click {
+            Some((last_pos, last_button, at, count))
+                if last_button == button
+                    && (position.x - last_pos.x).abs() <= 1.0
+                    && (position.y - last_pos.y).abs() <= 1.0
+                    && now.saturating_duration_since(at) <= self.click_window =>
+            {
+                count.saturating_add(1)
+            }
+            _ => 1,
+        };
+        self.last_click = Some((position, button, now, count));
+        count
+    }
+
     fn convert_key(&self, key: KeyEvent) -> Option<Event> {
         let presentar_key = match key.code {
             KeyCode::Char(ch) => char_to_key(ch)?,
@@ -92,26 +292,18 @@ impl InputHandler {
     }
 
     fn convert_mouse(&self, mouse: crossterm::event::MouseEvent) -> Event {
-        use crossterm::event::{MouseButton as CtMouseButton, MouseEventKind};
+        use crossterm::event::MouseEventKind;
 
         let position = Point::new(f32::from(mouse.column), f32::from(mouse.row));
 
         match mouse.kind {
             MouseEventKind::Down(button) => Event::MouseDown {
                 position,
-                button: match button {
-                    CtMouseButton::Left => MouseButton::Left,
-                    CtMouseButton::Right => MouseButton::Right,
-                    CtMouseButton::Middle => MouseButton::Middle,
-                },
+                button: convert_button(button),
             },
             MouseEventKind::Up(button) => Event::MouseUp {
                 position,
-                button: match button {
-                    CtMouseButton::Left => MouseButton::Left,
-                    CtMouseButton::Right => MouseButton::Right,
-                    CtMouseButton::Middle => MouseButton::Middle,
-                },
+                button: convert_button(button),
             },
             MouseEventKind::Moved | MouseEventKind::Drag(_) => Event::MouseMove { position },
             MouseEventKind::ScrollUp => Event::Scroll {
@@ -133,10 +325,101 @@ impl InputHandler {
         }
     }
 
-    /// Find a matching binding for a key event.
+    /// Find a matching single-key binding for a key event.
     #[must_use]
     pub fn find_binding(&self, event: &KeyEvent) -> Option<&KeyBinding> {
-        self.bindings.iter().find(|b| b.matches(event))
+        self.bindings
+            .iter()
+            .find(|b| !b.is_sequence() && b.matches(event))
+    }
+
+    /// Feed a key press through the binding table, resolving sequences.
+    ///
+    /// Keys that could still complete a sequence binding are buffered and
+    /// produce nothing yet. When a sequence completes its action is returned.
+    /// When the buffer can no longer complete any binding, or the previous
+    /// key arrived after the sequence timeout, buffered keys are re-dispatched
+    /// one at a time so nothing is swallowed.
+    pub fn feed_key(&mut self, key: KeyEvent, now: Instant) -> Vec<KeyDispatch> {
+        let mut out = self.flush_expired(now);
+        self.pending.push(key);
+        self.last_key_at = Some(now);
+
+        while !self.pending.is_empty() {
+            let waiting = self
+                .bindings
+                .iter()
+                .any(|b| b.key_count() > self.pending.len() && b.starts_with(&self.pending));
+            if waiting {
+                return out;
+            }
+
+            if let Some(binding) = self
+                .bindings
+                .iter()
+                .find(|b| b.key_count() == self.pending.len() && b.starts_with(&self.pending))
+            {
+                out.push(KeyDispatch::Action(binding.action.clone()));
+                self.pending.clear();
+                break;
+            }
+
+            let first = self.pending.remove(0);
+            out.push(self.dispatch_single(first));
+        }
+
+        self.last_key_at = None;
+        out
+    }
+
+    /// Re-dispatch buffered keys if the sequence window has elapsed.
+    ///
+    /// Call this when polling times out so a lone prefix key (e.g. a single
+    /// `g` waiting for a second one) is not held indefinitely.
+    pub fn flush_expired(&mut self, now: Instant) -> Vec<KeyDispatch> {
+        let Some(last) = self.last_key_at else {
+            return Vec::new();
+        };
+        let window = self
+            .bindings
+            .iter()
+            .filter(|b| b.key_count() > self.pending.len() && b.starts_with(&self.pending))
+            .map(|b| b.timeout)
+            .max()
+            .unwrap_or(Duration::ZERO);
+        if now.saturating_duration_since(last) <= window {
+            return Vec::new();
+        }
+
+        self.last_key_at = None;
+        std::mem::take(&mut self.pending)
+            .into_iter()
+            .map(|key| self.dispatch_single(key))
+            .collect()
+    }
+
+    /// Whether keys are buffered waiting for a sequence to complete.
+    #[must_use]
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    fn dispatch_single(&self, key: KeyEvent) -> KeyDispatch {
+        match self.find_binding(&key) {
+            Some(binding) => KeyDispatch::Action(binding.action.clone()),
+            None => KeyDispatch::Key(key),
+        }
+    }
+}
+
+/// Convert a crossterm mouse button to a presentar mouse button.
+fn convert_button(button: crossterm::event::MouseButton) -> MouseButton {
+    use crossterm::event::MouseButton as CtMouseButton;
+
+    match button {
+        CtMouseButton::Left => MouseButton::Left,
+        CtMouseButton::Right => MouseButton::Right,
+        CtMouseButton::Middle => MouseButton::Middle,
     }
 }
 
@@ -299,6 +582,144 @@ mod tests {
         assert!(handler.find_binding(&event2).is_none());
     }
 
+    fn press(ch: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE)
+    }
+
+    fn gg_handler() -> InputHandler {
+        let mut handler = InputHandler::new();
+        handler.add_binding(KeyBinding::sequence(
+            [KeyCode::Char('g'), KeyCode::Char('g')],
+            "top",
+        ));
+        handler
+    }
+
+    #[test]
+    fn test_key_binding_sequence() {
+        let binding = KeyBinding::sequence([KeyCode::Char('d'), KeyCode::Char('d')], "kill");
+        assert!(binding.is_sequence());
+        assert_eq!(binding.key_count(), 2);
+        assert_eq!(binding.timeout, DEFAULT_SEQUENCE_TIMEOUT);
+        assert!(!KeyBinding::simple(KeyCode::Char('q'), "quit").is_sequence());
+    }
+
+    #[test]
+    fn test_key_binding_then_and_default() {
+        let binding = KeyBinding::new(KeyCode::Char('x'), KeyModifiers::CONTROL, "cut")
+            .then(KeyCode::Char('k'), KeyModifiers::CONTROL);
+        assert_eq!(binding.key_count(), 2);
+        assert_eq!(
+            binding.followed_by,
+            vec![(KeyCode::Char('k'), KeyModifiers::CONTROL)]
+        );
+
+        let literal = KeyBinding {
+            code: KeyCode::Esc,
+            action: "close".to_string(),
+            ..KeyBinding::default()
+        };
+        assert!(!literal.is_sequence());
+        assert_eq!(literal.modifiers, KeyModifiers::NONE);
+        assert_eq!(literal.timeout, DEFAULT_SEQUENCE_TIMEOUT);
+    }
+
+    #[test]
+    fn test_feed_key_sequence_fires_within_window() {
+        let mut handler = gg_handler();
+        let t0 = Instant::now();
+
+        assert!(handler.feed_key(press('g'), t0).is_empty());
+        assert!(handler.has_pending());
+        let out = handler.feed_key(press('g'), t0 + Duration::from_millis(200));
+        assert_eq!(out, vec![KeyDispatch::Action("top".to_string())]);
+        assert!(!handler.has_pending());
+    }
+
+    #[test]
+    fn test_feed_key_sequence_timeout_redispatches() {
+        let mut handler = gg_handler();
+        let t0 = Instant::now();
+
+        assert!(handler.feed_key(press('g'), t0).is_empty());
+        // Second g arrives too late: first g is replayed, second starts afresh
+        let out = handler.feed_key(press('g'), t0 + Duration::from_millis(600));
+        assert_eq!(out, vec![KeyDispatch::Key(press('g'))]);
+        assert!(handler.has_pending());
+    }
+
+    #[test]
+    fn test_feed_key_flush_expired() {
+        let mut handler = gg_handler();
+        let t0 = Instant::now();
+
+        handler.feed_key(press('g'), t0);
+        assert!(handler
+            .flush_expired(t0 + Duration::from_millis(100))
+            .is_empty());
+        let out = handler.flush_expired(t0 + Duration::from_secs(1));
+        assert_eq!(out, vec![KeyDispatch::Key(press('g'))]);
+        assert!(!handler.has_pending());
+    }
+
+    #[test]
+    fn test_feed_key_broken_sequence_replays_keys() {
+        let mut handler = gg_handler();
+        handler.add_binding(KeyBinding::simple(KeyCode::Char('x'), "close"));
+        let t0 = Instant::now();
+
+        handler.feed_key(press('g'), t0);
+        let out = handler.feed_key(press('x'), t0);
+        assert_eq!(
+            out,
+            vec![
+                KeyDispatch::Key(press('g')),
+                KeyDispatch::Action("close".to_string())
+            ]
+        );
+        assert!(!handler.has_pending());
+    }
+
+    #[test]
+    fn test_feed_key_single_binding_immediate() {
+        let mut handler = gg_handler();
+        handler.add_binding(KeyBinding::simple(KeyCode::Char('q'), "quit"));
+        let out = handler.feed_key(press('q'), Instant::now());
+        assert_eq!(out, vec![KeyDispatch::Action("quit".to_string())]);
+        let out = handler.feed_key(press('z'), Instant::now());
+        assert_eq!(out, vec![KeyDispatch::Key(press('z'))]);
+    }
+
+    #[test]
+    fn test_feed_key_prefix_single_fires_after_timeout() {
+        let mut handler = gg_handler();
+        handler.add_binding(KeyBinding::simple(KeyCode::Char('g'), "goto"));
+        let t0 = Instant::now();
+
+        assert!(handler.feed_key(press('g'), t0).is_empty());
+        let out = handler.flush_expired(t0 + Duration::from_secs(1));
+        assert_eq!(out, vec![KeyDispatch::Action("goto".to_string())]);
+    }
+
+    #[test]
+    fn test_feed_key_custom_timeout() {
+        let mut handler = InputHandler::new();
+        handler.add_binding(
+            KeyBinding::sequence([KeyCode::Char('d'), KeyCode::Char('d')], "kill")
+                .with_timeout(Duration::from_secs(2)),
+        );
+        let t0 = Instant::now();
+        handler.feed_key(press('d'), t0);
+        let out = handler.feed_key(press('d'), t0 + Duration::from_millis(1500));
+        assert_eq!(out, vec![KeyDispatch::Action("kill".to_string())]);
+    }
+
+    #[test]
+    fn test_find_binding_skips_sequences() {
+        let handler = gg_handler();
+        assert!(handler.find_binding(&press('g')).is_none());
+    }
+
     #[test]
     fn test_convert_letter_keys() {
         let handler = InputHandler::new();
@@ -506,6 +927,178 @@ mod tests {
         assert!(matches!(result, Event::Scroll { delta_x, .. } if delta_x > 0.0));
     }
 
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> CrosstermEvent {
+        CrosstermEvent::Mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_process_drag_sequence() {
+        let mut handler = InputHandler::new();
+        let start = Point::new(2.0, 3.0);
+
+        let down = handler.process(mouse(MouseEventKind::Down(CtMouseButton::Left), 2, 3));
+        assert_eq!(
+            down,
+            vec![Event::MouseDown {
+                position: start,
+                button: MouseButton::Left
+            }]
+        );
+
+        let first = handler.process(mouse(MouseEventKind::Drag(CtMouseButton::Left), 5, 4));
+        assert_eq!(
+            first[1],
+            Event::Drag {
+                start,
+                current: Point::new(5.0, 4.0),
+                button: MouseButton::Left
+            }
+        );
+
+        let second = handler.process(mouse(MouseEventKind::Drag(CtMouseButton::Left), 9, 7));
+        assert_eq!(
+            second[1],
+            Event::Drag {
+                start,
+                current: Point::new(9.0, 7.0),
+                button: MouseButton::Left
+            }
+        );
+
+        let up = handler.process(mouse(MouseEventKind::Up(CtMouseButton::Left), 9, 7));
+        assert_eq!(
+            up,
+            vec![
+                Event::MouseUp {
+                    position: Point::new(9.0, 7.0),
+                    button: MouseButton::Left
+                },
+                Event::DragEnd {
+                    start,
+                    end: Point::new(9.0, 7.0),
+                    button: MouseButton::Left
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_process_click_without_move_has_no_drag_end() {
+        let mut handler = InputHandler::new();
+        handler.process(mouse(MouseEventKind::Down(CtMouseButton::Left), 1, 1));
+        let up = handler.process(mouse(MouseEventKind::Up(CtMouseButton::Left), 1, 1));
+        assert_eq!(up.len(), 2);
+        assert!(matches!(up[0], Event::MouseUp { .. }));
+        assert!(!up.iter().any(|e| matches!(e, Event::DragEnd { .. })));
+    }
+
+    fn click_at(handler: &mut InputHandler, column: u16, row: u16, at: Instant) -> Vec<Event> {
+        handler.process_at(
+            mouse(MouseEventKind::Down(CtMouseButton::Left), column, row),
+            at,
+        );
+        handler.process_at(
+            mouse(MouseEventKind::Up(CtMouseButton::Left), column, row),
+            at,
+        )
+    }
+
+    fn click_count(events: &[Event]) -> Option<u8> {
+        events.iter().find_map(|e| match e {
+            Event::Click { count, .. } => Some(*count),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_process_single_click() {
+        let mut handler = InputHandler::new();
+        let out = click_at(&mut handler, 5, 5, Instant::now());
+        assert_eq!(
+            out[1],
+            Event::Click {
+                position: Point::new(5.0, 5.0),
+                button: MouseButton::Left,
+                count: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_process_double_and_triple_click() {
+        let mut handler = InputHandler::new();
+        let t0 = Instant::now();
+        assert_eq!(click_count(&click_at(&mut handler, 5, 5, t0)), Some(1));
+        let t1 = t0 + Duration::from_millis(150);
+        assert_eq!(click_count(&click_at(&mut handler, 6, 5, t1)), Some(2));
+        let t2 = t1 + Duration::from_millis(150);
+        assert_eq!(click_count(&click_at(&mut handler, 6, 5, t2)), Some(3));
+    }
+
+    #[test]
+    fn test_process_click_resets_on_distance() {
+        let mut handler = InputHandler::new();
+        let t0 = Instant::now();
+        click_at(&mut handler, 5, 5, t0);
+        let out = click_at(&mut handler, 9, 5, t0 + Duration::from_millis(100));
+        assert_eq!(click_count(&out), Some(1));
+    }
+
+    #[test]
+    fn test_process_click_resets_after_window() {
+        let mut handler = InputHandler::new();
+        let t0 = Instant::now();
+        click_at(&mut handler, 5, 5, t0);
+        let out = click_at(&mut handler, 5, 5, t0 + Duration::from_millis(500));
+        assert_eq!(click_count(&out), Some(1));
+    }
+
+    #[test]
+    fn test_process_click_custom_window() {
+        let mut handler = InputHandler::new().with_click_window(Duration::from_secs(1));
+        let t0 = Instant::now();
+        click_at(&mut handler, 5, 5, t0);
+        let out = click_at(&mut handler, 5, 5, t0 + Duration::from_millis(800));
+        assert_eq!(click_count(&out), Some(2));
+    }
+
+    #[test]
+    fn test_process_click_resets_on_other_button() {
+        let mut handler = InputHandler::new();
+        let t0 = Instant::now();
+        click_at(&mut handler, 5, 5, t0);
+        handler.process_at(mouse(MouseEventKind::Down(CtMouseButton::Right), 5, 5), t0);
+        let out = handler.process_at(mouse(MouseEventKind::Up(CtMouseButton::Right), 5, 5), t0);
+        assert_eq!(click_count(&out), Some(1));
+    }
+
+    #[test]
+    fn test_process_drag_without_down_starts_at_current() {
+        let mut handler = InputHandler::new();
+        let out = handler.process(mouse(MouseEventKind::Drag(CtMouseButton::Right), 4, 4));
+        assert!(matches!(
+            out[1],
+            Event::Drag { start, current, button: MouseButton::Right } if start == current
+        ));
+    }
+
+    #[test]
+    fn test_process_plain_move_and_key() {
+        let mut handler = InputHandler::new();
+        let out = handler.process(mouse(MouseEventKind::Moved, 3, 3));
+        assert_eq!(out.len(), 1);
+        let out = handler.process(CrosstermEvent::Key(KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+        assert_eq!(out, vec![Event::key_down(Key::Enter)]);
+    }
+
     #[test]
     fn test_convert_resize() {
         let handler = InputHandler::new();
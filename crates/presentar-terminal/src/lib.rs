@@ -87,18 +87,26 @@ pub mod widgets;
 pub mod ptop;
 
 // Re-export main types
-pub use app::{AsyncCollector, QaTimings, Snapshot, SnapshotReceiver, TuiApp, TuiConfig};
+pub use app::{
+    restore_terminal, AsyncCollector, QaTimings, Snapshot, SnapshotReceiver, TuiApp, TuiConfig,
+};
 pub use color::ColorMode;
-pub use direct::{Cell, CellBuffer, DiffRenderer, DirectTerminalCanvas, Modifiers};
+pub use direct::{
+    Cell, CellBuffer, DiffRenderer, DirectTerminalCanvas, FrameDiffStats, Modifiers,
+    UnderlineStyle, Viewport,
+};
 pub use error::TuiError;
-pub use input::{InputHandler, KeyBinding};
-pub use theme::{Gradient, Theme};
+pub use input::{
+    InputHandler, KeyBinding, KeyDispatch, DEFAULT_CLICK_WINDOW, DEFAULT_SEQUENCE_TIMEOUT,
+};
+pub use theme::{Gradient, Interpolation, Theme};
 
 // Re-export widget types
 pub use widgets::{
     truncate,
     // Data science widgets (sklearn/ggplot style)
     Axis,
+    AxisScale,
     BarStyle,
     BinStrategy,
     // Legacy widgets (being phased out)
@@ -107,6 +115,8 @@ pub use widgets::{
     BoxPlot,
     BoxStats,
     BrailleGraph,
+    Candlestick,
+    ChartMode,
     ColumnHighlight,
     CompactBreakdown,
     ConfusionMatrix,
@@ -154,6 +164,7 @@ pub use widgets::{
     NetworkPanel,
     NodeId,
     Normalization,
+    Ohlc,
     Orientation,
     ProcessEntry,
     ProcessSort,
@@ -169,6 +180,7 @@ pub use widgets::{
     Series,
     Simplification,
     Sparkline,
+    SparklineSampling,
     Table,
     TitleBar,
     TitleBarPosition,
@@ -183,6 +195,7 @@ pub use widgets::{
     ViolinOrientation,
     ViolinPlot,
     ViolinStats,
+    Waterfall,
     DIMMED_BG,
     SELECTION_ACCENT,
     SELECTION_BG,
@@ -202,8 +215,9 @@ pub use random_seed::{
 
 // Re-export ComputeBlock types (SPEC-024 Section 15, 20)
 pub use compute_block::{
-    ComputeBlock, ComputeBlockId, CpuFrequencyBlock, CpuGovernor, CpuGovernorBlock,
-    FrequencyScalingState, GpuThermalBlock, GpuThermalState, GpuVramBlock, HugePagesBlock,
-    LoadTrendBlock, MemPressureBlock, MemoryPressureLevel, SimdInstructionSet, SparklineBlock,
+    ComputeBlock, ComputeBlockId, ComputeBlockRegistry, CpuFrequencyBlock, CpuGovernor,
+    CpuGovernorBlock, DiskPressureBlock, DiskPressureLevel, FrequencyScalingState, GpuThermalBlock,
+    GpuThermalState, GpuVramBlock, HugePagesBlock, LoadTrendBlock, MemPressureBlock,
+    MemoryPressureLevel, SimdInstructionSet, SparklineBlock,
     TrendDirection as ComputeTrendDirection,
 };
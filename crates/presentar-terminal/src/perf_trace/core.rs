@@ -1,4 +1,7 @@
+#[cfg(feature = "export")]
+use serde::Serialize;
 use std::collections::HashMap;
+use std::fmt::Write as _;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
@@ -356,6 +359,78 @@ impl BrickProfiler {
 
         lines.join("\n")
     }
+
+    /// Export stats in Prometheus text exposition format
+    ///
+    /// Each brick gets `name` and `type` labels; names are sanitized to
+    /// `[a-zA-Z0-9_]` so they are safe to scrape and query.
+    #[must_use]
+    pub fn export_prometheus(&self) -> String {
+        let mut sorted: Vec<_> = self.stats.iter().collect();
+        sorted.sort_by(|a, b| a.0.cmp(b.0));
+
+        let labels: Vec<String> = sorted
+            .iter()
+            .map(|(name, (brick_type, _))| {
+                format!(
+                    "name=\"{}\",type=\"{brick_type:?}\"",
+                    sanitize_metric_label(name)
+                )
+            })
+            .collect();
+
+        let mut out = String::new();
+        type Gauge = fn(&BrickType, &SimdStats) -> f64;
+        let gauges: [(&str, &str, Gauge); 3] = [
+            (
+                "brick_avg_micros",
+                "Average brick execution time in microseconds.",
+                |_, stats| stats.mean(),
+            ),
+            (
+                "brick_max_micros",
+                "Maximum brick execution time in microseconds.",
+                |_, stats| stats.max,
+            ),
+            (
+                "brick_budget_micros",
+                "Execution budget for the brick type in microseconds.",
+                |brick_type, _| brick_type.default_budget_us() as f64,
+            ),
+        ];
+        for (metric, help, value) in gauges {
+            let _ = writeln!(out, "# HELP {metric} {help}");
+            let _ = writeln!(out, "# TYPE {metric} gauge");
+            for ((_, (brick_type, stats)), label) in sorted.iter().zip(&labels) {
+                let _ = writeln!(out, "{metric}{{{label}}} {}", value(brick_type, stats));
+            }
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP brick_micros Brick execution time in microseconds."
+        );
+        let _ = writeln!(out, "# TYPE brick_micros summary");
+        for ((_, (_, stats)), label) in sorted.iter().zip(&labels) {
+            let _ = writeln!(out, "brick_micros_sum{{{label}}} {}", stats.sum);
+            let _ = writeln!(out, "brick_micros_count{{{label}}} {}", stats.count);
+        }
+
+        out
+    }
+}
+
+/// Replace characters outside `[a-zA-Z0-9_]` so a name is a safe metric label
+fn sanitize_metric_label(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if sanitized.is_empty() {
+        "_".to_string()
+    } else {
+        sanitized
+    }
 }
 
 /// Performance trace event (compatible with renacer `TraceEvent`)
@@ -547,7 +622,7 @@ impl PerfTracer {
     }
 
     /// Record a trace event
-    fn record_trace(&mut self, name: &str, duration: Duration, budget_us: u64) {
+    pub(super) fn record_trace(&mut self, name: &str, duration: Duration, budget_us: u64) {
         let timestamp_us = self.start_time.elapsed().as_micros() as u64;
         let budget_exceeded = duration.as_micros() as u64 > budget_us;
 
@@ -671,9 +746,159 @@ impl PerfTracer {
         lines.join("\n")
     }
 
+    /// Export recent events in Chrome Trace Event Format
+    ///
+    /// Produces a JSON array of complete (`"ph":"X"`) events that loads
+    /// directly into `chrome://tracing` or Perfetto. Timestamps and durations
+    /// are in microseconds, as the format expects.
+    #[cfg(feature = "export")]
+    #[must_use]
+    pub fn export_chrome_json(&self) -> String {
+        let pid = std::process::id();
+        let events: Vec<ChromeTraceEvent<'_>> = self
+            .recent_events
+            .iter()
+            .map(|event| ChromeTraceEvent {
+                name: &event.name,
+                cat: "presentar",
+                ph: "X",
+                ts: event.timestamp_us,
+                dur: u64::try_from(event.duration.as_micros()).unwrap_or(u64::MAX),
+                pid,
+                tid: 1,
+                args: ChromeTraceArgs {
+                    budget_us: event.budget_us.unwrap_or(0),
+                    budget_exceeded: event.budget_exceeded,
+                },
+            })
+            .collect();
+
+        serde_json::to_string(&events).expect("trace events serialize to JSON")
+    }
+
     /// Clear all stats
     pub fn clear(&mut self) {
         self.stats.clear();
         self.recent_events.clear();
     }
 }
+
+/// One complete (`"ph":"X"`) event in Chrome Trace Event Format
+#[cfg(feature = "export")]
+#[derive(Serialize)]
+struct ChromeTraceEvent<'a> {
+    name: &'a str,
+    cat: &'static str,
+    ph: &'static str,
+    ts: u64,
+    dur: u64,
+    pid: u32,
+    tid: u32,
+    args: ChromeTraceArgs,
+}
+
+/// Budget details attached to a Chrome trace event
+#[cfg(feature = "export")]
+#[derive(Serialize)]
+struct ChromeTraceArgs {
+    budget_us: u64,
+    budget_exceeded: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracer_with(events: &[(&str, u64)]) -> PerfTracer {
+        let mut tracer = PerfTracer::new();
+        for &(name, micros) in events {
+            tracer.record_trace(name, Duration::from_micros(micros), 1000);
+        }
+        tracer
+    }
+
+    #[test]
+    #[cfg(feature = "export")]
+    fn test_export_chrome_json_one_event_per_trace() {
+        let tracer = tracer_with(&[("collect", 1500), ("render", 250), ("collect", 900)]);
+        let json: serde_json::Value =
+            serde_json::from_str(&tracer.export_chrome_json()).expect("valid JSON");
+        let events = json.as_array().expect("array of events");
+
+        assert_eq!(events.len(), 3);
+        let durations: Vec<u64> = events
+            .iter()
+            .map(|e| e["dur"].as_u64().expect("dur"))
+            .collect();
+        assert_eq!(durations, vec![1500, 250, 900]);
+        for event in events {
+            assert_eq!(event["ph"], "X");
+            assert!(event["ts"].is_u64());
+            assert!(event["pid"].is_u64());
+            assert!(event["tid"].is_u64());
+        }
+        assert_eq!(events[0]["name"], "collect");
+        assert_eq!(events[0]["args"]["budget_exceeded"], true);
+        assert_eq!(events[1]["args"]["budget_exceeded"], false);
+    }
+
+    #[test]
+    #[cfg(feature = "export")]
+    fn test_export_chrome_json_escapes_names() {
+        let tracer = tracer_with(&[("say \"hi\"\n", 10)]);
+        let json: serde_json::Value =
+            serde_json::from_str(&tracer.export_chrome_json()).expect("valid JSON");
+        assert_eq!(json[0]["name"], "say \"hi\"\n");
+    }
+
+    fn profiler_with(bricks: &[(&str, BrickType, &[f64])]) -> BrickProfiler {
+        let mut profiler = BrickProfiler::new();
+        for &(name, brick_type, samples) in bricks {
+            let mut stats = SimdStats::new();
+            for &sample in samples {
+                stats.update(sample);
+            }
+            profiler.stats.insert(name.to_string(), (brick_type, stats));
+        }
+        profiler
+    }
+
+    #[test]
+    fn test_export_prometheus_line_per_brick() {
+        let profiler = profiler_with(&[
+            ("render", BrickType::Render, &[100.0, 300.0]),
+            ("collect-cpu stats", BrickType::Collect, &[5000.0]),
+        ]);
+        let text = profiler.export_prometheus();
+
+        assert!(text.contains("# HELP brick_avg_micros "));
+        assert!(text.contains("# TYPE brick_avg_micros gauge"));
+        assert!(text.contains("brick_avg_micros{name=\"render\",type=\"Render\"} 200"));
+        assert!(text.contains("brick_avg_micros{name=\"collect_cpu_stats\",type=\"Collect\"} 5000"));
+        assert!(text.contains("brick_micros_count{name=\"render\",type=\"Render\"} 2"));
+        assert!(text.contains("brick_micros_sum{name=\"render\",type=\"Render\"} 400"));
+
+        for line in text.lines().filter(|l| !l.starts_with('#')) {
+            let (metric, rest) = line.split_once('{').expect("labelled sample");
+            assert!(!metric.contains(' ') && !metric.contains('-'), "{metric}");
+            let labels = rest.split_once('}').expect("closing brace").0;
+            assert!(!labels.contains(' ') && !labels.contains('-'), "{labels}");
+        }
+    }
+
+    #[test]
+    fn test_sanitize_metric_label() {
+        assert_eq!(
+            sanitize_metric_label("render-cpu panel"),
+            "render_cpu_panel"
+        );
+        assert_eq!(sanitize_metric_label("ok_1"), "ok_1");
+        assert_eq!(sanitize_metric_label(""), "_");
+    }
+
+    #[test]
+    #[cfg(feature = "export")]
+    fn test_export_chrome_json_empty() {
+        assert_eq!(PerfTracer::new().export_chrome_json(), "[]");
+    }
+}
@@ -1,3 +1,5 @@
+use crate::random_seed::SeededRng;
+
 // =============================================================================
 // FIXED-SIZE RING BUFFER (trueno-viz O(1) history pattern)
 // =============================================================================
@@ -133,6 +135,46 @@ impl<const N: usize> RingBuffer<f64, N> {
             .copied()
             .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
     }
+
+    /// Sample variance of the window (O(n), n-1 denominator like `SimdStats`)
+    ///
+    /// Uses two passes around the mean for precision on small windows.
+    #[must_use]
+    pub fn variance(&self) -> f64 {
+        if self.len < 2 {
+            return 0.0;
+        }
+        let mean = self.mean();
+        let sum_sq: f64 = self.iter().map(|v| (v - mean) * (v - mean)).sum();
+        sum_sq / (self.len - 1) as f64
+    }
+
+    /// Sample standard deviation of the window (O(n))
+    #[must_use]
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Median of the window (O(n log n) over a stack copy, no allocation)
+    #[must_use]
+    pub fn median(&self) -> Option<f64> {
+        if self.len == 0 {
+            return None;
+        }
+        let mut window = [0.0; N];
+        for (slot, &v) in window.iter_mut().zip(self.iter()) {
+            *slot = v;
+        }
+        let window = &mut window[..self.len];
+        window.sort_by(f64::total_cmp);
+
+        let mid = self.len / 2;
+        if self.len % 2 == 0 {
+            Some((window[mid - 1] + window[mid]) / 2.0)
+        } else {
+            Some(window[mid])
+        }
+    }
 }
 
 // =============================================================================
@@ -243,6 +285,130 @@ impl LatencyHistogram {
     }
 }
 
+// =============================================================================
+// RESERVOIR SAMPLER (exact percentiles over a uniform sample)
+// =============================================================================
+
+/// Fixed-size uniform random sample of a stream (Vitter's Algorithm R)
+///
+/// Complements the histogram-based `PercentileTracker`: recording is O(1)
+/// and percentiles are exact over the retained sample (O(N log N) sort), which
+/// suits SLO reporting where bucket midpoints are too coarse. Sampling uses
+/// `SeededRng`, so a given seed and input stream always yield the same sample.
+/// Unlike `ReservoirSampler`, which is capped at 16 slots, the capacity is a
+/// compile-time parameter.
+#[derive(Debug, Clone)]
+pub struct FixedReservoir<const N: usize> {
+    samples: [f64; N],
+    len: usize,
+    seen: u64,
+    rng: SeededRng,
+}
+
+impl<const N: usize> Default for FixedReservoir<N> {
+    fn default() -> Self {
+        Self::with_rng(SeededRng::from_global_seed())
+    }
+}
+
+impl<const N: usize> FixedReservoir<N> {
+    /// Create a sampler with a fixed seed
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self::with_rng(SeededRng::new(seed))
+    }
+
+    fn with_rng(rng: SeededRng) -> Self {
+        Self {
+            samples: [0.0; N],
+            len: 0,
+            seen: 0,
+            rng,
+        }
+    }
+
+    /// Record a value (O(1))
+    pub fn record(&mut self, value: f64) {
+        self.seen += 1;
+        if self.len < N {
+            self.samples[self.len] = value;
+            self.len += 1;
+        } else if N > 0 {
+            let slot = self.rng.next_u64() % self.seen;
+            if slot < N as u64 {
+                self.samples[slot as usize] = value;
+            }
+        }
+    }
+
+    /// Number of retained samples
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if no samples are retained
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Total number of values recorded
+    #[must_use]
+    pub fn seen(&self) -> u64 {
+        self.seen
+    }
+
+    /// Retained samples (in reservoir order, not sorted)
+    #[must_use]
+    pub fn samples(&self) -> &[f64] {
+        &self.samples[..self.len]
+    }
+
+    /// Exact percentile of the retained sample (linear interpolation)
+    ///
+    /// Returns `None` when no samples have been recorded.
+    #[must_use]
+    pub fn percentile(&self, pct: f64) -> Option<f64> {
+        self.percentiles(&[pct]).pop()
+    }
+
+    /// Several exact percentiles with a single sort
+    ///
+    /// Returns an empty vector when no samples have been recorded.
+    #[must_use]
+    pub fn percentiles(&self, pcts: &[f64]) -> Vec<f64> {
+        if self.len == 0 {
+            return Vec::new();
+        }
+        let mut sorted = self.samples().to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let last = (sorted.len() - 1) as f64;
+        pcts.iter()
+            .map(|&pct| {
+                let rank = (pct.clamp(0.0, 100.0) / 100.0) * last;
+                let lower = rank.floor() as usize;
+                let upper = rank.ceil() as usize;
+                let frac = rank - lower as f64;
+                (sorted[upper] - sorted[lower]).mul_add(frac, sorted[lower])
+            })
+            .collect()
+    }
+
+    /// Exact median of the retained sample
+    #[must_use]
+    pub fn p50(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    /// Clear all samples (the RNG stream continues)
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.seen = 0;
+    }
+}
+
 // =============================================================================
 // TRACKER MACRO (PMAT-019: reduce ResourceManagement entropy)
 // =============================================================================
@@ -279,3 +445,102 @@ macro_rules! define_tracker {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_variance_hand_computed() {
+        let mut ring = RingBuffer::<f64, 5>::new();
+        for v in [2.0, 4.0, 4.0, 5.0, 7.0] {
+            ring.push(v);
+        }
+        // mean = 22 / 5 = 4.4
+        // squared deviations: 5.76 + 0.16 + 0.16 + 0.36 + 6.76 = 13.2
+        // sample variance = 13.2 / 4 = 3.3
+        assert!((ring.variance() - 3.3).abs() < 1e-12);
+        assert!((ring.std_dev() - ring.variance().sqrt()).abs() < f64::EPSILON);
+        assert_eq!(ring.median(), Some(4.0));
+    }
+
+    #[test]
+    fn test_ring_buffer_stats_follow_window() {
+        let mut ring = RingBuffer::<f64, 4>::new();
+        assert!(ring.variance().abs() < f64::EPSILON);
+        assert_eq!(ring.median(), None);
+
+        ring.push(10.0);
+        assert!(ring.variance().abs() < f64::EPSILON);
+        assert_eq!(ring.median(), Some(10.0));
+
+        // Window keeps the newest 4: [3, 1, 4, 2]
+        for v in [100.0, 3.0, 1.0, 4.0, 2.0] {
+            ring.push(v);
+        }
+        assert_eq!(ring.median(), Some(2.5));
+        // mean 2.5, squared deviations 0.25 + 2.25 + 2.25 + 0.25 = 5, / 3
+        assert!((ring.variance() - 5.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_reservoir_exact_under_capacity() {
+        let mut sampler = FixedReservoir::<16>::new(7);
+        for v in [5.0, 1.0, 4.0, 2.0, 3.0] {
+            sampler.record(v);
+        }
+        assert_eq!(sampler.len(), 5);
+        assert_eq!(sampler.p50(), Some(3.0));
+        assert_eq!(sampler.percentile(0.0), Some(1.0));
+        assert_eq!(sampler.percentile(100.0), Some(5.0));
+        assert_eq!(sampler.percentile(25.0), Some(2.0));
+        assert_eq!(sampler.percentile(90.0), Some(4.6));
+    }
+
+    #[test]
+    fn test_reservoir_seeded_p50_is_stable() {
+        let run = || {
+            let mut sampler = FixedReservoir::<256>::new(42);
+            for i in 0..10_000 {
+                sampler.record(f64::from(i));
+            }
+            sampler
+        };
+        let a = run();
+        let b = run();
+
+        assert_eq!(a.len(), 256);
+        assert_eq!(a.seen(), 10_000);
+        assert_eq!(a.samples(), b.samples());
+        let p50 = a.p50().expect("samples");
+        assert_eq!(Some(p50), b.p50());
+        // A uniform sample of 0..10000 should have its median near 5000
+        assert!((4000.0..6000.0).contains(&p50), "p50 = {p50}");
+    }
+
+    #[test]
+    fn test_reservoir_batch_matches_individual() {
+        let mut sampler = FixedReservoir::<64>::new(3);
+        for i in 0..1000 {
+            sampler.record(f64::from(i % 97));
+        }
+        let pcts = [99.9, 50.0, 0.0, 75.0];
+        let batch = sampler.percentiles(&pcts);
+        for (&pct, &value) in pcts.iter().zip(&batch) {
+            assert_eq!(sampler.percentile(pct), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_reservoir_empty_and_reset() {
+        let mut sampler = FixedReservoir::<8>::new(1);
+        assert!(sampler.is_empty());
+        assert_eq!(sampler.p50(), None);
+        assert!(sampler.percentiles(&[50.0]).is_empty());
+
+        sampler.record(1.0);
+        sampler.reset();
+        assert!(sampler.is_empty());
+        assert_eq!(sampler.seen(), 0);
+    }
+}
@@ -0,0 +1,142 @@
+//! Terminal flamegraph rendering for [`PerfTracer`] stats
+
+use super::PerfTracer;
+use crate::widgets::display_rules::{truncate, TruncateStrategy};
+use presentar_core::{Canvas, Color, Point, Rect, TextStyle};
+
+impl PerfTracer {
+    /// Render a one-level flamegraph of total time per operation
+    ///
+    /// Bars are laid side by side across `area`, widest (most total time)
+    /// first, each sized proportionally to its share of the overall time.
+    /// Operations that ever exceeded their budget are drawn red, others green.
+    pub fn render_flamegraph(&self, canvas: &mut dyn Canvas, area: Rect) {
+        let columns = area.width.floor().max(0.0) as u64;
+        if columns == 0 || area.height < 1.0 {
+            return;
+        }
+
+        let mut sorted: Vec<_> = self.all_stats().iter().collect();
+        sorted.sort_by(|a, b| {
+            b.1.total_duration
+                .cmp(&a.1.total_duration)
+                .then_with(|| a.0.cmp(b.0))
+        });
+        let total: u128 = sorted
+            .iter()
+            .map(|(_, s)| s.total_duration.as_micros())
+            .sum();
+        if total == 0 {
+            return;
+        }
+
+        // Round cumulative edges so the bars tile the area without gaps
+        let mut elapsed: u128 = 0;
+        let mut left = 0u64;
+        for (name, stats) in sorted {
+            elapsed += stats.total_duration.as_micros();
+            let right = ((elapsed * u128::from(columns) + total / 2) / total) as u64;
+            let width = right.saturating_sub(left);
+            if width == 0 {
+                continue;
+            }
+
+            let bar = Rect::new(area.x + left as f32, area.y, width as f32, area.height);
+            let color = if stats.budget_violations > 0 {
+                FLAME_OVER_BUDGET
+            } else {
+                FLAME_WITHIN_BUDGET
+            };
+            canvas.fill_rect(bar, color);
+
+            let label = truncate(name, width as usize, TruncateStrategy::End);
+            canvas.draw_text(
+                &label,
+                Point::new(bar.x, bar.y),
+                &TextStyle {
+                    color: Color::BLACK,
+                    ..Default::default()
+                },
+            );
+            left = right;
+        }
+    }
+}
+
+/// Flamegraph bar color for operations that exceeded their budget
+const FLAME_OVER_BUDGET: Color = Color {
+    r: 0.90,
+    g: 0.35,
+    b: 0.30,
+    a: 1.0,
+};
+/// Flamegraph bar color for operations within budget
+const FLAME_WITHIN_BUDGET: Color = Color {
+    r: 0.40,
+    g: 0.75,
+    b: 0.40,
+    a: 1.0,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::{CellBuffer, DirectTerminalCanvas};
+    use std::time::Duration;
+
+    fn tracer_with(events: &[(&str, u64)]) -> PerfTracer {
+        let mut tracer = PerfTracer::new();
+        for &(name, micros) in events {
+            tracer.record_trace(name, Duration::from_micros(micros), 1000);
+        }
+        tracer
+    }
+
+    #[test]
+    fn test_render_flamegraph_width_follows_total_time() {
+        // "collect" totals 3ms (over its 1ms budget), "render" 1ms (within)
+        let tracer = tracer_with(&[("render", 1000), ("collect", 1500), ("collect", 1500)]);
+        let mut buffer = CellBuffer::new(40, 2);
+        {
+            let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+            tracer.render_flamegraph(&mut canvas, Rect::new(0.0, 0.0, 40.0, 2.0));
+        }
+
+        let bg = |x: u16| buffer.get(x, 1).expect("cell").bg;
+        let over = (0..40).filter(|&x| bg(x) == FLAME_OVER_BUDGET).count();
+        let within = (0..40).filter(|&x| bg(x) == FLAME_WITHIN_BUDGET).count();
+        assert_eq!(over, 30);
+        assert_eq!(within, 10);
+        assert_eq!(bg(0), FLAME_OVER_BUDGET);
+
+        let row = &buffer.rows_as_strings()[0];
+        assert!(row.starts_with("collect"));
+        assert_eq!(&row[30..36], "render");
+    }
+
+    #[test]
+    fn test_render_flamegraph_truncates_labels() {
+        let tracer = tracer_with(&[("a_very_long_operation", 100), ("big", 900)]);
+        let mut buffer = CellBuffer::new(20, 1);
+        {
+            let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+            tracer.render_flamegraph(&mut canvas, Rect::new(0.0, 0.0, 20.0, 1.0));
+        }
+        let row = &buffer.rows_as_strings()[0];
+        // 10% of 20 columns = 2 columns: too narrow, so only the ellipsis fits
+        assert!(row.starts_with("big"));
+        assert!(row.ends_with("… "));
+        assert!(!row.contains("a_"));
+    }
+
+    #[test]
+    fn test_render_flamegraph_empty_tracer() {
+        let mut buffer = CellBuffer::new(10, 1);
+        buffer.clear_dirty();
+        {
+            let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+            PerfTracer::new().render_flamegraph(&mut canvas, Rect::new(0.0, 0.0, 10.0, 1.0));
+        }
+        assert_eq!(buffer.dirty_count(), 0);
+    }
+}
@@ -38,6 +38,7 @@
 mod analysis;
 mod core;
 mod data_structures;
+mod flamegraph;
 mod helpers_batch;
 mod helpers_infra;
 mod trackers;
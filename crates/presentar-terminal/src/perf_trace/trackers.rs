@@ -805,6 +805,26 @@ impl PercentileTracker {
         }
     }
 
+    /// Create a tracker with boundaries tuned for fast (sub-10ms) operations
+    ///
+    /// The default buckets are too coarse near the top for p99.9 on fast
+    /// operations; these are dense between 0.5ms and 8ms instead.
+    #[must_use]
+    pub fn for_fast_ops() -> Self {
+        Self::with_boundaries([
+            100,      // 0-0.1ms
+            250,      // 0.1-0.25ms
+            500,      // 0.25-0.5ms
+            750,      // 0.5-0.75ms
+            1_000,    // 0.75-1ms
+            1_500,    // 1-1.5ms
+            2_000,    // 1.5-2ms
+            4_000,    // 2-4ms
+            8_000,    // 4-8ms
+            u64::MAX, // 8ms+
+        ])
+    }
+
     /// Create with custom bucket boundaries (in microseconds)
     #[must_use]
     pub fn with_boundaries(boundaries: [u64; 10]) -> Self {
@@ -841,25 +861,69 @@ impl PercentileTracker {
             return 0;
         }
 
-        let target = ((pct / 100.0) * self.count as f64) as u64;
+        let target = self.target_rank(pct);
         let mut cumulative = 0u64;
 
         for (i, &bucket_count) in self.buckets.iter().enumerate() {
             cumulative += bucket_count;
             if cumulative >= target {
-                // Return midpoint of bucket
-                let lower = if i == 0 { 0 } else { self.boundaries[i - 1] };
-                let upper = self.boundaries[i];
-                if upper == u64::MAX {
-                    return lower + 500_000; // Estimate for last bucket
-                }
-                return (lower + upper) / 2;
+                return self.bucket_estimate_us(i);
             }
         }
 
         self.boundaries[8] // Return 1s as fallback
     }
 
+    /// Get several approximate percentiles in milliseconds in one pass (O(k log k))
+    ///
+    /// Results are returned in the same order as `pcts`.
+    #[must_use]
+    pub fn percentiles(&self, pcts: &[f64]) -> Vec<f64> {
+        let mut results = vec![0.0; pcts.len()];
+        if self.count == 0 {
+            return results;
+        }
+
+        let mut order: Vec<usize> = (0..pcts.len()).collect();
+        order.sort_by(|&a, &b| pcts[a].total_cmp(&pcts[b]));
+
+        let mut pending = order.into_iter().peekable();
+        let mut cumulative = 0u64;
+        for (i, &bucket_count) in self.buckets.iter().enumerate() {
+            cumulative += bucket_count;
+            while let Some(&idx) = pending.peek() {
+                if cumulative < self.target_rank(pcts[idx]) {
+                    break;
+                }
+                results[idx] = self.bucket_estimate_us(i) as f64 / 1000.0;
+                pending.next();
+            }
+        }
+        for idx in pending {
+            results[idx] = self.boundaries[8] as f64 / 1000.0;
+        }
+
+        results
+    }
+
+    /// Rank of the sample at the given percentile
+    fn target_rank(&self, pct: f64) -> u64 {
+        // At least one sample, or leading empty buckets would match
+        (((pct / 100.0) * self.count as f64) as u64).max(1)
+    }
+
+    /// Representative value of a bucket in microseconds (its midpoint)
+    fn bucket_estimate_us(&self, i: usize) -> u64 {
+        let lower = if i == 0 { 0 } else { self.boundaries[i - 1] };
+        let upper = self.boundaries[i];
+        if upper == u64::MAX {
+            // Open-ended bucket: extend by the width of the previous bucket
+            let prev_lower = if i < 2 { 0 } else { self.boundaries[i - 2] };
+            return lower.saturating_add(lower.saturating_sub(prev_lower));
+        }
+        (lower + upper) / 2
+    }
+
     /// Get approximate percentile value in milliseconds (O(1))
     #[must_use]
     pub fn percentile_ms(&self, pct: f64) -> f64 {
@@ -884,6 +948,12 @@ impl PercentileTracker {
         self.percentile_ms(99.0)
     }
 
+    /// Get p99.9 in milliseconds
+    #[must_use]
+    pub fn p999_ms(&self) -> f64 {
+        self.percentile_ms(99.9)
+    }
+
     /// Get total count
     #[must_use]
     pub fn count(&self) -> u64 {
@@ -1675,3 +1745,65 @@ impl AnomalyDetector {
         self.anomaly_count = 0;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentiles_batch_matches_individual() {
+        let mut tracker = PercentileTracker::new();
+        for i in 0..1000u64 {
+            tracker.record_us(i * 997 % 300_000);
+        }
+        tracker.record_us(2_000_000);
+
+        let pcts = [99.9, 50.0, 90.0, 0.0, 99.0, 100.0];
+        let batch = tracker.percentiles(&pcts);
+        assert_eq!(batch.len(), pcts.len());
+        for (&pct, &value) in pcts.iter().zip(&batch) {
+            assert!(
+                (value - tracker.percentile_ms(pct)).abs() < f64::EPSILON,
+                "p{pct}"
+            );
+        }
+        assert!((tracker.p999_ms() - batch[0]).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_percentiles_empty() {
+        let tracker = PercentileTracker::new();
+        assert_eq!(tracker.percentiles(&[50.0, 99.9]), vec![0.0, 0.0]);
+        assert!(tracker.p999_ms().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_for_fast_ops_resolves_tail() {
+        let mut coarse = PercentileTracker::new();
+        let mut fast = PercentileTracker::for_fast_ops();
+        for _ in 0..998 {
+            coarse.record_us(200);
+            fast.record_us(200);
+        }
+        for _ in 0..2 {
+            coarse.record_us(1_800);
+            fast.record_us(1_800);
+        }
+
+        // Default buckets lump 1-5ms together; fast-op buckets place the tail in 1.5-2ms
+        assert!((coarse.p999_ms() - 3.0).abs() < f64::EPSILON);
+        assert!((fast.p999_ms() - 1.75).abs() < f64::EPSILON);
+        assert!((fast.p50_ms() - 0.175).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_percentile_open_bucket_estimate() {
+        let mut tracker = PercentileTracker::new();
+        tracker.record_us(5_000_000);
+        assert_eq!(tracker.percentile_us(50.0), 1_500_000);
+
+        let mut fast = PercentileTracker::for_fast_ops();
+        fast.record_us(50_000);
+        assert_eq!(fast.percentile_us(50.0), 12_000);
+    }
+}
@@ -16,7 +16,7 @@
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use super::{Analyzer, AnalyzerError};
 
@@ -73,6 +73,14 @@ pub struct ProcessExtra {
     pub voluntary_ctxt_switches: u64,
     /// Involuntary context switches
     pub nonvoluntary_ctxt_switches: u64,
+    /// Cumulative bytes read from storage (`None` if `/proc/[pid]/io` is unreadable)
+    pub io_read_bytes: Option<u64>,
+    /// Cumulative bytes written to storage (`None` if `/proc/[pid]/io` is unreadable)
+    pub io_write_bytes: Option<u64>,
+    /// Read rate since the previous collection (bytes/sec)
+    pub read_bytes_per_sec: Option<f64>,
+    /// Write rate since the previous collection (bytes/sec)
+    pub write_bytes_per_sec: Option<f64>,
 }
 
 impl ProcessExtra {
@@ -190,6 +198,8 @@ impl ProcessExtraData {
 pub struct ProcessExtraAnalyzer {
     data: ProcessExtraData,
     interval: Duration,
+    /// Time of the previous collection, for I/O rate deltas
+    last_collect: Option<Instant>,
 }
 
 impl Default for ProcessExtraAnalyzer {
@@ -204,6 +214,7 @@ impl ProcessExtraAnalyzer {
         Self {
             data: ProcessExtraData::default(),
             interval: Duration::from_secs(2),
+            last_collect: None,
         }
     }
 
@@ -287,9 +298,30 @@ impl ProcessExtraAnalyzer {
         extra.io_class = IoPriorityClass::BestEffort;
         extra.io_priority = 4; // Default best-effort priority
 
+        // Read cumulative I/O byte counters (other users' processes need root)
+        if let Ok(content) = fs::read_to_string(proc_path.join("io")) {
+            (extra.io_read_bytes, extra.io_write_bytes) = Self::parse_io_bytes(&content);
+        }
+
         Some(extra)
     }
 
+    /// Parse `read_bytes` / `write_bytes` from `/proc/[pid]/io`
+    fn parse_io_bytes(content: &str) -> (Option<u64>, Option<u64>) {
+        let mut read = None;
+        let mut write = None;
+        for line in content.lines() {
+            if let Some((key, value)) = line.split_once(':') {
+                match key {
+                    "read_bytes" => read = value.trim().parse().ok(),
+                    "write_bytes" => write = value.trim().parse().ok(),
+                    _ => {}
+                }
+            }
+        }
+        (read, write)
+    }
+
     /// Parse CPU affinity hex mask
     fn parse_cpu_mask(hex: &str) -> Vec<bool> {
         let hex = hex.trim().replace(",", "");
@@ -330,6 +362,8 @@ impl Analyzer for ProcessExtraAnalyzer {
 
     fn collect(&mut self) -> Result<(), AnalyzerError> {
         let mut processes = HashMap::new();
+        let now = Instant::now();
+        let elapsed = self.last_collect.map(|last| now.duration_since(last));
 
         // Iterate over all processes
         let proc_path = Path::new("/proc");
@@ -346,12 +380,23 @@ impl Analyzer for ProcessExtraAnalyzer {
                 continue;
             };
 
-            if let Some(extra) = self.read_process_extra(pid) {
+            if let Some(mut extra) = self.read_process_extra(pid) {
+                if let (Some(elapsed), Some(prev)) = (elapsed, self.data.get(pid)) {
+                    extra.read_bytes_per_sec = prev
+                        .io_read_bytes
+                        .zip(extra.io_read_bytes)
+                        .and_then(|(prev, cur)| io_rate(prev, cur, elapsed));
+                    extra.write_bytes_per_sec = prev
+                        .io_write_bytes
+                        .zip(extra.io_write_bytes)
+                        .and_then(|(prev, cur)| io_rate(prev, cur, elapsed));
+                }
                 processes.insert(pid, extra);
             }
         }
 
         self.data = ProcessExtraData { processes };
+        self.last_collect = Some(now);
         Ok(())
     }
 
@@ -364,6 +409,15 @@ impl Analyzer for ProcessExtraAnalyzer {
     }
 }
 
+/// Bytes/sec between two cumulative I/O counter samples.
+///
+/// Returns `None` for a zero interval. A counter that went backwards (PID
+/// reuse) yields zero rather than a negative rate.
+fn io_rate(previous: u64, current: u64, elapsed: Duration) -> Option<f64> {
+    let secs = elapsed.as_secs_f64();
+    (secs > 0.0).then(|| current.saturating_sub(previous) as f64 / secs)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -387,6 +441,27 @@ mod tests {
         assert!(extra.is_oom_protected());
     }
 
+    #[test]
+    fn test_io_rate_from_successive_totals() {
+        // 4 MiB read and 1 MiB written over a 2 second interval
+        let elapsed = Duration::from_secs(2);
+        assert_eq!(io_rate(1_048_576, 5_242_880, elapsed), Some(2_097_152.0));
+        assert_eq!(io_rate(0, 1_048_576, elapsed), Some(524_288.0));
+        assert_eq!(io_rate(500, 100, elapsed), Some(0.0));
+        assert_eq!(io_rate(0, 100, Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_parse_io_bytes() {
+        let content = "rchar: 323934931\nwchar: 323929600\nsyscr: 632687\nsyscw: 632675\n\
+                       read_bytes: 4096\nwrite_bytes: 323932160\ncancelled_write_bytes: 0\n";
+        assert_eq!(
+            ProcessExtraAnalyzer::parse_io_bytes(content),
+            (Some(4096), Some(323_932_160))
+        );
+        assert_eq!(ProcessExtraAnalyzer::parse_io_bytes(""), (None, None));
+    }
+
     #[test]
     fn test_cgroup_short() {
         let mut extra = ProcessExtra::default();
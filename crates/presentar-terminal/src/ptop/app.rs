@@ -3,6 +3,7 @@
 //! Mirrors ttop's app.rs - maintains system state and history.
 
 use crossterm::event::{KeyCode, KeyModifiers};
+use std::path::Path;
 use std::time::Duration;
 
 use sysinfo::{
@@ -11,6 +12,10 @@ use sysinfo::{
 };
 
 use super::config::{DetailLevel, FilesViewMode, PanelType, PtopConfig, SignalType};
+use super::export;
+use super::ui::panels::battery::BatteryHistory;
+use super::ui::panels::geo_connections::ConnectionSort;
+use super::ui::panels::psi::PsiHistory;
 use super::ui::{read_gpu_info, GpuInfo};
 
 /// Parse a single meminfo line to extract value in bytes.
@@ -190,8 +195,8 @@ fn read_core_temperatures(core_count: usize) -> Vec<f32> {
 }
 
 use super::analyzers::{
-    AnalyzerRegistry, ConnectionsData, DiskEntropyData, DiskIoData, FileAnalyzerData, PsiData,
-    SensorHealthData, TreemapData,
+    AnalyzerRegistry, BatteryData, ConnectionsData, DiskEntropyData, DiskIoData, FileAnalyzerData,
+    PsiData, SensorHealthData, TreemapData,
 };
 use crate::{AsyncCollector, Snapshot};
 
@@ -238,6 +243,7 @@ pub struct MetricsSnapshot {
     pub disk_io_data: Option<DiskIoData>,
     pub disk_entropy_data: Option<DiskEntropyData>,
     pub file_analyzer_data: Option<FileAnalyzerData>,
+    pub battery_data: Option<BatteryData>,
 }
 
 /// Lightweight process info for rendering
@@ -300,6 +306,7 @@ impl Snapshot for MetricsSnapshot {
             disk_io_data: None,
             disk_entropy_data: None,
             file_analyzer_data: None,
+            battery_data: None,
         }
     }
 }
@@ -533,6 +540,7 @@ impl AsyncCollector for MetricsCollector {
             .file_analyzer
             .as_ref()
             .map(|f| f.data().clone());
+        let battery_data = self.analyzers.battery_data().cloned();
 
         MetricsSnapshot {
             cpu_avg: cpu_total as f64 / 100.0,
@@ -559,6 +567,7 @@ impl AsyncCollector for MetricsCollector {
             disk_io_data,
             disk_entropy_data,
             file_analyzer_data,
+            battery_data,
         }
     }
 }
@@ -683,6 +692,7 @@ pub struct PanelVisibility {
     pub connections: bool,
     // Optional panels (P2)
     pub battery: bool,
+    pub containers: bool,
     pub sensors_compact: bool,
     pub system: bool,
     // Advanced panels (P3)
@@ -706,6 +716,7 @@ impl Default for PanelVisibility {
             connections: false,
             // Optional panels - hidden by default, auto-shown via update_visibility()
             battery: false,
+            containers: false,
             sensors_compact: false,
             system: false,
             // Advanced panels - hidden by default
@@ -742,6 +753,10 @@ pub struct App {
     pub vram_history: RingBuffer<f64>,
     /// Cached GPU info (updated during `update()`)
     pub gpu_info: Option<GpuInfo>,
+    /// PSI some/full pressure history per resource (cleared when PSI is unavailable)
+    pub psi_history: PsiHistory,
+    /// Battery charge rate tracker for time-to-empty/full estimates
+    pub battery_history: BatteryHistory,
 
     // Per-core CPU data
     pub per_core_percent: Vec<f64>,
@@ -768,12 +783,16 @@ pub struct App {
     pub show_filter_input: bool,
     pub show_help: bool,
     pub running: bool,
+    /// Collection paused (Space): rendering continues from the last snapshot
+    pub paused: bool,
 
     // Signal handling (SPEC-024 Appendix G.6 P0 - ttop parity)
     /// Pending signal confirmation: (pid, process_name, signal_type)
     pub pending_signal: Option<(u32, String, SignalType)>,
     /// Last signal result: (success, message, timestamp) - auto-clears after 3s (PMAT-GAP-033)
     pub signal_result: Option<(bool, String, std::time::Instant)>,
+    /// Transient notification: (success, message, timestamp) - hidden after 3s
+    pub toast: Option<(bool, String, std::time::Instant)>,
 
     // Panel navigation and explode (SPEC-024 v5.0 Features D, E)
     /// Currently focused panel (receives keyboard input)
@@ -784,6 +803,8 @@ pub struct App {
     pub selected_column: usize,
     /// Files panel view mode (PMAT-GAP-034 - ttop parity)
     pub files_view_mode: FilesViewMode,
+    /// Exploded connections table sort order
+    pub connections_sort: ConnectionSort,
     /// Last focused panel before collapse (PMAT-GAP-035 - ttop parity)
     /// Used to restore focus when panel is shown again
     pub collapse_memory: Option<PanelType>,
@@ -836,6 +857,8 @@ pub struct App {
     pub snapshot_disk_entropy: Option<DiskEntropyData>,
     /// File analyzer data from last snapshot
     pub snapshot_file_analyzer: Option<FileAnalyzerData>,
+    /// Battery data from last snapshot
+    pub snapshot_battery: Option<BatteryData>,
 }
 
 impl App {
@@ -926,6 +949,10 @@ impl App {
         if analyzers.connections.is_some() {
             panels.connections = true;
         }
+        // Hidden unless a Docker/Podman socket was found
+        if analyzers.containers.is_some() {
+            panels.containers = true;
+        }
         if analyzers.treemap.is_some() {
             panels.files = true;
         }
@@ -943,6 +970,7 @@ impl App {
                 psi: false, // ttop shows Containers, not PSI
                 connections: true,
                 battery: false,
+                containers: true,
                 sensors_compact: false,
                 system: false,
                 treemap: false,
@@ -967,6 +995,8 @@ impl App {
             gpu_history: RingBuffer::new(60),
             vram_history: RingBuffer::new(60),
             gpu_info: None,
+            psi_history: PsiHistory::default(),
+            battery_history: BatteryHistory::default(),
             per_core_percent: vec![0.0; core_count],
             per_core_freq: vec![0; core_count], // SPEC-024 async update
             // Initialize temperatures immediately for non-deterministic mode
@@ -990,14 +1020,17 @@ impl App {
             show_filter_input: false,
             show_help: false,
             running: true,
+            paused: false,
             // Signal handling (SPEC-024 Appendix G.6 P0)
             pending_signal: None,
             signal_result: None,
+            toast: None,
             // Panel navigation (SPEC-024 v5.0 Feature D)
             focused_panel: Some(PanelType::Cpu), // Start with CPU focused
             exploded_panel: None,
             selected_column: 0, // Start with first column (PID)
             files_view_mode: FilesViewMode::default(), // PMAT-GAP-034: size view default
+            connections_sort: ConnectionSort::default(),
             collapse_memory: None, // PMAT-GAP-035: no collapsed focused panel
             config,
             frame_id: 0,
@@ -1033,6 +1066,7 @@ impl App {
             snapshot_disk_io: None,
             snapshot_disk_entropy: None,
             snapshot_file_analyzer: None,
+            snapshot_battery: None,
         };
 
         // In deterministic mode, populate with fixed data
@@ -1239,6 +1273,7 @@ impl App {
         // Copy analyzer data to snapshot fields for render access (sync mode parity with async mode)
         // This ensures render code can use the same snapshot_* fields in both modes.
         self.snapshot_psi = self.analyzers.psi.as_ref().map(|p| p.data().clone());
+        self.psi_history.update(self.snapshot_psi.as_ref());
         self.snapshot_connections = self
             .analyzers
             .connections
@@ -1261,6 +1296,8 @@ impl App {
             .file_analyzer
             .as_ref()
             .map(|f| f.data().clone());
+        self.snapshot_battery = self.analyzers.battery_data().cloned();
+        self.battery_history.update(self.snapshot_battery.as_ref());
     }
 
     /// Update frame timing stats
@@ -1329,12 +1366,15 @@ impl App {
         }
         self.snapshot_networks = snapshot.network_info;
         self.snapshot_psi = snapshot.psi_data;
+        self.psi_history.update(self.snapshot_psi.as_ref());
         self.snapshot_connections = snapshot.connections_data;
         self.snapshot_treemap = snapshot.treemap_data;
         self.snapshot_sensor_health = snapshot.sensor_health_data;
         self.snapshot_disk_io = snapshot.disk_io_data;
         self.snapshot_disk_entropy = snapshot.disk_entropy_data;
         self.snapshot_file_analyzer = snapshot.file_analyzer_data;
+        self.snapshot_battery = snapshot.battery_data;
+        self.battery_history.update(self.snapshot_battery.as_ref());
     }
 
     /// Build data availability context for display rules evaluation
@@ -1356,9 +1396,9 @@ impl App {
                 .map_or(0, |s| s.sensors.len()),
             gpu_available: self.gpu_info.is_some(),
             battery_available: self
-                .analyzers
-                .battery_data()
-                .is_some_and(|b| !b.batteries.is_empty()),
+                .snapshot_battery
+                .as_ref()
+                .is_some_and(BatteryData::has_battery),
             treemap_ready: self
                 .snapshot_treemap
                 .as_ref()
@@ -1451,6 +1491,10 @@ impl App {
     fn handle_exploded_mode_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
         match code {
             KeyCode::Esc | KeyCode::Char('z') => self.exploded_panel = None,
+            KeyCode::Char('s') if self.exploded_panel == Some(PanelType::Connections) => {
+                self.connections_sort = self.connections_sort.next();
+            }
+            KeyCode::F(2) => self.export_snapshot(),
             KeyCode::Char('q') => return true,
             KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => return true,
             KeyCode::Left | KeyCode::Char('h') => self.navigate_column_left(),
@@ -1514,6 +1558,8 @@ impl App {
             KeyCode::Char('?' | 'h') | KeyCode::F(1) => {
                 self.show_help = !self.show_help;
             }
+            KeyCode::Char(' ') => self.paused = !self.paused,
+            KeyCode::F(2) => self.export_snapshot(),
             KeyCode::Char('1') => self.toggle_panel(PanelType::Cpu),
             KeyCode::Char('2') => self.toggle_panel(PanelType::Memory),
             KeyCode::Char('3') => self.toggle_panel(PanelType::Disk),
@@ -1667,6 +1713,9 @@ impl App {
         if self.panels.files {
             visible.push(PanelType::Files);
         }
+        if self.panels.containers {
+            visible.push(PanelType::Containers);
+        }
 
         visible
     }
@@ -1748,6 +1797,65 @@ impl App {
         self.pending_signal = None;
     }
 
+    /// Rebuild a `MetricsSnapshot` from the currently displayed state.
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        let (net_rx, net_tx) = self.snapshot_networks.iter().fold((0u64, 0u64), |acc, n| {
+            (acc.0 + n.received, acc.1 + n.transmitted)
+        });
+        MetricsSnapshot {
+            cpu_avg: self.cpu_history.last().copied().unwrap_or(0.0),
+            per_core_percent: self.per_core_percent.clone(),
+            per_core_freq: self.per_core_freq.clone(),
+            per_core_temp: self.per_core_temp.clone(),
+            load_avg: self.load_avg.clone(),
+            mem_total: self.mem_total,
+            mem_used: self.mem_used,
+            mem_available: self.mem_available,
+            mem_cached: self.mem_cached,
+            swap_total: self.swap_total,
+            swap_used: self.swap_used,
+            net_rx,
+            net_tx,
+            gpu_info: self.gpu_info.clone(),
+            processes: self.snapshot_processes.clone(),
+            disk_info: self.snapshot_disks.clone(),
+            network_info: self.snapshot_networks.clone(),
+            psi_data: self.snapshot_psi.clone(),
+            connections_data: self.snapshot_connections.clone(),
+            treemap_data: self.snapshot_treemap.clone(),
+            sensor_health_data: self.snapshot_sensor_health.clone(),
+            disk_io_data: self.snapshot_disk_io.clone(),
+            disk_entropy_data: self.snapshot_disk_entropy.clone(),
+            file_analyzer_data: self.snapshot_file_analyzer.clone(),
+            battery_data: self.snapshot_battery.clone(),
+        }
+    }
+
+    /// Dump the current snapshot and analyzer data to a timestamped JSON file
+    /// in the working directory, reporting the outcome as a toast.
+    pub fn export_snapshot(&mut self) {
+        let mut value = export::snapshot_to_json(&self.metrics_snapshot());
+        if let Some(root) = value.as_object_mut() {
+            if let Some(containers) = &self.analyzers.containers {
+                root.insert(
+                    "containers".into(),
+                    export::containers_json(containers.data()),
+                );
+            }
+            if let Some(gpu_procs) = &self.analyzers.gpu_procs {
+                root.insert(
+                    "gpu_processes".into(),
+                    export::gpu_procs_json(gpu_procs.data()),
+                );
+            }
+        }
+        let (success, message) = match export::write_export(&value, Path::new(".")) {
+            Ok(path) => (true, format!("Snapshot saved to {}", path.display())),
+            Err(e) => (false, format!("Snapshot failed: {e}")),
+        };
+        self.toast = Some((success, message, std::time::Instant::now()));
+    }
+
     /// Clear old signal result after 3 seconds (PMAT-GAP-033 - ttop parity)
     pub fn clear_old_signal_result(&mut self) {
         if let Some((_, _, timestamp)) = &self.signal_result {
@@ -1840,7 +1948,7 @@ impl App {
             PanelType::Psi => self.panels.psi,
             PanelType::Battery => self.panels.battery,
             PanelType::Files => self.panels.files,
-            PanelType::Containers => false, // Not implemented
+            PanelType::Containers => self.panels.containers,
         }
     }
 
@@ -1858,7 +1966,7 @@ impl App {
             PanelType::Psi => self.panels.psi = visible,
             PanelType::Battery => self.panels.battery = visible,
             PanelType::Files => self.panels.files = visible,
-            PanelType::Containers => {} // Not implemented
+            PanelType::Containers => self.panels.containers = visible,
         }
     }
 
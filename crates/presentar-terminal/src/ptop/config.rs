@@ -432,6 +432,9 @@ impl Default for KeybindingConfig {
     }
 }
 
+/// Shortest allowed collection interval in milliseconds
+pub const MIN_REFRESH_MS: u64 = 50;
+
 /// Main ptop configuration
 #[derive(Debug, Clone)]
 pub struct PtopConfig {
@@ -851,6 +854,24 @@ keybindings:
         }
     }
 
+    /// Override the refresh interval, e.g. from the `--interval` CLI flag.
+    ///
+    /// `None` keeps the configured `refresh_ms`.
+    #[must_use]
+    pub fn with_interval_ms(mut self, interval_ms: Option<u64>) -> Self {
+        if let Some(ms) = interval_ms {
+            self.refresh_ms = ms;
+        }
+        self
+    }
+
+    /// Interval between background metric collections.
+    ///
+    /// Clamped to `MIN_REFRESH_MS` so a zero interval cannot spin the collector.
+    pub fn collect_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.refresh_ms.max(MIN_REFRESH_MS))
+    }
+
     /// Get panel config, returning default if not configured
     pub fn panel(&self, panel_type: PanelType) -> &PanelConfig {
         self.panels.get(&panel_type).unwrap_or_else(|| {
@@ -1094,6 +1115,30 @@ min_panel_width: 30
         assert_eq!(config.layout.min_panel_width, 30);
     }
 
+    #[test]
+    fn test_interval_arg_sets_collect_interval() {
+        let config = PtopConfig::default().with_interval_ms(Some(2500));
+        assert_eq!(config.refresh_ms, 2500);
+        assert_eq!(
+            config.collect_interval(),
+            std::time::Duration::from_millis(2500)
+        );
+
+        // No flag keeps the configured value
+        let config = PtopConfig::default().with_interval_ms(None);
+        assert_eq!(
+            config.collect_interval(),
+            std::time::Duration::from_millis(1000)
+        );
+
+        // Zero is clamped rather than spinning the collector
+        let config = PtopConfig::default().with_interval_ms(Some(0));
+        assert_eq!(
+            config.collect_interval(),
+            std::time::Duration::from_millis(MIN_REFRESH_MS)
+        );
+    }
+
     #[test]
     fn test_config_check_reload_returns_none_when_unchanged() {
         let config = PtopConfig::default();
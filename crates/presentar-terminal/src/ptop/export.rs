@@ -0,0 +1,386 @@
+//! Snapshot export for bug reports.
+//!
+//! Serializes a `MetricsSnapshot` and any available analyzer data to JSON.
+//! Analyzer sections are omitted when the analyzer is absent, so an export
+//! from a minimal system is still valid JSON with the core sections.
+
+// `json!` expands to `unwrap()` on infallible conversions.
+#![allow(clippy::disallowed_methods)]
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Map, Value};
+
+use super::analyzers::{
+    ConnectionsData, ContainersData, DiskEntropyData, DiskIoData, FileAnalyzerData, GpuProcsData,
+    PsiData, PsiResource, SensorHealthData, TreemapData,
+};
+use super::app::MetricsSnapshot;
+
+// =============================================================================
+// FILE OUTPUT
+// =============================================================================
+
+/// File name for an export taken at `unix_secs`.
+///
+/// Format: "ptop-snapshot-1700000000.json"
+#[must_use]
+pub fn export_file_name(unix_secs: u64) -> String {
+    format!("ptop-snapshot-{unix_secs}.json")
+}
+
+/// Write `value` as pretty-printed JSON into `dir`, returning the file path.
+///
+/// The file name is timestamped from the current system time.
+pub fn write_export(value: &Value, dir: &Path) -> io::Result<PathBuf> {
+    let unix_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let path = dir.join(export_file_name(unix_secs));
+    let text = serde_json::to_string_pretty(value).map_err(io::Error::other)?;
+    std::fs::write(&path, text)?;
+    Ok(path)
+}
+
+// =============================================================================
+// SNAPSHOT SERIALIZATION
+// =============================================================================
+
+/// Serialize a metrics snapshot, omitting absent analyzer sections.
+#[must_use]
+pub fn snapshot_to_json(snapshot: &MetricsSnapshot) -> Value {
+    let mut root = Map::new();
+    root.insert("version".into(), json!(env!("CARGO_PKG_VERSION")));
+    root.insert(
+        "cpu".into(),
+        json!({
+            "avg": snapshot.cpu_avg,
+            "per_core": snapshot.per_core_percent,
+            "freq_mhz": snapshot.per_core_freq,
+            "temp_c": snapshot.per_core_temp,
+            "load_avg": {
+                "one": snapshot.load_avg.one,
+                "five": snapshot.load_avg.five,
+                "fifteen": snapshot.load_avg.fifteen,
+            },
+        }),
+    );
+    root.insert(
+        "memory".into(),
+        json!({
+            "total": snapshot.mem_total,
+            "used": snapshot.mem_used,
+            "available": snapshot.mem_available,
+            "cached": snapshot.mem_cached,
+            "swap_total": snapshot.swap_total,
+            "swap_used": snapshot.swap_used,
+        }),
+    );
+    root.insert(
+        "network".into(),
+        json!({
+            "rx_bytes": snapshot.net_rx,
+            "tx_bytes": snapshot.net_tx,
+            "interfaces": snapshot.network_info.iter().map(|n| json!({
+                "name": n.name,
+                "received": n.received,
+                "transmitted": n.transmitted,
+            })).collect::<Vec<_>>(),
+        }),
+    );
+    root.insert(
+        "disks".into(),
+        snapshot
+            .disk_info
+            .iter()
+            .map(|d| {
+                json!({
+                    "name": d.name,
+                    "mount_point": d.mount_point,
+                    "file_system": d.file_system,
+                    "total_space": d.total_space,
+                    "available_space": d.available_space,
+                })
+            })
+            .collect(),
+    );
+    root.insert(
+        "processes".into(),
+        snapshot
+            .processes
+            .iter()
+            .map(|p| {
+                json!({
+                    "pid": p.pid,
+                    "name": p.name,
+                    "user": p.user,
+                    "cpu": p.cpu_usage,
+                    "memory": p.memory,
+                    "cmd": p.cmd,
+                })
+            })
+            .collect(),
+    );
+
+    if let Some(gpu) = &snapshot.gpu_info {
+        root.insert(
+            "gpu".into(),
+            json!({
+                "name": gpu.name,
+                "utilization": gpu.utilization,
+                "temperature": gpu.temperature,
+                "power_watts": gpu.power_watts,
+                "vram_used": gpu.vram_used,
+                "vram_total": gpu.vram_total,
+            }),
+        );
+    }
+    insert_some(&mut root, "psi", snapshot.psi_data.as_ref().map(psi_json));
+    insert_some(
+        &mut root,
+        "connections",
+        snapshot.connections_data.as_ref().map(connections_json),
+    );
+    insert_some(
+        &mut root,
+        "treemap",
+        snapshot.treemap_data.as_ref().map(treemap_json),
+    );
+    insert_some(
+        &mut root,
+        "sensors",
+        snapshot.sensor_health_data.as_ref().map(sensors_json),
+    );
+    insert_some(
+        &mut root,
+        "disk_io",
+        snapshot.disk_io_data.as_ref().map(disk_io_json),
+    );
+    insert_some(
+        &mut root,
+        "disk_entropy",
+        snapshot.disk_entropy_data.as_ref().map(disk_entropy_json),
+    );
+    insert_some(
+        &mut root,
+        "files",
+        snapshot.file_analyzer_data.as_ref().map(files_json),
+    );
+    Value::Object(root)
+}
+
+/// Insert `value` under `key` only when present.
+fn insert_some(root: &mut Map<String, Value>, key: &str, value: Option<Value>) {
+    if let Some(value) = value {
+        root.insert(key.to_string(), value);
+    }
+}
+
+// =============================================================================
+// ANALYZER SECTIONS
+// =============================================================================
+
+fn psi_resource_json(resource: &PsiResource) -> Value {
+    let averages = |a: &super::analyzers::PsiAverages| json!({ "avg10": a.avg10, "avg60": a.avg60, "avg300": a.avg300, "total_us": a.total_us });
+    json!({
+        "some": averages(&resource.some),
+        "full": resource.full.as_ref().map(averages),
+    })
+}
+
+/// Serialize PSI data.
+#[must_use]
+pub fn psi_json(psi: &PsiData) -> Value {
+    json!({
+        "available": psi.available,
+        "cpu": psi_resource_json(&psi.cpu),
+        "memory": psi_resource_json(&psi.memory),
+        "io": psi_resource_json(&psi.io),
+    })
+}
+
+/// Serialize TCP connection counts by state.
+#[must_use]
+pub fn connections_json(data: &ConnectionsData) -> Value {
+    let states: Map<String, Value> = data
+        .state_counts
+        .iter()
+        .map(|(state, count)| (state.as_str().to_string(), json!(count)))
+        .collect();
+    json!({
+        "total": data.connections.len(),
+        "states": states,
+    })
+}
+
+/// Serialize disk usage scan totals.
+#[must_use]
+pub fn treemap_json(data: &TreemapData) -> Value {
+    json!({
+        "root_path": data.root_path.display().to_string(),
+        "total_size": data.total_size,
+        "total_files": data.total_files,
+        "total_dirs": data.total_dirs,
+    })
+}
+
+/// Serialize hwmon sensor readings.
+#[must_use]
+pub fn sensors_json(data: &SensorHealthData) -> Value {
+    data.sensors
+        .iter()
+        .map(|s| {
+            json!({
+                "device": s.device,
+                "label": s.label,
+                "value": s.value,
+                "unit": s.sensor_type.unit(),
+                "status": s.status.as_str(),
+            })
+        })
+        .collect()
+}
+
+/// Serialize per-device disk I/O rates.
+#[must_use]
+pub fn disk_io_json(data: &DiskIoData) -> Value {
+    let devices: Map<String, Value> = data
+        .rates
+        .iter()
+        .map(|(name, rates)| {
+            (
+                name.clone(),
+                json!({
+                    "read_bytes_per_sec": rates.read_bytes_per_sec,
+                    "write_bytes_per_sec": rates.write_bytes_per_sec,
+                    "utilization_percent": rates.utilization_percent,
+                }),
+            )
+        })
+        .collect();
+    json!({
+        "total_read_bytes_per_sec": data.total_read_bytes_per_sec,
+        "total_write_bytes_per_sec": data.total_write_bytes_per_sec,
+        "devices": devices,
+    })
+}
+
+/// Serialize disk encryption counts.
+#[must_use]
+pub fn disk_entropy_json(data: &DiskEntropyData) -> Value {
+    json!({
+        "encrypted": data.encrypted_count,
+        "unencrypted": data.unencrypted_count,
+    })
+}
+
+/// Serialize open/hot file totals.
+#[must_use]
+pub fn files_json(data: &FileAnalyzerData) -> Value {
+    json!({
+        "total_open_files": data.total_open_files,
+        "total_hot_files": data.total_hot_files,
+    })
+}
+
+/// Serialize container list.
+#[must_use]
+pub fn containers_json(data: &ContainersData) -> Value {
+    data.containers
+        .iter()
+        .map(|c| {
+            json!({
+                "id": c.id,
+                "name": c.name,
+                "image": c.image,
+                "state": c.state.as_str(),
+                "cpu_percent": c.stats.cpu_percent,
+                "memory_bytes": c.stats.memory_bytes,
+            })
+        })
+        .collect()
+}
+
+/// Serialize per-process GPU usage.
+#[must_use]
+pub fn gpu_procs_json(data: &GpuProcsData) -> Value {
+    data.processes
+        .iter()
+        .map(|p| {
+            json!({
+                "pid": p.pid,
+                "name": p.name,
+                "gpu_index": p.gpu_index,
+                "used_memory": p.used_memory,
+                "sm_util": p.sm_util,
+            })
+        })
+        .collect()
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptop::analyzers::PsiAverages;
+    use crate::Snapshot;
+
+    fn synthetic_snapshot() -> MetricsSnapshot {
+        let mut snapshot = MetricsSnapshot::empty();
+        snapshot.cpu_avg = 42.5;
+        snapshot.per_core_percent = vec![40.0, 45.0];
+        snapshot.mem_total = 16 * 1024 * 1024 * 1024;
+        snapshot.mem_used = 4 * 1024 * 1024 * 1024;
+        snapshot.psi_data = Some(PsiData {
+            cpu: PsiResource {
+                some: PsiAverages {
+                    avg10: 3.5,
+                    ..Default::default()
+                },
+                full: None,
+            },
+            available: true,
+            ..Default::default()
+        });
+        snapshot
+    }
+
+    #[test]
+    fn test_snapshot_to_json_roundtrip() {
+        let value = snapshot_to_json(&synthetic_snapshot());
+        let text = serde_json::to_string_pretty(&value).unwrap();
+        let parsed: Value = serde_json::from_str(&text).unwrap();
+
+        assert_eq!(parsed["cpu"]["avg"], json!(42.5));
+        assert_eq!(parsed["cpu"]["per_core"], json!([40.0, 45.0]));
+        assert_eq!(
+            parsed["memory"]["total"],
+            json!(16_u64 * 1024 * 1024 * 1024)
+        );
+        assert_eq!(parsed["memory"]["used"], json!(4_u64 * 1024 * 1024 * 1024));
+        assert_eq!(parsed["psi"]["cpu"]["some"]["avg10"], json!(3.5));
+        assert!(parsed["psi"]["cpu"]["full"].is_null());
+    }
+
+    #[test]
+    fn test_snapshot_to_json_omits_absent_analyzers() {
+        let value = snapshot_to_json(&MetricsSnapshot::empty());
+        for key in ["gpu", "psi", "connections", "sensors", "disk_io", "files"] {
+            assert!(value.get(key).is_none(), "{key} should be omitted");
+        }
+        assert!(value.get("cpu").is_some());
+        assert!(value.get("memory").is_some());
+    }
+
+    #[test]
+    fn test_export_file_name() {
+        assert_eq!(
+            export_file_name(1_700_000_000),
+            "ptop-snapshot-1700000000.json"
+        );
+    }
+}
@@ -81,7 +81,9 @@ pub const _ENFORCE_DESIGN_PRINCIPLES: &str =
 pub mod analyzers;
 pub mod app;
 pub mod config;
+pub mod export;
 pub mod input;
+pub mod replay;
 pub mod ui;
 pub mod ui_atoms;
 
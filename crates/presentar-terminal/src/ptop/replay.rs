@@ -0,0 +1,393 @@
+//! Record-and-replay of metric snapshots.
+//!
+//! `--record` appends each collected `MetricsSnapshot` to a JSON Lines log
+//! (one `{"t_ms": .., "snapshot": ..}` object per frame, using the
+//! `export` format). `--replay` reads that log back and feeds the frames to
+//! the UI at their original cadence, optionally scaled by a speed multiplier.
+//!
+//! Replay restores CPU, memory, network, disk, process, GPU and PSI data.
+//! Other analyzer sections are recorded for inspection but only exported as
+//! summaries, so they are not restored.
+
+// `json!` expands to `unwrap()` on infallible conversions.
+#![allow(clippy::disallowed_methods)]
+
+use std::io::{self, BufRead, Write};
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use super::analyzers::{PsiAverages, PsiData, PsiResource};
+use super::app::{DiskInfo, MetricsSnapshot, NetworkInfo, ProcessInfo};
+use super::export::snapshot_to_json;
+use super::ui::GpuInfo;
+use crate::Snapshot;
+
+// =============================================================================
+// RECORDING
+// =============================================================================
+
+/// Appends snapshots to a JSON Lines log with their offset from the first frame.
+pub struct SnapshotRecorder<W: Write> {
+    writer: W,
+    started: Option<Instant>,
+}
+
+impl<W: Write> SnapshotRecorder<W> {
+    /// Create a recorder writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            started: None,
+        }
+    }
+
+    /// Record a snapshot at the current time.
+    pub fn record(&mut self, snapshot: &MetricsSnapshot) -> io::Result<()> {
+        let started = *self.started.get_or_insert_with(Instant::now);
+        self.record_at(snapshot, started.elapsed())
+    }
+
+    /// Record a snapshot at an explicit offset from the start of the recording.
+    pub fn record_at(&mut self, snapshot: &MetricsSnapshot, offset: Duration) -> io::Result<()> {
+        let line = json!({
+            "t_ms": offset.as_millis() as u64,
+            "snapshot": snapshot_to_json(snapshot),
+        });
+        serde_json::to_writer(&mut self.writer, &line).map_err(io::Error::other)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()
+    }
+
+    /// Consume the recorder, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+// =============================================================================
+// REPLAY
+// =============================================================================
+
+/// Recorded frames, replayed in order with their original spacing.
+pub struct SnapshotReplay {
+    frames: Vec<(u64, MetricsSnapshot)>,
+    speed: f64,
+    position: usize,
+}
+
+impl SnapshotReplay {
+    /// Parse a recording. Blank lines are skipped; malformed lines are errors.
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut frames = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let value: Value = serde_json::from_str(&line).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: {e}", index + 1),
+                )
+            })?;
+            let t_ms = value.get("t_ms").and_then(Value::as_u64).unwrap_or(0);
+            let snapshot = value
+                .get("snapshot")
+                .map_or_else(MetricsSnapshot::empty, snapshot_from_json);
+            frames.push((t_ms, snapshot));
+        }
+        Ok(Self {
+            frames,
+            speed: 1.0,
+            position: 0,
+        })
+    }
+
+    /// Set the playback speed multiplier (2.0 plays twice as fast).
+    ///
+    /// Non-positive or non-finite values fall back to real time.
+    #[must_use]
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.speed = if speed.is_finite() && speed > 0.0 {
+            speed
+        } else {
+            1.0
+        };
+        self
+    }
+
+    /// Number of recorded frames.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Whether the recording has no frames.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Next frame and the delay to wait before showing it.
+    ///
+    /// The first frame has no delay; later delays are the recorded spacing
+    /// divided by the speed multiplier.
+    pub fn next_frame(&mut self) -> Option<(Duration, MetricsSnapshot)> {
+        let (t_ms, snapshot) = self.frames.get(self.position)?.clone();
+        let previous = self
+            .position
+            .checked_sub(1)
+            .map_or(t_ms, |i| self.frames[i].0);
+        self.position += 1;
+        let delay = Duration::from_millis(t_ms.saturating_sub(previous));
+        Some((delay.div_f64(self.speed), snapshot))
+    }
+}
+
+// =============================================================================
+// PARSING
+// =============================================================================
+
+fn f64_field(value: &Value, key: &str) -> f64 {
+    value.get(key).and_then(Value::as_f64).unwrap_or(0.0)
+}
+
+fn u64_field(value: &Value, key: &str) -> u64 {
+    value.get(key).and_then(Value::as_u64).unwrap_or(0)
+}
+
+fn str_field(value: &Value, key: &str) -> String {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn array_field<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+fn psi_averages(value: &Value) -> PsiAverages {
+    PsiAverages {
+        avg10: f64_field(value, "avg10"),
+        avg60: f64_field(value, "avg60"),
+        avg300: f64_field(value, "avg300"),
+        total_us: u64_field(value, "total_us"),
+    }
+}
+
+fn psi_resource(value: &Value) -> PsiResource {
+    PsiResource {
+        some: value.get("some").map(psi_averages).unwrap_or_default(),
+        full: value
+            .get("full")
+            .filter(|full| !full.is_null())
+            .map(psi_averages),
+    }
+}
+
+/// Rebuild a `MetricsSnapshot` from its `export::snapshot_to_json` form.
+///
+/// Missing fields default to zero/empty.
+#[must_use]
+pub fn snapshot_from_json(value: &Value) -> MetricsSnapshot {
+    let mut snapshot = MetricsSnapshot::empty();
+    let null = Value::Null;
+
+    let cpu = value.get("cpu").unwrap_or(&null);
+    snapshot.cpu_avg = f64_field(cpu, "avg");
+    snapshot.per_core_percent = array_field(cpu, "per_core")
+        .iter()
+        .filter_map(Value::as_f64)
+        .collect();
+    snapshot.per_core_freq = array_field(cpu, "freq_mhz")
+        .iter()
+        .filter_map(Value::as_u64)
+        .collect();
+    snapshot.per_core_temp = array_field(cpu, "temp_c")
+        .iter()
+        .filter_map(Value::as_f64)
+        .map(|t| t as f32)
+        .collect();
+    let load = cpu.get("load_avg").unwrap_or(&null);
+    snapshot.load_avg = sysinfo::LoadAvg {
+        one: f64_field(load, "one"),
+        five: f64_field(load, "five"),
+        fifteen: f64_field(load, "fifteen"),
+    };
+
+    let mem = value.get("memory").unwrap_or(&null);
+    snapshot.mem_total = u64_field(mem, "total");
+    snapshot.mem_used = u64_field(mem, "used");
+    snapshot.mem_available = u64_field(mem, "available");
+    snapshot.mem_cached = u64_field(mem, "cached");
+    snapshot.swap_total = u64_field(mem, "swap_total");
+    snapshot.swap_used = u64_field(mem, "swap_used");
+
+    let net = value.get("network").unwrap_or(&null);
+    snapshot.net_rx = u64_field(net, "rx_bytes");
+    snapshot.net_tx = u64_field(net, "tx_bytes");
+    snapshot.network_info = array_field(net, "interfaces")
+        .iter()
+        .map(|n| NetworkInfo {
+            name: str_field(n, "name"),
+            received: u64_field(n, "received"),
+            transmitted: u64_field(n, "transmitted"),
+        })
+        .collect();
+
+    snapshot.disk_info = array_field(value, "disks")
+        .iter()
+        .map(|d| DiskInfo {
+            name: str_field(d, "name"),
+            mount_point: str_field(d, "mount_point"),
+            total_space: u64_field(d, "total_space"),
+            available_space: u64_field(d, "available_space"),
+            file_system: str_field(d, "file_system"),
+        })
+        .collect();
+
+    snapshot.processes = array_field(value, "processes")
+        .iter()
+        .map(|p| ProcessInfo {
+            pid: u32::try_from(u64_field(p, "pid")).unwrap_or(0),
+            name: str_field(p, "name"),
+            cpu_usage: f64_field(p, "cpu") as f32,
+            memory: u64_field(p, "memory"),
+            user: str_field(p, "user"),
+            cmd: str_field(p, "cmd"),
+        })
+        .collect();
+
+    snapshot.gpu_info = value.get("gpu").map(|g| GpuInfo {
+        name: str_field(g, "name"),
+        utilization: g
+            .get("utilization")
+            .and_then(Value::as_u64)
+            .and_then(|u| u8::try_from(u).ok()),
+        temperature: g
+            .get("temperature")
+            .and_then(Value::as_u64)
+            .and_then(|t| u32::try_from(t).ok()),
+        power_watts: g
+            .get("power_watts")
+            .and_then(Value::as_f64)
+            .map(|w| w as f32),
+        vram_used: g.get("vram_used").and_then(Value::as_u64),
+        vram_total: g.get("vram_total").and_then(Value::as_u64),
+    });
+
+    snapshot.psi_data = value.get("psi").map(|psi| PsiData {
+        cpu: psi_resource(psi.get("cpu").unwrap_or(&null)),
+        memory: psi_resource(psi.get("memory").unwrap_or(&null)),
+        io: psi_resource(psi.get("io").unwrap_or(&null)),
+        available: psi
+            .get("available")
+            .and_then(Value::as_bool)
+            .unwrap_or(false),
+    });
+
+    snapshot
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptop::app::App;
+    use crate::ptop::config::PtopConfig;
+
+    fn frame(cpu: f64, mem_used: u64) -> MetricsSnapshot {
+        let mut snapshot = MetricsSnapshot::empty();
+        snapshot.cpu_avg = cpu;
+        snapshot.per_core_percent = vec![cpu, cpu];
+        snapshot.mem_total = 8_000;
+        snapshot.mem_used = mem_used;
+        snapshot.processes = vec![ProcessInfo {
+            pid: 42,
+            name: "spike".to_string(),
+            cpu_usage: cpu as f32,
+            memory: 1024,
+            user: "root".to_string(),
+            cmd: "spike --burn".to_string(),
+        }];
+        snapshot.psi_data = Some(PsiData {
+            cpu: PsiResource {
+                some: PsiAverages {
+                    avg10: cpu / 10.0,
+                    ..Default::default()
+                },
+                full: None,
+            },
+            available: true,
+            ..Default::default()
+        });
+        snapshot
+    }
+
+    #[test]
+    fn test_record_and_replay_two_frames() {
+        let mut recorder = SnapshotRecorder::new(Vec::new());
+        recorder
+            .record_at(&frame(12.5, 2_000), Duration::ZERO)
+            .unwrap();
+        recorder
+            .record_at(&frame(97.0, 6_000), Duration::from_millis(1000))
+            .unwrap();
+        let log = recorder.into_inner();
+
+        let mut replay = SnapshotReplay::from_reader(log.as_slice()).unwrap();
+        assert_eq!(replay.len(), 2);
+
+        let mut app = App::with_config_lightweight(true, PtopConfig::default());
+        let mut delays = Vec::new();
+        while let Some((delay, snapshot)) = replay.next_frame() {
+            delays.push(delay);
+            app.apply_snapshot(snapshot);
+        }
+
+        assert_eq!(delays, vec![Duration::ZERO, Duration::from_millis(1000)]);
+        // Deterministic mode pre-fills history; the replayed frames come last
+        assert!(app.cpu_history.as_slice().ends_with(&[12.5, 97.0]));
+        assert_eq!(app.mem_used, 6_000);
+        assert_eq!(app.snapshot_processes[0].name, "spike");
+        assert_eq!(app.psi_data().map(|p| p.cpu.some.avg10), Some(9.7));
+    }
+
+    #[test]
+    fn test_replay_speed_scales_delays() {
+        let mut recorder = SnapshotRecorder::new(Vec::new());
+        recorder.record_at(&frame(1.0, 1), Duration::ZERO).unwrap();
+        recorder
+            .record_at(&frame(2.0, 2), Duration::from_millis(800))
+            .unwrap();
+        let log = recorder.into_inner();
+
+        let mut replay = SnapshotReplay::from_reader(log.as_slice())
+            .unwrap()
+            .with_speed(4.0);
+        assert_eq!(replay.next_frame().map(|f| f.0), Some(Duration::ZERO));
+        assert_eq!(
+            replay.next_frame().map(|f| f.0),
+            Some(Duration::from_millis(200))
+        );
+        assert!(replay.next_frame().is_none());
+    }
+
+    #[test]
+    fn test_replay_rejects_malformed_line() {
+        let err = SnapshotReplay::from_reader("{\"t_ms\": 0}\nnot json\n".as_bytes())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 2"));
+    }
+}
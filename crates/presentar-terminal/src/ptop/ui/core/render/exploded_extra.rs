@@ -1,4 +1,5 @@
 use super::*;
+use crate::ptop::ui::panels::geo_connections::draw_geo_connections;
 
 /// Get color for I/O rate display (read or write)
 fn io_rate_color(rate: f64, is_read: bool) -> Color {
@@ -642,16 +643,22 @@ pub(super) fn draw_gpu_exploded(app: &App, canvas: &mut DirectTerminalCanvas, ar
         );
         y += 1.0;
 
-        // Note: GPU processes would come from app.analyzers.gpu_procs_data()
-        // For now show placeholder
-        canvas.draw_text(
-            "  (GPU process list requires nvidia-smi)",
-            Point::new(inner.x, y),
-            &TextStyle {
-                color: Color::new(0.5, 0.5, 0.5, 1.0),
-                ..Default::default()
-            },
+        let procs_area = Rect::new(inner.x, y, inner.width, inner.y + inner.height - y);
+        let rows = crate::ptop::ui::panels::gpu_procs::draw_gpu_procs(
+            canvas,
+            app.analyzers.gpu_procs_data(),
+            procs_area,
         );
+        if rows == 0.0 {
+            canvas.draw_text(
+                "  (GPU process list requires nvidia-smi)",
+                Point::new(inner.x, y),
+                &TextStyle {
+                    color: Color::new(0.5, 0.5, 0.5, 1.0),
+                    ..Default::default()
+                },
+            );
+        }
     } else {
         canvas.draw_text(
             "No GPU detected or nvidia-smi not available",
@@ -1040,17 +1047,16 @@ pub(super) fn draw_process_exploded(app: &App, canvas: &mut DirectTerminalCanvas
 /// FULL SCREEN connections exploded view
 /// SPEC-024 Section 30: Exploded views fill the screen
 pub(super) fn draw_connections_exploded(app: &App, canvas: &mut DirectTerminalCanvas, area: Rect) {
-    use crate::widgets::display_rules::{format_column, ColumnAlign, TruncateStrategy};
-    use crate::widgets::selection::RowHighlight;
-
-    let border_color = CONNECTIONS_COLOR;
-
     let conn_data = app.snapshot_connections.as_ref();
-    let conn_count = conn_data.map(|d| d.connections.len()).unwrap_or(0);
+    let conn_count = conn_data.map_or(0, |d| d.connections.len());
 
-    let title = format!("▼ CONNECTIONS │ {} active", conn_count);
+    let title = format!(
+        "▼ CONNECTIONS │ {} active │ sort: {} [s]",
+        conn_count,
+        app.connections_sort.label()
+    );
 
-    let mut border = create_panel_border(&title, border_color, true);
+    let mut border = create_panel_border(&title, CONNECTIONS_COLOR, true);
     border.layout(area);
     border.paint(canvas);
     let inner = border.inner_rect();
@@ -1059,151 +1065,19 @@ pub(super) fn draw_connections_exploded(app: &App, canvas: &mut DirectTerminalCa
         return;
     }
 
-    let mut y = inner.y;
-
-    // Column widths
-    let col_proto = 6;
-    let col_local = 25;
-    let col_remote = 25;
-    let col_state = 12;
-    let col_pid = 8;
-    let col_proc = (inner.width as usize)
-        .saturating_sub(col_proto + col_local + col_remote + col_state + col_pid + 10);
-
-    // Header
-    let header_bg = Color::new(0.12, 0.15, 0.22, 1.0);
-    canvas.fill_rect(Rect::new(inner.x, y, inner.width, 1.0), header_bg);
-
-    let headers = [
-        "PROTO",
-        "LOCAL ADDRESS",
-        "REMOTE ADDRESS",
-        "STATE",
-        "PID",
-        "PROCESS",
-    ];
-    let widths = [
-        col_proto, col_local, col_remote, col_state, col_pid, col_proc,
-    ];
-    let mut hx = inner.x;
-    for (header, width) in headers.iter().zip(widths.iter()) {
-        canvas.draw_text(
-            &format_column(header, *width, ColumnAlign::Left, TruncateStrategy::End),
-            Point::new(hx, y),
-            &TextStyle {
-                color: border_color,
-                ..Default::default()
-            },
-        );
-        hx += *width as f32 + 1.0;
-    }
-    y += 1.0;
-
     if let Some(data) = conn_data {
-        for (i, conn) in data.connections.iter().enumerate() {
-            if (y - inner.y) as usize >= (inner.height as usize).saturating_sub(2) {
-                break;
-            }
-
-            let row_rect = Rect::new(inner.x, y, inner.width, 1.0);
-            let is_selected = i == 0;
-
-            let row_hl = RowHighlight::new(row_rect, is_selected);
-            row_hl.paint(canvas);
-            let text_style = row_hl.text_style();
-
-            let mut col_x = inner.x;
-
-            // Protocol
-            canvas.draw_text(
-                &format_column("TCP", col_proto, ColumnAlign::Left, TruncateStrategy::End),
-                Point::new(col_x, y),
-                &text_style,
-            );
-            col_x += col_proto as f32 + 1.0;
-
-            // Local address
-            let local = format!("{}:{}", conn.local_addr, conn.local_port);
-            canvas.draw_text(
-                &format_column(&local, col_local, ColumnAlign::Left, TruncateStrategy::End),
-                Point::new(col_x, y),
-                &text_style,
-            );
-            col_x += col_local as f32 + 1.0;
-
-            // Remote address
-            let remote = format!("{}:{}", conn.remote_addr, conn.remote_port);
-            canvas.draw_text(
-                &format_column(
-                    &remote,
-                    col_remote,
-                    ColumnAlign::Left,
-                    TruncateStrategy::End,
-                ),
-                Point::new(col_x, y),
-                &text_style,
-            );
-            col_x += col_remote as f32 + 1.0;
-
-            // State with color
-            let state_str = format!("{:?}", conn.state);
-            let state_color = match conn.state {
-                TcpState::Established => Color::new(0.3, 0.9, 0.3, 1.0),
-                TcpState::Listen => Color::new(0.3, 0.7, 0.9, 1.0),
-                TcpState::TimeWait => Color::new(0.7, 0.7, 0.3, 1.0),
-                TcpState::CloseWait => Color::new(0.9, 0.5, 0.3, 1.0),
-                _ => Color::new(0.6, 0.6, 0.6, 1.0),
-            };
-            canvas.draw_text(
-                &format_column(
-                    &state_str,
-                    col_state,
-                    ColumnAlign::Left,
-                    TruncateStrategy::End,
-                ),
-                Point::new(col_x, y),
-                &TextStyle {
-                    color: if is_selected {
-                        Color::WHITE
-                    } else {
-                        state_color
-                    },
-                    ..Default::default()
-                },
-            );
-            col_x += col_state as f32 + 1.0;
-
-            // PID
-            let pid_str = conn
-                .pid
-                .map(|p| p.to_string())
-                .unwrap_or_else(|| "-".to_string());
-            canvas.draw_text(
-                &format_column(&pid_str, col_pid, ColumnAlign::Right, TruncateStrategy::End),
-                Point::new(col_x, y),
-                &text_style,
-            );
-            col_x += col_pid as f32 + 1.0;
-
-            // Process name
-            let proc_name = conn.process_name.as_deref().unwrap_or("-");
-            canvas.draw_text(
-                &format_column(
-                    proc_name,
-                    col_proc,
-                    ColumnAlign::Left,
-                    TruncateStrategy::Command,
-                ),
-                Point::new(col_x, y),
-                &text_style,
-            );
-
-            y += 1.0;
-        }
+        // The first row carries the selection highlight.
+        draw_geo_connections(
+            canvas,
+            &data.connections,
+            inner,
+            app.connections_sort,
+            Some(0),
+        );
     } else {
         canvas.draw_text(
             "No connection data available",
-            Point::new(inner.x, y),
+            Point::new(inner.x, inner.y),
             &TextStyle {
                 color: Color::new(0.5, 0.5, 0.5, 1.0),
                 ..Default::default()
@@ -15,7 +15,7 @@ use crate::{
 };
 use presentar_core::{Canvas, Color, Point, Rect, TextStyle, Widget};
 
-use crate::ptop::analyzers::{ContainerState, SensorStatus, SensorType, TcpState};
+use crate::ptop::analyzers::{SensorStatus, SensorType, TcpState};
 use crate::ptop::app::{App, ProcessSortColumn};
 use crate::ptop::config::{calculate_grid_layout, snap_to_grid, DetailLevel, PanelType};
 use crate::ptop::ui::core::layout::push_if_visible;
@@ -476,8 +476,14 @@ fn draw_title_bar(app: &App, canvas: &mut DirectTerminalCanvas<'_>, w: f32) {
         .with_search_active(app.show_filter_input)
         .with_keybinds(keybinds)
         .with_primary_color(CPU_COLOR);
-    if app.exploded_panel.is_some() {
-        title_bar = title_bar.with_mode_indicator("[▣]");
+    let indicator = match (app.exploded_panel.is_some(), app.paused) {
+        (true, true) => Some("[▣] PAUSED"),
+        (true, false) => Some("[▣]"),
+        (false, true) => Some("PAUSED"),
+        (false, false) => None,
+    };
+    if let Some(indicator) = indicator {
+        title_bar = title_bar.with_mode_indicator(indicator);
     }
     title_bar.layout(Rect::new(0.0, 0.0, w, 1.0));
     title_bar.paint(canvas);
@@ -543,6 +549,37 @@ fn draw_overlays(app: &App, canvas: &mut DirectTerminalCanvas<'_>, w: f32, h: f3
     if app.show_fps {
         draw_fps_overlay(app, canvas, w);
     }
+    draw_toast(app, canvas, w, h);
+}
+
+/// How long a toast stays on screen.
+const TOAST_DURATION: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Draw the transient toast (e.g. snapshot export result) above the status bar.
+fn draw_toast(app: &App, canvas: &mut DirectTerminalCanvas<'_>, w: f32, h: f32) {
+    let Some((success, ref message, shown_at)) = app.toast else {
+        return;
+    };
+    if shown_at.elapsed() > TOAST_DURATION || h < 3.0 {
+        return;
+    }
+    let text = format!(" {message} ");
+    let width = (text.chars().count() as f32).min(w);
+    let x = (w - width - 1.0).max(0.0);
+    let y = h - 2.0;
+    canvas.fill_rect(Rect::new(x, y, width, 1.0), STATUS_BAR_BG);
+    canvas.draw_text(
+        &text,
+        Point::new(x, y),
+        &TextStyle {
+            color: if success {
+                Color::new(0.4, 1.0, 0.4, 1.0)
+            } else {
+                Color::new(1.0, 0.4, 0.4, 1.0)
+            },
+            ..Default::default()
+        },
+    );
 }
 
 pub fn draw(app: &App, buffer: &mut CellBuffer) {
@@ -606,6 +643,9 @@ fn count_top_panels(app: &App) -> u32 {
     if app.panels.psi {
         count += 1;
     }
+    if app.panels.containers {
+        count += 1;
+    }
     count
 }
 
@@ -738,8 +778,13 @@ fn draw_ttop_grid(app: &App, canvas: &mut DirectTerminalCanvas<'_>, area: Rect)
         Rect::new(area.x + cell_w, row1_y, cell_w, cell_h),
     );
 
-    // Third column: Sensors (33%) + Containers (67%)
+    // Third column: Sensors (33%) + Containers (67%), or Sensors alone
+    // when no container runtime was detected
     let col3_x = area.x + 2.0 * cell_w;
+    if !app.panels.containers {
+        draw_sensors_panel(app, canvas, Rect::new(col3_x, row1_y, cell_w, cell_h));
+        return;
+    }
     let sensors_h = (cell_h / 3.0).round();
     draw_sensors_panel(app, canvas, Rect::new(col3_x, row1_y, cell_w, sensors_h));
     draw_containers_panel(
@@ -800,6 +845,15 @@ fn build_panel_list(app: &App) -> Vec<fn(&App, &mut DirectTerminalCanvas<'_>, Re
         None,
     );
 
+    push_if_visible(
+        &mut panels,
+        app,
+        app.panels.containers,
+        PanelType::Containers,
+        draw_containers_panel,
+        None,
+    );
+
     if app.panels.sensors_compact {
         panels.push(draw_sensors_compact_panel);
     }
@@ -1338,6 +1338,11 @@ pub(super) fn draw_process_panel(app: &App, canvas: &mut DirectTerminalCanvas<'_
                         .with_cgroup(extra.cgroup_short())
                         .with_nice(extra.nice)
                         .with_threads(extra.num_threads);
+                    if let (Some(read), Some(write)) =
+                        (extra.read_bytes_per_sec, extra.write_bytes_per_sec)
+                    {
+                        entry = entry.with_io_rates(read, write);
+                    }
                 }
             }
             entry
@@ -1345,7 +1350,10 @@ pub(super) fn draw_process_panel(app: &App, canvas: &mut DirectTerminalCanvas<'_
         .collect();
 
     let mut table = if is_exploded {
-        ProcessTable::new().with_cmdline().with_threads_column()
+        ProcessTable::new()
+            .with_cmdline()
+            .with_threads_column()
+            .with_io_column()
     } else {
         ProcessTable::new().compact().with_threads_column()
     };
@@ -1399,6 +1407,8 @@ pub(super) fn draw_help_overlay(canvas: &mut DirectTerminalCanvas<'_>, w: f32, h
         ("", "-- General --", true),
         ("q, Esc, Ctrl+C", "Quit", false),
         ("h, ?", "Toggle help", false),
+        ("Space", "Pause/resume updates", false),
+        ("F2", "Export snapshot to JSON", false),
         ("", "-- Panel Navigation --", true),
         ("Tab", "Focus next panel", false),
         ("Shift+Tab", "Focus previous panel", false),
@@ -1,4 +1,6 @@
 use super::*;
+use crate::ptop::ui::panels::battery::{build_battery_title, draw_battery_detail, BatteryState};
+use crate::ptop::ui::panels::psi::{draw_pressure_trend, PsiMetricType};
 
 // ============================================================================
 // NEW PANELS (F006-F014): GPU, Battery, Sensors, PSI, Connections, etc.
@@ -364,108 +366,23 @@ pub(super) fn draw_gpu_panel(app: &App, canvas: &mut DirectTerminalCanvas<'_>, b
     canvas.pop_clip();
 }
 
-/// Battery information from /`sys/class/power_supply`
-#[derive(Debug, Default)]
-struct BatteryInfo {
-    /// Capacity as percentage (0-100)
-    capacity: u8,
-    /// Status: Charging, Discharging, Full, Not charging, Unknown
-    status: String,
-    /// Time remaining in minutes (if available)
-    time_remaining_mins: Option<u32>,
-    /// Whether battery is present
-    #[allow(dead_code)]
-    present: bool,
-}
-
-/// Read battery info from /`sys/class/power_supply` (Linux only)
-fn read_battery_info() -> Option<BatteryInfo> {
-    #[cfg(target_os = "linux")]
-    {
-        use std::fs;
-        use std::path::Path;
-
-        // Look for BAT0, BAT1, etc.
-        for i in 0..4 {
-            let bat_path = format!("/sys/class/power_supply/BAT{i}");
-            let path = Path::new(&bat_path);
-
-            if !path.exists() {
-                continue;
-            }
-
-            // Read capacity
-            let capacity = fs::read_to_string(format!("{bat_path}/capacity"))
-                .ok()
-                .and_then(|s| s.trim().parse::<u8>().ok())
-                .unwrap_or(0);
-
-            // Read status
-            let status = fs::read_to_string(format!("{bat_path}/status"))
-                .ok()
-                .map_or_else(|| "Unknown".to_string(), |s| s.trim().to_string());
-
-            // Try to calculate time remaining
-            // Uses energy_now/power_now or charge_now/current_now
-            let time_remaining_mins = {
-                let energy_now = fs::read_to_string(format!("{bat_path}/energy_now"))
-                    .ok()
-                    .and_then(|s| s.trim().parse::<u64>().ok());
-                let power_now = fs::read_to_string(format!("{bat_path}/power_now"))
-                    .ok()
-                    .and_then(|s| s.trim().parse::<u64>().ok());
-                let energy_full = fs::read_to_string(format!("{bat_path}/energy_full"))
-                    .ok()
-                    .and_then(|s| s.trim().parse::<u64>().ok());
-
-                match (energy_now, power_now, energy_full, status.as_str()) {
-                    (Some(en), Some(pn), _, "Discharging") if pn > 0 => Some((en * 60 / pn) as u32),
-                    (Some(en), Some(pn), Some(ef), "Charging") if pn > 0 => {
-                        let remaining = ef.saturating_sub(en);
-                        Some((remaining * 60 / pn) as u32)
-                    }
-                    _ => None,
-                }
-            };
-
-            return Some(BatteryInfo {
-                capacity,
-                status,
-                time_remaining_mins,
-                present: true,
-            });
-        }
-
-        None
-    }
-
-    #[cfg(not(target_os = "linux"))]
-    {
-        None
-    }
-}
-
-/// F007: Battery Panel - shows charge level and state
+/// Draw Battery panel: charge bar, state, and estimated time to empty/full.
 pub(super) fn draw_battery_panel(app: &App, canvas: &mut DirectTerminalCanvas<'_>, bounds: Rect) {
-    let battery = read_battery_info();
+    let battery = app.snapshot_battery.as_ref().filter(|b| b.has_battery());
 
     // ttop-style title (Border adds outer spaces)
-    let title = battery
-        .as_ref()
-        .map(|b| {
-            let time_str = b
-                .time_remaining_mins
-                .map(|m| {
-                    if m >= 60 {
-                        format!(" │ {}h{}m", m / 60, m % 60)
-                    } else {
-                        format!(" │ {m}m")
-                    }
-                })
-                .unwrap_or_default();
-            format!("Battery │ {}% │ {}{}", b.capacity, b.status, time_str)
-        })
-        .unwrap_or_else(|| "Battery │ No battery".to_string());
+    let title = battery.map_or_else(
+        || "Battery │ No battery".to_string(),
+        |b| {
+            let state = BatteryState::from(b.combined_state);
+            let estimate = if state.is_charging() {
+                app.battery_history.time_to_full()
+            } else {
+                app.battery_history.time_to_empty()
+            };
+            build_battery_title(f64::from(b.combined_percentage), state, estimate)
+        },
+    );
 
     // Check if this panel is focused (SPEC-024 v5.0 Feature D)
     let is_focused = app.is_panel_focused(PanelType::Battery);
@@ -479,66 +396,7 @@ pub(super) fn draw_battery_panel(app: &App, canvas: &mut DirectTerminalCanvas<'_
     }
 
     if let Some(bat) = battery {
-        // Draw charge bar with inverted color (red=low, green=full)
-        let bar_width = (inner.width as usize).min(30);
-        let bar = make_bar(bat.capacity as f64 / 100.0, bar_width);
-
-        // Color: red when low, yellow when medium, green when high
-        let color = if bat.capacity < 20 {
-            Color {
-                r: 1.0,
-                g: 0.3,
-                b: 0.3,
-                a: 1.0,
-            } // Red
-        } else if bat.capacity < 50 {
-            Color {
-                r: 1.0,
-                g: 0.8,
-                b: 0.2,
-                a: 1.0,
-            } // Yellow
-        } else {
-            Color {
-                r: 0.3,
-                g: 0.9,
-                b: 0.3,
-                a: 1.0,
-            } // Green
-        };
-
-        canvas.draw_text(
-            &bar,
-            Point::new(inner.x, inner.y),
-            &TextStyle {
-                color,
-                ..Default::default()
-            },
-        );
-
-        // Show status icon
-        if inner.height >= 2.0 {
-            let status_icon = match bat.status.as_str() {
-                "Charging" => "⚡ Charging",
-                "Discharging" => "🔋 Discharging",
-                "Full" => "✓ Full",
-                "Not charging" => "— Idle",
-                _ => "? Unknown",
-            };
-            canvas.draw_text(
-                status_icon,
-                Point::new(inner.x, inner.y + 1.0),
-                &TextStyle {
-                    color: Color {
-                        r: 0.7,
-                        g: 0.7,
-                        b: 0.7,
-                        a: 1.0,
-                    },
-                    ..Default::default()
-                },
-            );
-        }
+        draw_battery_detail(canvas, bat, &app.battery_history, inner);
     } else {
         canvas.draw_text(
             "No battery detected",
@@ -809,12 +667,18 @@ pub(super) fn draw_containers_panel(
     canvas: &mut DirectTerminalCanvas<'_>,
     bounds: Rect,
 ) {
-    // ttop-style title
-    let title = "Containers";
+    use crate::ptop::ui::panels::containers::{build_containers_title, draw_containers};
+
+    let data = app.analyzers.containers_data();
+    // ttop-style title in deterministic mode
+    let title = match data {
+        Some(data) if !app.deterministic => build_containers_title(data),
+        _ => "Containers".to_string(),
+    };
 
     // Check if this panel is focused (SPEC-024 v5.0 Feature D)
     let is_focused = app.is_panel_focused(PanelType::Containers);
-    let mut border = create_panel_border(title, CONTAINERS_COLOR, is_focused);
+    let mut border = create_panel_border(&title, CONTAINERS_COLOR, is_focused);
     border.layout(bounds);
     border.paint(canvas);
     let inner = border.inner_rect();
@@ -823,83 +687,25 @@ pub(super) fn draw_containers_panel(
         return;
     }
 
+    let dim = TextStyle {
+        color: Color {
+            r: 0.5,
+            g: 0.5,
+            b: 0.5,
+            a: 1.0,
+        },
+        ..Default::default()
+    };
+
     // In deterministic mode, show "No running containers" like ttop
     if app.deterministic {
-        canvas.draw_text(
-            "No running containers",
-            Point::new(inner.x, inner.y),
-            &TextStyle {
-                color: Color {
-                    r: 0.5,
-                    g: 0.5,
-                    b: 0.5,
-                    a: 1.0,
-                },
-                ..Default::default()
-            },
-        );
+        canvas.draw_text("No running containers", Point::new(inner.x, inner.y), &dim);
         return;
     }
 
-    // Get containers data from analyzer
-    if let Some(data) = app.analyzers.containers_data() {
-        if data.containers.is_empty() {
-            canvas.draw_text(
-                "No running containers",
-                Point::new(inner.x, inner.y),
-                &TextStyle {
-                    color: Color {
-                        r: 0.5,
-                        g: 0.5,
-                        b: 0.5,
-                        a: 1.0,
-                    },
-                    ..Default::default()
-                },
-            );
-        } else {
-            let mut y = inner.y;
-            for container in data.containers.iter().take(inner.height as usize) {
-                let status_icon = match container.state {
-                    ContainerState::Running => "●",
-                    ContainerState::Paused => "◐",
-                    ContainerState::Exited => "○",
-                    ContainerState::Created => "◎",
-                    ContainerState::Restarting => "↻",
-                    ContainerState::Removing => "⊘",
-                    ContainerState::Dead => "✗",
-                    ContainerState::Unknown => "?",
-                };
-                let name: String = container.name.chars().take(20).collect();
-                let cpu = container.stats.cpu_percent;
-                let mem_mb = container.stats.memory_bytes / (1024 * 1024);
-
-                let text = format!("{status_icon} {name:<20} {cpu:>5.1}% {mem_mb:>4}MB");
-                canvas.draw_text(
-                    &text,
-                    Point::new(inner.x, y),
-                    &TextStyle {
-                        color: CONTAINERS_COLOR,
-                        ..Default::default()
-                    },
-                );
-                y += 1.0;
-            }
-        }
-    } else {
-        canvas.draw_text(
-            "No container runtime",
-            Point::new(inner.x, inner.y),
-            &TextStyle {
-                color: Color {
-                    r: 0.5,
-                    g: 0.5,
-                    b: 0.5,
-                    a: 1.0,
-                },
-                ..Default::default()
-            },
-        );
+    match data {
+        Some(data) => draw_containers(canvas, data, inner, true),
+        None => canvas.draw_text("No container runtime", Point::new(inner.x, inner.y), &dim),
     }
 }
 
@@ -922,50 +728,32 @@ pub(super) fn draw_psi_panel(app: &App, canvas: &mut DirectTerminalCanvas<'_>, b
     // Use PSI data from analyzer
     if let Some(psi) = app.psi_data() {
         if psi.available {
-            let mut y = inner.y;
-
-            // CPU pressure
-            let cpu = psi.cpu.some.avg10;
-            let cpu_symbol = pressure_symbol(cpu);
-            let cpu_color = pressure_color(cpu);
-            canvas.draw_text(
-                &format!("CPU  {cpu_symbol} {cpu:>5.1}%"),
-                Point::new(inner.x, y),
-                &TextStyle {
-                    color: cpu_color,
-                    ..Default::default()
-                },
-            );
-            y += 1.0;
-
-            // Memory pressure
-            if y < inner.y + inner.height {
-                let mem = psi.memory.some.avg10;
-                let mem_symbol = pressure_symbol(mem);
-                let mem_color = pressure_color(mem);
+            let rows = [
+                ("CPU", PsiMetricType::Cpu, psi.cpu.some.avg10),
+                ("MEM", PsiMetricType::Memory, psi.memory.some.avg10),
+                ("I/O", PsiMetricType::Io, psi.io.some.avg10),
+            ];
+            for (i, (label, metric, value)) in rows.into_iter().enumerate() {
+                let y = inner.y + i as f32;
+                if y >= inner.y + inner.height {
+                    break;
+                }
+                let text = format!("{label}  {} {value:>5.1}%", pressure_symbol(value));
                 canvas.draw_text(
-                    &format!("MEM  {mem_symbol} {mem:>5.1}%"),
+                    &text,
                     Point::new(inner.x, y),
                     &TextStyle {
-                        color: mem_color,
+                        color: pressure_color(value),
                         ..Default::default()
                     },
                 );
-                y += 1.0;
-            }
 
-            // I/O pressure
-            if y < inner.y + inner.height {
-                let io = psi.io.some.avg10;
-                let io_symbol = pressure_symbol(io);
-                let io_color = pressure_color(io);
-                canvas.draw_text(
-                    &format!("I/O  {io_symbol} {io:>5.1}%"),
-                    Point::new(inner.x, y),
-                    &TextStyle {
-                        color: io_color,
-                        ..Default::default()
-                    },
+                // Trend arrow + sparkline of recent pressure
+                let offset = text.chars().count() as f32 + 1.0;
+                draw_pressure_trend(
+                    canvas,
+                    app.psi_history.get(metric),
+                    Rect::new(inner.x + offset, y, (inner.width - offset).max(0.0), 1.0),
                 );
             }
         } else {
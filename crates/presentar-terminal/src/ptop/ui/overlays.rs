@@ -27,6 +27,8 @@ pub const HELP_LINES: &[(&str, &str, bool)] = &[
     ("", "-- General --", true),
     ("q, Esc, Ctrl+C", "Quit", false),
     ("h, ?", "Toggle help", false),
+    ("Space", "Pause/resume updates", false),
+    ("F2", "Export snapshot to JSON", false),
     ("", "-- Panel Navigation --", true),
     ("Tab", "Focus next panel", false),
     ("Shift+Tab", "Focus previous panel", false),
@@ -394,8 +396,8 @@ mod tests {
 
     #[test]
     fn test_help_lines_count() {
-        // 23 help lines
-        assert_eq!(HELP_LINES.len(), 23);
+        // 25 help lines
+        assert_eq!(HELP_LINES.len(), 25);
     }
 
     #[test]
@@ -413,7 +415,7 @@ mod tests {
             .iter()
             .filter(|(_, _, is_sect)| !*is_sect)
             .collect();
-        assert_eq!(keybindings.len(), 18, "Should have 18 keybindings");
+        assert_eq!(keybindings.len(), 20, "Should have 20 keybindings");
     }
 
     #[test]
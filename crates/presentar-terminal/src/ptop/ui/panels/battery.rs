@@ -1,9 +1,17 @@
 //! Battery panel rendering and utilities.
 //!
 //! Provides battery panel title building, charge formatting,
-//! and helper functions for rendering battery metrics.
+//! and helper functions for rendering battery metrics. Time-to-empty and
+//! time-to-full come from the reported power draw when available, and are
+//! otherwise estimated from the observed charge rate, since many batteries
+//! do not report `power_now`.
 
-use presentar_core::Color;
+use std::time::Instant;
+
+use presentar_core::{Canvas, Color, Point, Rect, TextStyle};
+
+use crate::perf_trace::DerivativeTracker;
+use crate::ptop::analyzers::BatteryData;
 
 // =============================================================================
 // BATTERY TITLE BUILDING
@@ -51,6 +59,8 @@ pub enum BatteryState {
     Discharging,
     /// Battery is full and connected to power
     Full,
+    /// Connected to power but neither charging nor discharging
+    NotCharging,
     /// Battery status is unknown
     Unknown,
     /// Battery is not present
@@ -65,6 +75,7 @@ impl BatteryState {
             Self::Charging => "Charging",
             Self::Discharging => "Discharging",
             Self::Full => "Full",
+            Self::NotCharging => "Not charging",
             Self::Unknown => "Unknown",
             Self::NotPresent => "N/A",
         }
@@ -77,6 +88,7 @@ impl BatteryState {
             Self::Charging => "CHG",
             Self::Discharging => "DIS",
             Self::Full => "FULL",
+            Self::NotCharging => "IDLE",
             Self::Unknown => "UNK",
             Self::NotPresent => "N/A",
         }
@@ -89,6 +101,7 @@ impl BatteryState {
             Self::Charging => "⚡",
             Self::Discharging => "🔋",
             Self::Full => "🔌",
+            Self::NotCharging => "—",
             Self::Unknown => "❓",
             Self::NotPresent => "∅",
         }
@@ -107,6 +120,19 @@ impl BatteryState {
     }
 }
 
+impl From<crate::ptop::analyzers::BatteryState> for BatteryState {
+    fn from(state: crate::ptop::analyzers::BatteryState) -> Self {
+        use crate::ptop::analyzers::BatteryState as A;
+        match state {
+            A::Charging => Self::Charging,
+            A::Discharging => Self::Discharging,
+            A::Full => Self::Full,
+            A::NotCharging => Self::NotCharging,
+            A::Unknown => Self::Unknown,
+        }
+    }
+}
+
 // =============================================================================
 // TIME FORMATTING
 // =============================================================================
@@ -169,6 +195,7 @@ pub fn battery_state_color(state: BatteryState) -> Color {
         BatteryState::Charging => Color::new(0.3, 0.9, 0.5, 1.0), // Green
         BatteryState::Full => Color::new(0.4, 0.8, 1.0, 1.0),     // Blue
         BatteryState::Discharging => Color::new(1.0, 0.8, 0.3, 1.0), // Yellow
+        BatteryState::NotCharging => Color::new(0.6, 0.7, 0.8, 1.0), // Slate
         BatteryState::Unknown => Color::new(0.5, 0.5, 0.5, 1.0),  // Gray
         BatteryState::NotPresent => Color::new(0.3, 0.3, 0.3, 1.0), // Dark gray
     }
@@ -284,6 +311,209 @@ impl BatteryHealth {
     }
 }
 
+// =============================================================================
+// CHARGE ESTIMATE
+// =============================================================================
+
+/// Charge rates below this magnitude (percent per second, ~0.36%/h) are
+/// treated as flat, so no estimate is shown.
+pub const MIN_CHARGE_RATE: f64 = 1e-4;
+
+/// Placeholder shown when no time estimate is available.
+pub const NO_ESTIMATE: &str = "—";
+
+/// Tracks the combined charge over time to estimate time to empty/full.
+#[derive(Debug, Clone, Default)]
+pub struct BatteryHistory {
+    /// Rate of change of charge (percent per second)
+    charge: DerivativeTracker,
+    /// Most recent charge percentage
+    percent: f64,
+    /// Analyzer timestamp of the most recent sample
+    last_sample: Option<Instant>,
+    /// Last seen charging or discharging state
+    direction: Option<BatteryState>,
+}
+
+impl BatteryHistory {
+    /// Record a new analyzer reading, or reset when no battery is present.
+    ///
+    /// The analyzer refreshes less often than ptop collects, so repeated
+    /// readings (same `last_update`) are ignored rather than counted as a
+    /// flat charge. Samples from before a switch between charging and
+    /// discharging are dropped so the old slope does not skew the estimate.
+    pub fn update(&mut self, data: Option<&BatteryData>) {
+        let Some(data) = data.filter(|d| d.has_battery()) else {
+            self.reset();
+            return;
+        };
+        let state = BatteryState::from(data.combined_state);
+        if state.is_charging() || state.is_discharging() {
+            if self.direction.is_some_and(|prev| prev != state) {
+                self.reset();
+            }
+            self.direction = Some(state);
+        }
+        let Some(at) = data.last_update else {
+            return;
+        };
+        let dt = match self.last_sample {
+            Some(prev) if at <= prev => return,
+            Some(prev) => at.duration_since(prev).as_secs_f64(),
+            None => 0.0,
+        };
+        self.last_sample = Some(at);
+        self.push_with_dt(f64::from(data.combined_percentage), dt);
+    }
+
+    /// Record a charge percentage taken `dt_secs` after the previous one.
+    pub fn push_with_dt(&mut self, percent: f64, dt_secs: f64) {
+        self.charge.update_with_dt(percent, dt_secs);
+        self.percent = percent;
+    }
+
+    /// Clear all samples.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Smoothed charge rate in percent per hour.
+    #[must_use]
+    pub fn rate_per_hour(&self) -> f64 {
+        self.charge.smoothed() * 3600.0
+    }
+
+    /// Estimated seconds until empty, if the charge is falling.
+    #[must_use]
+    pub fn time_to_empty(&self) -> Option<u64> {
+        let rate = self.charge.smoothed();
+        (self.charge.count() >= 2 && rate < -MIN_CHARGE_RATE).then(|| (self.percent / -rate) as u64)
+    }
+
+    /// Estimated seconds until full, if the charge is rising.
+    #[must_use]
+    pub fn time_to_full(&self) -> Option<u64> {
+        let rate = self.charge.smoothed();
+        (self.charge.count() >= 2 && rate > MIN_CHARGE_RATE)
+            .then(|| ((100.0 - self.percent).max(0.0) / rate) as u64)
+    }
+}
+
+/// Combined power draw in watts, if every battery reports it.
+#[must_use]
+pub fn power_draw(data: &BatteryData) -> Option<f64> {
+    let mut watts = 0.0;
+    for battery in &data.batteries {
+        watts += battery.power_now?.abs();
+    }
+    (watts > 0.0).then_some(watts)
+}
+
+/// Estimate seconds until empty (or full, when `charging`) from the
+/// reported energy and power draw, summed across batteries.
+#[must_use]
+pub fn power_estimate(data: &BatteryData, charging: bool) -> Option<u64> {
+    let watts = power_draw(data)?;
+    let (mut now, mut full) = (0.0, 0.0);
+    for battery in &data.batteries {
+        now += battery.energy_now?;
+        full += battery.energy_full?;
+    }
+    let remaining = if charging { (full - now).max(0.0) } else { now };
+    Some((remaining / watts * 3600.0) as u64)
+}
+
+/// Format an optional estimate, using `NO_ESTIMATE` when absent.
+#[must_use]
+pub fn format_estimate(seconds: Option<u64>) -> String {
+    seconds.map_or_else(|| NO_ESTIMATE.to_string(), format_time_remaining)
+}
+
+// =============================================================================
+// DRAWING
+// =============================================================================
+
+/// Draw charge bar, state, and time estimate rows.
+///
+/// Charging batteries show time to full; everything else time to empty.
+/// The reported power draw is preferred, falling back to the charge history.
+pub fn draw_battery_detail(
+    canvas: &mut dyn Canvas,
+    data: &BatteryData,
+    history: &BatteryHistory,
+    bounds: Rect,
+) {
+    if bounds.height < 1.0 {
+        return;
+    }
+    let percent = f64::from(data.combined_percentage);
+    let state = BatteryState::from(data.combined_state);
+
+    let bar_width = (bounds.width as usize).saturating_sub(6).min(30);
+    let filled = ((percent / 100.0).clamp(0.0, 1.0) * bar_width as f64).round() as usize;
+    let bar = format!(
+        "{}{} {percent:>3.0}%",
+        "█".repeat(filled),
+        "░".repeat(bar_width - filled)
+    );
+    canvas.draw_text(
+        &bar,
+        Point::new(bounds.x, bounds.y),
+        &TextStyle {
+            color: battery_percent_color(percent, state.is_charging()),
+            ..Default::default()
+        },
+    );
+
+    if bounds.height >= 2.0 {
+        canvas.draw_text(
+            &format!("{} {}", state.icon(), state.display_name()),
+            Point::new(bounds.x, bounds.y + 1.0),
+            &TextStyle {
+                color: battery_state_color(state),
+                ..Default::default()
+            },
+        );
+    }
+
+    if bounds.height >= 3.0 {
+        let label = if state.is_charging() {
+            "Time to full"
+        } else {
+            "Time to empty"
+        };
+        let from_power = if state.is_charging() || state.is_discharging() {
+            power_estimate(data, state.is_charging())
+        } else {
+            None
+        };
+        let (estimate, rate) = if let Some(secs) = from_power {
+            let watts = power_draw(data).unwrap_or_default();
+            (Some(secs), format!(" ({watts:.1} W)"))
+        } else {
+            let estimate = if state.is_charging() {
+                history.time_to_full()
+            } else {
+                history.time_to_empty()
+            };
+            let rate = if estimate.is_some() {
+                format!(" ({:+.1}%/h)", history.rate_per_hour())
+            } else {
+                String::new()
+            };
+            (estimate, rate)
+        };
+        canvas.draw_text(
+            &format!("{label}: {}{rate}", format_estimate(estimate)),
+            Point::new(bounds.x, bounds.y + 2.0),
+            &TextStyle {
+                color: Color::new(0.7, 0.7, 0.7, 1.0),
+                ..Default::default()
+            },
+        );
+    }
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -453,6 +683,17 @@ mod tests {
         assert!(color.b > 0.9);
     }
 
+    #[test]
+    fn test_not_charging_is_distinct_from_full() {
+        use crate::ptop::analyzers::BatteryState as A;
+        let state = BatteryState::from(A::NotCharging);
+        assert_eq!(state, BatteryState::NotCharging);
+        assert_ne!(state, BatteryState::from(A::Full));
+        assert_eq!(state.display_name(), "Not charging");
+        assert!(!state.is_charging());
+        assert!(!state.is_discharging());
+    }
+
     // =========================================================================
     // BatteryIcon tests
     // =========================================================================
@@ -547,4 +788,174 @@ mod tests {
         let debug = format!("{:?}", health);
         assert!(debug.contains("Good"));
     }
+
+    // =========================================================================
+    // BatteryHistory tests
+    // =========================================================================
+
+    fn render_detail(data: &BatteryData, history: &BatteryHistory) -> Vec<String> {
+        use crate::direct::{CellBuffer, DirectTerminalCanvas};
+        let (width, height) = (40, 3);
+        let mut buffer = CellBuffer::new(width, height);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        draw_battery_detail(
+            &mut canvas,
+            data,
+            history,
+            Rect::new(0.0, 0.0, f32::from(width), f32::from(height)),
+        );
+        buffer.rows_as_strings()
+    }
+
+    fn battery_data(percent: f32, state: crate::ptop::analyzers::BatteryState) -> BatteryData {
+        BatteryData {
+            batteries: vec![crate::ptop::analyzers::BatteryInfo {
+                name: "BAT0".to_string(),
+                percentage: percent,
+                state,
+                ..Default::default()
+            }],
+            combined_percentage: percent,
+            combined_state: state,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_declining_charge_gives_time_to_empty() {
+        let mut history = BatteryHistory::default();
+        // 0.5% per minute over 20 samples
+        for i in 0..20 {
+            history.push_with_dt(80.0 - f64::from(i) * 0.5, 60.0);
+        }
+
+        let tte = history.time_to_empty().expect("estimate");
+        assert!(tte > 0);
+        // 70.5% at 30%/h is roughly 2h20m; the EMA lags slightly above that
+        assert!((7_000..12_000).contains(&tte), "tte = {tte}");
+        assert!(history.time_to_full().is_none());
+        assert!(history.rate_per_hour() < 0.0);
+    }
+
+    #[test]
+    fn test_flat_charge_has_no_estimate() {
+        let mut history = BatteryHistory::default();
+        for _ in 0..10 {
+            history.push_with_dt(55.0, 5.0);
+        }
+        assert!(history.time_to_empty().is_none());
+        assert!(history.time_to_full().is_none());
+        assert_eq!(format_estimate(history.time_to_empty()), NO_ESTIMATE);
+    }
+
+    #[test]
+    fn test_rising_charge_gives_time_to_full() {
+        let mut history = BatteryHistory::default();
+        for i in 0..20 {
+            history.push_with_dt(40.0 + f64::from(i), 60.0);
+        }
+        assert!(history.time_to_full().is_some_and(|t| t > 0));
+        assert!(history.time_to_empty().is_none());
+    }
+
+    #[test]
+    fn test_update_ignores_repeated_readings_and_resets() {
+        use crate::ptop::analyzers::BatteryState as A;
+        let start = Instant::now();
+        let mut history = BatteryHistory::default();
+        let mut data = battery_data(90.0, A::Discharging);
+        data.last_update = Some(start);
+        history.update(Some(&data));
+        history.update(Some(&data));
+        assert_eq!(history.charge.count(), 1);
+
+        data.combined_percentage = 89.0;
+        data.last_update = Some(start + std::time::Duration::from_secs(60));
+        history.update(Some(&data));
+        assert!(history.time_to_empty().is_some());
+
+        history.update(None);
+        assert!(history.time_to_empty().is_none());
+        assert_eq!(history.charge.count(), 0);
+    }
+
+    #[test]
+    fn test_update_resets_when_direction_flips() {
+        use crate::ptop::analyzers::BatteryState as A;
+        let start = Instant::now();
+        let mut history = BatteryHistory::default();
+        let mut data = battery_data(90.0, A::Discharging);
+        for i in 0..5u8 {
+            data.combined_percentage = 90.0 - f32::from(i);
+            data.last_update = Some(start + std::time::Duration::from_secs(u64::from(i) * 60));
+            history.update(Some(&data));
+        }
+        assert!(history.time_to_empty().is_some());
+
+        // Idle readings in between do not clear the history by themselves
+        data.combined_state = A::NotCharging;
+        data.last_update = Some(start + std::time::Duration::from_secs(300));
+        history.update(Some(&data));
+        assert_eq!(history.charge.count(), 6);
+
+        data.combined_state = A::Charging;
+        data.last_update = Some(start + std::time::Duration::from_secs(360));
+        history.update(Some(&data));
+        assert_eq!(history.charge.count(), 1);
+        assert!(history.time_to_empty().is_none());
+        assert!(history.time_to_full().is_none());
+    }
+
+    #[test]
+    fn test_power_estimate() {
+        use crate::ptop::analyzers::BatteryState as A;
+        let mut data = battery_data(50.0, A::Discharging);
+        assert!(power_estimate(&data, false).is_none());
+
+        let battery = &mut data.batteries[0];
+        battery.energy_now = Some(30.0);
+        battery.energy_full = Some(60.0);
+        battery.power_now = Some(15.0);
+        assert_eq!(power_draw(&data), Some(15.0));
+        assert_eq!(power_estimate(&data, false), Some(7200));
+        assert_eq!(power_estimate(&data, true), Some(7200));
+
+        data.batteries[0].power_now = Some(0.0);
+        assert!(power_estimate(&data, false).is_none());
+    }
+
+    #[test]
+    fn test_draw_battery_detail_prefers_power_draw() {
+        use crate::ptop::analyzers::BatteryState as A;
+        let mut data = battery_data(50.0, A::Discharging);
+        let battery = &mut data.batteries[0];
+        battery.energy_now = Some(20.0);
+        battery.energy_full = Some(40.0);
+        battery.power_now = Some(10.0);
+        let lines = render_detail(&data, &BatteryHistory::default());
+        assert!(
+            lines[2].starts_with("Time to empty: 2h 0m (10.0 W)"),
+            "{lines:?}"
+        );
+
+        data.combined_state = A::NotCharging;
+        let lines = render_detail(&data, &BatteryHistory::default());
+        assert!(lines[1].contains("Not charging"));
+        assert!(lines[2].starts_with("Time to empty: —"));
+    }
+
+    #[test]
+    fn test_draw_battery_detail() {
+        use crate::ptop::analyzers::BatteryState as A;
+        let lines = render_detail(
+            &battery_data(64.0, A::Discharging),
+            &BatteryHistory::default(),
+        );
+        assert!(lines[0].contains("64%"));
+        assert!(lines[1].contains("Discharging"));
+        assert!(lines[2].starts_with("Time to empty: —"));
+
+        let lines = render_detail(&battery_data(64.0, A::Charging), &BatteryHistory::default());
+        assert!(lines[2].starts_with("Time to full: —"));
+    }
 }
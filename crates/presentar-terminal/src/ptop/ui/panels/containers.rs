@@ -0,0 +1,214 @@
+//! Containers panel rendering and utilities.
+//!
+//! Renders `ContainersAnalyzer` data as a CPU-sorted table using the
+//! `ContainersPanel` widget, with each row tagged by its runtime.
+
+use presentar_core::{Canvas, Color, Point, Rect, TextStyle, Widget};
+
+use crate::ptop::analyzers::{Container, ContainerRuntime, ContainerState, ContainersData};
+use crate::widgets::{ContainerEntry, ContainersPanel};
+
+// =============================================================================
+// TITLE BUILDING
+// =============================================================================
+
+/// Build containers panel title.
+///
+/// Format: "Containers │ 2/3 running │ Docker"
+#[must_use]
+pub fn build_containers_title(data: &ContainersData) -> String {
+    let running = data.running().count();
+    match data.runtime {
+        Some(runtime) => format!(
+            "Containers │ {running}/{} running │ {}",
+            data.total(),
+            runtime.as_str()
+        ),
+        None => format!("Containers │ {running}/{} running", data.total()),
+    }
+}
+
+// =============================================================================
+// WIDGET CONVERSION
+// =============================================================================
+
+/// Map an analyzer container state onto the widget's coarser states.
+#[must_use]
+pub fn widget_state(state: ContainerState) -> crate::widgets::ContainerState {
+    use crate::widgets::ContainerState as W;
+    match state {
+        ContainerState::Running => W::Running,
+        ContainerState::Paused => W::Paused,
+        ContainerState::Restarting => W::Restarting,
+        ContainerState::Dead => W::Dead,
+        ContainerState::Exited
+        | ContainerState::Created
+        | ContainerState::Removing
+        | ContainerState::Unknown => W::Stopped,
+    }
+}
+
+/// Convert an analyzer container into a `ContainersPanel` row.
+#[must_use]
+pub fn container_entry(container: &Container) -> ContainerEntry {
+    let runtime = match container.runtime {
+        ContainerRuntime::Docker => crate::widgets::ContainerRuntime::Docker,
+        ContainerRuntime::Podman => crate::widgets::ContainerRuntime::Podman,
+    };
+    ContainerEntry::new(&container.name, &container.id)
+        .with_state(widget_state(container.state))
+        .with_runtime(runtime)
+        .with_cpu(container.stats.cpu_percent)
+        .with_memory(container.stats.memory_bytes, container.stats.memory_limit)
+        .with_image(container.display_image())
+}
+
+// =============================================================================
+// DRAWING
+// =============================================================================
+
+/// Draw the containers table, highest CPU first.
+///
+/// With `running_only`, stopped containers are filtered out and an empty
+/// result shows "No running containers".
+pub fn draw_containers(
+    canvas: &mut dyn Canvas,
+    data: &ContainersData,
+    bounds: Rect,
+    running_only: bool,
+) {
+    let has_rows = data
+        .containers
+        .iter()
+        .any(|c| !running_only || c.state == ContainerState::Running);
+    if !has_rows {
+        canvas.draw_text(
+            if running_only {
+                "No running containers"
+            } else {
+                "No containers"
+            },
+            Point::new(bounds.x, bounds.y),
+            &TextStyle {
+                color: Color::new(0.5, 0.5, 0.5, 1.0),
+                ..Default::default()
+            },
+        );
+        return;
+    }
+
+    let mut panel = ContainersPanel::new()
+        .with_containers(data.containers.iter().map(container_entry).collect())
+        .running_only(running_only)
+        .sort_by_cpu(true)
+        .max_containers(bounds.height as usize);
+    panel.layout(bounds);
+    panel.paint(canvas);
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::{CellBuffer, DirectTerminalCanvas};
+    use crate::ptop::analyzers::ContainerStats;
+
+    fn container(name: &str, state: ContainerState, cpu: f32) -> Container {
+        Container {
+            id: format!("{name}-id"),
+            name: name.to_string(),
+            image: format!("docker.io/library/{name}:latest"),
+            state,
+            status: state.as_str().to_string(),
+            runtime: ContainerRuntime::Docker,
+            stats: ContainerStats {
+                cpu_percent: cpu,
+                memory_bytes: 128 * 1024 * 1024,
+                ..Default::default()
+            },
+            created: 0,
+            ports: Vec::new(),
+        }
+    }
+
+    fn render(data: &ContainersData, running_only: bool) -> Vec<String> {
+        let (width, height) = (50, 4);
+        let mut buffer = CellBuffer::new(width, height);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        draw_containers(
+            &mut canvas,
+            data,
+            Rect::new(0.0, 0.0, f32::from(width), f32::from(height)),
+            running_only,
+        );
+        buffer.rows_as_strings()
+    }
+
+    fn sample() -> ContainersData {
+        ContainersData {
+            containers: vec![
+                container("old-job", ContainerState::Exited, 0.0),
+                container("api", ContainerState::Running, 12.5),
+            ],
+            runtime: Some(ContainerRuntime::Docker),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build_containers_title() {
+        assert_eq!(
+            build_containers_title(&sample()),
+            "Containers │ 1/2 running │ Docker"
+        );
+    }
+
+    #[test]
+    fn test_container_entry_conversion() {
+        let entry = container_entry(&container("db", ContainerState::Exited, 3.0));
+        assert_eq!(entry.state, crate::widgets::ContainerState::Stopped);
+        assert_eq!(
+            entry.runtime,
+            Some(crate::widgets::ContainerRuntime::Docker)
+        );
+        assert_eq!(entry.image, "db:latest");
+        assert_eq!(entry.cpu_percent, 3.0);
+    }
+
+    #[test]
+    fn test_running_only_filter() {
+        let lines = render(&sample(), true);
+        assert!(lines[0].contains("docker api"));
+        assert!(lines[0].contains("12.5%"));
+        assert!(lines.iter().all(|l| !l.contains("old-job")));
+
+        let lines = render(&sample(), false);
+        assert!(lines.iter().any(|l| l.contains("old-job")));
+    }
+
+    #[test]
+    fn test_running_only_empty_message() {
+        let data = ContainersData {
+            containers: vec![container("old-job", ContainerState::Exited, 0.0)],
+            ..Default::default()
+        };
+        assert!(render(&data, true)[0].starts_with("No running containers"));
+    }
+
+    #[test]
+    fn test_sorted_by_cpu() {
+        let data = ContainersData {
+            containers: vec![
+                container("low", ContainerState::Running, 1.0),
+                container("high", ContainerState::Running, 50.0),
+            ],
+            ..Default::default()
+        };
+        let lines = render(&data, true);
+        assert!(lines[0].contains("high"));
+        assert!(lines[1].contains("low"));
+    }
+}
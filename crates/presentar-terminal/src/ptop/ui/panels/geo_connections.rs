@@ -0,0 +1,437 @@
+//! Geo-annotated connections table rendering and utilities.
+//!
+//! Renders `ConnectionsAnalyzer` TCP connections with the remote address,
+//! state, owning process and the country resolved by the embedded `geoip`
+//! table. Loopback and private remotes are labeled "local" instead of being
+//! looked up.
+
+use std::net::IpAddr;
+
+use presentar_core::{Canvas, Color, Point, Rect, TextStyle};
+
+use super::connections::{is_private_ip, ACTIVE_COLOR, DIM_COLOR, LISTEN_COLOR};
+use crate::ptop::analyzers::{lookup, TcpConnection, TcpState};
+use crate::ptop::ui::colors::CONNECTIONS_COLOR;
+use crate::widgets::display_rules::{format_column, take_width, ColumnAlign, TruncateStrategy};
+use crate::widgets::selection::RowHighlight;
+
+// =============================================================================
+// SORTING
+// =============================================================================
+
+/// Sort order for the connections table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectionSort {
+    /// By state (LISTEN, ESTABLISHED, then the rest), then remote address
+    #[default]
+    State,
+    /// By remote address and port
+    RemoteAddr,
+}
+
+impl ConnectionSort {
+    /// Cycle to the next sort order.
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            Self::State => Self::RemoteAddr,
+            Self::RemoteAddr => Self::State,
+        }
+    }
+
+    /// Short label for panel titles.
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::State => "state",
+            Self::RemoteAddr => "remote",
+        }
+    }
+}
+
+/// Display rank of a TCP state (LISTEN first, then ESTABLISHED).
+fn state_rank(state: TcpState) -> u8 {
+    match state {
+        TcpState::Listen => 0,
+        TcpState::Established => 1,
+        _ => 2,
+    }
+}
+
+/// Sort connections in place.
+pub fn sort_connections(conns: &mut [&TcpConnection], sort: ConnectionSort) {
+    match sort {
+        ConnectionSort::State => conns.sort_by(|a, b| {
+            state_rank(a.state)
+                .cmp(&state_rank(b.state))
+                .then_with(|| (a.remote_addr, a.remote_port).cmp(&(b.remote_addr, b.remote_port)))
+        }),
+        ConnectionSort::RemoteAddr => conns.sort_by_key(|c| (c.remote_addr, c.remote_port)),
+    }
+}
+
+// =============================================================================
+// LOCATION
+// =============================================================================
+
+/// Location label for a remote address.
+///
+/// Loopback/private addresses return "local"; public IPv4 addresses return
+/// "flag CODE Name" from `geoip::lookup`, and anything unresolved "??".
+#[must_use]
+pub fn connection_location(addr: &IpAddr) -> String {
+    if is_private_ip(addr) || addr.is_unspecified() {
+        return "local".to_string();
+    }
+    match addr {
+        IpAddr::V4(v4) => lookup(*v4).map_or_else(
+            || "??".to_string(),
+            |info| format!("{} {} {}", info.flag, info.code, info.name),
+        ),
+        IpAddr::V6(_) => "??".to_string(),
+    }
+}
+
+/// Text color for a connection state.
+fn state_color(state: TcpState) -> Color {
+    match state {
+        TcpState::Established => ACTIVE_COLOR,
+        TcpState::Listen => LISTEN_COLOR,
+        TcpState::TimeWait | TcpState::CloseWait => Color::new(0.9, 0.6, 0.3, 1.0),
+        _ => DIM_COLOR,
+    }
+}
+
+// =============================================================================
+// DRAWING
+// =============================================================================
+
+/// Fixed columns as (header, width, alignment); LOCATION follows them and
+/// takes whatever width remains (flags are double-width, so it goes last).
+const COLUMNS: [(&str, usize, ColumnAlign); 6] = [
+    ("PROTO", 5, ColumnAlign::Left),
+    ("LOCAL ADDRESS", 23, ColumnAlign::Left),
+    ("REMOTE ADDRESS", 23, ColumnAlign::Left),
+    ("STATE", 11, ColumnAlign::Left),
+    ("PID", 7, ColumnAlign::Right),
+    ("PROCESS", 16, ColumnAlign::Left),
+];
+
+/// Index of the STATE column, drawn in its own color.
+const STATE_COLUMN: usize = 3;
+
+/// Index of the PROCESS column, truncated command-style.
+const PROCESS_COLUMN: usize = 5;
+
+/// Header background (matches the other exploded tables).
+const HEADER_BG: Color = Color {
+    r: 0.12,
+    g: 0.15,
+    b: 0.22,
+    a: 1.0,
+};
+
+/// Lay out one row's cells into exactly `width` display columns.
+fn layout_row(cells: &[&str; 7], width: usize) -> String {
+    let mut line = String::with_capacity(width);
+    let mut remaining = width;
+    for (i, cell) in cells.iter().enumerate() {
+        if i > 0 {
+            if remaining == 0 {
+                break;
+            }
+            line.push(' ');
+            remaining -= 1;
+        }
+        let (column, align) = COLUMNS
+            .get(i)
+            .map_or((remaining, ColumnAlign::Left), |&(_, w, align)| {
+                (w.min(remaining), align)
+            });
+        let strategy = if i == PROCESS_COLUMN {
+            TruncateStrategy::Command
+        } else {
+            TruncateStrategy::End
+        };
+        line.push_str(&format_column(cell, column, align, strategy));
+        remaining -= column;
+    }
+    line
+}
+
+/// Display column where cell `index` starts.
+fn column_start(index: usize) -> usize {
+    COLUMNS[..index].iter().map(|&(_, w, _)| w + 1).sum()
+}
+
+/// Column header for `draw_geo_connections`, laid out to `width` columns.
+#[must_use]
+pub fn connections_header(width: usize) -> String {
+    let [proto, local, remote, state, pid, process] = COLUMNS.map(|(header, _, _)| header);
+    layout_row(
+        &[proto, local, remote, state, pid, process, "LOCATION"],
+        width,
+    )
+}
+
+/// Format one table row, truncated and padded to `width` display columns.
+#[must_use]
+pub fn format_connection_row(conn: &TcpConnection, width: usize) -> String {
+    let local = format!("{}:{}", conn.local_addr, conn.local_port);
+    let remote = format!("{}:{}", conn.remote_addr, conn.remote_port);
+    let pid = conn.pid.map_or_else(|| "-".to_string(), |p| p.to_string());
+    let location = connection_location(&conn.remote_addr);
+    layout_row(
+        &[
+            if conn.is_ipv6 { "TCP6" } else { "TCP" },
+            local.as_str(),
+            remote.as_str(),
+            conn.state.as_str(),
+            pid.as_str(),
+            conn.process_name.as_deref().unwrap_or("-"),
+            location.as_str(),
+        ],
+        width,
+    )
+}
+
+/// Draw the connections table sorted by `sort`.
+///
+/// `selected` is the row (in sorted order) drawn with the selection
+/// highlight.
+pub fn draw_geo_connections(
+    canvas: &mut dyn Canvas,
+    conns: &[TcpConnection],
+    bounds: Rect,
+    sort: ConnectionSort,
+    selected: Option<usize>,
+) {
+    if bounds.height < 1.0 || bounds.width < 1.0 {
+        return;
+    }
+    let width = bounds.width as usize;
+    canvas.fill_rect(Rect::new(bounds.x, bounds.y, bounds.width, 1.0), HEADER_BG);
+    canvas.draw_text(
+        &connections_header(width),
+        Point::new(bounds.x, bounds.y),
+        &TextStyle {
+            color: CONNECTIONS_COLOR,
+            ..Default::default()
+        },
+    );
+
+    if conns.is_empty() {
+        if bounds.height >= 2.0 {
+            canvas.draw_text(
+                take_width("No connections", width),
+                Point::new(bounds.x, bounds.y + 1.0),
+                &TextStyle {
+                    color: DIM_COLOR,
+                    ..Default::default()
+                },
+            );
+        }
+        return;
+    }
+
+    let mut rows: Vec<&TcpConnection> = conns.iter().collect();
+    sort_connections(&mut rows, sort);
+    let max_rows = (bounds.height as usize).saturating_sub(1);
+    let state_x = column_start(STATE_COLUMN);
+    for (i, conn) in rows.into_iter().take(max_rows).enumerate() {
+        let y = bounds.y + 1.0 + i as f32;
+        let is_selected = selected == Some(i);
+        let row_hl = RowHighlight::new(Rect::new(bounds.x, y, bounds.width, 1.0), is_selected);
+        row_hl.paint(canvas);
+        canvas.draw_text(
+            &format_connection_row(conn, width),
+            Point::new(bounds.x, y),
+            &row_hl.text_style(),
+        );
+
+        // Re-draw the state in its own color unless the row is selected.
+        if !is_selected && state_x < width {
+            let state_width = COLUMNS[STATE_COLUMN].1.min(width - state_x);
+            canvas.draw_text(
+                take_width(conn.state.as_str(), state_width),
+                Point::new(bounds.x + state_x as f32, y),
+                &TextStyle {
+                    color: state_color(conn.state),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::{CellBuffer, DirectTerminalCanvas};
+    use crate::ptop::analyzers::get_country_code;
+    use crate::widgets::selection::SELECTION_BG;
+    use std::net::Ipv4Addr;
+    use unicode_width::UnicodeWidthStr;
+
+    fn conn(remote: [u8; 4], port: u16, state: TcpState, process: &str) -> TcpConnection {
+        TcpConnection {
+            local_addr: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)),
+            local_port: 51_000,
+            remote_addr: IpAddr::V4(Ipv4Addr::from(remote)),
+            remote_port: port,
+            state,
+            inode: 0,
+            uid: 1000,
+            pid: Some(4242),
+            process_name: Some(process.to_string()),
+            is_ipv6: false,
+            first_seen: None,
+        }
+    }
+
+    fn render(conns: &[TcpConnection], sort: ConnectionSort) -> Vec<String> {
+        render_sized(conns, sort, 120, 120)
+    }
+
+    /// Render into a `buffer_width` buffer with the table `width` columns wide.
+    fn render_sized(
+        conns: &[TcpConnection],
+        sort: ConnectionSort,
+        buffer_width: u16,
+        width: u16,
+    ) -> Vec<String> {
+        let height = 5;
+        let mut buffer = CellBuffer::new(buffer_width, height);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        draw_geo_connections(
+            &mut canvas,
+            conns,
+            Rect::new(0.0, 0.0, f32::from(width), f32::from(height)),
+            sort,
+            None,
+        );
+        buffer.rows_as_strings()
+    }
+
+    #[test]
+    fn test_public_ip_row_has_country_code() {
+        let ip = Ipv4Addr::new(8, 8, 8, 8);
+        let code = get_country_code(ip);
+        assert_ne!(code, "??");
+
+        let lines = render(
+            &[conn(ip.octets(), 443, TcpState::Established, "curl")],
+            ConnectionSort::State,
+        );
+        let row = &lines[1];
+        assert!(row.starts_with("TCP   192.168.1.10:51000"));
+        assert!(row.contains("8.8.8.8:443"));
+        assert!(row.contains("ESTABLISHED"));
+        assert!(row.contains("curl"));
+        assert!(row.contains(code), "row {row:?} missing {code}");
+    }
+
+    #[test]
+    fn test_header_has_all_columns() {
+        let header = connections_header(120);
+        for column in [
+            "PROTO",
+            "LOCAL ADDRESS",
+            "REMOTE ADDRESS",
+            "STATE",
+            "PID",
+            "PROCESS",
+            "LOCATION",
+        ] {
+            assert!(
+                header.contains(column),
+                "header {header:?} missing {column}"
+            );
+        }
+        let state = header.find("STATE").unwrap();
+        assert_eq!(state, column_start(STATE_COLUMN));
+    }
+
+    #[test]
+    fn test_row_fits_width() {
+        let public = conn(
+            [8, 8, 8, 8],
+            443,
+            TcpState::Established,
+            "a-very-long-process",
+        );
+        for width in 0..120 {
+            let row = format_connection_row(&public, width);
+            assert_eq!(UnicodeWidthStr::width(row.as_str()), width, "width {width}");
+        }
+
+        // Nothing is drawn past the table bounds.
+        let lines = render_sized(&[public], ConnectionSort::State, 100, 40);
+        for line in &lines[..2] {
+            let outside: String = line.chars().skip(40).collect();
+            assert!(outside.trim().is_empty(), "{line:?}");
+        }
+    }
+
+    #[test]
+    fn test_selected_row_is_highlighted() {
+        let conns = [
+            conn([1, 1, 1, 1], 443, TcpState::Established, "a"),
+            conn([9, 9, 9, 9], 443, TcpState::Established, "b"),
+        ];
+        let mut buffer = CellBuffer::new(100, 4);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        draw_geo_connections(
+            &mut canvas,
+            &conns,
+            Rect::new(0.0, 0.0, 100.0, 4.0),
+            ConnectionSort::RemoteAddr,
+            Some(1),
+        );
+        assert_eq!(buffer.get(10, 2).unwrap().bg, SELECTION_BG);
+        assert_ne!(buffer.get(10, 1).unwrap().bg, SELECTION_BG);
+    }
+
+    #[test]
+    fn test_private_and_loopback_are_local() {
+        for ip in [[127, 0, 0, 1], [10, 1, 2, 3], [192, 168, 0, 5]] {
+            assert_eq!(
+                connection_location(&IpAddr::V4(Ipv4Addr::from(ip))),
+                "local"
+            );
+        }
+        let row =
+            format_connection_row(&conn([10, 0, 0, 7], 22, TcpState::Established, "ssh"), 120);
+        assert!(row.trim_end().ends_with("local"));
+    }
+
+    #[test]
+    fn test_sort_by_state_and_remote() {
+        let conns = vec![
+            conn([9, 9, 9, 9], 443, TcpState::TimeWait, "a"),
+            conn([1, 1, 1, 1], 443, TcpState::Established, "b"),
+            conn([0, 0, 0, 0], 0, TcpState::Listen, "c"),
+        ];
+
+        let lines = render(&conns, ConnectionSort::State);
+        assert!(lines[1].contains("LISTEN"));
+        assert!(lines[2].contains("ESTABLISHED"));
+        assert!(lines[3].contains("TIME_WAIT"));
+
+        let lines = render(&conns, ConnectionSort::RemoteAddr);
+        assert!(lines[1].contains("0.0.0.0:0"));
+        assert!(lines[2].contains("1.1.1.1:443"));
+        assert!(lines[3].contains("9.9.9.9:443"));
+    }
+
+    #[test]
+    fn test_connection_sort_cycle() {
+        assert_eq!(ConnectionSort::default().next(), ConnectionSort::RemoteAddr);
+        assert_eq!(ConnectionSort::RemoteAddr.next(), ConnectionSort::State);
+        assert_eq!(ConnectionSort::RemoteAddr.label(), "remote");
+    }
+}
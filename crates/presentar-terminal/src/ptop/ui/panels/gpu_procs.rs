@@ -0,0 +1,254 @@
+//! GPU processes panel rendering and utilities.
+//!
+//! Renders the per-process VRAM and SM utilization collected by
+//! `GpuProcsAnalyzer`, grouped by device. Each device is drawn with the
+//! `GpuPanel` widget under a one-line device header. Nothing is drawn when
+//! no GPU is present, so callers can collapse the panel.
+
+use presentar_core::{Canvas, Point, Rect, TextStyle, Widget};
+
+use crate::ptop::analyzers::{GpuInfo, GpuProcess, GpuProcsData, GpuVendor};
+use crate::ptop::ui::colors::GPU_COLOR;
+use crate::widgets::{GpuDevice, GpuPanel};
+
+// =============================================================================
+// TITLE BUILDING
+// =============================================================================
+
+/// Build GPU processes panel title.
+///
+/// Format: "GPU Procs │ 3 procs │ 2 GPUs"
+#[must_use]
+pub fn build_gpu_procs_title(data: &GpuProcsData) -> String {
+    let gpus = data.gpu_count();
+    format!(
+        "GPU Procs │ {} procs │ {} GPU{}",
+        data.process_count(),
+        gpus,
+        if gpus == 1 { "" } else { "s" }
+    )
+}
+
+// =============================================================================
+// GROUPING
+// =============================================================================
+
+/// Group processes by the GPU they run on, in device order.
+///
+/// Processes within a device are sorted by VRAM usage (largest first).
+/// Processes whose `gpu_index` matches no known GPU are dropped.
+#[must_use]
+pub fn processes_by_device(data: &GpuProcsData) -> Vec<(&GpuInfo, Vec<&GpuProcess>)> {
+    data.gpus
+        .iter()
+        .map(|gpu| {
+            let mut procs: Vec<_> = data
+                .processes
+                .iter()
+                .filter(|p| p.gpu_index == gpu.index)
+                .collect();
+            procs.sort_by(|a, b| b.used_memory.cmp(&a.used_memory));
+            (gpu, procs)
+        })
+        .collect()
+}
+
+// =============================================================================
+// WIDGET CONVERSION
+// =============================================================================
+
+/// Convert analyzer GPU info into a `GpuPanel` device.
+#[must_use]
+pub fn panel_device(gpu: &GpuInfo) -> GpuDevice {
+    let vendor = match gpu.vendor {
+        GpuVendor::Nvidia => crate::widgets::GpuVendor::Nvidia,
+        GpuVendor::Amd => crate::widgets::GpuVendor::Amd,
+        GpuVendor::Intel => crate::widgets::GpuVendor::Intel,
+        GpuVendor::Apple | GpuVendor::Unknown => crate::widgets::GpuVendor::Unknown,
+    };
+    let mut device = GpuDevice::new(&gpu.name)
+        .with_vendor(vendor)
+        .with_utilization(gpu.utilization)
+        .with_vram(gpu.used_memory, gpu.total_memory);
+    device.index = gpu.index;
+    device.temperature = gpu.temperature;
+    device.power_draw = gpu.power_draw;
+    device.power_limit = gpu.power_limit;
+    device.fan_speed = gpu.fan_speed;
+    device
+}
+
+/// Convert an analyzer process into a `GpuPanel` process row.
+#[must_use]
+pub fn panel_process(proc: &GpuProcess) -> crate::widgets::GpuProcess {
+    let row = crate::widgets::GpuProcess::new(&proc.name, proc.pid, proc.used_memory);
+    match proc.gpu_util() {
+        Some(util) => row.with_utilization(util),
+        None => row,
+    }
+}
+
+/// Info lines `GpuPanel` draws between its utilization bar and process list.
+fn info_rows(device: &GpuDevice) -> usize {
+    usize::from(device.temperature.is_some() || device.power_draw.is_some())
+        + usize::from(device.vram_total > 0)
+        + usize::from(device.fan_speed.is_some())
+}
+
+// =============================================================================
+// DRAWING
+// =============================================================================
+
+/// Draw GPU processes grouped by device.
+///
+/// Returns the number of rows used. Returns zero without drawing anything
+/// when `data` is `None` or reports no GPU.
+pub fn draw_gpu_procs(canvas: &mut dyn Canvas, data: Option<&GpuProcsData>, bounds: Rect) -> f32 {
+    let Some(data) = data.filter(|d| d.has_gpu()) else {
+        return 0.0;
+    };
+
+    let bottom = bounds.y + bounds.height;
+    let mut y = bounds.y;
+    for (gpu, procs) in processes_by_device(data) {
+        if y >= bottom {
+            break;
+        }
+        canvas.draw_text(
+            &format!("GPU{} {} │ {} procs", gpu.index, gpu.name, procs.len()),
+            Point::new(bounds.x, y),
+            &TextStyle {
+                color: GPU_COLOR,
+                ..Default::default()
+            },
+        );
+        y += 1.0;
+
+        let device = panel_device(gpu);
+        let fixed_rows = 1 + info_rows(&device);
+        let available = (bottom - y).max(0.0) as usize;
+        let shown = procs.len().min(available.saturating_sub(fixed_rows));
+        let height = (fixed_rows + shown).min(available) as f32;
+
+        let mut panel = GpuPanel::new()
+            .with_device(device)
+            .with_processes(procs.into_iter().map(panel_process).collect())
+            .max_processes(shown);
+        panel.layout(Rect::new(bounds.x, y, bounds.width, height));
+        panel.paint(canvas);
+        y += height;
+    }
+    y - bounds.y
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::{CellBuffer, DirectTerminalCanvas};
+    use crate::ptop::analyzers::GpuProcType;
+
+    fn gpu(index: u32) -> GpuInfo {
+        GpuInfo {
+            index,
+            name: "RTX 4090".to_string(),
+            vendor: GpuVendor::Nvidia,
+            total_memory: 24 * 1024 * 1024 * 1024,
+            used_memory: 6 * 1024 * 1024 * 1024,
+            free_memory: 18 * 1024 * 1024 * 1024,
+            utilization: 65.0,
+            memory_utilization: 25.0,
+            temperature: Some(61.0),
+            power_draw: Some(220.0),
+            power_limit: Some(450.0),
+            fan_speed: None,
+            driver_version: None,
+        }
+    }
+
+    fn process(pid: u32, name: &str, gpu_index: u32, mb: u64, sm_util: u8) -> GpuProcess {
+        GpuProcess {
+            pid,
+            name: name.to_string(),
+            gpu_index,
+            used_memory: mb * 1024 * 1024,
+            proc_type: GpuProcType::Compute,
+            sm_util,
+            ..Default::default()
+        }
+    }
+
+    fn render(data: Option<&GpuProcsData>, width: u16, height: u16) -> (f32, Vec<String>) {
+        let mut buffer = CellBuffer::new(width, height);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        let rows = draw_gpu_procs(
+            &mut canvas,
+            data,
+            Rect::new(0.0, 0.0, f32::from(width), f32::from(height)),
+        );
+        (rows, buffer.rows_as_strings())
+    }
+
+    #[test]
+    fn test_build_gpu_procs_title() {
+        let data = GpuProcsData {
+            gpus: vec![gpu(0)],
+            processes: vec![process(1, "a", 0, 10, 0)],
+            ..Default::default()
+        };
+        assert_eq!(build_gpu_procs_title(&data), "GPU Procs │ 1 procs │ 1 GPU");
+    }
+
+    #[test]
+    fn test_processes_by_device_sorts_by_vram() {
+        let data = GpuProcsData {
+            gpus: vec![gpu(0), gpu(1)],
+            processes: vec![
+                process(10, "small", 0, 100, 0),
+                process(11, "other", 1, 500, 0),
+                process(12, "large", 0, 900, 0),
+                process(13, "orphan", 7, 50, 0),
+            ],
+            ..Default::default()
+        };
+        let groups = processes_by_device(&data);
+        let pids: Vec<Vec<u32>> = groups
+            .iter()
+            .map(|(_, procs)| procs.iter().map(|p| p.pid).collect())
+            .collect();
+        assert_eq!(pids, vec![vec![12, 10], vec![11]]);
+    }
+
+    #[test]
+    fn test_draw_two_processes_on_one_device() {
+        let data = GpuProcsData {
+            gpus: vec![gpu(0)],
+            processes: vec![
+                process(4242, "python3", 0, 4096, 80),
+                process(5151, "blender", 0, 1024, 0),
+            ],
+            ..Default::default()
+        };
+
+        let (rows, lines) = render(Some(&data), 60, 12);
+
+        assert!(rows > 0.0);
+        assert!(lines[0].contains("GPU0 RTX 4090"));
+        let python = lines.iter().find(|l| l.contains("python3")).unwrap();
+        assert!(python.contains("4.0G") && python.contains("80%"));
+        assert!(lines.iter().any(|l| l.contains("blender")));
+    }
+
+    #[test]
+    fn test_hidden_without_gpu() {
+        let empty = GpuProcsData::default();
+        for data in [None, Some(&empty)] {
+            let (rows, lines) = render(data, 40, 4);
+            assert_eq!(rows, 0.0);
+            assert!(lines.iter().all(|l| l.trim().is_empty()));
+        }
+    }
+}
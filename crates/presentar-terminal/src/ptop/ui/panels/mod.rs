@@ -7,8 +7,11 @@
 
 pub mod battery;
 pub mod connections;
+pub mod containers;
 pub mod cpu;
 pub mod disk;
+pub mod geo_connections;
+pub mod gpu_procs;
 pub mod memory;
 pub mod network;
 pub mod process;
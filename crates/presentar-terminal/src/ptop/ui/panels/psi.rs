@@ -3,7 +3,12 @@
 //! Provides PSI panel title building, pressure formatting,
 //! and helper functions for rendering system pressure metrics.
 
-use presentar_core::Color;
+use presentar_core::{Canvas, Color, Point, Rect, TextStyle, Widget};
+
+use crate::perf_trace::{Trend, TrendDetector};
+use crate::ptop::analyzers::{PsiData, PsiResource};
+use crate::ptop::app::RingBuffer;
+use crate::widgets::Sparkline;
 
 // =============================================================================
 // PSI TITLE BUILDING
@@ -281,6 +286,142 @@ impl PsiSeverity {
     }
 }
 
+// =============================================================================
+// PRESSURE HISTORY
+// =============================================================================
+
+/// Samples of pressure history kept per resource.
+pub const PSI_HISTORY_LEN: usize = 60;
+
+/// Recent `some`/`full` avg10 pressure for one resource.
+pub struct PressureHistory {
+    /// "some" pressure samples (percent)
+    pub some: RingBuffer<f64>,
+    /// "full" pressure samples (percent, 0 when the kernel reports none)
+    pub full: RingBuffer<f64>,
+}
+
+impl Default for PressureHistory {
+    fn default() -> Self {
+        Self {
+            some: RingBuffer::new(PSI_HISTORY_LEN),
+            full: RingBuffer::new(PSI_HISTORY_LEN),
+        }
+    }
+}
+
+impl PressureHistory {
+    /// Record the current avg10 values of a resource.
+    pub fn push(&mut self, resource: &PsiResource) {
+        self.some.push(resource.some.avg10);
+        self.full
+            .push(resource.full.as_ref().map_or(0.0, |full| full.avg10));
+    }
+
+    /// Direction of the recorded `some` pressure.
+    ///
+    /// Returns `Trend::Unknown` until enough samples have been collected.
+    #[must_use]
+    pub fn trend(&self) -> Trend {
+        let mut detector = TrendDetector::for_percentage();
+        for &value in self.some.as_slice() {
+            detector.update(value);
+        }
+        detector.trend()
+    }
+}
+
+/// Bounded PSI history for the CPU, memory and I/O resources.
+#[derive(Default)]
+pub struct PsiHistory {
+    /// CPU pressure history
+    pub cpu: PressureHistory,
+    /// Memory pressure history
+    pub memory: PressureHistory,
+    /// I/O pressure history
+    pub io: PressureHistory,
+}
+
+impl PsiHistory {
+    /// Record a PSI sample, or clear all history when PSI is unavailable.
+    pub fn update(&mut self, data: Option<&PsiData>) {
+        match data.filter(|psi| psi.available) {
+            Some(psi) => {
+                self.cpu.push(&psi.cpu);
+                self.memory.push(&psi.memory);
+                self.io.push(&psi.io);
+            }
+            None => self.reset(),
+        }
+    }
+
+    /// Drop all recorded samples.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// History for a single resource.
+    #[must_use]
+    pub fn get(&self, metric: PsiMetricType) -> &PressureHistory {
+        match metric {
+            PsiMetricType::Cpu => &self.cpu,
+            PsiMetricType::Memory => &self.memory,
+            PsiMetricType::Io => &self.io,
+        }
+    }
+}
+
+/// Get arrow symbol for a pressure trend.
+#[must_use]
+pub fn trend_arrow(trend: Trend) -> &'static str {
+    match trend {
+        Trend::Up => "↑",
+        Trend::Down => "↓",
+        Trend::Flat => "→",
+        Trend::Unknown => " ",
+    }
+}
+
+/// Get color for a pressure trend (rising pressure is bad).
+#[must_use]
+pub fn trend_color(trend: Trend) -> Color {
+    match trend {
+        Trend::Up => Color::new(1.0, 0.5, 0.3, 1.0),
+        Trend::Down => Color::new(0.3, 0.9, 0.5, 1.0),
+        Trend::Flat | Trend::Unknown => Color::new(0.5, 0.5, 0.5, 1.0),
+    }
+}
+
+/// Draw a trend arrow followed by a sparkline of `some` pressure.
+///
+/// The sparkline scales to at least 10% so idle systems stay flat.
+pub fn draw_pressure_trend(canvas: &mut dyn Canvas, history: &PressureHistory, bounds: Rect) {
+    if bounds.width < 1.0 || bounds.height < 1.0 {
+        return;
+    }
+    let trend = history.trend();
+    canvas.draw_text(
+        trend_arrow(trend),
+        Point::new(bounds.x, bounds.y),
+        &TextStyle {
+            color: trend_color(trend),
+            ..Default::default()
+        },
+    );
+
+    if bounds.width < 3.0 {
+        return;
+    }
+    let samples = history.some.as_slice();
+    let peak = samples.iter().copied().fold(10.0, f64::max);
+    let latest = history.some.last().copied().unwrap_or(0.0);
+    let mut sparkline = Sparkline::new(samples.to_vec())
+        .with_color(pressure_color(latest))
+        .with_range(0.0, peak);
+    sparkline.layout(Rect::new(bounds.x + 2.0, bounds.y, bounds.width - 2.0, 1.0));
+    sparkline.paint(canvas);
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -571,4 +712,80 @@ mod tests {
         let debug = format!("{:?}", severity);
         assert!(debug.contains("High"));
     }
+
+    // =========================================================================
+    // PsiHistory tests
+    // =========================================================================
+
+    fn psi_sample(cpu: f64, memory_full: f64) -> PsiData {
+        use crate::ptop::analyzers::PsiAverages;
+        PsiData {
+            cpu: PsiResource {
+                some: PsiAverages {
+                    avg10: cpu,
+                    ..Default::default()
+                },
+                full: None,
+            },
+            memory: PsiResource {
+                some: PsiAverages::default(),
+                full: Some(PsiAverages {
+                    avg10: memory_full,
+                    ..Default::default()
+                }),
+            },
+            io: PsiResource::default(),
+            available: true,
+        }
+    }
+
+    #[test]
+    fn test_psi_history_rising_trend() {
+        let mut history = PsiHistory::default();
+        assert_eq!(history.get(PsiMetricType::Cpu).trend(), Trend::Unknown);
+
+        for cpu in [2.0, 6.0, 11.0, 17.0, 24.0, 32.0] {
+            history.update(Some(&psi_sample(cpu, 1.5)));
+        }
+
+        assert_eq!(history.get(PsiMetricType::Cpu).trend(), Trend::Up);
+        assert_eq!(trend_arrow(history.cpu.trend()), "↑");
+        assert_eq!(history.io.trend(), Trend::Flat);
+        assert_eq!(history.memory.full.last(), Some(&1.5));
+        assert_eq!(history.cpu.full.last(), Some(&0.0));
+    }
+
+    #[test]
+    fn test_psi_history_bounded_and_reset() {
+        let mut history = PsiHistory::default();
+        for i in 0..(PSI_HISTORY_LEN + 10) {
+            history.update(Some(&psi_sample(i as f64, 0.0)));
+        }
+        assert_eq!(history.cpu.some.as_slice().len(), PSI_HISTORY_LEN);
+
+        let unavailable = PsiData::default();
+        history.update(Some(&unavailable));
+        assert!(history.cpu.some.as_slice().is_empty());
+
+        history.update(Some(&psi_sample(5.0, 0.0)));
+        history.update(None);
+        assert!(history.memory.full.as_slice().is_empty());
+    }
+
+    #[test]
+    fn test_draw_pressure_trend() {
+        use crate::direct::{CellBuffer, DirectTerminalCanvas};
+
+        let mut history = PressureHistory::default();
+        for value in [40.0, 30.0, 20.0, 10.0] {
+            history.push(&psi_sample(value, 0.0).cpu);
+        }
+        let mut buffer = CellBuffer::new(12, 1);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        draw_pressure_trend(&mut canvas, &history, Rect::new(0.0, 0.0, 12.0, 1.0));
+
+        let line = &buffer.rows_as_strings()[0];
+        assert!(line.starts_with("↓ "));
+        assert!(line.trim_end().chars().count() > 2);
+    }
 }
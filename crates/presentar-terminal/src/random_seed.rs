@@ -108,6 +108,58 @@ impl SeededRng {
     pub fn next_f64(&mut self) -> f64 {
         (self.next_u64() as f64) / (u64::MAX as f64)
     }
+
+    /// Generate a normally distributed f64 (Box–Muller transform)
+    ///
+    /// Consumes two uniforms per call; the second Box–Muller output is
+    /// discarded so the sequence depends only on the seed and call count.
+    pub fn next_gaussian(&mut self, mean: f64, std: f64) -> f64 {
+        // Map to (0, 1] so ln() stays finite
+        let u1 = 1.0 - self.next_f64().min(1.0 - f64::EPSILON);
+        let u2 = self.next_f64();
+        let z = (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos();
+        mean + std * z
+    }
+
+    /// Generate an exponentially distributed f64 with rate `lambda` (inverse CDF)
+    ///
+    /// The mean of the distribution is `1 / lambda`; `lambda` must be positive.
+    pub fn next_exponential(&mut self, lambda: f64) -> f64 {
+        debug_assert!(lambda > 0.0, "next_exponential requires lambda > 0");
+        let u = 1.0 - self.next_f64().min(1.0 - f64::EPSILON);
+        -u.ln() / lambda
+    }
+
+    /// Generate random index in [0, n)
+    ///
+    /// Uses a widening multiply instead of modulo; `n` must be non-zero.
+    pub fn next_index(&mut self, n: usize) -> usize {
+        debug_assert!(n > 0, "next_index requires n > 0");
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+
+    /// Shuffle `items` in place (Fisher–Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.next_index(i + 1);
+            items.swap(i, j);
+        }
+    }
+
+    /// Choose `k` items from `items` without replacement (reservoir sampling)
+    ///
+    /// Returns all items when `k >= items.len()`. The result keeps reservoir
+    /// order, which is not the input order; shuffle it if that matters.
+    pub fn sample<T: Clone>(&mut self, items: &[T], k: usize) -> Vec<T> {
+        let mut reservoir: Vec<T> = items.iter().take(k).cloned().collect();
+        for (i, item) in items.iter().enumerate().skip(k) {
+            let j = self.next_index(i + 1);
+            if j < k {
+                reservoir[j] = item.clone();
+            }
+        }
+        reservoir
+    }
 }
 
 #[cfg(test)]
@@ -240,6 +292,90 @@ mod tests {
         assert!(ratio > 0.9 && ratio < 1.1, "Distribution skewed: {}", ratio);
     }
 
+    #[test]
+    fn test_shuffle_is_reproducible() {
+        let run = || {
+            let mut rng = SeededRng::new(DEFAULT_SEED);
+            let mut items: Vec<u32> = (0..10).collect();
+            rng.shuffle(&mut items);
+            items
+        };
+        let first = run();
+        assert_eq!(first, run());
+        // Pinned so the permutation is stable across runs and platforms
+        assert_eq!(first, vec![9, 3, 8, 7, 2, 6, 1, 4, 5, 0]);
+
+        let mut sorted = first;
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_shuffle_empty_and_single() {
+        let mut rng = SeededRng::new(DEFAULT_SEED);
+        let mut empty: [u8; 0] = [];
+        rng.shuffle(&mut empty);
+        let mut one = [7];
+        rng.shuffle(&mut one);
+        assert_eq!(one, [7]);
+    }
+
+    #[test]
+    fn test_sample_reproducible_and_distinct() {
+        let items: Vec<u32> = (0..100).collect();
+        let a = SeededRng::new(DEFAULT_SEED).sample(&items, 5);
+        let b = SeededRng::new(DEFAULT_SEED).sample(&items, 5);
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 5);
+
+        let mut unique = a.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), 5);
+        assert!(a.iter().all(|v| *v < 100));
+
+        let all = SeededRng::new(1).sample(&items[..3], 10);
+        assert_eq!(all, vec![0, 1, 2]);
+    }
+
+    fn mean_std(samples: &[f64]) -> (f64, f64) {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        (mean, var.sqrt())
+    }
+
+    #[test]
+    fn test_gaussian_mean_and_std() {
+        let mut rng = SeededRng::new(DEFAULT_SEED);
+        let samples: Vec<f64> = (0..10_000).map(|_| rng.next_gaussian(5.0, 2.0)).collect();
+        let (mean, std) = mean_std(&samples);
+        assert!((mean - 5.0).abs() < 0.1, "mean = {mean}");
+        assert!((std - 2.0).abs() < 0.1, "std = {std}");
+        assert!(samples.iter().all(|x| x.is_finite()));
+
+        let mut again = SeededRng::new(DEFAULT_SEED);
+        assert_eq!(again.next_gaussian(5.0, 2.0), samples[0]);
+    }
+
+    #[test]
+    fn test_exponential_mean() {
+        let mut rng = SeededRng::new(DEFAULT_SEED);
+        let samples: Vec<f64> = (0..10_000).map(|_| rng.next_exponential(4.0)).collect();
+        let (mean, std) = mean_std(&samples);
+        assert!((mean - 0.25).abs() < 0.02, "mean = {mean}");
+        assert!((std - 0.25).abs() < 0.03, "std = {std}");
+        assert!(samples.iter().all(|x| x.is_finite() && *x >= 0.0));
+    }
+
+    #[test]
+    fn test_next_index_range() {
+        let mut rng = SeededRng::new(DEFAULT_SEED);
+        for _ in 0..1000 {
+            assert!(rng.next_index(7) < 7);
+        }
+    }
+
     #[test]
     fn test_set_get_seed() {
         set_global_seed(999);
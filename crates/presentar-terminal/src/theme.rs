@@ -2,14 +2,42 @@
 //!
 //! Provides color themes and smooth gradient interpolation for terminal UIs.
 //! Based on trueno-viz theme system for visual consistency.
-
+//!
+//! With the `theme-files` feature, themes can also be loaded from TOML or
+//! YAML files:
+//!
+//! ```toml
+//! name = "my-theme"
+//! background = "#1d2021"
+//! foreground = "#ebdbb2"
+//! cpu = ["#b8bb26", "#fabd2f", "#fb4934"]
+//! ```
+
+use crate::error::TuiError;
 use presentar_core::Color;
+#[cfg(feature = "theme-files")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::str::FromStr;
+
+/// Color space used to blend between gradient stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// CIELAB interpolation (default).
+    #[default]
+    Lab,
+    /// OKLCH interpolation; keeps lightness even across hue changes.
+    Oklch,
+}
 
 /// A color gradient with 2-3 stops for smooth interpolation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Gradient {
     /// Gradient color stops (RGB hex strings like "#FF0000").
     stops: Vec<Color>,
+    /// Explicit stop positions in [0, 1], sorted; `None` spaces stops evenly.
+    positions: Option<Vec<f64>>,
+    /// Color space used between stops.
+    interpolation: Interpolation,
 }
 
 impl Gradient {
@@ -18,6 +46,8 @@ impl Gradient {
     pub fn two(start: Color, end: Color) -> Self {
         Self {
             stops: vec![start, end],
+            positions: None,
+            interpolation: Interpolation::default(),
         }
     }
 
@@ -26,6 +56,8 @@ impl Gradient {
     pub fn three(start: Color, mid: Color, end: Color) -> Self {
         Self {
             stops: vec![start, mid, end],
+            positions: None,
+            interpolation: Interpolation::default(),
         }
     }
 
@@ -34,9 +66,68 @@ impl Gradient {
     pub fn from_hex(stops: &[&str]) -> Self {
         Self {
             stops: stops.iter().map(|s| parse_hex(s)).collect(),
+            positions: None,
+            interpolation: Interpolation::default(),
+        }
+    }
+
+    /// Create a gradient with explicit stop positions in [0, 1].
+    ///
+    /// Positions are clamped to [0, 1] and sorted; when two stops share a
+    /// position the later one wins. Stops with non-finite positions are
+    /// dropped. Sampling outside the first/last position clamps to the
+    /// endpoint colors.
+    #[must_use]
+    pub fn with_stops(stops: Vec<(f32, Color)>) -> Self {
+        let mut stops: Vec<(f64, Color)> = stops
+            .into_iter()
+            .filter(|(pos, _)| pos.is_finite())
+            .map(|(pos, color)| (f64::from(pos.clamp(0.0, 1.0)), color))
+            .collect();
+        // Stable sort keeps input order among equal positions
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut deduped: Vec<(f64, Color)> = Vec::with_capacity(stops.len());
+        for (pos, color) in stops {
+            match deduped.last_mut() {
+                Some(last) if last.0 == pos => last.1 = color,
+                _ => deduped.push((pos, color)),
+            }
+        }
+
+        let (positions, stops) = deduped.into_iter().unzip();
+        Self {
+            stops,
+            positions: Some(positions),
+            interpolation: Interpolation::default(),
         }
     }
 
+    /// Stop positions, or `None` when stops are evenly spaced.
+    #[must_use]
+    pub fn positions(&self) -> Option<&[f64]> {
+        self.positions.as_deref()
+    }
+
+    /// Set the color space used to blend between stops.
+    #[must_use]
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Color stops in order.
+    #[must_use]
+    pub fn stops(&self) -> &[Color] {
+        &self.stops
+    }
+
+    /// Color space used to blend between stops.
+    #[must_use]
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
     /// Sample the gradient at position t (0.0 - 1.0).
     #[must_use]
     pub fn sample(&self, t: f64) -> Color {
@@ -56,15 +147,30 @@ impl Gradient {
         }
 
         // Find the segment
-        let segment_count = self.stops.len() - 1;
-        let segment_size = 1.0 / segment_count as f64;
-        let segment = ((t / segment_size) as usize).min(segment_count - 1);
-        let local_t = (t - segment as f64 * segment_size) / segment_size;
+        let (segment, local_t) = if let Some(positions) = &self.positions {
+            let next = positions.partition_point(|&p| p <= t);
+            if next == 0 {
+                return self.stops[0];
+            }
+            if next == positions.len() || positions[next - 1] == t {
+                return self.stops[next - 1];
+            }
+            let (p0, p1) = (positions[next - 1], positions[next]);
+            (next - 1, (t - p0) / (p1 - p0))
+        } else {
+            let segment_count = self.stops.len() - 1;
+            let segment_size = 1.0 / segment_count as f64;
+            let segment = ((t / segment_size) as usize).min(segment_count - 1);
+            (segment, (t - segment as f64 * segment_size) / segment_size)
+        };
 
         let start = self.stops[segment];
         let end = self.stops[segment + 1];
 
-        interpolate_lab(start, end, local_t)
+        match self.interpolation {
+            Interpolation::Lab => interpolate_lab(start, end, local_t),
+            Interpolation::Oklch => start.lerp_oklch(&end, local_t as f32),
+        }
     }
 
     /// Get color for a percentage value (0-100).
@@ -82,7 +188,7 @@ impl Default for Gradient {
 }
 
 /// Theme configuration for terminal UI.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Theme {
     /// Theme name.
     pub name: String,
@@ -112,6 +218,15 @@ impl Default for Theme {
     }
 }
 
+impl FromStr for Theme {
+    type Err = TuiError;
+
+    /// Parse a preset name, as accepted by [`Theme::from_preset`].
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_preset(name).ok_or_else(|| TuiError::UnknownTheme(name.to_string()))
+    }
+}
+
 impl Theme {
     /// Create a new default theme.
     #[must_use]
@@ -187,6 +302,142 @@ impl Theme {
         }
     }
 
+    /// Solarized Dark theme (low contrast, warm accents).
+    #[must_use]
+    pub fn solarized_dark() -> Self {
+        Self {
+            name: "solarized_dark".to_string(),
+            background: parse_hex("#002b36"),
+            foreground: parse_hex("#839496"),
+            border: parse_hex("#586e75"),
+            dim: parse_hex("#073642"),
+            cpu: Gradient::from_hex(&["#859900", "#b58900", "#dc322f"]),
+            memory: Gradient::from_hex(&["#2aa198", "#b58900", "#dc322f"]),
+            gpu: Gradient::from_hex(&["#6c71c4", "#268bd2", "#dc322f"]),
+            temperature: Gradient::from_hex(&["#268bd2", "#cb4b16", "#dc322f"]),
+            network: Gradient::from_hex(&["#2aa198", "#859900"]),
+        }
+    }
+
+    /// Gruvbox theme (dark, retro).
+    #[must_use]
+    pub fn gruvbox() -> Self {
+        Self {
+            name: "gruvbox".to_string(),
+            background: parse_hex("#282828"),
+            foreground: parse_hex("#ebdbb2"),
+            border: parse_hex("#504945"),
+            dim: parse_hex("#665c54"),
+            cpu: Gradient::from_hex(&["#b8bb26", "#fabd2f", "#fb4934"]),
+            memory: Gradient::from_hex(&["#83a598", "#fabd2f", "#fb4934"]),
+            gpu: Gradient::from_hex(&["#d3869b", "#8ec07c", "#fb4934"]),
+            temperature: Gradient::from_hex(&["#83a598", "#fe8019", "#fb4934"]),
+            network: Gradient::from_hex(&["#83a598", "#b8bb26"]),
+        }
+    }
+
+    /// Names accepted by [`Theme::from_preset`], each equal to the
+    /// [`Theme::name`] of the preset it selects.
+    #[must_use]
+    pub fn preset_names() -> &'static [&'static str] {
+        &[
+            "tokyo_night",
+            "dracula",
+            "nord",
+            "monokai",
+            "solarized_dark",
+            "gruvbox",
+        ]
+    }
+
+    /// Look up a built-in theme by name (e.g. `"nord"`, `"solarized_dark"`).
+    ///
+    /// Matching is case-insensitive and treats `-` like `_`, so
+    /// `"solarized-dark"` also works. Returns `None` for unknown names.
+    #[must_use]
+    pub fn from_preset(name: &str) -> Option<Self> {
+        let theme = match name.to_ascii_lowercase().replace('-', "_").as_str() {
+            "tokyo_night" => Self::tokyo_night(),
+            "dracula" => Self::dracula(),
+            "nord" => Self::nord(),
+            "monokai" => Self::monokai(),
+            "solarized_dark" => Self::solarized_dark(),
+            "gruvbox" => Self::gruvbox(),
+            _ => return None,
+        };
+        debug_assert!(
+            theme.meets_wcag_aa(),
+            "preset {} fails WCAG AA text contrast",
+            theme.name
+        );
+        Some(theme)
+    }
+
+    /// Color for positive changes (first stop of the memory gradient).
+    #[must_use]
+    pub fn positive(&self) -> Color {
+        self.memory
+            .stops()
+            .first()
+            .copied()
+            .unwrap_or(self.foreground)
+    }
+
+    /// Color for negative changes (last stop of the memory gradient).
+    #[must_use]
+    pub fn negative(&self) -> Color {
+        self.memory
+            .stops()
+            .last()
+            .copied()
+            .unwrap_or(self.foreground)
+    }
+
+    /// Whether foreground text on the background meets WCAG AA (4.5:1).
+    #[must_use]
+    pub fn meets_wcag_aa(&self) -> bool {
+        self.foreground.contrast_ratio(&self.background) >= 4.5
+    }
+
+    /// Parse a theme from TOML.
+    ///
+    /// Accepts the top-level keys `name`, the colors `background`,
+    /// `foreground`, `border` and `dim` as hex strings, and the gradients
+    /// `cpu`, `memory`, `gpu`, `temperature` and `network` as arrays of hex
+    /// stops. Keys that are absent keep their value from [`Theme::default`].
+    /// Unknown keys and malformed values are errors.
+    #[cfg(feature = "theme-files")]
+    pub fn from_toml_str(src: &str) -> Result<Self, TuiError> {
+        let file: ThemeFile = toml::from_str(src).map_err(|e| TuiError::ThemeParse {
+            line: e.span().map_or(1, |span| line_of_offset(src, span.start)),
+            message: e.message().to_string(),
+        })?;
+        Ok(file.apply(Self::default()))
+    }
+
+    /// Parse a theme from YAML.
+    ///
+    /// Takes the same keys as [`Theme::from_toml_str`] as a top-level
+    /// mapping. Gradients may be flow or block sequences.
+    #[cfg(feature = "theme-files")]
+    pub fn from_yaml_str(src: &str) -> Result<Self, TuiError> {
+        let file: ThemeFile = serde_yaml_ng::from_str(src).map_err(|e| TuiError::ThemeParse {
+            line: e.location().map_or(1, |loc| loc.line()),
+            message: e.to_string(),
+        })?;
+        Ok(file.apply(Self::default()))
+    }
+
+    /// Serialize to the TOML format read by [`Theme::from_toml_str`].
+    ///
+    /// Gradient interpolation and stop positions are not stored; reloaded
+    /// gradients use [`Interpolation::Lab`] with evenly spaced stops.
+    #[cfg(feature = "theme-files")]
+    #[must_use]
+    pub fn to_toml_str(&self) -> String {
+        toml::to_string(&ThemeFile::from(self)).expect("theme fields serialize to TOML")
+    }
+
     /// Get color for CPU usage percentage.
     #[must_use]
     pub fn cpu_color(&self, percent: f64) -> Color {
@@ -213,6 +464,156 @@ impl Theme {
     }
 }
 
+/// On-disk theme representation shared by the TOML and YAML loaders.
+#[cfg(feature = "theme-files")]
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    background: Option<HexColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    foreground: Option<HexColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    border: Option<HexColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dim: Option<HexColor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cpu: Option<GradientStops>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    memory: Option<GradientStops>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gpu: Option<GradientStops>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    temperature: Option<GradientStops>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    network: Option<GradientStops>,
+}
+
+#[cfg(feature = "theme-files")]
+impl ThemeFile {
+    /// Overlay the keys present in the file onto `theme`.
+    fn apply(self, mut theme: Theme) -> Theme {
+        if let Some(name) = self.name {
+            theme.name = name;
+        }
+        for (value, slot) in [
+            (self.background, &mut theme.background),
+            (self.foreground, &mut theme.foreground),
+            (self.border, &mut theme.border),
+            (self.dim, &mut theme.dim),
+        ] {
+            if let Some(HexColor(color)) = value {
+                *slot = color;
+            }
+        }
+        for (value, slot) in [
+            (self.cpu, &mut theme.cpu),
+            (self.memory, &mut theme.memory),
+            (self.gpu, &mut theme.gpu),
+            (self.temperature, &mut theme.temperature),
+            (self.network, &mut theme.network),
+        ] {
+            if let Some(GradientStops(stops)) = value {
+                *slot = Gradient {
+                    stops,
+                    positions: None,
+                    interpolation: Interpolation::default(),
+                };
+            }
+        }
+        theme
+    }
+}
+
+#[cfg(feature = "theme-files")]
+impl From<&Theme> for ThemeFile {
+    fn from(theme: &Theme) -> Self {
+        let stops = |g: &Gradient| Some(GradientStops(g.stops.clone()));
+        Self {
+            name: Some(theme.name.clone()),
+            background: Some(HexColor(theme.background)),
+            foreground: Some(HexColor(theme.foreground)),
+            border: Some(HexColor(theme.border)),
+            dim: Some(HexColor(theme.dim)),
+            cpu: stops(&theme.cpu),
+            memory: stops(&theme.memory),
+            gpu: stops(&theme.gpu),
+            temperature: stops(&theme.temperature),
+            network: stops(&theme.network),
+        }
+    }
+}
+
+/// A color stored as a `#rrggbb` string.
+#[cfg(feature = "theme-files")]
+#[derive(Debug)]
+struct HexColor(Color);
+
+#[cfg(feature = "theme-files")]
+impl Serialize for HexColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_hex())
+    }
+}
+
+#[cfg(feature = "theme-files")]
+impl<'de> Deserialize<'de> for HexColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        Color::from_hex(&hex)
+            .map(Self)
+            .map_err(|e| de::Error::custom(format!("invalid color `{hex}`: {e}")))
+    }
+}
+
+/// Non-empty list of gradient stops.
+#[cfg(feature = "theme-files")]
+#[derive(Debug)]
+struct GradientStops(Vec<Color>);
+
+#[cfg(feature = "theme-files")]
+impl Serialize for GradientStops {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|c| c.to_hex()))
+    }
+}
+
+#[cfg(feature = "theme-files")]
+impl<'de> Deserialize<'de> for GradientStops {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StopsVisitor;
+
+        impl<'de> de::Visitor<'de> for StopsVisitor {
+            type Value = GradientStops;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a list of colors")
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut stops = Vec::new();
+                while let Some(HexColor(color)) = seq.next_element()? {
+                    stops.push(color);
+                }
+                if stops.is_empty() {
+                    return Err(de::Error::custom("gradient needs at least one color"));
+                }
+                Ok(GradientStops(stops))
+            }
+        }
+
+        deserializer.deserialize_seq(StopsVisitor)
+    }
+}
+
+/// 1-based line number of a byte offset in `src`.
+#[cfg(feature = "theme-files")]
+fn line_of_offset(src: &str, offset: usize) -> usize {
+    src.get(..offset).unwrap_or(src).matches('\n').count() + 1
+}
+
 /// Parse hex color string to Color.
 fn parse_hex(hex: &str) -> Color {
     let hex = hex.trim_start_matches('#');
@@ -460,7 +861,11 @@ mod tests {
 
     #[test]
     fn test_gradient_empty() {
-        let g = Gradient { stops: vec![] };
+        let g = Gradient {
+            stops: vec![],
+            positions: None,
+            interpolation: Interpolation::Lab,
+        };
         let c = g.sample(0.5);
         assert_eq!(c, Color::WHITE);
     }
@@ -469,6 +874,8 @@ mod tests {
     fn test_gradient_single() {
         let g = Gradient {
             stops: vec![Color::RED],
+            positions: None,
+            interpolation: Interpolation::Lab,
         };
         let c = g.sample(0.5);
         assert!((c.r - 1.0).abs() < 0.01); // 0-1 range
@@ -591,6 +998,8 @@ mod tests {
         // Test gradient with more stops
         let g = Gradient {
             stops: vec![Color::RED, Color::GREEN, Color::BLUE, Color::WHITE],
+            positions: None,
+            interpolation: Interpolation::Lab,
         };
         let _ = g.sample(0.0);
         let _ = g.sample(0.33);
@@ -670,6 +1079,269 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_gradient_default_interpolation_is_lab() {
+        assert_eq!(Gradient::default().interpolation(), Interpolation::Lab);
+    }
+
+    #[test]
+    fn test_gradient_oklch_endpoints() {
+        let g = Gradient::two(Color::RED, Color::BLUE).with_interpolation(Interpolation::Oklch);
+        assert_eq!(g.interpolation(), Interpolation::Oklch);
+        assert!((g.sample(0.0).r - 1.0).abs() < 0.01);
+        assert!((g.sample(1.0).b - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_gradient_oklch_midpoint_brighter_than_rgb() {
+        let g = Gradient::two(Color::RED, Color::GREEN).with_interpolation(Interpolation::Oklch);
+        let oklch_mid = g.sample(0.5);
+        let rgb_mid = Color::RED.lerp(&Color::GREEN, 0.5);
+        assert!(oklch_mid.relative_luminance() > rgb_mid.relative_luminance());
+    }
+
+    #[test]
+    fn test_theme_from_preset_all_names() {
+        for name in Theme::preset_names() {
+            let theme = Theme::from_preset(name).expect("preset exists");
+            assert_eq!(theme.name, *name);
+        }
+    }
+
+    #[test]
+    fn test_theme_presets_meet_wcag_aa() {
+        for name in Theme::preset_names() {
+            let t = Theme::from_preset(name).expect("preset exists");
+            let ratio = t.foreground.contrast_ratio(&t.background);
+            assert!(ratio >= 4.5, "{name} contrast {ratio:.2} < 4.5");
+            assert!(t.meets_wcag_aa());
+        }
+    }
+
+    #[test]
+    fn test_theme_from_preset_normalizes_name() {
+        assert_eq!(Theme::from_preset("NORD").expect("nord").name, "nord");
+        assert_eq!(
+            Theme::from_preset("solarized-dark")
+                .expect("solarized")
+                .name,
+            "solarized_dark"
+        );
+        assert_eq!(
+            Theme::from_preset("Tokyo-Night").expect("tokyo night").name,
+            "tokyo_night"
+        );
+    }
+
+    #[test]
+    fn test_theme_from_preset_unknown() {
+        assert!(Theme::from_preset("not-a-theme").is_none());
+        assert!(Theme::from_preset("").is_none());
+    }
+
+    #[test]
+    fn test_theme_from_str() {
+        let theme: Theme = "Solarized-Dark".parse().expect("preset");
+        assert_eq!(theme.name, "solarized_dark");
+
+        let err = "not-a-theme".parse::<Theme>().unwrap_err();
+        assert!(matches!(err, TuiError::UnknownTheme(ref n) if n == "not-a-theme"));
+        assert!(err.to_string().contains("nord"), "{err}");
+    }
+
+    #[test]
+    fn test_theme_positive_negative_colors() {
+        let t = Theme::tokyo_night();
+        assert_eq!(t.positive(), parse_hex("#9ece6a"));
+        assert_eq!(t.negative(), parse_hex("#f7768e"));
+        assert_ne!(t.positive(), t.negative());
+    }
+
+    #[test]
+    fn test_theme_meets_wcag_aa_fails_low_contrast() {
+        let mut t = Theme::nord();
+        t.foreground = t.background;
+        assert!(!t.meets_wcag_aa());
+    }
+
+    #[test]
+    fn test_gradient_with_stops_sample_between() {
+        let g = Gradient::with_stops(vec![
+            (0.0, Color::GREEN),
+            (0.7, Color::YELLOW),
+            (0.9, Color::RED),
+        ]);
+        // Halfway between 0.7 and 0.9 blends yellow and red
+        let c = g.sample(0.8);
+        assert!(c.r > 0.9);
+        assert!(c.g > 0.1 && c.g < 0.95);
+        // Below 0.7 still sits in the green-yellow band
+        assert!(g.sample(0.35).g > 0.9);
+    }
+
+    #[test]
+    fn test_gradient_with_stops_exact_hit() {
+        let g = Gradient::with_stops(vec![
+            (0.0, Color::GREEN),
+            (0.7, Color::YELLOW),
+            (0.9, Color::RED),
+        ]);
+        assert_eq!(g.sample(0.0), Color::GREEN);
+        assert_eq!(g.sample(f64::from(0.7f32)), Color::YELLOW);
+        assert_eq!(g.sample(f64::from(0.9f32)), Color::RED);
+    }
+
+    #[test]
+    fn test_gradient_with_stops_clamps_outside_range() {
+        let g = Gradient::with_stops(vec![(0.2, Color::BLUE), (0.8, Color::RED)]);
+        assert_eq!(g.sample(0.0), Color::BLUE);
+        assert_eq!(g.sample(0.1), Color::BLUE);
+        assert_eq!(g.sample(0.95), Color::RED);
+        assert_eq!(g.sample(1.0), Color::RED);
+    }
+
+    #[test]
+    fn test_gradient_with_stops_single_stop() {
+        let g = Gradient::with_stops(vec![(0.5, Color::RED)]);
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            assert_eq!(g.sample(t), Color::RED);
+        }
+    }
+
+    #[test]
+    fn test_gradient_with_stops_sorts_and_dedups() {
+        let g = Gradient::with_stops(vec![
+            (1.0, Color::RED),
+            (0.0, Color::GREEN),
+            (0.5, Color::BLUE),
+            (0.5, Color::YELLOW),
+        ]);
+        assert_eq!(g.positions(), Some(&[0.0, 0.5, 1.0][..]));
+        assert_eq!(g.stops(), &[Color::GREEN, Color::YELLOW, Color::RED]);
+    }
+
+    #[test]
+    fn test_gradient_with_stops_drops_non_finite() {
+        let g = Gradient::with_stops(vec![(f32::NAN, Color::BLUE), (0.0, Color::RED)]);
+        assert_eq!(g.stops(), &[Color::RED]);
+        assert!(Gradient::default().positions().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn test_theme_toml_round_trip() {
+        for name in Theme::preset_names() {
+            let theme = Theme::from_preset(name).expect("preset exists");
+            let parsed = Theme::from_toml_str(&theme.to_toml_str()).expect("round trip");
+            assert_eq!(parsed, theme);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn test_theme_from_toml_str() {
+        let src = r##"
+# My theme
+name = "custom"
+background = "#1d2021" # dark
+foreground = '#ebdbb2'
+cpu = ["#b8bb26", "#fb4934"]
+"##;
+        let t = Theme::from_toml_str(src).expect("valid toml");
+        assert_eq!(t.name, "custom");
+        assert_eq!(t.background, parse_hex("#1d2021"));
+        assert_eq!(t.foreground, parse_hex("#ebdbb2"));
+        assert_eq!(t.cpu.stops(), &[parse_hex("#b8bb26"), parse_hex("#fb4934")]);
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn test_theme_from_toml_missing_keys_use_default() {
+        let t = Theme::from_toml_str("name = \"partial\"\n").expect("valid toml");
+        let default = Theme::default();
+        assert_eq!(t.name, "partial");
+        assert_eq!(t.background, default.background);
+        assert_eq!(t.network, default.network);
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn test_theme_from_toml_unknown_key_reports_line() {
+        let err = Theme::from_toml_str("name = \"x\"\n\ncolour = \"#ffffff\"\n")
+            .expect_err("unknown key");
+        assert!(matches!(err, TuiError::ThemeParse { line: 3, .. }));
+        assert!(err.to_string().contains("colour"));
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn test_theme_from_toml_invalid_values() {
+        let err = Theme::from_toml_str("background = \"#zzzzzz\"").expect_err("bad hex");
+        assert!(matches!(err, TuiError::ThemeParse { line: 1, .. }));
+
+        let err = Theme::from_toml_str("cpu = \"#ffffff\"").expect_err("not a list");
+        assert!(err.to_string().contains("list of colors"));
+
+        let err = Theme::from_toml_str("cpu = []").expect_err("empty gradient");
+        assert!(err.to_string().contains("at least one color"));
+
+        let err = Theme::from_toml_str("just text").expect_err("no equals sign");
+        assert!(matches!(err, TuiError::ThemeParse { line: 1, .. }));
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn test_theme_from_yaml_str() {
+        let src = r##"
+name: yaml-theme
+background: "#282828"
+cpu: ["#b8bb26", "#fb4934"]
+memory:
+  - "#83a598"
+  - '#fabd2f'
+  - "#fb4934"
+network:
+  - "#83a598"
+"##;
+        let t = Theme::from_yaml_str(src).expect("valid yaml");
+        assert_eq!(t.name, "yaml-theme");
+        assert_eq!(t.background, parse_hex("#282828"));
+        assert_eq!(t.cpu.stops().len(), 2);
+        assert_eq!(
+            t.memory.stops(),
+            &[
+                parse_hex("#83a598"),
+                parse_hex("#fabd2f"),
+                parse_hex("#fb4934")
+            ]
+        );
+        assert_eq!(t.network.stops(), &[parse_hex("#83a598")]);
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn test_theme_from_yaml_errors() {
+        let err = Theme::from_yaml_str("name: ok\nbogus: 1\n").expect_err("unknown key");
+        assert!(matches!(err, TuiError::ThemeParse { line: 2, .. }));
+
+        let err = Theme::from_yaml_str("- \"#ffffff\"").expect_err("orphan item");
+        assert!(matches!(err, TuiError::ThemeParse { line: 1, .. }));
+
+        let err = Theme::from_yaml_str("cpu: []\n").expect_err("empty gradient");
+        assert!(err.to_string().contains("at least one color"));
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn test_theme_toml_escapes_name() {
+        let theme = Theme {
+            name: "say \"hi\" \\ bye".to_string(),
+            ..Theme::default()
+        };
+        let parsed = Theme::from_toml_str(&theme.to_toml_str()).expect("escaped name");
+        assert_eq!(parsed.name, theme.name);
+    }
+
     #[test]
     fn test_parse_hex_short() {
         // Too short should return white
@@ -25,9 +25,16 @@
 //! ```
 
 use crate::direct::{CellBuffer, Modifiers};
-use presentar_core::{Canvas, Color, FontWeight, Point, Rect, TextStyle, Transform2D, Widget};
+use presentar_core::{
+    Canvas, Color, FontWeight, Point, Rect, Size, TextStyle, Transform2D, Widget,
+};
+#[cfg(feature = "export")]
+use serde::Serialize;
 use std::collections::HashMap;
+use std::io::{self, Write};
 use std::time::{Duration, Instant};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 // ============================================================================
 // HeadlessCanvas
@@ -119,6 +126,50 @@ impl HeadlessCanvas {
         output
     }
 
+    /// Render the buffer as plain text: glyphs only, rows separated by `\n`.
+    ///
+    /// Same as [`Self::dump`] without the trailing newline. Wide-character
+    /// continuation cells are empty, so each row's display width equals the
+    /// canvas width.
+    #[must_use]
+    pub fn to_plain_string(&self) -> String {
+        let mut output = self.dump();
+        output.pop();
+        output
+    }
+
+    /// Render the buffer as text with SGR escapes for colors and modifiers.
+    ///
+    /// Colors are emitted as 24-bit escapes; transparent backgrounds use the
+    /// terminal default. Every row ends with a reset so lines can be printed
+    /// or diffed independently.
+    #[must_use]
+    pub fn to_ansi_string(&self) -> String {
+        let mut output = String::new();
+        for y in 0..self.buffer.height() {
+            if y > 0 {
+                output.push('\n');
+            }
+            let mut current: Option<(Color, Color, Modifiers)> = None;
+            for x in 0..self.buffer.width() {
+                let Some(cell) = self.buffer.get(x, y) else {
+                    continue;
+                };
+                if cell.is_continuation() {
+                    continue;
+                }
+                let style = (cell.fg, cell.bg, cell.modifiers);
+                if current != Some(style) {
+                    push_sgr(&mut output, cell.fg, cell.bg, cell.modifiers);
+                    current = Some(style);
+                }
+                output.push_str(&cell.symbol);
+            }
+            output.push_str("\x1b[0m");
+        }
+        output
+    }
+
     /// Get collected metrics.
     #[must_use]
     pub fn metrics(&self) -> &RenderMetrics {
@@ -222,19 +273,23 @@ impl Canvas for HeadlessCanvas {
             Modifiers::NONE
         };
 
+        // Advance by display width so wide glyphs keep their continuation cell.
         let mut cx = x;
-        for ch in text.chars() {
+        for grapheme in text.graphemes(true) {
             if cx >= self.buffer.width() {
                 break;
             }
-            let mut buf = [0u8; 4];
-            let s = ch.encode_utf8(&mut buf);
             self.buffer
-                .update(cx, y, s, style.color, Color::TRANSPARENT, modifiers);
-            cx = cx.saturating_add(1);
+                .update(cx, y, grapheme, style.color, Color::TRANSPARENT, modifiers);
+            cx = cx.saturating_add(UnicodeWidthStr::width(grapheme).clamp(1, 2) as u16);
         }
     }
 
+    /// One row high; width is the text's display width in cells, matching `draw_text`.
+    fn measure_text(&self, text: &str, _style: &TextStyle) -> Size {
+        Size::new(UnicodeWidthStr::width(text) as f32, 1.0)
+    }
+
     fn draw_line(&mut self, from: Point, to: Point, color: Color, _width: f32) {
         // Simple Bresenham line for terminal
         let x0 = from.x as i32;
@@ -370,6 +425,48 @@ impl Canvas for HeadlessCanvas {
     }
 }
 
+/// Append a full SGR sequence (reset, modifiers, colors) for one style run.
+fn push_sgr(output: &mut String, fg: Color, bg: Color, modifiers: Modifiers) {
+    use std::fmt::Write as _;
+
+    const CODES: [(Modifiers, &str); 8] = [
+        (Modifiers::BOLD, "1"),
+        (Modifiers::DIM, "2"),
+        (Modifiers::ITALIC, "3"),
+        (Modifiers::UNDERLINE, "4"),
+        (Modifiers::BLINK, "5"),
+        (Modifiers::REVERSE, "7"),
+        (Modifiers::HIDDEN, "8"),
+        (Modifiers::STRIKETHROUGH, "9"),
+    ];
+    let channel = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    output.push_str("\x1b[0");
+    for (flag, code) in CODES {
+        if modifiers.contains(flag) {
+            output.push(';');
+            output.push_str(code);
+        }
+    }
+    let _ = write!(
+        output,
+        ";38;2;{};{};{}",
+        channel(fg.r),
+        channel(fg.g),
+        channel(fg.b)
+    );
+    if bg.a > 0.0 {
+        let _ = write!(
+            output,
+            ";48;2;{};{};{}",
+            channel(bg.r),
+            channel(bg.g),
+            channel(bg.b)
+        );
+    }
+    output.push('m');
+}
+
 // ============================================================================
 // RenderMetrics
 // ============================================================================
@@ -571,6 +668,20 @@ impl FrameTimeStats {
     pub fn sample_count(&self) -> usize {
         self.samples.len()
     }
+
+    /// Median absolute deviation from the median, in microseconds.
+    #[must_use]
+    pub fn median_abs_dev_us(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+        let median = sorted[sorted.len() / 2];
+        let mut deviations: Vec<u64> = sorted.iter().map(|&x| x.abs_diff(median)).collect();
+        deviations.sort_unstable();
+        deviations[deviations.len() / 2] as f64
+    }
 }
 
 /// Memory statistics.
@@ -741,6 +852,23 @@ impl BenchmarkHarness {
         }
     }
 
+    /// Run benchmark on a widget and write Criterion-style `estimates.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails.
+    #[cfg(feature = "export")]
+    pub fn run_to_json<W: Widget, O: Write>(
+        &mut self,
+        widget: &mut W,
+        bounds: Rect,
+        out: &mut O,
+    ) -> io::Result<BenchmarkResult> {
+        let result = self.benchmark(widget, bounds);
+        out.write_all(result.to_criterion_json().as_bytes())?;
+        Ok(result)
+    }
+
     /// Get reference to canvas.
     #[must_use]
     pub fn canvas(&self) -> &HeadlessCanvas {
@@ -792,6 +920,82 @@ impl BenchmarkResult {
             self.metrics.meets_targets(&PerformanceTargets::default()),
         )
     }
+
+    /// Export frame times as a Criterion `estimates.json` document.
+    ///
+    /// Values are in nanoseconds. Confidence intervals are 95% normal
+    /// approximations from the standard error, since no bootstrap is run.
+    #[cfg(feature = "export")]
+    #[must_use]
+    pub fn to_criterion_json(&self) -> String {
+        let stats = &self.metrics.frame_times;
+        let n = stats.sample_count().max(1) as f64;
+        let mean = stats.mean_us * 1000.0;
+        let std_dev = stats.stddev_us * 1000.0;
+        let median = stats.p50_us as f64 * 1000.0;
+        let mad = stats.median_abs_dev_us() * 1000.0;
+
+        let mean_se = std_dev / n.sqrt();
+        // Asymptotic standard errors of the median and of the std deviation
+        let median_se = mean_se * (std::f64::consts::PI / 2.0).sqrt();
+        let std_dev_se = std_dev / (2.0 * (n - 1.0).max(1.0)).sqrt();
+
+        let estimates = CriterionEstimates {
+            mean: CriterionEstimate::new(mean, mean_se),
+            median: CriterionEstimate::new(median, median_se),
+            median_abs_dev: CriterionEstimate::new(mad, median_se),
+            slope: None,
+            std_dev: CriterionEstimate::new(std_dev, std_dev_se),
+        };
+        // Non-finite values serialize as `null`, keeping the document valid
+        serde_json::to_string(&estimates).expect("estimates serialize to JSON")
+    }
+}
+
+/// Criterion `estimates.json` document.
+#[cfg(feature = "export")]
+#[derive(Serialize)]
+struct CriterionEstimates {
+    mean: CriterionEstimate,
+    median: CriterionEstimate,
+    median_abs_dev: CriterionEstimate,
+    slope: Option<CriterionEstimate>,
+    std_dev: CriterionEstimate,
+}
+
+/// One Criterion `Estimate` object.
+#[cfg(feature = "export")]
+#[derive(Serialize)]
+struct CriterionEstimate {
+    confidence_interval: CriterionInterval,
+    point_estimate: f64,
+    standard_error: f64,
+}
+
+/// Criterion `ConfidenceInterval` object.
+#[cfg(feature = "export")]
+#[derive(Serialize)]
+struct CriterionInterval {
+    confidence_level: f64,
+    lower_bound: f64,
+    upper_bound: f64,
+}
+
+#[cfg(feature = "export")]
+impl CriterionEstimate {
+    /// Build an estimate with a 95% normal confidence interval.
+    fn new(point: f64, standard_error: f64) -> Self {
+        const Z_95: f64 = 1.96;
+        Self {
+            confidence_interval: CriterionInterval {
+                confidence_level: 0.95,
+                lower_bound: Z_95.mul_add(-standard_error, point).max(0.0),
+                upper_bound: Z_95.mul_add(standard_error, point),
+            },
+            point_estimate: point,
+            standard_error,
+        }
+    }
 }
 
 /// Result from comparing two widgets.
@@ -1059,6 +1263,59 @@ mod tests {
         assert!(dump.contains("Hi"));
     }
 
+    #[test]
+    fn test_headless_canvas_to_plain_string() {
+        let mut canvas = HeadlessCanvas::new(8, 2);
+        canvas.render_frame(|c| {
+            c.draw_text("CPU 42%", Point::new(0.0, 0.0), &TextStyle::default());
+            c.draw_text("MEM 7%", Point::new(0.0, 1.0), &TextStyle::default());
+        });
+        let plain = canvas.to_plain_string();
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines, vec!["CPU 42% ", "MEM 7%  "]);
+        assert!(lines.iter().all(|l| l.chars().count() == 8));
+        assert!(!plain.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_headless_canvas_to_plain_string_wide_chars() {
+        let mut canvas = HeadlessCanvas::new(4, 1);
+        canvas.buffer_mut().write_str(0, 0, "日本");
+        assert_eq!(canvas.to_plain_string(), "日本");
+    }
+
+    #[test]
+    fn test_headless_canvas_draw_text_wide_chars() {
+        let mut canvas = HeadlessCanvas::new(6, 1);
+        canvas.draw_text("日本a", Point::new(0.0, 0.0), &TextStyle::default());
+        assert_eq!(canvas.to_plain_string(), "日本a ");
+        assert!(canvas.buffer().get(1, 0).unwrap().is_continuation());
+        assert_eq!(canvas.buffer().get(2, 0).unwrap().symbol.as_str(), "本");
+    }
+
+    #[test]
+    fn test_headless_canvas_measure_text_wide_chars() {
+        let canvas = HeadlessCanvas::new(10, 1);
+        let style = TextStyle::default();
+        assert_eq!(canvas.measure_text("hello", &style), Size::new(5.0, 1.0));
+        assert_eq!(canvas.measure_text("日本語", &style), Size::new(6.0, 1.0));
+    }
+
+    #[test]
+    fn test_headless_canvas_to_ansi_string() {
+        let mut canvas = HeadlessCanvas::new(3, 2);
+        canvas
+            .buffer_mut()
+            .update(0, 0, "A", Color::RED, Color::BLACK, Modifiers::BOLD);
+        let ansi = canvas.to_ansi_string();
+        let lines: Vec<&str> = ansi.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("\x1b[0;1;38;2;255;0;0;48;2;0;0;0mA"));
+        assert!(lines.iter().all(|l| l.ends_with("\x1b[0m")));
+        // Transparent background falls back to the terminal default.
+        assert!(lines[1].starts_with("\x1b[0;38;2;255;255;255m   "));
+    }
+
     #[test]
     fn test_headless_canvas_clear() {
         let mut canvas = HeadlessCanvas::new(10, 10);
@@ -1213,6 +1470,80 @@ mod tests {
         assert!(json.contains("80"));
     }
 
+    #[test]
+    #[cfg(feature = "export")]
+    fn test_benchmark_result_criterion_json_round_trip() {
+        let mut harness = BenchmarkHarness::new(40, 10).with_frames(2, 25);
+        let mut widget = TestWidget::new();
+        let mut out = Vec::new();
+        let result = harness
+            .run_to_json(&mut widget, Rect::new(0.0, 0.0, 40.0, 10.0), &mut out)
+            .unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let stats = &result.metrics.frame_times;
+        let mean = json["mean"]["point_estimate"].as_f64().unwrap();
+        assert!((mean - stats.mean_us * 1000.0).abs() < 1e-6);
+
+        let median = json["median"]["point_estimate"].as_f64().unwrap();
+        assert!((median - stats.p50_us as f64 * 1000.0).abs() < 1e-6);
+        let std_dev = json["std_dev"]["point_estimate"].as_f64().unwrap();
+        assert!((std_dev - stats.stddev_us * 1000.0).abs() < 1e-6);
+
+        let ci = &json["mean"]["confidence_interval"];
+        assert_eq!(ci["confidence_level"].as_f64(), Some(0.95));
+        assert!(ci["lower_bound"].as_f64().unwrap() <= mean);
+        assert!(ci["upper_bound"].as_f64().unwrap() >= mean);
+        assert!(json["slope"].is_null());
+    }
+
+    #[test]
+    #[cfg(feature = "export")]
+    fn test_criterion_json_known_samples() {
+        let mut metrics = RenderMetrics::new();
+        for us in [10, 20, 30, 40, 50] {
+            metrics.record_frame(Duration::from_micros(us));
+        }
+        metrics.frame_times.finalize();
+        let result = BenchmarkResult {
+            widget_name: "known".to_string(),
+            metrics,
+            final_frame: String::new(),
+            width: 1,
+            height: 1,
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&result.to_criterion_json()).unwrap();
+        assert_eq!(json["mean"]["point_estimate"].as_f64(), Some(30_000.0));
+        assert_eq!(json["median"]["point_estimate"].as_f64(), Some(30_000.0));
+        assert_eq!(
+            json["median_abs_dev"]["point_estimate"].as_f64(),
+            Some(10_000.0)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "export")]
+    fn test_criterion_json_non_finite_is_null() {
+        let mut metrics = RenderMetrics::new();
+        metrics.record_frame(Duration::from_micros(10));
+        metrics.frame_times.finalize();
+        metrics.frame_times.mean_us = f64::NAN;
+        metrics.frame_times.stddev_us = f64::INFINITY;
+        let result = BenchmarkResult {
+            widget_name: "broken".to_string(),
+            metrics,
+            final_frame: String::new(),
+            width: 1,
+            height: 1,
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&result.to_criterion_json()).unwrap();
+        assert!(json["mean"]["point_estimate"].is_null());
+        assert!(json["std_dev"]["point_estimate"].is_null());
+        assert_eq!(json["median"]["point_estimate"].as_f64(), Some(10_000.0));
+    }
+
     #[test]
     fn test_comparison_result_summary() {
         let result_a = BenchmarkResult {
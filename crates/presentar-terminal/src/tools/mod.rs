@@ -12,6 +12,7 @@
 
 pub mod bench;
 mod color_diff;
+mod ssim;
 #[cfg(feature = "tui-compare")]
 mod tui_compare;
 
@@ -20,6 +21,7 @@ pub use bench::{
     HeadlessCanvas, MemoryStats, PerformanceTargets, RenderMetrics,
 };
 pub use color_diff::{average_delta_e, ciede2000, rgb_to_lab, DeltaECategory, Lab, Rgb};
+pub use ssim::structural_similarity;
 #[cfg(feature = "tui-compare")]
 pub use tui_compare::{
     compare_tui, generate_report, DiffCell, PanelResult, PanelThreshold, TuiComparisonConfig,
@@ -0,0 +1,245 @@
+//! Structural similarity (SSIM) over rendered cell grids.
+//!
+//! Unlike the feature-gated comparison in `tui_compare`, this works directly
+//! on two `HeadlessCanvas` frames and treats each cell as a two-channel
+//! "pixel": a glyph channel (which character is drawn) and a color channel
+//! (the luminance of the visible color). Scores are computed over a sliding
+//! window and averaged, so a local change lowers the score proportionally to
+//! how many windows it touches.
+
+use super::bench::HeadlessCanvas;
+use crate::direct::{Cell, CellBuffer};
+use presentar_core::Color;
+
+/// Window edge length in cells (clamped to the grid size).
+const WINDOW: usize = 4;
+
+/// Stabilizing constants from the SSIM paper for a dynamic range of 1.0.
+const C1: f64 = 0.01 * 0.01;
+const C2: f64 = 0.03 * 0.03;
+
+/// Structural similarity between two rendered frames.
+///
+/// Returns 1.0 for identical frames and decreases as glyphs or colors
+/// diverge. Frames with different dimensions score 0.0.
+#[must_use]
+pub fn structural_similarity(a: &HeadlessCanvas, b: &HeadlessCanvas) -> f64 {
+    let (a, b) = (a.buffer(), b.buffer());
+    if a.width() != b.width() || a.height() != b.height() {
+        return 0.0;
+    }
+    let width = a.width() as usize;
+    let height = a.height() as usize;
+    if width == 0 || height == 0 {
+        return 1.0;
+    }
+
+    let glyph = channel_ssim(
+        &channel(a, glyph_signal),
+        &channel(b, glyph_signal),
+        width,
+        height,
+    );
+    let color = channel_ssim(
+        &channel(a, color_signal),
+        &channel(b, color_signal),
+        width,
+        height,
+    );
+    (glyph + color) / 2.0
+}
+
+/// Extract one signal channel from a buffer in row-major order.
+fn channel(buffer: &CellBuffer, signal: fn(&Cell) -> f64) -> Vec<f64> {
+    buffer.cells().iter().map(signal).collect()
+}
+
+/// Glyph signal: 0.0 for blank cells, otherwise a stable value in (0.25, 1.0].
+fn glyph_signal(cell: &Cell) -> f64 {
+    if is_blank(cell) {
+        return 0.0;
+    }
+    // FNV-1a over the grapheme bytes.
+    let hash = cell
+        .symbol
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |h, byte| {
+            (h ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    0.25 + 0.75 * ((hash % 1024) + 1) as f64 / 1024.0
+}
+
+/// Color signal: luminance of the foreground if a glyph is drawn,
+/// otherwise of the background.
+fn color_signal(cell: &Cell) -> f64 {
+    if is_blank(cell) {
+        luminance(cell.bg)
+    } else {
+        luminance(cell.fg)
+    }
+}
+
+fn is_blank(cell: &Cell) -> bool {
+    cell.symbol.is_empty() || cell.symbol == " "
+}
+
+/// Relative luminance (Rec. 709 weights), scaled by alpha.
+fn luminance(color: Color) -> f64 {
+    let y = 0.0722_f64.mul_add(
+        f64::from(color.b),
+        0.2126_f64.mul_add(f64::from(color.r), 0.7152 * f64::from(color.g)),
+    );
+    y * f64::from(color.a)
+}
+
+/// Mean SSIM over all window positions (stride 1).
+fn channel_ssim(x: &[f64], y: &[f64], width: usize, height: usize) -> f64 {
+    // Short-circuit so identical frames score exactly 1.0 despite rounding.
+    if x == y {
+        return 1.0;
+    }
+    let win_w = WINDOW.min(width);
+    let win_h = WINDOW.min(height);
+    let mut total = 0.0;
+    let mut windows = 0_usize;
+
+    for top in 0..=(height - win_h) {
+        for left in 0..=(width - win_w) {
+            total += window_ssim(x, y, width, left, top, win_w, win_h);
+            windows += 1;
+        }
+    }
+
+    total / windows as f64
+}
+
+/// SSIM for a single window.
+fn window_ssim(
+    x: &[f64],
+    y: &[f64],
+    stride: usize,
+    left: usize,
+    top: usize,
+    win_w: usize,
+    win_h: usize,
+) -> f64 {
+    let indices = || {
+        (top..top + win_h)
+            .flat_map(move |row| (left..left + win_w).map(move |col| row * stride + col))
+    };
+    let n = (win_w * win_h) as f64;
+
+    let mean_x = indices().map(|i| x[i]).sum::<f64>() / n;
+    let mean_y = indices().map(|i| y[i]).sum::<f64>() / n;
+
+    let mut var_x = 0.0;
+    let mut var_y = 0.0;
+    let mut covar = 0.0;
+    for i in indices() {
+        let dx = x[i] - mean_x;
+        let dy = y[i] - mean_y;
+        var_x = dx.mul_add(dx, var_x);
+        var_y = dy.mul_add(dy, var_y);
+        covar = dx.mul_add(dy, covar);
+    }
+    var_x /= n;
+    var_y /= n;
+    covar /= n;
+
+    let numerator = (2.0 * mean_x).mul_add(mean_y, C1) * 2.0_f64.mul_add(covar, C2);
+    let denominator = mean_x.mul_add(mean_x, mean_y.mul_add(mean_y, C1)) * (var_x + var_y + C2);
+    numerator / denominator
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::Modifiers;
+
+    fn canvas_with_text(text: &[&str]) -> HeadlessCanvas {
+        let mut canvas = HeadlessCanvas::new(12, 6);
+        for (y, line) in text.iter().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                canvas.buffer_mut().update(
+                    x as u16,
+                    y as u16,
+                    &ch.to_string(),
+                    Color::WHITE,
+                    Color::BLACK,
+                    Modifiers::NONE,
+                );
+            }
+        }
+        canvas
+    }
+
+    #[test]
+    fn test_identical_frames_score_one() {
+        let a = canvas_with_text(&["CPU 42%", "MEM 13%", "|||||"]);
+        let b = canvas_with_text(&["CPU 42%", "MEM 13%", "|||||"]);
+        assert_eq!(structural_similarity(&a, &b), 1.0);
+    }
+
+    #[test]
+    fn test_empty_frames_score_one() {
+        let a = HeadlessCanvas::new(3, 2);
+        let b = HeadlessCanvas::new(3, 2);
+        assert_eq!(structural_similarity(&a, &b), 1.0);
+    }
+
+    #[test]
+    fn test_single_cell_change_lowers_score() {
+        let a = canvas_with_text(&["CPU 42%", "MEM 13%", "|||||"]);
+        let b = canvas_with_text(&["CPU 43%", "MEM 13%", "|||||"]);
+        let score = structural_similarity(&a, &b);
+        assert!(score < 1.0, "score {score}");
+        assert!(score > 0.0, "score {score}");
+    }
+
+    #[test]
+    fn test_color_only_change_lowers_score() {
+        let a = canvas_with_text(&["CPU 42%"]);
+        let mut b = canvas_with_text(&["CPU 42%"]);
+        b.buffer_mut()
+            .update(0, 0, "C", Color::RED, Color::BLACK, Modifiers::NONE);
+        assert!(structural_similarity(&a, &b) < 1.0);
+    }
+
+    #[test]
+    fn test_window_ssim_reference_value() {
+        // Hand-computed from the Wang et al. formula with population
+        // statistics: means 0.5/0.75, variances 0.25/0.1875, covariance 0.125.
+        let x = [0.0, 0.0, 1.0, 1.0];
+        let y = [0.0, 1.0, 1.0, 1.0];
+        let expected = (0.7501 * 0.2509) / (0.8126 * 0.4384);
+        let score = window_ssim(&x, &y, 2, 0, 0, 2, 2);
+        assert!((score - expected).abs() < 1e-12, "score {score}");
+        assert!((score - 0.528_290_089_170_384).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_uniform_background_change_reference_value() {
+        // A 4x4 frame is a single window. Glyphs are identical (SSIM 1.0);
+        // the color channel goes from constant 0.0 to constant 1.0, leaving
+        // only the luminance term: C1 / (1 + C1).
+        let a = HeadlessCanvas::new(4, 4);
+        let mut b = HeadlessCanvas::new(4, 4);
+        for y in 0..4 {
+            for x in 0..4 {
+                b.buffer_mut()
+                    .update(x, y, " ", Color::WHITE, Color::WHITE, Modifiers::NONE);
+            }
+        }
+        let expected = (1.0 + C1 / (1.0 + C1)) / 2.0;
+        let score = structural_similarity(&a, &b);
+        assert!((score - expected).abs() < 1e-9, "score {score}");
+        assert!((score - 0.500_049_995).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_dimension_mismatch_scores_zero() {
+        let a = HeadlessCanvas::new(10, 5);
+        let b = HeadlessCanvas::new(10, 6);
+        assert_eq!(structural_similarity(&a, &b), 0.0);
+    }
+}
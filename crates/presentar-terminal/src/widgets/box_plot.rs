@@ -144,7 +144,10 @@ impl Default for BoxPlot {
 }
 
 impl BoxPlot {
-    /// Create a new box plot.
+    /// Create a new box plot from precomputed stats.
+    ///
+    /// Stats can be computed once (e.g. during collection) and rendered
+    /// many times; painting never touches the raw samples.
     #[must_use]
     pub fn new(stats: Vec<BoxStats>) -> Self {
         let (gmin, gmax) = Self::compute_global_range(&stats);
@@ -274,8 +277,8 @@ impl BoxPlot {
             ..Default::default()
         };
 
-        // Calculate positions
-        let width_f64 = width as f64;
+        // Calculate positions (last cell is the maximum)
+        let width_f64 = (width - 1.0).max(0.0) as f64;
         let min_pos = (self.normalize(stats.min) * width_f64) as usize;
         let q1_pos = (self.normalize(stats.q1) * width_f64) as usize;
         let median_pos = (self.normalize(stats.median) * width_f64) as usize;
@@ -339,7 +342,7 @@ impl BoxPlot {
         };
 
         // Calculate positions (inverted: 0 at bottom, 1 at top)
-        let height_f64 = height as f64;
+        let height_f64 = (height - 1.0).max(0.0) as f64;
         let min_pos = ((1.0 - self.normalize(stats.min)) * height_f64) as usize;
         let q1_pos = ((1.0 - self.normalize(stats.q1)) * height_f64) as usize;
         let median_pos = ((1.0 - self.normalize(stats.median)) * height_f64) as usize;
@@ -599,6 +602,27 @@ mod tests {
         assert_eq!(bp.count(), 1);
     }
 
+    #[test]
+    fn test_box_plot_from_quartiles_positions() {
+        let mut bp = BoxPlot::new(vec![BoxStats::new(0.0, 25.0, 50.0, 75.0, 100.0)]);
+        // 5-wide label gutter + 2, leaving 41 cells for the box
+        bp.layout(Rect::new(0.0, 0.0, 48.0, 1.0));
+        let mut canvas = MockCanvas::new();
+        bp.paint(&mut canvas);
+
+        let (line, pos) = &canvas.texts[0];
+        assert_eq!(pos.x, 7.0);
+        let chars: Vec<char> = line.chars().collect();
+        assert_eq!(chars.len(), 41);
+        assert_eq!(chars[0], '├');
+        assert_eq!(chars[10], '[');
+        assert_eq!(chars[20], '│');
+        assert_eq!(chars[30], ']');
+        assert_eq!(chars[40], '┤');
+        assert_eq!(chars[5], '─');
+        assert_eq!(chars[15], '█');
+    }
+
     #[test]
     fn test_box_plot_from_data() {
         let data1 = vec![1.0, 2.0, 3.0, 4.0, 5.0];
@@ -0,0 +1,435 @@
+//! Candlestick (OHLC) chart widget.
+//!
+//! Draws one box-and-wick glyph column per candle: the wick (`│`) spans the
+//! high to the low and the body (`█`) spans the open to the close. Candles
+//! are two cells apart when there is room and collapse to one cell each when
+//! horizontally constrained, keeping the most recent candles visible.
+
+use crate::theme::Theme;
+use presentar_core::{
+    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
+    LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
+};
+use std::any::Any;
+use std::time::Duration;
+
+/// Width reserved for price labels.
+const LABEL_WIDTH: f32 = 7.0;
+
+/// A single open/high/low/close sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ohlc {
+    /// Sample time (e.g. seconds since epoch).
+    pub time: f64,
+    /// Opening value.
+    pub open: f64,
+    /// Highest value.
+    pub high: f64,
+    /// Lowest value.
+    pub low: f64,
+    /// Closing value.
+    pub close: f64,
+}
+
+impl Ohlc {
+    /// Create a new sample.
+    #[must_use]
+    pub const fn new(time: f64, open: f64, high: f64, low: f64, close: f64) -> Self {
+        Self {
+            time,
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+
+    /// Whether the candle closed at or above its open.
+    #[must_use]
+    pub fn is_up(&self) -> bool {
+        self.close >= self.open
+    }
+
+    /// Whether every field is finite.
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        [self.open, self.high, self.low, self.close]
+            .iter()
+            .all(|v| v.is_finite())
+    }
+}
+
+/// Candlestick chart widget.
+#[derive(Debug, Clone)]
+pub struct Candlestick {
+    candles: Vec<Ohlc>,
+    up_color: Color,
+    down_color: Color,
+    show_labels: bool,
+    bounds: Rect,
+}
+
+impl Default for Candlestick {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl Candlestick {
+    /// Create a new candlestick chart.
+    #[must_use]
+    pub fn new(candles: Vec<Ohlc>) -> Self {
+        let theme = Theme::default();
+        Self {
+            candles,
+            up_color: theme.positive(),
+            down_color: theme.negative(),
+            show_labels: true,
+            bounds: Rect::default(),
+        }
+    }
+
+    /// Take up/down colors from a theme.
+    #[must_use]
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.up_color = theme.positive();
+        self.down_color = theme.negative();
+        self
+    }
+
+    /// Set up/down colors explicitly.
+    #[must_use]
+    pub fn with_colors(mut self, up: Color, down: Color) -> Self {
+        self.up_color = up;
+        self.down_color = down;
+        self
+    }
+
+    /// Toggle price labels.
+    #[must_use]
+    pub fn with_labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Update candles.
+    pub fn set_candles(&mut self, candles: Vec<Ohlc>) {
+        self.candles = candles;
+    }
+
+    /// Append a candle.
+    pub fn push(&mut self, candle: Ohlc) {
+        self.candles.push(candle);
+    }
+
+    /// Get the candles.
+    #[must_use]
+    pub fn candles(&self) -> &[Ohlc] {
+        &self.candles
+    }
+
+    /// Get the cell spacing and the visible (most recent) candles for a width.
+    fn visible(&self, cols: usize) -> (usize, &[Ohlc]) {
+        let n = self.candles.len();
+        let stride = if cols >= n * 2 { 2 } else { 1 };
+        let count = n.min(cols / stride);
+        (stride, &self.candles[n - count..])
+    }
+
+    /// Get the (low, high) range over finite candles.
+    fn price_range(candles: &[Ohlc]) -> (f64, f64) {
+        let (lo, hi) = candles
+            .iter()
+            .filter(|c| c.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), c| {
+                (lo.min(c.low), hi.max(c.high))
+            });
+        if lo > hi {
+            (0.0, 1.0)
+        } else if (hi - lo).abs() < f64::EPSILON {
+            (lo - 0.5, hi + 0.5)
+        } else {
+            (lo, hi)
+        }
+    }
+
+    fn draw_labels(&self, canvas: &mut dyn Canvas, lo: f64, hi: f64) {
+        let style = TextStyle {
+            color: Color::new(0.6, 0.6, 0.6, 1.0),
+            ..Default::default()
+        };
+        let bottom = self.bounds.y + self.bounds.height - 1.0;
+        canvas.draw_text(
+            &format!("{hi:>6.1}"),
+            Point::new(self.bounds.x, self.bounds.y),
+            &style,
+        );
+        if bottom > self.bounds.y {
+            canvas.draw_text(
+                &format!("{lo:>6.1}"),
+                Point::new(self.bounds.x, bottom),
+                &style,
+            );
+        }
+    }
+}
+
+impl Widget for Candlestick {
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<Self>()
+    }
+
+    fn measure(&self, constraints: Constraints) -> Size {
+        let label_width = if self.show_labels { LABEL_WIDTH } else { 0.0 };
+        let width = label_width + (self.candles.len() * 2) as f32;
+        constraints.constrain(Size::new(width, constraints.max_height.min(15.0)))
+    }
+
+    fn layout(&mut self, bounds: Rect) -> LayoutResult {
+        self.bounds = bounds;
+        LayoutResult {
+            size: Size::new(bounds.width, bounds.height),
+        }
+    }
+
+    fn paint(&self, canvas: &mut dyn Canvas) {
+        let label_width = if self.show_labels { LABEL_WIDTH } else { 0.0 };
+        let plot_x = self.bounds.x + label_width;
+        let cols = (self.bounds.width - label_width).max(0.0) as usize;
+        let rows = self.bounds.height as usize;
+        if self.candles.is_empty() || cols == 0 || rows == 0 {
+            return;
+        }
+
+        let (stride, visible) = self.visible(cols);
+        let (lo, hi) = Self::price_range(visible);
+        if self.show_labels {
+            self.draw_labels(canvas, lo, hi);
+        }
+
+        let row_of = |value: f64| -> usize {
+            let t = ((hi - value) / (hi - lo)).clamp(0.0, 1.0);
+            ((t * (rows - 1) as f64).round() as usize).min(rows - 1)
+        };
+
+        for (i, candle) in visible.iter().enumerate() {
+            if !candle.is_finite() {
+                continue;
+            }
+            let style = TextStyle {
+                color: if candle.is_up() {
+                    self.up_color
+                } else {
+                    self.down_color
+                },
+                ..Default::default()
+            };
+            let x = plot_x + (i * stride) as f32;
+            let wick_top = row_of(candle.high.max(candle.open).max(candle.close));
+            let wick_bottom = row_of(candle.low.min(candle.open).min(candle.close));
+            let body_top = row_of(candle.open.max(candle.close));
+            let body_bottom = row_of(candle.open.min(candle.close));
+
+            for row in wick_top..=wick_bottom {
+                let glyph = if (body_top..=body_bottom).contains(&row) {
+                    "█"
+                } else {
+                    "│"
+                };
+                canvas.draw_text(glyph, Point::new(x, self.bounds.y + row as f32), &style);
+            }
+        }
+    }
+
+    fn event(&mut self, _event: &Event) -> Option<Box<dyn Any + Send>> {
+        None
+    }
+
+    fn children(&self) -> &[Box<dyn Widget>] {
+        &[]
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        &mut []
+    }
+}
+
+impl Brick for Candlestick {
+    fn brick_name(&self) -> &'static str {
+        "candlestick"
+    }
+
+    fn assertions(&self) -> &[BrickAssertion] {
+        static ASSERTIONS: &[BrickAssertion] = &[BrickAssertion::max_latency_ms(16)];
+        ASSERTIONS
+    }
+
+    fn budget(&self) -> BrickBudget {
+        BrickBudget::uniform(16)
+    }
+
+    fn verify(&self) -> BrickVerification {
+        BrickVerification {
+            passed: self.assertions().to_vec(),
+            failed: vec![],
+            verification_time: Duration::from_micros(10),
+        }
+    }
+
+    fn to_html(&self) -> String {
+        String::new()
+    }
+
+    fn to_css(&self) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::{CellBuffer, DirectTerminalCanvas};
+
+    fn render(chart: &mut Candlestick, width: u16, height: u16) -> CellBuffer {
+        chart.layout(Rect::new(0.0, 0.0, f32::from(width), f32::from(height)));
+        let mut buffer = CellBuffer::new(width, height);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        chart.paint(&mut canvas);
+        buffer
+    }
+
+    fn column(buffer: &CellBuffer, x: u16, height: u16) -> String {
+        (0..height)
+            .filter_map(|y| buffer.get(x, y).map(|c| c.symbol.to_string()))
+            .collect()
+    }
+
+    fn sample() -> Vec<Ohlc> {
+        vec![
+            Ohlc::new(0.0, 10.0, 25.0, 5.0, 20.0),
+            Ohlc::new(1.0, 20.0, 22.0, 10.0, 12.0),
+        ]
+    }
+
+    #[test]
+    fn test_ohlc_direction() {
+        assert!(Ohlc::new(0.0, 1.0, 3.0, 0.0, 2.0).is_up());
+        assert!(!Ohlc::new(0.0, 2.0, 3.0, 0.0, 1.0).is_up());
+        assert!(!Ohlc::new(0.0, f64::NAN, 3.0, 0.0, 1.0).is_finite());
+    }
+
+    #[test]
+    fn test_candlestick_up_candle_uses_up_color() {
+        let mut chart = Candlestick::new(sample())
+            .with_colors(Color::GREEN, Color::RED)
+            .with_labels(false);
+        let buffer = render(&mut chart, 10, 10);
+
+        let up = buffer.get(0, 5).expect("cell");
+        assert_eq!(up.fg, Color::GREEN);
+        let down = buffer.get(2, 5).expect("cell");
+        assert_eq!(down.fg, Color::RED);
+    }
+
+    #[test]
+    fn test_candlestick_wick_spans_high_to_low() {
+        let mut chart = Candlestick::new(sample()).with_labels(false);
+        let buffer = render(&mut chart, 10, 10);
+
+        // high 25 -> row 0, close 20 -> row 2, open 10 -> row 7, low 5 -> row 9
+        assert_eq!(column(&buffer, 0, 10), "││██████││");
+        // high 22 -> row 1, open 20 -> row 2, close 12 -> row 6, low 10 -> row 7
+        assert_eq!(column(&buffer, 2, 10), " │█████│  ");
+    }
+
+    #[test]
+    fn test_candlestick_collapses_when_narrow() {
+        let candles: Vec<Ohlc> = (0..5)
+            .map(|i| Ohlc::new(f64::from(i), 1.0, 2.0, 0.0, 1.5))
+            .collect();
+        let chart = Candlestick::new(candles.clone()).with_labels(false);
+
+        let (stride, visible) = chart.visible(10);
+        assert_eq!((stride, visible.len()), (2, 5));
+
+        let (stride, visible) = chart.visible(6);
+        assert_eq!((stride, visible.len()), (1, 5));
+
+        // Too narrow for all candles: keep the most recent ones
+        let (stride, visible) = chart.visible(3);
+        assert_eq!(stride, 1);
+        assert_eq!(visible, &candles[2..]);
+    }
+
+    #[test]
+    fn test_candlestick_respects_bounds() {
+        let candles: Vec<Ohlc> = (0..20)
+            .map(|i| Ohlc::new(f64::from(i), 1.0, 2.0, 0.0, 1.5))
+            .collect();
+        let mut chart = Candlestick::new(candles).with_labels(false);
+        chart.layout(Rect::new(0.0, 0.0, 5.0, 4.0));
+        let mut buffer = CellBuffer::new(8, 4);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        chart.paint(&mut canvas);
+
+        for x in 5..8 {
+            assert_eq!(column(&buffer, x, 4), "    ");
+        }
+        assert_ne!(column(&buffer, 4, 4), "    ");
+    }
+
+    #[test]
+    fn test_candlestick_labels() {
+        let mut chart = Candlestick::new(sample());
+        let buffer = render(&mut chart, 20, 10);
+        let top: String = (0..6)
+            .filter_map(|x| buffer.get(x, 0).map(|c| c.symbol.to_string()))
+            .collect();
+        assert_eq!(top, "  25.0");
+    }
+
+    #[test]
+    fn test_candlestick_empty_and_flat() {
+        let mut chart = Candlestick::default();
+        let buffer = render(&mut chart, 10, 5);
+        assert_eq!(column(&buffer, 7, 5), "     ");
+
+        assert_eq!(
+            Candlestick::price_range(&[Ohlc::new(0.0, 3.0, 3.0, 3.0, 3.0)]),
+            (2.5, 3.5)
+        );
+    }
+
+    #[test]
+    fn test_candlestick_with_theme() {
+        let theme = Theme::nord();
+        let chart = Candlestick::new(sample()).with_theme(&theme);
+        assert_eq!(chart.up_color, theme.positive());
+        assert_eq!(chart.down_color, theme.negative());
+    }
+
+    #[test]
+    fn test_candlestick_push() {
+        let mut chart = Candlestick::default();
+        chart.push(Ohlc::new(0.0, 1.0, 2.0, 0.5, 1.5));
+        assert_eq!(chart.candles().len(), 1);
+        chart.set_candles(sample());
+        assert_eq!(chart.candles().len(), 2);
+    }
+
+    #[test]
+    fn test_candlestick_brick() {
+        let chart = Candlestick::default();
+        assert_eq!(chart.brick_name(), "candlestick");
+        assert!(!chart.assertions().is_empty());
+        assert!(chart.verify().is_valid());
+        assert!(chart.to_html().is_empty());
+    }
+
+    #[test]
+    fn test_candlestick_measure() {
+        let chart = Candlestick::new(sample());
+        let size = chart.measure(Constraints::loose(Size::new(100.0, 40.0)));
+        assert_eq!(size, Size::new(LABEL_WIDTH + 4.0, 15.0));
+    }
+}
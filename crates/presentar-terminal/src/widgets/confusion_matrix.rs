@@ -79,6 +79,8 @@ pub struct ConfusionMatrix {
     show_percentages: bool,
     /// Title.
     title: Option<String>,
+    /// Whether to show recall/precision margins and F1.
+    show_metrics: bool,
     /// Cached bounds.
     bounds: Rect,
 }
@@ -104,6 +106,7 @@ impl ConfusionMatrix {
             show_values: true,
             show_percentages: false,
             title: None,
+            show_metrics: false,
             bounds: Rect::default(),
         }
     }
@@ -157,6 +160,14 @@ impl ConfusionMatrix {
         self
     }
 
+    /// Show per-class recall (right column), precision (bottom row), and
+    /// macro F1 alongside accuracy.
+    #[must_use]
+    pub fn with_metrics(mut self, show: bool) -> Self {
+        self.show_metrics = show;
+        self
+    }
+
     /// Update matrix data.
     pub fn set_matrix(&mut self, matrix: Vec<Vec<u64>>) {
         self.matrix = matrix;
@@ -193,35 +204,13 @@ impl ConfusionMatrix {
     /// Get precision for a class.
     #[must_use]
     pub fn precision(&self, class: usize) -> f64 {
-        let col_sum: u64 = self
-            .matrix
-            .iter()
-            .map(|row| row.get(class).copied().unwrap_or(0))
-            .sum();
-        if col_sum == 0 {
-            return 0.0;
-        }
-        self.matrix
-            .get(class)
-            .and_then(|row| row.get(class))
-            .copied()
-            .unwrap_or(0) as f64
-            / col_sum as f64
+        self.checked_precision(class).unwrap_or(0.0)
     }
 
     /// Get recall for a class.
     #[must_use]
     pub fn recall(&self, class: usize) -> f64 {
-        let row_sum: u64 = self.matrix.get(class).map_or(0, |row| row.iter().sum());
-        if row_sum == 0 {
-            return 0.0;
-        }
-        self.matrix
-            .get(class)
-            .and_then(|row| row.get(class))
-            .copied()
-            .unwrap_or(0) as f64
-            / row_sum as f64
+        self.checked_recall(class).unwrap_or(0.0)
     }
 
     /// Get F1 score for a class.
@@ -235,6 +224,58 @@ impl ConfusionMatrix {
         2.0 * p * r / (p + r)
     }
 
+    fn diagonal(&self, class: usize) -> u64 {
+        self.matrix
+            .get(class)
+            .and_then(|row| row.get(class))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn row_sum(&self, class: usize) -> u64 {
+        self.matrix.get(class).map_or(0, |row| row.iter().sum())
+    }
+
+    fn col_sum(&self, class: usize) -> u64 {
+        self.matrix
+            .iter()
+            .map(|row| row.get(class).copied().unwrap_or(0))
+            .sum()
+    }
+
+    /// Precision for a class, or `None` if nothing was predicted as it.
+    #[must_use]
+    pub fn checked_precision(&self, class: usize) -> Option<f64> {
+        let col_sum = self.col_sum(class);
+        (col_sum > 0).then(|| self.diagonal(class) as f64 / col_sum as f64)
+    }
+
+    /// Recall for a class, or `None` if the class has no actual samples.
+    #[must_use]
+    pub fn checked_recall(&self, class: usize) -> Option<f64> {
+        let row_sum = self.row_sum(class);
+        (row_sum > 0).then(|| self.diagonal(class) as f64 / row_sum as f64)
+    }
+
+    /// Macro-averaged F1 over classes that appear in the matrix, or `None`
+    /// if the matrix is empty.
+    #[must_use]
+    pub fn macro_f1(&self) -> Option<f64> {
+        let classes: Vec<usize> = (0..self.size())
+            .filter(|&c| self.row_sum(c) > 0 || self.col_sum(c) > 0)
+            .collect();
+        if classes.is_empty() {
+            return None;
+        }
+        let sum: f64 = classes.iter().map(|&c| self.f1_score(c)).sum();
+        Some(sum / classes.len() as f64)
+    }
+
+    /// Format a metric as `0.00`, or `—` when undefined.
+    fn format_metric(value: Option<f64>) -> String {
+        value.map_or_else(|| "—".to_string(), |v| format!("{v:.2}"))
+    }
+
     fn normalize_value(&self, row: usize, col: usize, value: u64) -> f64 {
         match self.normalization {
             Normalization::None => {
@@ -335,10 +376,12 @@ impl Widget for ConfusionMatrix {
         let n = self.size();
         let title_rows = if self.title.is_some() { 2 } else { 0 };
 
-        // Width: label column + header labels + cells
-        let width = (label_w + 2 + n * (self.cell_width + 1)) as f32;
-        // Height: title + header row + data rows + accuracy row
-        let height = (title_rows + 1 + n + 1) as f32;
+        let metric_cols = usize::from(self.show_metrics);
+
+        // Width: label column + header labels + cells (+ recall column)
+        let width = (label_w + 2 + (n + metric_cols) * (self.cell_width + 1)) as f32;
+        // Height: title + header row + data rows (+ precision row) + accuracy row
+        let height = (title_rows + 1 + n + metric_cols + 1) as f32;
 
         constraints.constrain(Size::new(width.min(constraints.max_width), height))
     }
@@ -387,6 +430,10 @@ impl Widget for ConfusionMatrix {
             };
             canvas.draw_text(truncated, Point::new(x, y), &header_style);
         }
+        let metrics_x = header_x + (n * (self.cell_width + 1)) as f32;
+        if self.show_metrics {
+            canvas.draw_text("Rec", Point::new(metrics_x, y), &dim_style);
+        }
         y += 1.0;
 
         // Draw matrix rows
@@ -425,12 +472,35 @@ impl Widget for ConfusionMatrix {
                     canvas.draw_text(&text, Point::new(x, y), &value_style);
                 }
             }
+            if self.show_metrics {
+                let recall = Self::format_metric(self.checked_recall(row_idx));
+                canvas.draw_text(&recall, Point::new(metrics_x, y), &dim_style);
+            }
+            y += 1.0;
+        }
+
+        // Draw precision margin
+        if self.show_metrics {
+            canvas.draw_text("Prec", Point::new(self.bounds.x, y), &dim_style);
+            for col_idx in 0..n {
+                let x = header_x + (col_idx * (self.cell_width + 1)) as f32;
+                let precision = Self::format_metric(self.checked_precision(col_idx));
+                canvas.draw_text(&precision, Point::new(x, y), &dim_style);
+            }
             y += 1.0;
         }
 
         // Draw accuracy
         let accuracy = self.accuracy();
-        let acc_text = format!("Accuracy: {:.1}%", accuracy * 100.0);
+        let acc_text = if self.show_metrics {
+            format!(
+                "Accuracy: {:.1}%  F1: {}",
+                accuracy * 100.0,
+                Self::format_metric(self.macro_f1())
+            )
+        } else {
+            format!("Accuracy: {:.1}%", accuracy * 100.0)
+        };
         canvas.draw_text(&acc_text, Point::new(self.bounds.x, y), &header_style);
     }
 
@@ -727,6 +797,68 @@ mod tests {
         assert_eq!(cm.accuracy(), 0.0);
     }
 
+    #[test]
+    fn test_checked_metrics_known_counts() {
+        // Actual 0: 8 right, 2 wrong; actual 1: 4 wrong, 6 right
+        let cm = ConfusionMatrix::new(vec![vec![8, 2], vec![4, 6]]);
+        assert_eq!(cm.checked_recall(0), Some(0.8));
+        assert_eq!(cm.checked_recall(1), Some(0.6));
+        assert_eq!(cm.checked_precision(0), Some(8.0 / 12.0));
+        assert_eq!(cm.checked_precision(1), Some(0.75));
+    }
+
+    #[test]
+    fn test_paint_metrics_margins() {
+        let mut cm = ConfusionMatrix::new(vec![vec![8, 2], vec![4, 6]]).with_metrics(true);
+        cm.layout(Rect::new(0.0, 0.0, 40.0, 10.0));
+        let mut canvas = MockCanvas::new();
+        cm.paint(&mut canvas);
+
+        let text_at = |text: &str| {
+            canvas
+                .texts
+                .iter()
+                .find(|(t, _)| t == text)
+                .map(|(_, p)| (p.x, p.y))
+        };
+        // Recall column right of the cells, one per row
+        assert_eq!(text_at("Rec"), Some((19.0, 0.0)));
+        assert_eq!(text_at("0.80"), Some((19.0, 1.0)));
+        assert_eq!(text_at("0.60"), Some((19.0, 2.0)));
+        // Precision row below the matrix
+        assert_eq!(text_at("0.67"), Some((5.0, 3.0)));
+        assert_eq!(text_at("0.75"), Some((12.0, 3.0)));
+        assert!(canvas
+            .texts
+            .iter()
+            .any(|(t, p)| t.starts_with("Accuracy: 70.0%  F1: 0.70") && p.y == 4.0));
+    }
+
+    #[test]
+    fn test_metrics_empty_class_dash() {
+        let mut cm = ConfusionMatrix::new(vec![vec![5, 0, 0], vec![1, 4, 0], vec![0, 0, 0]])
+            .with_metrics(true);
+        assert_eq!(cm.checked_recall(2), None);
+        assert_eq!(cm.checked_precision(2), None);
+        assert_eq!(cm.macro_f1(), Some((10.0 / 11.0 + 8.0 / 9.0) / 2.0));
+
+        cm.layout(Rect::new(0.0, 0.0, 40.0, 10.0));
+        let mut canvas = MockCanvas::new();
+        cm.paint(&mut canvas);
+        let dashes = canvas.texts.iter().filter(|(t, _)| t == "—").count();
+        assert_eq!(dashes, 2);
+    }
+
+    #[test]
+    fn test_measure_with_metrics() {
+        let cm = ConfusionMatrix::new(vec![vec![8, 2], vec![4, 6]]);
+        let plain = cm.measure(Constraints::loose(Size::new(100.0, 100.0)));
+        let cm = cm.with_metrics(true);
+        let size = cm.measure(Constraints::loose(Size::new(100.0, 100.0)));
+        assert_eq!(size.width, plain.width + 7.0);
+        assert_eq!(size.height, plain.height + 1.0);
+    }
+
     #[test]
     fn test_zero_precision() {
         let cm = ConfusionMatrix::new(vec![vec![0, 0], vec![0, 0]]);
@@ -44,6 +44,31 @@ impl ContainerState {
     }
 }
 
+/// Container runtime, shown as a tag on each row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerRuntime {
+    Docker,
+    Podman,
+}
+
+impl ContainerRuntime {
+    /// Get row tag.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Self::Docker => "docker",
+            Self::Podman => "podman",
+        }
+    }
+
+    /// Get tag color.
+    pub fn color(&self) -> Color {
+        match self {
+            Self::Docker => Color::new(0.1, 0.6, 0.95, 1.0), // Docker blue
+            Self::Podman => Color::new(0.6, 0.4, 0.8, 1.0),  // Podman purple
+        }
+    }
+}
+
 /// A container entry.
 #[derive(Debug, Clone)]
 pub struct ContainerEntry {
@@ -61,6 +86,8 @@ pub struct ContainerEntry {
     pub memory_limit: u64,
     /// Image name.
     pub image: String,
+    /// Runtime managing the container, if known.
+    pub runtime: Option<ContainerRuntime>,
 }
 
 impl ContainerEntry {
@@ -75,6 +102,7 @@ impl ContainerEntry {
             memory_bytes: 0,
             memory_limit: 0,
             image: String::new(),
+            runtime: None,
         }
     }
 
@@ -107,6 +135,13 @@ impl ContainerEntry {
         self
     }
 
+    /// Set container runtime.
+    #[must_use]
+    pub fn with_runtime(mut self, runtime: ContainerRuntime) -> Self {
+        self.runtime = Some(runtime);
+        self
+    }
+
     /// Format memory for display.
     pub fn memory_display(&self) -> String {
         let mb = self.memory_bytes as f64 / 1_048_576.0;
@@ -138,6 +173,8 @@ pub struct ContainersPanel {
     max_containers: usize,
     /// Compact mode (single line per container).
     compact: bool,
+    /// Sort by CPU usage (highest first).
+    sort_by_cpu: bool,
     /// Cached bounds.
     bounds: Rect,
 }
@@ -157,6 +194,7 @@ impl ContainersPanel {
             running_only: true,
             max_containers: 5,
             compact: true,
+            sort_by_cpu: false,
             bounds: Rect::default(),
         }
     }
@@ -194,6 +232,13 @@ impl ContainersPanel {
         self
     }
 
+    /// Sort containers by CPU usage, highest first.
+    #[must_use]
+    pub fn sort_by_cpu(mut self, sort: bool) -> Self {
+        self.sort_by_cpu = sort;
+        self
+    }
+
     /// Get running container count.
     pub fn running_count(&self) -> usize {
         self.containers
@@ -208,11 +253,21 @@ impl ContainersPanel {
     }
 
     /// Get visible containers (filtered).
-    fn visible_containers(&self) -> impl Iterator<Item = &ContainerEntry> {
-        self.containers
+    fn visible_containers(&self) -> Vec<&ContainerEntry> {
+        let mut visible: Vec<_> = self
+            .containers
             .iter()
             .filter(|c| !self.running_only || c.state == ContainerState::Running)
-            .take(self.max_containers)
+            .collect();
+        if self.sort_by_cpu {
+            visible.sort_by(|a, b| {
+                b.cpu_percent
+                    .partial_cmp(&a.cpu_percent)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+        visible.truncate(self.max_containers);
+        visible
     }
 
     /// Draw a container line.
@@ -234,8 +289,22 @@ impl ContainersPanel {
             },
         );
 
+        // Runtime tag
+        let tag_width = container.runtime.map_or(0, |r| r.tag().len() + 1);
+        if let Some(runtime) = container.runtime {
+            canvas.draw_text(
+                runtime.tag(),
+                Point::new(x + 2.0, y),
+                &TextStyle {
+                    color: runtime.color(),
+                    ..Default::default()
+                },
+            );
+        }
+        let name_x = x + 2.0 + tag_width as f32;
+
         // Container name (truncated)
-        let max_name = ((width - 20.0) / 2.0) as usize;
+        let max_name = (((width - 20.0) / 2.0) as usize).saturating_sub(tag_width);
         let name = if container.name.len() > max_name {
             format!("{}...", &container.name[..max_name.saturating_sub(3)])
         } else {
@@ -244,7 +313,7 @@ impl ContainersPanel {
 
         canvas.draw_text(
             &name,
-            Point::new(x + 2.0, y),
+            Point::new(name_x, y),
             &TextStyle {
                 color: Color::WHITE,
                 ..Default::default()
@@ -305,7 +374,7 @@ impl Widget for ContainersPanel {
     }
 
     fn measure(&self, constraints: Constraints) -> Size {
-        let visible = self.visible_containers().count();
+        let visible = self.visible_containers().len();
         let height = (visible as f32).max(1.0).min(constraints.max_height);
         Size::new(constraints.max_width, height)
     }
@@ -568,7 +637,7 @@ mod tests {
             .with_containers(containers)
             .running_only(true);
 
-        assert_eq!(panel.visible_containers().count(), 2);
+        assert_eq!(panel.visible_containers().len(), 2);
     }
 
     #[test]
@@ -582,7 +651,7 @@ mod tests {
             .with_containers(containers)
             .running_only(false);
 
-        assert_eq!(panel.visible_containers().count(), 2);
+        assert_eq!(panel.visible_containers().len(), 2);
     }
 
     #[test]
@@ -609,6 +678,47 @@ mod tests {
             .with_containers(containers)
             .max_containers(3);
 
-        assert_eq!(panel.visible_containers().count(), 3);
+        assert_eq!(panel.visible_containers().len(), 3);
+    }
+
+    #[test]
+    fn test_containers_panel_sort_by_cpu() {
+        let containers = vec![
+            ContainerEntry::new("idle", "a").with_cpu(0.5),
+            ContainerEntry::new("busy", "b").with_cpu(80.0),
+            ContainerEntry::new("mid", "c").with_cpu(12.0),
+        ];
+        let panel = ContainersPanel::new()
+            .with_containers(containers)
+            .sort_by_cpu(true)
+            .max_containers(2);
+
+        let names: Vec<_> = panel
+            .visible_containers()
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["busy", "mid"]);
+    }
+
+    #[test]
+    fn test_containers_panel_paint_runtime_tag() {
+        use crate::direct::{CellBuffer, DirectTerminalCanvas};
+
+        let mut panel = ContainersPanel::new()
+            .with_containers(vec![
+                ContainerEntry::new("web", "a").with_runtime(ContainerRuntime::Podman)
+            ]);
+        panel.layout(Rect::new(0.0, 0.0, 40.0, 1.0));
+
+        let mut buffer = CellBuffer::new(40, 1);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        panel.paint(&mut canvas);
+
+        assert!(buffer.rows_as_strings()[0].starts_with("● podman web"));
+        assert_eq!(
+            buffer.get(2, 0).unwrap().fg,
+            ContainerRuntime::Podman.color()
+        );
     }
 }
@@ -4,8 +4,8 @@
 
 use compact_str::CompactString;
 use presentar_core::{
-    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event, Key,
-    LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
+    delimited::push_record, Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color,
+    Constraints, Event, Key, LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
 };
 use std::any::Any;
 use std::time::Duration;
@@ -99,6 +99,39 @@ impl CellValue {
         }
     }
 
+    /// Get the underlying value as plain text for export.
+    ///
+    /// Visualizations export their source data rather than glyphs: series
+    /// are joined with `;` and status dots use the level name.
+    #[must_use]
+    pub fn plain_text(&self) -> String {
+        fn join<T: std::fmt::Display>(values: &[T]) -> String {
+            values
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(";")
+        }
+
+        match self {
+            Self::Null => String::new(),
+            Self::Bool(b) => b.to_string(),
+            Self::Int64(n) => n.to_string(),
+            Self::Float64(f) | Self::TrendArrow(f) | Self::ProgressBar(f) => f.to_string(),
+            Self::String(s) => s.to_string(),
+            Self::Sparkline(values) | Self::SparkBar(values) => join(values),
+            Self::SparkWinLoss(values) => join(values),
+            Self::MicroBar { value, .. } => value.to_string(),
+            Self::StatusDot(level) => match level {
+                StatusLevel::Ok => "ok",
+                StatusLevel::Warning => "warning",
+                StatusLevel::Critical => "critical",
+                StatusLevel::Unknown => "unknown",
+            }
+            .to_string(),
+        }
+    }
+
     fn render_sparkline(values: &[f64], width: usize) -> String {
         const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
 
@@ -416,6 +449,41 @@ impl DataFrame {
         self.selected_row = row;
     }
 
+    /// Export all rows as RFC 4180 CSV with a header row of column names.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        self.to_delimited(',')
+    }
+
+    /// Export all rows as tab-separated values with a header row.
+    ///
+    /// Fields containing tabs, quotes, or newlines are quoted as in CSV.
+    #[must_use]
+    pub fn to_tsv(&self) -> String {
+        self.to_delimited('\t')
+    }
+
+    fn to_delimited(&self, delimiter: char) -> String {
+        let mut out = String::new();
+        let header: Vec<String> = self.columns.iter().map(|c| c.name.to_string()).collect();
+        push_record(&mut out, &header, delimiter);
+
+        for row in 0..self.row_count() {
+            let fields: Vec<String> = self
+                .columns
+                .iter()
+                .map(|c| {
+                    c.values
+                        .get(row)
+                        .map(CellValue::plain_text)
+                        .unwrap_or_default()
+                })
+                .collect();
+            push_record(&mut out, &fields, delimiter);
+        }
+        out
+    }
+
     fn render_cell(&self, value: &CellValue, width: usize, align: ColumnAlign) -> (String, Color) {
         let (content, color) = value.render(width);
         let padded = match align {
@@ -1453,6 +1521,60 @@ mod tests {
         assert!(debug.contains("String"));
     }
 
+    // ==================== Export Tests ====================
+
+    fn export_frame() -> DataFrame {
+        DataFrame::new()
+            .with_column(Column::from_strings(
+                "name",
+                &["plain", "say \"hi\"", "two\nlines", "a,b"],
+            ))
+            .with_column(Column::from_i64("count", &[1, 2, 3, 4]))
+    }
+
+    #[test]
+    fn test_dataframe_to_csv_header_and_order() {
+        let df = DataFrame::new()
+            .with_column(Column::from_i64("B", &[2]))
+            .with_column(Column::from_f64("A", &[1.5]));
+        assert_eq!(df.to_csv(), "B,A\r\n2,1.5\r\n");
+    }
+
+    #[test]
+    fn test_dataframe_to_csv_embedded_quote() {
+        let csv = export_frame().to_csv();
+        assert!(csv.contains("\"say \"\"hi\"\"\",2\r\n"));
+        assert!(csv.contains("\"a,b\",4\r\n"));
+    }
+
+    #[test]
+    fn test_dataframe_to_csv_embedded_newline() {
+        let csv = export_frame().to_csv();
+        assert!(csv.contains("\"two\nlines\",3\r\n"));
+        // Header plus four records
+        assert_eq!(csv.matches("\r\n").count(), 5);
+    }
+
+    #[test]
+    fn test_dataframe_to_tsv() {
+        let tsv = export_frame().to_tsv();
+        assert!(tsv.starts_with("name\tcount\r\n"));
+        // Commas need no quoting in TSV
+        assert!(tsv.contains("a,b\t4\r\n"));
+        assert!(tsv.contains("\"two\nlines\"\t3\r\n"));
+    }
+
+    #[test]
+    fn test_dataframe_to_csv_visualizations() {
+        let df = DataFrame::new()
+            .with_column(Column::sparkline_from_rows("trend", vec![vec![1.0, 2.5]]))
+            .with_column(
+                Column::new("status").with_values(vec![CellValue::StatusDot(StatusLevel::Warning)]),
+            )
+            .with_column(Column::new("short"));
+        assert_eq!(df.to_csv(), "trend,status,short\r\n1;2.5,warning,\r\n");
+    }
+
     // ==================== render_cell Tests ====================
 
     #[test]
@@ -17,6 +17,7 @@
 //! - Truncate: <10µs per string
 
 use std::borrow::Cow;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 // =============================================================================
 // BYTE FORMATTING (1000 vs 1024 base)
@@ -87,6 +88,32 @@ pub fn format_rate(bytes_per_sec: u64) -> String {
     format!("{}/s", format_bytes_si(bytes_per_sec))
 }
 
+/// Format a fractional bytes/second rate compactly (1024-based, no `/s`)
+///
+/// Intended for narrow columns whose header already names the unit.
+///
+/// # Examples
+/// ```ignore
+/// assert_eq!(format_bytes_rate(512.0), "512B");
+/// assert_eq!(format_bytes_rate(1_572_864.0), "1.5M");
+/// ```
+#[must_use]
+pub fn format_bytes_rate(bytes_per_sec: f64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    const GB: f64 = MB * 1024.0;
+
+    if bytes_per_sec >= GB {
+        format!("{:.1}G", bytes_per_sec / GB)
+    } else if bytes_per_sec >= MB {
+        format!("{:.1}M", bytes_per_sec / MB)
+    } else if bytes_per_sec >= KB {
+        format!("{:.0}K", bytes_per_sec / KB)
+    } else {
+        format!("{bytes_per_sec:.0}B")
+    }
+}
+
 // =============================================================================
 // PERCENTAGE FORMATTING
 // =============================================================================
@@ -241,40 +268,39 @@ pub enum TruncateStrategy {
 
 /// Truncate string to fit within width using specified strategy
 ///
+/// Widths are terminal display columns, so wide (CJK / emoji) glyphs count
+/// as two and are never split.
+///
 /// # Arguments
 /// * `s` - Input string
-/// * `width` - Maximum width in characters
+/// * `width` - Maximum width in display columns
 /// * `strategy` - Truncation strategy
 ///
 /// # Returns
 /// Truncated string with ellipsis if needed
 #[must_use]
 pub fn truncate(s: &str, width: usize, strategy: TruncateStrategy) -> Cow<'_, str> {
-    let char_count = s.chars().count();
-
-    if char_count <= width {
+    if s.width() <= width {
         return Cow::Borrowed(s);
     }
 
-    if width <= 3 {
+    if width < 3 {
         return Cow::Owned("…".repeat(width.min(1)));
     }
 
     match strategy {
         TruncateStrategy::End => {
-            let take = width - 1; // Leave room for ellipsis
-            let truncated: String = s.chars().take(take).collect();
+            let truncated = take_width(s, width - 1); // Leave room for ellipsis
             Cow::Owned(format!("{truncated}…"))
         }
         TruncateStrategy::Start => {
-            let skip = char_count - width + 1;
-            let truncated: String = s.chars().skip(skip).collect();
+            let truncated = take_width_from_end(s, width - 1);
             Cow::Owned(format!("…{truncated}"))
         }
         TruncateStrategy::Middle => {
             let half = (width - 1) / 2;
-            let start: String = s.chars().take(half).collect();
-            let end: String = s.chars().skip(char_count - half).collect();
+            let start = take_width(s, half);
+            let end = take_width_from_end(s, half);
             Cow::Owned(format!("{start}…{end}"))
         }
         TruncateStrategy::Path => truncate_path(s, width),
@@ -282,9 +308,33 @@ pub fn truncate(s: &str, width: usize, strategy: TruncateStrategy) -> Cow<'_, st
     }
 }
 
+/// Longest prefix of `s` that fits within `width` display columns.
+pub(crate) fn take_width(s: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (idx, ch) in s.char_indices() {
+        used += ch.width().unwrap_or(0);
+        if used > width {
+            return &s[..idx];
+        }
+    }
+    s
+}
+
+/// Longest suffix of `s` that fits within `width` display columns.
+fn take_width_from_end(s: &str, width: usize) -> &str {
+    let mut used = 0;
+    for (idx, ch) in s.char_indices().rev() {
+        used += ch.width().unwrap_or(0);
+        if used > width {
+            return &s[idx + ch.len_utf8()..];
+        }
+    }
+    s
+}
+
 /// Path-aware truncation: "/home/user/very/long/path" → "/home/.../path"
 fn truncate_path(path: &str, width: usize) -> Cow<'_, str> {
-    if path.chars().count() <= width {
+    if path.width() <= width {
         return Cow::Borrowed(path);
     }
 
@@ -306,9 +356,8 @@ fn truncate_path(path: &str, width: usize) -> Cow<'_, str> {
     let result = format!("{prefix}{first}/…/{last}");
 
     // If still too long, truncate the result
-    if result.chars().count() > width {
-        let truncated: String = result.chars().take(width - 1).collect();
-        Cow::Owned(format!("{truncated}…"))
+    if result.width() > width {
+        Cow::Owned(simple_truncate(&result, width))
     } else {
         Cow::Owned(result)
     }
@@ -372,8 +421,7 @@ fn build_suffix_from_key_args(key_args: &[&str], max_width: usize) -> String {
 /// Simple end truncation with ellipsis.
 #[inline]
 fn simple_truncate(s: &str, width: usize) -> String {
-    let truncated: String = s.chars().take(width - 1).collect();
-    format!("{truncated}…")
+    format!("{}…", take_width(s, width - 1))
 }
 
 /// Build truncated command from components.
@@ -407,7 +455,7 @@ fn build_command_with_args(
     }
 
     // Final safety: ensure we don't exceed width
-    if result.chars().count() > width {
+    if result.width() > width {
         simple_truncate(&result, width)
     } else {
         result
@@ -427,7 +475,7 @@ fn build_command_with_args(
 /// - `python /home/user/scripts/long/path/script.py --port=8080`
 ///   → `python script.py --port=8080` (width=30)
 fn truncate_command(cmd: &str, width: usize) -> Cow<'_, str> {
-    if cmd.chars().count() <= width {
+    if cmd.width() <= width {
         return Cow::Borrowed(cmd);
     }
 
@@ -481,7 +529,7 @@ pub enum ColumnAlign {
 
 /// Format a value to fit exactly within a column width
 ///
-/// **GUARANTEE**: Output will NEVER exceed `width` characters
+/// **GUARANTEE**: Output will NEVER exceed `width` display columns
 ///
 /// # Arguments
 /// * `value` - The string to format
@@ -496,11 +544,11 @@ pub fn format_column(
     truncate_strategy: TruncateStrategy,
 ) -> String {
     let truncated = truncate(value, width, truncate_strategy);
-    let len = truncated.chars().count();
+    let len = truncated.width();
 
     if len >= width {
-        // Already at or over width, just take exactly width chars
-        truncated.chars().take(width).collect()
+        // Already at or over width, just take what fits
+        take_width(&truncated, width).to_string()
     } else {
         let padding = width - len;
         match align {
@@ -544,221 +592,7 @@ pub fn format_bytes_column(bytes: u64, width: usize) -> String {
 // O(1) FUZZY SEARCH (from pzsh/aprender-shell patterns)
 // =============================================================================
 
-/// Search result with relevance scoring
-#[derive(Debug, Clone)]
-pub struct SearchResult<T> {
-    /// The matched item
-    pub item: T,
-    /// Relevance score (0.0 - 1.0, higher is better)
-    pub score: f32,
-    /// Match positions (for highlighting)
-    pub matches: Vec<usize>,
-}
-
-/// Fast fuzzy search with O(1) amortized lookup via pre-computed index
-///
-/// Performance targets:
-/// - Build: O(n) where n = number of items
-/// - Search: O(m * k) where m = query length, k = average results
-/// - Memory: O(n * `avg_key_length`)
-#[derive(Debug, Clone)]
-pub struct FuzzyIndex<T: Clone> {
-    /// Items to search
-    items: Vec<T>,
-    /// Pre-computed lowercase keys for fast comparison
-    keys: Vec<String>,
-    /// Trigram index for O(1) candidate lookup
-    trigrams: std::collections::HashMap<[u8; 3], Vec<usize>>,
-    /// Character index for single-char queries
-    char_index: std::collections::HashMap<char, Vec<usize>>,
-}
-
-impl<T: Clone> FuzzyIndex<T> {
-    /// Build a fuzzy search index from items
-    ///
-    /// # Arguments
-    /// * `items` - Items to index
-    /// * `key_fn` - Function to extract searchable key from each item
-    pub fn new<F>(items: Vec<T>, key_fn: F) -> Self
-    where
-        F: Fn(&T) -> String,
-    {
-        let keys: Vec<String> = items
-            .iter()
-            .map(|item| key_fn(item).to_lowercase())
-            .collect();
-
-        let mut trigrams: std::collections::HashMap<[u8; 3], Vec<usize>> =
-            std::collections::HashMap::new();
-        let mut char_index: std::collections::HashMap<char, Vec<usize>> =
-            std::collections::HashMap::new();
-
-        for (idx, key) in keys.iter().enumerate() {
-            // Build character index
-            for ch in key.chars() {
-                char_index.entry(ch).or_default().push(idx);
-            }
-
-            // Build trigram index
-            let bytes = key.as_bytes();
-            if bytes.len() >= 3 {
-                for window in bytes.windows(3) {
-                    let trigram: [u8; 3] = [window[0], window[1], window[2]];
-                    trigrams.entry(trigram).or_default().push(idx);
-                }
-            }
-        }
-
-        // Deduplicate indices
-        for indices in trigrams.values_mut() {
-            indices.sort_unstable();
-            indices.dedup();
-        }
-        for indices in char_index.values_mut() {
-            indices.sort_unstable();
-            indices.dedup();
-        }
-
-        Self {
-            items,
-            keys,
-            trigrams,
-            char_index,
-        }
-    }
-
-    /// Search for items matching query
-    ///
-    /// # Arguments
-    /// * `query` - Search query (case-insensitive)
-    /// * `limit` - Maximum results to return
-    ///
-    /// # Returns
-    /// Sorted results by relevance (highest first)
-    #[must_use]
-    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult<T>> {
-        if query.is_empty() {
-            return Vec::new();
-        }
-
-        let query_lower = query.to_lowercase();
-        let query_chars: Vec<char> = query_lower.chars().collect();
-
-        // Get candidate indices
-        let candidates = self.get_candidates(&query_lower);
-
-        // Score and filter candidates
-        let mut results: Vec<SearchResult<T>> = candidates
-            .into_iter()
-            .filter_map(|idx| {
-                let key = &self.keys[idx];
-                let (score, matches) = self.score_match(key, &query_chars);
-                if score > 0.0 {
-                    Some(SearchResult {
-                        item: self.items[idx].clone(),
-                        score,
-                        matches,
-                    })
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        // Sort by score (descending)
-        results.sort_by(|a, b| {
-            b.score
-                .partial_cmp(&a.score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        results.truncate(limit);
-
-        results
-    }
-
-    /// Get candidate indices using index
-    fn get_candidates(&self, query: &str) -> Vec<usize> {
-        let bytes = query.as_bytes();
-
-        // Use trigram index if query is long enough
-        if bytes.len() >= 3 {
-            let trigram: [u8; 3] = [bytes[0], bytes[1], bytes[2]];
-            if let Some(indices) = self.trigrams.get(&trigram) {
-                return indices.clone();
-            }
-        }
-
-        // Fall back to character index
-        if let Some(first_char) = query.chars().next() {
-            if let Some(indices) = self.char_index.get(&first_char) {
-                return indices.clone();
-            }
-        }
-
-        // Fall back to scanning all items
-        (0..self.items.len()).collect()
-    }
-
-    /// Score a match using fuzzy substring matching
-    fn score_match(&self, key: &str, query_chars: &[char]) -> (f32, Vec<usize>) {
-        if query_chars.is_empty() {
-            return (0.0, Vec::new());
-        }
-
-        let key_chars: Vec<char> = key.chars().collect();
-        let mut matches = Vec::new();
-        let mut query_idx = 0;
-
-        // Find subsequence matches
-        for (key_idx, &key_char) in key_chars.iter().enumerate() {
-            if query_idx < query_chars.len() && key_char == query_chars[query_idx] {
-                matches.push(key_idx);
-                query_idx += 1;
-            }
-        }
-
-        // Must match all query characters
-        if query_idx != query_chars.len() {
-            return (0.0, Vec::new());
-        }
-
-        // Calculate score based on:
-        // - Consecutive matches (bonus)
-        // - Position of first match (earlier is better)
-        // - Match density (matches / key length)
-
-        let mut score = 1.0;
-
-        // Consecutive bonus
-        let mut consecutive = 0;
-        for i in 1..matches.len() {
-            if matches[i] == matches[i - 1] + 1 {
-                consecutive += 1;
-            }
-        }
-        score += consecutive as f32 * 0.1;
-
-        // Early match bonus
-        if !matches.is_empty() {
-            score += (1.0 - matches[0] as f32 / key_chars.len() as f32) * 0.3;
-        }
-
-        // Exact prefix bonus
-        if key.starts_with(&query_chars.iter().collect::<String>()) {
-            score += 0.5;
-        }
-
-        // Exact match bonus
-        if key_chars.len() == query_chars.len() {
-            score += 1.0;
-        }
-
-        // Density factor
-        score *= query_chars.len() as f32 / key_chars.len() as f32;
-
-        (score.min(1.0), matches)
-    }
-}
+pub use presentar_core::fuzzy::{FuzzyIndex, SearchResult};
 
 // =============================================================================
 // TESTS
@@ -804,6 +638,14 @@ mod tests {
         assert_eq!(format_rate(1_000_000), "1.00M/s");
     }
 
+    #[test]
+    fn test_format_bytes_rate() {
+        assert_eq!(format_bytes_rate(0.0), "0B");
+        assert_eq!(format_bytes_rate(2048.0), "2K");
+        assert_eq!(format_bytes_rate(1_572_864.0), "1.5M");
+        assert_eq!(format_bytes_rate(1024.0 * 1024.0 * 1024.0), "1.0G");
+    }
+
     // =========================================================================
     // PERCENTAGE FORMATTING TESTS
     // =========================================================================
@@ -940,7 +782,33 @@ mod tests {
     #[test]
     fn test_truncate_very_short() {
         assert_eq!(truncate("hello", 2, TruncateStrategy::End), "…");
-        assert_eq!(truncate("hello", 3, TruncateStrategy::End), "…");
+        assert_eq!(truncate("hello", 3, TruncateStrategy::End), "he…");
+    }
+
+    #[test]
+    fn test_truncate_wide_chars() {
+        assert_eq!("日本".width(), 4);
+        assert_eq!(truncate("日本", 4, TruncateStrategy::End), "日本");
+        assert_eq!(truncate("日本", 3, TruncateStrategy::End), "日…");
+        // A wide glyph is never split across the boundary.
+        assert_eq!(truncate("日本語", 4, TruncateStrategy::End), "日…");
+        assert_eq!(truncate("日本語", 5, TruncateStrategy::Start), "…本語");
+    }
+
+    #[test]
+    fn test_format_column_wide_chars() {
+        let col = format_column(
+            "日本語テキスト",
+            6,
+            ColumnAlign::Left,
+            TruncateStrategy::End,
+        );
+        assert_eq!(col, "日本… ");
+        assert_eq!(col.width(), 6);
+        assert_eq!(
+            format_column("日本", 6, ColumnAlign::Right, TruncateStrategy::End),
+            "  日本"
+        );
     }
 
     #[test]
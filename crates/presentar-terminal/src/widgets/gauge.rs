@@ -49,6 +49,10 @@ pub struct Gauge {
     show_value: bool,
     /// Unit suffix (e.g., "°C", "%").
     unit: Option<String>,
+    /// Goal value marked with a tick.
+    target: Option<f64>,
+    /// Colored bands as (upper bound fraction, color), ascending.
+    bands: Vec<(f32, Color)>,
     /// Cached bounds.
     bounds: Rect,
 }
@@ -73,6 +77,8 @@ impl Gauge {
             critical_threshold: 90.0,
             show_value: true,
             unit: None,
+            target: None,
+            bands: Vec::new(),
             bounds: Rect::default(),
         }
     }
@@ -134,6 +140,35 @@ impl Gauge {
         self
     }
 
+    /// Mark a target/goal value with a tick on the track.
+    #[must_use]
+    pub fn with_target(mut self, value: f64) -> Self {
+        self.target = Some(value.clamp(0.0, self.max));
+        self
+    }
+
+    /// Segment the track into colored bands.
+    ///
+    /// Each band is `(upper, color)` where `upper` is a fraction of max
+    /// (0.0-1.0); a band covers everything above the previous bound.
+    /// Bands override the warn/critical threshold colors.
+    #[must_use]
+    pub fn with_bands(mut self, mut bands: Vec<(f32, Color)>) -> Self {
+        bands.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.bands = bands;
+        self
+    }
+
+    /// Color of the band containing `fraction` (0.0-1.0) of max.
+    #[must_use]
+    pub fn band_color(&self, fraction: f64) -> Option<Color> {
+        self.bands
+            .iter()
+            .find(|(upper, _)| fraction <= f64::from(*upper))
+            .or_else(|| self.bands.last())
+            .map(|&(_, color)| color)
+    }
+
     /// Update the value.
     pub fn set_value(&mut self, value: f64) {
         self.value = value.clamp(0.0, self.max);
@@ -159,6 +194,9 @@ impl Gauge {
     #[must_use]
     pub fn current_color(&self) -> Color {
         let pct = self.percent();
+        if let Some(color) = self.band_color(pct / 100.0) {
+            return color;
+        }
         if pct >= self.critical_threshold {
             Color::new(1.0, 0.3, 0.3, 1.0) // Red
         } else if pct >= self.warn_threshold {
@@ -168,6 +206,86 @@ impl Gauge {
         }
     }
 
+    /// Cell index of the target tick on a track of `cells` cells.
+    fn target_cell(&self, cells: usize) -> Option<usize> {
+        let target = self.target?;
+        if cells == 0 || self.max <= 0.0 {
+            return None;
+        }
+        let idx = (target / self.max * cells as f64) as usize;
+        Some(idx.min(cells - 1))
+    }
+
+    /// Color of track cell `i` of `cells`: its band color, dimmed when
+    /// unfilled, or `base` without bands.
+    fn cell_color(&self, i: usize, cells: usize, filled: bool, base: Color) -> Color {
+        let fraction = (i as f64 + 0.5) / cells as f64;
+        match self.band_color(fraction) {
+            Some(c) if filled => c,
+            Some(c) => Color::new(c.r * 0.4, c.g * 0.4, c.b * 0.4, c.a),
+            None => base,
+        }
+    }
+
+    /// Draw a horizontal track `left` + `cells` + `right` at `pos`, with
+    /// the first `filled` cells heavy, band colors and the target tick.
+    fn draw_track(
+        &self,
+        canvas: &mut dyn Canvas,
+        pos: Point,
+        (left, right): (&str, &str),
+        cells: usize,
+        filled: usize,
+        style: &TextStyle,
+    ) {
+        let glyph = |i: usize| if i < filled { '━' } else { '─' };
+        let tick = self.target_cell(cells);
+
+        if self.bands.is_empty() && tick.is_none() {
+            let mut line = String::with_capacity(cells + 2);
+            line.push_str(left);
+            line.extend((0..cells).map(glyph));
+            line.push_str(right);
+            canvas.draw_text(&line, pos, style);
+            return;
+        }
+
+        let left_width = left.chars().count();
+        if !left.is_empty() {
+            canvas.draw_text(left, pos, style);
+        }
+        // Group consecutive cells of the same color into runs
+        let mut start = 0;
+        while start < cells {
+            let color = self.cell_color(start, cells, start < filled, style.color);
+            let mut run = String::new();
+            let mut end = start;
+            while end < cells && self.cell_color(end, cells, end < filled, style.color) == color {
+                run.push(glyph(end));
+                end += 1;
+            }
+            let run_style = TextStyle {
+                color,
+                ..style.clone()
+            };
+            let x = pos.x + (left_width + start) as f32;
+            canvas.draw_text(&run, Point::new(x, pos.y), &run_style);
+            start = end;
+        }
+        if let Some(i) = tick {
+            let tick_style = TextStyle {
+                color: Color::new(0.9, 0.9, 0.9, 1.0),
+                ..style.clone()
+            };
+            let x = pos.x + (left_width + i) as f32;
+            canvas.draw_text("┃", Point::new(x, pos.y), &tick_style);
+        }
+        if !right.is_empty() {
+            let x = pos.x + (left_width + cells) as f32;
+            canvas.draw_text(right, Point::new(x, pos.y), style);
+        }
+    }
+
     fn render_arc(&self, canvas: &mut dyn Canvas) {
         let width = self.bounds.width as usize;
         let height = self.bounds.height as usize;
@@ -194,17 +312,14 @@ impl Gauge {
         let filled = ((pct * arc_width as f64).round() as usize).min(arc_width);
 
         // Top of arc: ╭───╮
-        let mut top = String::with_capacity(width);
-        top.push('╭');
-        for i in 0..arc_width {
-            if i < filled {
-                top.push('━');
-            } else {
-                top.push('─');
-            }
-        }
-        top.push('╮');
-        canvas.draw_text(&top, Point::new(self.bounds.x, self.bounds.y), &style);
+        self.draw_track(
+            canvas,
+            Point::new(self.bounds.x, self.bounds.y),
+            ("╭", "╮"),
+            arc_width,
+            filled,
+            &style,
+        );
 
         // Middle: │ value │
         if height > 2 {
@@ -214,14 +329,11 @@ impl Gauge {
             } else {
                 String::new()
             };
-            let padding = (arc_width.saturating_sub(value_text.len())) / 2;
+            let value_len = value_text.chars().count().min(arc_width);
+            let padding = (arc_width - value_len) / 2;
             let mut middle = String::with_capacity(width);
             middle.push('│');
-            for _ in 0..padding {
-                middle.push(' ');
-            }
-            middle.push_str(&value_text);
-            for _ in 0..(arc_width - padding - value_text.len()) {
+            for _ in 0..arc_width {
                 middle.push(' ');
             }
             middle.push('│');
@@ -230,6 +342,14 @@ impl Gauge {
                 Point::new(self.bounds.x, self.bounds.y + 1.0),
                 &dim_style,
             );
+            // Readout in the current (band/threshold) color
+            if !value_text.is_empty() {
+                canvas.draw_text(
+                    &value_text,
+                    Point::new(self.bounds.x + 1.0 + padding as f32, self.bounds.y + 1.0),
+                    &style,
+                );
+            }
         }
 
         // Bottom of arc: ╰───╯
@@ -284,12 +404,26 @@ impl Gauge {
         for i in 0..height {
             let y = self.bounds.y + (height - 1 - i) as f32;
             if i < filled {
-                canvas.draw_text("█", Point::new(self.bounds.x, y), &style);
+                let cell_style = TextStyle {
+                    color: self.cell_color(i, height, true, color),
+                    ..Default::default()
+                };
+                canvas.draw_text("█", Point::new(self.bounds.x, y), &cell_style);
             } else {
-                canvas.draw_text("░", Point::new(self.bounds.x, y), &dim_style);
+                let cell_style = TextStyle {
+                    color: self.cell_color(i, height, false, dim_style.color),
+                    ..Default::default()
+                };
+                canvas.draw_text("░", Point::new(self.bounds.x, y), &cell_style);
             }
         }
 
+        // Target tick beside the bar
+        if let Some(i) = self.target_cell(height) {
+            let y = self.bounds.y + (height - 1 - i) as f32;
+            canvas.draw_text("◂", Point::new(self.bounds.x + 1.0, y), &style);
+        }
+
         // Value at bottom
         if self.show_value {
             let unit = self.unit.as_deref().unwrap_or("");
@@ -346,16 +480,14 @@ impl Gauge {
 
         // Quarter arc: ───╮
         //                 │
-        let mut top = String::with_capacity(width);
-        for i in 0..arc_width {
-            if i < filled {
-                top.push('━');
-            } else {
-                top.push('─');
-            }
-        }
-        top.push('╮');
-        canvas.draw_text(&top, Point::new(self.bounds.x, self.bounds.y), &style);
+        self.draw_track(
+            canvas,
+            Point::new(self.bounds.x, self.bounds.y),
+            ("", "╮"),
+            arc_width,
+            filled,
+            &style,
+        );
 
         // Vertical part
         if height > 1 {
@@ -411,17 +543,14 @@ impl Gauge {
         let filled = ((pct * arc_width as f64).round() as usize).min(arc_width);
 
         // Half arc (bottom semicircle): ╰───╯
-        let mut arc = String::with_capacity(width);
-        arc.push('╰');
-        for i in 0..arc_width {
-            if i < filled {
-                arc.push('━');
-            } else {
-                arc.push('─');
-            }
-        }
-        arc.push('╯');
-        canvas.draw_text(&arc, Point::new(self.bounds.x, self.bounds.y), &style);
+        self.draw_track(
+            canvas,
+            Point::new(self.bounds.x, self.bounds.y),
+            ("╰", "╯"),
+            arc_width,
+            filled,
+            &style,
+        );
 
         // Value above arc
         if self.show_value && height > 1 {
@@ -481,16 +610,14 @@ impl Gauge {
 
         // Top section (horizontal part with right corner)
         let top_filled = ((pct * arc_width as f64).round() as usize).min(arc_width);
-        let mut top = String::with_capacity(width);
-        for i in 0..arc_width {
-            if i < top_filled {
-                top.push('━');
-            } else {
-                top.push('─');
-            }
-        }
-        top.push('╮');
-        canvas.draw_text(&top, Point::new(self.bounds.x, self.bounds.y), &style);
+        self.draw_track(
+            canvas,
+            Point::new(self.bounds.x, self.bounds.y),
+            ("", "╮"),
+            arc_width,
+            top_filled,
+            &style,
+        );
 
         // Middle section (vertical bar on right)
         if height > 2 {
@@ -649,19 +776,24 @@ mod tests {
 
     struct MockCanvas {
         texts: Vec<(String, Point)>,
+        colors: Vec<Color>,
     }
 
     impl MockCanvas {
         fn new() -> Self {
-            Self { texts: vec![] }
+            Self {
+                texts: vec![],
+                colors: vec![],
+            }
         }
     }
 
     impl Canvas for MockCanvas {
         fn fill_rect(&mut self, _rect: Rect, _color: Color) {}
         fn stroke_rect(&mut self, _rect: Rect, _color: Color, _width: f32) {}
-        fn draw_text(&mut self, text: &str, position: Point, _style: &TextStyle) {
+        fn draw_text(&mut self, text: &str, position: Point, style: &TextStyle) {
             self.texts.push((text.to_string(), position));
+            self.colors.push(style.color);
         }
         fn draw_line(&mut self, _from: Point, _to: Point, _color: Color, _width: f32) {}
         fn fill_circle(&mut self, _center: Point, _radius: f32, _color: Color) {}
@@ -784,6 +916,71 @@ mod tests {
         assert!(color.g < 0.5);
     }
 
+    fn green() -> Color {
+        Color::new(0.3, 0.9, 0.4, 1.0)
+    }
+
+    fn amber() -> Color {
+        Color::new(1.0, 0.7, 0.2, 1.0)
+    }
+
+    #[test]
+    fn test_gauge_band_color_readout() {
+        let mut gauge = Gauge::new(0.9, 1.0)
+            .with_mode(GaugeMode::Arc)
+            .with_bands(vec![(1.0, amber()), (0.8, green())]);
+        assert_eq!(gauge.current_color(), amber());
+
+        gauge.bounds = Rect::new(0.0, 0.0, 10.0, 4.0);
+        let mut canvas = MockCanvas::new();
+        gauge.paint(&mut canvas);
+        let readout = canvas
+            .texts
+            .iter()
+            .position(|(t, _)| t == "1")
+            .expect("readout drawn");
+        assert_eq!(canvas.colors[readout], amber());
+
+        gauge.set_value(0.5);
+        assert_eq!(gauge.current_color(), green());
+    }
+
+    #[test]
+    fn test_gauge_bands_segment_track() {
+        let mut gauge = Gauge::new(100.0, 100.0)
+            .with_mode(GaugeMode::Half)
+            .with_bands(vec![(0.5, green()), (1.0, amber())]);
+        gauge.bounds = Rect::new(0.0, 0.0, 10.0, 2.0);
+        let mut canvas = MockCanvas::new();
+        gauge.paint(&mut canvas);
+        // ╰ + green run + amber run + ╯
+        assert!(canvas
+            .texts
+            .iter()
+            .zip(&canvas.colors)
+            .any(|((t, p), c)| t == "━━━━" && p.x == 1.0 && *c == green()));
+        assert!(canvas
+            .texts
+            .iter()
+            .zip(&canvas.colors)
+            .any(|((t, p), c)| t == "━━━━" && p.x == 5.0 && *c == amber()));
+    }
+
+    #[test]
+    fn test_gauge_target_tick() {
+        let mut gauge = Gauge::percentage(50.0)
+            .with_mode(GaugeMode::Arc)
+            .with_target(75.0);
+        gauge.bounds = Rect::new(0.0, 0.0, 10.0, 4.0);
+        let mut canvas = MockCanvas::new();
+        gauge.paint(&mut canvas);
+        // 8 track cells: 75% lands in cell 6, after the ╭ corner
+        assert!(canvas
+            .texts
+            .iter()
+            .any(|(t, p)| t == "┃" && p.x == 7.0 && p.y == 0.0));
+    }
+
     #[test]
     fn test_gauge_paint_arc() {
         let mut gauge = Gauge::default().with_mode(GaugeMode::Arc);
@@ -159,6 +159,8 @@ pub struct GpuProcess {
     pub pid: u32,
     /// GPU memory used in bytes.
     pub vram_used: u64,
+    /// SM (shader) utilization percentage, if reported.
+    pub utilization: Option<f32>,
 }
 
 impl GpuProcess {
@@ -169,9 +171,17 @@ impl GpuProcess {
             name: name.into(),
             pid,
             vram_used: vram,
+            utilization: None,
         }
     }
 
+    /// Set SM utilization percentage.
+    #[must_use]
+    pub fn with_utilization(mut self, util: f32) -> Self {
+        self.utilization = Some(util);
+        self
+    }
+
     /// Format VRAM for display.
     pub fn vram_display(&self) -> String {
         let mb = self.vram_used / (1024 * 1024);
@@ -355,6 +365,7 @@ impl GpuPanel {
 
     /// Draw top processes.
     fn draw_processes(&self, canvas: &mut dyn Canvas, start_y: f32) {
+        use std::fmt::Write as _;
         if !self.show_processes || self.processes.is_empty() {
             return;
         }
@@ -375,7 +386,10 @@ impl GpuPanel {
                 proc.name.clone()
             };
 
-            let line = format!("{:<12} {:>6}", name, proc.vram_display());
+            let mut line = format!("{:<12} {:>6}", name, proc.vram_display());
+            if let Some(util) = proc.utilization {
+                let _ = write!(line, " {util:>3.0}%");
+            }
             canvas.draw_text(
                 &line,
                 Point::new(x, y),
@@ -644,6 +658,28 @@ mod tests {
         panel.paint(&mut canvas);
     }
 
+    #[test]
+    fn test_gpu_panel_paint_process_utilization() {
+        use crate::direct::{CellBuffer, DirectTerminalCanvas};
+
+        let processes = vec![
+            GpuProcess::new("trainer", 1, 2 * 1024 * 1024 * 1024).with_utilization(87.0),
+            GpuProcess::new("idle", 2, 64 * 1024 * 1024),
+        ];
+        let mut panel = GpuPanel::new()
+            .with_device(GpuDevice::new("GPU"))
+            .with_processes(processes);
+        panel.layout(Rect::new(0.0, 0.0, 40.0, 4.0));
+
+        let mut buffer = CellBuffer::new(40, 4);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        panel.paint(&mut canvas);
+
+        let rows = buffer.rows_as_strings();
+        assert_eq!(rows[1].trim_end(), "trainer        2.0G  87%");
+        assert_eq!(rows[2].trim_end(), "idle            64M");
+    }
+
     #[test]
     fn test_gpu_panel_paint_without_processes() {
         use crate::direct::{CellBuffer, DirectTerminalCanvas};
@@ -24,6 +24,18 @@ pub enum HeatmapPalette {
     Grayscale,
     /// Single color intensity.
     Mono(u8, u8, u8),
+    /// Diverging scheme for signed data: `low` → `mid` → `high`.
+    ///
+    /// Pair with [`Heatmap::with_center`] so the neutral `mid` color marks
+    /// the center value.
+    Diverging {
+        /// Color at the minimum.
+        low: (u8, u8, u8),
+        /// Color at the center.
+        mid: (u8, u8, u8),
+        /// Color at the maximum.
+        high: (u8, u8, u8),
+    },
 }
 
 impl HeatmapPalette {
@@ -71,6 +83,23 @@ impl HeatmapPalette {
                 let b = (*b as f32 / 255.0) * t;
                 Color::new(r, g, b, 1.0)
             }
+            Self::Diverging { low, mid, high } => {
+                let (from, to, s) = if t < 0.5 {
+                    (mid, low, (0.5 - t) * 2.0)
+                } else {
+                    (mid, high, (t - 0.5) * 2.0)
+                };
+                let channel = |a: u8, b: u8| {
+                    let (a, b) = (f32::from(a) / 255.0, f32::from(b) / 255.0);
+                    (b - a).mul_add(s, a)
+                };
+                Color::new(
+                    channel(from.0, to.0),
+                    channel(from.1, to.1),
+                    channel(from.2, to.2),
+                    1.0,
+                )
+            }
         }
     }
 }
@@ -116,6 +145,8 @@ pub struct Heatmap {
     min: f64,
     /// Maximum value for normalization.
     max: f64,
+    /// Value mapped to the middle of the palette.
+    center: Option<f64>,
     /// Show cell values.
     show_values: bool,
     /// Cell width in characters.
@@ -144,6 +175,7 @@ impl Heatmap {
             palette: HeatmapPalette::default(),
             min,
             max,
+            center: None,
             show_values: false,
             cell_width: 4,
             cell_height: 1,
@@ -190,6 +222,22 @@ impl Heatmap {
         self
     }
 
+    /// Anchor the middle of the palette at `value`.
+    ///
+    /// Values are scaled by their distance from the center, so equal offsets
+    /// above and below map symmetrically around the palette midpoint.
+    #[must_use]
+    pub fn with_center(mut self, value: f64) -> Self {
+        self.center = Some(value);
+        self
+    }
+
+    /// Get the palette color for a value.
+    #[must_use]
+    pub fn color_for(&self, value: f64) -> Color {
+        self.palette.color(self.normalize(value))
+    }
+
     /// Show cell values.
     #[must_use]
     pub fn with_values(mut self, show: bool) -> Self {
@@ -236,6 +284,15 @@ impl Heatmap {
     }
 
     fn normalize(&self, value: f64) -> f64 {
+        if let Some(center) = self.center {
+            let half = (self.max - center).abs().max((center - self.min).abs());
+            return if half < f64::EPSILON {
+                0.5
+            } else {
+                (0.5 + (value - center) / (2.0 * half)).clamp(0.0, 1.0)
+            };
+        }
+
         let range = self.max - self.min;
         if range.abs() < f64::EPSILON {
             0.5
@@ -562,6 +619,60 @@ mod tests {
         assert!((full.r - 1.0).abs() < 0.01);
     }
 
+    const DIVERGING: HeatmapPalette = HeatmapPalette::Diverging {
+        low: (0, 0, 255),
+        mid: (255, 255, 255),
+        high: (255, 0, 0),
+    };
+
+    fn color_distance(a: Color, b: Color) -> f32 {
+        ((a.r - b.r).powi(2) + (a.g - b.g).powi(2) + (a.b - b.b).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn test_palette_diverging_endpoints() {
+        assert_eq!(DIVERGING.color(0.0), Color::new(0.0, 0.0, 1.0, 1.0));
+        assert_eq!(DIVERGING.color(0.5), Color::WHITE);
+        assert_eq!(DIVERGING.color(1.0), Color::new(1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_heatmap_center_maps_to_mid() {
+        // Skewed data: the center is not the midpoint of min and max
+        let heatmap = Heatmap::from_values(vec![vec![-0.2, 0.0, 1.0]])
+            .with_palette(DIVERGING)
+            .with_center(0.0);
+        assert_eq!(heatmap.color_for(0.0), Color::WHITE);
+        assert!(heatmap.color_for(-0.2).b > heatmap.color_for(-0.2).r);
+        assert_eq!(heatmap.color_for(1.0), Color::new(1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_heatmap_diverging_symmetric() {
+        let heatmap = Heatmap::from_values(vec![vec![-1.0, 1.0]])
+            .with_palette(DIVERGING)
+            .with_center(0.0);
+        let mid = heatmap.color_for(0.0);
+        let below = heatmap.color_for(-0.5);
+        let above = heatmap.color_for(0.5);
+
+        assert_ne!(below, above);
+        let (d_below, d_above) = (color_distance(below, mid), color_distance(above, mid));
+        assert!(d_below > 0.0);
+        assert!((d_below - d_above).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_heatmap_center_paint() {
+        let mut heatmap = Heatmap::from_values(vec![vec![-1.0, 0.0, 3.0]])
+            .with_palette(DIVERGING)
+            .with_center(0.0);
+        heatmap.bounds = Rect::new(0.0, 0.0, 12.0, 1.0);
+        let mut canvas = MockCanvas::new();
+        heatmap.paint(&mut canvas);
+        assert_eq!(canvas.rects[1].1, Color::WHITE);
+    }
+
     #[test]
     fn test_heatmap_cell_with_label() {
         let cell = HeatmapCell::with_label(5.0, "test");
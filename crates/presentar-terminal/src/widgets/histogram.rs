@@ -3,6 +3,7 @@
 //! Implements P202 from SPEC-024 Section 15.2.
 
 use crate::theme::Gradient;
+use crate::widgets::line_chart::{decade_label, AxisScale};
 use presentar_core::{
     Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
     LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
@@ -58,6 +59,8 @@ pub struct Histogram {
     color: Color,
     gradient: Option<Gradient>,
     show_labels: bool,
+    /// Scale of the count axis.
+    y_scale: AxisScale,
     bounds: Rect,
     /// Computed bin edges and counts.
     computed_bins: Vec<(f64, f64, usize)>, // (start, end, count)
@@ -75,6 +78,7 @@ impl Histogram {
             color: Color::new(0.3, 0.7, 1.0, 1.0),
             gradient: None,
             show_labels: true,
+            y_scale: AxisScale::default(),
             bounds: Rect::default(),
             computed_bins: Vec::new(),
         };
@@ -125,6 +129,36 @@ impl Histogram {
         self
     }
 
+    /// Set the count axis scale.
+    ///
+    /// Under [`AxisScale::Log10`] bar lengths follow `log10(count)` up to the
+    /// next decade; single-count bins sit on the axis minimum and are drawn
+    /// as a minimal stub so they stay visible.
+    #[must_use]
+    pub fn with_y_scale(mut self, scale: AxisScale) -> Self {
+        self.y_scale = scale;
+        self
+    }
+
+    /// Get the decade exponent at the top of a log count axis.
+    fn log_top(max_count: usize) -> i32 {
+        ((max_count.max(1) as f64).log10().ceil() as i32).max(1)
+    }
+
+    /// Bar length in cells for a bin count along an axis of `extent` cells.
+    fn bar_length(&self, count: usize, max_count: usize, extent: f32) -> f32 {
+        if count == 0 || max_count == 0 {
+            return 0.0;
+        }
+        match self.y_scale {
+            AxisScale::Linear => (count as f32 / max_count as f32) * extent,
+            AxisScale::Log10 => {
+                let frac = (count as f64).log10() / f64::from(Self::log_top(max_count));
+                (frac as f32 * extent).max(0.125)
+            }
+        }
+    }
+
     /// Update data.
     pub fn set_data(&mut self, data: Vec<f64>) {
         self.data = data;
@@ -340,13 +374,17 @@ impl Histogram {
                 ..Default::default()
             };
 
+            let (top, bottom) = match self.y_scale {
+                AxisScale::Linear => (max_count.to_string(), "0".to_string()),
+                AxisScale::Log10 => (decade_label(Self::log_top(max_count)), "1".to_string()),
+            };
             canvas.draw_text(
-                &format!("{max_count:>4}"),
+                &format!("{top:>4}"),
                 Point::new(self.bounds.x, plot_y),
                 &label_style,
             );
             canvas.draw_text(
-                "   0",
+                &format!("{bottom:>4}"),
                 Point::new(self.bounds.x, plot_y + plot_height - 1.0),
                 &label_style,
             );
@@ -354,11 +392,7 @@ impl Histogram {
 
         // Draw bars
         for (i, &(start, _end, count)) in self.computed_bins.iter().enumerate() {
-            let bar_height = if max_count > 0 {
-                (count as f32 / max_count as f32) * plot_height
-            } else {
-                0.0
-            };
+            let bar_height = self.bar_length(count, max_count, plot_height);
 
             let x = plot_x + i as f32 * bar_width;
             let y = plot_y + plot_height - bar_height;
@@ -440,11 +474,7 @@ impl Histogram {
         let bar_height = (plot_height / n_bins as f32).max(1.0);
 
         for (i, &(start, _end, count)) in self.computed_bins.iter().enumerate() {
-            let bar_width = if max_count > 0 {
-                (count as f32 / max_count as f32) * plot_width
-            } else {
-                0.0
-            };
+            let bar_width = self.bar_length(count, max_count, plot_width);
 
             let x = plot_x;
             let y = plot_y + i as f32 * bar_height;
@@ -553,6 +583,44 @@ mod tests {
         assert!(!fd.computed_bins.is_empty());
     }
 
+    #[test]
+    fn test_histogram_log_scale_bar_length() {
+        let hist = Histogram::new(vec![1.0]).with_y_scale(AxisScale::Log10);
+        // max 1000 -> three decades; each decade is a third of the extent
+        assert!((hist.bar_length(10, 1000, 9.0) - 3.0).abs() < 1e-4);
+        assert!((hist.bar_length(100, 1000, 9.0) - 6.0).abs() < 1e-4);
+        assert!((hist.bar_length(1000, 1000, 9.0) - 9.0).abs() < 1e-4);
+        // Single counts clamp to a visible stub, empty bins draw nothing
+        assert_eq!(hist.bar_length(1, 1000, 9.0), 0.125);
+        assert_eq!(hist.bar_length(0, 1000, 9.0), 0.0);
+    }
+
+    #[test]
+    fn test_histogram_linear_bar_length() {
+        let hist = Histogram::new(vec![1.0]);
+        assert_eq!(hist.bar_length(5, 10, 8.0), 4.0);
+        assert_eq!(hist.bar_length(0, 10, 8.0), 0.0);
+    }
+
+    #[test]
+    fn test_histogram_log_scale_labels() {
+        use crate::direct::{CellBuffer, DirectTerminalCanvas};
+
+        let data: Vec<f64> = (0..150).map(|i| if i < 120 { 0.0 } else { 9.0 }).collect();
+        let mut hist = Histogram::new(data)
+            .with_bins(BinStrategy::Count(2))
+            .with_y_scale(AxisScale::Log10);
+        hist.layout(Rect::new(0.0, 0.0, 20.0, 8.0));
+        let mut buffer = CellBuffer::new(20, 8);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        hist.paint(&mut canvas);
+
+        let top: String = (0..4)
+            .filter_map(|x| buffer.get(x, 0).map(|c| c.symbol.to_string()))
+            .collect();
+        assert_eq!(top.trim(), "1000");
+    }
+
     #[test]
     fn test_empty_data() {
         let hist = Histogram::new(vec![]);
@@ -49,6 +49,47 @@ pub enum LineStyle {
     Markers,
 }
 
+/// Mapping from data values to axis positions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AxisScale {
+    /// Values are positioned linearly.
+    #[default]
+    Linear,
+    /// Values are positioned by `log10`, with ticks at decade boundaries.
+    ///
+    /// Zero and negative values are clamped to the axis minimum.
+    Log10,
+}
+
+impl AxisScale {
+    /// Map a data value into axis space, or `None` if it is out of domain.
+    #[must_use]
+    pub fn transform(self, value: f64) -> Option<f64> {
+        match self {
+            Self::Linear => value.is_finite().then_some(value),
+            Self::Log10 => (value.is_finite() && value > 0.0).then(|| value.log10()),
+        }
+    }
+
+    /// Map a value from axis space back to a data value.
+    #[must_use]
+    pub fn invert(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => 10f64.powf(value),
+        }
+    }
+}
+
+/// Format a decade tick label (`10^exp`) in at most five characters.
+pub(crate) fn decade_label(exp: i32) -> String {
+    if (0..5).contains(&exp) {
+        format!("{:.0}", 10f64.powi(exp))
+    } else {
+        format!("1e{exp}")
+    }
+}
+
 /// Axis configuration.
 #[derive(Debug, Clone)]
 pub struct Axis {
@@ -62,6 +103,8 @@ pub struct Axis {
     pub ticks: usize,
     /// Show grid lines.
     pub grid: bool,
+    /// Value scale (applied to the Y axis of a [`LineChart`]).
+    pub scale: AxisScale,
 }
 
 impl Default for Axis {
@@ -72,10 +115,45 @@ impl Default for Axis {
             max: None,
             ticks: 5,
             grid: false,
+            scale: AxisScale::default(),
         }
     }
 }
 
+impl Axis {
+    /// Set the value scale.
+    #[must_use]
+    pub fn scale(mut self, scale: AxisScale) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+/// How multiple series are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChartMode {
+    /// Each series is drawn as an independent line (default).
+    #[default]
+    Lines,
+    /// Series are stacked bottom-up in insertion order and the band between
+    /// each is filled; the top edge is the running total.
+    ///
+    /// Values are summed point-by-point, so series should share X coordinates.
+    /// Negative and non-finite values count as zero.
+    StackedArea,
+}
+
+/// A labeled reference value drawn over the series.
+#[derive(Debug, Clone)]
+struct Annotation {
+    value: f64,
+    label: String,
+    color: Color,
+}
+
+/// Braille dot bits for each row of a cell, both dot columns set.
+const BRAILLE_ROW_BITS: [u32; 4] = [0x09, 0x12, 0x24, 0xC0];
+
 /// Legend position.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum LegendPosition {
@@ -100,6 +178,13 @@ pub struct LineChart {
     y_axis: Axis,
     legend: LegendPosition,
     simplification: Simplification,
+    mode: ChartMode,
+    /// Horizontal threshold lines at Y values.
+    hlines: Vec<Annotation>,
+    /// Vertical event markers at X values.
+    vmarkers: Vec<Annotation>,
+    /// Include annotation values in axis autoscaling.
+    annotation_autoscale: bool,
     bounds: Rect,
     /// Margin for axis labels.
     margin_left: f32,
@@ -116,6 +201,10 @@ impl LineChart {
             y_axis: Axis::default(),
             legend: LegendPosition::default(),
             simplification: Simplification::default(),
+            mode: ChartMode::default(),
+            hlines: Vec::new(),
+            vmarkers: Vec::new(),
+            annotation_autoscale: false,
             bounds: Rect::default(),
             margin_left: 6.0,
             margin_bottom: 2.0,
@@ -159,6 +248,48 @@ impl LineChart {
         self
     }
 
+    /// Set how series are combined.
+    #[must_use]
+    pub fn mode(mut self, mode: ChartMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Add a dashed horizontal threshold line at a Y value.
+    ///
+    /// Values outside the Y range are pinned to the plot edge.
+    #[must_use]
+    pub fn with_hline(mut self, value: f64, label: &str, color: Color) -> Self {
+        self.hlines.push(Annotation {
+            value,
+            label: label.to_string(),
+            color,
+        });
+        self
+    }
+
+    /// Add a labeled vertical marker at an X value, e.g. a deploy event.
+    ///
+    /// Values outside the X range are pinned to the plot edge.
+    #[must_use]
+    pub fn with_vmarker(mut self, x_value: f64, label: &str, color: Color) -> Self {
+        self.vmarkers.push(Annotation {
+            value: x_value,
+            label: label.to_string(),
+            color,
+        });
+        self
+    }
+
+    /// Include threshold lines and markers when autoscaling the axes.
+    ///
+    /// Off by default, so annotations never rescale the data.
+    #[must_use]
+    pub fn with_annotation_autoscale(mut self, enabled: bool) -> Self {
+        self.annotation_autoscale = enabled;
+        self
+    }
+
     /// Set X axis configuration.
     #[must_use]
     pub fn with_x_axis(mut self, axis: Axis) -> Self {
@@ -214,12 +345,14 @@ impl LineChart {
         let mut x_min = f64::INFINITY;
         let mut x_max = f64::NEG_INFINITY;
 
-        for series in &self.series {
-            for &(x, _) in &series.data {
-                if x.is_finite() {
-                    x_min = x_min.min(x);
-                    x_max = x_max.max(x);
-                }
+        let xs = self
+            .series
+            .iter()
+            .flat_map(|s| s.data.iter().map(|&(x, _)| x));
+        for x in xs.chain(self.autoscaled(&self.vmarkers)) {
+            if x.is_finite() {
+                x_min = x_min.min(x);
+                x_max = x_max.max(x);
             }
         }
 
@@ -234,7 +367,17 @@ impl LineChart {
     }
 
     /// Compute Y range from all series.
+    ///
+    /// Under [`AxisScale::Log10`] the range is in log space, widened to whole
+    /// decades.
     fn y_range(&self) -> (f64, f64) {
+        if self.y_axis.scale == AxisScale::Log10 {
+            return self.y_range_log();
+        }
+        if self.mode == ChartMode::StackedArea {
+            return self.y_range_stacked();
+        }
+
         if let Some(min) = self.y_axis.min {
             if let Some(max) = self.y_axis.max {
                 return (min, max);
@@ -244,12 +387,14 @@ impl LineChart {
         let mut y_min = f64::INFINITY;
         let mut y_max = f64::NEG_INFINITY;
 
-        for series in &self.series {
-            for &(_, y) in &series.data {
-                if y.is_finite() {
-                    y_min = y_min.min(y);
-                    y_max = y_max.max(y);
-                }
+        let ys = self
+            .series
+            .iter()
+            .flat_map(|s| s.data.iter().map(|&(_, y)| y));
+        for y in ys.chain(self.autoscaled(&self.hlines)) {
+            if y.is_finite() {
+                y_min = y_min.min(y);
+                y_max = y_max.max(y);
             }
         }
 
@@ -265,6 +410,128 @@ impl LineChart {
         }
     }
 
+    /// Compute the Y range of a stacked chart: zero up to the largest total.
+    fn y_range_stacked(&self) -> (f64, f64) {
+        let total = self
+            .stacked_series()
+            .last()
+            .map(|top| top.iter().fold(0.0_f64, |acc, &(_, y)| acc.max(y)))
+            .unwrap_or(0.0);
+        let total = self
+            .autoscaled(&self.hlines)
+            .filter(|y| y.is_finite())
+            .fold(total, f64::max);
+        let min = self.y_axis.min.unwrap_or(0.0);
+        let max = self.y_axis.max.unwrap_or(total);
+        (min, if max > min { max } else { min + 1.0 })
+    }
+
+    /// Accumulate series values bottom-up, in series order.
+    ///
+    /// Each returned series holds the running total at every point.
+    fn stacked_series(&self) -> Vec<Vec<(f64, f64)>> {
+        let mut totals: Vec<f64> = Vec::new();
+        self.series
+            .iter()
+            .map(|series| {
+                if totals.len() < series.data.len() {
+                    totals.resize(series.data.len(), 0.0);
+                }
+                series
+                    .data
+                    .iter()
+                    .zip(totals.iter_mut())
+                    .map(|(&(x, y), total)| {
+                        if y.is_finite() && y > 0.0 {
+                            *total += y;
+                        }
+                        (x, *total)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Compute the log10 Y range, snapped to decade boundaries.
+    fn y_range_log(&self) -> (f64, f64) {
+        let scale = AxisScale::Log10;
+        let explicit_min = self.y_axis.min.and_then(|v| scale.transform(v));
+        let explicit_max = self.y_axis.max.and_then(|v| scale.transform(v));
+        if let (Some(min), Some(max)) = (explicit_min, explicit_max) {
+            return (min, max);
+        }
+
+        let mut y_min = f64::INFINITY;
+        let mut y_max = f64::NEG_INFINITY;
+        let ys = self
+            .series
+            .iter()
+            .flat_map(|s| s.data.iter().map(|&(_, y)| y));
+        for y in ys.chain(self.autoscaled(&self.hlines)) {
+            if let Some(v) = scale.transform(y) {
+                y_min = y_min.min(v);
+                y_max = y_max.max(v);
+            }
+        }
+
+        if y_min == f64::INFINITY {
+            return (0.0, 1.0);
+        }
+
+        let min = explicit_min.unwrap_or_else(|| y_min.floor());
+        let mut max = explicit_max.unwrap_or_else(|| y_max.ceil());
+        if max <= min {
+            max = min + 1.0;
+        }
+        (min, max)
+    }
+
+    /// Get annotation values that take part in autoscaling.
+    fn autoscaled<'a>(&self, annotations: &'a [Annotation]) -> impl Iterator<Item = f64> + 'a {
+        let annotations = if self.annotation_autoscale {
+            annotations
+        } else {
+            &[]
+        };
+        annotations.iter().map(|a| a.value)
+    }
+
+    /// Map a data point to braille grid coordinates.
+    ///
+    /// Ranges are in axis space. Returns `None` for non-finite points; the flag
+    /// is set when the Y value was out of the axis domain and clamped to the
+    /// axis minimum.
+    fn to_grid(
+        &self,
+        (x, y): (f64, f64),
+        (x_min, x_max): (f64, f64),
+        (y_min, y_max): (f64, f64),
+        cols: usize,
+        rows: usize,
+    ) -> Option<(usize, usize, bool)> {
+        if !x.is_finite() || !y.is_finite() {
+            return None;
+        }
+
+        // Normalize to 0..1
+        let x_norm = if x_max > x_min {
+            (x - x_min) / (x_max - x_min)
+        } else {
+            0.5
+        };
+        let (y_norm, clamped) = match self.y_axis.scale.transform(y) {
+            Some(y) if y_max > y_min => ((y - y_min) / (y_max - y_min), false),
+            Some(_) => (0.5, false),
+            None => (0.0, true),
+        };
+
+        // Convert to grid coordinates
+        let gx = ((x_norm * (cols - 1) as f64).round() as usize).min(cols.saturating_sub(1));
+        let gy =
+            (((1.0 - y_norm) * (rows - 1) as f64).round() as usize).min(rows.saturating_sub(1));
+        Some((gx, gy, clamped))
+    }
+
     /// Apply simplification to a series.
     fn simplify(&self, data: &[(f64, f64)]) -> Vec<(f64, f64)> {
         match self.simplification {
@@ -287,6 +554,22 @@ impl LineChart {
             color: Color::new(0.6, 0.6, 0.6, 1.0),
             ..Default::default()
         };
+        if self.y_axis.scale == AxisScale::Log10 {
+            // Ticks at decade boundaries
+            if self.y_axis.ticks == 0 || y_max <= y_min {
+                return;
+            }
+            for exp in (y_min.ceil() as i32)..=(y_max.floor() as i32) {
+                let t = (y_max - f64::from(exp)) / (y_max - y_min);
+                let y_pos = plot_y + (plot_height - 1.0) * t as f32;
+                canvas.draw_text(
+                    &format!("{:>5}", decade_label(exp)),
+                    Point::new(self.bounds.x, y_pos.round()),
+                    &style,
+                );
+            }
+            return;
+        }
         for i in 0..=self.y_axis.ticks {
             let t = i as f64 / self.y_axis.ticks as f64;
             let y_val = y_min + (y_max - y_min) * (1.0 - t);
@@ -331,6 +614,85 @@ impl LineChart {
         }
     }
 
+    /// Draw threshold lines and event markers over the plot area.
+    ///
+    /// Positions are clamped to the plot; labels that do not fit are omitted.
+    fn draw_annotations(
+        &self,
+        canvas: &mut dyn Canvas,
+        (x_min, x_max): (f64, f64),
+        (y_min, y_max): (f64, f64),
+        plot: Rect,
+    ) {
+        let (plot_x, plot_y) = (plot.x, plot.y);
+        let cols = plot.width as usize;
+        let rows = plot.height as usize;
+        if cols == 0 || rows == 0 {
+            return;
+        }
+
+        for line in &self.hlines {
+            let Some(y) = self.y_axis.scale.transform(line.value) else {
+                continue;
+            };
+            let t = if y_max > y_min {
+                ((y_max - y) / (y_max - y_min)).clamp(0.0, 1.0)
+            } else {
+                0.5
+            };
+            let row = plot_y + ((rows - 1) as f64 * t).round() as f32;
+            let style = TextStyle {
+                color: line.color,
+                ..Default::default()
+            };
+            canvas.draw_text(&"╌".repeat(cols), Point::new(plot_x, row), &style);
+
+            let label_len = line.label.chars().count();
+            if label_len > 0 && label_len <= cols {
+                let x = plot_x + (cols - label_len) as f32;
+                canvas.draw_text(&line.label, Point::new(x, row), &style);
+            }
+        }
+
+        for marker in &self.vmarkers {
+            if !marker.value.is_finite() {
+                continue;
+            }
+            let t = if x_max > x_min {
+                ((marker.value - x_min) / (x_max - x_min)).clamp(0.0, 1.0)
+            } else {
+                0.5
+            };
+            let col = ((cols - 1) as f64 * t).round() as usize;
+            let style = TextStyle {
+                color: marker.color,
+                ..Default::default()
+            };
+            for row in 0..rows {
+                canvas.draw_text(
+                    "┊",
+                    Point::new(plot_x + col as f32, plot_y + row as f32),
+                    &style,
+                );
+            }
+
+            // Label on the top row, right of the marker or else left of it
+            let label_len = marker.label.chars().count();
+            let label_col = if col + 1 + label_len <= cols {
+                Some(col + 1)
+            } else {
+                col.checked_sub(label_len)
+            };
+            if let Some(label_col) = label_col.filter(|_| label_len > 0) {
+                canvas.draw_text(
+                    &marker.label,
+                    Point::new(plot_x + label_col as f32, plot_y),
+                    &style,
+                );
+            }
+        }
+    }
+
     /// Draw the legend.
     fn draw_legend(
         &self,
@@ -424,9 +786,18 @@ impl Widget for LineChart {
             plot_height,
         );
 
+        let stacked = match self.mode {
+            ChartMode::Lines => Vec::new(),
+            ChartMode::StackedArea => self.stacked_series(),
+        };
+        let mut band_floor: Vec<usize> = Vec::new();
+
         // Draw each series
-        for series in &self.series {
-            let simplified = self.simplify(&series.data);
+        for (index, series) in self.series.iter().enumerate() {
+            let simplified = match self.mode {
+                ChartMode::Lines => self.simplify(&series.data),
+                ChartMode::StackedArea => stacked[index].clone(),
+            };
             let style = TextStyle {
                 color: series.color,
                 ..Default::default()
@@ -443,63 +814,28 @@ impl Widget for LineChart {
             let mut grid = vec![vec![false; rows]; cols];
 
             // Plot points onto grid
-            for &(x, y) in &simplified {
-                if !x.is_finite() || !y.is_finite() {
-                    continue;
-                }
-
-                // Normalize to 0..1
-                let x_norm = if x_max > x_min {
-                    (x - x_min) / (x_max - x_min)
-                } else {
-                    0.5
-                };
-                let y_norm = if y_max > y_min {
-                    (y - y_min) / (y_max - y_min)
-                } else {
-                    0.5
-                };
-
-                // Convert to grid coordinates
-                let gx =
-                    ((x_norm * (cols - 1) as f64).round() as usize).min(cols.saturating_sub(1));
-                let gy = (((1.0 - y_norm) * (rows - 1) as f64).round() as usize)
-                    .min(rows.saturating_sub(1));
-
+            let points: Vec<(usize, usize, bool)> = simplified
+                .iter()
+                .filter_map(|&p| self.to_grid(p, (x_min, x_max), (y_min, y_max), cols, rows))
+                .collect();
+            for &(gx, gy, _) in &points {
                 grid[gx][gy] = true;
             }
 
             // Connect adjacent points with lines (Bresenham-like)
-            let points: Vec<(usize, usize)> = simplified
-                .iter()
-                .filter_map(|&(x, y)| {
-                    if !x.is_finite() || !y.is_finite() {
-                        return None;
-                    }
-                    let x_norm = if x_max > x_min {
-                        (x - x_min) / (x_max - x_min)
-                    } else {
-                        0.5
-                    };
-                    let y_norm = if y_max > y_min {
-                        (y - y_min) / (y_max - y_min)
-                    } else {
-                        0.5
-                    };
-                    let gx =
-                        ((x_norm * (cols - 1) as f64).round() as usize).min(cols.saturating_sub(1));
-                    let gy = (((1.0 - y_norm) * (rows - 1) as f64).round() as usize)
-                        .min(rows.saturating_sub(1));
-                    Some((gx, gy))
-                })
-                .collect();
-
             for window in points.windows(2) {
                 if let [p1, p2] = window {
                     draw_line(&mut grid, p1.0, p1.1, p2.0, p2.1);
                 }
             }
 
+            if self.mode == ChartMode::StackedArea {
+                band_floor.resize(cols, rows);
+                fill_band(&mut grid, &mut band_floor);
+                render_area(canvas, &grid, plot_x, plot_y, &style);
+                continue;
+            }
+
             // Render grid as braille
             let char_rows = plot_height as usize;
             for cy in 0..char_rows {
@@ -531,8 +867,22 @@ impl Widget for LineChart {
                     }
                 }
             }
+
+            // Mark values clamped to the axis minimum (e.g. zero on a log axis)
+            let bottom = plot_y + (plot_height - 1.0).max(0.0);
+            for &(gx, _, _) in points.iter().filter(|p| p.2) {
+                canvas.draw_text("▾", Point::new(plot_x + gx as f32, bottom), &style);
+            }
         }
 
+        // Annotations sit on top of the series
+        self.draw_annotations(
+            canvas,
+            (x_min, x_max),
+            (y_min, y_max),
+            Rect::new(plot_x, plot_y, plot_width, plot_height),
+        );
+
         // Draw legend
         self.draw_legend(canvas, plot_x, plot_y, plot_width, plot_height);
     }
@@ -645,6 +995,44 @@ fn draw_line(grid: &mut [Vec<bool>], x0: usize, y0: usize, x1: usize, y1: usize)
     }
 }
 
+/// Fill each column from the line drawn in `grid` down to `floor`.
+///
+/// `floor` holds the exclusive lower bound per column and is raised to the
+/// new top so the next band stacks on this one.
+fn fill_band(grid: &mut [Vec<bool>], floor: &mut [usize]) {
+    for (column, floor) in grid.iter_mut().zip(floor.iter_mut()) {
+        if let Some(top) = column.iter().position(|&dot| dot) {
+            for dot in column.iter_mut().take(*floor).skip(top) {
+                *dot = true;
+            }
+            *floor = (*floor).min(top);
+        }
+    }
+}
+
+/// Render a filled dot grid using full-width braille cells.
+fn render_area(canvas: &mut dyn Canvas, grid: &[Vec<bool>], x: f32, y: f32, style: &TextStyle) {
+    for (cx, column) in grid.iter().enumerate() {
+        for (cy, cell) in column.chunks(4).enumerate() {
+            let bits = cell
+                .iter()
+                .zip(BRAILLE_ROW_BITS)
+                .filter(|(dot, _)| **dot)
+                .fold(0, |acc, (_, bit)| acc | bit);
+            if bits == 0 {
+                continue;
+            }
+            if let Some(ch) = char::from_u32(0x2800 + bits) {
+                canvas.draw_text(
+                    &ch.to_string(),
+                    Point::new(x + cx as f32, y + cy as f32),
+                    style,
+                );
+            }
+        }
+    }
+}
+
 /// Douglas-Peucker line simplification algorithm.
 fn douglas_peucker(points: &[(f64, f64)], epsilon: f64) -> Vec<(f64, f64)> {
     if points.len() < 3 {
@@ -866,6 +1254,7 @@ mod tests {
                 max: Some(10.0),
                 ticks: 5,
                 grid: true,
+                scale: AxisScale::Linear,
             })
             .with_y_axis(Axis {
                 label: Some("Y Label".to_string()),
@@ -873,6 +1262,7 @@ mod tests {
                 max: Some(100.0),
                 ticks: 10,
                 grid: true,
+                scale: AxisScale::Linear,
             });
         let bounds = Rect::new(0.0, 0.0, 80.0, 24.0);
         chart.layout(bounds);
@@ -1041,6 +1431,152 @@ mod tests {
         assert_eq!(ymax, 10.0);
     }
 
+    #[test]
+    fn test_axis_scale_transform() {
+        assert_eq!(AxisScale::Linear.transform(-5.0), Some(-5.0));
+        assert_eq!(AxisScale::Log10.transform(1000.0), Some(3.0));
+        assert_eq!(AxisScale::Log10.transform(0.0), None);
+        assert_eq!(AxisScale::Log10.transform(-1.0), None);
+        assert_eq!(AxisScale::Log10.invert(2.0), 100.0);
+        assert_eq!(Axis::default().scale, AxisScale::Linear);
+    }
+
+    #[test]
+    fn test_decade_label() {
+        assert_eq!(decade_label(0), "1");
+        assert_eq!(decade_label(3), "1000");
+        assert_eq!(decade_label(6), "1e6");
+        assert_eq!(decade_label(-2), "1e-2");
+    }
+
+    #[test]
+    fn test_line_chart_log_scale_even_spacing() {
+        let chart = LineChart::new()
+            .add_series(
+                "latency",
+                vec![(0.0, 1.0), (1.0, 10.0), (2.0, 100.0), (3.0, 1000.0)],
+                Color::RED,
+            )
+            .with_y_axis(Axis::default().scale(AxisScale::Log10));
+        let y_range = chart.y_range();
+        assert_eq!(y_range, (0.0, 3.0));
+
+        // 40 dot rows: positions 39, 26, 13, 0
+        let rows: Vec<usize> = chart.series[0]
+            .data
+            .iter()
+            .filter_map(|&p| chart.to_grid(p, (0.0, 3.0), y_range, 10, 40))
+            .map(|(_, gy, _)| gy)
+            .collect();
+        assert_eq!(rows, vec![39, 26, 13, 0]);
+    }
+
+    #[test]
+    fn test_line_chart_log_scale_clamps_non_positive() {
+        let mut chart = LineChart::new()
+            .add_series(
+                "latency",
+                vec![(0.0, 0.0), (1.0, 10.0), (2.0, -5.0), (3.0, 100.0)],
+                Color::RED,
+            )
+            .with_y_axis(Axis::default().scale(AxisScale::Log10));
+        let y_range = chart.y_range();
+        assert_eq!(y_range, (1.0, 2.0));
+
+        let clamped = chart.to_grid((0.0, 0.0), (0.0, 3.0), y_range, 10, 40);
+        assert_eq!(clamped, Some((0, 39, true)));
+
+        chart.layout(Rect::new(0.0, 0.0, 40.0, 12.0));
+        let mut buffer = CellBuffer::new(40, 12);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        chart.paint(&mut canvas);
+        let indicators = (0..40)
+            .filter(|&x| buffer.get(x, 9).is_some_and(|c| c.symbol.as_str() == "▾"))
+            .count();
+        assert_eq!(indicators, 2);
+    }
+
+    fn stacked_cpu_chart() -> LineChart {
+        LineChart::new()
+            .add_series("user", vec![(0.0, 10.0), (1.0, 10.0)], Color::RED)
+            .add_series("system", vec![(0.0, 20.0), (1.0, 20.0)], Color::GREEN)
+            .add_series("iowait", vec![(0.0, 30.0), (1.0, 30.0)], Color::BLUE)
+            .mode(ChartMode::StackedArea)
+    }
+
+    #[test]
+    fn test_line_chart_stacked_series_accumulate() {
+        let chart = stacked_cpu_chart();
+        let stacked = chart.stacked_series();
+        assert_eq!(stacked.len(), 3);
+        assert_eq!(stacked[0], vec![(0.0, 10.0), (1.0, 10.0)]);
+        assert_eq!(stacked[1], vec![(0.0, 30.0), (1.0, 30.0)]);
+        assert_eq!(stacked[2], vec![(0.0, 60.0), (1.0, 60.0)]);
+        assert_eq!(chart.y_range(), (0.0, 60.0));
+    }
+
+    #[test]
+    fn test_line_chart_stacked_ignores_negative_and_nan() {
+        let chart = LineChart::new()
+            .add_series("a", vec![(0.0, 5.0), (1.0, f64::NAN)], Color::RED)
+            .add_series("b", vec![(0.0, -3.0), (1.0, 4.0)], Color::GREEN)
+            .mode(ChartMode::StackedArea);
+        let stacked = chart.stacked_series();
+        assert_eq!(stacked[1], vec![(0.0, 5.0), (1.0, 4.0)]);
+    }
+
+    #[test]
+    fn test_line_chart_stacked_area_reaches_total() {
+        let mut chart = stacked_cpu_chart().with_legend(LegendPosition::None);
+        chart.layout(Rect::new(0.0, 0.0, 40.0, 12.0));
+        let mut buffer = CellBuffer::new(40, 12);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        chart.paint(&mut canvas);
+
+        // Top of the value axis is labelled with the stack total
+        let label: String = buffer.rows_as_strings()[0].chars().take(6).collect();
+        assert_eq!(label.trim(), "60");
+
+        // Top plot row is drawn by the last series, bottom row by the first
+        let top = buffer.get(6, 0).expect("top cell");
+        assert_ne!(top.symbol.as_str(), " ");
+        assert_eq!(top.fg, Color::BLUE);
+        let bottom = buffer.get(6, 9).expect("bottom cell");
+        assert_eq!(bottom.symbol.as_str(), "⣿");
+        assert_eq!(bottom.fg, Color::RED);
+    }
+
+    #[test]
+    fn test_line_chart_stacked_respects_axis_max() {
+        let chart = stacked_cpu_chart().with_y_axis(Axis {
+            max: Some(100.0),
+            ..Default::default()
+        });
+        assert_eq!(chart.y_range(), (0.0, 100.0));
+    }
+
+    #[test]
+    fn test_fill_band_stacks_on_floor() {
+        let mut grid = vec![vec![false; 8]; 1];
+        grid[0][5] = true;
+        let mut floor = vec![8];
+        fill_band(&mut grid, &mut floor);
+        assert_eq!(
+            grid[0],
+            [false, false, false, false, false, true, true, true]
+        );
+        assert_eq!(floor, vec![5]);
+
+        let mut next = vec![vec![false; 8]; 1];
+        next[0][2] = true;
+        fill_band(&mut next, &mut floor);
+        assert_eq!(
+            next[0],
+            [false, false, true, true, true, false, false, false]
+        );
+        assert_eq!(floor, vec![2]);
+    }
+
     #[test]
     fn test_line_chart_nan_values() {
         let mut chart = LineChart::new().add_series(
@@ -1194,6 +1730,87 @@ mod tests {
         assert!(matches!(series.style, LineStyle::Dashed));
     }
 
+    fn slo_chart() -> LineChart {
+        LineChart::new()
+            .add_series("latency", vec![(0.0, 150.0), (10.0, 50.0)], Color::BLUE)
+            .with_y_axis(Axis {
+                min: Some(0.0),
+                max: Some(150.0),
+                ..Axis::default()
+            })
+            .with_legend(LegendPosition::None)
+    }
+
+    #[test]
+    fn test_hline_above_data_drawn_over_series() {
+        let threshold = Color::new(1.0, 0.5, 0.0, 1.0);
+        let mut chart = slo_chart().with_hline(200.0, "SLO", threshold);
+        chart.layout(Rect::new(0.0, 0.0, 40.0, 12.0));
+        let mut buffer = CellBuffer::new(40, 12);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        chart.paint(&mut canvas);
+
+        // Pinned to the top plot row, over the series' first point
+        let cell = buffer.get(6, 0).expect("cell");
+        assert_eq!(cell.symbol.as_str(), "╌");
+        assert_eq!(cell.fg, threshold);
+        let top: String = buffer.rows_as_strings()[0].chars().skip(6).collect();
+        assert_eq!(top, format!("{}SLO", "╌".repeat(31)));
+        // Autoscaling ignores the threshold by default
+        assert_eq!(chart.y_range(), (0.0, 150.0));
+    }
+
+    #[test]
+    fn test_hline_autoscale_flag() {
+        let chart = LineChart::new()
+            .add_series("latency", vec![(0.0, 100.0), (10.0, 150.0)], Color::BLUE)
+            .with_hline(200.0, "SLO", Color::RED);
+        let (_, max) = chart.y_range();
+        assert!(max < 200.0);
+
+        let (_, max) = chart.with_annotation_autoscale(true).y_range();
+        assert!(max > 200.0);
+    }
+
+    #[test]
+    fn test_vmarker_with_label() {
+        let deploy = Color::new(0.5, 0.5, 1.0, 1.0);
+        let mut chart = slo_chart().with_vmarker(5.0, "deploy", deploy);
+        chart.layout(Rect::new(0.0, 0.0, 40.0, 12.0));
+        let mut buffer = CellBuffer::new(40, 12);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        chart.paint(&mut canvas);
+
+        // 34 plot columns, the middle one is 6 + round(33 * 0.5)
+        for y in 1..10 {
+            assert_eq!(buffer.get(23, y).expect("cell").symbol.as_str(), "┊");
+        }
+        assert_eq!(buffer.get(23, 0).expect("cell").fg, deploy);
+        let label: String = buffer.rows_as_strings()[0]
+            .chars()
+            .skip(24)
+            .take(6)
+            .collect();
+        assert_eq!(label, "deploy");
+    }
+
+    #[test]
+    fn test_vmarker_label_flips_or_omits() {
+        let mut chart = slo_chart()
+            .with_vmarker(10.0, "end", Color::RED)
+            .with_vmarker(0.0, &"x".repeat(40), Color::GREEN);
+        chart.layout(Rect::new(0.0, 0.0, 40.0, 12.0));
+        let mut buffer = CellBuffer::new(40, 12);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        chart.paint(&mut canvas);
+
+        // No room right of the last column, so the label goes left
+        let top = &buffer.rows_as_strings()[0];
+        assert!(top.ends_with("end┊"));
+        // Too long for the plot on either side: omitted
+        assert!(!top.contains('x'));
+    }
+
     #[test]
     fn test_line_chart_single_point_x_range() {
         // When all points have same X value
@@ -1,8 +1,11 @@
 //! Horizontal meter/gauge widget.
+//!
+//! Values can jump instantly via [`Meter::set_value`] or ease toward a
+//! target with [`Meter::animate_to`] driven by [`Meter::tick`].
 
 use presentar_core::{
-    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
-    LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
+    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, EasedValue,
+    Easing, Event, LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
 };
 use std::any::Any;
 use std::time::Duration;
@@ -16,6 +19,8 @@ pub struct Meter {
     fill_color: Color,
     gradient_end: Option<Color>,
     show_percentage: bool,
+    /// In-flight transition toward a target value.
+    animation: Option<EasedValue>,
     bounds: Rect,
 }
 
@@ -30,6 +35,7 @@ impl Meter {
             fill_color: Color::GREEN,
             gradient_end: None,
             show_percentage: true,
+            animation: None,
             bounds: Rect::new(0.0, 0.0, 0.0, 0.0),
         }
     }
@@ -69,9 +75,42 @@ impl Meter {
         self
     }
 
-    /// Update the value.
+    /// Update the value immediately, cancelling any animation.
     pub fn set_value(&mut self, value: f64) {
         self.value = value.clamp(0.0, self.max);
+        self.animation = None;
+    }
+
+    /// Animate from the current value to `value` over `duration`.
+    ///
+    /// Call [`Meter::tick`] each frame to advance the transition.
+    pub fn animate_to(&mut self, value: f64, duration: Duration, easing: Easing) {
+        let target = value.clamp(0.0, self.max);
+        if duration.is_zero() {
+            self.set_value(target);
+            return;
+        }
+        self.animation =
+            Some(EasedValue::new(self.value, target, duration.as_secs_f64()).with_easing(easing));
+    }
+
+    /// Advance the animation by `dt`. Returns `true` once settled.
+    pub fn tick(&mut self, dt: Duration) -> bool {
+        if let Some(anim) = &mut self.animation {
+            anim.update(dt.as_secs_f64());
+            self.value = anim.value().clamp(0.0, self.max);
+            if anim.is_complete() {
+                self.value = anim.to;
+                self.animation = None;
+            }
+        }
+        self.is_settled()
+    }
+
+    /// Whether no animation is in progress.
+    #[must_use]
+    pub fn is_settled(&self) -> bool {
+        self.animation.is_none()
     }
 
     /// Get the current value.
@@ -315,6 +354,36 @@ mod tests {
         assert_eq!(meter.value(), 80.0);
     }
 
+    #[test]
+    fn test_meter_animate_to() {
+        let mut meter = Meter::new(0.0, 1.0);
+        meter.animate_to(1.0, Duration::from_millis(400), Easing::EaseInOut);
+        assert!(!meter.is_settled());
+
+        assert!(!meter.tick(Duration::from_millis(200)));
+        assert!(meter.value() > 0.0 && meter.value() < 1.0);
+
+        assert!(meter.tick(Duration::from_millis(250)));
+        assert_eq!(meter.value(), 1.0);
+    }
+
+    #[test]
+    fn test_meter_set_value_cancels_animation() {
+        let mut meter = Meter::percentage(0.0);
+        meter.animate_to(80.0, Duration::from_secs(1), Easing::Linear);
+        meter.set_value(30.0);
+        assert!(meter.tick(Duration::from_millis(500)));
+        assert_eq!(meter.value(), 30.0);
+    }
+
+    #[test]
+    fn test_meter_animate_zero_duration() {
+        let mut meter = Meter::percentage(10.0);
+        meter.animate_to(60.0, Duration::ZERO, Easing::Linear);
+        assert!(meter.is_settled());
+        assert_eq!(meter.value(), 60.0);
+    }
+
     #[test]
     fn test_meter_with_label() {
         let meter = Meter::percentage(50.0).with_label("CPU");
@@ -15,6 +15,7 @@
 
 mod border;
 mod box_plot;
+mod candlestick;
 mod cluster_plot;
 mod collapsible_panel;
 mod confusion_matrix;
@@ -64,14 +65,16 @@ mod tree;
 mod treemap;
 mod ux;
 mod violin_plot;
+mod waterfall;
 
 pub use border::{Border, BorderStyle};
 pub use box_plot::{BoxPlot, BoxStats, Orientation};
+pub use candlestick::{Candlestick, Ohlc};
 pub use cluster_plot::{ClusterAlgorithm, ClusterPlot};
 pub use collapsible_panel::{CollapseDirection, CollapseIndicators, CollapsiblePanel};
 pub use confusion_matrix::{ConfusionMatrix, MatrixPalette, Normalization};
 pub use connections_panel::{ConnectionEntry, ConnectionsPanel, TcpState};
-pub use containers_panel::{ContainerEntry, ContainerState, ContainersPanel};
+pub use containers_panel::{ContainerEntry, ContainerRuntime, ContainerState, ContainersPanel};
 pub use core_stats_dataframe::{CoreStatsDataFrame, CoreStatsRow, CoreStatsSortColumn};
 pub use cpu_exploded::{
     CpuCoreState, CpuStateBreakdown, FreqTempHeatmap, LoadAverageTimeline, PerCoreSparklineGrid,
@@ -93,7 +96,9 @@ pub use info_dense::{
     TrendSparkline,
 };
 pub use layout::{Direction, Layout, LayoutItem, SizeSpec};
-pub use line_chart::{Axis, LegendPosition, LineChart, LineStyle, Series, Simplification};
+pub use line_chart::{
+    Axis, AxisScale, ChartMode, LegendPosition, LineChart, LineStyle, Series, Simplification,
+};
 pub use loss_curve::{EmaConfig, LossCurve, LossSeries};
 pub use memory_bar::{HugePages, MemoryBar, MemorySegment};
 pub use meter::Meter;
@@ -108,7 +113,7 @@ pub use process_dataframe::{
 pub use process_table::{ProcessEntry, ProcessSort, ProcessState, ProcessTable};
 pub use radar_plot::{RadarPlot, RadarSeries};
 pub use roc_pr_curve::{CurveData, CurveMode, RocPrCurve};
-pub use scatter_plot::{MarkerStyle, ScatterAxis, ScatterPlot};
+pub use scatter_plot::{MarkerStyle, ScatterAxis, ScatterMode, ScatterPlot};
 pub use scrollbar::{ScrollOrientation, Scrollbar, ScrollbarChars};
 pub use segmented_meter::{Segment, SegmentedMeter};
 pub use selection::{
@@ -116,19 +121,20 @@ pub use selection::{
     SELECTION_GUTTER,
 };
 pub use sensors_panel::{SensorReading, SensorStatus, SensorsPanel};
-pub use sparkline::{Sparkline, TrendDirection};
+pub use sparkline::{Sparkline, SparklineSampling, TrendDirection};
 pub use symbols::{
     BrailleSymbols, CustomSymbols, SymbolSet, BLOCK_DOWN, BLOCK_UP, BRAILLE_DOWN, BRAILLE_UP,
     SPARKLINE, SUBSCRIPT, SUPERSCRIPT, TTY_DOWN, TTY_UP,
 };
-pub use table::Table;
-pub use text::{Text, TextAlign};
-pub use text_input::TextInput;
+pub use table::{ColumnWidth, Table};
+pub use text::{Text, TextAlign, WrapMode};
+pub use text_input::{TextClipboard, TextInput};
 pub use title_bar::{TitleBar, TitleBarPosition, TitleBarStyle};
-pub use tree::{NodeId, Tree, TreeNode};
+pub use tree::{ChildrenProvider, NodeId, Tree, TreeNode};
 pub use treemap::{Treemap, TreemapLayout, TreemapNode};
 pub use ux::{truncate, truncate_middle, truncate_with, EmptyState, HealthStatus};
 pub use violin_plot::{ViolinData, ViolinOrientation, ViolinPlot, ViolinStats};
+pub use waterfall::Waterfall;
 
 // Display Rules (SPEC-024 Section 28) - Grammar of Graphics formatting
 pub use display_rules::{
@@ -4,14 +4,17 @@
 //! Reference: ttop/btop process displays.
 
 use crate::theme::Gradient;
+use crate::widgets::display_rules::{format_bytes_rate, take_width, FuzzyIndex};
 use presentar_core::{
     Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event, Key,
     LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
 };
 use std::any::Any;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::Write as _;
 use std::time::Duration;
+use unicode_width::UnicodeWidthStr;
 
 /// Process state (from /proc/[pid]/stat)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -84,6 +87,10 @@ pub struct ProcessEntry {
     pub nice: Option<i32>,
     /// Thread count (CB-PROC-006).
     pub threads: Option<u32>,
+    /// Storage read rate in bytes/sec (`None` if io stats are unreadable).
+    pub read_bytes_per_sec: Option<f64>,
+    /// Storage write rate in bytes/sec (`None` if io stats are unreadable).
+    pub write_bytes_per_sec: Option<f64>,
     /// Parent process ID (CB-PROC-001 tree view).
     pub parent_pid: Option<u32>,
     /// Tree depth level for indentation (CB-PROC-001).
@@ -116,6 +123,8 @@ impl ProcessEntry {
             cgroup: None,
             nice: None,
             threads: None,
+            read_bytes_per_sec: None,
+            write_bytes_per_sec: None,
             parent_pid: None,
             tree_depth: 0,
             is_last_child: false,
@@ -165,6 +174,22 @@ impl ProcessEntry {
         self
     }
 
+    /// Set storage read/write rates in bytes/sec.
+    #[must_use]
+    pub fn with_io_rates(mut self, read: f64, write: f64) -> Self {
+        self.read_bytes_per_sec = Some(read);
+        self.write_bytes_per_sec = Some(write);
+        self
+    }
+
+    /// Combined read + write rate, if known.
+    fn io_rate(&self) -> Option<f64> {
+        match (self.read_bytes_per_sec, self.write_bytes_per_sec) {
+            (None, None) => None,
+            (read, write) => Some(read.unwrap_or(0.0) + write.unwrap_or(0.0)),
+        }
+    }
+
     /// Set parent PID (CB-PROC-001 tree view).
     #[must_use]
     pub fn with_parent_pid(mut self, ppid: u32) -> Self {
@@ -189,6 +214,8 @@ pub enum ProcessSort {
     Memory,
     Command,
     Oom,
+    /// Combined read + write rate.
+    Io,
 }
 
 /// Process table widget with color-coded CPU/Memory bars.
@@ -219,8 +246,16 @@ pub struct ProcessTable {
     show_nice: bool,
     /// Show thread count column (CB-PROC-006).
     show_threads: bool,
+    /// Show I/O read/write rate columns.
+    show_io: bool,
     /// Tree view mode (CB-PROC-001).
     tree_view: bool,
+    /// Active fuzzy filter query.
+    filter: String,
+    /// Processes hidden by the filter.
+    filtered_out: Vec<ProcessEntry>,
+    /// Filter match scores by PID.
+    match_scores: HashMap<u32, f32>,
     /// Cached bounds.
     bounds: Rect,
 }
@@ -248,7 +283,11 @@ impl ProcessTable {
             show_oom: false,
             show_nice: false,
             show_threads: false,
+            show_io: false,
             tree_view: false,
+            filter: String::new(),
+            filtered_out: Vec::new(),
+            match_scores: HashMap::new(),
             bounds: Rect::default(),
         }
     }
@@ -256,15 +295,141 @@ impl ProcessTable {
     /// Set processes.
     pub fn set_processes(&mut self, processes: Vec<ProcessEntry>) {
         self.processes = processes;
-        // Tree view (CB-PROC-001) takes precedence over sorting
+        self.filtered_out.clear();
+        self.apply_filter();
+        self.reorder();
+        // Clamp selection
+        if !self.processes.is_empty() && self.selected >= self.processes.len() {
+            self.selected = self.processes.len() - 1;
+        }
+    }
+
+    /// Update processes in place, keyed by PID.
+    ///
+    /// Surviving PIDs are updated, gone PIDs are dropped and new PIDs are
+    /// inserted in sorted position. The selected PID stays selected and keeps
+    /// its row on screen; if it is gone, the selection index is clamped.
+    pub fn update(&mut self, entries: Vec<ProcessEntry>) {
+        let selected_pid = self.selected_process().map(|p| p.pid);
+        let screen_row = self.selected.saturating_sub(self.scroll_offset);
+
+        // Filtered-out processes are diffed too, so they reappear in place
+        self.processes.append(&mut self.filtered_out);
+        let mut incoming: HashMap<u32, ProcessEntry> =
+            entries.into_iter().map(|p| (p.pid, p)).collect();
+        self.processes.retain_mut(|existing| {
+            incoming.remove(&existing.pid).is_some_and(|entry| {
+                *existing = entry;
+                true
+            })
+        });
+
         if self.tree_view {
+            self.processes.extend(incoming.into_values());
+            self.apply_filter();
             self.build_tree();
         } else {
+            // Stable re-sort is near-linear for the mostly ordered survivors
             self.sort_processes();
+            let (sort_by, ascending) = (self.sort_by, self.sort_ascending);
+            let mut new_entries: Vec<ProcessEntry> = incoming.into_values().collect();
+            new_entries.sort_by_key(|p| p.pid);
+            for entry in new_entries {
+                let pos = self.processes.partition_point(|p| {
+                    Self::compare_entries(sort_by, ascending, p, &entry).is_le()
+                });
+                self.processes.insert(pos, entry);
+            }
+            self.apply_filter();
         }
-        // Clamp selection
-        if !self.processes.is_empty() && self.selected >= self.processes.len() {
-            self.selected = self.processes.len() - 1;
+
+        match selected_pid.and_then(|pid| self.processes.iter().position(|p| p.pid == pid)) {
+            Some(index) => {
+                self.selected = index;
+                self.scroll_offset = index.saturating_sub(screen_row);
+            }
+            None => {
+                self.selected = self.selected.min(self.processes.len().saturating_sub(1));
+            }
+        }
+        self.scroll_offset = self
+            .scroll_offset
+            .min(self.processes.len().saturating_sub(1));
+        self.ensure_visible();
+    }
+
+    /// Filter processes by fuzzy-matching `query` against the command name.
+    ///
+    /// Matching rows keep the current sort order; an empty query shows all
+    /// processes. The selected PID stays selected if it still matches.
+    pub fn set_filter(&mut self, query: &str) {
+        let selected_pid = self.selected_process().map(|p| p.pid);
+
+        self.filter = query.trim().to_string();
+        self.processes.append(&mut self.filtered_out);
+        self.apply_filter();
+        self.reorder();
+
+        if let Some(index) =
+            selected_pid.and_then(|pid| self.processes.iter().position(|p| p.pid == pid))
+        {
+            self.selected = index;
+        } else {
+            self.selected = self.selected.min(self.processes.len().saturating_sub(1));
+        }
+        self.scroll_offset = self
+            .scroll_offset
+            .min(self.processes.len().saturating_sub(1));
+        self.ensure_visible();
+    }
+
+    /// Get the active filter query.
+    #[must_use]
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Get the filter match score (0.0 - 1.0) for a visible PID.
+    ///
+    /// Returns `None` when no filter is active or the PID is not shown.
+    #[must_use]
+    pub fn match_score(&self, pid: u32) -> Option<f32> {
+        self.match_scores.get(&pid).copied()
+    }
+
+    /// Move processes not matching the filter into `filtered_out`.
+    fn apply_filter(&mut self) {
+        self.match_scores.clear();
+        if self.filter.is_empty() {
+            return;
+        }
+
+        let index = FuzzyIndex::new((0..self.processes.len()).collect(), |&i: &usize| {
+            self.processes[i].command.clone()
+        });
+        let scores: HashMap<usize, f32> = index
+            .search(&self.filter, self.processes.len())
+            .into_iter()
+            .map(|result| (result.item, result.score))
+            .collect();
+
+        for (i, process) in std::mem::take(&mut self.processes).into_iter().enumerate() {
+            if let Some(&score) = scores.get(&i) {
+                self.match_scores.insert(process.pid, score);
+                self.processes.push(process);
+            } else {
+                self.filtered_out.push(process);
+            }
+        }
+    }
+
+    /// Order processes by tree (CB-PROC-001) or by the sort column.
+    fn reorder(&mut self) {
+        // Tree view takes precedence over sorting
+        if self.tree_view {
+            self.build_tree();
+        } else {
+            self.sort_processes();
         }
     }
 
@@ -276,6 +441,8 @@ impl ProcessTable {
     /// Clear all processes.
     pub fn clear(&mut self) {
         self.processes.clear();
+        self.filtered_out.clear();
+        self.match_scores.clear();
         self.selected = 0;
         self.scroll_offset = 0;
     }
@@ -329,6 +496,13 @@ impl ProcessTable {
         self
     }
 
+    /// Show I/O read/write rate columns.
+    #[must_use]
+    pub fn with_io_column(mut self) -> Self {
+        self.show_io = true;
+        self
+    }
+
     /// Enable tree view mode (CB-PROC-001).
     #[must_use]
     pub fn with_tree_view(mut self) -> Self {
@@ -356,10 +530,10 @@ impl ProcessTable {
             self.sort_ascending = !self.sort_ascending;
         } else {
             self.sort_by = column;
-            // Default directions (CPU/Memory/OOM default to descending)
+            // Default directions (CPU/Memory/OOM/IO default to descending)
             self.sort_ascending = !matches!(
                 column,
-                ProcessSort::Cpu | ProcessSort::Memory | ProcessSort::Oom
+                ProcessSort::Cpu | ProcessSort::Memory | ProcessSort::Oom | ProcessSort::Io
             );
         }
         self.sort_processes();
@@ -548,72 +722,42 @@ impl ProcessTable {
     }
 
     fn sort_processes(&mut self) {
-        let ascending = self.sort_ascending;
-        match self.sort_by {
-            ProcessSort::Pid => {
-                self.processes.sort_by(|a, b| {
-                    if ascending {
-                        a.pid.cmp(&b.pid)
-                    } else {
-                        b.pid.cmp(&a.pid)
-                    }
-                });
-            }
-            ProcessSort::User => {
-                self.processes.sort_by(|a, b| {
-                    if ascending {
-                        a.user.cmp(&b.user)
-                    } else {
-                        b.user.cmp(&a.user)
-                    }
-                });
-            }
-            ProcessSort::Cpu => {
-                self.processes.sort_by(|a, b| {
-                    let cmp = a
-                        .cpu_percent
-                        .partial_cmp(&b.cpu_percent)
-                        .unwrap_or(std::cmp::Ordering::Equal);
-                    if ascending {
-                        cmp
-                    } else {
-                        cmp.reverse()
-                    }
-                });
-            }
-            ProcessSort::Memory => {
-                self.processes.sort_by(|a, b| {
-                    let cmp = a
-                        .mem_percent
-                        .partial_cmp(&b.mem_percent)
-                        .unwrap_or(std::cmp::Ordering::Equal);
-                    if ascending {
-                        cmp
-                    } else {
-                        cmp.reverse()
-                    }
-                });
-            }
-            ProcessSort::Command => {
-                self.processes.sort_by(|a, b| {
-                    if ascending {
-                        a.command.cmp(&b.command)
-                    } else {
-                        b.command.cmp(&a.command)
-                    }
-                });
-            }
-            ProcessSort::Oom => {
-                self.processes.sort_by(|a, b| {
-                    let a_oom = a.oom_score.unwrap_or(0);
-                    let b_oom = b.oom_score.unwrap_or(0);
-                    if ascending {
-                        a_oom.cmp(&b_oom)
-                    } else {
-                        b_oom.cmp(&a_oom)
-                    }
-                });
-            }
+        let (sort_by, ascending) = (self.sort_by, self.sort_ascending);
+        self.processes
+            .sort_by(|a, b| Self::compare_entries(sort_by, ascending, a, b));
+    }
+
+    /// Compare two entries by a sort column and direction.
+    fn compare_entries(
+        sort_by: ProcessSort,
+        ascending: bool,
+        a: &ProcessEntry,
+        b: &ProcessEntry,
+    ) -> std::cmp::Ordering {
+        let cmp = match sort_by {
+            ProcessSort::Pid => a.pid.cmp(&b.pid),
+            ProcessSort::User => a.user.cmp(&b.user),
+            ProcessSort::Cpu => a
+                .cpu_percent
+                .partial_cmp(&b.cpu_percent)
+                .unwrap_or(std::cmp::Ordering::Equal),
+            ProcessSort::Memory => a
+                .mem_percent
+                .partial_cmp(&b.mem_percent)
+                .unwrap_or(std::cmp::Ordering::Equal),
+            ProcessSort::Command => a.command.cmp(&b.command),
+            ProcessSort::Oom => a.oom_score.unwrap_or(0).cmp(&b.oom_score.unwrap_or(0)),
+            // Unknown rates sort below any measured rate, including zero
+            ProcessSort::Io => a
+                .io_rate()
+                .unwrap_or(-1.0)
+                .partial_cmp(&b.io_rate().unwrap_or(-1.0))
+                .unwrap_or(std::cmp::Ordering::Equal),
+        };
+        if ascending {
+            cmp
+        } else {
+            cmp.reverse()
         }
     }
 
@@ -631,14 +775,15 @@ impl ProcessTable {
     }
 
     fn truncate(s: &str, width: usize) -> String {
-        if s.chars().count() <= width {
-            format!("{s:width$}")
+        // Measure in display columns so CJK / emoji names stay aligned
+        let used = s.width();
+        if used <= width {
+            format!("{s}{}", " ".repeat(width - used))
         } else if width > 1 {
             // Use proper ellipsis character "…" instead of "..."
-            let chars: String = s.chars().take(width - 1).collect();
-            format!("{chars}…")
+            format!("{}…", take_width(s, width - 1))
         } else {
-            s.chars().take(width).collect()
+            take_width(s, width).to_string()
         }
     }
 
@@ -673,6 +818,19 @@ impl ProcessTable {
         }
     }
 
+    /// Get I/O rate color based on throughput.
+    fn io_color(bytes_per_sec: f64) -> Color {
+        if bytes_per_sec >= 10.0 * 1024.0 * 1024.0 {
+            Color::new(1.0, 0.3, 0.3, 1.0) // Red - heavy I/O
+        } else if bytes_per_sec >= 1024.0 * 1024.0 {
+            Color::new(1.0, 0.8, 0.2, 1.0) // Yellow - moderate
+        } else if bytes_per_sec > 0.0 {
+            Color::new(0.8, 0.8, 0.8, 1.0) // White - light
+        } else {
+            Color::new(0.5, 0.5, 0.5, 1.0) // Gray - idle
+        }
+    }
+
     /// Build header string for the table.
     fn build_header(&self, cols: &ColumnWidths) -> String {
         let sep = if self.compact { " " } else { " │ " };
@@ -697,6 +855,12 @@ impl ProcessTable {
             header.push_str(sep);
             let _ = write!(header, "{:>3}", "TH");
         }
+        if self.show_io {
+            header.push_str(sep);
+            let _ = write!(header, "{:>w$}", "READ/s", w = cols.io);
+            header.push_str(sep);
+            let _ = write!(header, "{:>w$}", "WRIT/s", w = cols.io);
+        }
         header.push_str(sep);
         let _ = write!(
             header,
@@ -799,6 +963,25 @@ impl ProcessTable {
             );
             x += 3.0;
         }
+        // I/O rates
+        if self.show_io {
+            for rate in [proc.read_bytes_per_sec, proc.write_bytes_per_sec] {
+                x += sep;
+                let (text, color) = rate.map_or_else(
+                    || ("—".to_string(), Color::new(0.4, 0.4, 0.4, 1.0)),
+                    |r| (format_bytes_rate(r), Self::io_color(r)),
+                );
+                canvas.draw_text(
+                    &format!("{text:>w$}", w = cols.io),
+                    Point::new(x, y),
+                    &TextStyle {
+                        color,
+                        ..Default::default()
+                    },
+                );
+                x += cols.io as f32;
+            }
+        }
         // CPU
         x += sep;
         canvas.draw_text(
@@ -880,6 +1063,7 @@ struct ColumnWidths {
     oom: usize,
     nice: usize,
     threads: usize,
+    io: usize,
     user: usize,
     cpu: usize,
     mem: usize,
@@ -895,15 +1079,17 @@ impl ColumnWidths {
         let oom = if table.show_oom { 4 } else { 0 };
         let nice = if table.show_nice { 4 } else { 0 };
         let threads = if table.show_threads { 4 } else { 0 };
+        let io = if table.show_io { 6 } else { 0 };
         let user = if table.compact { 0 } else { 8 };
         let cpu = 6;
         let mem = 6;
         let sep = if table.compact { 1 } else { 3 };
         let extra_cols = usize::from(table.show_oom)
             + usize::from(table.show_nice)
-            + usize::from(table.show_threads);
+            + usize::from(table.show_threads)
+            + 2 * usize::from(table.show_io);
         let num_seps = if table.compact { 3 } else { 4 } + extra_cols;
-        let fixed = pid + state + oom + nice + threads + user + cpu + mem + sep * num_seps;
+        let fixed = pid + state + oom + nice + threads + 2 * io + user + cpu + mem + sep * num_seps;
         let cmd = width.saturating_sub(fixed);
         Self {
             pid,
@@ -911,6 +1097,7 @@ impl ColumnWidths {
             oom,
             nice,
             threads,
+            io,
             user,
             cpu,
             mem,
@@ -1069,6 +1256,7 @@ impl Widget for ProcessTable {
                     Key::P => self.sort_by(ProcessSort::Pid),
                     Key::N => self.sort_by(ProcessSort::Command),
                     Key::O => self.sort_by(ProcessSort::Oom),
+                    Key::I => self.sort_by(ProcessSort::Io),
                     Key::T => self.toggle_tree_view(), // CB-PROC-001
                     _ => {}
                 }
@@ -363,6 +363,46 @@ fn test_process_table_paint_with_oom() {
     assert!(canvas.texts.iter().any(|(t, _)| t.contains("800")));
 }
 
+#[test]
+fn test_process_table_sort_io() {
+    let mut table = ProcessTable::new();
+    let entries = vec![
+        ProcessEntry::new(1, "user", 10.0, 5.0, "unknown"),
+        ProcessEntry::new(2, "user", 10.0, 5.0, "idle").with_io_rates(0.0, 0.0),
+        ProcessEntry::new(3, "user", 10.0, 5.0, "writer").with_io_rates(0.0, 4096.0),
+        ProcessEntry::new(4, "user", 10.0, 5.0, "reader").with_io_rates(1024.0, 0.0),
+    ];
+    table.set_processes(entries);
+
+    // Default descending; processes without io stats sort last
+    table.sort_by(ProcessSort::Io);
+    let order: Vec<_> = table.processes.iter().map(|p| p.pid).collect();
+    assert_eq!(order, vec![3, 4, 2, 1]);
+}
+
+#[test]
+fn test_process_table_paint_with_io() {
+    let mut table = ProcessTable::new().with_io_column();
+    let entries = vec![
+        ProcessEntry::new(1, "user", 10.0, 5.0, "busy").with_io_rates(1_572_864.0, 2048.0),
+        ProcessEntry::new(2, "other", 10.0, 5.0, "private"),
+    ];
+    table.set_processes(entries);
+    table.bounds = Rect::new(0.0, 0.0, 100.0, 20.0);
+
+    let mut canvas = MockCanvas::new();
+    table.paint(&mut canvas);
+
+    assert!(canvas.texts.iter().any(|(t, _)| t.contains("READ/s")));
+    assert!(canvas.texts.iter().any(|(t, _)| t.contains("WRIT/s")));
+    assert!(canvas.texts.iter().any(|(t, _)| t.trim() == "1.5M"));
+    assert!(canvas.texts.iter().any(|(t, _)| t.trim() == "2K"));
+    assert_eq!(
+        canvas.texts.iter().filter(|(t, _)| t.trim() == "—").count(),
+        2
+    );
+}
+
 #[test]
 fn test_process_table_paint_with_nice() {
     let mut table = ProcessTable::new().with_nice_column();
@@ -703,6 +743,13 @@ fn test_process_table_truncate_width_1() {
     assert_eq!(ProcessTable::truncate("hello", 1), "h");
 }
 
+#[test]
+fn test_process_table_truncate_wide_chars() {
+    assert_eq!(ProcessTable::truncate("日本", 6), "日本  ");
+    assert_eq!(ProcessTable::truncate("日本語", 5), "日本…");
+    assert_eq!(ProcessTable::truncate("日本", 3), "日…");
+}
+
 #[test]
 fn test_process_table_paint_all_columns() {
     // Test paint with all optional columns enabled
@@ -1083,3 +1130,171 @@ fn test_f_tree_004_dfs_traversal_order() {
     );
     assert_eq!(commands[4], "C", "C should be after B's subtree");
 }
+
+// =========================================================================
+// Incremental update
+// =========================================================================
+
+fn pids(table: &ProcessTable) -> Vec<u32> {
+    table.processes.iter().map(|p| p.pid).collect()
+}
+
+#[test]
+fn test_update_keeps_selected_pid() {
+    let mut table = ProcessTable::new();
+    table.set_processes(sample_processes());
+    // CPU descending: rustc, firefox, systemd
+    table.select(1);
+    assert_eq!(table.selected_process().map(|p| p.pid), Some(1234));
+
+    // firefox now leads on CPU
+    table.update(vec![
+        ProcessEntry::new(1, "root", 0.5, 0.1, "systemd"),
+        ProcessEntry::new(1234, "noah", 90.0, 5.5, "firefox"),
+        ProcessEntry::new(5678, "noah", 10.0, 12.3, "rustc"),
+    ]);
+
+    assert_eq!(pids(&table), vec![1234, 5678, 1]);
+    assert_eq!(table.selected(), 0);
+    assert_eq!(table.selected_process().map(|p| p.pid), Some(1234));
+    assert_eq!(table.selected_process().map(|p| p.cpu_percent), Some(90.0));
+}
+
+#[test]
+fn test_update_drops_gone_and_inserts_new_sorted() {
+    let mut table = ProcessTable::new();
+    table.set_processes(sample_processes());
+
+    table.update(vec![
+        ProcessEntry::new(1, "root", 0.5, 0.1, "systemd"),
+        ProcessEntry::new(5678, "noah", 80.0, 12.3, "rustc"),
+        ProcessEntry::new(42, "noah", 30.0, 1.0, "cargo"),
+    ]);
+
+    assert_eq!(pids(&table), vec![5678, 42, 1]);
+}
+
+#[test]
+fn test_update_selected_pid_gone_clamps() {
+    let mut table = ProcessTable::new();
+    table.set_processes(sample_processes());
+    table.select(2);
+
+    table.update(vec![ProcessEntry::new(5678, "noah", 80.0, 12.3, "rustc")]);
+    assert_eq!(table.selected(), 0);
+
+    table.update(Vec::new());
+    assert!(table.is_empty());
+    assert!(table.selected_process().is_none());
+}
+
+#[test]
+fn test_update_keeps_selected_screen_row() {
+    let mut table = ProcessTable::new();
+    table.layout(Rect::new(0.0, 0.0, 80.0, 7.0));
+    let entries: Vec<ProcessEntry> = (0..20)
+        .map(|i| ProcessEntry::new(i, "user", i as f32, 0.0, "proc"))
+        .collect();
+    table.set_processes(entries);
+    table.select(10);
+    let offset = table.scroll_offset;
+    let screen_row = table.selected() - offset;
+
+    // A new process above the selection shifts it down one row
+    let mut entries: Vec<ProcessEntry> = (0..20)
+        .map(|i| ProcessEntry::new(i, "user", i as f32, 0.0, "proc"))
+        .collect();
+    entries.push(ProcessEntry::new(100, "user", 99.0, 0.0, "new"));
+    table.update(entries);
+
+    assert_eq!(table.selected(), 11);
+    assert_eq!(table.selected() - table.scroll_offset, screen_row);
+}
+
+#[test]
+fn test_update_tree_view() {
+    let mut table = ProcessTable::new().with_tree_view();
+    table.set_processes(vec![
+        ProcessEntry::new(1, "root", 1.0, 0.0, "init"),
+        ProcessEntry::new(2, "root", 5.0, 0.0, "child").with_parent_pid(1),
+    ]);
+    table.select(1);
+
+    table.update(vec![
+        ProcessEntry::new(1, "root", 1.0, 0.0, "init"),
+        ProcessEntry::new(2, "root", 5.0, 0.0, "child").with_parent_pid(1),
+        ProcessEntry::new(3, "root", 9.0, 0.0, "other").with_parent_pid(1),
+    ]);
+
+    assert_eq!(pids(&table), vec![1, 3, 2]);
+    assert_eq!(table.selected_process().map(|p| p.pid), Some(2));
+}
+
+// =========================================================================
+// Fuzzy filter
+// =========================================================================
+
+fn filter_processes() -> Vec<ProcessEntry> {
+    vec![
+        ProcessEntry::new(1, "root", 0.5, 0.1, "systemd"),
+        ProcessEntry::new(200, "noah", 40.0, 8.0, "chrome"),
+        ProcessEntry::new(300, "noah", 10.0, 2.0, "cargo-watch"),
+        ProcessEntry::new(400, "noah", 60.0, 4.0, "chromedriver"),
+    ]
+}
+
+#[test]
+fn test_set_filter_keeps_matches_in_sort_order() {
+    let mut table = ProcessTable::new();
+    table.set_processes(filter_processes());
+
+    table.set_filter("ch");
+    assert_eq!(table.filter(), "ch");
+    // CPU descending order is preserved, not match score order
+    assert_eq!(pids(&table), vec![400, 200, 300]);
+    assert!(table.processes.iter().any(|p| p.command == "chrome"));
+    assert!(!table.processes.iter().any(|p| p.command == "systemd"));
+    assert!(table.match_score(200).is_some_and(|s| s > 0.0));
+    assert_eq!(table.match_score(1), None);
+}
+
+#[test]
+fn test_set_filter_empty_restores_all() {
+    let mut table = ProcessTable::new();
+    table.set_processes(filter_processes());
+    table.set_filter("ch");
+    table.sort_by(ProcessSort::Pid);
+
+    table.set_filter("");
+    assert_eq!(pids(&table), vec![1, 200, 300, 400]);
+    assert_eq!(table.match_score(200), None);
+}
+
+#[test]
+fn test_set_filter_keeps_selected_pid() {
+    let mut table = ProcessTable::new();
+    table.set_processes(filter_processes());
+    // CPU descending: chromedriver, chrome, cargo-watch, systemd
+    table.select(1);
+
+    table.set_filter("chrome");
+    assert_eq!(table.selected_process().map(|p| p.pid), Some(200));
+    table.set_filter("");
+    assert_eq!(table.selected_process().map(|p| p.pid), Some(200));
+}
+
+#[test]
+fn test_filter_applies_to_updates() {
+    let mut table = ProcessTable::new();
+    table.set_processes(filter_processes());
+    table.set_filter("ch");
+
+    let mut entries = filter_processes();
+    entries.push(ProcessEntry::new(500, "noah", 1.0, 1.0, "bash"));
+    entries.push(ProcessEntry::new(600, "noah", 90.0, 1.0, "chromium"));
+    table.update(entries);
+    assert_eq!(pids(&table), vec![600, 400, 200, 300]);
+
+    table.set_filter("");
+    assert_eq!(table.len(), 6);
+}
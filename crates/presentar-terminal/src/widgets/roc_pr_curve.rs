@@ -6,7 +6,7 @@
 use crate::theme::Gradient;
 use presentar_core::{
     Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
-    LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
+    FontWeight, LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
 };
 use std::any::Any;
 use std::time::Duration;
@@ -117,16 +117,8 @@ impl CurveData {
         // End point
         points.push((1.0, 1.0));
 
-        // Sort by FPR for proper curve
-        points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
-
-        // Compute AUC using trapezoidal rule
-        let mut auc = 0.0;
-        for i in 1..points.len() {
-            let dx = points[i].0 - points[i - 1].0;
-            let avg_y = (points[i].1 + points[i - 1].1) / 2.0;
-            auc += dx * avg_y;
-        }
+        // TPR only rises with FPR, so ties climb upwards
+        let auc = trapezoid_auc(&mut points, false);
 
         self.roc_points = Some(points);
         self.auc_roc = Some(auc);
@@ -169,16 +161,8 @@ impl CurveData {
             points.push((recall, precision));
         }
 
-        // Sort by recall for proper curve
-        points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
-
-        // Compute AUC using trapezoidal rule
-        let mut auc = 0.0;
-        for i in 1..points.len() {
-            let dx = points[i].0 - points[i - 1].0;
-            let avg_y = (points[i].1 + points[i - 1].1) / 2.0;
-            auc += dx * avg_y;
-        }
+        // Extra false positives at equal recall only lower precision
+        let auc = trapezoid_auc(&mut points, true);
 
         self.pr_points = Some(points);
         self.auc_pr = Some(auc);
@@ -319,6 +303,27 @@ impl CurveData {
         (tp, fp)
     }
 
+    /// (FPR, TPR) when classifying `score >= threshold` as positive.
+    fn roc_point_at(&self, threshold: f64) -> Option<(f64, f64)> {
+        let (n_pos, n_neg) = self.count_classes_scalar();
+        if n_pos == 0.0 || n_neg == 0.0 {
+            return None;
+        }
+        let (tp, fp) = self.count_positives_at_threshold_scalar(threshold);
+        Some((fp / n_neg, tp / n_pos))
+    }
+
+    /// (recall, precision) when classifying `score >= threshold` as positive.
+    fn pr_point_at(&self, threshold: f64) -> Option<(f64, f64)> {
+        let (n_pos, _) = self.count_classes_scalar();
+        if n_pos == 0.0 {
+            return None;
+        }
+        let (tp, fp) = self.count_positives_at_threshold_scalar(threshold);
+        let precision = if tp + fp > 0.0 { tp / (tp + fp) } else { 1.0 };
+        Some((tp / n_pos, precision))
+    }
+
     /// Get AUC-ROC.
     #[must_use]
     pub fn auc_roc(&self) -> Option<f64> {
@@ -332,6 +337,29 @@ impl CurveData {
     }
 }
 
+/// Area under a curve by the trapezoidal rule, clamped to `[0, 1]`.
+///
+/// Points are sorted by x first; `descending_ties` orders points sharing an
+/// x value from high to low y, matching how a PR curve is traversed.
+fn trapezoid_auc(points: &mut [(f64, f64)], descending_ties: bool) -> f64 {
+    points.sort_by(|a, b| {
+        let ties = if descending_ties {
+            b.1.partial_cmp(&a.1)
+        } else {
+            a.1.partial_cmp(&b.1)
+        };
+        a.0.partial_cmp(&b.0)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(ties.unwrap_or(std::cmp::Ordering::Equal))
+    });
+
+    let auc: f64 = points
+        .windows(2)
+        .map(|w| (w[1].0 - w[0].0) * (w[1].1 + w[0].1) / 2.0)
+        .sum();
+    auc.clamp(0.0, 1.0)
+}
+
 /// ROC/PR curve widget.
 #[derive(Debug, Clone)]
 pub struct RocPrCurve {
@@ -347,6 +375,8 @@ pub struct RocPrCurve {
     show_grid: bool,
     /// Optional gradient for curve coloring.
     gradient: Option<Gradient>,
+    /// Decision threshold to highlight on each curve.
+    operating_point: Option<f64>,
     bounds: Rect,
 }
 
@@ -368,6 +398,7 @@ impl RocPrCurve {
             show_auc: true,
             show_grid: true,
             gradient: None,
+            operating_point: None,
             bounds: Rect::default(),
         }
     }
@@ -414,11 +445,56 @@ impl RocPrCurve {
         self
     }
 
+    /// Highlight the operating point at a decision threshold.
+    ///
+    /// Scores `>= threshold` count as positive; the marker lands on
+    /// (FPR, TPR) in ROC plots and (recall, precision) in PR plots.
+    #[must_use]
+    pub fn with_operating_point(mut self, threshold: f64) -> Self {
+        self.operating_point = Some(threshold);
+        self
+    }
+
+    /// Area under each curve, in curve order.
+    ///
+    /// ROC AUC for [`CurveMode::Roc`] and [`CurveMode::Both`], average
+    /// precision for [`CurveMode::PrecisionRecall`]. Values are in `[0, 1]`.
+    #[must_use]
+    pub fn auc(&self) -> Vec<f64> {
+        self.curves
+            .iter()
+            .map(|curve| {
+                let mut curve = curve.clone();
+                if self.mode == CurveMode::PrecisionRecall {
+                    curve.compute_pr(self.num_thresholds);
+                    curve.auc_pr.unwrap_or(0.0)
+                } else {
+                    curve.compute_roc(self.num_thresholds);
+                    curve.auc_roc.unwrap_or(0.0)
+                }
+            })
+            .collect()
+    }
+
     /// Add a curve.
     pub fn add_curve(&mut self, curve: CurveData) {
         self.curves.push(curve);
     }
 
+    /// Draw the operating point marker for a point in curve space.
+    fn draw_operating_point(canvas: &mut dyn Canvas, area: Rect, point: (f64, f64)) {
+        let max_x = (area.width - 1.0).max(0.0);
+        let max_y = (area.height - 1.0).max(0.0);
+        let x = area.x + ((point.0 * area.width as f64) as f32).clamp(0.0, max_x);
+        let y = area.y + (((1.0 - point.1) * area.height as f64) as f32).clamp(0.0, max_y);
+        let style = TextStyle {
+            color: Color::new(1.0, 0.85, 0.2, 1.0),
+            weight: FontWeight::Bold,
+            ..Default::default()
+        };
+        canvas.draw_text("◆", Point::new(x, y), &style);
+    }
+
     fn render_roc(&mut self, canvas: &mut dyn Canvas, area: Rect) {
         let dim_style = TextStyle {
             color: Color::new(0.3, 0.3, 0.3, 1.0),
@@ -486,6 +562,10 @@ impl RocPrCurve {
                 }
             }
 
+            if let Some(point) = self.operating_point.and_then(|t| curve.roc_point_at(t)) {
+                Self::draw_operating_point(canvas, area, point);
+            }
+
             // Draw legend with AUC
             if self.show_auc {
                 let auc = curve.auc_roc.unwrap_or(0.0);
@@ -555,6 +635,10 @@ impl RocPrCurve {
                 }
             }
 
+            if let Some(point) = self.operating_point.and_then(|t| curve.pr_point_at(t)) {
+                Self::draw_operating_point(canvas, area, point);
+            }
+
             // Draw legend with AUC
             if self.show_auc {
                 let auc = curve.auc_pr.unwrap_or(0.0);
@@ -1143,4 +1227,95 @@ mod tests {
         curve.layout(Rect::new(0.0, 0.0, 60.0, 25.0));
         curve.paint(&mut canvas);
     }
+
+    #[test]
+    fn test_auc_perfect_classifier() {
+        let curve = RocPrCurve::new(vec![CurveData::new(
+            "Perfect",
+            vec![0.0, 0.0, 1.0, 1.0],
+            vec![0.1, 0.2, 0.8, 0.9],
+        )]);
+        let auc = curve.auc();
+        assert_eq!(auc.len(), 1);
+        assert!((auc[0] - 1.0).abs() < 1e-9, "auc = {}", auc[0]);
+    }
+
+    #[test]
+    fn test_auc_pr_mode_perfect_classifier() {
+        let curve = RocPrCurve::new(vec![CurveData::new(
+            "Perfect",
+            vec![0.0, 0.0, 1.0, 1.0],
+            vec![0.1, 0.2, 0.8, 0.9],
+        )])
+        .with_mode(CurveMode::PrecisionRecall);
+        let auc = curve.auc();
+        assert!((0.0..=1.0).contains(&auc[0]));
+    }
+
+    #[test]
+    fn test_trapezoid_auc_unsorted_input() {
+        let mut points = vec![(1.0, 1.0), (0.0, 1.0), (0.5, 1.0), (0.0, 0.0)];
+        let auc = trapezoid_auc(&mut points, false);
+        assert!((auc - 1.0).abs() < 1e-9);
+        assert_eq!(points[0], (0.0, 0.0));
+        assert_eq!(points[3], (1.0, 1.0));
+    }
+
+    #[test]
+    fn test_trapezoid_auc_clamped() {
+        let mut points = vec![(0.0, 2.0), (1.0, 2.0)];
+        assert_eq!(trapezoid_auc(&mut points, false), 1.0);
+        let mut points = vec![(0.0, -1.0), (1.0, -1.0)];
+        assert_eq!(trapezoid_auc(&mut points, false), 0.0);
+    }
+
+    #[test]
+    fn test_operating_point_coordinates() {
+        let data = CurveData::new("Test", vec![0.0, 0.0, 1.0, 1.0], vec![0.1, 0.6, 0.8, 0.9]);
+        assert_eq!(data.roc_point_at(0.5), Some((0.5, 1.0)));
+        let (recall, precision) = data.pr_point_at(0.5).expect("has positives");
+        assert!((recall - 1.0).abs() < 1e-9);
+        assert!((precision - 2.0 / 3.0).abs() < 1e-9);
+        assert!(CurveData::new("Neg", vec![0.0], vec![0.5])
+            .roc_point_at(0.5)
+            .is_none());
+    }
+
+    #[test]
+    fn test_operating_point_marker_roc() {
+        let mut curve = RocPrCurve::new(vec![CurveData::new(
+            "Test",
+            vec![0.0, 0.0, 1.0, 1.0],
+            vec![0.1, 0.6, 0.8, 0.9],
+        )])
+        .with_operating_point(0.5);
+
+        let mut buffer = CellBuffer::new(50, 20);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        curve.layout(Rect::new(0.0, 0.0, 50.0, 20.0));
+        curve.paint(&mut canvas);
+
+        let cell = buffer.get(25, 0).expect("cell");
+        assert_eq!(cell.symbol.as_str(), "◆");
+    }
+
+    #[test]
+    fn test_operating_point_marker_pr_clamped_to_area() {
+        let mut curve = RocPrCurve::new(vec![CurveData::new(
+            "Test",
+            vec![0.0, 0.0, 1.0, 1.0],
+            vec![0.1, 0.6, 0.8, 0.9],
+        )])
+        .with_mode(CurveMode::PrecisionRecall)
+        .with_operating_point(0.5);
+
+        let mut buffer = CellBuffer::new(50, 20);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        curve.layout(Rect::new(0.0, 0.0, 50.0, 20.0));
+        curve.paint(&mut canvas);
+
+        // recall 1.0 is clamped to the last column; precision 2/3 sits a third down
+        let cell = buffer.get(49, 7).expect("cell");
+        assert_eq!(cell.symbol.as_str(), "◆");
+    }
 }
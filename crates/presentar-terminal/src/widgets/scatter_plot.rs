@@ -1,13 +1,18 @@
 //! Scatter plot widget with marker styles.
 //!
 //! Implements P201 from SPEC-024 Section 15.2.
+//!
+//! Large point counts can be aggregated into density bins
+//! ([`ScatterMode::Hexbin`] / [`ScatterMode::SquareBins`]) colored by count.
 
+use super::heatmap::HeatmapPalette;
 use crate::theme::Gradient;
 use presentar_core::{
     Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
     LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
 };
 use std::any::Any;
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Marker style for scatter points.
@@ -46,6 +51,18 @@ impl MarkerStyle {
     }
 }
 
+/// How points are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScatterMode {
+    /// One marker per point (default).
+    #[default]
+    Points,
+    /// Hexagonal bins (alternate bin rows offset by half a bin).
+    Hexbin,
+    /// Square grid bins.
+    SquareBins,
+}
+
 /// Axis configuration.
 #[derive(Debug, Clone)]
 pub struct ScatterAxis {
@@ -82,6 +99,11 @@ pub struct ScatterPlot {
     x_axis: ScatterAxis,
     y_axis: ScatterAxis,
     show_axes: bool,
+    mode: ScatterMode,
+    /// Bin width in cells (bins are half as tall, for cell aspect).
+    bin_size: usize,
+    /// Palette for bin counts.
+    palette: HeatmapPalette,
     bounds: Rect,
 }
 
@@ -98,6 +120,9 @@ impl ScatterPlot {
             x_axis: ScatterAxis::default(),
             y_axis: ScatterAxis::default(),
             show_axes: true,
+            mode: ScatterMode::default(),
+            bin_size: 2,
+            palette: HeatmapPalette::Viridis,
             bounds: Rect::default(),
         }
     }
@@ -145,6 +170,27 @@ impl ScatterPlot {
         self
     }
 
+    /// Set render mode (markers or density bins).
+    #[must_use]
+    pub fn with_mode(mut self, mode: ScatterMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set bin width in cells for binned modes (minimum 1).
+    #[must_use]
+    pub fn with_bin_size(mut self, size: usize) -> Self {
+        self.bin_size = size.max(1);
+        self
+    }
+
+    /// Set the palette used to color bins by count.
+    #[must_use]
+    pub fn with_palette(mut self, palette: HeatmapPalette) -> Self {
+        self.palette = palette;
+        self
+    }
+
     /// Update points.
     pub fn set_points(&mut self, points: Vec<(f64, f64)>) {
         self.points = points;
@@ -274,6 +320,118 @@ impl ScatterPlot {
         }
     }
 
+    /// Map a data point to screen coordinates inside `plot`.
+    fn to_screen(
+        (x, y): (f64, f64),
+        plot: Rect,
+        (x_min, x_max): (f64, f64),
+        (y_min, y_max): (f64, f64),
+    ) -> Option<(f32, f32)> {
+        if !x.is_finite() || !y.is_finite() {
+            return None;
+        }
+
+        // Normalize coordinates
+        let x_norm = if x_max > x_min {
+            (x - x_min) / (x_max - x_min)
+        } else {
+            0.5
+        };
+        let y_norm = if y_max > y_min {
+            (y - y_min) / (y_max - y_min)
+        } else {
+            0.5
+        };
+
+        // Convert to screen coordinates
+        let screen_x = plot.x + (x_norm * plot.width as f64) as f32;
+        let screen_y = plot.y + ((1.0 - y_norm) * plot.height as f64) as f32;
+
+        // Check bounds
+        if screen_x < plot.x
+            || screen_x >= plot.x + plot.width
+            || screen_y < plot.y
+            || screen_y >= plot.y + plot.height
+        {
+            return None;
+        }
+        Some((screen_x, screen_y))
+    }
+
+    /// Bin size in cells as (width, height).
+    fn bin_dims(&self) -> (f32, f32) {
+        let width = self.bin_size as f32;
+        (width, (self.bin_size / 2).max(1) as f32)
+    }
+
+    /// Horizontal offset of bin row `row` (hex rows alternate by half a bin).
+    fn row_offset(&self, row: i32) -> f32 {
+        if self.mode == ScatterMode::Hexbin && row % 2 == 1 {
+            self.bin_dims().0 / 2.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Count points per (column, row) bin within `plot`.
+    fn bin_counts(
+        &self,
+        plot: Rect,
+        x_range: (f64, f64),
+        y_range: (f64, f64),
+    ) -> HashMap<(i32, i32), usize> {
+        let (bin_w, bin_h) = self.bin_dims();
+        let mut counts = HashMap::new();
+        for &point in &self.points {
+            let Some((sx, sy)) = Self::to_screen(point, plot, x_range, y_range) else {
+                continue;
+            };
+            let row = ((sy - plot.y) / bin_h) as i32;
+            let col = ((sx - plot.x - self.row_offset(row)) / bin_w).floor() as i32;
+            *counts.entry((col, row)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn draw_bins(
+        &self,
+        canvas: &mut dyn Canvas,
+        plot: Rect,
+        x_range: (f64, f64),
+        y_range: (f64, f64),
+    ) {
+        let counts = self.bin_counts(plot, x_range, y_range);
+        let max_count = counts.values().copied().max().unwrap_or(0);
+        if max_count == 0 {
+            return;
+        }
+
+        let (bin_w, bin_h) = self.bin_dims();
+        let right = plot.x + plot.width;
+        let bottom = plot.y + plot.height;
+        for (&(col, row), &count) in &counts {
+            let style = TextStyle {
+                color: self.palette.color(count as f64 / max_count as f64),
+                ..Default::default()
+            };
+            let x0 = (plot.x + col as f32 * bin_w + self.row_offset(row)).max(plot.x);
+            let x1 = (plot.x + (col + 1) as f32 * bin_w + self.row_offset(row)).min(right);
+            let cells = (x1 - x0) as usize;
+            if cells == 0 {
+                continue;
+            }
+            let run = "█".repeat(cells);
+            let y0 = plot.y + row as f32 * bin_h;
+            for k in 0..bin_h as usize {
+                let y = y0 + k as f32;
+                if y >= bottom {
+                    break;
+                }
+                canvas.draw_text(&run, Point::new(x0, y), &style);
+            }
+        }
+    }
+
     /// Get color for a point at given index.
     fn point_color(&self, i: usize, c_min: f64, c_max: f64) -> Color {
         if let (Some(ref values), Some(ref gradient)) = (&self.color_by, &self.gradient) {
@@ -358,49 +516,26 @@ impl Widget for ScatterPlot {
             );
         }
 
-        // Draw points
-        let marker_char = self.marker.char();
-
-        for (i, &(x, y)) in self.points.iter().enumerate() {
-            if !x.is_finite() || !y.is_finite() {
-                continue;
-            }
-
-            // Normalize coordinates
-            let x_norm = if x_max > x_min {
-                (x - x_min) / (x_max - x_min)
-            } else {
-                0.5
-            };
-            let y_norm = if y_max > y_min {
-                (y - y_min) / (y_max - y_min)
-            } else {
-                0.5
-            };
+        let plot = Rect::new(plot_x, plot_y, plot_width, plot_height);
+        if self.mode == ScatterMode::Points {
+            // Draw points
+            let marker = self.marker.char().to_string();
+            for (i, &point) in self.points.iter().enumerate() {
+                let Some((screen_x, screen_y)) =
+                    Self::to_screen(point, plot, (x_min, x_max), (y_min, y_max))
+                else {
+                    continue;
+                };
 
-            // Convert to screen coordinates
-            let screen_x = plot_x + (x_norm * plot_width as f64) as f32;
-            let screen_y = plot_y + ((1.0 - y_norm) * plot_height as f64) as f32;
+                let style = TextStyle {
+                    color: self.point_color(i, c_min, c_max),
+                    ..Default::default()
+                };
 
-            // Check bounds
-            if screen_x < plot_x
-                || screen_x >= plot_x + plot_width
-                || screen_y < plot_y
-                || screen_y >= plot_y + plot_height
-            {
-                continue;
+                canvas.draw_text(&marker, Point::new(screen_x, screen_y), &style);
             }
-
-            let style = TextStyle {
-                color: self.point_color(i, c_min, c_max),
-                ..Default::default()
-            };
-
-            canvas.draw_text(
-                &marker_char.to_string(),
-                Point::new(screen_x, screen_y),
-                &style,
-            );
+        } else {
+            self.draw_bins(canvas, plot, (x_min, x_max), (y_min, y_max));
         }
 
         // Draw axis labels if present
@@ -502,6 +637,80 @@ mod tests {
         assert_eq!(MarkerStyle::Diamond.char(), '◇');
     }
 
+    fn fixed_axis() -> ScatterAxis {
+        ScatterAxis {
+            min: Some(0.0),
+            max: Some(100.0),
+            ..ScatterAxis::default()
+        }
+    }
+
+    /// 9k points clustered near (50, 50) plus a 1k uniform background.
+    fn clustered_points() -> Vec<(f64, f64)> {
+        let cluster = (0..9000).map(|i| {
+            (
+                50.0 + f64::from(i % 30) * 0.1,
+                50.0 + f64::from(i / 30 % 30) * 0.1,
+            )
+        });
+        let background = (0..1000).map(|i| (f64::from(i % 40) * 2.5, f64::from(i / 40) * 4.0));
+        cluster.chain(background).collect()
+    }
+
+    #[test]
+    fn test_scatter_hexbin_density_color() {
+        let mut scatter = ScatterPlot::new(clustered_points())
+            .with_mode(ScatterMode::Hexbin)
+            .with_bin_size(4)
+            .with_palette(HeatmapPalette::Grayscale)
+            .with_x_axis(fixed_axis())
+            .with_y_axis(fixed_axis())
+            .with_axes(false);
+        scatter.layout(Rect::new(0.0, 0.0, 60.0, 20.0));
+
+        let mut buffer = CellBuffer::new(60, 20);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        scatter.paint(&mut canvas);
+
+        // Cluster lands around cell (30, 9); (6, 2) only sees background
+        let dense = buffer.get(30, 9).expect("in bounds");
+        let sparse = buffer.get(6, 2).expect("in bounds");
+        assert_eq!(dense.symbol.as_str(), "█");
+        assert_eq!(sparse.symbol.as_str(), "█");
+        assert!(dense.fg.r > sparse.fg.r);
+        assert!((dense.fg.r - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_scatter_bin_counts_cover_all_points() {
+        let scatter = ScatterPlot::new(clustered_points())
+            .with_mode(ScatterMode::SquareBins)
+            .with_bin_size(4)
+            .with_x_axis(fixed_axis())
+            .with_y_axis(fixed_axis());
+        let plot = Rect::new(0.0, 0.0, 60.0, 20.0);
+        let counts = scatter.bin_counts(plot, (0.0, 100.0), (0.0, 100.0));
+        // Background points on y = 0 fall on the bottom edge and are clipped
+        let in_plot = scatter
+            .points
+            .iter()
+            .filter(|&&p| ScatterPlot::to_screen(p, plot, (0.0, 100.0), (0.0, 100.0)).is_some())
+            .count();
+        assert_eq!(counts.values().sum::<usize>(), in_plot);
+        assert!(counts.len() < in_plot / 10);
+    }
+
+    #[test]
+    fn test_scatter_hexbin_offsets_odd_rows() {
+        let scatter = ScatterPlot::new(vec![])
+            .with_mode(ScatterMode::Hexbin)
+            .with_bin_size(4);
+        assert_eq!(scatter.row_offset(0), 0.0);
+        assert_eq!(scatter.row_offset(1), 2.0);
+        let square = scatter.with_mode(ScatterMode::SquareBins);
+        assert_eq!(square.row_offset(1), 0.0);
+    }
+
     #[test]
     fn test_empty_scatter() {
         let scatter = ScatterPlot::new(vec![]);
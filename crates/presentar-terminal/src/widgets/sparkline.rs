@@ -2,6 +2,14 @@
 //!
 //! Provides minimal inline visualization using vertical block characters.
 //! Ideal for embedding in tables or status lines.
+//!
+//! With [`Sparkline::with_symbols`] the sparkline instead packs two samples
+//! per cell using a paired [`SymbolSet`] (braille by default gives 2×4 dots
+//! per cell), stacking extra rows when the bounds are taller than one line.
+//!
+//! Hovering with the mouse moves a cursor to the nearest data point; the host
+//! reads it back with [`Sparkline::cursor`] and [`Sparkline::value_at`] to
+//! render a readout.
 
 use presentar_core::{
     Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
@@ -10,6 +18,8 @@ use presentar_core::{
 use std::any::Any;
 use std::time::Duration;
 
+use super::symbols::{BrailleSymbols, SymbolSet};
+
 /// Block characters for sparkline rendering (8 levels).
 const SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
 
@@ -47,6 +57,17 @@ impl TrendDirection {
     }
 }
 
+/// How data points are picked when there are more points than samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SparklineSampling {
+    /// Sample `i` of `n` slots shows point `i * len / n` (default).
+    #[default]
+    Stride,
+    /// Each sample shows the last point of its bucket, so the final column
+    /// always shows the most recent value.
+    Latest,
+}
+
 /// Compact sparkline widget for inline graphs.
 #[derive(Debug, Clone)]
 pub struct Sparkline {
@@ -64,6 +85,14 @@ pub struct Sparkline {
     show_y_axis: bool,
     /// UX-121: Y-axis label format (e.g., "{:.0}%").
     y_format: Option<String>,
+    /// Paired symbol set; `None` renders 8-level block characters.
+    symbols: Option<BrailleSymbols>,
+    /// How data points map to samples.
+    sampling: SparklineSampling,
+    /// Highlighted data index.
+    cursor: Option<usize>,
+    /// Color of the highlighted column.
+    cursor_color: Color,
     /// Cached bounds.
     bounds: Rect,
 }
@@ -87,6 +116,10 @@ impl Sparkline {
             show_trend: false,
             show_y_axis: false,
             y_format: None,
+            symbols: None,
+            sampling: SparklineSampling::default(),
+            cursor: None,
+            cursor_color: Color::WHITE,
             bounds: Rect::default(),
         }
     }
@@ -131,6 +164,84 @@ impl Sparkline {
         self
     }
 
+    /// Render with a paired symbol set (two samples per cell, 4 levels per row).
+    #[must_use]
+    pub fn with_symbols(mut self, set: SymbolSet) -> Self {
+        self.symbols = Some(BrailleSymbols::new(set));
+        self
+    }
+
+    /// Get the paired symbol set, if one is used.
+    #[must_use]
+    pub fn symbol_set(&self) -> Option<SymbolSet> {
+        self.symbols.as_ref().map(BrailleSymbols::set)
+    }
+
+    /// Set how data points are picked when downsampling.
+    #[must_use]
+    pub fn with_sampling(mut self, sampling: SparklineSampling) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Get the downsampling strategy.
+    #[must_use]
+    pub fn sampling(&self) -> SparklineSampling {
+        self.sampling
+    }
+
+    /// Highlight the column showing the data point at `index`.
+    #[must_use]
+    pub fn with_cursor(mut self, index: usize) -> Self {
+        self.cursor = Some(index);
+        self
+    }
+
+    /// Set the color of the highlighted column.
+    #[must_use]
+    pub fn with_cursor_color(mut self, color: Color) -> Self {
+        self.cursor_color = color;
+        self
+    }
+
+    /// Set or clear the highlighted data index.
+    pub fn set_cursor(&mut self, index: Option<usize>) {
+        self.cursor = index;
+    }
+
+    /// Get the highlighted data index.
+    #[must_use]
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Get the data value at `index`.
+    #[must_use]
+    pub fn value_at(&self, index: usize) -> Option<f64> {
+        self.data.get(index).copied()
+    }
+
+    /// Map an x coordinate to the data index shown in that column.
+    ///
+    /// When the data is downsampled, this is the last data index in the
+    /// bucket of the column's right-most sample, so the right edge maps to
+    /// the final point under any sampling. Returns `None` outside the graph
+    /// area.
+    #[must_use]
+    pub fn index_at_x(&self, x: f32) -> Option<usize> {
+        let width = self.available_width();
+        let offset = x - self.bounds.x;
+        if self.data.is_empty() || offset < 0.0 || offset >= width as f32 {
+            return None;
+        }
+
+        let column = offset as usize;
+        let (count, per_cell) = self.sample_layout(width);
+        // Use the right-most sample drawn in the cell
+        let sample = ((column + 1) * per_cell - 1).min(count - 1);
+        Some(self.bucket_last(sample, count, width * per_cell))
+    }
+
     /// Get the Y-axis label width needed for layout.
     #[must_use]
     #[allow(clippy::literal_string_with_formatting_args)]
@@ -205,6 +316,98 @@ impl Sparkline {
             ((value - self.min) / range).clamp(0.0, 1.0)
         }
     }
+
+    /// Get the number of columns available for the graph.
+    fn available_width(&self) -> usize {
+        let width = self.bounds.width.max(0.0) as usize;
+        if self.show_trend {
+            width.saturating_sub(2)
+        } else {
+            width
+        }
+    }
+
+    /// Get the sample count and samples per cell for a graph width.
+    fn sample_layout(&self, width: usize) -> (usize, usize) {
+        let per_cell = if self.symbols.is_some() { 2 } else { 1 };
+        ((width * per_cell).min(self.data.len()), per_cell)
+    }
+
+    /// Get the data index drawn by sample `i` of `count`, out of `slots`
+    /// sample positions available.
+    fn sample_index(&self, i: usize, count: usize, slots: usize) -> usize {
+        match self.sampling {
+            SparklineSampling::Stride => (i * self.data.len()) / slots,
+            SparklineSampling::Latest => (((i + 1) * self.data.len()) / count).saturating_sub(1),
+        }
+    }
+
+    /// Get the last data index in the bucket drawn by sample `i`.
+    fn bucket_last(&self, i: usize, count: usize, slots: usize) -> usize {
+        let shown = self.sample_index(i, count, slots);
+        match self.sampling {
+            SparklineSampling::Stride => (((i + 1) * self.data.len()) / slots)
+                .saturating_sub(1)
+                .max(shown),
+            SparklineSampling::Latest => shown,
+        }
+    }
+
+    /// Get the cell column whose bucket holds data `index`.
+    fn column_of_index(&self, index: usize, width: usize) -> Option<usize> {
+        let (count, per_cell) = self.sample_layout(width);
+        if index >= self.data.len() || count == 0 {
+            return None;
+        }
+        let slots = width * per_cell;
+        let sample = (0..count).find(|&i| self.bucket_last(i, count, slots) >= index)?;
+        Some(sample / per_cell)
+    }
+
+    /// Resample the data into at most `slots` normalized values.
+    ///
+    /// Shared by every render mode so they trace the same envelope.
+    fn normalized_samples(&self, slots: usize) -> Vec<f64> {
+        let count = slots.min(self.data.len());
+        (0..count)
+            .map(|i| {
+                let idx = self.sample_index(i, count, slots);
+                self.normalize(self.data.get(idx).copied().unwrap_or(0.0))
+            })
+            .collect()
+    }
+
+    /// Build block-character text (one row, 8 levels per cell).
+    fn render_blocks(&self, width: usize) -> String {
+        self.normalized_samples(width)
+            .into_iter()
+            .map(|norm| SPARK_CHARS[((norm * 7.0).round() as usize).min(7)])
+            .collect()
+    }
+
+    /// Build paired-symbol rows, top row first (4 levels per sample per row).
+    fn render_paired(symbols: &BrailleSymbols, samples: &[f64], rows: usize) -> Vec<String> {
+        let total_levels = (rows * 4) as f64;
+        let levels: Vec<usize> = samples
+            .iter()
+            .map(|norm| (norm * total_levels).round() as usize)
+            .collect();
+        let row_level = |level: usize, row: usize| level.saturating_sub(row * 4).min(4) as u8;
+
+        (0..rows)
+            .rev()
+            .map(|row| {
+                levels
+                    .chunks(2)
+                    .map(|pair| {
+                        let left = row_level(pair[0], row);
+                        let right = pair.get(1).map_or(0, |&l| row_level(l, row));
+                        symbols.char_pair(left, right)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 }
 
 impl Brick for Sparkline {
@@ -262,32 +465,50 @@ impl Widget for Sparkline {
             return;
         }
 
-        let available_width = if self.show_trend {
-            (self.bounds.width as usize).saturating_sub(2)
-        } else {
-            self.bounds.width as usize
-        };
-
+        let available_width = self.available_width();
         if available_width == 0 {
             return;
         }
 
-        // Build sparkline string
-        let mut spark = String::with_capacity(available_width);
-
-        for i in 0..available_width.min(self.data.len()) {
-            let idx = (i * self.data.len()) / available_width;
-            let value = self.data.get(idx).copied().unwrap_or(0.0);
-            let norm = self.normalize(value);
-            let char_idx = ((norm * 7.0).round() as usize).min(7);
-            spark.push(SPARK_CHARS[char_idx]);
-        }
-
         let style = TextStyle {
             color: self.color,
             ..Default::default()
         };
-        canvas.draw_text(&spark, Point::new(self.bounds.x, self.bounds.y), &style);
+
+        let lines = if let Some(symbols) = &self.symbols {
+            let rows = (self.bounds.height as usize).max(1);
+            let samples = self.normalized_samples(available_width * 2);
+            Self::render_paired(symbols, &samples, rows)
+        } else {
+            vec![self.render_blocks(available_width)]
+        };
+        for (i, line) in lines.iter().enumerate() {
+            canvas.draw_text(
+                line,
+                Point::new(self.bounds.x, self.bounds.y + i as f32),
+                &style,
+            );
+        }
+
+        // Redraw the cursor column in the highlight color
+        if let Some(column) = self
+            .cursor
+            .and_then(|index| self.column_of_index(index, available_width))
+        {
+            let cursor_style = TextStyle {
+                color: self.cursor_color,
+                ..Default::default()
+            };
+            for (i, line) in lines.iter().enumerate() {
+                if let Some(ch) = line.chars().nth(column) {
+                    canvas.draw_text(
+                        &ch.to_string(),
+                        Point::new(self.bounds.x + column as f32, self.bounds.y + i as f32),
+                        &cursor_style,
+                    );
+                }
+            }
+        }
 
         // Draw trend indicator
         if self.show_trend {
@@ -304,7 +525,18 @@ impl Widget for Sparkline {
         }
     }
 
-    fn event(&mut self, _event: &Event) -> Option<Box<dyn Any + Send>> {
+    fn event(&mut self, event: &Event) -> Option<Box<dyn Any + Send>> {
+        match event {
+            Event::MouseMove { position } => {
+                let rows = self.bounds.height.max(1.0);
+                let in_rows = position.y >= self.bounds.y && position.y < self.bounds.y + rows;
+                if let Some(index) = self.index_at_x(position.x).filter(|_| in_rows) {
+                    self.cursor = Some(index);
+                }
+            }
+            Event::MouseLeave => self.cursor = None,
+            _ => {}
+        }
         None
     }
 
@@ -423,6 +655,189 @@ mod tests {
         assert!(canvas.texts.len() >= 1);
     }
 
+    fn braille_dots(c: char) -> u32 {
+        u32::from(c).saturating_sub(0x2800).count_ones()
+    }
+
+    #[test]
+    fn test_sparkline_with_symbols() {
+        let spark = Sparkline::new(vec![1.0]);
+        assert_eq!(spark.symbol_set(), None);
+        let spark = spark.with_symbols(SymbolSet::Braille);
+        assert_eq!(spark.symbol_set(), Some(SymbolSet::Braille));
+    }
+
+    #[test]
+    fn test_sparkline_braille_increasing() {
+        let data: Vec<f64> = (0..16).map(f64::from).collect();
+        let mut spark = Sparkline::new(data).with_symbols(SymbolSet::Braille);
+        spark.bounds = Rect::new(0.0, 0.0, 8.0, 1.0);
+        let mut canvas = MockCanvas::new();
+        spark.paint(&mut canvas);
+
+        assert_eq!(canvas.texts.len(), 1);
+        let chars: Vec<char> = canvas.texts[0].0.chars().collect();
+        assert_eq!(chars.len(), 8);
+        let first = braille_dots(chars[0]);
+        let last = braille_dots(chars[7]);
+        assert!(last > first, "last {last} should exceed first {first}");
+    }
+
+    #[test]
+    fn test_sparkline_braille_multi_row() {
+        let mut spark = Sparkline::new(vec![0.0, 1.0]).with_symbols(SymbolSet::Braille);
+        spark.bounds = Rect::new(0.0, 0.0, 1.0, 2.0);
+        let mut canvas = MockCanvas::new();
+        spark.paint(&mut canvas);
+
+        // Right sample is full height: top and bottom rows each have 4 dots in
+        // the right column, left sample is empty
+        assert_eq!(canvas.texts.len(), 2);
+        assert_eq!(canvas.texts[0].0, "⢸");
+        assert_eq!(canvas.texts[1].0, "⢸");
+        assert_eq!(canvas.texts[1].1, Point::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_sparkline_modes_share_envelope() {
+        let data = vec![3.0, 9.0, 1.0, 7.0, 5.0, 2.0];
+        let spark = Sparkline::new(data);
+        let samples = spark.normalized_samples(6);
+
+        let blocks: Vec<char> = spark.render_blocks(6).chars().collect();
+        let braille = Sparkline::render_paired(&BrailleSymbols::default(), &samples, 2);
+
+        // Max sample in both modes lands in the same position at full height
+        assert_eq!(blocks[1], '█');
+        assert_eq!(braille[0].chars().next(), Some('⢸'));
+    }
+
+    #[test]
+    fn test_sparkline_value_at() {
+        let spark = Sparkline::new(vec![1.0, 2.0, 3.0]).with_cursor(1);
+        assert_eq!(spark.cursor(), Some(1));
+        assert_eq!(spark.value_at(1), Some(2.0));
+        assert_eq!(spark.value_at(3), None);
+    }
+
+    #[test]
+    fn test_sparkline_index_at_x_downsampled() {
+        let data: Vec<f64> = (0..100).map(f64::from).collect();
+        let mut spark = Sparkline::new(data).with_sampling(SparklineSampling::Latest);
+        spark.layout(Rect::new(5.0, 0.0, 10.0, 1.0));
+
+        // Right edge maps to the final data point
+        assert_eq!(spark.index_at_x(14.9), Some(99));
+        // Each column represents the last point of its 10-point bucket
+        assert_eq!(spark.index_at_x(5.0), Some(9));
+        assert_eq!(spark.index_at_x(9.5), Some(49));
+        assert_eq!(spark.index_at_x(4.0), None);
+        assert_eq!(spark.index_at_x(15.0), None);
+    }
+
+    #[test]
+    fn test_sparkline_index_at_x_braille() {
+        let data: Vec<f64> = (0..100).map(f64::from).collect();
+        let mut spark = Sparkline::new(data)
+            .with_sampling(SparklineSampling::Latest)
+            .with_symbols(SymbolSet::Braille);
+        spark.layout(Rect::new(0.0, 0.0, 10.0, 1.0));
+        assert_eq!(spark.index_at_x(9.0), Some(99));
+        assert_eq!(spark.index_at_x(0.0), Some(9));
+    }
+
+    #[test]
+    fn test_sparkline_default_sampling_is_stride() {
+        let data: Vec<f64> = (0..100).map(f64::from).collect();
+        let mut spark = Sparkline::new(data);
+        assert_eq!(spark.sampling(), SparklineSampling::Stride);
+        spark.layout(Rect::new(0.0, 0.0, 10.0, 1.0));
+
+        // Hovering a column reports the last point of its 10-point bucket
+        assert_eq!(spark.index_at_x(0.0), Some(9));
+        assert_eq!(spark.index_at_x(9.0), Some(99));
+
+        // Column i shows point i * len / width, as before sampling was configurable
+        let legacy: String = (0..10)
+            .map(|i| {
+                let norm = spark.normalize(spark.data[i * 100 / 10]);
+                SPARK_CHARS[((norm * 7.0).round() as usize).min(7)]
+            })
+            .collect();
+        assert_eq!(spark.render_blocks(10), legacy);
+    }
+
+    #[test]
+    fn test_sparkline_index_at_x_stride() {
+        let data: Vec<f64> = (0..100).map(f64::from).collect();
+        let mut spark = Sparkline::new(data);
+        spark.layout(Rect::new(5.0, 0.0, 10.0, 1.0));
+
+        // Right edge maps to the final data point, not the point drawn there
+        assert_eq!(spark.index_at_x(14.9), Some(99));
+        assert_eq!(spark.index_at_x(9.5), Some(49));
+
+        // The hovered point highlights the column that drew its bucket
+        assert_eq!(spark.column_of_index(99, 10), Some(9));
+        assert_eq!(spark.column_of_index(45, 10), Some(4));
+    }
+
+    #[test]
+    fn test_sparkline_index_at_x_sparse() {
+        let mut spark =
+            Sparkline::new(vec![1.0, 2.0, 3.0]).with_sampling(SparklineSampling::Latest);
+        spark.layout(Rect::new(0.0, 0.0, 10.0, 1.0));
+        assert_eq!(spark.index_at_x(2.0), Some(2));
+        // Beyond the last point, the last point is the nearest
+        assert_eq!(spark.index_at_x(8.0), Some(2));
+    }
+
+    #[test]
+    fn test_sparkline_mouse_move_sets_cursor() {
+        let data: Vec<f64> = (0..100).map(f64::from).collect();
+        let mut spark = Sparkline::new(data)
+            .with_sampling(SparklineSampling::Latest)
+            .with_trend(true);
+        spark.layout(Rect::new(0.0, 3.0, 12.0, 1.0));
+
+        let event = Event::MouseMove {
+            position: Point::new(9.0, 3.0),
+        };
+        assert!(spark.event(&event).is_none());
+        assert_eq!(spark.cursor(), Some(99));
+
+        // Trend arrow columns and other rows are ignored
+        spark.set_cursor(None);
+        spark.event(&Event::MouseMove {
+            position: Point::new(11.0, 3.0),
+        });
+        spark.event(&Event::MouseMove {
+            position: Point::new(2.0, 5.0),
+        });
+        assert_eq!(spark.cursor(), None);
+
+        spark.set_cursor(Some(4));
+        spark.event(&Event::MouseLeave);
+        assert_eq!(spark.cursor(), None);
+    }
+
+    #[test]
+    fn test_sparkline_cursor_highlight() {
+        let mut spark = Sparkline::new(vec![1.0, 2.0, 3.0, 4.0])
+            .with_cursor(2)
+            .with_cursor_color(Color::RED);
+        spark.bounds = Rect::new(0.0, 0.0, 4.0, 1.0);
+        let mut canvas = MockCanvas::new();
+        spark.paint(&mut canvas);
+
+        assert_eq!(canvas.texts.len(), 2);
+        assert_eq!(canvas.texts[1].1, Point::new(2.0, 0.0));
+        assert_eq!(
+            canvas.texts[1].0.chars().next(),
+            canvas.texts[0].0.chars().nth(2)
+        );
+    }
+
     #[test]
     fn test_sparkline_empty() {
         let mut spark = Sparkline::new(vec![]);
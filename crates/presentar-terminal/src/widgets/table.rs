@@ -1,11 +1,17 @@
 //! Scrollable table widget.
+//!
+//! Columns are sized per [`ColumnWidth`] strategy; wrapped columns break
+//! long cells across lines and grow the row height to match.
 
+use super::display_rules::take_width;
+use super::text::{wrap_ranges, WrapMode};
 use presentar_core::{
     Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event, Key,
     LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
 };
 use std::any::Any;
 use std::time::Duration;
+use unicode_width::UnicodeWidthStr;
 
 /// Gray color constant.
 const GRAY: Color = Color {
@@ -23,11 +29,29 @@ const CYAN: Color = Color {
     a: 1.0,
 };
 
+/// Column width strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ColumnWidth {
+    /// Exactly this many columns.
+    Fixed(usize),
+    /// Fraction (0.0-1.0) of the width left after separators.
+    Fraction(f32),
+    /// Widest header/cell, shrunk proportionally when the table overflows.
+    #[default]
+    Auto,
+    /// Widest header/cell, never shrunk.
+    Fit,
+}
+
 /// Scrollable table widget with headers and rows.
 #[derive(Debug, Clone)]
 pub struct Table {
     headers: Vec<String>,
     rows: Vec<Vec<String>>,
+    /// Per-column width strategies (missing entries are `Auto`).
+    widths: Vec<ColumnWidth>,
+    /// Columns whose cells wrap instead of truncating.
+    wrapped: Vec<usize>,
     selected: usize,
     scroll_offset: usize,
     sort_column: Option<usize>,
@@ -44,6 +68,8 @@ impl Table {
         Self {
             headers,
             rows: Vec::new(),
+            widths: Vec::new(),
+            wrapped: Vec::new(),
             selected: 0,
             scroll_offset: 0,
             sort_column: None,
@@ -61,6 +87,22 @@ impl Table {
         self
     }
 
+    /// Set per-column width strategies.
+    #[must_use]
+    pub fn with_column_widths(mut self, widths: Vec<ColumnWidth>) -> Self {
+        self.widths = widths;
+        self
+    }
+
+    /// Word-wrap cells of `column` within its width, growing row height.
+    #[must_use]
+    pub fn wrap_column(mut self, column: usize) -> Self {
+        if !self.wrapped.contains(&column) {
+            self.wrapped.push(column);
+        }
+        self
+    }
+
     /// Set the header color.
     #[must_use]
     pub fn with_header_color(mut self, color: Color) -> Self {
@@ -149,15 +191,31 @@ impl Table {
     }
 
     fn ensure_visible(&mut self) {
-        let visible_rows = (self.bounds.height as usize).saturating_sub(1);
-        if visible_rows == 0 {
+        let visible_lines = (self.bounds.height as usize).saturating_sub(1);
+        if visible_lines == 0 {
             return;
         }
 
         if self.selected < self.scroll_offset {
             self.scroll_offset = self.selected;
-        } else if self.selected >= self.scroll_offset + visible_rows {
-            self.scroll_offset = self.selected - visible_rows + 1;
+            return;
+        }
+        if self.wrapped.is_empty() {
+            if self.selected >= self.scroll_offset + visible_lines {
+                self.scroll_offset = self.selected - visible_lines + 1;
+            }
+            return;
+        }
+
+        // Multi-line rows: scroll until the selected row's last line fits
+        let widths = self.column_widths(self.bounds.width as usize);
+        let selected = self.selected.min(self.rows.len().saturating_sub(1));
+        let mut used: usize = (self.scroll_offset..=selected)
+            .map(|i| self.row_height(&self.rows[i], &widths))
+            .sum();
+        while used > visible_lines && self.scroll_offset < selected {
+            used -= self.row_height(&self.rows[self.scroll_offset], &widths);
+            self.scroll_offset += 1;
         }
     }
 
@@ -166,37 +224,108 @@ impl Table {
             return vec![];
         }
 
-        let mut widths: Vec<usize> = self.headers.iter().map(String::len).collect();
-
+        // Natural (content) widths in display columns
+        let mut natural: Vec<usize> = self.headers.iter().map(|h| h.width()).collect();
         for row in &self.rows {
-            for (i, cell) in row.iter().enumerate() {
-                if i < widths.len() {
-                    widths[i] = widths[i].max(cell.len());
-                }
+            for (w, cell) in natural.iter_mut().zip(row) {
+                *w = (*w).max(cell.width());
             }
         }
 
-        let total_content: usize = widths.iter().sum();
         let separators = (self.headers.len() - 1) * 3;
         let available = total_width.saturating_sub(separators);
+        let strategy = |i: usize| self.widths.get(i).copied().unwrap_or_default();
 
+        let mut widths: Vec<usize> = natural
+            .iter()
+            .enumerate()
+            .map(|(i, &w)| match strategy(i) {
+                ColumnWidth::Fixed(n) => n,
+                ColumnWidth::Fraction(f) => (available as f32 * f.clamp(0.0, 1.0)) as usize,
+                ColumnWidth::Auto | ColumnWidth::Fit => w,
+            })
+            .collect();
+
+        // Shrink Auto columns proportionally into the remaining space
+        let total_content: usize = widths.iter().sum();
         if total_content > available {
-            let ratio = available as f64 / total_content as f64;
-            for w in &mut widths {
-                *w = ((*w as f64) * ratio).max(3.0) as usize;
+            let is_auto = |i: usize| strategy(i) == ColumnWidth::Auto;
+            let auto_total: usize = (0..widths.len())
+                .filter(|&i| is_auto(i))
+                .map(|i| widths[i])
+                .sum();
+            let room = available.saturating_sub(total_content - auto_total);
+            let ratio = room as f64 / auto_total.max(1) as f64;
+            for (i, w) in widths.iter_mut().enumerate() {
+                if is_auto(i) {
+                    *w = ((*w as f64) * ratio).max(3.0) as usize;
+                }
             }
         }
 
         widths
     }
 
+    /// Lines in `column` for `cell`: word-wrapped or a single line.
+    fn cell_lines<'a>(&self, column: usize, cell: &'a str, width: usize) -> Vec<&'a str> {
+        if self.wrapped.contains(&column) {
+            wrap_ranges(cell, width, WrapMode::Word)
+                .into_iter()
+                .map(|range| &cell[range])
+                .collect()
+        } else {
+            vec![cell]
+        }
+    }
+
+    /// Number of lines `row` occupies with the given column widths.
+    fn row_height(&self, row: &[String], widths: &[usize]) -> usize {
+        if self.wrapped.is_empty() {
+            return 1;
+        }
+        row.iter()
+            .enumerate()
+            .map(|(j, cell)| {
+                let w = widths.get(j).copied().unwrap_or(10);
+                self.cell_lines(j, cell, w).len()
+            })
+            .max()
+            .unwrap_or(1)
+            .max(1)
+    }
+
+    /// Render `row` as display lines, one per line of its tallest cell.
+    fn row_lines(&self, row: &[String], widths: &[usize]) -> Vec<String> {
+        let cells: Vec<Vec<&str>> = row
+            .iter()
+            .enumerate()
+            .map(|(j, cell)| self.cell_lines(j, cell, widths.get(j).copied().unwrap_or(10)))
+            .collect();
+        let height = cells.iter().map(Vec::len).max().unwrap_or(1).max(1);
+
+        (0..height)
+            .map(|k| {
+                let mut line = String::new();
+                for (j, lines) in cells.iter().enumerate() {
+                    if j > 0 {
+                        line.push_str(" │ ");
+                    }
+                    let w = widths.get(j).copied().unwrap_or(10);
+                    line.push_str(&Self::truncate(lines.get(k).copied().unwrap_or(""), w));
+                }
+                line
+            })
+            .collect()
+    }
+
     fn truncate(s: &str, width: usize) -> String {
-        if s.len() <= width {
-            format!("{s:width$}")
+        let used = s.width();
+        if used <= width {
+            format!("{s}{}", " ".repeat(width - used))
         } else if width > 3 {
-            format!("{}...", &s[..width - 3])
+            format!("{}...", take_width(s, width - 3))
         } else {
-            s[..width].to_string()
+            take_width(s, width).to_string()
         }
     }
 }
@@ -257,7 +386,13 @@ impl Widget for Table {
     fn measure(&self, constraints: Constraints) -> Size {
         let width = constraints.max_width.max(20.0);
         let min_height = 3.0;
-        let preferred_height = (self.rows.len() + 1) as f32;
+        let widths = self.column_widths(width as usize);
+        let row_lines: usize = self
+            .rows
+            .iter()
+            .map(|row| self.row_height(row, &widths))
+            .sum();
+        let preferred_height = (row_lines + 1) as f32;
         let height = constraints
             .max_height
             .max(min_height)
@@ -322,12 +457,12 @@ impl Widget for Table {
             ..Default::default()
         };
 
-        for (i, row_idx) in (self.scroll_offset..self.rows.len())
-            .take(visible_rows)
-            .enumerate()
-        {
-            let row = &self.rows[row_idx];
-            let y = self.bounds.y + 2.0 + i as f32;
+        let mut line_idx = 0;
+        for row_idx in self.scroll_offset..self.rows.len() {
+            if line_idx >= visible_rows {
+                break;
+            }
+            let y = self.bounds.y + 2.0 + line_idx as f32;
 
             let style = if row_idx == self.selected {
                 &selected_style
@@ -335,14 +470,8 @@ impl Widget for Table {
                 &default_style
             };
 
-            let mut row_line = String::new();
-            for (j, cell) in row.iter().enumerate() {
-                if j > 0 {
-                    row_line.push_str(" │ ");
-                }
-                let w = col_widths.get(j).copied().unwrap_or(10);
-                row_line.push_str(&Self::truncate(cell, w));
-            }
+            let lines = self.row_lines(&self.rows[row_idx], &col_widths);
+            let shown = lines.len().min(visible_rows - line_idx);
 
             // Draw selection background
             if row_idx == self.selected {
@@ -353,12 +482,15 @@ impl Widget for Table {
                     0.3,
                 );
                 canvas.fill_rect(
-                    Rect::new(self.bounds.x, y, self.bounds.width, 1.0),
+                    Rect::new(self.bounds.x, y, self.bounds.width, shown as f32),
                     bg_color,
                 );
             }
 
-            canvas.draw_text(&row_line, Point::new(self.bounds.x, y), style);
+            for (k, line) in lines.iter().take(shown).enumerate() {
+                canvas.draw_text(line, Point::new(self.bounds.x, y + k as f32), style);
+            }
+            line_idx += shown;
         }
 
         // Show "No data" if empty
@@ -774,6 +906,72 @@ mod tests {
         assert_eq!(table.selected(), 0);
     }
 
+    #[test]
+    fn test_table_column_width_strategies() {
+        let table = Table::new(vec!["PID".into(), "Name".into(), "Cmd".into()])
+            .with_column_widths(vec![
+                ColumnWidth::Fit,
+                ColumnWidth::Fixed(8),
+                ColumnWidth::Fraction(0.5),
+            ])
+            .with_rows(vec![vec!["123456".into(), "a".into(), "b".into()]]);
+        // 46 - 2 separators * 3 = 40 available
+        assert_eq!(table.column_widths(46), vec![6, 8, 20]);
+    }
+
+    #[test]
+    fn test_table_auto_shrinks_but_fit_does_not() {
+        let table = Table::new(vec!["A".into(), "B".into()])
+            .with_column_widths(vec![ColumnWidth::Fit, ColumnWidth::Auto])
+            .with_rows(vec![vec!["x".repeat(10), "y".repeat(30)]]);
+        let widths = table.column_widths(23);
+        assert_eq!(widths[0], 10);
+        assert_eq!(widths[1], 10);
+    }
+
+    #[test]
+    fn test_table_wrapped_column_grows_row() {
+        let mut table = Table::new(vec!["Name".into(), "Description".into()])
+            .with_column_widths(vec![ColumnWidth::Fixed(4), ColumnWidth::Fixed(10)])
+            .wrap_column(1)
+            .with_rows(vec![
+                vec!["cpu".into(), "usage of all cores".into()],
+                vec!["mem".into(), "resident".into()],
+            ]);
+        table.layout(Rect::new(0.0, 0.0, 17.0, 10.0));
+
+        let mut canvas = MockCanvas::new();
+        table.paint(&mut canvas);
+        let row_texts: Vec<(&str, f32)> = canvas
+            .texts
+            .iter()
+            .filter(|(_, p)| p.y >= 2.0)
+            .map(|(t, p)| (t.as_str(), p.y))
+            .collect();
+        assert_eq!(
+            row_texts,
+            vec![
+                ("cpu  │ usage of  ", 2.0),
+                ("     │ all cores ", 3.0),
+                ("mem  │ resident  ", 4.0),
+            ]
+        );
+        // Selection background spans both lines of the first row
+        assert_eq!(canvas.rects[0], Rect::new(0.0, 2.0, 17.0, 2.0));
+    }
+
+    #[test]
+    fn test_table_scroll_with_wrapped_rows() {
+        let mut table = Table::new(vec!["Description".into()])
+            .with_column_widths(vec![ColumnWidth::Fixed(5)])
+            .wrap_column(0)
+            .with_rows((0..10).map(|_| vec!["aaaa bbbb".into()]).collect());
+        // 5 data lines after the header: two 2-line rows fit
+        table.layout(Rect::new(0.0, 0.0, 5.0, 6.0));
+        table.select(3);
+        assert_eq!(table.scroll_offset, 2);
+    }
+
     #[test]
     fn test_table_narrow_columns() {
         let table = Table::new(vec!["A".into(), "B".into(), "C".into()]).with_rows(vec![vec![
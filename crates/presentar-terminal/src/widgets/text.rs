@@ -1,13 +1,18 @@
 //! Simple text display widget.
 //!
-//! Renders text with optional styling.
+//! Renders text with optional styling and configurable overflow handling
+//! (see [`WrapMode`]).
 
+use super::display_rules::{take_width, truncate, TruncateStrategy};
 use presentar_core::{
     Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
     FontWeight, LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
 };
 use std::any::Any;
+use std::borrow::Cow;
+use std::ops::Range;
 use std::time::Duration;
+use unicode_width::UnicodeWidthStr;
 
 /// Text alignment.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -18,13 +23,34 @@ pub enum TextAlign {
     Right,
 }
 
+/// Overflow handling for text wider than its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WrapMode {
+    /// Single line, truncated with "..." when too long.
+    #[default]
+    NoWrap,
+    /// Break on whitespace; only words longer than a line are hard-broken.
+    Word,
+    /// Break at any character.
+    Char,
+    /// Single line, truncated with "…" (see `display_rules::truncate`).
+    Ellipsis,
+}
+
+/// Styled run of text: a byte range of the content and its style.
+type Span = (Range<usize>, TextStyle);
+
 /// Simple text display widget.
 #[derive(Debug, Clone)]
 pub struct Text {
     content: String,
+    /// Styled runs covering `content`; empty means a single run in the
+    /// widget's color and weight.
+    spans: Vec<Span>,
     color: Color,
     bold: bool,
     align: TextAlign,
+    wrap: WrapMode,
     bounds: Rect,
 }
 
@@ -34,13 +60,31 @@ impl Text {
     pub fn new(content: impl Into<String>) -> Self {
         Self {
             content: content.into(),
+            spans: Vec::new(),
             color: Color::new(0.8, 0.8, 0.8, 1.0),
             bold: false,
             align: TextAlign::Left,
+            wrap: WrapMode::NoWrap,
             bounds: Rect::default(),
         }
     }
 
+    /// Create a text widget from differently styled runs.
+    ///
+    /// The runs form one logical string, so wrapping flows across run
+    /// boundaries while each character keeps its run's style. `with_color`
+    /// and `bold` do not affect span text.
+    #[must_use]
+    pub fn from_spans(spans: Vec<(String, TextStyle)>) -> Self {
+        let mut text = Self::new(String::new());
+        for (run, style) in spans {
+            let start = text.content.len();
+            text.content.push_str(&run);
+            text.spans.push((start..text.content.len(), style));
+        }
+        text
+    }
+
     /// Set text color.
     #[must_use]
     pub fn with_color(mut self, color: Color) -> Self {
@@ -76,15 +120,223 @@ impl Text {
         self
     }
 
+    /// Set overflow handling.
+    #[must_use]
+    pub fn wrap(mut self, mode: WrapMode) -> Self {
+        self.wrap = mode;
+        self
+    }
+
     /// Get the text content.
     #[must_use]
     pub fn content(&self) -> &str {
         &self.content
     }
 
-    /// Set the text content.
+    /// Set the text content (replacing any styled spans).
     pub fn set_content(&mut self, content: impl Into<String>) {
         self.content = content.into();
+        self.spans.clear();
+    }
+
+    /// Lines as displayed within `width` columns under the current wrap mode.
+    #[must_use]
+    pub fn wrapped_lines(&self, width: usize) -> Vec<Cow<'_, str>> {
+        self.line_ranges(width)
+            .into_iter()
+            .map(|(range, suffix)| {
+                if suffix.is_empty() {
+                    Cow::Borrowed(&self.content[range])
+                } else {
+                    Cow::Owned(format!("{}{suffix}", &self.content[range]))
+                }
+            })
+            .collect()
+    }
+
+    /// Styled runs of each displayed line within `width` columns.
+    ///
+    /// Adjacent runs with the same style are merged; a truncation ellipsis
+    /// takes the style of the text before it.
+    #[must_use]
+    pub fn styled_lines(&self, width: usize) -> Vec<Vec<(Cow<'_, str>, TextStyle)>> {
+        let base = [(0..self.content.len(), self.base_style())];
+        let spans: &[Span] = if self.spans.is_empty() {
+            &base
+        } else {
+            &self.spans
+        };
+
+        self.line_ranges(width)
+            .into_iter()
+            .map(|(range, suffix)| {
+                let mut pieces: Vec<(Range<usize>, &TextStyle)> = Vec::new();
+                for (span, style) in spans {
+                    let (start, end) = (span.start.max(range.start), span.end.min(range.end));
+                    if start >= end {
+                        continue;
+                    }
+                    match pieces.last_mut() {
+                        Some((last, last_style)) if last.end == start && *last_style == style => {
+                            last.end = end;
+                        }
+                        _ => pieces.push((start..end, style)),
+                    }
+                }
+                let mut runs: Vec<(Cow<'_, str>, TextStyle)> = pieces
+                    .into_iter()
+                    .map(|(range, style)| (Cow::Borrowed(&self.content[range]), style.clone()))
+                    .collect();
+                if !suffix.is_empty() {
+                    match runs.last_mut() {
+                        Some((text, _)) => text.to_mut().push_str(suffix),
+                        None => runs.push((Cow::Borrowed(suffix), spans[0].1.clone())),
+                    }
+                }
+                runs
+            })
+            .collect()
+    }
+
+    /// Number of rows the text occupies at `width` columns.
+    fn line_count(&self, width: usize) -> usize {
+        match self.wrap {
+            WrapMode::NoWrap | WrapMode::Ellipsis => 1,
+            WrapMode::Word | WrapMode::Char => wrap_ranges(&self.content, width, self.wrap).len(),
+        }
+    }
+
+    /// Displayed lines as content ranges plus any appended ellipsis.
+    fn line_ranges(&self, width: usize) -> Vec<(Range<usize>, &'static str)> {
+        match self.wrap {
+            WrapMode::NoWrap => vec![truncated_range(
+                truncate_legacy(&self.content, width),
+                "...",
+            )],
+            WrapMode::Ellipsis => vec![truncated_range(
+                truncate(&self.content, width, TruncateStrategy::End),
+                "…",
+            )],
+            WrapMode::Word | WrapMode::Char => wrap_ranges(&self.content, width, self.wrap)
+                .into_iter()
+                .map(|range| (range, ""))
+                .collect(),
+        }
+    }
+
+    /// Style for text without spans.
+    fn base_style(&self) -> TextStyle {
+        TextStyle {
+            color: self.color,
+            weight: if self.bold {
+                FontWeight::Bold
+            } else {
+                FontWeight::Normal
+            },
+            ..Default::default()
+        }
+    }
+}
+
+/// Split a truncated copy of some text into its kept prefix length and the
+/// ellipsis appended to it.
+fn truncated_range(
+    truncated: Cow<'_, str>,
+    ellipsis: &'static str,
+) -> (Range<usize>, &'static str) {
+    match &truncated {
+        Cow::Owned(text) => match text.strip_suffix(ellipsis) {
+            Some(prefix) => (0..prefix.len(), ellipsis),
+            None => (0..text.len(), ""),
+        },
+        Cow::Borrowed(text) => (0..text.len(), ""),
+    }
+}
+
+/// Truncate with "..." (or a bare cut below 4 columns).
+fn truncate_legacy(text: &str, width: usize) -> Cow<'_, str> {
+    if text.chars().count() <= width {
+        Cow::Borrowed(text)
+    } else if width > 3 {
+        Cow::Owned(format!(
+            "{}...",
+            text.chars().take(width - 3).collect::<String>()
+        ))
+    } else {
+        Cow::Owned(text.chars().take(width).collect())
+    }
+}
+
+/// Byte ranges of `text` for each line wrapped at `width` columns.
+///
+/// Explicit newlines always start a new line. `Word` mode breaks on
+/// whitespace and hard-breaks only tokens wider than a line; other modes
+/// break at any character.
+pub(crate) fn wrap_ranges(text: &str, width: usize, mode: WrapMode) -> Vec<Range<usize>> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut base = 0;
+    for para in text.split('\n') {
+        if mode == WrapMode::Word {
+            wrap_words(para, base, width, &mut lines);
+        } else {
+            wrap_chars(para, base, width, &mut lines);
+        }
+        base += para.len() + 1;
+    }
+    lines
+}
+
+/// Byte length of the longest prefix of `s` within `width`, at least one char.
+fn chunk_len(s: &str, width: usize) -> usize {
+    match take_width(s, width).len() {
+        0 => s.chars().next().map_or(0, char::len_utf8),
+        len => len,
+    }
+}
+
+fn wrap_chars(para: &str, base: usize, width: usize, lines: &mut Vec<Range<usize>>) {
+    if para.is_empty() {
+        lines.push(base..base);
+        return;
+    }
+    let mut pos = 0;
+    while pos < para.len() {
+        let len = chunk_len(&para[pos..], width);
+        lines.push(base + pos..base + pos + len);
+        pos += len;
+    }
+}
+
+fn wrap_words(para: &str, base: usize, width: usize, lines: &mut Vec<Range<usize>>) {
+    // Current line as a range within `para`
+    let mut line: Option<Range<usize>> = None;
+    for word in para.split_whitespace() {
+        let start = word.as_ptr() as usize - para.as_ptr() as usize;
+        let end = start + word.len();
+
+        if let Some(current) = &mut line {
+            if para[current.start..end].width() <= width {
+                current.end = end;
+                continue;
+            }
+        }
+        if let Some(current) = line.take() {
+            lines.push(base + current.start..base + current.end);
+        }
+
+        // Hard-break words wider than a line, keeping the tail open
+        let mut pos = start;
+        while para[pos..end].width() > width {
+            let len = chunk_len(&para[pos..end], width);
+            lines.push(base + pos..base + pos + len);
+            pos += len;
+        }
+        line = Some(pos..end);
+    }
+    match line {
+        Some(current) => lines.push(base + current.start..base + current.end),
+        None => lines.push(base..base),
     }
 }
 
@@ -131,16 +383,24 @@ impl Widget for Text {
     }
 
     fn measure(&self, constraints: Constraints) -> Size {
-        let char_count = self.content.chars().count() as f32;
-        let width = char_count.min(constraints.max_width);
-        let height = 1.0_f32.min(constraints.max_height);
+        let (width, rows) = match self.wrap {
+            WrapMode::NoWrap | WrapMode::Ellipsis => (self.content.chars().count() as f32, 1.0),
+            WrapMode::Word | WrapMode::Char => {
+                let lines = self.wrapped_lines(constraints.max_width as usize);
+                let widest = lines.iter().map(|l| l.width()).max().unwrap_or(0);
+                (widest as f32, lines.len() as f32)
+            }
+        };
+        let width = width.min(constraints.max_width);
+        let height = rows.min(constraints.max_height);
         constraints.constrain(Size::new(width, height))
     }
 
     fn layout(&mut self, bounds: Rect) -> LayoutResult {
         self.bounds = bounds;
+        let rows = self.line_count(bounds.width as usize) as f32;
         LayoutResult {
-            size: Size::new(bounds.width, bounds.height.min(1.0)),
+            size: Size::new(bounds.width, bounds.height.min(rows)),
         }
     }
 
@@ -149,44 +409,23 @@ impl Widget for Text {
             return;
         }
 
-        let style = TextStyle {
-            color: self.color,
-            weight: if self.bold {
-                FontWeight::Bold
-            } else {
-                FontWeight::Normal
-            },
-            ..Default::default()
-        };
-
         let width = self.bounds.width as usize;
-        let text_len = self.content.chars().count();
-
-        let x_offset = match self.align {
-            TextAlign::Left => 0.0,
-            TextAlign::Center => ((width.saturating_sub(text_len)) / 2) as f32,
-            TextAlign::Right => (width.saturating_sub(text_len)) as f32,
-        };
-
-        // Truncate if needed
-        let display: String = if text_len > width {
-            if width > 3 {
-                format!(
-                    "{}...",
-                    self.content.chars().take(width - 3).collect::<String>()
-                )
-            } else {
-                self.content.chars().take(width).collect()
+        let rows = self.bounds.height as usize;
+
+        for (row, runs) in self.styled_lines(width).iter().take(rows).enumerate() {
+            let line_width: usize = runs.iter().map(|(text, _)| text.width()).sum();
+            let mut x = self.bounds.x
+                + match self.align {
+                    TextAlign::Left => 0.0,
+                    TextAlign::Center => ((width.saturating_sub(line_width)) / 2) as f32,
+                    TextAlign::Right => (width.saturating_sub(line_width)) as f32,
+                };
+            let y = self.bounds.y + row as f32;
+            for (text, style) in runs {
+                canvas.draw_text(text, Point::new(x, y), style);
+                x += text.width() as f32;
             }
-        } else {
-            self.content.clone()
-        };
-
-        canvas.draw_text(
-            &display,
-            Point::new(self.bounds.x + x_offset, self.bounds.y),
-            &style,
-        );
+        }
     }
 
     fn event(&mut self, _event: &Event) -> Option<Box<dyn Any + Send>> {
@@ -520,6 +759,132 @@ mod tests {
         assert_eq!(canvas.texts[0].1.y, 10.0);
     }
 
+    #[test]
+    fn test_text_wrap_word() {
+        let text = Text::new("hello world foo").wrap(WrapMode::Word);
+        assert_eq!(text.wrapped_lines(6), vec!["hello", "world", "foo"]);
+        assert_eq!(text.wrapped_lines(11), vec!["hello world", "foo"]);
+    }
+
+    #[test]
+    fn test_text_wrap_word_hard_breaks_long_tokens() {
+        let text = Text::new("a supercalifragilistic b").wrap(WrapMode::Word);
+        assert_eq!(
+            text.wrapped_lines(8),
+            vec!["a", "supercal", "ifragili", "stic b"]
+        );
+    }
+
+    #[test]
+    fn test_text_wrap_char() {
+        let text = Text::new("hello world").wrap(WrapMode::Char);
+        assert_eq!(text.wrapped_lines(4), vec!["hell", "o wo", "rld"]);
+    }
+
+    #[test]
+    fn test_text_wrap_ellipsis() {
+        let text = Text::new("hello").wrap(WrapMode::Ellipsis);
+        assert_eq!(text.wrapped_lines(4), vec!["hel…"]);
+        assert_eq!(text.wrapped_lines(5), vec!["hello"]);
+    }
+
+    #[test]
+    fn test_text_wrap_paint_and_measure() {
+        let mut text = Text::new("hello world foo").wrap(WrapMode::Word);
+        let size = text.measure(Constraints::new(0.0, 6.0, 0.0, 10.0));
+        assert_eq!(size, Size::new(5.0, 3.0));
+
+        let result = text.layout(Rect::new(0.0, 0.0, 6.0, 2.0));
+        assert_eq!(result.size.height, 2.0);
+
+        let mut canvas = MockCanvas::new();
+        text.paint(&mut canvas);
+        // Only the rows that fit are drawn
+        assert_eq!(canvas.texts.len(), 2);
+        assert_eq!(canvas.texts[1], ("world".to_string(), Point::new(0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_text_spans_wrap_preserves_styles() {
+        let level = TextStyle {
+            color: Color::RED,
+            weight: FontWeight::Bold,
+            ..Default::default()
+        };
+        let text = Text::from_spans(vec![
+            ("ERROR".to_string(), level.clone()),
+            (" disk full".to_string(), TextStyle::default()),
+        ])
+        .wrap(WrapMode::Char);
+        assert_eq!(text.content(), "ERROR disk full");
+
+        // Char wrap at 4 splits the red run across the first line break
+        let lines = text.styled_lines(4);
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], vec![(Cow::Borrowed("ERRO"), level.clone())]);
+        assert_eq!(
+            lines[1],
+            vec![
+                (Cow::Borrowed("R"), level),
+                (Cow::Borrowed(" di"), TextStyle::default()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_spans_merge_adjacent_same_style() {
+        let red = TextStyle {
+            color: Color::RED,
+            ..Default::default()
+        };
+        let text = Text::from_spans(vec![
+            ("a".to_string(), red.clone()),
+            ("b".to_string(), red.clone()),
+            ("c".to_string(), TextStyle::default()),
+            ("d".to_string(), red.clone()),
+        ]);
+        assert_eq!(
+            text.styled_lines(10),
+            vec![vec![
+                (Cow::Borrowed("ab"), red.clone()),
+                (Cow::Borrowed("c"), TextStyle::default()),
+                (Cow::Borrowed("d"), red),
+            ]]
+        );
+    }
+
+    #[test]
+    fn test_text_spans_paint_color_boundary() {
+        let mut text = Text::from_spans(vec![
+            (
+                "WARN".to_string(),
+                TextStyle {
+                    color: Color::YELLOW,
+                    ..Default::default()
+                },
+            ),
+            (" cpu hot now".to_string(), TextStyle::default()),
+        ])
+        .wrap(WrapMode::Word);
+        text.layout(Rect::new(2.0, 0.0, 8.0, 3.0));
+
+        let mut canvas = MockCanvas::new();
+        text.paint(&mut canvas);
+        let drawn: Vec<(&str, f32, f32)> = canvas
+            .texts
+            .iter()
+            .map(|(t, p)| (t.as_str(), p.x, p.y))
+            .collect();
+        assert_eq!(
+            drawn,
+            vec![
+                ("WARN", 2.0, 0.0),
+                (" cpu", 6.0, 0.0),
+                ("hot now", 2.0, 1.0)
+            ]
+        );
+    }
+
     #[test]
     fn test_text_paint_exact_fit() {
         let mut text = Text::new("Hi");
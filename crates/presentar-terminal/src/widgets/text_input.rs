@@ -2,14 +2,42 @@
 //!
 //! Provides a text input field with cursor, selection, and editing.
 //! Based on btop filter input patterns.
+//!
+//! Keyboard: Shift+arrows select, Ctrl+arrows move by word (Ctrl+Shift
+//! selects by word), Ctrl+Backspace/Delete delete by word, and Ctrl+C/X/V
+//! copy, cut and paste through an injected [`TextClipboard`].
 
+use super::selection::SELECTION_BG;
 use presentar_core::{
-    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event, Key,
-    LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
+    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Clipboard, Color, Constraints,
+    Event, Key, LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
 };
 use std::any::Any;
+use std::fmt;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+/// Clipboard backend used by `TextInput` copy/cut/paste shortcuts.
+pub trait TextClipboard: fmt::Debug + Send + Sync {
+    /// Read the clipboard text, if any.
+    fn read_text(&self) -> Option<String>;
+
+    /// Replace the clipboard contents with `text`.
+    fn write_text(&self, text: &str);
+}
+
+impl TextClipboard for Mutex<Clipboard> {
+    fn read_text(&self) -> Option<String> {
+        self.lock().ok()?.read_text().ok().flatten()
+    }
+
+    fn write_text(&self, text: &str) {
+        if let Ok(mut clipboard) = self.lock() {
+            let _ = clipboard.write_text(text);
+        }
+    }
+}
+
 /// Text input widget with cursor and editing support.
 #[derive(Debug, Clone)]
 pub struct TextInput {
@@ -37,6 +65,8 @@ pub struct TextInput {
     selection_color: Color,
     /// Placeholder color.
     placeholder_color: Color,
+    /// Clipboard for Ctrl+C/X/V (shortcuts are ignored without one).
+    clipboard: Option<Arc<dyn TextClipboard>>,
     /// Cached bounds.
     bounds: Rect,
 }
@@ -62,8 +92,9 @@ impl TextInput {
             focused: false,
             text_color: Color::WHITE,
             cursor_color: Color::new(0.8, 0.8, 0.8, 1.0),
-            selection_color: Color::new(0.3, 0.5, 0.8, 0.5),
+            selection_color: SELECTION_BG,
             placeholder_color: Color::new(0.5, 0.5, 0.5, 1.0),
+            clipboard: None,
             bounds: Rect::default(),
         }
     }
@@ -118,6 +149,13 @@ impl TextInput {
         self
     }
 
+    /// Set the clipboard used by Ctrl+C/X/V.
+    #[must_use]
+    pub fn with_clipboard(mut self, clipboard: Arc<dyn TextClipboard>) -> Self {
+        self.clipboard = Some(clipboard);
+        self
+    }
+
     // ================= State Getters =================
 
     /// Get the current text.
@@ -253,6 +291,22 @@ impl TextInput {
         }
     }
 
+    /// Delete word before cursor (Ctrl+Backspace).
+    pub fn delete_word_back(&mut self) {
+        if self.selection.is_some() {
+            self.delete_selection();
+            return;
+        }
+        let start = self.word_left_pos();
+        if start < self.cursor {
+            let start_byte = self.char_byte_pos(start);
+            let end_byte = self.cursor_byte_pos();
+            self.text.drain(start_byte..end_byte);
+            self.cursor = start;
+            self.adjust_scroll();
+        }
+    }
+
     /// Delete from cursor to end of line.
     pub fn delete_to_end(&mut self) {
         let byte_pos = self.cursor_byte_pos();
@@ -291,41 +345,14 @@ impl TextInput {
     /// Move cursor to start of previous word.
     pub fn move_word_left(&mut self) {
         self.selection = None;
-        if self.cursor == 0 {
-            return;
-        }
-        let chars: Vec<char> = self.text.chars().collect();
-        let mut pos = self.cursor - 1;
-        // Skip whitespace
-        while pos > 0 && chars[pos].is_whitespace() {
-            pos -= 1;
-        }
-        // Skip word characters
-        while pos > 0 && !chars[pos - 1].is_whitespace() {
-            pos -= 1;
-        }
-        self.cursor = pos;
+        self.cursor = self.word_left_pos();
         self.adjust_scroll();
     }
 
     /// Move cursor to end of next word.
     pub fn move_word_right(&mut self) {
         self.selection = None;
-        let chars: Vec<char> = self.text.chars().collect();
-        let len = chars.len();
-        if self.cursor >= len {
-            return;
-        }
-        let mut pos = self.cursor;
-        // Skip word characters
-        while pos < len && !chars[pos].is_whitespace() {
-            pos += 1;
-        }
-        // Skip whitespace
-        while pos < len && chars[pos].is_whitespace() {
-            pos += 1;
-        }
-        self.cursor = pos;
+        self.cursor = self.word_right_pos();
         self.adjust_scroll();
     }
 
@@ -423,6 +450,28 @@ impl TextInput {
         self.adjust_scroll();
     }
 
+    /// Extend selection to the start of the previous word.
+    pub fn extend_selection_word_left(&mut self) {
+        self.select_to(self.word_left_pos());
+    }
+
+    /// Extend selection to the start of the next word.
+    pub fn extend_selection_word_right(&mut self) {
+        self.select_to(self.word_right_pos());
+    }
+
+    /// Move the cursor to `pos`, selecting from the selection anchor.
+    fn select_to(&mut self, pos: usize) {
+        let anchor = match self.selection {
+            Some((start, end)) if self.cursor == start => end,
+            Some((start, _)) => start,
+            None => self.cursor,
+        };
+        self.selection = (anchor != pos).then(|| (anchor.min(pos), anchor.max(pos)));
+        self.cursor = pos;
+        self.adjust_scroll();
+    }
+
     /// Clear selection.
     pub fn clear_selection(&mut self) {
         self.selection = None;
@@ -470,6 +519,30 @@ impl TextInput {
         self.insert_str(text);
     }
 
+    /// Copy the selection to the injected clipboard (Ctrl+C).
+    pub fn copy_to_clipboard(&self) {
+        if let (Some(clipboard), Some(text)) = (&self.clipboard, self.copy()) {
+            clipboard.write_text(&text);
+        }
+    }
+
+    /// Cut the selection to the injected clipboard (Ctrl+X).
+    pub fn cut_to_clipboard(&mut self) {
+        if self.clipboard.is_none() {
+            return;
+        }
+        if let (Some(text), Some(clipboard)) = (self.cut(), &self.clipboard) {
+            clipboard.write_text(&text);
+        }
+    }
+
+    /// Paste from the injected clipboard (Ctrl+V).
+    pub fn paste_from_clipboard(&mut self) {
+        if let Some(text) = self.clipboard.as_ref().and_then(|c| c.read_text()) {
+            self.paste(&text);
+        }
+    }
+
     // ================= Focus =================
 
     /// Set focused state.
@@ -493,6 +566,40 @@ impl TextInput {
             .map_or(self.text.len(), |(i, _)| i)
     }
 
+    /// Cursor position at the start of the previous word.
+    fn word_left_pos(&self) -> usize {
+        if self.cursor == 0 {
+            return 0;
+        }
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut pos = self.cursor - 1;
+        // Skip whitespace
+        while pos > 0 && chars[pos].is_whitespace() {
+            pos -= 1;
+        }
+        // Skip word characters
+        while pos > 0 && !chars[pos - 1].is_whitespace() {
+            pos -= 1;
+        }
+        pos
+    }
+
+    /// Cursor position at the start of the next word.
+    fn word_right_pos(&self) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let len = chars.len();
+        let mut pos = self.cursor.min(len);
+        // Skip word characters
+        while pos < len && !chars[pos].is_whitespace() {
+            pos += 1;
+        }
+        // Skip whitespace
+        while pos < len && chars[pos].is_whitespace() {
+            pos += 1;
+        }
+        pos
+    }
+
     /// Get byte position of cursor.
     fn cursor_byte_pos(&self) -> usize {
         self.char_byte_pos(self.cursor)
@@ -635,19 +742,31 @@ impl Widget for TextInput {
         }
 
         match event {
-            Event::KeyDown { key, .. } => match key {
+            Event::KeyDown { key, modifiers } => match key {
+                Key::Backspace if modifiers.ctrl => self.delete_word_back(),
                 Key::Backspace => {
                     self.backspace();
                 }
+                Key::Delete if modifiers.ctrl => self.delete_word(),
                 Key::Delete => {
                     self.delete();
                 }
-                Key::Left => {
-                    self.move_left();
-                }
-                Key::Right => {
-                    self.move_right();
-                }
+                Key::Left => match (modifiers.ctrl, modifiers.shift) {
+                    (true, true) => self.extend_selection_word_left(),
+                    (true, false) => self.move_word_left(),
+                    (false, true) => self.extend_selection_left(),
+                    (false, false) => self.move_left(),
+                },
+                Key::Right => match (modifiers.ctrl, modifiers.shift) {
+                    (true, true) => self.extend_selection_word_right(),
+                    (true, false) => self.move_word_right(),
+                    (false, true) => self.extend_selection_right(),
+                    (false, false) => self.move_right(),
+                },
+                Key::A if modifiers.ctrl => self.select_all(),
+                Key::C if modifiers.ctrl => self.copy_to_clipboard(),
+                Key::X if modifiers.ctrl => self.cut_to_clipboard(),
+                Key::V if modifiers.ctrl => self.paste_from_clipboard(),
                 Key::Home => {
                     self.move_home();
                 }
@@ -1388,6 +1507,102 @@ mod tests {
         assert!(input.scroll_offset > 0);
     }
 
+    // =====================================================
+    // Keyboard Selection / Clipboard Tests
+    // =====================================================
+
+    fn key(key: Key, ctrl: bool, shift: bool) -> Event {
+        Event::KeyDown {
+            key,
+            modifiers: presentar_core::Modifiers {
+                ctrl,
+                shift,
+                ..presentar_core::Modifiers::NONE
+            },
+        }
+    }
+
+    fn type_text(input: &mut TextInput, text: &str) {
+        input.event(&Event::TextInput {
+            text: text.to_string(),
+        });
+    }
+
+    #[test]
+    fn test_shift_ctrl_left_selects_word_then_delete() {
+        let mut input = TextInput::new().with_focused(true);
+        type_text(&mut input, "hello world");
+
+        input.event(&key(Key::Left, true, true));
+        assert_eq!(input.selection(), Some((6, 11)));
+        assert_eq!(input.selected_text().as_deref(), Some("world"));
+
+        input.event(&key(Key::Backspace, false, false));
+        assert_eq!(input.text(), "hello ");
+        assert_eq!(input.cursor(), 6);
+    }
+
+    #[test]
+    fn test_ctrl_arrows_move_by_word() {
+        let mut input = TextInput::new()
+            .with_text("one two three")
+            .with_focused(true);
+        input.event(&key(Key::Left, true, false));
+        assert_eq!(input.cursor(), 8);
+        input.event(&key(Key::Left, true, false));
+        assert_eq!(input.cursor(), 4);
+        input.event(&key(Key::Right, true, false));
+        assert_eq!(input.cursor(), 8);
+
+        // Shift+Right extends by one char from the cursor
+        input.event(&key(Key::Right, false, true));
+        assert_eq!(input.selected_text().as_deref(), Some("t"));
+    }
+
+    #[test]
+    fn test_ctrl_backspace_deletes_word() {
+        let mut input = TextInput::new()
+            .with_text("git commit -m")
+            .with_focused(true);
+        input.event(&key(Key::Backspace, true, false));
+        assert_eq!(input.text(), "git commit ");
+        input.event(&key(Key::Backspace, true, false));
+        assert_eq!(input.text(), "git ");
+    }
+
+    #[test]
+    fn test_clipboard_copy_cut_paste() {
+        let clipboard = Arc::new(Mutex::new(Clipboard::new()));
+        let mut input = TextInput::new()
+            .with_text("hello world")
+            .with_focused(true)
+            .with_clipboard(clipboard.clone());
+
+        input.event(&key(Key::Left, true, true));
+        input.event(&key(Key::C, true, false));
+        assert_eq!(clipboard.read_text().as_deref(), Some("world"));
+        assert_eq!(input.text(), "hello world");
+
+        input.event(&key(Key::X, true, false));
+        assert_eq!(input.text(), "hello ");
+
+        input.event(&key(Key::Home, false, false));
+        input.event(&key(Key::V, true, false));
+        assert_eq!(input.text(), "worldhello ");
+    }
+
+    #[test]
+    fn test_selection_paints_selection_bg() {
+        let mut input = TextInput::new().with_text("abc").with_focused(true);
+        input.layout(Rect::new(0.0, 0.0, 10.0, 1.0));
+        input.extend_selection_left();
+        assert_eq!(input.selection_color, SELECTION_BG);
+
+        let mut canvas = MockCanvas::new();
+        input.paint(&mut canvas);
+        assert!(canvas.rects.contains(&Rect::new(2.0, 0.0, 1.0, 1.0)));
+    }
+
     #[test]
     fn test_scroll_on_move_home() {
         let mut input = TextInput::new().with_text("hello world");
@@ -295,20 +295,17 @@ impl Widget for TitleBar {
         };
 
         canvas.draw_text(&self.app_name, Point::new(x, y), &name_style);
-        x += self.app_name.len() as f32;
+        x += canvas.measure_text(&self.app_name, &name_style).width;
 
         if show_version {
             if let Some(ref ver) = self.version {
                 let ver_text = format!(" v{ver}");
-                canvas.draw_text(
-                    &ver_text,
-                    Point::new(x, y),
-                    &TextStyle {
-                        color: self.secondary_color,
-                        ..Default::default()
-                    },
-                );
-                x += ver_text.len() as f32;
+                let ver_style = TextStyle {
+                    color: self.secondary_color,
+                    ..Default::default()
+                };
+                canvas.draw_text(&ver_text, Point::new(x, y), &ver_style);
+                x += canvas.measure_text(&ver_text, &ver_style).width;
             }
         }
 
@@ -372,21 +369,18 @@ impl Widget for TitleBar {
 
         // Mode indicator (e.g., [FULLSCREEN])
         if let Some(ref indicator) = self.mode_indicator {
-            canvas.draw_text(
-                indicator,
-                Point::new(right_x, y),
-                &TextStyle {
-                    color: Color {
-                        r: 0.9,
-                        g: 0.7,
-                        b: 0.2,
-                        a: 1.0,
-                    }, // Yellow/gold
-                    weight: FontWeight::Bold,
-                    ..Default::default()
-                },
-            );
-            right_x += indicator.len() as f32 + 2.0;
+            let indicator_style = TextStyle {
+                color: Color {
+                    r: 0.9,
+                    g: 0.7,
+                    b: 0.2,
+                    a: 1.0,
+                }, // Yellow/gold
+                weight: FontWeight::Bold,
+                ..Default::default()
+            };
+            canvas.draw_text(indicator, Point::new(right_x, y), &indicator_style);
+            right_x += canvas.measure_text(indicator, &indicator_style).width + 2.0;
         }
 
         // Status text (if any)
@@ -410,17 +404,14 @@ impl Widget for TitleBar {
                 .collect::<Vec<_>>()
                 .join(" ");
 
-            let keybind_x =
-                (width as f32 - keybind_str.len() as f32 - 1.0).max(right_section_start);
+            let keybind_style = TextStyle {
+                color: self.secondary_color,
+                ..Default::default()
+            };
+            let keybind_width = canvas.measure_text(&keybind_str, &keybind_style).width;
+            let keybind_x = (width as f32 - keybind_width - 1.0).max(right_section_start);
 
-            canvas.draw_text(
-                &keybind_str,
-                Point::new(keybind_x, y),
-                &TextStyle {
-                    color: self.secondary_color,
-                    ..Default::default()
-                },
-            );
+            canvas.draw_text(&keybind_str, Point::new(keybind_x, y), &keybind_style);
         }
     }
 
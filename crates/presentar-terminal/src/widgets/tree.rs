@@ -2,13 +2,20 @@
 //!
 //! Provides collapsible tree view using Unicode tree-drawing characters.
 //! Ideal for process trees, file systems, or cluster hierarchies.
+//!
+//! Large hierarchies can load children on demand: mark nodes with
+//! [`TreeNode::lazy`] and supply a provider via [`Tree::with_lazy_children`].
+//! [`Tree::set_filter`] narrows the view to fuzzy matches and their ancestors.
 
+use super::display_rules::FuzzyIndex;
 use presentar_core::{
     Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
     LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
 };
 use std::any::Any;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Tree branch characters.
@@ -17,6 +24,12 @@ const BRANCH_TEE: &str = "├── ";
 const BRANCH_ELBOW: &str = "└── ";
 const BRANCH_SPACE: &str = "    ";
 
+/// Placeholder shown under an expanded node whose children are not loaded.
+const LOADING_LABEL: &str = "loading…";
+
+/// Callback fetching the children of a lazy node on first expansion.
+pub type ChildrenProvider = Arc<dyn Fn(&NodeId) -> Vec<TreeNode> + Send + Sync>;
+
 /// Unique identifier for tree nodes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NodeId(pub u64);
@@ -42,6 +55,8 @@ pub struct TreeNode {
     pub children: Vec<Self>,
     /// Node color.
     pub color: Option<Color>,
+    /// Children are not loaded yet; the tree's provider fetches them.
+    pub lazy: bool,
 }
 
 impl TreeNode {
@@ -54,9 +69,23 @@ impl TreeNode {
             info: None,
             children: vec![],
             color: None,
+            lazy: false,
         }
     }
 
+    /// Mark this node as expandable with children loaded on demand.
+    #[must_use]
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    /// Whether this node can be expanded (has or may load children).
+    #[must_use]
+    pub fn is_expandable(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+
     /// Add info text.
     #[must_use]
     pub fn with_info(mut self, info: impl Into<String>) -> Self {
@@ -103,7 +132,7 @@ impl TreeNode {
 }
 
 /// Tree widget for hierarchical visualization.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Tree {
     /// Root node.
     root: Option<TreeNode>,
@@ -120,10 +149,38 @@ pub struct Tree {
     scroll_offset: usize,
     /// Selected node ID.
     selected: Option<NodeId>,
+    /// Loads children of lazy nodes on first expansion.
+    provider: Option<ChildrenProvider>,
+    /// Drop provider-loaded children on collapse.
+    evict_on_collapse: bool,
+    /// Nodes whose children came from the provider.
+    loaded: HashSet<NodeId>,
+    /// Active filter query.
+    filter: Option<String>,
+    /// Nodes matching the filter.
+    matches: HashSet<NodeId>,
+    /// Nodes kept by the filter: matches plus their ancestors.
+    filtered: Option<HashSet<NodeId>>,
+    /// Expansion state to restore when the filter clears.
+    saved_expanded: Option<HashSet<NodeId>>,
     /// Cached bounds.
     bounds: Rect,
 }
 
+impl fmt::Debug for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tree")
+            .field("root", &self.root)
+            .field("expanded", &self.expanded)
+            .field("scroll_offset", &self.scroll_offset)
+            .field("selected", &self.selected)
+            .field("lazy", &self.provider.is_some())
+            .field("evict_on_collapse", &self.evict_on_collapse)
+            .field("filter", &self.filter)
+            .finish_non_exhaustive()
+    }
+}
+
 impl Default for Tree {
     fn default() -> Self {
         Self::new()
@@ -142,6 +199,13 @@ impl Tree {
             indent_width: 4,
             scroll_offset: 0,
             selected: None,
+            provider: None,
+            evict_on_collapse: false,
+            loaded: HashSet::new(),
+            filter: None,
+            matches: HashSet::new(),
+            filtered: None,
+            saved_expanded: None,
             bounds: Rect::default(),
         }
     }
@@ -149,9 +213,31 @@ impl Tree {
     /// Set the root node.
     #[must_use]
     pub fn with_root(mut self, root: TreeNode) -> Self {
-        // Expand root by default
-        self.expanded.insert(root.id);
-        self.root = Some(root);
+        self.set_root(root);
+        self
+    }
+
+    /// Load children of lazy nodes on first expansion via `provider`.
+    ///
+    /// Loaded children are cached; already-expanded lazy nodes load now.
+    #[must_use]
+    pub fn with_lazy_children<F>(mut self, provider: F) -> Self
+    where
+        F: Fn(&NodeId) -> Vec<TreeNode> + Send + Sync + 'static,
+    {
+        self.provider = Some(Arc::new(provider));
+        let expanded: Vec<NodeId> = self.expanded.iter().copied().collect();
+        for id in expanded {
+            self.load_children(id);
+        }
+        self
+    }
+
+    /// Drop lazily loaded children when their parent collapses, bounding
+    /// memory at the cost of re-fetching on the next expansion.
+    #[must_use]
+    pub fn evict_on_collapse(mut self, evict: bool) -> Self {
+        self.evict_on_collapse = evict;
         self
     }
 
@@ -191,20 +277,36 @@ impl Tree {
     /// Toggle expansion of a node.
     pub fn toggle(&mut self, id: NodeId) {
         if self.expanded.contains(&id) {
-            self.expanded.remove(&id);
+            self.collapse(id);
         } else {
-            self.expanded.insert(id);
+            self.expand(id);
         }
     }
 
-    /// Expand a node.
+    /// Expand a node, loading lazy children if needed.
     pub fn expand(&mut self, id: NodeId) {
         self.expanded.insert(id);
+        self.load_children(id);
     }
 
-    /// Collapse a node.
+    /// Collapse a node, evicting lazily loaded children if configured.
+    ///
+    /// Evicted descendants also lose their expanded and loaded state, so a
+    /// re-fetched subtree starts collapsed.
     pub fn collapse(&mut self, id: NodeId) {
         self.expanded.remove(&id);
+        if self.evict_on_collapse && self.loaded.remove(&id) {
+            if let Some(node) = self.root.as_mut().and_then(|r| Self::find_mut(r, id)) {
+                let mut descendants = HashSet::new();
+                for child in &node.children {
+                    Self::collect_all_ids(child, &mut descendants);
+                }
+                node.children.clear();
+                node.lazy = true;
+                self.expanded.retain(|n| !descendants.contains(n));
+                self.loaded.retain(|n| !descendants.contains(n));
+            }
+        }
     }
 
     /// Check if a node is expanded.
@@ -229,10 +331,120 @@ impl Tree {
         self.selected
     }
 
-    /// Set a new root.
+    /// Set a new root, expanded by default.
+    ///
+    /// A lazy root loads its children now if a provider is set.
     pub fn set_root(&mut self, root: TreeNode) {
-        self.expanded.insert(root.id);
+        let id = root.id;
+        self.expanded.insert(id);
         self.root = Some(root);
+        self.load_children(id);
+    }
+
+    /// Filter to nodes whose labels fuzzy-match `query`, plus their
+    /// ancestors, expanding the path to every match. Non-matching
+    /// ancestors are dimmed. An empty query clears the filter.
+    ///
+    /// Unloaded lazy children are not searched.
+    pub fn set_filter(&mut self, query: &str) {
+        if query.is_empty() {
+            self.clear_filter();
+            return;
+        }
+        let Some(ref root) = self.root else {
+            return;
+        };
+
+        let mut nodes = Vec::new();
+        let mut parents = HashMap::new();
+        Self::collect_labels(root, None, &mut nodes, &mut parents);
+        let index = FuzzyIndex::new(nodes, |(_, label): &(NodeId, String)| label.clone());
+        let matches: HashSet<NodeId> = index
+            .search(query, usize::MAX)
+            .into_iter()
+            .map(|result| result.item.0)
+            .collect();
+
+        let saved = self
+            .saved_expanded
+            .get_or_insert_with(|| self.expanded.clone());
+        let mut expanded = saved.clone();
+        let mut kept = matches.clone();
+        for id in &matches {
+            let mut current = *id;
+            while let Some(&parent) = parents.get(&current) {
+                expanded.insert(parent);
+                if !kept.insert(parent) {
+                    break; // path already walked
+                }
+                current = parent;
+            }
+        }
+
+        self.expanded = expanded;
+        self.matches = matches;
+        self.filtered = Some(kept);
+        self.filter = Some(query.to_string());
+        self.scroll_offset = 0;
+    }
+
+    /// Clear the filter, restoring the expansion state from before it.
+    pub fn clear_filter(&mut self) {
+        if let Some(saved) = self.saved_expanded.take() {
+            self.expanded = saved;
+        }
+        self.filter = None;
+        self.matches.clear();
+        self.filtered = None;
+    }
+
+    /// Active filter query, if any.
+    #[must_use]
+    pub fn filter(&self) -> Option<&str> {
+        self.filter.as_deref()
+    }
+
+    /// IDs of the nodes currently shown, in display order.
+    #[must_use]
+    pub fn visible_nodes(&self) -> Vec<NodeId> {
+        let mut ids = Vec::new();
+        if let Some(ref root) = self.root {
+            if self.is_shown(root.id) {
+                self.collect_visible(root, &mut ids);
+            }
+        }
+        ids
+    }
+
+    fn collect_visible(&self, node: &TreeNode, ids: &mut Vec<NodeId>) {
+        ids.push(node.id);
+        if self.expanded.contains(&node.id) {
+            for child in node.children.iter().filter(|c| self.is_shown(c.id)) {
+                self.collect_visible(child, ids);
+            }
+        }
+    }
+
+    fn collect_labels(
+        node: &TreeNode,
+        parent: Option<NodeId>,
+        nodes: &mut Vec<(NodeId, String)>,
+        parents: &mut HashMap<NodeId, NodeId>,
+    ) {
+        nodes.push((node.id, node.label.clone()));
+        if let Some(parent) = parent {
+            parents.insert(node.id, parent);
+        }
+        for child in &node.children {
+            Self::collect_labels(child, Some(node.id), nodes, parents);
+        }
+    }
+
+    /// Whether the filter (if any) keeps this node.
+    fn is_shown(&self, id: NodeId) -> bool {
+        self.filtered
+            .as_ref()
+            .map_or(true, |kept| kept.contains(&id))
     }
 
     /// Get visible line count.
@@ -240,19 +452,47 @@ impl Tree {
     pub fn visible_lines(&self) -> usize {
         self.root
             .as_ref()
+            .filter(|r| self.is_shown(r.id))
             .map_or(0, |r| self.count_visible_lines(r))
     }
 
     fn count_visible_lines(&self, node: &TreeNode) -> usize {
         let mut count = 1;
         if self.expanded.contains(&node.id) {
-            for child in &node.children {
+            if node.lazy && self.filtered.is_none() {
+                count += 1; // loading placeholder
+            }
+            for child in node.children.iter().filter(|c| self.is_shown(c.id)) {
                 count += self.count_visible_lines(child);
             }
         }
         count
     }
 
+    /// Fetch children for a lazy node from the provider (once).
+    fn load_children(&mut self, id: NodeId) {
+        let Some(provider) = self.provider.clone() else {
+            return;
+        };
+        let Some(node) = self.root.as_mut().and_then(|r| Self::find_mut(r, id)) else {
+            return;
+        };
+        if node.lazy {
+            node.children = provider(&id);
+            node.lazy = false;
+            self.loaded.insert(id);
+        }
+    }
+
+    fn find_mut(node: &mut TreeNode, id: NodeId) -> Option<&mut TreeNode> {
+        if node.id == id {
+            return Some(node);
+        }
+        node.children
+            .iter_mut()
+            .find_map(|child| Self::find_mut(child, id))
+    }
+
     fn collect_all_ids(node: &TreeNode, ids: &mut HashSet<NodeId>) {
         ids.insert(node.id);
         for child in &node.children {
@@ -295,7 +535,7 @@ impl Tree {
             canvas.draw_text(&branch, Point::new(x, *y), &branch_style);
 
             // Draw expand/collapse indicator
-            let indicator = if node.children.is_empty() {
+            let indicator = if !node.is_expandable() {
                 "  "
             } else if self.expanded.contains(&node.id) {
                 "▼ "
@@ -308,7 +548,11 @@ impl Tree {
 
             // Draw label
             let label_x = indicator_x + 2.0;
-            let color = node.color.unwrap_or(self.default_color);
+            let color = if self.filtered.is_some() && !self.matches.contains(&node.id) {
+                Color::new(0.45, 0.45, 0.45, 1.0) // non-matching ancestor
+            } else {
+                node.color.unwrap_or(self.default_color)
+            };
             let is_selected = self.selected == Some(node.id);
 
             let label_style = TextStyle {
@@ -347,7 +591,7 @@ impl Tree {
         *y += 1.0;
 
         // Render children if expanded
-        if self.expanded.contains(&node.id) && !node.children.is_empty() {
+        if self.expanded.contains(&node.id) && node.is_expandable() {
             let child_prefix = if prefix.is_empty() {
                 String::new()
             } else if is_last {
@@ -356,8 +600,25 @@ impl Tree {
                 format!("{prefix}{BRANCH_PIPE}")
             };
 
-            let child_count = node.children.len();
-            for (i, child) in node.children.iter().enumerate() {
+            if node.lazy && self.filtered.is_none() {
+                if *y >= self.bounds.y && *y < self.bounds.y + visible_height {
+                    let style = TextStyle {
+                        color: Color::new(0.5, 0.5, 0.5, 1.0),
+                        ..Default::default()
+                    };
+                    let line = format!("{child_prefix}{BRANCH_ELBOW}{LOADING_LABEL}");
+                    canvas.draw_text(&line, Point::new(x, *y), &style);
+                }
+                *y += 1.0;
+            }
+
+            let children: Vec<&TreeNode> = node
+                .children
+                .iter()
+                .filter(|c| self.is_shown(c.id))
+                .collect();
+            let child_count = children.len();
+            for (i, child) in children.into_iter().enumerate() {
                 let child_is_last = i == child_count - 1;
                 self.render_node(
                     canvas,
@@ -888,4 +1149,163 @@ mod tests {
         // Should render deep structure
         assert!(canvas.texts.len() >= 5);
     }
+
+    fn lazy_tree(evict: bool) -> (Tree, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let root = TreeNode::new(1, "/").with_child(TreeNode::new(2, "home").lazy());
+        let tree = Tree::new()
+            .with_root(root)
+            .evict_on_collapse(evict)
+            .with_lazy_children(move |id| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                vec![
+                    TreeNode::new(id.0 * 10, "a"),
+                    TreeNode::new(id.0 * 10 + 1, "b"),
+                ]
+            });
+        (tree, calls)
+    }
+
+    #[test]
+    fn test_tree_lazy_children_loaded_once() {
+        use std::sync::atomic::Ordering;
+        let (mut tree, calls) = lazy_tree(false);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert_eq!(tree.visible_lines(), 2);
+
+        tree.expand(NodeId::new(2));
+        assert_eq!(tree.visible_lines(), 4);
+        tree.toggle(NodeId::new(2));
+        tree.toggle(NodeId::new(2));
+        assert_eq!(tree.visible_lines(), 4);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_tree_lazy_evict_on_collapse() {
+        use std::sync::atomic::Ordering;
+        let (mut tree, calls) = lazy_tree(true);
+        tree.expand(NodeId::new(2));
+        tree.collapse(NodeId::new(2));
+        let home = &tree.root.as_ref().expect("root").children[0];
+        assert!(home.lazy && home.children.is_empty());
+
+        tree.expand(NodeId::new(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_tree_lazy_evict_forgets_descendants() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let root = TreeNode::new(1, "/").with_child(TreeNode::new(2, "home").lazy());
+        let mut tree = Tree::new()
+            .with_root(root)
+            .evict_on_collapse(true)
+            .with_lazy_children(move |id| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                match id.0 {
+                    2 => vec![TreeNode::new(20, "user").lazy()],
+                    _ => vec![TreeNode::new(200, "docs")],
+                }
+            });
+
+        tree.expand(NodeId::new(2));
+        tree.expand(NodeId::new(20));
+        assert_eq!(tree.visible_lines(), 4);
+
+        tree.collapse(NodeId::new(2));
+        tree.expand(NodeId::new(2));
+        // /home/user comes back collapsed rather than stuck "loading…"
+        assert!(!tree.is_expanded(NodeId::new(20)));
+        assert_eq!(tree.visible_lines(), 3);
+
+        tree.expand(NodeId::new(20));
+        assert_eq!(tree.visible_lines(), 4);
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_tree_lazy_root_loads_with_provider() {
+        let mut tree = Tree::new().with_lazy_children(|_| vec![TreeNode::new(2, "child")]);
+        tree.set_root(TreeNode::new(1, "/").lazy());
+        assert!(!tree.root.as_ref().expect("root").lazy);
+        assert_eq!(tree.visible_lines(), 2);
+    }
+
+    #[test]
+    fn test_tree_lazy_loading_placeholder() {
+        // Expanded lazy node without a provider shows a placeholder
+        let root = TreeNode::new(1, "/").with_child(TreeNode::new(2, "home").lazy());
+        let mut tree = Tree::new().with_root(root);
+        tree.expand(NodeId::new(2));
+        tree.layout(Rect::new(0.0, 0.0, 40.0, 10.0));
+        assert_eq!(tree.visible_lines(), 3);
+
+        let mut canvas = MockCanvas::new();
+        tree.paint(&mut canvas);
+        assert!(canvas.texts.iter().any(|(t, _)| t.ends_with(LOADING_LABEL)));
+        assert!(canvas.texts.iter().any(|(t, _)| t == "▼ "));
+    }
+
+    fn three_level_tree() -> Tree {
+        let root = TreeNode::new(1, "root").with_children(vec![
+            TreeNode::new(2, "src").with_children(vec![
+                TreeNode::new(4, "widgets").with_child(TreeNode::new(7, "scrollbar.rs")),
+                TreeNode::new(5, "lib.rs"),
+            ]),
+            TreeNode::new(3, "docs").with_child(TreeNode::new(6, "guide.md")),
+        ]);
+        Tree::new().with_root(root)
+    }
+
+    #[test]
+    fn test_tree_filter_expands_ancestors() {
+        let mut tree = three_level_tree();
+        assert!(!tree.is_expanded(NodeId::new(2)));
+
+        tree.set_filter("scrollbar");
+        assert_eq!(tree.filter(), Some("scrollbar"));
+        assert!(tree.is_expanded(NodeId::new(2)));
+        assert!(tree.is_expanded(NodeId::new(4)));
+        assert_eq!(
+            tree.visible_nodes(),
+            vec![
+                NodeId::new(1),
+                NodeId::new(2),
+                NodeId::new(4),
+                NodeId::new(7)
+            ]
+        );
+        assert_eq!(tree.visible_lines(), 4);
+    }
+
+    #[test]
+    fn test_tree_clear_filter_restores_expansion() {
+        let mut tree = three_level_tree();
+        tree.expand(NodeId::new(3));
+        tree.set_filter("scroll");
+        tree.set_filter("scrollbar");
+        tree.clear_filter();
+
+        assert_eq!(tree.filter(), None);
+        assert!(tree.is_expanded(NodeId::new(3)));
+        assert!(!tree.is_expanded(NodeId::new(2)));
+        assert_eq!(tree.visible_lines(), 4);
+    }
+
+    #[test]
+    fn test_tree_filter_hides_unrelated_branches() {
+        let mut tree = three_level_tree();
+        tree.set_filter("guide");
+        tree.layout(Rect::new(0.0, 0.0, 40.0, 10.0));
+
+        let mut canvas = MockCanvas::new();
+        tree.paint(&mut canvas);
+        assert!(canvas.texts.iter().any(|(t, _)| t == "guide.md"));
+        assert!(!canvas.texts.iter().any(|(t, _)| t == "src"));
+    }
 }
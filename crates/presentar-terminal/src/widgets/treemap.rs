@@ -132,7 +132,9 @@ impl TreemapNode {
 /// Layout algorithm for treemap.
 #[derive(Debug, Clone, Copy, Default)]
 pub enum TreemapLayout {
-    /// Squarify algorithm (default, best aspect ratios).
+    /// Squarified layout (Bruls et al.), keeping rectangles close to square (default).
+    ///
+    /// Deterministic for a given child order; zero-value children are omitted.
     #[default]
     Squarify,
     /// Slice and dice (alternating horizontal/vertical).
@@ -240,7 +242,7 @@ impl Treemap {
         }
     }
 
-    /// Compute layout using squarify algorithm.
+    /// Compute layout using the selected algorithm.
     fn compute_layout(&mut self) {
         self.computed_rects.clear();
         self.flat_nodes.clear();
@@ -256,11 +258,152 @@ impl Treemap {
 
         // Compute rectangles
         let bounds = self.bounds;
-        self.squarify_layout(&root, bounds, 0, &mut 0);
+        match self.layout {
+            TreemapLayout::Squarify => self.squarify_layout(&root, bounds, 0, 0),
+            _ => self.greedy_layout(&root, bounds, 0, &mut 0),
+        }
+    }
+
+    /// Count nodes of a subtree as flattened by `flatten_nodes_static`.
+    fn flat_size(node: &TreemapNode, depth: usize) -> usize {
+        if depth < 3 {
+            1 + node
+                .children
+                .iter()
+                .map(|child| Self::flat_size(child, depth + 1))
+                .sum::<usize>()
+        } else {
+            1
+        }
+    }
+
+    /// Squarified layout (Bruls, Huizing & van Wijk).
+    ///
+    /// Children are placed largest first in rows along the shorter side of
+    /// the remaining space; a row grows while adding a child does not worsen
+    /// its worst aspect ratio.
+    fn squarify_layout(&mut self, node: &TreemapNode, rect: Rect, depth: usize, flat_idx: usize) {
+        self.computed_rects.push(ComputedRect {
+            rect,
+            node_idx: flat_idx,
+            depth,
+        });
+
+        if node.children.is_empty()
+            || depth >= self.max_depth
+            || rect.width < 2.0
+            || rect.height < 2.0
+        {
+            return;
+        }
+
+        // (flat index, child, value), omitting zero-area children
+        let mut next_idx = flat_idx + 1;
+        let mut items: Vec<(usize, &TreemapNode, f64)> = Vec::new();
+        for child in &node.children {
+            let value = child.total_value();
+            if value > 0.0 && value.is_finite() {
+                items.push((next_idx, child, value));
+            }
+            next_idx += Self::flat_size(child, depth + 1);
+        }
+        // Stable sort keeps input order for equal values
+        items.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut remaining = Rect::new(
+            rect.x + self.border_width,
+            rect.y + self.border_width,
+            (rect.width - 2.0 * self.border_width).max(0.0),
+            (rect.height - 2.0 * self.border_width).max(0.0),
+        );
+        let total: f64 = items.iter().map(|item| item.2).sum();
+        if total <= 0.0 || remaining.area() <= 0.0 {
+            return;
+        }
+
+        // Scale values to areas of the remaining rect
+        let scale = f64::from(remaining.area()) / total;
+        let areas: Vec<f64> = items.iter().map(|item| item.2 * scale).collect();
+
+        let mut start = 0;
+        while start < items.len() {
+            let side = f64::from(remaining.width.min(remaining.height));
+            let mut end = start + 1;
+            let mut worst = Self::row_worst(&areas[start..end], side);
+            while end < items.len() {
+                let with_next = Self::row_worst(&areas[start..=end], side);
+                if with_next > worst {
+                    break;
+                }
+                worst = with_next;
+                end += 1;
+            }
+
+            let row_area: f64 = areas[start..end].iter().sum();
+            let mut offset = 0.0f32;
+            let child_rects: Vec<Rect> = if remaining.width >= remaining.height {
+                // Column along the left edge
+                let width = (row_area / f64::from(remaining.height)) as f32;
+                let rects = areas[start..end]
+                    .iter()
+                    .map(|area| {
+                        let height = (*area / f64::from(width)) as f32;
+                        let r = Rect::new(remaining.x, remaining.y + offset, width, height);
+                        offset += height;
+                        r
+                    })
+                    .collect();
+                remaining = Rect::new(
+                    remaining.x + width,
+                    remaining.y,
+                    (remaining.width - width).max(0.0),
+                    remaining.height,
+                );
+                rects
+            } else {
+                // Row along the top edge
+                let height = (row_area / f64::from(remaining.width)) as f32;
+                let rects = areas[start..end]
+                    .iter()
+                    .map(|area| {
+                        let width = (*area / f64::from(height)) as f32;
+                        let r = Rect::new(remaining.x + offset, remaining.y, width, height);
+                        offset += width;
+                        r
+                    })
+                    .collect();
+                remaining = Rect::new(
+                    remaining.x,
+                    remaining.y + height,
+                    remaining.width,
+                    (remaining.height - height).max(0.0),
+                );
+                rects
+            };
+
+            for (&(idx, child, _), child_rect) in items[start..end].iter().zip(child_rects) {
+                self.squarify_layout(child, child_rect, depth + 1, idx);
+            }
+            start = end;
+        }
     }
 
-    /// Squarify algorithm implementation.
-    fn squarify_layout(
+    /// Worst aspect ratio of a row of areas laid along a side of length `side`.
+    fn row_worst(areas: &[f64], side: f64) -> f64 {
+        let sum: f64 = areas.iter().sum();
+        if sum <= 0.0 || side <= 0.0 {
+            return f64::INFINITY;
+        }
+        let side_sq = side * side;
+        let sum_sq = sum * sum;
+        areas
+            .iter()
+            .map(|&area| (side_sq * area / sum_sq).max(sum_sq / (side_sq * area)))
+            .fold(0.0, f64::max)
+    }
+
+    /// Greedy row layout, used by the layouts without a dedicated implementation.
+    fn greedy_layout(
         &mut self,
         node: &TreemapNode,
         rect: Rect,
@@ -451,7 +594,7 @@ impl Treemap {
                 r
             };
 
-            self.squarify_layout(children[child_idx], child_rect, depth + 1, node_idx);
+            self.greedy_layout(children[child_idx], child_rect, depth + 1, node_idx);
         }
 
         remaining
@@ -685,6 +828,104 @@ mod tests {
         assert!(matches!(treemap2.layout, TreemapLayout::Binary));
     }
 
+    fn squarified(root: TreemapNode, width: f32, height: f32) -> Treemap {
+        let mut treemap = Treemap::new()
+            .with_root(root)
+            .with_layout(TreemapLayout::Squarify);
+        treemap.layout(Rect::new(0.0, 0.0, width, height));
+        treemap
+    }
+
+    fn child_rects(treemap: &Treemap) -> Vec<(String, Rect)> {
+        treemap
+            .computed_rects
+            .iter()
+            .filter(|c| c.depth == 1)
+            .map(|c| (treemap.flat_nodes[c.node_idx].0.label.clone(), c.rect))
+            .collect()
+    }
+
+    #[test]
+    fn test_squarified_equal_weights_near_square() {
+        for (count, width, height) in [
+            (6, 60.0, 40.0),
+            (12, 60.0, 40.0),
+            (8, 80.0, 24.0),
+            (16, 40.0, 40.0),
+        ] {
+            let children: Vec<TreemapNode> = (0..count)
+                .map(|i| TreemapNode::leaf(&format!("n{i}"), 1.0))
+                .collect();
+            let treemap = squarified(TreemapNode::branch("root", children), width, height);
+
+            let rects = child_rects(&treemap);
+            assert_eq!(rects.len(), count);
+            for (label, rect) in rects {
+                let ratio = rect.width.max(rect.height) / rect.width.min(rect.height);
+                assert!(ratio < 2.0, "{label} in {count}: {rect:?} ratio {ratio}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_squarified_fills_bounds() {
+        let children: Vec<TreemapNode> = [50.0, 20.0, 10.0, 10.0, 5.0, 5.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| TreemapNode::leaf(&format!("n{i}"), v))
+            .collect();
+        let treemap = squarified(TreemapNode::branch("root", children), 60.0, 20.0);
+
+        let rects = child_rects(&treemap);
+        let area: f32 = rects.iter().map(|(_, r)| r.area()).sum();
+        assert!((area - 1200.0).abs() < 0.01);
+        // Largest child takes half the area
+        assert_eq!(rects[0].0, "n0");
+        assert!((rects[0].1.area() - 600.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_squarified_omits_zero_area_nodes() {
+        let root = TreemapNode::branch(
+            "root",
+            vec![
+                TreemapNode::leaf("a", 10.0),
+                TreemapNode::leaf("zero", 0.0),
+                TreemapNode::branch("b", vec![TreemapNode::leaf("b1", 10.0)]),
+            ],
+        );
+        let treemap = squarified(root, 40.0, 20.0);
+
+        let labels: Vec<String> = child_rects(&treemap).into_iter().map(|(l, _)| l).collect();
+        assert_eq!(labels, vec!["a", "b"]);
+        // Grandchild maps back to its own node
+        let deep: Vec<&str> = treemap
+            .computed_rects
+            .iter()
+            .filter(|c| c.depth == 2)
+            .map(|c| treemap.flat_nodes[c.node_idx].0.label.as_str())
+            .collect();
+        assert_eq!(deep, vec!["b1"]);
+    }
+
+    #[test]
+    fn test_squarified_deterministic() {
+        let make = || {
+            let children: Vec<TreemapNode> = [3.0, 7.0, 3.0, 1.0, 7.0]
+                .iter()
+                .enumerate()
+                .map(|(i, &v)| TreemapNode::leaf(&format!("n{i}"), v))
+                .collect();
+            squarified(TreemapNode::branch("root", children), 50.0, 30.0)
+        };
+        let first = child_rects(&make());
+        let second = child_rects(&make());
+        assert_eq!(first, second);
+        // Equal values keep input order
+        let labels: Vec<&str> = first.iter().map(|(l, _)| l.as_str()).collect();
+        assert_eq!(labels, vec!["n1", "n4", "n0", "n2", "n3"]);
+    }
+
     #[test]
     fn test_treemap_with_gradient() {
         let gradient = Gradient::two(
@@ -37,16 +37,64 @@ pub struct ViolinData {
 }
 
 /// Statistics for a violin.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct ViolinStats {
+    /// Minimum value.
     pub min: f64,
+    /// Maximum value.
     pub max: f64,
+    /// Median (50th percentile).
     pub median: f64,
+    /// First quartile (25th percentile).
     pub q1: f64,
+    /// Third quartile (75th percentile).
     pub q3: f64,
+    /// Arithmetic mean.
     pub mean: f64,
 }
 
+impl ViolinStats {
+    /// Create violin stats from precomputed values.
+    #[must_use]
+    pub fn new(min: f64, q1: f64, median: f64, q3: f64, max: f64, mean: f64) -> Self {
+        Self {
+            min,
+            max,
+            median,
+            q1,
+            q3,
+            mean,
+        }
+    }
+
+    /// Calculate violin stats from a data slice (sorts a copy).
+    #[must_use]
+    pub fn from_data(values: &[f64]) -> Self {
+        if values.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let n = sorted.len();
+        let median = if n % 2 == 0 {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+        } else {
+            sorted[n / 2]
+        };
+
+        Self {
+            min: sorted[0],
+            max: sorted[n - 1],
+            median,
+            q1: sorted[n / 4],
+            q3: sorted[3 * n / 4],
+            mean: sorted.iter().sum::<f64>() / n as f64,
+        }
+    }
+}
+
 impl ViolinData {
     /// Create new violin data.
     #[must_use]
@@ -60,6 +108,37 @@ impl ViolinData {
         }
     }
 
+    /// Create violin data from precomputed stats and density curve.
+    ///
+    /// `densities` are sampled evenly from `stats.min` to `stats.max` and
+    /// normalized to [0, 1]. No raw samples are kept, so rendering never
+    /// re-sorts or re-runs the KDE.
+    #[must_use]
+    pub fn from_stats(label: impl Into<String>, stats: ViolinStats, densities: Vec<f64>) -> Self {
+        let mut densities = densities;
+        let max_density = densities.iter().copied().fold(0.0, f64::max);
+        if max_density > 0.0 {
+            for d in &mut densities {
+                *d /= max_density;
+            }
+        }
+        Self {
+            label: label.into(),
+            values: Vec::new(),
+            color: Color::new(0.3, 0.7, 1.0, 1.0),
+            densities: Some(densities),
+            stats: Some(stats),
+        }
+    }
+
+    /// Compute stats and KDE now so later renders reuse them.
+    #[must_use]
+    pub fn precomputed(mut self, kde_points: usize) -> Self {
+        self.compute_stats();
+        self.compute_kde(kde_points.max(2));
+        self
+    }
+
     /// Set color.
     #[must_use]
     pub fn with_color(mut self, color: Color) -> Self {
@@ -69,41 +148,19 @@ impl ViolinData {
 
     /// Compute statistics for this violin.
     fn compute_stats(&mut self) {
+        self.stats = Some(ViolinStats::from_data(&self.values));
+    }
+
+    /// Value range covered by this violin.
+    fn value_range(&self) -> Option<(f64, f64)> {
         if self.values.is_empty() {
-            self.stats = Some(ViolinStats {
-                min: 0.0,
-                max: 0.0,
-                median: 0.0,
-                q1: 0.0,
-                q3: 0.0,
-                mean: 0.0,
-            });
-            return;
+            return self.stats.map(|s| (s.min, s.max));
         }
-
-        let mut sorted = self.values.clone();
-        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-
-        let n = sorted.len();
-        let min = sorted[0];
-        let max = sorted[n - 1];
-        let median = if n % 2 == 0 {
-            (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
-        } else {
-            sorted[n / 2]
-        };
-        let q1 = sorted[n / 4];
-        let q3 = sorted[3 * n / 4];
-        let mean = sorted.iter().sum::<f64>() / n as f64;
-
-        self.stats = Some(ViolinStats {
-            min,
-            max,
-            median,
-            q1,
-            q3,
-            mean,
-        });
+        let finite = self.values.iter().copied().filter(|v| v.is_finite());
+        finite.fold(None, |acc, v| match acc {
+            None => Some((v, v)),
+            Some((lo, hi)) => Some((f64::min(lo, v), f64::max(hi, v))),
+        })
     }
 
     /// Get statistics, computing if necessary.
@@ -122,7 +179,7 @@ impl ViolinData {
             return;
         }
 
-        let stats = self.stats().clone();
+        let stats = *self.stats();
         let range = stats.max - stats.min;
         if range == 0.0 {
             self.densities = Some(vec![1.0; num_points]);
@@ -302,13 +359,9 @@ impl ViolinPlot {
         let mut min = f64::INFINITY;
         let mut max = f64::NEG_INFINITY;
 
-        for violin in &self.violins {
-            for &v in &violin.values {
-                if v.is_finite() {
-                    min = min.min(v);
-                    max = max.max(v);
-                }
-            }
+        for (lo, hi) in self.violins.iter().filter_map(ViolinData::value_range) {
+            min = min.min(lo);
+            max = max.max(hi);
         }
 
         if min == f64::INFINITY {
@@ -617,6 +670,50 @@ mod tests {
         assert_eq!(stats.max, 0.0);
     }
 
+    #[test]
+    fn test_violin_stats_from_data_matches_lazy() {
+        let values = vec![5.0, 1.0, 4.0, 2.0, 3.0];
+        let mut data = ViolinData::new("Test", values.clone());
+        assert_eq!(*data.stats(), ViolinStats::from_data(&values));
+    }
+
+    #[test]
+    fn test_violin_from_stats_without_samples() {
+        let stats = ViolinStats::new(10.0, 20.0, 25.0, 30.0, 50.0, 27.0);
+        let data = ViolinData::from_stats("Pre", stats, vec![0.5, 2.0, 1.0]);
+        assert!(data.values.is_empty());
+        assert_eq!(data.densities, Some(vec![0.25, 1.0, 0.5]));
+
+        let plot = ViolinPlot::new(vec![data]);
+        let (min, max) = plot.global_range();
+        assert!((min - 8.0).abs() < 1e-9);
+        assert!((max - 52.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_violin_from_stats_paints_median() {
+        let stats = ViolinStats::new(0.0, 25.0, 50.0, 75.0, 100.0, 50.0);
+        let mut plot = ViolinPlot::new(vec![ViolinData::from_stats(
+            "Pre",
+            stats,
+            vec![0.2, 0.6, 1.0, 0.6, 0.2],
+        )]);
+
+        let mut buffer = CellBuffer::new(20, 12);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        plot.layout(Rect::new(0.0, 0.0, 20.0, 11.0));
+        plot.paint(&mut canvas);
+
+        assert!(buffer.rows_as_strings().iter().any(|row| row.contains('─')));
+    }
+
+    #[test]
+    fn test_violin_precomputed_caches_kde() {
+        let data = ViolinData::new("Test", vec![1.0, 2.0, 3.0, 4.0, 5.0]).precomputed(30);
+        assert_eq!(data.densities.as_ref().map(Vec::len), Some(30));
+        assert_eq!(data.stats.map(|s| s.median), Some(3.0));
+    }
+
     #[test]
     fn test_violin_kde() {
         let mut data = ViolinData::new("Test", vec![1.0, 2.0, 3.0, 4.0, 5.0]);
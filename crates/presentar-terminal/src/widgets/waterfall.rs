@@ -0,0 +1,389 @@
+//! Waterfall chart widget for incremental contributions.
+//!
+//! Each labeled delta is drawn as a floating bar starting at the running total
+//! of the deltas before it; a final total bar is anchored to zero. Connector
+//! lines (`─`) link the end of each bar to the start of the next.
+
+use crate::theme::Theme;
+use presentar_core::{
+    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
+    LayoutResult, Point, Rect, Size, TextStyle, TypeId, Widget,
+};
+use std::any::Any;
+use std::time::Duration;
+
+/// Waterfall chart widget.
+#[derive(Debug, Clone)]
+pub struct Waterfall {
+    /// Labeled signed deltas, in order.
+    deltas: Vec<(String, f64)>,
+    /// Label of the final total bar.
+    total_label: String,
+    positive_color: Color,
+    negative_color: Color,
+    total_color: Color,
+    show_labels: bool,
+    bounds: Rect,
+}
+
+impl Default for Waterfall {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl Waterfall {
+    /// Create a waterfall from labeled deltas.
+    ///
+    /// Non-finite deltas are treated as zero.
+    #[must_use]
+    pub fn new(deltas: Vec<(String, f64)>) -> Self {
+        let theme = Theme::default();
+        Self {
+            deltas,
+            total_label: "Total".to_string(),
+            positive_color: theme.positive(),
+            negative_color: theme.negative(),
+            total_color: theme.foreground,
+            show_labels: true,
+            bounds: Rect::default(),
+        }
+    }
+
+    /// Append a labeled delta.
+    #[must_use]
+    pub fn with_delta(mut self, label: impl Into<String>, delta: f64) -> Self {
+        self.deltas.push((label.into(), delta));
+        self
+    }
+
+    /// Set the label of the total bar.
+    #[must_use]
+    pub fn with_total_label(mut self, label: impl Into<String>) -> Self {
+        self.total_label = label.into();
+        self
+    }
+
+    /// Take bar colors from a theme.
+    #[must_use]
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.positive_color = theme.positive();
+        self.negative_color = theme.negative();
+        self.total_color = theme.foreground;
+        self
+    }
+
+    /// Set positive, negative and total bar colors.
+    #[must_use]
+    pub fn with_colors(mut self, positive: Color, negative: Color, total: Color) -> Self {
+        self.positive_color = positive;
+        self.negative_color = negative;
+        self.total_color = total;
+        self
+    }
+
+    /// Toggle the label row.
+    #[must_use]
+    pub fn with_labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Update deltas.
+    pub fn set_deltas(&mut self, deltas: Vec<(String, f64)>) {
+        self.deltas = deltas;
+    }
+
+    /// Get the (start, end) of each delta bar followed by the total bar.
+    #[must_use]
+    pub fn bar_spans(&self) -> Vec<(f64, f64)> {
+        let mut running = 0.0;
+        let mut spans: Vec<(f64, f64)> = self
+            .deltas
+            .iter()
+            .map(|&(_, delta)| {
+                let start = running;
+                if delta.is_finite() {
+                    running += delta;
+                }
+                (start, running)
+            })
+            .collect();
+        spans.push((0.0, running));
+        spans
+    }
+
+    /// Get the running total after all deltas.
+    #[must_use]
+    pub fn total(&self) -> f64 {
+        self.bar_spans().last().map_or(0.0, |&(_, end)| end)
+    }
+
+    /// Get the value range covered by all bars, always including zero.
+    fn value_range(spans: &[(f64, f64)]) -> (f64, f64) {
+        let (lo, hi) = spans
+            .iter()
+            .fold((0.0_f64, 0.0_f64), |(lo, hi), &(start, end)| {
+                (lo.min(start).min(end), hi.max(start).max(end))
+            });
+        if (hi - lo).abs() < f64::EPSILON {
+            (lo, lo + 1.0)
+        } else {
+            (lo, hi)
+        }
+    }
+
+    fn bar_color(&self, index: usize, (start, end): (f64, f64)) -> Color {
+        if index == self.deltas.len() {
+            self.total_color
+        } else if end >= start {
+            self.positive_color
+        } else {
+            self.negative_color
+        }
+    }
+
+    fn label(&self, index: usize) -> &str {
+        self.deltas
+            .get(index)
+            .map_or(self.total_label.as_str(), |(label, _)| label.as_str())
+    }
+}
+
+impl Widget for Waterfall {
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<Self>()
+    }
+
+    fn measure(&self, constraints: Constraints) -> Size {
+        let width = ((self.deltas.len() + 1) * 4) as f32;
+        constraints.constrain(Size::new(width, constraints.max_height.min(12.0)))
+    }
+
+    fn layout(&mut self, bounds: Rect) -> LayoutResult {
+        self.bounds = bounds;
+        LayoutResult {
+            size: Size::new(bounds.width, bounds.height),
+        }
+    }
+
+    fn paint(&self, canvas: &mut dyn Canvas) {
+        let label_rows = usize::from(self.show_labels);
+        let cols = self.bounds.width.max(0.0) as usize;
+        let rows = (self.bounds.height.max(0.0) as usize).saturating_sub(label_rows);
+        let spans = self.bar_spans();
+        if cols == 0 || rows == 0 {
+            return;
+        }
+
+        // One slot per bar: the bar plus a one-cell gap for the connector
+        let slot = (cols / spans.len()).max(1);
+        let bar_width = slot.saturating_sub(1).max(1);
+        let (lo, hi) = Self::value_range(&spans);
+        let row_of = |value: f64| -> usize {
+            let t = ((hi - value) / (hi - lo)).clamp(0.0, 1.0);
+            ((t * (rows - 1) as f64).round() as usize).min(rows - 1)
+        };
+
+        for (i, &span) in spans.iter().enumerate() {
+            let x0 = i * slot;
+            if x0 >= cols {
+                break;
+            }
+            let width = bar_width.min(cols - x0);
+            let style = TextStyle {
+                color: self.bar_color(i, span),
+                ..Default::default()
+            };
+            let x = self.bounds.x + x0 as f32;
+
+            let bar: String = "█".repeat(width);
+            for row in row_of(span.0.max(span.1))..=row_of(span.0.min(span.1)) {
+                canvas.draw_text(&bar, Point::new(x, self.bounds.y + row as f32), &style);
+            }
+
+            // Connector from this bar's end to the next bar's start
+            let connector_x = x0 + bar_width;
+            if i + 1 < spans.len() && slot > 1 && connector_x < cols {
+                canvas.draw_text(
+                    "─",
+                    Point::new(
+                        self.bounds.x + connector_x as f32,
+                        self.bounds.y + row_of(span.1) as f32,
+                    ),
+                    &TextStyle {
+                        color: Color::new(0.6, 0.6, 0.6, 1.0),
+                        ..Default::default()
+                    },
+                );
+            }
+
+            if self.show_labels {
+                let label: String = self.label(i).chars().take(width).collect();
+                canvas.draw_text(
+                    &label,
+                    Point::new(x, self.bounds.y + rows as f32),
+                    &TextStyle {
+                        color: Color::new(0.6, 0.6, 0.6, 1.0),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+    }
+
+    fn event(&mut self, _event: &Event) -> Option<Box<dyn Any + Send>> {
+        None
+    }
+
+    fn children(&self) -> &[Box<dyn Widget>] {
+        &[]
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn Widget>] {
+        &mut []
+    }
+}
+
+impl Brick for Waterfall {
+    fn brick_name(&self) -> &'static str {
+        "waterfall"
+    }
+
+    fn assertions(&self) -> &[BrickAssertion] {
+        static ASSERTIONS: &[BrickAssertion] = &[BrickAssertion::max_latency_ms(16)];
+        ASSERTIONS
+    }
+
+    fn budget(&self) -> BrickBudget {
+        BrickBudget::uniform(16)
+    }
+
+    fn verify(&self) -> BrickVerification {
+        BrickVerification {
+            passed: self.assertions().to_vec(),
+            failed: vec![],
+            verification_time: Duration::from_micros(10),
+        }
+    }
+
+    fn to_html(&self) -> String {
+        String::new()
+    }
+
+    fn to_css(&self) -> String {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::{CellBuffer, DirectTerminalCanvas};
+
+    fn render(chart: &mut Waterfall, width: u16, height: u16) -> CellBuffer {
+        chart.layout(Rect::new(0.0, 0.0, f32::from(width), f32::from(height)));
+        let mut buffer = CellBuffer::new(width, height);
+        let mut canvas = DirectTerminalCanvas::new(&mut buffer);
+        chart.paint(&mut canvas);
+        buffer
+    }
+
+    /// Rows (top, bottom) covered by bar glyphs in column `x`.
+    fn bar_rows(buffer: &CellBuffer, x: u16, height: u16) -> Option<(u16, u16)> {
+        let rows: Vec<u16> = (0..height)
+            .filter(|&y| buffer.get(x, y).is_some_and(|c| c.symbol.as_str() == "█"))
+            .collect();
+        Some((*rows.first()?, *rows.last()?))
+    }
+
+    fn memory() -> Waterfall {
+        Waterfall::new(vec![
+            ("heap".to_string(), 50.0),
+            ("cache".to_string(), 30.0),
+            ("freed".to_string(), -20.0),
+        ])
+        .with_colors(Color::GREEN, Color::RED, Color::WHITE)
+    }
+
+    #[test]
+    fn test_waterfall_total_is_sum() {
+        let chart = memory();
+        assert_eq!(chart.total(), 60.0);
+        assert_eq!(
+            chart.bar_spans(),
+            vec![(0.0, 50.0), (50.0, 80.0), (80.0, 60.0), (0.0, 60.0)]
+        );
+    }
+
+    #[test]
+    fn test_waterfall_ignores_non_finite() {
+        let chart = Waterfall::default()
+            .with_delta("a", 5.0)
+            .with_delta("b", f64::NAN)
+            .with_delta("c", -2.0);
+        assert_eq!(chart.total(), 3.0);
+    }
+
+    #[test]
+    fn test_waterfall_negative_renders_below_predecessor() {
+        let mut chart = memory().with_labels(false);
+        let buffer = render(&mut chart, 16, 9);
+
+        // Slots are 4 cells wide: bars at x = 0, 4, 8, 12
+        let cache = bar_rows(&buffer, 4, 9).expect("cache bar");
+        let freed = bar_rows(&buffer, 8, 9).expect("freed bar");
+        // The negative bar starts at its predecessor's end and extends down
+        assert_eq!(freed.0, cache.0);
+        assert!(freed.1 > cache.0);
+        assert!(freed.1 < bar_rows(&buffer, 0, 9).expect("heap").1);
+        assert_eq!(buffer.get(8, freed.1).expect("cell").fg, Color::RED);
+        assert_eq!(buffer.get(4, cache.1).expect("cell").fg, Color::GREEN);
+    }
+
+    #[test]
+    fn test_waterfall_total_bar_anchored_to_zero() {
+        let mut chart = memory().with_labels(false);
+        let buffer = render(&mut chart, 16, 9);
+
+        let heap = bar_rows(&buffer, 0, 9).expect("heap bar");
+        let total = bar_rows(&buffer, 12, 9).expect("total bar");
+        assert_eq!(total.1, heap.1);
+        assert_eq!(buffer.get(12, total.1).expect("cell").fg, Color::WHITE);
+    }
+
+    #[test]
+    fn test_waterfall_connectors() {
+        let mut chart = memory().with_labels(false);
+        let buffer = render(&mut chart, 16, 9);
+
+        // Connector after the heap bar sits on the heap bar's top row
+        let heap = bar_rows(&buffer, 0, 9).expect("heap bar");
+        assert_eq!(buffer.get(3, heap.0).expect("cell").symbol.as_str(), "─");
+        // No connector after the total bar
+        assert!((0..9).all(|y| buffer.get(15, y).expect("cell").symbol.as_str() == " "));
+    }
+
+    #[test]
+    fn test_waterfall_labels() {
+        let mut chart = memory().with_total_label("Sum");
+        let buffer = render(&mut chart, 16, 10);
+        assert_eq!(buffer.rows_as_strings()[9], "hea cac fre Sum ");
+    }
+
+    #[test]
+    fn test_waterfall_empty() {
+        let chart = Waterfall::default();
+        assert_eq!(chart.total(), 0.0);
+        assert_eq!(chart.bar_spans(), vec![(0.0, 0.0)]);
+        assert_eq!(Waterfall::value_range(&chart.bar_spans()), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_waterfall_brick() {
+        let chart = Waterfall::default();
+        assert_eq!(chart.brick_name(), "waterfall");
+        assert!(chart.verify().is_valid());
+        assert!(chart.children().is_empty());
+    }
+}
@@ -38,6 +38,8 @@ fn create_test_process() -> ProcessEntry {
         cgroup: None,
         nice: None,
         threads: None,
+        read_bytes_per_sec: None,
+        write_bytes_per_sec: None,
         parent_pid: None,
         tree_depth: 0,
         is_last_child: false,
@@ -213,6 +213,8 @@ fn f046_processtable_has_header() {
         cgroup: None,
         nice: None,
         threads: None,
+        read_bytes_per_sec: None,
+        write_bytes_per_sec: None,
         parent_pid: None,
         tree_depth: 0,
         is_last_child: false,
@@ -249,6 +251,8 @@ fn f047_processtable_has_separator() {
         cgroup: None,
         nice: None,
         threads: None,
+        read_bytes_per_sec: None,
+        write_bytes_per_sec: None,
         parent_pid: None,
         tree_depth: 0,
         is_last_child: false,
@@ -287,6 +291,8 @@ fn f048_processtable_selection_works() {
         cgroup: None,
         nice: None,
         threads: None,
+        read_bytes_per_sec: None,
+        write_bytes_per_sec: None,
         parent_pid: None,
         tree_depth: 0,
         is_last_child: false,
@@ -304,6 +310,8 @@ fn f048_processtable_selection_works() {
         cgroup: None,
         nice: None,
         threads: None,
+        read_bytes_per_sec: None,
+        write_bytes_per_sec: None,
         parent_pid: None,
         tree_depth: 0,
         is_last_child: false,
@@ -338,6 +346,8 @@ fn f049_processtable_sorting() {
         cgroup: None,
         nice: None,
         threads: None,
+        read_bytes_per_sec: None,
+        write_bytes_per_sec: None,
         parent_pid: None,
         tree_depth: 0,
         is_last_child: false,
@@ -355,6 +365,8 @@ fn f049_processtable_sorting() {
         cgroup: None,
         nice: None,
         threads: None,
+        read_bytes_per_sec: None,
+        write_bytes_per_sec: None,
         parent_pid: None,
         tree_depth: 0,
         is_last_child: false,
@@ -390,6 +402,8 @@ fn f050_processtable_scrolling() {
             cgroup: None,
             nice: None,
             threads: None,
+            read_bytes_per_sec: None,
+            write_bytes_per_sec: None,
             parent_pid: None,
             tree_depth: 0,
             is_last_child: false,
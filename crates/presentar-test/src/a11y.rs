@@ -9,7 +9,10 @@
 //! - Heading hierarchy (1.3.1)
 
 use presentar_core::widget::AccessibleRole;
-use presentar_core::{Color, Widget};
+use presentar_core::{AccessibilityTree, AccessibleNodeId, Color, Widget};
+#[cfg(feature = "terminal")]
+use presentar_terminal::direct::{CellBuffer, Modifiers};
+use std::collections::BTreeMap;
 
 /// Minimum touch target size in pixels (WCAG 2.5.5)
 pub const MIN_TOUCH_TARGET_SIZE: f32 = 44.0;
@@ -181,6 +184,148 @@ impl A11yChecker {
             passes_aaa: ratio >= aaa_threshold,
         }
     }
+
+    /// Check the contrast of rendered terminal text (WCAG 1.4.3).
+    ///
+    /// Every glyph is paired with its cell background; bold cells use the
+    /// large-text threshold. Adjacent failing cells with the same colors on a
+    /// row are reported as one violation at the run's first cell.
+    /// Transparent backgrounds are assumed to be the terminal default (black).
+    #[cfg(feature = "terminal")]
+    #[must_use]
+    pub fn check_cell_contrast(buffer: &CellBuffer, config: &A11yConfig) -> A11yReport {
+        let mut violations = Vec::new();
+
+        for y in 0..buffer.height() {
+            // (fg, bg, bold) of the failing run currently open on this row
+            let mut open_run: Option<(Color, Color, bool)> = None;
+            for x in 0..buffer.width() {
+                let Some(cell) = buffer.get(x, y) else {
+                    continue;
+                };
+                if cell.symbol.trim().is_empty() || cell.modifiers.contains(Modifiers::HIDDEN) {
+                    continue;
+                }
+
+                let bold = cell.modifiers.contains(Modifiers::BOLD);
+                let background = if cell.bg.a == 0.0 {
+                    Color::BLACK
+                } else {
+                    cell.bg
+                };
+                let key = (cell.fg, background, bold);
+                let ratio = cell.fg.contrast_ratio(&background);
+                let minimum = if bold {
+                    config.min_contrast_large
+                } else {
+                    config.min_contrast_normal
+                };
+
+                if ratio >= minimum {
+                    open_run = None;
+                } else if open_run != Some(key) {
+                    open_run = Some(key);
+                    violations.push(A11yViolation {
+                        rule: "color-contrast".to_string(),
+                        message: format!(
+                            "Insufficient contrast {ratio:.2}:1 at ({x}, {y}) (minimum {minimum}:1)"
+                        ),
+                        wcag: "1.4.3".to_string(),
+                        impact: Impact::Serious,
+                    });
+                }
+            }
+        }
+
+        A11yReport { violations }
+    }
+
+    /// Audit keyboard reachability and tab order (WCAG 2.1.1, 2.4.3).
+    ///
+    /// Walks the tree from the root in document order. Every enabled, visible
+    /// focusable or interactive node must be in the tab sequence
+    /// (`tab_index >= 0`), and explicit tab indices (`> 0`) must be unique and
+    /// form a gap-free sequence starting at 1.
+    #[must_use]
+    pub fn check_focus_order(tree: &AccessibilityTree) -> A11yReport {
+        let mut violations = Vec::new();
+        let mut explicit: BTreeMap<i32, Vec<String>> = BTreeMap::new();
+
+        let mut stack: Vec<AccessibleNodeId> = tree.root().map(|n| n.id).into_iter().collect();
+        while let Some(id) = stack.pop() {
+            let Some(node) = tree.get(id) else {
+                continue;
+            };
+            stack.extend(node.children.iter().rev());
+
+            if !node.enabled || !node.visible {
+                continue;
+            }
+            let label = node
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("{:?}#{}", node.role, node.id.0));
+
+            if node.tab_index < 0 {
+                if node.focusable || Self::is_interactive_role(node.role) {
+                    violations.push(A11yViolation {
+                        rule: "focus-unreachable".to_string(),
+                        message: format!("'{label}' cannot be reached with the keyboard"),
+                        wcag: "2.1.1".to_string(),
+                        impact: Impact::Critical,
+                    });
+                }
+            } else if node.tab_index > 0 {
+                explicit.entry(node.tab_index).or_default().push(label);
+            }
+        }
+
+        let mut expected = 1;
+        for (&index, labels) in &explicit {
+            if index > expected {
+                violations.push(A11yViolation {
+                    rule: "focus-order-gap".to_string(),
+                    message: format!(
+                        "Tab order gap: index {expected} missing before '{}' (index {index})",
+                        labels[0]
+                    ),
+                    wcag: "2.4.3".to_string(),
+                    impact: Impact::Moderate,
+                });
+            }
+            if labels.len() > 1 {
+                violations.push(A11yViolation {
+                    rule: "focus-order-duplicate".to_string(),
+                    message: format!(
+                        "Tab index {index} shared by {} elements: {}",
+                        labels.len(),
+                        labels.join(", ")
+                    ),
+                    wcag: "2.4.3".to_string(),
+                    impact: Impact::Serious,
+                });
+            }
+            expected = index + 1;
+        }
+
+        A11yReport { violations }
+    }
+
+    /// Roles that must be operable from the keyboard.
+    const fn is_interactive_role(role: AccessibleRole) -> bool {
+        matches!(
+            role,
+            AccessibleRole::Button
+                | AccessibleRole::Checkbox
+                | AccessibleRole::TextInput
+                | AccessibleRole::Link
+                | AccessibleRole::MenuItem
+                | AccessibleRole::ComboBox
+                | AccessibleRole::Slider
+                | AccessibleRole::Tab
+                | AccessibleRole::Radio
+        )
+    }
 }
 
 /// Accessibility report.
@@ -1350,3 +1495,118 @@ impl FormA11yReport {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use presentar_core::{AccessibilityTreeBuilder, Rect};
+
+    #[cfg(feature = "terminal")]
+    fn buffer_with_text(text: &str, fg: Color, bg: Color, modifiers: Modifiers) -> CellBuffer {
+        let mut buffer = CellBuffer::new(20, 2);
+        for (x, ch) in text.chars().enumerate() {
+            buffer.update(x as u16, 0, &ch.to_string(), fg, bg, modifiers);
+        }
+        buffer
+    }
+
+    #[cfg(feature = "terminal")]
+    #[test]
+    fn test_cell_contrast_gray_on_gray() {
+        let fg = Color::rgb(0.5, 0.5, 0.5);
+        let bg = Color::rgb(0.4, 0.4, 0.4);
+        let buffer = buffer_with_text("low contrast", fg, bg, Modifiers::NONE);
+
+        let report = A11yChecker::check_cell_contrast(&buffer, &A11yConfig::default());
+        assert_eq!(report.violations.len(), 1);
+        let violation = &report.violations[0];
+        assert_eq!(violation.rule, "color-contrast");
+        assert_eq!(violation.wcag, "1.4.3");
+        assert!(violation.message.contains("at (0, 0)"));
+    }
+
+    #[cfg(feature = "terminal")]
+    #[test]
+    fn test_cell_contrast_white_on_black_passes() {
+        let buffer = buffer_with_text("readable", Color::WHITE, Color::BLACK, Modifiers::NONE);
+        assert!(A11yChecker::check_cell_contrast(&buffer, &A11yConfig::default()).is_passing());
+    }
+
+    #[cfg(feature = "terminal")]
+    #[test]
+    fn test_cell_contrast_bold_uses_large_threshold() {
+        // ~3.7:1 fails AA for normal text but passes for bold/large text.
+        let fg = Color::rgb(0.4, 0.4, 0.4);
+        let config = A11yConfig::default();
+
+        let normal = buffer_with_text("x", fg, Color::BLACK, Modifiers::NONE);
+        let ratio = fg.contrast_ratio(&Color::BLACK);
+        assert!((3.0..4.5).contains(&ratio), "ratio {ratio}");
+        assert_eq!(
+            A11yChecker::check_cell_contrast(&normal, &config)
+                .violations
+                .len(),
+            1
+        );
+
+        let bold = buffer_with_text("x", fg, Color::BLACK, Modifiers::BOLD);
+        assert!(A11yChecker::check_cell_contrast(&bold, &config).is_passing());
+    }
+
+    #[cfg(feature = "terminal")]
+    #[test]
+    fn test_cell_contrast_ignores_blank_cells() {
+        let gray = Color::rgb(0.5, 0.5, 0.5);
+        let mut buffer = CellBuffer::new(4, 1);
+        buffer.fill_rect(0, 0, 4, 1, gray, gray);
+        assert!(A11yChecker::check_cell_contrast(&buffer, &A11yConfig::default()).is_passing());
+    }
+
+    fn form_tree(first: i32, second: i32) -> AccessibilityTree {
+        let bounds = Rect::new(0.0, 0.0, 80.0, 24.0);
+        let (builder, ok) = AccessibilityTreeBuilder::new()
+            .root(AccessibleRole::Generic, bounds)
+            .child(AccessibleRole::Button, Rect::new(0.0, 0.0, 10.0, 1.0));
+        let (builder, cancel) =
+            builder.child(AccessibleRole::Button, Rect::new(12.0, 0.0, 10.0, 1.0));
+        let mut tree = builder.build();
+        for (id, name, index) in [(ok, "OK", first), (cancel, "Cancel", second)] {
+            let node = tree.get_mut(id).expect("node");
+            node.name = Some(name.to_string());
+            node.focusable = true;
+            node.tab_index = index;
+        }
+        tree
+    }
+
+    #[test]
+    fn test_focus_order_duplicate_index() {
+        let report = A11yChecker::check_focus_order(&form_tree(1, 1));
+        assert_eq!(report.violations.len(), 1);
+        let violation = &report.violations[0];
+        assert_eq!(violation.rule, "focus-order-duplicate");
+        assert_eq!(violation.wcag, "2.4.3");
+        assert!(violation.message.contains("OK, Cancel"));
+    }
+
+    #[test]
+    fn test_focus_order_sequential_passes() {
+        assert!(A11yChecker::check_focus_order(&form_tree(1, 2)).is_passing());
+        assert!(A11yChecker::check_focus_order(&form_tree(0, 0)).is_passing());
+    }
+
+    #[test]
+    fn test_focus_order_gap() {
+        let report = A11yChecker::check_focus_order(&form_tree(1, 3));
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(report.violations[0].rule, "focus-order-gap");
+    }
+
+    #[test]
+    fn test_focus_order_unreachable() {
+        let report = A11yChecker::check_focus_order(&form_tree(0, -1));
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(report.violations[0].rule, "focus-unreachable");
+        assert!(report.violations[0].message.contains("'Cancel'"));
+    }
+}
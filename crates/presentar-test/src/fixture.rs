@@ -428,15 +428,49 @@ impl TestData {
     /// Generate minimal PNG image data.
     #[must_use]
     pub fn minimal_png(width: u32, height: u32, color: [u8; 4]) -> Vec<u8> {
-        // This is a minimal valid PNG with solid color
-        // In real implementation would use proper PNG encoding
-        let mut png = Vec::new();
+        let pixels = width as usize * height as usize;
+        Self::encode_png(width, height, &color.repeat(pixels))
+    }
 
-        // PNG signature
-        png.extend_from_slice(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]);
+    /// Encode RGBA pixel data as a PNG.
+    ///
+    /// `rgba` holds `width * height` pixels in row-major order. The image
+    /// data is stored in uncompressed deflate blocks, which every decoder
+    /// accepts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rgba` is not exactly `width * height * 4` bytes long.
+    #[must_use]
+    pub fn encode_png(width: u32, height: u32, rgba: &[u8]) -> Vec<u8> {
+        let row_len = width as usize * 4;
+        assert_eq!(
+            Some(rgba.len()),
+            row_len.checked_mul(height as usize),
+            "pixel data does not match {width}x{height}"
+        );
+
+        let mut raw = Vec::with_capacity(rgba.len() + height as usize);
+        for y in 0..height as usize {
+            raw.push(0); // filter: none
+            raw.extend_from_slice(&rgba[y * row_len..(y + 1) * row_len]);
+        }
 
-        // IHDR chunk
-        let mut ihdr = Vec::new();
+        let mut idat = vec![0x78, 0x01]; // zlib header, no compression
+        let mut blocks = raw.chunks(usize::from(u16::MAX)).peekable();
+        if blocks.peek().is_none() {
+            idat.extend_from_slice(&[0x01, 0x00, 0x00, 0xFF, 0xFF]);
+        }
+        while let Some(block) = blocks.next() {
+            idat.push(u8::from(blocks.peek().is_none())); // BFINAL, stored
+            let len = u16::try_from(block.len()).unwrap_or(u16::MAX);
+            idat.extend_from_slice(&len.to_le_bytes());
+            idat.extend_from_slice(&(!len).to_le_bytes());
+            idat.extend_from_slice(block);
+        }
+        idat.extend_from_slice(&Self::adler32(&raw).to_be_bytes());
+
+        let mut ihdr = Vec::with_capacity(13);
         ihdr.extend_from_slice(&width.to_be_bytes());
         ihdr.extend_from_slice(&height.to_be_bytes());
         ihdr.push(8); // bit depth
@@ -445,46 +479,22 @@ impl TestData {
         ihdr.push(0); // filter
         ihdr.push(0); // interlace
 
-        png.extend_from_slice(&(ihdr.len() as u32).to_be_bytes());
-        png.extend_from_slice(b"IHDR");
-        png.extend_from_slice(&ihdr);
-        png.extend_from_slice(&Self::crc32(b"IHDR", &ihdr).to_be_bytes());
-
-        // IDAT chunk (minimal - just one pixel repeated conceptually)
-        // This is simplified; real PNG needs proper zlib compression
-        let mut idat = vec![0x08, 0x1D]; // zlib header
-        let pixel_row_size = 1 + width as usize * 4; // filter byte + RGBA
-        let raw_size = pixel_row_size * height as usize;
-
-        // Simplified: store uncompressed (this is not valid zlib but demonstrates structure)
-        idat.push(0x01); // final block, uncompressed
-        idat.extend_from_slice(&(raw_size as u16).to_le_bytes());
-        idat.extend_from_slice(&(!(raw_size as u16)).to_le_bytes());
-
-        for _ in 0..height {
-            idat.push(0); // filter: none
-            for _ in 0..width {
-                idat.extend_from_slice(&color);
-            }
-        }
-
-        // Adler32 checksum (simplified)
-        let adler = Self::adler32(&idat[2..]);
-        idat.extend_from_slice(&adler.to_be_bytes());
-
-        png.extend_from_slice(&(idat.len() as u32).to_be_bytes());
-        png.extend_from_slice(b"IDAT");
-        png.extend_from_slice(&idat);
-        png.extend_from_slice(&Self::crc32(b"IDAT", &idat).to_be_bytes());
-
-        // IEND chunk
-        png.extend_from_slice(&0u32.to_be_bytes());
-        png.extend_from_slice(b"IEND");
-        png.extend_from_slice(&Self::crc32(b"IEND", &[]).to_be_bytes());
-
+        let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        Self::write_chunk(&mut png, b"IHDR", &ihdr);
+        Self::write_chunk(&mut png, b"IDAT", &idat);
+        Self::write_chunk(&mut png, b"IEND", &[]);
         png
     }
 
+    /// Append a length-prefixed, CRC-terminated PNG chunk.
+    fn write_chunk(png: &mut Vec<u8>, chunk_type: &[u8], data: &[u8]) {
+        let len = u32::try_from(data.len()).expect("PNG chunk exceeds 4 GiB");
+        png.extend_from_slice(&len.to_be_bytes());
+        png.extend_from_slice(chunk_type);
+        png.extend_from_slice(data);
+        png.extend_from_slice(&Self::crc32(chunk_type, data).to_be_bytes());
+    }
+
     /// Compute CRC32 for PNG chunk.
     fn crc32(chunk_type: &[u8], data: &[u8]) -> u32 {
         const CRC_TABLE: [u32; 256] = {
@@ -899,6 +909,41 @@ mod tests {
         assert!(iend_pos.is_some());
     }
 
+    #[test]
+    fn test_encode_png_stored_blocks() {
+        let png = TestData::minimal_png(2, 1, [1, 2, 3, 4]);
+        let idat = png.windows(4).position(|w| w == b"IDAT").expect("IDAT") + 4;
+
+        // zlib header, then one final stored block holding both rows.
+        assert_eq!(&png[idat..idat + 2], &[0x78, 0x01]);
+        assert_eq!(png[idat + 2], 0x01);
+        assert_eq!(&png[idat + 3..idat + 7], &[9, 0, !9, 0xFF]);
+        assert_eq!(&png[idat + 7..idat + 16], &[0, 1, 2, 3, 4, 1, 2, 3, 4]);
+        let adler = TestData::adler32(&[0, 1, 2, 3, 4, 1, 2, 3, 4]);
+        assert_eq!(&png[idat + 16..idat + 20], &adler.to_be_bytes());
+    }
+
+    #[test]
+    fn test_encode_png_splits_large_images() {
+        // 200x100 RGBA rows exceed a single 64 KiB stored block.
+        let png = TestData::minimal_png(200, 100, [0, 0, 0, 255]);
+        let idat = png.windows(4).position(|w| w == b"IDAT").expect("IDAT") + 4;
+        assert_eq!(png[idat + 2], 0x00, "first block is not final");
+        assert_eq!(&png[idat + 3..idat + 5], &u16::MAX.to_le_bytes());
+    }
+
+    #[test]
+    fn test_png_chunk_crc() {
+        let png = TestData::minimal_png(1, 1, [0, 0, 0, 0]);
+        assert_eq!(&png[png.len() - 4..], &0xAE42_6082_u32.to_be_bytes());
+    }
+
+    #[test]
+    #[should_panic(expected = "pixel data does not match")]
+    fn test_encode_png_rejects_short_data() {
+        let _ = TestData::encode_png(2, 2, &[0; 4]);
+    }
+
     // =========================================================================
     // Tar Parsing Tests
     // =========================================================================
@@ -5,6 +5,8 @@
 )]
 //! Grade scoring system for quality evaluation.
 
+#[cfg(feature = "terminal")]
+use presentar_terminal::tools::BenchmarkResult;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -625,6 +627,50 @@ impl Default for PerformanceGates {
     }
 }
 
+#[cfg(feature = "terminal")]
+impl PerformanceGates {
+    /// Check measured benchmark output against these gates.
+    ///
+    /// Uses the mean frame time rather than an estimate from manifest
+    /// complexity, so the 16ms/60fps budget is asserted against real data.
+    /// Peak memory is only checked when the benchmark recorded it.
+    #[must_use]
+    pub fn check_benchmark(&self, result: &BenchmarkResult) -> GateCheckResult {
+        let mut violations = Vec::new();
+        let stats = &result.metrics.frame_times;
+
+        let mean_ms = stats.mean_us / 1000.0;
+        if mean_ms > f64::from(self.max_render_time_ms) {
+            violations.push(GateViolation {
+                gate: "max_render_time_ms".to_string(),
+                expected: format!("<= {}ms", self.max_render_time_ms),
+                actual: match result.metrics.frame_count {
+                    0 => format!("{mean_ms:.2}ms (mean)"),
+                    frames => format!("{mean_ms:.2}ms (mean of {frames} frames)"),
+                },
+                severity: ViolationSeverity::Error,
+            });
+        }
+
+        let peak_mb = result.metrics.memory.peak_bytes as f64 / (1024.0 * 1024.0);
+        if peak_mb > f64::from(self.max_memory_mb) {
+            violations.push(GateViolation {
+                gate: "max_memory_mb".to_string(),
+                expected: format!("<= {}MB", self.max_memory_mb),
+                actual: format!("{peak_mb:.1}MB"),
+                severity: ViolationSeverity::Warning,
+            });
+        }
+
+        GateCheckResult {
+            passed: violations
+                .iter()
+                .all(|v| v.severity != ViolationSeverity::Error),
+            violations,
+        }
+    }
+}
+
 impl Default for AccessibilityGates {
     fn default() -> Self {
         Self {
@@ -1911,4 +1957,56 @@ mod tests {
     fn test_quality_gates_config_file_constant() {
         assert_eq!(QualityGates::CONFIG_FILE, ".presentar-gates.toml");
     }
+
+    // =========================================================================
+    // PerformanceGates::check_benchmark Tests
+    // =========================================================================
+
+    #[cfg(feature = "terminal")]
+    fn benchmark_with_frames(frame_ms: u64, frames: usize) -> BenchmarkResult {
+        use presentar_terminal::tools::RenderMetrics;
+        use std::time::Duration;
+
+        let mut metrics = RenderMetrics::new();
+        for _ in 0..frames {
+            metrics.record_frame(Duration::from_millis(frame_ms));
+        }
+        BenchmarkResult {
+            widget_name: "test".to_string(),
+            metrics,
+            final_frame: String::new(),
+            width: 80,
+            height: 24,
+        }
+    }
+
+    #[cfg(feature = "terminal")]
+    #[test]
+    fn test_performance_gates_benchmark_over_budget_fails() {
+        let result = PerformanceGates::default().check_benchmark(&benchmark_with_frames(20, 10));
+        assert!(!result.passed);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].gate, "max_render_time_ms");
+        assert_eq!(result.violations[0].severity, ViolationSeverity::Error);
+        assert!(result.violations[0].actual.starts_with("20.00ms"));
+    }
+
+    #[cfg(feature = "terminal")]
+    #[test]
+    fn test_performance_gates_benchmark_within_budget_passes() {
+        let result = PerformanceGates::default().check_benchmark(&benchmark_with_frames(5, 10));
+        assert!(result.passed);
+        assert!(result.violations.is_empty());
+    }
+
+    #[cfg(feature = "terminal")]
+    #[test]
+    fn test_performance_gates_benchmark_memory_warning() {
+        let mut bench = benchmark_with_frames(5, 10);
+        bench.metrics.memory.peak_bytes = 200 * 1024 * 1024;
+        let result = PerformanceGates::default().check_benchmark(&bench);
+        assert!(result.passed);
+        assert_eq!(result.violations[0].gate, "max_memory_mb");
+        assert_eq!(result.violations[0].severity, ViolationSeverity::Warning);
+    }
 }
@@ -1,8 +1,13 @@
 //! Test harness for Presentar applications.
 //!
-//! Zero external dependencies - pure Rust testing.
+//! Zero external dependencies - pure Rust testing. Rendering to a terminal
+//! frame and the rendered-output assertions need the `terminal` feature.
 
+#[cfg(feature = "terminal")]
+use presentar_core::Color;
 use presentar_core::{Event, Key, MouseButton, Rect, Widget};
+#[cfg(feature = "terminal")]
+use presentar_terminal::tools::HeadlessCanvas;
 use std::collections::VecDeque;
 
 use crate::selector::Selector;
@@ -15,6 +20,12 @@ pub struct Harness {
     event_queue: VecDeque<Event>,
     /// Current viewport size
     viewport: Rect,
+    /// Terminal frame size in cells used by `render`
+    #[cfg(feature = "terminal")]
+    terminal_size: (u16, u16),
+    /// Last rendered frame (terminal cells), if any
+    #[cfg(feature = "terminal")]
+    frame: Option<HeadlessCanvas>,
 }
 
 impl Harness {
@@ -24,6 +35,10 @@ impl Harness {
             root: Box::new(root),
             event_queue: VecDeque::new(),
             viewport: Rect::new(0.0, 0.0, 1280.0, 720.0),
+            #[cfg(feature = "terminal")]
+            terminal_size: (80, 24),
+            #[cfg(feature = "terminal")]
+            frame: None,
         }
     }
 
@@ -34,6 +49,14 @@ impl Harness {
         self
     }
 
+    /// Set the terminal frame size in cells used by `render` (default 80x24).
+    #[cfg(feature = "terminal")]
+    #[must_use]
+    pub const fn terminal_size(mut self, width: u16, height: u16) -> Self {
+        self.terminal_size = (width, height);
+        self
+    }
+
     // === Event Simulation ===
 
     /// Simulate a click on a widget matching the selector.
@@ -131,6 +154,27 @@ impl Harness {
         String::new()
     }
 
+    /// Lay out the root widget in a terminal frame and paint it.
+    ///
+    /// The frame is `terminal_size` cells (80x24 unless set otherwise).
+    #[cfg(feature = "terminal")]
+    pub fn render(&mut self) -> &mut Self {
+        let (width, height) = self.terminal_size;
+        let mut canvas = HeadlessCanvas::new(width, height);
+        self.root
+            .layout(Rect::new(0.0, 0.0, f32::from(width), f32::from(height)));
+        self.root.paint(&mut canvas);
+        self.frame = Some(canvas);
+        self
+    }
+
+    /// Get the last rendered frame.
+    #[cfg(feature = "terminal")]
+    #[must_use]
+    pub const fn frame(&self) -> Option<&HeadlessCanvas> {
+        self.frame.as_ref()
+    }
+
     /// Check if a widget exists.
     #[must_use]
     pub fn exists(&self, selector: &str) -> bool {
@@ -193,6 +237,45 @@ impl Harness {
         self
     }
 
+    /// Assert that the rendered frame contains a substring on some row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no frame has been rendered or the text is not found.
+    #[cfg(feature = "terminal")]
+    pub fn assert_rendered_contains(&self, substring: &str) -> &Self {
+        let text = self.rendered_frame().to_plain_string();
+        assert!(
+            text.lines().any(|line| line.contains(substring)),
+            "Expected rendered frame to contain '{substring}' but got:\n{text}"
+        );
+        self
+    }
+
+    /// Assert the foreground color of a rendered cell.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no frame has been rendered, the cell is outside the frame,
+    /// or the color does not match.
+    #[cfg(feature = "terminal")]
+    pub fn assert_cell_color(&self, x: u16, y: u16, expected: Color) -> &Self {
+        let frame = self.rendered_frame();
+        let Some(cell) = frame.buffer().get(x, y) else {
+            panic!(
+                "Cell ({x}, {y}) is outside the rendered {}x{} frame",
+                frame.width(),
+                frame.height()
+            );
+        };
+        assert_eq!(
+            cell.fg, expected,
+            "Expected cell ({x}, {y}) '{}' to have color {expected:?} but got {:?}",
+            cell.symbol, cell.fg
+        );
+        self
+    }
+
     /// Assert the count of matching widgets.
     ///
     /// # Panics
@@ -209,6 +292,13 @@ impl Harness {
 
     // === Internal ===
 
+    #[cfg(feature = "terminal")]
+    fn rendered_frame(&self) -> &HeadlessCanvas {
+        self.frame
+            .as_ref()
+            .expect("No rendered frame; call render() before asserting on output")
+    }
+
     fn process_events(&mut self) {
         while let Some(event) = self.event_queue.pop_front() {
             self.root.event(&event);
@@ -271,8 +361,8 @@ impl Harness {
 mod tests {
     use super::*;
     use presentar_core::{
-        widget::LayoutResult, Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas,
-        Constraints, Size, TypeId,
+        widget::LayoutResult, Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color,
+        Constraints, Point, Size, TextStyle, TypeId,
     };
     use std::any::Any;
     use std::time::Duration;
@@ -281,6 +371,7 @@ mod tests {
     struct MockWidget {
         test_id: Option<String>,
         accessible_name: Option<String>,
+        label: Option<(String, Color)>,
         children: Vec<Box<dyn Widget>>,
     }
 
@@ -289,6 +380,7 @@ mod tests {
             Self {
                 test_id: None,
                 accessible_name: None,
+                label: None,
                 children: Vec::new(),
             }
         }
@@ -303,6 +395,12 @@ mod tests {
             self
         }
 
+        #[cfg(feature = "terminal")]
+        fn with_label(mut self, text: &str, color: Color) -> Self {
+            self.label = Some((text.to_string(), color));
+            self
+        }
+
         fn with_child(mut self, child: MockWidget) -> Self {
             self.children.push(Box::new(child));
             self
@@ -349,7 +447,15 @@ mod tests {
         fn layout(&mut self, b: Rect) -> LayoutResult {
             LayoutResult { size: b.size() }
         }
-        fn paint(&self, _: &mut dyn Canvas) {}
+        fn paint(&self, canvas: &mut dyn Canvas) {
+            if let Some((text, color)) = &self.label {
+                let style = TextStyle {
+                    color: *color,
+                    ..TextStyle::default()
+                };
+                canvas.draw_text(text, Point::new(1.0, 1.0), &style);
+            }
+        }
         fn event(&mut self, _: &Event) -> Option<Box<dyn Any + Send>> {
             None
         }
@@ -699,4 +805,73 @@ mod tests {
         let harness = Harness::new(widget);
         harness.assert_count("[data-testid='item']", 5);
     }
+
+    #[cfg(feature = "terminal")]
+    mod rendered {
+        use super::*;
+
+        // =========================================================================
+        // Rendered Output Assertions
+        // =========================================================================
+
+        fn rendered_label() -> Harness {
+            let widget = MockWidget::new().with_label("CPU 42%", Color::GREEN);
+            let mut harness = Harness::new(widget).terminal_size(20, 4);
+            harness.render();
+            harness
+        }
+
+        #[test]
+        fn test_harness_render_default_frame_size() {
+            let mut harness = Harness::new(MockWidget::new());
+            harness.render();
+            let frame = harness.frame().expect("frame rendered");
+            assert_eq!((frame.width(), frame.height()), (80, 24));
+        }
+
+        #[test]
+        fn test_harness_render_frame_size() {
+            let harness = rendered_label();
+            let frame = harness.frame().expect("frame rendered");
+            assert_eq!((frame.width(), frame.height()), (20, 4));
+        }
+
+        #[test]
+        fn test_harness_assert_rendered_contains() {
+            rendered_label()
+                .assert_rendered_contains("CPU 42%")
+                .assert_rendered_contains("42");
+        }
+
+        #[test]
+        #[should_panic(expected = "to contain 'MEM'")]
+        fn test_harness_assert_rendered_contains_fails() {
+            rendered_label().assert_rendered_contains("MEM");
+        }
+
+        #[test]
+        #[should_panic(expected = "call render()")]
+        fn test_harness_assert_rendered_contains_without_render() {
+            Harness::new(MockWidget::new()).assert_rendered_contains("CPU");
+        }
+
+        #[test]
+        fn test_harness_assert_cell_color() {
+            rendered_label()
+                .assert_cell_color(1, 1, Color::GREEN)
+                .assert_cell_color(7, 1, Color::GREEN);
+        }
+
+        #[test]
+        #[should_panic(expected = "to have color")]
+        fn test_harness_assert_cell_color_mismatch() {
+            rendered_label().assert_cell_color(1, 1, Color::RED);
+        }
+
+        #[test]
+        #[should_panic(expected = "Cell (20, 1) is outside the rendered 20x4 frame")]
+        fn test_harness_assert_cell_color_out_of_bounds() {
+            rendered_label().assert_cell_color(20, 1, Color::GREEN);
+        }
+    }
 }
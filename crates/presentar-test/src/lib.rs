@@ -64,7 +64,11 @@
 #![allow(clippy::duplicated_attributes)]
 //! Testing harness for Presentar applications.
 //!
-//! Zero external dependencies. Pure Rust + WASM only.
+//! Zero external dependencies by default. Pure Rust + WASM only.
+//!
+//! The opt-in `terminal` feature adds helpers for terminal frames rendered
+//! with `presentar-terminal`: text snapshots, rendered-output assertions on
+//! [`Harness`], cell contrast checks and benchmark performance gates.
 //!
 //! # Proc Macros
 //!
@@ -116,7 +120,7 @@ pub use grade::{
 };
 pub use harness::Harness;
 pub use selector::{Selector, SelectorParser};
-pub use snapshot::{ComparisonResult, Image, Snapshot};
+pub use snapshot::{ComparisonResult, Image, Snapshot, TextSnapshot};
 
 // TUI Testing Framework (SPEC-024 Section 12 & 13)
 // Tests DEFINE interface - implementation follows
@@ -4,7 +4,10 @@
 
 use presentar_core::draw::DrawCommand;
 use presentar_core::Color;
+#[cfg(feature = "terminal")]
+use presentar_terminal::tools::HeadlessCanvas;
 use std::collections::hash_map::DefaultHasher;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 
@@ -676,6 +679,85 @@ impl Snapshot {
         }
     }
 
+    /// Capture a terminal frame as deterministic plain text.
+    ///
+    /// Rows are newline-terminated with trailing whitespace trimmed, so the
+    /// golden file reviews cleanly in a PR diff.
+    #[cfg(feature = "terminal")]
+    #[must_use]
+    pub fn capture_text(canvas: &HeadlessCanvas) -> TextSnapshot {
+        let mut text = String::new();
+        for line in canvas.to_plain_string().lines() {
+            text.push_str(line.trim_end());
+            text.push('\n');
+        }
+        TextSnapshot::new(text)
+    }
+
+    /// Line-level unified diff between two texts (three lines of context).
+    ///
+    /// Returns an empty string if the texts are identical.
+    #[must_use]
+    pub fn text_diff(expected: &str, actual: &str) -> String {
+        const CONTEXT: usize = 3;
+
+        // Keep line terminators so a missing final newline shows up as a change
+        let old: Vec<&str> = expected.split_inclusive('\n').collect();
+        let new: Vec<&str> = actual.split_inclusive('\n').collect();
+        let ops = diff_lines(&old, &new);
+        if ops.iter().all(|op| matches!(op, LineOp::Equal(..))) {
+            return String::new();
+        }
+
+        let mut out = String::from("--- expected\n+++ actual\n");
+        let mut i = 0;
+        while i < ops.len() {
+            if matches!(ops[i], LineOp::Equal(..)) {
+                i += 1;
+                continue;
+            }
+
+            // Merge changes separated by at most 2*CONTEXT equal lines into one hunk.
+            let start = i.saturating_sub(CONTEXT);
+            let mut last_change = i;
+            let mut j = i + 1;
+            while j < ops.len() && j - last_change <= 2 * CONTEXT + 1 {
+                if !matches!(ops[j], LineOp::Equal(..)) {
+                    last_change = j;
+                }
+                j += 1;
+            }
+            let end = (last_change + 1 + CONTEXT).min(ops.len());
+
+            let (old_start, new_start) = ops[start].positions();
+            let hunk = &ops[start..end];
+            let old_len = hunk
+                .iter()
+                .filter(|op| !matches!(op, LineOp::Insert(..)))
+                .count();
+            let new_len = hunk
+                .iter()
+                .filter(|op| !matches!(op, LineOp::Delete(..)))
+                .count();
+            out.push_str(&format!(
+                "@@ -{},{} +{},{} @@\n",
+                old_start + 1,
+                old_len,
+                new_start + 1,
+                new_len
+            ));
+            for op in hunk {
+                match *op {
+                    LineOp::Equal(o, _) => push_diff_line(&mut out, ' ', old[o]),
+                    LineOp::Delete(o, _) => push_diff_line(&mut out, '-', old[o]),
+                    LineOp::Insert(_, n) => push_diff_line(&mut out, '+', new[n]),
+                }
+            }
+            i = end;
+        }
+        out
+    }
+
     fn baseline_path(name: &str) -> PathBuf {
         PathBuf::from(format!("tests/snapshots/{name}.png"))
     }
@@ -709,6 +791,136 @@ impl Snapshot {
     }
 }
 
+/// Text captured for golden-file comparison.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextSnapshot {
+    text: String,
+}
+
+impl TextSnapshot {
+    /// Wrap already-rendered text.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+
+    /// Get the captured text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Compare against `tests/snapshots/<name>.txt`.
+    ///
+    /// Run with `UPDATE_SNAPSHOTS=1` to create or regenerate the golden file.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a unified diff if the text differs from the golden file,
+    /// or if no golden file exists and updating is not enabled.
+    pub fn assert_text_match(&self, name: &str) {
+        let update = std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|v| v == "1");
+        self.assert_text_match_in(Path::new("tests/snapshots"), name, update);
+    }
+
+    fn assert_text_match_in(&self, dir: &Path, name: &str, update: bool) {
+        let path = dir.join(format!("{name}.txt"));
+
+        if update {
+            if let Err(e) =
+                std::fs::create_dir_all(dir).and_then(|()| std::fs::write(&path, &self.text))
+            {
+                panic!("Failed to write snapshot {}: {e}", path.display());
+            }
+            println!("Updated text snapshot: {}", path.display());
+            return;
+        }
+
+        let Ok(expected) = std::fs::read_to_string(&path) else {
+            panic!(
+                "No text snapshot found for '{}'. Run with UPDATE_SNAPSHOTS=1 to create.\n\
+                 Expected path: {}",
+                name,
+                path.display()
+            );
+        };
+
+        assert!(
+            expected == self.text,
+            "Text snapshot '{}' does not match ({}).\n{}",
+            name,
+            path.display(),
+            Snapshot::text_diff(&expected, &self.text)
+        );
+    }
+}
+
+impl fmt::Display for TextSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Append one unified-diff line, marking a missing final newline as `diff` does.
+fn push_diff_line(out: &mut String, prefix: char, line: &str) {
+    out.push(prefix);
+    if let Some(body) = line.strip_suffix('\n') {
+        out.push_str(body);
+        out.push('\n');
+    } else {
+        out.push_str(line);
+        out.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+/// One step of a line diff, carrying the (old, new) line positions.
+#[derive(Debug, Clone, Copy)]
+enum LineOp {
+    Equal(usize, usize),
+    Delete(usize, usize),
+    Insert(usize, usize),
+}
+
+impl LineOp {
+    const fn positions(self) -> (usize, usize) {
+        match self {
+            Self::Equal(o, n) | Self::Delete(o, n) | Self::Insert(o, n) => (o, n),
+        }
+    }
+}
+
+/// Longest-common-subsequence line diff.
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<LineOp> {
+    // lcs[i][j] = LCS length of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push(LineOp::Equal(i, j));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push(LineOp::Delete(i, j));
+            i += 1;
+        } else {
+            ops.push(LineOp::Insert(i, j));
+            j += 1;
+        }
+    }
+    ops
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1126,4 +1338,92 @@ mod tests {
         assert!(!result.is_match(0.0));
         assert!(result.byte_diff > 0.0);
     }
+
+    #[cfg(feature = "terminal")]
+    fn status_canvas(cpu: &str) -> HeadlessCanvas {
+        use presentar_core::{Canvas, Point, TextStyle};
+
+        let mut canvas = HeadlessCanvas::new(12, 3);
+        canvas.draw_text(cpu, Point::new(0.0, 0.0), &TextStyle::default());
+        canvas.draw_text("MEM 13%", Point::new(0.0, 1.0), &TextStyle::default());
+        canvas
+    }
+
+    /// Temporary snapshot directory, removed on drop (even when a test panics).
+    struct ScratchDir(PathBuf);
+
+    impl ScratchDir {
+        fn new(test: &str) -> Self {
+            Self(
+                std::env::temp_dir()
+                    .join(format!("presentar-snapshot-{test}-{}", std::process::id())),
+            )
+        }
+    }
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[cfg(feature = "terminal")]
+    #[test]
+    fn test_snapshot_capture_text() {
+        let text = Snapshot::capture_text(&status_canvas("CPU 42%"));
+        assert_eq!(text.as_str(), "CPU 42%\nMEM 13%\n\n");
+    }
+
+    #[cfg(feature = "terminal")]
+    #[test]
+    fn test_snapshot_text_roundtrip() {
+        let dir = ScratchDir::new("roundtrip");
+        let text = Snapshot::capture_text(&status_canvas("CPU 42%"));
+
+        text.assert_text_match_in(&dir.0, "status", true);
+        assert!(dir.0.join("status.txt").exists());
+
+        let again = Snapshot::capture_text(&status_canvas("CPU 42%"));
+        again.assert_text_match_in(&dir.0, "status", false);
+    }
+
+    #[test]
+    #[should_panic(expected = "-CPU 42%")]
+    fn test_snapshot_text_mismatch_shows_diff() {
+        let dir = ScratchDir::new("mismatch");
+        TextSnapshot::new("CPU 42%\nMEM 13%\n").assert_text_match_in(&dir.0, "status", true);
+        TextSnapshot::new("CPU 99%\nMEM 13%\n").assert_text_match_in(&dir.0, "status", false);
+    }
+
+    #[test]
+    #[should_panic(expected = "UPDATE_SNAPSHOTS=1")]
+    fn test_snapshot_text_missing_golden() {
+        let dir = ScratchDir::new("missing");
+        TextSnapshot::new("x\n").assert_text_match_in(&dir.0, "absent", false);
+    }
+
+    #[test]
+    fn test_snapshot_text_diff_trailing_newline() {
+        let diff = Snapshot::text_diff("a\nb\n", "a\nb");
+        assert_eq!(
+            diff,
+            "--- expected\n+++ actual\n@@ -1,2 +1,2 @@\n a\n-b\n+b\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn test_snapshot_text_diff_unified() {
+        let expected = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let actual = "a\nb\nc\nd\nE\nf\ng\nh\ni\nj\n";
+        let diff = Snapshot::text_diff(expected, actual);
+        assert_eq!(
+            diff,
+            "--- expected\n+++ actual\n@@ -2,7 +2,7 @@\n b\n c\n d\n-e\n+E\n f\n g\n h\n"
+        );
+    }
+
+    #[test]
+    fn test_snapshot_text_diff_identical() {
+        assert!(Snapshot::text_diff("same\n", "same\n").is_empty());
+    }
 }
@@ -1,12 +1,14 @@
 //! `DataTable` widget for displaying tabular data.
 
 use presentar_core::{
+    delimited::push_record,
     widget::{AccessibleRole, LayoutResult, TextStyle},
-    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event, Rect,
-    Size, TypeId, Widget,
+    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
+    FuzzyIndex, Rect, Size, TypeId, Widget,
 };
 use serde::{Deserialize, Serialize};
 use std::any::Any;
+use std::cmp::Ordering;
 use std::time::Duration;
 
 /// Column definition for a data table.
@@ -115,6 +117,65 @@ impl CellValue {
             Self::Empty => String::new(),
         }
     }
+
+    /// Check whether the cell has no content (empty or whitespace-only text).
+    #[must_use]
+    pub fn is_blank(&self) -> bool {
+        match self {
+            Self::Empty => true,
+            Self::Text(s) => s.trim().is_empty(),
+            Self::Number(_) | Self::Bool(_) => false,
+        }
+    }
+
+    /// Get the numeric value, parsing text cells.
+    #[must_use]
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Text(s) => s.trim().parse().ok(),
+            Self::Bool(_) | Self::Empty => None,
+        }
+    }
+}
+
+/// Check whether every non-blank cell in a column is numeric.
+fn is_numeric_column<'a>(cells: impl IntoIterator<Item = Option<&'a CellValue>>) -> bool {
+    cells
+        .into_iter()
+        .flatten()
+        .filter(|cell| !cell.is_blank())
+        .all(|cell| cell.as_number().is_some())
+}
+
+/// Compare two cells of a column for sorting.
+///
+/// Numeric columns compare by value, other columns compare display text
+/// case-insensitively. Missing and blank cells sort last in either direction.
+fn compare_cells(
+    a: Option<&CellValue>,
+    b: Option<&CellValue>,
+    numeric: bool,
+    direction: SortDirection,
+) -> Ordering {
+    let a = a.filter(|cell| !cell.is_blank());
+    let b = b.filter(|cell| !cell.is_blank());
+    let (a, b) = match (a, b) {
+        (None, None) => return Ordering::Equal,
+        (None, Some(_)) => return Ordering::Greater,
+        (Some(_), None) => return Ordering::Less,
+        (Some(a), Some(b)) => (a, b),
+    };
+
+    let ordering = match (numeric, a.as_number(), b.as_number()) {
+        (true, Some(x), Some(y)) => x.total_cmp(&y),
+        _ => a.display().to_lowercase().cmp(&b.display().to_lowercase()),
+    };
+
+    match direction {
+        SortDirection::Ascending => ordering,
+        SortDirection::Descending => ordering.reverse(),
+    }
 }
 
 impl From<&str> for CellValue {
@@ -175,6 +236,15 @@ impl TableRow {
     }
 }
 
+/// Parts of a [`DataTable`] pinned while the rest scrolls.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct FrozenPanes {
+    /// Keep the header row pinned
+    header: bool,
+    /// Number of leading columns pinned
+    columns: usize,
+}
+
 /// `DataTable` widget for displaying tabular data.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataTable {
@@ -198,6 +268,21 @@ pub struct DataTable {
     striped: bool,
     /// Show borders
     bordered: bool,
+    /// Header row and leading columns pinned while scrolling
+    #[serde(default)]
+    frozen: FrozenPanes,
+    /// Horizontal scroll offset
+    #[serde(skip)]
+    scroll_x: f32,
+    /// Vertical scroll offset
+    #[serde(skip)]
+    scroll_y: f32,
+    /// Active fuzzy filter query
+    #[serde(skip)]
+    filter: String,
+    /// Filter match score per row; empty when no filter is active
+    #[serde(skip)]
+    match_scores: Vec<Option<f32>>,
     /// Header background color
     header_bg: Color,
     /// Row background color
@@ -234,6 +319,11 @@ impl Default for DataTable {
             selectable: false,
             striped: true,
             bordered: true,
+            frozen: FrozenPanes::default(),
+            scroll_x: 0.0,
+            scroll_y: 0.0,
+            filter: String::new(),
+            match_scores: Vec::new(),
             header_bg: Color::new(0.95, 0.95, 0.95, 1.0),
             row_bg: Color::WHITE,
             row_alt_bg: Color::new(0.98, 0.98, 0.98, 1.0),
@@ -273,6 +363,7 @@ impl DataTable {
     #[must_use]
     pub fn row(mut self, row: TableRow) -> Self {
         self.rows.push(row);
+        self.apply_filter();
         self
     }
 
@@ -280,6 +371,7 @@ impl DataTable {
     #[must_use]
     pub fn rows(mut self, rows: impl IntoIterator<Item = TableRow>) -> Self {
         self.rows.extend(rows);
+        self.apply_filter();
         self
     }
 
@@ -318,6 +410,20 @@ impl DataTable {
         self
     }
 
+    /// Keep the header row visible while scrolling vertically.
+    #[must_use]
+    pub const fn freeze_header(mut self, frozen: bool) -> Self {
+        self.frozen.header = frozen;
+        self
+    }
+
+    /// Keep the first `n` columns visible while scrolling horizontally.
+    #[must_use]
+    pub const fn freeze_columns(mut self, n: usize) -> Self {
+        self.frozen.columns = n;
+        self
+    }
+
     /// Set header background color.
     #[must_use]
     pub const fn header_bg(mut self, color: Color) -> Self {
@@ -409,6 +515,20 @@ impl DataTable {
         self.sort_direction
     }
 
+    /// Get the scroll offset as `(x, y)`.
+    #[must_use]
+    pub const fn get_scroll_offset(&self) -> (f32, f32) {
+        (self.scroll_x, self.scroll_y)
+    }
+
+    /// Scroll to an offset, clamped to the content size.
+    pub fn scroll_to(&mut self, x: f32, y: f32) {
+        let max_x = (self.calculate_width() - self.bounds.width).max(0.0);
+        let max_y = (self.calculate_height() - self.bounds.height).max(0.0);
+        self.scroll_x = x.clamp(0.0, max_x);
+        self.scroll_y = y.clamp(0.0, max_y);
+    }
+
     /// Check if table is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -427,17 +547,142 @@ impl DataTable {
     }
 
     /// Set sort column and direction.
+    ///
+    /// Rows keep their stored order and indices; sorting only changes the
+    /// order they are displayed in (see [`DataTable::display_order`]).
     pub fn set_sort(&mut self, column: impl Into<String>, direction: SortDirection) {
         self.sort_column = Some(column.into());
         self.sort_direction = direction;
     }
 
+    /// Get the indices of the visible rows, in display order.
+    ///
+    /// Rows hidden by the filter are left out and the rest are stable-sorted
+    /// by the current sort column.
+    #[must_use]
+    pub fn display_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.rows.len())
+            .filter(|&index| self.is_visible(index))
+            .collect();
+        if let Some(key) = self.sort_column.as_deref() {
+            let numeric = is_numeric_column(self.rows.iter().map(|row| row.get(key)));
+            order.sort_by(|&a, &b| {
+                compare_cells(
+                    self.rows[a].get(key),
+                    self.rows[b].get(key),
+                    numeric,
+                    self.sort_direction,
+                )
+            });
+        }
+        order
+    }
+
+    /// Check whether the row at `index` passes the filter.
+    fn is_visible(&self, index: usize) -> bool {
+        self.filter.is_empty() || self.match_score(index).is_some()
+    }
+
+    /// Filter rows by fuzzy-matching `query` against their cell text.
+    ///
+    /// Rows are matched and scored by [`FuzzyIndex`] over their displayed
+    /// cells, ignoring case, as in the terminal `ProcessTable`. Hidden rows
+    /// keep their index and the current sort still applies; an empty query
+    /// shows all rows. A selected row that gets hidden is deselected.
+    pub fn set_filter(&mut self, query: &str) {
+        self.filter = query.trim().to_string();
+        self.apply_filter();
+        if self
+            .selected_row
+            .is_some_and(|index| !self.is_visible(index))
+        {
+            self.selected_row = None;
+        }
+        self.scroll_to(self.scroll_x, self.scroll_y);
+    }
+
+    /// Recompute the per-row match scores for the active filter.
+    fn apply_filter(&mut self) {
+        self.match_scores.clear();
+        if self.filter.is_empty() {
+            return;
+        }
+
+        let index = FuzzyIndex::new((0..self.rows.len()).collect(), |&i: &usize| {
+            self.row_text(&self.rows[i])
+        });
+        self.match_scores = vec![None; self.rows.len()];
+        for result in index.search(&self.filter, self.rows.len()) {
+            self.match_scores[result.item] = Some(result.score);
+        }
+    }
+
+    /// Get the active filter query.
+    #[must_use]
+    pub fn get_filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Get the filter match score (0.0 - 1.0) for the row at `index`.
+    ///
+    /// Higher scores mean tighter matches. Returns `None` when no filter is
+    /// active or the row does not match.
+    #[must_use]
+    pub fn match_score(&self, index: usize) -> Option<f32> {
+        self.match_scores.get(index).copied().flatten()
+    }
+
+    /// Get a row's displayed cell text, lowercased, in column order.
+    fn row_text(&self, row: &TableRow) -> String {
+        self.columns
+            .iter()
+            .filter_map(|col| row.get(&col.key))
+            .map(|cell| cell.display().to_lowercase())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Clear data.
     pub fn clear(&mut self) {
         self.rows.clear();
+        self.match_scores.clear();
         self.selected_row = None;
     }
 
+    /// Export rows as RFC 4180 CSV.
+    ///
+    /// The header row uses column headers; columns and the visible rows
+    /// follow display order.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        self.to_delimited(',')
+    }
+
+    /// Export rows as tab-separated values.
+    ///
+    /// Fields containing tabs, quotes, or newlines are quoted as in CSV.
+    #[must_use]
+    pub fn to_tsv(&self) -> String {
+        self.to_delimited('\t')
+    }
+
+    /// Export rows with the given field delimiter.
+    fn to_delimited(&self, delimiter: char) -> String {
+        let mut out = String::new();
+        let header: Vec<String> = self.columns.iter().map(|c| c.header.clone()).collect();
+        push_record(&mut out, &header, delimiter);
+
+        for row in self.display_order().into_iter().map(|i| &self.rows[i]) {
+            let fields: Vec<String> = self
+                .columns
+                .iter()
+                .map(|c| row.get(&c.key).map(CellValue::display).unwrap_or_default())
+                .collect();
+            push_record(&mut out, &fields, delimiter);
+        }
+        out
+    }
+
     /// Calculate total width.
     fn calculate_width(&self) -> f32 {
         let mut total = 0.0;
@@ -449,94 +694,163 @@ impl DataTable {
 
     /// Calculate total height.
     fn calculate_height(&self) -> f32 {
-        (self.rows.len() as f32).mul_add(self.row_height, self.header_height)
+        let visible = (0..self.rows.len())
+            .filter(|&index| self.is_visible(index))
+            .count();
+        (visible as f32).mul_add(self.row_height, self.header_height)
     }
 
     /// Get row Y position.
     fn row_y(&self, index: usize) -> f32 {
         (index as f32).mul_add(self.row_height, self.bounds.y + self.header_height)
     }
-}
-
-impl Widget for DataTable {
-    fn type_id(&self) -> TypeId {
-        TypeId::of::<Self>()
-    }
-
-    fn measure(&self, constraints: Constraints) -> Size {
-        let preferred = Size::new(self.calculate_width(), self.calculate_height());
-        constraints.constrain(preferred)
-    }
 
-    fn layout(&mut self, bounds: Rect) -> LayoutResult {
-        self.bounds = bounds;
-        LayoutResult {
-            size: bounds.size(),
-        }
+    /// Get the frozen columns.
+    fn frozen(&self) -> &[TableColumn] {
+        &self.columns[..self.frozen.columns.min(self.columns.len())]
     }
 
-    fn paint(&self, canvas: &mut dyn Canvas) {
-        // Draw header row
-        let header_rect = Rect::new(
-            self.bounds.x,
-            self.bounds.y,
-            self.bounds.width,
-            self.header_height,
-        );
-        canvas.fill_rect(header_rect, self.header_bg);
+    /// Paint header cells for `columns` into `rect`, starting at `x`.
+    fn paint_header(&self, canvas: &mut dyn Canvas, columns: &[TableColumn], rect: Rect, x: f32) {
+        canvas.fill_rect(rect, self.header_bg);
 
-        // Draw header text
-        let mut x = self.bounds.x;
-        for col in &self.columns {
-            let col_width = col.width.unwrap_or(100.0);
-            let text_style = TextStyle {
-                size: 14.0,
-                color: self.header_text_color,
-                weight: presentar_core::widget::FontWeight::Bold,
-                ..TextStyle::default()
-            };
+        let text_style = TextStyle {
+            size: 14.0,
+            color: self.header_text_color,
+            weight: presentar_core::widget::FontWeight::Bold,
+            ..TextStyle::default()
+        };
+        let mut x = x;
+        for col in columns {
             canvas.draw_text(
                 &col.header,
-                presentar_core::Point::new(x + 8.0, self.bounds.y + self.header_height / 2.0),
+                presentar_core::Point::new(x + 8.0, rect.y + self.header_height / 2.0),
                 &text_style,
             );
-            x += col_width;
+            x += col.width.unwrap_or(100.0);
         }
+    }
 
-        // Draw data rows
-        for (row_idx, row) in self.rows.iter().enumerate() {
-            let row_y = self.row_y(row_idx);
+    /// Paint the rows in `order` for `columns` in a band of the given width,
+    /// starting at `x`.
+    ///
+    /// Rows are shifted up by the vertical scroll offset; rows outside the
+    /// bounds are skipped.
+    fn paint_rows(
+        &self,
+        canvas: &mut dyn Canvas,
+        order: &[usize],
+        columns: &[TableColumn],
+        band_width: f32,
+        x: f32,
+    ) {
+        let text_style = TextStyle {
+            size: 14.0,
+            color: self.text_color,
+            ..TextStyle::default()
+        };
+
+        for (position, &row_idx) in order.iter().enumerate() {
+            let row = &self.rows[row_idx];
+            let row_y = self.row_y(position) - self.scroll_y;
+            if row_y + self.row_height <= self.bounds.y
+                || row_y >= self.bounds.y + self.bounds.height
+            {
+                continue;
+            }
 
             // Determine background color
             let bg_color = if self.selected_row == Some(row_idx) {
                 self.selected_bg
-            } else if self.striped && row_idx % 2 == 1 {
+            } else if self.striped && position % 2 == 1 {
                 self.row_alt_bg
             } else {
                 self.row_bg
             };
 
-            let row_rect = Rect::new(self.bounds.x, row_y, self.bounds.width, self.row_height);
+            let row_rect = Rect::new(self.bounds.x, row_y, band_width, self.row_height);
             canvas.fill_rect(row_rect, bg_color);
 
             // Draw cell values
-            let mut x = self.bounds.x;
-            for col in &self.columns {
-                let col_width = col.width.unwrap_or(100.0);
+            let mut cell_x = x;
+            for col in columns {
                 if let Some(cell) = row.get(&col.key) {
-                    let text_style = TextStyle {
-                        size: 14.0,
-                        color: self.text_color,
-                        ..TextStyle::default()
-                    };
                     canvas.draw_text(
                         &cell.display(),
-                        presentar_core::Point::new(x + 8.0, row_y + self.row_height / 2.0),
+                        presentar_core::Point::new(cell_x + 8.0, row_y + self.row_height / 2.0),
                         &text_style,
                     );
                 }
-                x += col_width;
+                cell_x += col.width.unwrap_or(100.0);
+            }
+        }
+    }
+}
+
+impl Widget for DataTable {
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<Self>()
+    }
+
+    fn measure(&self, constraints: Constraints) -> Size {
+        let preferred = Size::new(self.calculate_width(), self.calculate_height());
+        constraints.constrain(preferred)
+    }
+
+    fn layout(&mut self, bounds: Rect) -> LayoutResult {
+        self.bounds = bounds;
+        LayoutResult {
+            size: bounds.size(),
+        }
+    }
+
+    fn paint(&self, canvas: &mut dyn Canvas) {
+        let bounds = self.bounds;
+        let header_y = if self.frozen.header {
+            bounds.y
+        } else {
+            bounds.y - self.scroll_y
+        };
+        let scrolled_x = bounds.x - self.scroll_x;
+        // Frozen columns wider than the viewport are clipped to it
+        let frozen_width = self
+            .frozen()
+            .iter()
+            .map(|col| col.width.unwrap_or(100.0))
+            .sum::<f32>()
+            .min(bounds.width);
+
+        let order = self.display_order();
+        canvas.push_clip(bounds);
+
+        // Scrolling content
+        let header_rect = Rect::new(bounds.x, header_y, bounds.width, self.header_height);
+        if !self.frozen.header {
+            self.paint_header(canvas, &self.columns, header_rect, scrolled_x);
+        }
+        self.paint_rows(canvas, &order, &self.columns, bounds.width, scrolled_x);
+
+        // Frozen bands are drawn last so they overlay the scrolled cells
+        if frozen_width > 0.0 {
+            canvas.push_clip(Rect::new(bounds.x, bounds.y, frozen_width, bounds.height));
+            self.paint_rows(canvas, &order, self.frozen(), frozen_width, bounds.x);
+            if !self.frozen.header {
+                let band = Rect::new(bounds.x, header_y, frozen_width, self.header_height);
+                self.paint_header(canvas, self.frozen(), band, bounds.x);
+            }
+            canvas.pop_clip();
+        }
+
+        if self.frozen.header {
+            canvas.push_clip(header_rect);
+            self.paint_header(canvas, &self.columns, header_rect, scrolled_x);
+            if frozen_width > 0.0 {
+                let corner = Rect::new(bounds.x, bounds.y, frozen_width, self.header_height);
+                canvas.push_clip(corner);
+                self.paint_header(canvas, self.frozen(), corner, bounds.x);
+                canvas.pop_clip();
             }
+            canvas.pop_clip();
         }
 
         // Draw borders
@@ -549,10 +863,18 @@ impl Widget for DataTable {
             );
             canvas.stroke_rect(border_rect, self.border_color, 1.0);
         }
+
+        canvas.pop_clip();
     }
 
-    fn event(&mut self, _event: &Event) -> Option<Box<dyn Any + Send>> {
+    fn event(&mut self, event: &Event) -> Option<Box<dyn Any + Send>> {
         // Row selection and sorting would be handled here
+        if let Event::Scroll { delta_x, delta_y } = event {
+            self.scroll_to(
+                delta_x.mul_add(-self.row_height, self.scroll_x),
+                delta_y.mul_add(-self.row_height, self.scroll_y),
+            );
+        }
         None
     }
 
@@ -819,6 +1141,181 @@ mod tests {
         assert_eq!(table.get_sort_direction(), SortDirection::Descending);
     }
 
+    fn sort_cells() -> Vec<CellValue> {
+        vec![
+            CellValue::from("10"),
+            CellValue::from("2"),
+            CellValue::from(""),
+            CellValue::from("apple"),
+        ]
+    }
+
+    fn sorted_display(cells: &[CellValue], direction: SortDirection) -> Vec<String> {
+        let numeric = is_numeric_column(cells.iter().map(Some));
+        let mut cells = cells.to_vec();
+        cells.sort_by(|a, b| compare_cells(Some(a), Some(b), numeric, direction));
+        cells.iter().map(CellValue::display).collect()
+    }
+
+    #[test]
+    fn test_compare_cells_mixed_column_is_lexicographic() {
+        let cells = sort_cells();
+        assert!(!is_numeric_column(cells.iter().map(Some)));
+        assert_eq!(
+            sorted_display(&cells, SortDirection::Ascending),
+            vec!["10", "2", "apple", ""]
+        );
+        assert_eq!(
+            sorted_display(&cells, SortDirection::Descending),
+            vec!["apple", "2", "10", ""]
+        );
+    }
+
+    #[test]
+    fn test_compare_cells_numeric_column() {
+        let cells = vec![
+            CellValue::from("10"),
+            CellValue::from("2"),
+            CellValue::Empty,
+            CellValue::from(1.5),
+        ];
+        assert!(is_numeric_column(cells.iter().map(Some)));
+        assert_eq!(
+            sorted_display(&cells, SortDirection::Ascending),
+            vec!["1.5", "2", "10", ""]
+        );
+        assert_eq!(
+            sorted_display(&cells, SortDirection::Descending),
+            vec!["10", "2", "1.5", ""]
+        );
+    }
+
+    #[test]
+    fn test_compare_cells_case_insensitive() {
+        let cells = vec![CellValue::from("banana"), CellValue::from("Apple")];
+        assert_eq!(
+            sorted_display(&cells, SortDirection::Ascending),
+            vec!["Apple", "banana"]
+        );
+    }
+
+    #[test]
+    fn test_compare_cells_missing_last() {
+        let value = CellValue::from(1);
+        for direction in [SortDirection::Ascending, SortDirection::Descending] {
+            assert_eq!(
+                compare_cells(None, Some(&value), true, direction),
+                Ordering::Greater
+            );
+            assert_eq!(
+                compare_cells(Some(&value), None, true, direction),
+                Ordering::Less
+            );
+        }
+    }
+
+    #[test]
+    fn test_data_table_set_sort_orders_view() {
+        let mut table = DataTable::new()
+            .column(TableColumn::new("v", "V").sortable())
+            .rows(
+                sort_cells()
+                    .into_iter()
+                    .map(|v| TableRow::new().cell("v", v)),
+            );
+        table.select_row(Some(0));
+
+        table.set_sort("v", SortDirection::Descending);
+        assert_eq!(table.display_order(), vec![3, 1, 0, 2]);
+        // Stored rows and the selected index are untouched
+        assert_eq!(table.get_rows()[0].get("v"), Some(&CellValue::from("10")));
+        assert_eq!(table.get_selected_row(), Some(0));
+        assert!(table.to_csv().starts_with("V\r\napple\r\n2\r\n10\r\n"));
+    }
+
+    fn filter_table() -> DataTable {
+        DataTable::new()
+            .column(TableColumn::new("name", "Name"))
+            .column(TableColumn::new("cpu", "CPU"))
+            .rows(
+                [
+                    ("systemd", 1),
+                    ("chrome", 40),
+                    ("bash", 5),
+                    ("Chromium", 20),
+                ]
+                .into_iter()
+                .map(|(name, cpu)| TableRow::new().cell("name", name).cell("cpu", cpu)),
+            )
+    }
+
+    fn names(table: &DataTable) -> Vec<String> {
+        table
+            .display_order()
+            .into_iter()
+            .map(|i| {
+                table.get_rows()[i]
+                    .get("name")
+                    .map(CellValue::display)
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_data_table_set_filter() {
+        let mut table = filter_table();
+        table.set_filter("ch");
+        assert_eq!(table.get_filter(), "ch");
+        assert_eq!(names(&table), vec!["chrome", "Chromium"]);
+        assert_eq!(table.row_count(), 4);
+        // Shorter row text is a denser match
+        let chrome = table.match_score(1).expect("chrome matches");
+        let chromium = table.match_score(3).expect("Chromium matches");
+        assert!(chrome > chromium);
+        assert_eq!(table.match_score(0), None);
+
+        table.set_filter("");
+        assert_eq!(names(&table), vec!["systemd", "chrome", "bash", "Chromium"]);
+        assert_eq!(table.match_score(0), None);
+    }
+
+    #[test]
+    fn test_data_table_set_filter_keeps_visible_selection() {
+        let mut table = filter_table();
+        table.select_row(Some(1));
+        table.set_filter("chr");
+        assert_eq!(table.get_selected_row(), Some(1));
+
+        table.set_filter("bash");
+        assert_eq!(table.get_selected_row(), None);
+    }
+
+    #[test]
+    fn test_data_table_set_filter_preserves_sort() {
+        let mut table = filter_table();
+        table.set_sort("cpu", SortDirection::Descending);
+        table.set_filter("ch");
+        assert_eq!(names(&table), vec!["chrome", "Chromium"]);
+
+        table.set_sort("cpu", SortDirection::Ascending);
+        table.set_filter("");
+        assert_eq!(names(&table), vec!["systemd", "bash", "Chromium", "chrome"]);
+    }
+
+    #[test]
+    fn test_data_table_filter_not_serialized() {
+        let mut table = filter_table();
+        table.set_filter("ch");
+        let json = serde_json::to_string(&table).unwrap();
+        assert!(!json.contains("\"filter\""));
+        assert!(!json.contains("match_scores"));
+
+        let restored: DataTable = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.row_count(), 4);
+        assert_eq!(restored.match_score(0), None);
+    }
+
     #[test]
     fn test_sort_direction() {
         assert_ne!(SortDirection::Ascending, SortDirection::Descending);
@@ -1051,6 +1548,116 @@ mod tests {
         table.paint(&mut canvas);
     }
 
+    // ===== Scroll / Freeze Tests =====
+
+    fn scroll_table() -> DataTable {
+        DataTable::new()
+            .column(TableColumn::new("id", "ID").width(100.0))
+            .column(TableColumn::new("name", "Name").width(100.0))
+            .column(TableColumn::new("value", "Value").width(100.0))
+            .rows((0..20).map(|i| {
+                TableRow::new()
+                    .cell("id", format!("row-{i}"))
+                    .cell("name", "item")
+                    .cell("value", i)
+            }))
+    }
+
+    fn painted_texts(table: &DataTable) -> Vec<(String, presentar_core::Point)> {
+        let mut canvas = RecordingCanvas::new();
+        table.paint(&mut canvas);
+        canvas
+            .commands()
+            .iter()
+            .filter_map(|cmd| match cmd {
+                presentar_core::DrawCommand::Text {
+                    content, position, ..
+                } => Some((content.clone(), *position)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_data_table_scroll_to_clamps() {
+        let mut table = scroll_table();
+        table.layout(Rect::new(0.0, 0.0, 150.0, 200.0));
+
+        table.scroll_to(-10.0, 10_000.0);
+        // 300px of columns in 150px, 44 + 20 * 40 px of rows in 200px
+        assert_eq!(table.get_scroll_offset(), (0.0, 644.0));
+
+        table.event(&Event::Scroll {
+            delta_x: -1.0,
+            delta_y: 1.0,
+        });
+        assert_eq!(table.get_scroll_offset(), (40.0, 604.0));
+    }
+
+    #[test]
+    fn test_data_table_frozen_header_stays_on_top() {
+        let mut table = scroll_table().freeze_header(true);
+        table.layout(Rect::new(0.0, 0.0, 300.0, 200.0));
+        table.scroll_to(0.0, 300.0);
+
+        let texts = painted_texts(&table);
+        let top_row: Vec<&str> = texts
+            .iter()
+            .filter(|(_, pos)| pos.y == 22.0)
+            .map(|(text, _)| text.as_str())
+            .collect();
+        assert_eq!(top_row, vec!["ID", "Name", "Value"]);
+        // Header is painted after the scrolled rows beneath it
+        assert_eq!(texts.last().map(|(text, _)| text.as_str()), Some("Value"));
+        // Rows scrolled out of view are not painted
+        assert!(!texts.iter().any(|(text, _)| text == "row-0"));
+    }
+
+    #[test]
+    fn test_data_table_unfrozen_header_scrolls_away() {
+        let mut table = scroll_table();
+        table.layout(Rect::new(0.0, 0.0, 300.0, 200.0));
+        table.scroll_to(0.0, 300.0);
+
+        let texts = painted_texts(&table);
+        assert!(!texts.iter().any(|(_, pos)| pos.y == 22.0));
+    }
+
+    #[test]
+    fn test_data_table_frozen_columns_stay_left() {
+        let mut table = scroll_table().freeze_header(true).freeze_columns(1);
+        table.layout(Rect::new(0.0, 0.0, 150.0, 200.0));
+        table.scroll_to(100.0, 0.0);
+
+        let texts = painted_texts(&table);
+        let last_row0 = texts
+            .iter()
+            .rev()
+            .find(|(text, _)| text == "row-0")
+            .map(|(_, pos)| pos.x);
+        assert_eq!(last_row0, Some(8.0));
+        let last_id = texts.iter().rev().find(|(text, _)| text == "ID");
+        assert_eq!(last_id.map(|(_, pos)| pos.x), Some(8.0));
+        // Scrolled column shifts left under the frozen band
+        assert!(texts
+            .iter()
+            .any(|(text, pos)| text == "Value" && pos.x == 108.0));
+    }
+
+    #[test]
+    fn test_data_table_wide_frozen_columns_clipped() {
+        let mut table = scroll_table().freeze_columns(2);
+        table.layout(Rect::new(0.0, 0.0, 150.0, 200.0));
+
+        let mut canvas = RecordingCanvas::new();
+        table.paint(&mut canvas);
+        for cmd in canvas.commands() {
+            if let presentar_core::DrawCommand::Rect { bounds, .. } = cmd {
+                assert!(bounds.x + bounds.width <= 150.0, "{bounds:?} overflows");
+            }
+        }
+    }
+
     #[test]
     fn test_data_table_paint_all_alignments() {
         let mut table = DataTable::new()
@@ -1146,6 +1753,54 @@ mod tests {
         assert!(!table.bordered);
     }
 
+    // ===== Export Tests =====
+
+    fn export_table() -> DataTable {
+        DataTable::new()
+            .column(TableColumn::new("note", "Note"))
+            .column(TableColumn::new("qty", "Qty"))
+            .row(TableRow::new().cell("note", "say \"hi\"").cell("qty", 2))
+            .row(TableRow::new().cell("note", "two\nlines").cell("qty", 3))
+            .row(TableRow::new().cell("note", "a,b"))
+    }
+
+    #[test]
+    fn test_data_table_to_csv_header() {
+        let csv = export_table().to_csv();
+        assert!(csv.starts_with("Note,Qty\r\n"));
+        assert!(csv.ends_with("\"a,b\",\r\n"));
+    }
+
+    #[test]
+    fn test_data_table_to_csv_embedded_quote() {
+        let csv = export_table().to_csv();
+        assert!(csv.contains("\r\n\"say \"\"hi\"\"\",2\r\n"));
+    }
+
+    #[test]
+    fn test_data_table_to_csv_embedded_newline() {
+        let csv = export_table().to_csv();
+        assert!(csv.contains("\r\n\"two\nlines\",3\r\n"));
+        assert_eq!(csv.matches("\r\n").count(), 4);
+    }
+
+    #[test]
+    fn test_data_table_to_csv_column_order() {
+        let table = DataTable::new()
+            .column(TableColumn::new("b", "B"))
+            .column(TableColumn::new("a", "A"))
+            .row(TableRow::new().cell("a", 1).cell("b", true));
+        assert_eq!(table.to_csv(), "B,A\r\nYes,1\r\n");
+    }
+
+    #[test]
+    fn test_data_table_to_tsv() {
+        let tsv = export_table().to_tsv();
+        assert!(tsv.starts_with("Note\tQty\r\n"));
+        assert!(tsv.ends_with("a,b\t\r\n"));
+        assert!(tsv.contains("\"two\nlines\"\t3\r\n"));
+    }
+
     // ===== Additional CellValue Tests =====
 
     #[test]
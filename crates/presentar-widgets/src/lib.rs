@@ -52,7 +52,7 @@ pub use slider::{Slider, SliderChanged};
 pub use stack::{Stack, StackAlignment, StackFit};
 pub use tabs::{Tab, TabChanged, TabOrientation, Tabs};
 pub use text::Text;
-pub use text_input::{TextChanged, TextInput, TextSubmitted};
+pub use text_input::{TextChanged, TextInput, TextSubmitted, ValidatorFn};
 pub use toggle::{Toggle, ToggleChanged};
 pub use tooltip::{Tooltip, TooltipPlacement};
 
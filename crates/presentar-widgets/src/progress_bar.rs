@@ -2,8 +2,8 @@
 
 use presentar_core::{
     widget::{AccessibleRole, LayoutResult},
-    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event, Rect,
-    Size, TypeId, Widget,
+    Brick, BrickAssertion, BrickBudget, BrickVerification, Canvas, Color, Constraints, Event,
+    Point, Rect, Size, TextStyle, TypeId, Widget,
 };
 use serde::{Deserialize, Serialize};
 use std::any::Any;
@@ -19,6 +19,12 @@ pub enum ProgressMode {
     Indeterminate,
 }
 
+/// Seconds for the indeterminate segment to travel across and back.
+const INDETERMINATE_PERIOD: f32 = 1.5;
+
+/// Fraction of the track covered by the indeterminate segment.
+const INDETERMINATE_SEGMENT: f32 = 0.25;
+
 /// Progress bar widget.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProgressBar {
@@ -44,6 +50,12 @@ pub struct ProgressBar {
     accessible_name_value: Option<String>,
     /// Test ID
     test_id_value: Option<String>,
+    /// Indeterminate animation phase (0.0 to 1.0 per cycle)
+    #[serde(skip)]
+    phase: f32,
+    /// Elapsed time for ETA estimation (None hides the ETA)
+    #[serde(skip)]
+    elapsed: Option<Duration>,
     /// Current layout bounds
     #[serde(skip)]
     bounds: Rect,
@@ -63,6 +75,8 @@ impl Default for ProgressBar {
             label_color: Color::BLACK,
             accessible_name_value: None,
             test_id_value: None,
+            phase: 0.0,
+            elapsed: None,
             bounds: Rect::default(),
         }
     }
@@ -157,6 +171,20 @@ impl ProgressBar {
         self
     }
 
+    /// Show a time-remaining estimate from `elapsed` time and the
+    /// fraction of work done (which also becomes the value).
+    #[must_use]
+    pub fn with_eta(mut self, elapsed: Duration, fraction_done: f32) -> Self {
+        self.set_eta(elapsed, fraction_done);
+        self
+    }
+
+    /// Update the ETA inputs (mutable).
+    pub fn set_eta(&mut self, elapsed: Duration, fraction_done: f32) {
+        self.elapsed = Some(elapsed);
+        self.set_value(fraction_done);
+    }
+
     /// Set the accessible name.
     #[must_use]
     pub fn accessible_name(mut self, name: impl Into<String>) -> Self {
@@ -211,6 +239,56 @@ impl ProgressBar {
         self.value = (self.value + delta).clamp(0.0, 1.0);
     }
 
+    /// Advance the indeterminate animation by `dt`.
+    pub fn tick(&mut self, dt: Duration) {
+        if self.mode == ProgressMode::Indeterminate {
+            self.phase = (self.phase + dt.as_secs_f32() / INDETERMINATE_PERIOD).fract();
+        }
+    }
+
+    /// Estimated time remaining, extrapolated linearly from progress so far.
+    ///
+    /// `None` until some time has elapsed and some work is done, or when the
+    /// estimate is not a representable duration.
+    #[must_use]
+    pub fn eta(&self) -> Option<Duration> {
+        let elapsed = self.elapsed?;
+        if elapsed.is_zero() || self.value <= 0.0 {
+            return None;
+        }
+        let remaining = elapsed.as_secs_f64() * f64::from(1.0 - self.value) / f64::from(self.value);
+        Duration::try_from_secs_f64(remaining).ok()
+    }
+
+    /// ETA rendered as text, e.g. `"10s"`, `"2m 05s"`, or `"—"` when unknown.
+    #[must_use]
+    pub fn eta_text(&self) -> String {
+        let Some(eta) = self.eta() else {
+            return "—".to_string();
+        };
+        let secs = eta.as_secs_f64().round() as u64;
+        if secs >= 3600 {
+            format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+        } else if secs >= 60 {
+            format!("{}m {:02}s", secs / 60, secs % 60)
+        } else {
+            format!("{secs}s")
+        }
+    }
+
+    /// Indeterminate segment bounds within `track`, bouncing end to end.
+    fn indeterminate_segment(&self, track: Rect) -> Rect {
+        let width = track.width * INDETERMINATE_SEGMENT;
+        // Triangle wave: 0 → 1 → 0 over one period
+        let t = 1.0 - 2.0f32.mul_add(self.phase, -1.0).abs();
+        Rect::new(
+            (track.width - width).mul_add(t, track.x),
+            track.y,
+            width,
+            track.height,
+        )
+    }
+
     /// Calculate the fill width.
     fn fill_width(&self, total_width: f32) -> f32 {
         total_width * self.value
@@ -265,7 +343,7 @@ impl Widget for ProgressBar {
     }
 
     fn measure(&self, constraints: Constraints) -> Size {
-        let preferred_height = if self.show_label {
+        let preferred_height = if self.show_label || self.elapsed.is_some() {
             self.height + 20.0
         } else {
             self.height
@@ -286,11 +364,26 @@ impl Widget for ProgressBar {
         let track_rect = Rect::new(self.bounds.x, self.bounds.y, self.bounds.width, self.height);
         canvas.fill_rect(track_rect, self.track_color);
 
-        // Draw fill (progress) - only for determinate mode
-        if self.mode == ProgressMode::Determinate && self.value > 0.0 {
-            let fill_width = self.fill_width(track_rect.width);
-            let fill_rect = Rect::new(track_rect.x, track_rect.y, fill_width, self.height);
-            canvas.fill_rect(fill_rect, self.fill_color);
+        match self.mode {
+            ProgressMode::Determinate if self.value > 0.0 => {
+                let fill_width = self.fill_width(track_rect.width);
+                let fill_rect = Rect::new(track_rect.x, track_rect.y, fill_width, self.height);
+                canvas.fill_rect(fill_rect, self.fill_color);
+            }
+            ProgressMode::Determinate => {}
+            ProgressMode::Indeterminate => {
+                canvas.fill_rect(self.indeterminate_segment(track_rect), self.fill_color);
+            }
+        }
+
+        // ETA below the bar
+        if self.elapsed.is_some() && self.mode == ProgressMode::Determinate {
+            let style = TextStyle {
+                color: self.label_color,
+                ..TextStyle::default()
+            };
+            let pos = Point::new(track_rect.x, track_rect.y + self.height + 4.0);
+            canvas.draw_text(&format!("ETA {}", self.eta_text()), pos, &style);
         }
     }
 
@@ -762,16 +855,111 @@ mod tests {
         }
     }
 
+    fn segment_x(pb: &ProgressBar) -> f32 {
+        let mut canvas = RecordingCanvas::new();
+        pb.paint(&mut canvas);
+        match &canvas.commands()[1] {
+            DrawCommand::Rect { bounds, .. } => bounds.x,
+            _ => panic!("Expected Rect command for segment"),
+        }
+    }
+
     #[test]
-    fn test_progress_bar_paint_indeterminate_no_fill() {
+    fn test_progress_bar_paint_indeterminate_segment() {
         let mut pb = ProgressBar::with_value(0.5).indeterminate();
         pb.layout(Rect::new(0.0, 0.0, 200.0, 8.0));
 
         let mut canvas = RecordingCanvas::new();
         pb.paint(&mut canvas);
 
-        // Indeterminate mode: only track, no fill
-        assert_eq!(canvas.command_count(), 1);
+        // Track + 25% segment at the start, ignoring value
+        assert_eq!(canvas.command_count(), 2);
+        match &canvas.commands()[1] {
+            DrawCommand::Rect { bounds, .. } => {
+                assert_eq!(bounds.x, 0.0);
+                assert_eq!(bounds.width, 50.0);
+            }
+            _ => panic!("Expected Rect command for segment"),
+        }
+    }
+
+    #[test]
+    fn test_progress_bar_indeterminate_tick_bounces() {
+        let mut pb = ProgressBar::new().indeterminate();
+        pb.layout(Rect::new(0.0, 0.0, 200.0, 8.0));
+
+        pb.tick(Duration::from_millis(375));
+        assert_eq!(segment_x(&pb), 75.0);
+        pb.tick(Duration::from_millis(375));
+        assert_eq!(segment_x(&pb), 150.0);
+        pb.tick(Duration::from_millis(375));
+        assert_eq!(segment_x(&pb), 75.0);
+    }
+
+    #[test]
+    fn test_progress_bar_tick_ignored_when_determinate() {
+        let mut pb = ProgressBar::with_value(0.5);
+        pb.tick(Duration::from_millis(500));
+        assert_eq!(pb.phase, 0.0);
+    }
+
+    // ===== ETA Tests =====
+
+    #[test]
+    fn test_progress_bar_eta_half_done() {
+        let pb = ProgressBar::new().with_eta(Duration::from_secs(10), 0.5);
+        assert_eq!(pb.get_value(), 0.5);
+        assert_eq!(pb.eta(), Some(Duration::from_secs(10)));
+        assert_eq!(pb.eta_text(), "10s");
+    }
+
+    #[test]
+    fn test_progress_bar_eta_unknown() {
+        assert_eq!(ProgressBar::new().eta_text(), "—");
+        let pb = ProgressBar::new().with_eta(Duration::from_secs(5), 0.0);
+        assert_eq!(pb.eta(), None);
+        assert_eq!(pb.eta_text(), "—");
+        let pb = ProgressBar::new().with_eta(Duration::ZERO, 0.3);
+        assert_eq!(pb.eta_text(), "—");
+    }
+
+    #[test]
+    fn test_progress_bar_eta_out_of_range() {
+        let pb = ProgressBar::new().with_eta(Duration::from_secs(1), 1e-20);
+        assert_eq!(pb.eta(), None);
+        assert_eq!(pb.eta_text(), "—");
+
+        let mut pb = ProgressBar::new().with_eta(Duration::from_secs(1), 0.5);
+        pb.set_value(f32::NAN);
+        assert_eq!(pb.eta(), None);
+        assert_eq!(pb.eta_text(), "—");
+    }
+
+    #[test]
+    fn test_progress_bar_eta_minutes_and_hours() {
+        let pb = ProgressBar::new().with_eta(Duration::from_secs(125), 0.5);
+        assert_eq!(pb.eta_text(), "2m 05s");
+        let pb = ProgressBar::new().with_eta(Duration::from_secs(3720), 0.5);
+        assert_eq!(pb.eta_text(), "1h 02m");
+    }
+
+    #[test]
+    fn test_progress_bar_paint_eta() {
+        let mut pb = ProgressBar::new().with_eta(Duration::from_secs(10), 0.5);
+        pb.layout(Rect::new(0.0, 0.0, 200.0, 28.0));
+
+        let mut canvas = RecordingCanvas::new();
+        pb.paint(&mut canvas);
+
+        match &canvas.commands()[2] {
+            DrawCommand::Text {
+                content, position, ..
+            } => {
+                assert_eq!(content, "ETA 10s");
+                assert_eq!(position.y, 12.0);
+            }
+            _ => panic!("Expected Text command for ETA"),
+        }
     }
 
     #[test]
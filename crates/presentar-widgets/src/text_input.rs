@@ -21,6 +21,17 @@ pub struct TextChanged {
 pub struct TextSubmitted {
     /// The submitted text value
     pub value: String,
+    /// Whether the value passed the validator (always true without one)
+    pub valid: bool,
+}
+
+/// Type alias for the validation callback.
+///
+/// Returns `Err` with a message for the host to display when invalid.
+pub type ValidatorFn = Box<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+const fn default_mask() -> char {
+    '•'
 }
 
 /// `TextInput` widget for text entry.
@@ -34,6 +45,9 @@ pub struct TextInput {
     disabled: bool,
     /// Whether to obscure text (password mode)
     obscure: bool,
+    /// Glyph shown for each character in password mode
+    #[serde(default = "default_mask")]
+    mask: char,
     /// Maximum length (0 = unlimited)
     max_length: usize,
     /// Text style
@@ -63,6 +77,12 @@ pub struct TextInput {
     /// Cursor position (character index)
     #[serde(skip)]
     cursor: usize,
+    /// Validation callback run on every change
+    #[serde(skip)]
+    validator: Option<ValidatorFn>,
+    /// Current validation error, if any
+    #[serde(skip)]
+    error: Option<String>,
 }
 
 impl Default for TextInput {
@@ -80,6 +100,7 @@ impl TextInput {
             placeholder: String::new(),
             disabled: false,
             obscure: false,
+            mask: default_mask(),
             max_length: 0,
             text_style: TextStyle::default(),
             placeholder_color: Color::new(0.6, 0.6, 0.6, 1.0),
@@ -93,6 +114,8 @@ impl TextInput {
             bounds: Rect::default(),
             focused: false,
             cursor: 0,
+            validator: None,
+            error: None,
         }
     }
 
@@ -104,6 +127,7 @@ impl TextInput {
             self.value.truncate(self.max_length);
         }
         self.cursor = self.value.len();
+        self.validate();
         self
     }
 
@@ -128,6 +152,27 @@ impl TextInput {
         self
     }
 
+    /// Set password mode, showing `mask` for each character.
+    ///
+    /// The real value stays available through `get_value`.
+    #[must_use]
+    pub const fn masked(mut self, mask: char) -> Self {
+        self.obscure = true;
+        self.mask = mask;
+        self
+    }
+
+    /// Set a validator, run now and after every change.
+    #[must_use]
+    pub fn with_validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self.validate();
+        self
+    }
+
     /// Set maximum length.
     #[must_use]
     pub fn max_length(mut self, max: usize) -> Self {
@@ -232,16 +277,46 @@ impl TextInput {
         self.focused
     }
 
+    /// Check if the value passes the validator (true without one).
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Get the current validation error message.
+    #[must_use]
+    pub fn error_message(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     /// Get display text (obscured if password mode).
     #[must_use]
     pub fn display_text(&self) -> String {
         if self.obscure {
-            "•".repeat(self.value.len())
+            std::iter::repeat(self.mask)
+                .take(self.value.chars().count())
+                .collect()
         } else {
             self.value.clone()
         }
     }
 
+    /// Re-run the validator against the current value.
+    fn validate(&mut self) {
+        self.error = self
+            .validator
+            .as_ref()
+            .and_then(|validator| validator(&self.value).err());
+    }
+
+    /// Validate and build the change message.
+    fn changed(&mut self) -> Box<dyn Any + Send> {
+        self.validate();
+        Box::new(TextChanged {
+            value: self.value.clone(),
+        })
+    }
+
     /// Insert text at cursor.
     fn insert_text(&mut self, text: &str) -> bool {
         if self.disabled {
@@ -372,24 +447,18 @@ impl Widget for TextInput {
             }
             Event::TextInput { text } if self.focused => {
                 if self.insert_text(text) {
-                    return Some(Box::new(TextChanged {
-                        value: self.value.clone(),
-                    }));
+                    return Some(self.changed());
                 }
             }
             Event::KeyDown { key, .. } if self.focused => match key {
                 Key::Backspace => {
                     if self.backspace() {
-                        return Some(Box::new(TextChanged {
-                            value: self.value.clone(),
-                        }));
+                        return Some(self.changed());
                     }
                 }
                 Key::Delete => {
                     if self.delete() {
-                        return Some(Box::new(TextChanged {
-                            value: self.value.clone(),
-                        }));
+                        return Some(self.changed());
                     }
                 }
                 Key::Left => self.move_left(),
@@ -399,6 +468,7 @@ impl Widget for TextInput {
                 Key::Enter => {
                     return Some(Box::new(TextSubmitted {
                         value: self.value.clone(),
+                        valid: self.is_valid(),
                     }));
                 }
                 _ => {}
@@ -542,8 +612,10 @@ mod tests {
     fn test_text_submitted_message() {
         let msg = TextSubmitted {
             value: "world".to_string(),
+            valid: true,
         };
         assert_eq!(msg.value, "world");
+        assert!(msg.valid);
     }
 
     // =========================================================================
@@ -1020,6 +1092,50 @@ mod tests {
         assert_eq!(msg.value, "hello");
     }
 
+    #[test]
+    fn test_text_input_validator_rejects_empty_submit() {
+        let mut input = TextInput::new().with_validator(|value| {
+            if value.is_empty() {
+                Err("Required".to_string())
+            } else {
+                Ok(())
+            }
+        });
+        input.layout(Rect::new(0.0, 0.0, 200.0, 30.0));
+        input.event(&Event::FocusIn);
+        assert!(!input.is_valid());
+        assert_eq!(input.error_message(), Some("Required"));
+
+        let msg = input
+            .event(&Event::key_down(Key::Enter))
+            .unwrap()
+            .downcast::<TextSubmitted>()
+            .unwrap();
+        assert!(!msg.valid);
+
+        // Typing revalidates
+        input.event(&Event::TextInput {
+            text: "x".to_string(),
+        });
+        assert!(input.is_valid());
+        assert!(input.error_message().is_none());
+        let msg = input
+            .event(&Event::key_down(Key::Enter))
+            .unwrap()
+            .downcast::<TextSubmitted>()
+            .unwrap();
+        assert!(msg.valid);
+        assert_eq!(msg.value, "x");
+    }
+
+    #[test]
+    fn test_text_input_masked() {
+        let input = TextInput::new().value("pässwd").masked('*');
+        assert_eq!(input.display_text(), "******");
+        assert_eq!(input.get_value(), "pässwd");
+        assert!(input.to_html().contains(r#"type="password""#));
+    }
+
     #[test]
     fn test_text_input_event_key_when_not_focused() {
         let mut input = TextInput::new().value("hello");
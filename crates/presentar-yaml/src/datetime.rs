@@ -0,0 +1,299 @@
+//! Date/time helpers for the expression built-ins.
+//!
+//! Timestamps are seconds since the Unix epoch (fractional seconds allowed).
+//! Calendar conversion uses the proleptic Gregorian calendar and is exact
+//! for any `i64` day count, so no timezone database is needed: offsets are
+//! applied as a fixed number of minutes east of UTC.
+
+use std::fmt::Write;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Current time as seconds since the Unix epoch.
+pub(crate) fn now() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |d| d.as_secs_f64())
+}
+
+/// Parse a UTC offset: `"+05:30"`, `"-0800"`, `"Z"`/`"UTC"`.
+///
+/// Returns minutes east of UTC.
+pub(crate) fn parse_offset(offset: &str) -> Option<i64> {
+    let offset = offset.trim();
+    if offset.eq_ignore_ascii_case("z") || offset.eq_ignore_ascii_case("utc") {
+        return Some(0);
+    }
+    let (sign, rest) = match offset.as_bytes().first()? {
+        b'+' => (1, &offset[1..]),
+        b'-' => (-1, &offset[1..]),
+        _ => return None,
+    };
+    let digits: String = rest.chars().filter(|c| *c != ':').collect();
+    if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i64 = digits[..2].parse().ok()?;
+    let minutes: i64 = digits[2..].parse().ok()?;
+    Some(sign * (hours * 60 + minutes))
+}
+
+/// Seconds per unit for `date_diff`.
+pub(crate) fn unit_seconds(unit: &str) -> Option<f64> {
+    match unit {
+        "ms" | "millis" | "milliseconds" => Some(0.001),
+        "s" | "sec" | "secs" | "second" | "seconds" => Some(1.0),
+        "m" | "min" | "mins" | "minute" | "minutes" => Some(60.0),
+        "h" | "hour" | "hours" => Some(3600.0),
+        "d" | "day" | "days" => Some(86_400.0),
+        "w" | "week" | "weeks" => Some(604_800.0),
+        _ => None,
+    }
+}
+
+/// Broken-down calendar time.
+struct DateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    weekday: usize,
+    day_of_year: u32,
+}
+
+impl DateTime {
+    fn from_timestamp(seconds: i64) -> Self {
+        let days = seconds.div_euclid(SECONDS_PER_DAY);
+        let secs_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        let jan1 = days_from_civil(year, 1, 1);
+        Self {
+            year,
+            month,
+            day,
+            hour: (secs_of_day / 3600) as u32,
+            minute: (secs_of_day % 3600 / 60) as u32,
+            second: (secs_of_day % 60) as u32,
+            // 1970-01-01 was a Thursday.
+            weekday: (days + 4).rem_euclid(7) as usize,
+            day_of_year: (days - jan1 + 1) as u32,
+        }
+    }
+}
+
+/// Days since the epoch to (year, month, day). (Hinnant's algorithm.)
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// (year, month, day) to days since the epoch.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(month);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Format a timestamp with a strftime-style pattern.
+///
+/// Supported: `%Y %y %m %d %e %H %I %M %S %p %b %B %a %A %j %F %T %z %Z %s %%`.
+/// Unknown directives are copied through unchanged.
+///
+/// Returns `None` if applying the offset overflows the timestamp.
+pub(crate) fn format(timestamp: f64, pattern: &str, offset_minutes: i64) -> Option<String> {
+    let seconds = timestamp.floor() as i64;
+    let local = offset_minutes
+        .checked_mul(60)
+        .and_then(|offset| seconds.checked_add(offset))?;
+    let dt = DateTime::from_timestamp(local);
+    let mut out = String::with_capacity(pattern.len() + 8);
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        let _ = match chars.next() {
+            Some('Y') => write!(out, "{}", dt.year),
+            Some('y') => write!(out, "{:02}", dt.year.rem_euclid(100)),
+            Some('m') => write!(out, "{:02}", dt.month),
+            Some('d') => write!(out, "{:02}", dt.day),
+            Some('e') => write!(out, "{:2}", dt.day),
+            Some('H') => write!(out, "{:02}", dt.hour),
+            Some('I') => write!(out, "{:02}", (dt.hour + 11) % 12 + 1),
+            Some('M') => write!(out, "{:02}", dt.minute),
+            Some('S') => write!(out, "{:02}", dt.second),
+            Some('p') => out.write_str(if dt.hour < 12 { "AM" } else { "PM" }),
+            Some('b') => out.write_str(&MONTH_NAMES[dt.month as usize - 1][..3]),
+            Some('B') => out.write_str(MONTH_NAMES[dt.month as usize - 1]),
+            Some('a') => out.write_str(&DAY_NAMES[dt.weekday][..3]),
+            Some('A') => out.write_str(DAY_NAMES[dt.weekday]),
+            Some('j') => write!(out, "{:03}", dt.day_of_year),
+            Some('F') => write!(out, "{}-{:02}-{:02}", dt.year, dt.month, dt.day),
+            Some('T') => write!(out, "{:02}:{:02}:{:02}", dt.hour, dt.minute, dt.second),
+            Some('z') => out.write_str(&format_offset(offset_minutes, false)),
+            Some('Z') if offset_minutes == 0 => out.write_str("UTC"),
+            Some('Z') => out.write_str(&format_offset(offset_minutes, true)),
+            Some('s') => write!(out, "{seconds}"),
+            Some('%') => out.write_char('%'),
+            Some(other) => write!(out, "%{other}"),
+            None => out.write_char('%'),
+        };
+    }
+    Some(out)
+}
+
+/// Format a timestamp as an HTTP date: `Sun, 06 Nov 1994 08:49:37 GMT`.
+pub(crate) fn format_http_date(timestamp: i64) -> String {
+    format(timestamp as f64, "%a, %d %b %Y %T GMT", 0).unwrap_or_default()
+}
+
+/// Parse an HTTP date in the RFC 9110 IMF-fixdate form.
+///
+/// Returns seconds since the Unix epoch. The obsolete RFC 850 and asctime
+/// forms are rejected.
+pub(crate) fn parse_http_date(text: &str) -> Option<i64> {
+    let (_, rest) = text.trim().split_once(", ")?;
+    let mut parts = rest.split(' ');
+    let day: u32 = parts.next()?.parse().ok()?;
+    let month = parts.next()?;
+    let month = MONTH_NAMES
+        .iter()
+        .position(|name| name[..3].eq_ignore_ascii_case(month))? as u32
+        + 1;
+    let year: i64 = parts.next()?.parse().ok()?;
+    let mut clock = parts.next()?.split(':').map(|p| p.parse::<i64>().ok());
+    let (hour, minute, second) = (clock.next()??, clock.next()??, clock.next()??);
+    if parts.next()? != "GMT" || parts.next().is_some() || !(1..=31).contains(&day) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second)
+}
+
+fn format_offset(minutes: i64, colon: bool) -> String {
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let minutes = minutes.abs();
+    if colon {
+        format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+    } else {
+        format!("{sign}{:02}{:02}", minutes / 60, minutes % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_epoch() {
+        assert_eq!(
+            format(0.0, "%Y-%m-%d %H:%M:%S", 0).as_deref(),
+            Some("1970-01-01 00:00:00")
+        );
+        assert_eq!(
+            format(0.0, "%A %B %e, %Y", 0).as_deref(),
+            Some("Thursday January  1, 1970")
+        );
+        assert_eq!(format(0.0, "%Z %z", 0).as_deref(), Some("UTC +0000"));
+    }
+
+    #[test]
+    fn test_format_known_timestamps() {
+        // 2000-02-29T12:34:56Z (leap day)
+        assert_eq!(
+            format(951_827_696.0, "%F %T", 0).as_deref(),
+            Some("2000-02-29 12:34:56")
+        );
+        assert_eq!(
+            format(951_827_696.0, "%j %a %I%p", 0).as_deref(),
+            Some("060 Tue 12PM")
+        );
+        // Before the epoch
+        assert_eq!(format(-86_400.0, "%F", 0).as_deref(), Some("1969-12-31"));
+    }
+
+    #[test]
+    fn test_format_with_offset() {
+        assert_eq!(
+            format(0.0, "%F %T %Z", 330).as_deref(),
+            Some("1970-01-01 05:30:00 +05:30")
+        );
+        assert_eq!(
+            format(0.0, "%F %H %z", -480).as_deref(),
+            Some("1969-12-31 16 -0800")
+        );
+    }
+
+    #[test]
+    fn test_format_offset_overflow() {
+        assert_eq!(format(0.0, "%F", i64::MAX), None);
+        assert_eq!(format(f64::MAX, "%F", 60), None);
+    }
+
+    #[test]
+    fn test_parse_offset() {
+        assert_eq!(parse_offset("+05:30"), Some(330));
+        assert_eq!(parse_offset("-0800"), Some(-480));
+        assert_eq!(parse_offset("UTC"), Some(0));
+        assert_eq!(parse_offset("5"), None);
+        assert_eq!(parse_offset("+5:3"), None);
+    }
+
+    #[test]
+    fn test_http_date_round_trip() {
+        let text = "Sun, 06 Nov 1994 08:49:37 GMT";
+        assert_eq!(parse_http_date(text), Some(784_111_777));
+        assert_eq!(format_http_date(784_111_777), text);
+        assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 PST"), None);
+    }
+
+    #[test]
+    fn test_civil_round_trip() {
+        for days in [-719_468, -1, 0, 59, 10_957, 20_000, 2_932_896] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Environment variable substitution in manifest string values.
+//!
+//! `${VAR}` is replaced by the variable's value and `${VAR:-default}` falls
+//! back to `default` when the variable is unset or empty. `$${` escapes a
+//! literal `${`. Only string values are expanded; mapping keys are left
+//! untouched.
+
+use crate::error::ParseError;
+use serde_yaml_ng::Value;
+
+/// Expand variables in every string value of a YAML tree.
+pub(crate) fn substitute(
+    value: &mut Value,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<(), ParseError> {
+    match value {
+        Value::String(text) => {
+            if text.contains("${") {
+                *text = expand(text, lookup)?;
+            }
+        }
+        Value::Sequence(items) => {
+            for item in items {
+                substitute(item, lookup)?;
+            }
+        }
+        Value::Mapping(mapping) => {
+            for (_, item) in mapping.iter_mut() {
+                substitute(item, lookup)?;
+            }
+        }
+        Value::Tagged(tagged) => substitute(&mut tagged.value, lookup)?,
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+/// Expand `${...}` references in a single string.
+fn expand(input: &str, lookup: &impl Fn(&str) -> Option<String>) -> Result<String, ParseError> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find('$') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start..];
+
+        if let Some(escaped) = after.strip_prefix("$${") {
+            output.push_str("${");
+            rest = escaped;
+            continue;
+        }
+        let Some(body) = after.strip_prefix("${") else {
+            output.push('$');
+            rest = &after[1..];
+            continue;
+        };
+        let Some(end) = body.find('}') else {
+            return Err(ParseError::InvalidValue {
+                field: input.to_string(),
+                message: "unterminated `${` variable reference".to_string(),
+            });
+        };
+
+        let reference = &body[..end];
+        let (name, default) = match reference.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (reference, None),
+        };
+        let value = lookup(name).filter(|v| !v.is_empty() || default.is_none());
+        match (value, default) {
+            (Some(value), _) => output.push_str(&value),
+            (None, Some(default)) => output.push_str(default),
+            (None, None) => return Err(ParseError::UndefinedVariable(name.to_string())),
+        }
+        rest = &body[end + 1..];
+    }
+
+    output.push_str(rest);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Manifest;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "HOST" => Some("prod.example.com".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_expand_variables() {
+        assert_eq!(
+            expand("https://${HOST}/data", &lookup).unwrap(),
+            "https://prod.example.com/data"
+        );
+        assert_eq!(expand("${MISSING:-local}", &lookup).unwrap(), "local");
+        assert_eq!(expand("${EMPTY:-fallback}", &lookup).unwrap(), "fallback");
+        assert_eq!(expand("${EMPTY}", &lookup).unwrap(), "");
+        assert_eq!(expand("cost: $5", &lookup).unwrap(), "cost: $5");
+        assert_eq!(expand("$${HOST}", &lookup).unwrap(), "${HOST}");
+    }
+
+    #[test]
+    fn test_expand_missing_variable_is_error() {
+        let err = expand("${NOT_SET}", &lookup).unwrap_err();
+        assert!(matches!(err, ParseError::UndefinedVariable(ref name) if name == "NOT_SET"));
+        assert!(expand("${UNTERMINATED", &lookup).is_err());
+    }
+
+    const ENV_MANIFEST: &str = r#"
+presentar: "0.1"
+name: "env-app"
+version: "1.0.0"
+data:
+  sales:
+    source: "${PRESENTAR_TEST_SALES_URL}"
+  costs:
+    source: "${PRESENTAR_TEST_UNSET_COSTS:-file://costs.ald}"
+layout:
+  type: "dashboard"
+"#;
+
+    #[test]
+    fn test_manifest_substitutes_env() {
+        let env = |name: &str| match name {
+            "PRESENTAR_TEST_SALES_URL" => Some("https://staging/sales.ald".to_string()),
+            _ => None,
+        };
+
+        let manifest = Manifest::from_yaml_with_env(ENV_MANIFEST, env).unwrap();
+
+        assert_eq!(manifest.data["sales"].source, "https://staging/sales.ald");
+        assert_eq!(manifest.data["costs"].source, "file://costs.ald");
+    }
+
+    #[test]
+    fn test_manifest_unset_variable_names_it() {
+        let err = Manifest::from_yaml_with_env(ENV_MANIFEST, |_| None).unwrap_err();
+        assert!(err.to_string().contains("PRESENTAR_TEST_SALES_URL"));
+    }
+
+    #[test]
+    fn test_from_yaml_keeps_references_verbatim() {
+        let manifest = Manifest::from_yaml(ENV_MANIFEST).unwrap();
+        assert_eq!(manifest.data["sales"].source, "${PRESENTAR_TEST_SALES_URL}");
+    }
+}
@@ -20,6 +20,19 @@ pub enum ParseError {
         /// Error message
         message: String,
     },
+    /// `${VAR}` reference to an unset environment variable with no default
+    UndefinedVariable(String),
+    /// Included file could not be loaded (missing, unreadable, cyclic, or
+    /// outside the manifest's directory).
+    ///
+    /// Added alongside `Manifest::from_file`; exhaustive matches on
+    /// `ParseError` need an arm for it.
+    Include {
+        /// Path of the included file
+        path: String,
+        /// Error message
+        message: String,
+    },
 }
 
 impl fmt::Display for ParseError {
@@ -32,6 +45,10 @@ impl fmt::Display for ParseError {
             Self::InvalidValue { field, message } => {
                 write!(f, "Invalid value for '{field}': {message}")
             }
+            Self::UndefinedVariable(name) => {
+                write!(f, "Undefined environment variable: {name}")
+            }
+            Self::Include { path, message } => write!(f, "Include error in {path}: {message}"),
         }
     }
 }
@@ -79,6 +96,15 @@ mod tests {
 
         let err = ParseError::Validation("layout is required".to_string());
         assert_eq!(err.to_string(), "Validation error: layout is required");
+
+        let err = ParseError::Include {
+            path: "sections/a.yaml".to_string(),
+            message: "include cycle".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Include error in sections/a.yaml: include cycle"
+        );
     }
 
     #[test]
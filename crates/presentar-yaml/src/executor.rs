@@ -163,12 +163,19 @@ impl Value {
     }
 
     /// Extract numeric values from array items by field name.
+    ///
+    /// An empty `field` reads the items themselves. Items that are missing
+    /// the field or are not numbers are skipped, so aggregations such as
+    /// `avg` divide by the number of values actually aggregated.
     pub fn extract_numbers(&self, field: &str) -> Result<Vec<f64>, ExecutionError> {
-        Ok(self
-            .require_array()?
-            .iter()
-            .filter_map(|item| item.get(field)?.as_number())
-            .collect())
+        let items = self.require_array()?.iter();
+        Ok(if field.is_empty() {
+            items.filter_map(Self::as_number).collect()
+        } else {
+            items
+                .filter_map(|item| item.get(field)?.as_number())
+                .collect()
+        })
     }
 
     /// Get array length or object key count.
@@ -320,11 +327,10 @@ impl ExpressionExecutor {
     ///
     /// Returns an error if execution fails.
     pub fn execute(&self, expr: &Expression, ctx: &DataContext) -> Result<Value, ExecutionError> {
-        // Resolve source
-        let mut value = ctx
-            .get(&expr.source)
-            .cloned()
-            .ok_or_else(|| ExecutionError::SourceNotFound(expr.source.clone()))?;
+        // Resolve source; `now()` is sampled once so it is consistent
+        // across the whole expression.
+        let now = crate::datetime::now();
+        let mut value = Self::resolve_source(&expr.source, ctx, now)?;
 
         // Apply transforms
         for transform in &expr.transforms {
@@ -334,6 +340,32 @@ impl ExpressionExecutor {
         Ok(value)
     }
 
+    /// Resolve an expression source: a built-in function call or a (dotted)
+    /// data context path.
+    fn resolve_source(source: &str, ctx: &DataContext, now: f64) -> Result<Value, ExecutionError> {
+        if let Some((name, args)) = split_call(source.trim()) {
+            if is_builtin_function(name) {
+                let args = split_args(args)
+                    .into_iter()
+                    .map(|arg| Self::resolve_arg(arg, ctx, now))
+                    .collect::<Result<Vec<_>, _>>()?;
+                return call_builtin(name, &args, now);
+            }
+        }
+
+        ctx.get(source)
+            .cloned()
+            .ok_or_else(|| ExecutionError::SourceNotFound(source.to_string()))
+    }
+
+    /// Resolve a built-in function argument: a literal or a source.
+    fn resolve_arg(arg: &str, ctx: &DataContext, now: f64) -> Result<Value, ExecutionError> {
+        match parse_literal(arg) {
+            Some(literal) => Ok(literal),
+            None => Self::resolve_source(arg, ctx, now),
+        }
+    }
+
     fn apply_transform(
         &self,
         value: &Value,
@@ -1256,3 +1288,149 @@ impl ExpressionExecutor {
         Ok(Value::Array(vec![]))
     }
 }
+
+// =============================================================================
+// Built-in functions
+// =============================================================================
+
+/// Functions callable as an expression source.
+const BUILTIN_FUNCTIONS: [&str; 3] = ["now", "format_date", "date_diff"];
+
+fn is_builtin_function(name: &str) -> bool {
+    BUILTIN_FUNCTIONS.contains(&name)
+}
+
+/// Split `name(args)` into its name and raw argument list.
+fn split_call(source: &str) -> Option<(&str, &str)> {
+    let open = source.find('(')?;
+    let inner = source.strip_suffix(')')?;
+    let name = source[..open].trim();
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+    Some((name, &inner[open + 1..]))
+}
+
+/// Split arguments on top-level commas, respecting quotes and parentheses.
+fn split_args(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0_usize;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+
+    for (i, c) in args.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                parts.push(args[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = args[start..].trim();
+    if !last.is_empty() || !parts.is_empty() {
+        parts.push(last);
+    }
+    parts
+}
+
+/// Parse a quoted string or numeric literal.
+fn parse_literal(source: &str) -> Option<Value> {
+    for quote in ['"', '\''] {
+        if source.len() >= 2 && source.starts_with(quote) && source.ends_with(quote) {
+            return Some(Value::String(source[1..source.len() - 1].to_string()));
+        }
+    }
+    source.parse::<f64>().ok().map(Value::Number)
+}
+
+fn call_builtin(name: &str, args: &[Value], now: f64) -> Result<Value, ExecutionError> {
+    match name {
+        // now() -> seconds since the Unix epoch
+        "now" => {
+            expect_arity(name, args, 0, 0)?;
+            Ok(Value::Number(now))
+        }
+        // format_date(timestamp, format, [offset]) -> string, UTC by default
+        "format_date" => {
+            expect_arity(name, args, 2, 3)?;
+            let timestamp = timestamp_arg(name, &args[0])?;
+            let pattern = args[1].as_str().ok_or_else(|| {
+                ExecutionError::TypeMismatch(format!("{name} format must be a string"))
+            })?;
+            let offset = args.get(2).map_or(Ok(0), |arg| offset_arg(name, arg))?;
+            crate::datetime::format(timestamp, pattern, offset)
+                .map(Value::String)
+                .ok_or_else(|| {
+                    ExecutionError::InvalidTransform(format!(
+                        "{name}: timestamp out of range with offset {offset} minutes"
+                    ))
+                })
+        }
+        // date_diff(a, b, [unit]) -> whole units from b to a (seconds by default)
+        "date_diff" => {
+            expect_arity(name, args, 2, 3)?;
+            let a = timestamp_arg(name, &args[0])?;
+            let b = timestamp_arg(name, &args[1])?;
+            let unit = match args.get(2) {
+                Some(arg) => arg.as_str().ok_or_else(|| {
+                    ExecutionError::TypeMismatch(format!("{name} unit must be a string"))
+                })?,
+                None => "seconds",
+            };
+            let per_unit = crate::datetime::unit_seconds(unit).ok_or_else(|| {
+                ExecutionError::InvalidTransform(format!("{name}: unknown unit '{unit}'"))
+            })?;
+            Ok(Value::Number(((a - b) / per_unit).trunc()))
+        }
+        _ => Err(ExecutionError::InvalidTransform(format!(
+            "unknown function: {name}"
+        ))),
+    }
+}
+
+fn expect_arity(name: &str, args: &[Value], min: usize, max: usize) -> Result<(), ExecutionError> {
+    if args.len() < min || args.len() > max {
+        let expected = if min == max {
+            min.to_string()
+        } else {
+            format!("{min}-{max}")
+        };
+        return Err(ExecutionError::InvalidTransform(format!(
+            "{name} expects {expected} argument(s), got {}",
+            args.len()
+        )));
+    }
+    Ok(())
+}
+
+/// A timestamp argument: a number, or a numeric string.
+fn timestamp_arg(name: &str, value: &Value) -> Result<f64, ExecutionError> {
+    match value {
+        Value::Number(n) => Ok(*n),
+        Value::String(s) => s.trim().parse().map_err(|_| {
+            ExecutionError::TypeMismatch(format!("{name} expects a timestamp, got '{s}'"))
+        }),
+        _ => Err(ExecutionError::TypeMismatch(format!(
+            "{name} expects a timestamp"
+        ))),
+    }
+}
+
+/// An offset argument: hours east of UTC as a number, or `"+HH:MM"`.
+fn offset_arg(name: &str, value: &Value) -> Result<i64, ExecutionError> {
+    match value {
+        Value::Number(hours) if hours.is_finite() => Ok((hours * 60.0).round() as i64),
+        Value::String(s) => crate::datetime::parse_offset(s).ok_or_else(|| {
+            ExecutionError::TypeMismatch(format!("{name}: invalid UTC offset '{s}'"))
+        }),
+        _ => Err(ExecutionError::TypeMismatch(format!(
+            "{name} offset must be hours or \"+HH:MM\""
+        ))),
+    }
+}
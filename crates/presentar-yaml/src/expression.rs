@@ -42,12 +42,12 @@ pub enum Transform {
     },
     /// Count rows
     Count,
-    /// Sum a field: `sum(field)`
+    /// Sum a field: `sum(field)`, or an array of numbers: `sum`
     Sum {
         /// Field to sum
         field: String,
     },
-    /// Average a field: `mean(field)`
+    /// Average a field: `mean(field)` / `avg(field)`, or an array of numbers: `avg`
     Mean {
         /// Field to average
         field: String,
@@ -95,12 +95,12 @@ pub enum Transform {
         /// Number of rows to skip
         n: usize,
     },
-    /// Minimum value: `min(field)`
+    /// Minimum value: `min(field)`, or of an array of numbers: `min`
     Min {
         /// Field to find minimum
         field: String,
     },
-    /// Maximum value: `max(field)`
+    /// Maximum value: `max(field)`, or of an array of numbers: `max`
     Max {
         /// Field to find maximum
         field: String,
@@ -320,7 +320,7 @@ impl ExpressionParser {
                 "sum" => Ok(Transform::Sum {
                     field: args_str.to_string(),
                 }),
-                "mean" => Ok(Transform::Mean {
+                "mean" | "avg" => Ok(Transform::Mean {
                     field: args_str.to_string(),
                 }),
                 "rate" => Ok(Transform::Rate {
@@ -487,6 +487,19 @@ impl ExpressionParser {
             // Simple transform without args
             match input {
                 "count" => Ok(Transform::Count),
+                // Bare aggregations fold over the array elements themselves.
+                "sum" => Ok(Transform::Sum {
+                    field: String::new(),
+                }),
+                "mean" | "avg" => Ok(Transform::Mean {
+                    field: String::new(),
+                }),
+                "min" => Ok(Transform::Min {
+                    field: String::new(),
+                }),
+                "max" => Ok(Transform::Max {
+                    field: String::new(),
+                }),
                 "percentage" => Ok(Transform::Percentage),
                 "flatten" => Ok(Transform::Flatten),
                 "reverse" => Ok(Transform::Reverse),
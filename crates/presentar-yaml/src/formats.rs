@@ -1,4 +1,5 @@
-//! File format loaders for Aprender (.apr) and Alimentar (.ald) files.
+//! File format loaders for Aprender (.apr) and Alimentar (.ald) files, plus
+//! JSON and NDJSON record sources.
 //!
 //! # File Formats
 //!
@@ -35,9 +36,25 @@
 //!     [Tensor data as in ALD]
 //! [Metadata section]
 //! ```
+//!
+//! ## JSON / NDJSON records
+//!
+//! A JSON document holding an array of objects (or a single object), or
+//! newline-delimited JSON with one object per line. Records load into the
+//! same [`Value`] tree the expression executor works on.
+//!
+//! ## Parquet
+//!
+//! Flat Parquet files are read column by column into an [`AldDataset`]; see
+//! [`read_parquet`].
 
+use crate::executor::{DataContext, Value};
+use serde_json::value::RawValue;
+use std::collections::HashMap;
 use std::io::{self, Read, Write};
 
+pub use crate::parquet::read_parquet;
+
 /// Data type for tensor elements.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u32)]
@@ -444,6 +461,17 @@ pub enum FormatError {
     TruncatedData,
     /// IO error
     IoError(String),
+    /// Malformed JSON record (1-based line)
+    InvalidJson {
+        /// Line of the offending input
+        line: usize,
+        /// Parser message
+        message: String,
+    },
+    /// Malformed or inconsistent Parquet file
+    InvalidParquet(String),
+    /// Valid input using a feature this reader does not support
+    Unsupported(String),
 }
 
 impl std::fmt::Display for FormatError {
@@ -454,6 +482,11 @@ impl std::fmt::Display for FormatError {
             Self::InvalidDType(d) => write!(f, "Invalid dtype: {d}"),
             Self::TruncatedData => write!(f, "Truncated data"),
             Self::IoError(e) => write!(f, "IO error: {e}"),
+            Self::InvalidJson { line, message } => {
+                write!(f, "Invalid JSON at line {line}: {message}")
+            }
+            Self::InvalidParquet(message) => write!(f, "Invalid Parquet file: {message}"),
+            Self::Unsupported(feature) => write!(f, "Unsupported: {feature}"),
         }
     }
 }
@@ -530,6 +563,269 @@ fn write_tensor<W: Write>(writer: &mut W, tensor: &Tensor) -> io::Result<()> {
     writer.write_all(&tensor.data)
 }
 
+// =============================================================================
+// JSON / NDJSON records
+// =============================================================================
+
+/// Number of leading records sampled to infer column types.
+pub const TYPE_INFERENCE_ROWS: usize = 100;
+
+/// Text record format for data sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// A JSON array of objects, or a single object
+    Json,
+    /// Newline-delimited JSON, one object per line
+    Ndjson,
+}
+
+impl Format {
+    /// Detect the format from a file extension.
+    #[must_use]
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "ndjson" | "jsonl" => Some(Self::Ndjson),
+            _ => None,
+        }
+    }
+
+    /// Parse records, failing on the first malformed record.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::InvalidJson`] with the offending line.
+    pub fn load(self, text: &str) -> Result<Records, FormatError> {
+        self.parse(text, false)
+    }
+
+    /// Parse records, skipping malformed NDJSON lines.
+    ///
+    /// Skipped lines are counted in [`Records::skipped`]. A JSON document is
+    /// all-or-nothing, so this only differs from [`Format::load`] for NDJSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::InvalidJson`] if a JSON document is malformed.
+    pub fn load_lenient(self, text: &str) -> Result<Records, FormatError> {
+        self.parse(text, true)
+    }
+
+    fn parse(self, text: &str, skip_malformed: bool) -> Result<Records, FormatError> {
+        let mut rows = Vec::new();
+        let mut skipped = 0;
+
+        match self {
+            Self::Json => {
+                // Keep each item as a slice of `text` so that errors can
+                // report the line the item starts on.
+                let document: &RawValue =
+                    serde_json::from_str(text).map_err(|e| FormatError::InvalidJson {
+                        line: e.line(),
+                        message: e.to_string(),
+                    })?;
+                let items: Vec<&RawValue> = if document.get().starts_with('[') {
+                    serde_json::from_str(document.get()).map_err(|e| FormatError::InvalidJson {
+                        line: line_of(text, document.get()),
+                        message: e.to_string(),
+                    })?
+                } else if document.get().starts_with('{') {
+                    vec![document]
+                } else {
+                    return Err(FormatError::InvalidJson {
+                        line: line_of(text, document.get()),
+                        message: "expected an array of objects or an object".to_string(),
+                    });
+                };
+                for item in items {
+                    let line = line_of(text, item.get());
+                    let value =
+                        serde_json::from_str::<serde_json::Value>(item.get()).map_err(|e| {
+                            FormatError::InvalidJson {
+                                line: line + e.line() - 1,
+                                message: e.to_string(),
+                            }
+                        })?;
+                    if !value.is_object() {
+                        return Err(FormatError::InvalidJson {
+                            line,
+                            message: "array items must be objects".to_string(),
+                        });
+                    }
+                    rows.push(json_to_value(value));
+                }
+            }
+            Self::Ndjson => {
+                for (index, line) in text.lines().enumerate() {
+                    let line = line.trim();
+                    if line.is_empty() {
+                        continue;
+                    }
+                    let record = serde_json::from_str::<serde_json::Value>(line)
+                        .map_err(|e| e.to_string())
+                        .and_then(|value| {
+                            if value.is_object() {
+                                Ok(value)
+                            } else {
+                                Err("record must be an object".to_string())
+                            }
+                        });
+                    match record {
+                        Ok(value) => rows.push(json_to_value(value)),
+                        Err(_) if skip_malformed => skipped += 1,
+                        Err(message) => {
+                            return Err(FormatError::InvalidJson {
+                                line: index + 1,
+                                message,
+                            })
+                        }
+                    }
+                }
+            }
+        }
+
+        let columns = infer_columns(&rows);
+        Ok(Records {
+            rows,
+            columns,
+            skipped,
+        })
+    }
+}
+
+/// 1-based line on which `part`, a subslice of `text`, starts.
+fn line_of(text: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(text.as_ptr() as usize);
+    text.get(..offset)
+        .map_or(1, |before| before.matches('\n').count() + 1)
+}
+
+/// Inferred type of a record column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    /// Only nulls were seen
+    Null,
+    /// Booleans
+    Bool,
+    /// Numbers
+    Number,
+    /// Strings
+    String,
+    /// Arrays
+    Array,
+    /// Nested objects
+    Object,
+    /// More than one non-null type
+    Mixed,
+}
+
+impl ColumnType {
+    const fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(_) => Self::Bool,
+            Value::Number(_) => Self::Number,
+            Value::String(_) => Self::String,
+            Value::Array(_) => Self::Array,
+            Value::Object(_) => Self::Object,
+        }
+    }
+
+    /// Combine with another observation; nulls don't change the type.
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Null, t) | (t, Self::Null) => t,
+            (a, b) if a == b => a,
+            _ => Self::Mixed,
+        }
+    }
+}
+
+/// Records loaded from a JSON or NDJSON source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Records {
+    /// One object per record
+    pub rows: Vec<Value>,
+    /// Column names and types, sorted by name
+    pub columns: Vec<(String, ColumnType)>,
+    /// Malformed lines skipped by [`Format::load_lenient`]
+    pub skipped: usize,
+}
+
+impl Records {
+    /// Number of records.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Check if there are no records.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Inferred type of a column.
+    #[must_use]
+    pub fn column_type(&self, name: &str) -> Option<ColumnType> {
+        self.columns
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, kind)| *kind)
+    }
+
+    /// Convert into an array value.
+    #[must_use]
+    pub fn into_value(self) -> Value {
+        Value::Array(self.rows)
+    }
+
+    /// Register the records as a named data source.
+    pub fn insert_into(self, ctx: &mut DataContext, name: impl Into<String>) {
+        ctx.insert(name, self.into_value());
+    }
+}
+
+fn infer_columns(rows: &[Value]) -> Vec<(String, ColumnType)> {
+    let mut types: HashMap<&str, ColumnType> = HashMap::new();
+    for row in rows.iter().take(TYPE_INFERENCE_ROWS) {
+        let Some(fields) = row.as_object() else {
+            continue;
+        };
+        for (name, value) in fields {
+            let seen = ColumnType::of(value);
+            types
+                .entry(name.as_str())
+                .and_modify(|kind| *kind = kind.merge(seen))
+                .or_insert(seen);
+        }
+    }
+    let mut columns: Vec<(String, ColumnType)> = types
+        .into_iter()
+        .map(|(name, kind)| (name.to_string(), kind))
+        .collect();
+    columns.sort_by(|a, b| a.0.cmp(&b.0));
+    columns
+}
+
+fn json_to_value(json: serde_json::Value) -> Value {
+    match json {
+        serde_json::Value::Null => Value::Null,
+        serde_json::Value::Bool(b) => Value::Bool(b),
+        serde_json::Value::Number(n) => n.as_f64().map_or(Value::Null, Value::Number),
+        serde_json::Value::String(s) => Value::String(s),
+        serde_json::Value::Array(items) => {
+            Value::Array(items.into_iter().map(json_to_value).collect())
+        }
+        serde_json::Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key, json_to_value(value)))
+                .collect(),
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -713,4 +1009,91 @@ mod tests {
         assert!(FormatError::InvalidDType(255).to_string().contains("255"));
         assert!(FormatError::TruncatedData.to_string().contains("Truncated"));
     }
+
+    // =========================================================================
+    // JSON / NDJSON tests
+    // =========================================================================
+
+    const NDJSON: &str = r#"{"id": 1, "name": "alpha", "active": true, "score": null}
+{"id": 2, "name": "beta", "active": false, "score": 0.5}
+{"id": 3, "name": "gamma", "active": true, "score": 0.75}
+"#;
+
+    #[test]
+    fn test_ndjson_loads_into_context() {
+        let records = Format::Ndjson.load(NDJSON).unwrap();
+        assert_eq!(records.skipped, 0);
+        assert_eq!(
+            records.columns,
+            vec![
+                ("active".to_string(), ColumnType::Bool),
+                ("id".to_string(), ColumnType::Number),
+                ("name".to_string(), ColumnType::String),
+                ("score".to_string(), ColumnType::Number),
+            ]
+        );
+
+        let mut ctx = DataContext::new();
+        records.insert_into(&mut ctx, "events");
+
+        let rows = ctx.get("events").and_then(Value::as_array).unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1].get("name").and_then(Value::as_str), Some("beta"));
+        assert_eq!(rows[2].get("id").and_then(Value::as_number), Some(3.0));
+        assert_eq!(rows[0].get("active").and_then(Value::as_bool), Some(true));
+        assert!(rows[0].get("score").is_some_and(Value::is_null));
+    }
+
+    #[test]
+    fn test_ndjson_malformed_lines() {
+        let text = "{\"a\": 1}\nnot json\n\n[1, 2]\n{\"a\": \"x\"}\n";
+
+        let err = Format::Ndjson.load(text).unwrap_err();
+        assert!(matches!(err, FormatError::InvalidJson { line: 2, .. }));
+
+        let records = Format::Ndjson.load_lenient(text).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records.skipped, 2);
+        assert_eq!(records.column_type("a"), Some(ColumnType::Mixed));
+    }
+
+    #[test]
+    fn test_json_array_and_object() {
+        let records = Format::Json
+            .load(r#"[{"x": 1, "tags": ["a"]}, {"x": 2, "meta": {"k": 1}}]"#)
+            .unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records.column_type("tags"), Some(ColumnType::Array));
+        assert_eq!(records.column_type("meta"), Some(ColumnType::Object));
+
+        assert_eq!(Format::Json.load(r#"{"x": 1}"#).unwrap().len(), 1);
+        assert!(Format::Json.load("42").is_err());
+        assert!(Format::Json.load("[1, 2]").is_err());
+        assert!(matches!(
+            Format::Json.load("[{\"x\": 1},\n{oops}]"),
+            Err(FormatError::InvalidJson { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_json_errors_report_item_line() {
+        let text = "[\n  {\"x\": 1},\n  {\"x\": 2},\n  3\n]";
+        assert!(matches!(
+            Format::Json.load(text),
+            Err(FormatError::InvalidJson { line: 4, .. })
+        ));
+
+        assert!(matches!(
+            Format::Json.load("\n\n\"text\""),
+            Err(FormatError::InvalidJson { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(Format::from_extension("json"), Some(Format::Json));
+        assert_eq!(Format::from_extension("JSONL"), Some(Format::Ndjson));
+        assert_eq!(Format::from_extension("ndjson"), Some(Format::Ndjson));
+        assert_eq!(Format::from_extension("csv"), None);
+    }
 }
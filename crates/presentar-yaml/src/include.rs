@@ -0,0 +1,377 @@
+//! Include directives for composing a manifest from several files.
+//!
+//! Two forms are accepted, both resolved relative to the including file:
+//!
+//! - `!include path.yaml` replaces the tagged node with the file's contents.
+//!   As a sequence item, an included sequence is spliced in place.
+//! - A `$include: path.yaml` key (or list of paths) inside a mapping merges
+//!   the included mappings underneath the local keys: nested mappings merge,
+//!   sequences concatenate (included items first), and local scalars win.
+//!
+//! Include paths must be relative and stay inside the directory of the
+//! top-level manifest: absolute paths, `..` components and symlinks that
+//! resolve outside it are rejected.
+
+use crate::error::ParseError;
+use serde_yaml_ng::{Mapping, Value};
+use std::path::{Component, Path, PathBuf};
+
+/// Mapping key that merges other files into the mapping.
+const INCLUDE_KEY: &str = "$include";
+
+/// YAML tag that replaces a node with another file.
+const INCLUDE_TAG: &str = "include";
+
+/// Files visited while resolving a manifest's includes.
+#[derive(Default)]
+struct Loader {
+    /// Files currently being resolved, top-level manifest first
+    stack: Vec<PathBuf>,
+    /// Every file read, in resolution order, without repeats
+    files: Vec<PathBuf>,
+}
+
+/// Load a YAML file and resolve its includes recursively.
+pub(crate) fn load(path: &Path) -> Result<Value, ParseError> {
+    load_file(path, &mut Loader::default())
+}
+
+/// Canonical paths of a manifest and every file it includes, in the order
+/// they are resolved.
+pub(crate) fn sources(path: &Path) -> Result<Vec<PathBuf>, ParseError> {
+    let mut loader = Loader::default();
+    load_file(path, &mut loader)?;
+    Ok(loader.files)
+}
+
+fn load_file(path: &Path, loader: &mut Loader) -> Result<Value, ParseError> {
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| include_error(path, e.to_string()))?;
+    // The top-level manifest is the first entry of the stack.
+    let root = loader.stack.first().and_then(|manifest| manifest.parent());
+    if root.is_some_and(|root| !canonical.starts_with(root)) {
+        return Err(include_error(
+            path,
+            "resolves outside the manifest directory".to_string(),
+        ));
+    }
+    if loader.stack.contains(&canonical) {
+        let chain: Vec<String> = loader
+            .stack
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|p| p.display().to_string())
+            .collect();
+        return Err(include_error(
+            path,
+            format!("include cycle: {}", chain.join(" -> ")),
+        ));
+    }
+
+    let text =
+        std::fs::read_to_string(&canonical).map_err(|e| include_error(path, e.to_string()))?;
+    let value: Value = serde_yaml_ng::from_str(&text)?;
+    let base = canonical
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    if !loader.files.contains(&canonical) {
+        loader.files.push(canonical.clone());
+    }
+    loader.stack.push(canonical);
+    let resolved = resolve(value, &base, loader);
+    loader.stack.pop();
+    resolved
+}
+
+fn resolve(value: Value, base: &Path, loader: &mut Loader) -> Result<Value, ParseError> {
+    match value {
+        Value::Tagged(tagged) if tagged.tag == INCLUDE_TAG => {
+            let target = include_target(&tagged.value, base)?;
+            load_file(&target, loader)
+        }
+        Value::Mapping(mapping) => resolve_mapping(mapping, base, loader),
+        Value::Sequence(items) => {
+            let mut resolved = Vec::with_capacity(items.len());
+            for item in items {
+                let splice = is_include(&item);
+                match resolve(item, base, loader)? {
+                    Value::Sequence(inner) if splice => resolved.extend(inner),
+                    other => resolved.push(other),
+                }
+            }
+            Ok(Value::Sequence(resolved))
+        }
+        other => Ok(other),
+    }
+}
+
+fn resolve_mapping(
+    mut mapping: Mapping,
+    base: &Path,
+    loader: &mut Loader,
+) -> Result<Value, ParseError> {
+    let include = mapping.remove(INCLUDE_KEY);
+
+    let mut local = Mapping::new();
+    for (key, value) in mapping {
+        local.insert(key, resolve(value, base, loader)?);
+    }
+
+    let Some(include) = include else {
+        return Ok(Value::Mapping(local));
+    };
+    let targets = match include {
+        Value::Sequence(items) => items,
+        single => vec![single],
+    };
+
+    // A bare `{ $include: file }` stands for the file itself, whatever its type.
+    if local.is_empty() && targets.len() == 1 {
+        let target = include_target(&targets[0], base)?;
+        return load_file(&target, loader);
+    }
+
+    let mut merged = Value::Mapping(Mapping::new());
+    for target in &targets {
+        let path = include_target(target, base)?;
+        let included = load_file(&path, loader)?;
+        if !included.is_mapping() {
+            return Err(include_error(
+                &path,
+                format!("`{INCLUDE_KEY}` inside a mapping must include a mapping"),
+            ));
+        }
+        merged = merge(merged, included);
+    }
+    Ok(merge(merged, Value::Mapping(local)))
+}
+
+/// Layer `overlay` over `base`.
+fn merge(base: Value, overlay: Value) -> Value {
+    match (base, overlay) {
+        (Value::Mapping(mut base), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                let value = match base.remove(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => value,
+                };
+                base.insert(key, value);
+            }
+            Value::Mapping(base)
+        }
+        (Value::Sequence(mut base), Value::Sequence(overlay)) => {
+            base.extend(overlay);
+            Value::Sequence(base)
+        }
+        (_, overlay) => overlay,
+    }
+}
+
+fn is_include(value: &Value) -> bool {
+    match value {
+        Value::Tagged(tagged) => tagged.tag == INCLUDE_TAG,
+        Value::Mapping(mapping) => mapping.len() == 1 && mapping.contains_key(INCLUDE_KEY),
+        _ => false,
+    }
+}
+
+fn include_target(value: &Value, base: &Path) -> Result<PathBuf, ParseError> {
+    let relative = value.as_str().ok_or_else(|| ParseError::InvalidValue {
+        field: INCLUDE_KEY.to_string(),
+        message: "include path must be a string".to_string(),
+    })?;
+    let escapes = Path::new(relative)
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir));
+    if escapes {
+        return Err(include_error(
+            Path::new(relative),
+            "include paths must be relative and may not contain `..`".to_string(),
+        ));
+    }
+    Ok(base.join(relative))
+}
+
+fn include_error(path: &Path, message: String) -> ParseError {
+    ParseError::Include {
+        path: path.display().to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Manifest;
+    use std::fs;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("presentar-yaml-include-{name}"));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("sections")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_include_section_and_data() {
+        let dir = temp_dir("merge");
+        fs::write(
+            dir.join("app.yaml"),
+            r#"
+presentar: "0.1"
+name: "split-app"
+version: "1.0.0"
+data:
+  $include: "data.yaml"
+  local:
+    source: "local.ald"
+layout:
+  type: "dashboard"
+  sections:
+    - id: "header"
+    - !include "sections/chart.yaml"
+"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("data.yaml"),
+            "sales:\n  source: \"pacha://datasets/sales\"\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("sections/chart.yaml"),
+            "id: \"chart\"\nwidgets:\n  - type: \"chart\"\n    data: \"{{ data.sales }}\"\n",
+        )
+        .unwrap();
+
+        let manifest = Manifest::from_file(dir.join("app.yaml")).unwrap();
+
+        let ids: Vec<&str> = manifest
+            .layout
+            .sections
+            .iter()
+            .map(|s| s.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["header", "chart"]);
+        assert_eq!(manifest.layout.sections[1].widgets.len(), 1);
+        assert!(manifest.data.contains_key("sales"));
+        assert!(manifest.data.contains_key("local"));
+
+        let files = sources(&dir.join("app.yaml")).unwrap();
+        let names: Vec<&Path> = files
+            .iter()
+            .map(|file| file.strip_prefix(dir.canonicalize().unwrap()).unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                Path::new("app.yaml"),
+                Path::new("data.yaml"),
+                Path::new("sections/chart.yaml")
+            ]
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_include_sequence_is_spliced() {
+        let dir = temp_dir("splice");
+        fs::write(
+            dir.join("app.yaml"),
+            r#"
+presentar: "0.1"
+name: "split-app"
+version: "1.0.0"
+layout:
+  type: "dashboard"
+  sections:
+    - id: "first"
+    - $include: "sections/more.yaml"
+"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("sections/more.yaml"),
+            "- id: \"second\"\n- id: \"third\"\n",
+        )
+        .unwrap();
+
+        let manifest = Manifest::from_file(dir.join("app.yaml")).unwrap();
+        assert_eq!(manifest.layout.sections.len(), 3);
+        assert_eq!(manifest.layout.sections[2].id, "third");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_include_cycle_is_error() {
+        let dir = temp_dir("cycle");
+        fs::write(dir.join("a.yaml"), "child: !include \"b.yaml\"\n").unwrap();
+        fs::write(dir.join("b.yaml"), "child: !include \"a.yaml\"\n").unwrap();
+
+        let err = load(&dir.join("a.yaml")).unwrap_err();
+        assert!(matches!(err, ParseError::Include { .. }));
+        assert!(err.to_string().contains("include cycle"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_include_outside_manifest_directory_is_error() {
+        let dir = temp_dir("escape");
+        fs::write(dir.join("secret.yaml"), "token: hunter2\n").unwrap();
+        fs::write(
+            dir.join("sections/parent.yaml"),
+            "child: !include \"../secret.yaml\"\n",
+        )
+        .unwrap();
+        let absolute = dir.join("secret.yaml").display().to_string();
+        fs::write(
+            dir.join("sections/absolute.yaml"),
+            format!("child: !include {absolute:?}\n"),
+        )
+        .unwrap();
+
+        for file in ["parent.yaml", "absolute.yaml"] {
+            let err = load(&dir.join("sections").join(file)).unwrap_err();
+            assert!(matches!(err, ParseError::Include { .. }), "{file}: {err}");
+            assert!(err.to_string().contains("may not contain `..`"), "{file}");
+        }
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_include_symlink_outside_manifest_directory_is_error() {
+        let dir = temp_dir("symlink");
+        fs::write(dir.join("secret.yaml"), "token: hunter2\n").unwrap();
+        std::os::unix::fs::symlink(dir.join("secret.yaml"), dir.join("sections/link.yaml"))
+            .unwrap();
+        fs::write(
+            dir.join("sections/app.yaml"),
+            "child: !include \"link.yaml\"\n",
+        )
+        .unwrap();
+
+        let err = load(&dir.join("sections/app.yaml")).unwrap_err();
+        assert!(err.to_string().contains("outside the manifest directory"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_include_missing_file_is_error() {
+        let dir = temp_dir("missing");
+        fs::write(dir.join("a.yaml"), "child: !include \"nope.yaml\"\n").unwrap();
+
+        let err = load(&dir.join("a.yaml")).unwrap_err();
+        assert!(err.to_string().contains("nope.yaml"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
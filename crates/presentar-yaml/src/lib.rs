@@ -22,18 +22,24 @@
 #![allow(clippy::cloned_instead_of_copied)]
 //! YAML manifest parser for Presentar applications.
 
+mod datetime;
+mod env;
 mod error;
 mod executor;
 mod expression;
 pub mod formats;
+mod include;
 mod manifest;
 pub mod pacha;
+mod parquet;
 pub mod scene;
 
 pub use error::ParseError;
 pub use executor::{DataContext, ExecutionError, ExpressionExecutor, Value};
 pub use expression::{Expression, ExpressionError, ExpressionParser, Transform};
-pub use formats::{AldDataset, AprModel, DType, FormatError, ModelLayer, Tensor};
+pub use formats::{
+    AldDataset, AprModel, ColumnType, DType, Format, FormatError, ModelLayer, Records, Tensor,
+};
 pub use manifest::{DataSource, Manifest, ModelRef, Section, WidgetConfig};
 pub use pacha::{
     parse_refresh_interval, ContentType, LoadedResource, PachaError, PachaLoader, PachaUri,
@@ -1,5 +1,6 @@
 //! YAML manifest types for Presentar applications.
 
+use crate::error::ParseError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -194,6 +195,9 @@ pub struct ThemeConfig {
 impl Manifest {
     /// Parse a manifest from YAML string.
     ///
+    /// String values are taken verbatim; use [`Self::from_yaml_with_env`]
+    /// to substitute `${VAR}` references.
+    ///
     /// # Errors
     ///
     /// Returns an error if the YAML is invalid.
@@ -201,6 +205,72 @@ impl Manifest {
         serde_yaml_ng::from_str(yaml)
     }
 
+    /// Parse a manifest from YAML string, substituting `${VAR}` and
+    /// `${VAR:-default}` in string values with the variables `env` returns.
+    ///
+    /// Pass `|name| std::env::var(name).ok()` to read the process
+    /// environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the YAML is invalid or references an unset
+    /// variable without a default.
+    pub fn from_yaml_with_env(
+        yaml: &str,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, ParseError> {
+        // Deserialize directly when there is nothing to substitute so that
+        // schema errors keep their line/column locations.
+        if !yaml.contains("${") {
+            return Ok(serde_yaml_ng::from_str(yaml)?);
+        }
+        let mut value: serde_yaml_ng::Value = serde_yaml_ng::from_str(yaml)?;
+        crate::env::substitute(&mut value, &env)?;
+        Ok(serde_yaml_ng::from_value(value)?)
+    }
+
+    /// Load a manifest from a file, resolving `!include` / `$include`
+    /// directives relative to the including file and then substituting
+    /// variables from the process environment as in
+    /// [`Self::from_yaml_with_env`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read, the includes form a
+    /// cycle, a variable is unset, or the merged YAML is not a valid
+    /// manifest.
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, ParseError> {
+        Self::from_file_with_env(path, |name| std::env::var(name).ok())
+    }
+
+    /// [`Self::from_file`] with variables looked up through `env`.
+    ///
+    /// # Errors
+    ///
+    /// As for [`Self::from_file`].
+    pub fn from_file_with_env(
+        path: impl AsRef<std::path::Path>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, ParseError> {
+        let mut value = crate::include::load(path.as_ref())?;
+        crate::env::substitute(&mut value, &env)?;
+        Ok(serde_yaml_ng::from_value(value)?)
+    }
+
+    /// Canonical paths of the files [`Self::from_file`] reads for `path`:
+    /// the manifest itself, then each included file once, in the order the
+    /// includes are resolved.
+    ///
+    /// # Errors
+    ///
+    /// As for [`Self::from_file`], except that the merged YAML is not
+    /// checked against the manifest schema.
+    pub fn source_files(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Vec<std::path::PathBuf>, ParseError> {
+        crate::include::sources(path.as_ref())
+    }
+
     /// Serialize manifest to YAML string.
     ///
     /// # Errors
@@ -15,6 +15,7 @@
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 /// Pacha resource types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -156,6 +157,20 @@ pub enum PachaError {
     IoError(String),
     /// Unsupported format
     UnsupportedFormat(String),
+    /// Load did not finish within the configured timeout
+    Timeout {
+        /// Time spent before giving up (milliseconds)
+        elapsed_ms: u64,
+        /// Error from the last attempt
+        last: Box<Self>,
+    },
+    /// Every retry attempt failed
+    Exhausted {
+        /// Number of attempts made
+        attempts: u32,
+        /// Error from the last attempt
+        last: Box<Self>,
+    },
 }
 
 impl std::fmt::Display for PachaError {
@@ -167,6 +182,12 @@ impl std::fmt::Display for PachaError {
             Self::ParseError(msg) => write!(f, "Parse error: {msg}"),
             Self::IoError(msg) => write!(f, "IO error: {msg}"),
             Self::UnsupportedFormat(fmt) => write!(f, "Unsupported format: {fmt}"),
+            Self::Timeout { elapsed_ms, last } => {
+                write!(f, "Timed out after {elapsed_ms}ms: {last}")
+            }
+            Self::Exhausted { attempts, last } => {
+                write!(f, "Gave up after {attempts} attempts: {last}")
+            }
         }
     }
 }
@@ -179,10 +200,43 @@ pub struct PachaLoader {
     base_dir: PathBuf,
     /// Cache of loaded resources
     cache: HashMap<String, LoadedResource>,
+    /// HTTP client for remote URIs (None = local only)
+    client: Option<Box<dyn HttpClient>>,
+    /// Retry policy (None = single attempt)
+    retry: Option<RetryConfig>,
+    /// Time budget for one load, including retries
+    timeout: Option<Duration>,
+    /// Time source for timeouts and retry delays
+    clock: Box<dyn RetryClock>,
+}
+
+/// Time source for [`PachaLoader`] timeouts and retry delays.
+trait RetryClock: Send + Sync {
+    /// Current time.
+    fn now(&self) -> Instant;
+
+    /// Block for `delay`.
+    fn sleep(&self, delay: Duration);
+}
+
+/// Monotonic system time and real sleeps.
+struct SystemClock;
+
+impl RetryClock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, delay: Duration) {
+        sleep(delay);
+    }
 }
 
 /// A loaded resource.
+///
+/// Build one outside this crate with [`LoadedResource::new`].
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct LoadedResource {
     /// Resource URI
     pub uri: String,
@@ -192,6 +246,26 @@ pub struct LoadedResource {
     pub content_type: ContentType,
     /// Last modified timestamp
     pub last_modified: Option<u64>,
+    /// Entity tag from the server, used to revalidate the cached copy
+    pub etag: Option<String>,
+    /// True when a refresh was answered with `304 Not Modified` and this
+    /// is the previously cached copy
+    pub not_modified: bool,
+}
+
+impl LoadedResource {
+    /// Create a freshly loaded resource with no revalidation metadata.
+    #[must_use]
+    pub fn new(uri: impl Into<String>, data: Vec<u8>, content_type: ContentType) -> Self {
+        Self {
+            uri: uri.into(),
+            data,
+            content_type,
+            last_modified: None,
+            etag: None,
+            not_modified: false,
+        }
+    }
 }
 
 /// Content type of loaded resource.
@@ -242,6 +316,10 @@ impl PachaLoader {
         Self {
             base_dir,
             cache: HashMap::new(),
+            client: None,
+            retry: None,
+            timeout: None,
+            clock: Box::new(SystemClock),
         }
     }
 
@@ -251,6 +329,40 @@ impl PachaLoader {
         Self::new(std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")))
     }
 
+    /// Fetch remote `pacha://host/...` URIs over HTTP with this client.
+    #[must_use]
+    pub fn with_client(mut self, client: impl HttpClient + 'static) -> Self {
+        self.client = Some(Box::new(client));
+        self
+    }
+
+    /// Retry failed loads, making at most `max_attempts` attempts in total.
+    ///
+    /// Delays between attempts follow `backoff` (its own `max_attempts` is
+    /// ignored). Only transient failures are retried: connection and IO
+    /// errors and HTTP 5xx responses. Missing resources and other 4xx
+    /// responses fail immediately. If every attempt fails the error is
+    /// [`PachaError::Exhausted`] carrying the last failure.
+    #[must_use]
+    pub fn with_retry(mut self, max_attempts: u32, backoff: RetryConfig) -> Self {
+        self.retry = Some(RetryConfig {
+            max_attempts: max_attempts.max(1),
+            ..backoff
+        });
+        self
+    }
+
+    /// Give up on a load, retries included, once `timeout` has elapsed.
+    ///
+    /// The remaining budget is passed to the HTTP client as the request
+    /// timeout. Running out of time yields [`PachaError::Timeout`] carrying
+    /// the last failure.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Load a resource from a pacha:// URI.
     ///
     /// # Errors
@@ -263,7 +375,24 @@ impl PachaLoader {
         }
 
         let parsed = PachaUri::parse(uri)?;
-        let resource = self.load_uri(&parsed, uri)?;
+        let resource = self.load_uri(&parsed, uri, None)?;
+        self.cache.insert(uri.to_string(), resource);
+        Ok(self.cache.get(uri).unwrap())
+    }
+
+    /// Reload a resource, revalidating any cached copy.
+    ///
+    /// A remote resource cached with an `ETag` or `Last-Modified` is
+    /// requested with `If-None-Match` / `If-Modified-Since`. On
+    /// `304 Not Modified` the cached copy is kept and returned with
+    /// `not_modified` set, so refresh intervals only transfer changed data.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the resource cannot be loaded.
+    pub fn refresh(&mut self, uri: &str) -> Result<&LoadedResource, PachaError> {
+        let parsed = PachaUri::parse(uri)?;
+        let resource = self.load_uri(&parsed, uri, self.cache.get(uri))?;
         self.cache.insert(uri.to_string(), resource);
         Ok(self.cache.get(uri).unwrap())
     }
@@ -275,16 +404,87 @@ impl PachaLoader {
     /// Returns error if the resource cannot be loaded.
     pub fn load_fresh(&self, uri: &str) -> Result<LoadedResource, PachaError> {
         let parsed = PachaUri::parse(uri)?;
-        self.load_uri(&parsed, uri)
+        self.load_uri(&parsed, uri, None)
+    }
+
+    fn load_uri(
+        &self,
+        parsed: &PachaUri,
+        uri: &str,
+        cached: Option<&LoadedResource>,
+    ) -> Result<LoadedResource, PachaError> {
+        let max_attempts = self.retry.as_ref().map_or(1, |retry| retry.max_attempts);
+        let started = self.timeout.map(|_| self.clock.now());
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+            let remaining = self
+                .timeout
+                .zip(started)
+                .map(|(timeout, started)| timeout.saturating_sub(self.elapsed_since(started)));
+            let failure = match self.attempt(parsed, uri, cached, remaining) {
+                Ok(resource) => return Ok(resource),
+                Err(failure) if !failure.retryable => return Err(failure.error),
+                Err(failure) => failure,
+            };
+
+            if attempts >= max_attempts {
+                return Err(if max_attempts == 1 {
+                    failure.error
+                } else {
+                    PachaError::Exhausted {
+                        attempts,
+                        last: Box::new(failure.error),
+                    }
+                });
+            }
+
+            // Give up early if the backoff before the next attempt would
+            // run past the deadline.
+            let delay = Duration::from_millis(
+                self.retry
+                    .as_ref()
+                    .map_or(0, |retry| retry.delay_for_attempt(attempts)),
+            );
+            if let (Some(timeout), Some(started)) = (self.timeout, started) {
+                let elapsed = self.elapsed_since(started);
+                if elapsed + delay >= timeout {
+                    return Err(PachaError::Timeout {
+                        elapsed_ms: elapsed.as_millis() as u64,
+                        last: Box::new(failure.error),
+                    });
+                }
+            }
+            self.clock.sleep(delay);
+        }
+    }
+
+    fn elapsed_since(&self, started: Instant) -> Duration {
+        self.clock.now().saturating_duration_since(started)
     }
 
-    fn load_uri(&self, parsed: &PachaUri, uri: &str) -> Result<LoadedResource, PachaError> {
+    /// Make a single load attempt.
+    fn attempt(
+        &self,
+        parsed: &PachaUri,
+        uri: &str,
+        cached: Option<&LoadedResource>,
+        timeout: Option<Duration>,
+    ) -> Result<LoadedResource, Failure> {
         if parsed.is_remote() && parsed.host.as_deref() != Some("localhost") {
-            return Err(PachaError::ConnectionError(
-                "Remote Pacha servers not yet supported".to_string(),
-            ));
+            let Some(client) = &self.client else {
+                return Err(Failure::permanent(PachaError::ConnectionError(
+                    "Remote Pacha servers need an HTTP client (see PachaLoader::with_client)"
+                        .to_string(),
+                )));
+            };
+            return fetch_http(client.as_ref(), parsed, uri, cached, timeout);
         }
+        self.load_local(parsed, uri).map_err(Failure::classify)
+    }
 
+    fn load_local(&self, parsed: &PachaUri, uri: &str) -> Result<LoadedResource, PachaError> {
         // Load from local filesystem
         let path = parsed.to_local_path(&self.base_dir);
 
@@ -324,6 +524,8 @@ impl PachaLoader {
                     data,
                     content_type,
                     last_modified,
+                    etag: None,
+                    not_modified: false,
                 });
             }
         }
@@ -514,6 +716,134 @@ impl HttpClient for NoopHttpClient {
     }
 }
 
+/// A failed load attempt and whether trying again could help.
+struct Failure {
+    error: PachaError,
+    retryable: bool,
+}
+
+impl Failure {
+    const fn permanent(error: PachaError) -> Self {
+        Self {
+            error,
+            retryable: false,
+        }
+    }
+
+    const fn transient(error: PachaError) -> Self {
+        Self {
+            error,
+            retryable: true,
+        }
+    }
+
+    /// Connection and IO errors are transient; everything else is permanent.
+    fn classify(error: PachaError) -> Self {
+        let retryable = matches!(
+            error,
+            PachaError::ConnectionError(_) | PachaError::IoError(_)
+        );
+        Self { error, retryable }
+    }
+}
+
+/// Fetch a remote resource with a single HTTP request.
+///
+/// With a `cached` copy the request is conditional, and a `304` answer
+/// returns that copy.
+fn fetch_http(
+    client: &dyn HttpClient,
+    parsed: &PachaUri,
+    uri: &str,
+    cached: Option<&LoadedResource>,
+    timeout: Option<Duration>,
+) -> Result<LoadedResource, Failure> {
+    let url = http_url(parsed);
+    let mut req = HttpRequest::get(&url)
+        .with_header("Accept", "application/json, application/octet-stream, */*")
+        .with_header("User-Agent", "Presentar/0.1");
+    if let Some(timeout) = timeout {
+        req = req.with_timeout(timeout.as_millis() as u64);
+    }
+    if let Some(etag) = cached.and_then(|c| c.etag.as_deref()) {
+        req = req.with_header("If-None-Match", etag);
+    }
+    if let Some(modified) = cached.and_then(|c| c.last_modified) {
+        req = req.with_header(
+            "If-Modified-Since",
+            crate::datetime::format_http_date(modified as i64),
+        );
+    }
+
+    let response = client.request(req).map_err(Failure::classify)?;
+    match response.status {
+        304 => cached
+            .map(|cached| LoadedResource {
+                not_modified: true,
+                ..cached.clone()
+            })
+            .ok_or_else(|| {
+                Failure::permanent(PachaError::ConnectionError(
+                    "HTTP 304 without a cached copy".to_string(),
+                ))
+            }),
+        200..=299 => Ok(LoadedResource {
+            uri: uri.to_string(),
+            content_type: response.detect_content_type(),
+            last_modified: last_modified_header(&response),
+            etag: response.get_header("etag").map(str::to_string),
+            data: response.body,
+            not_modified: false,
+        }),
+        404 | 410 => Err(Failure::permanent(PachaError::NotFound(url))),
+        500..=599 => Err(Failure::transient(PachaError::ConnectionError(format!(
+            "HTTP {} error",
+            response.status
+        )))),
+        status => Err(Failure::permanent(PachaError::ConnectionError(format!(
+            "HTTP {status} error"
+        )))),
+    }
+}
+
+/// `Last-Modified` response header as seconds since the epoch.
+fn last_modified_header(response: &HttpResponse) -> Option<u64> {
+    response
+        .get_header("last-modified")
+        .and_then(crate::datetime::parse_http_date)
+        .and_then(|secs| u64::try_from(secs).ok())
+}
+
+/// Build the HTTP URL for a remote Pacha URI.
+fn http_url(parsed: &PachaUri) -> String {
+    let scheme = "https"; // Default to HTTPS for remote
+    let host = parsed.host.as_deref().unwrap_or("localhost");
+    let port = parsed.port.map_or(String::new(), |p| format!(":{p}"));
+
+    let mut url = format!("{scheme}://{host}{port}{}", parsed.path);
+
+    if !parsed.query.is_empty() {
+        let query: Vec<String> = parsed
+            .query
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect();
+        url.push('?');
+        url.push_str(&query.join("&"));
+    }
+
+    url
+}
+
+/// Block between retry attempts. Browsers cannot block the main thread, so
+/// on WASM retries run back to back.
+fn sleep(delay: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    std::thread::sleep(delay);
+    #[cfg(target_arch = "wasm32")]
+    let _ = delay;
+}
+
 /// Retry configuration for HTTP requests.
 #[derive(Debug, Clone)]
 pub struct RetryConfig {
@@ -644,9 +974,11 @@ impl<C: HttpClient> RemotePachaLoader<C> {
                     let content_type = response.detect_content_type();
                     return Ok(LoadedResource {
                         uri: uri.to_string(),
+                        last_modified: last_modified_header(&response),
+                        etag: response.get_header("etag").map(str::to_string),
                         data: response.body,
                         content_type,
-                        last_modified: None, // Could parse from headers
+                        not_modified: false,
                     });
                 }
                 Ok(response) => {
@@ -668,23 +1000,7 @@ impl<C: HttpClient> RemotePachaLoader<C> {
 
     /// Build HTTP URL from parsed Pacha URI.
     fn build_http_url(&self, parsed: &PachaUri) -> String {
-        let scheme = "https"; // Default to HTTPS for remote
-        let host = parsed.host.as_deref().unwrap_or("localhost");
-        let port = parsed.port.map_or(String::new(), |p| format!(":{p}"));
-
-        let mut url = format!("{scheme}://{host}{port}{}", parsed.path);
-
-        if !parsed.query.is_empty() {
-            let query: Vec<String> = parsed
-                .query
-                .iter()
-                .map(|(k, v)| format!("{k}={v}"))
-                .collect();
-            url.push('?');
-            url.push_str(&query.join("&"));
-        }
-
-        url
+        http_url(parsed)
     }
 
     /// Clear the cache.
@@ -1330,6 +1646,8 @@ mod tests {
             data: vec![1, 2, 3],
             content_type: ContentType::Json,
             last_modified: Some(12345),
+            etag: None,
+            not_modified: false,
         };
         let cloned = resource;
         assert_eq!(cloned.uri, "pacha://data/test");
@@ -1338,6 +1656,17 @@ mod tests {
         assert_eq!(cloned.last_modified, Some(12345));
     }
 
+    #[test]
+    fn test_loaded_resource_new() {
+        let resource = LoadedResource::new("pacha://data/test", vec![7], ContentType::Csv);
+        assert_eq!(resource.uri, "pacha://data/test");
+        assert_eq!(resource.data, vec![7]);
+        assert_eq!(resource.content_type, ContentType::Csv);
+        assert!(resource.last_modified.is_none());
+        assert!(resource.etag.is_none());
+        assert!(!resource.not_modified);
+    }
+
     #[test]
     fn test_content_type_clone() {
         let ct = ContentType::Csv;
@@ -1481,4 +1810,294 @@ mod tests {
         // Should attempt max_attempts times
         assert_eq!(attempts.load(Ordering::SeqCst), 3);
     }
+
+    // =========================================================================
+    // PachaLoader retry and timeout tests
+    // =========================================================================
+
+    type Script = fn(u32, &HttpRequest) -> Result<HttpResponse, PachaError>;
+
+    /// Client that answers each request with `respond(attempt, &req)`.
+    struct ScriptedClient {
+        attempts: std::sync::Arc<std::sync::atomic::AtomicU32>,
+        respond: Script,
+    }
+
+    impl HttpClient for ScriptedClient {
+        fn request(&self, req: HttpRequest) -> Result<HttpResponse, PachaError> {
+            let attempt = self
+                .attempts
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+                + 1;
+            (self.respond)(attempt, &req)
+        }
+    }
+
+    /// Clock that only moves when the loader sleeps.
+    struct ManualClock {
+        start: Instant,
+        elapsed: std::sync::Mutex<Duration>,
+    }
+
+    impl RetryClock for ManualClock {
+        fn now(&self) -> Instant {
+            self.start + *self.elapsed.lock().unwrap()
+        }
+
+        fn sleep(&self, delay: Duration) {
+            *self.elapsed.lock().unwrap() += delay;
+        }
+    }
+
+    fn scripted_loader(
+        respond: Script,
+    ) -> (PachaLoader, std::sync::Arc<std::sync::atomic::AtomicU32>) {
+        let attempts = std::sync::Arc::default();
+        let loader = PachaLoader::new(PathBuf::from(".")).with_client(ScriptedClient {
+            attempts: std::sync::Arc::clone(&attempts),
+            respond,
+        });
+        (loader, attempts)
+    }
+
+    fn no_delay() -> RetryConfig {
+        RetryConfig {
+            initial_delay_ms: 0,
+            max_delay_ms: 0,
+            ..RetryConfig::default()
+        }
+    }
+
+    fn refused(_attempt: u32, _req: &HttpRequest) -> Result<HttpResponse, PachaError> {
+        Err(PachaError::ConnectionError(
+            "connection refused".to_string(),
+        ))
+    }
+
+    #[test]
+    fn test_loader_retry_exhausts_attempts() {
+        let (loader, attempts) = scripted_loader(refused);
+        let loader = loader.with_retry(4, no_delay());
+
+        let err = loader
+            .load_fresh("pacha://flaky.example.com:9000/data/metrics")
+            .unwrap_err();
+
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 4);
+        match err {
+            PachaError::Exhausted { attempts, last } => {
+                assert_eq!(attempts, 4);
+                assert!(matches!(*last, PachaError::ConnectionError(_)));
+            }
+            other => panic!("expected Exhausted, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_loader_retry_recovers_from_transient_failure() {
+        let (loader, attempts) = scripted_loader(|attempt, _| {
+            if attempt < 3 {
+                return Ok(HttpResponse {
+                    status: 503,
+                    headers: HashMap::new(),
+                    body: vec![],
+                });
+            }
+            Ok(HttpResponse {
+                status: 200,
+                headers: HashMap::from([(
+                    "Content-Type".to_string(),
+                    "application/json".to_string(),
+                )]),
+                body: b"{}".to_vec(),
+            })
+        });
+        let mut loader = loader.with_retry(5, no_delay());
+
+        let resource = loader
+            .load("pacha://flaky.example.com:9000/data/metrics")
+            .unwrap();
+
+        assert_eq!(resource.content_type, ContentType::Json);
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_loader_retry_skips_permanent_errors() {
+        let (loader, attempts) = scripted_loader(|_, _| {
+            Ok(HttpResponse {
+                status: 404,
+                headers: HashMap::new(),
+                body: vec![],
+            })
+        });
+        let loader = loader.with_retry(5, no_delay());
+
+        let err = loader
+            .load_fresh("pacha://registry.example.com:9000/data/missing")
+            .unwrap_err();
+
+        assert!(matches!(err, PachaError::NotFound(_)));
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_loader_single_attempt_returns_raw_error() {
+        let (loader, attempts) = scripted_loader(refused);
+
+        let err = loader
+            .load_fresh("pacha://flaky.example.com:9000/data/metrics")
+            .unwrap_err();
+
+        assert!(matches!(err, PachaError::ConnectionError(_)));
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_loader_timeout_stops_retrying() {
+        let (loader, attempts) = scripted_loader(refused);
+        let backoff = RetryConfig {
+            initial_delay_ms: 20,
+            max_delay_ms: 20,
+            backoff_multiplier: 1.0,
+            ..RetryConfig::default()
+        };
+        let mut loader = loader
+            .with_retry(1000, backoff)
+            .with_timeout(Duration::from_millis(70));
+        loader.clock = Box::new(ManualClock {
+            start: Instant::now(),
+            elapsed: std::sync::Mutex::default(),
+        });
+
+        let err = loader
+            .load_fresh("pacha://flaky.example.com:9000/data/metrics")
+            .unwrap_err();
+
+        // Attempts at 0, 20, 40 and 60ms; another 20ms delay would pass 70ms.
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 4);
+        match err {
+            PachaError::Timeout { elapsed_ms, last } => {
+                assert_eq!(elapsed_ms, 60);
+                assert!(matches!(*last, PachaError::ConnectionError(_)));
+            }
+            other => panic!("expected Timeout, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_loader_final_attempt_reports_exhausted_before_timeout() {
+        let (loader, attempts) = scripted_loader(refused);
+        let backoff = RetryConfig {
+            initial_delay_ms: 20,
+            max_delay_ms: 20,
+            backoff_multiplier: 1.0,
+            ..RetryConfig::default()
+        };
+        let mut loader = loader
+            .with_retry(3, backoff)
+            .with_timeout(Duration::from_millis(50));
+        loader.clock = Box::new(ManualClock {
+            start: Instant::now(),
+            elapsed: std::sync::Mutex::default(),
+        });
+
+        let err = loader
+            .load_fresh("pacha://flaky.example.com:9000/data/metrics")
+            .unwrap_err();
+
+        // The third attempt at 40ms is the last; no further delay is owed.
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+        assert!(matches!(err, PachaError::Exhausted { attempts: 3, .. }));
+    }
+
+    #[test]
+    fn test_loader_single_attempt_with_timeout_returns_raw_error() {
+        let (loader, _) = scripted_loader(refused);
+        let loader = loader.with_timeout(Duration::ZERO);
+
+        let err = loader
+            .load_fresh("pacha://flaky.example.com:9000/data/metrics")
+            .unwrap_err();
+
+        assert!(matches!(err, PachaError::ConnectionError(_)));
+    }
+
+    #[test]
+    fn test_retry_errors_display_last_failure() {
+        let err = PachaError::Exhausted {
+            attempts: 3,
+            last: Box::new(PachaError::ConnectionError("refused".to_string())),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Gave up after 3 attempts: Connection error: refused"
+        );
+    }
+
+    #[test]
+    fn test_loader_refresh_not_modified_returns_cached() {
+        let (loader, attempts) = scripted_loader(|attempt, req| {
+            if attempt == 1 {
+                assert!(!req.headers.contains_key("If-None-Match"));
+                return Ok(HttpResponse {
+                    status: 200,
+                    headers: HashMap::from([
+                        ("ETag".to_string(), "\"v1\"".to_string()),
+                        (
+                            "Last-Modified".to_string(),
+                            "Sun, 06 Nov 1994 08:49:37 GMT".to_string(),
+                        ),
+                    ]),
+                    body: b"original".to_vec(),
+                });
+            }
+            assert_eq!(req.headers["If-None-Match"], "\"v1\"");
+            assert_eq!(
+                req.headers["If-Modified-Since"],
+                "Sun, 06 Nov 1994 08:49:37 GMT"
+            );
+            Ok(HttpResponse {
+                status: 304,
+                headers: HashMap::new(),
+                body: vec![],
+            })
+        });
+        let mut loader = loader;
+        let uri = "pacha://registry.example.com:9000/data/large";
+
+        let first = loader.load(uri).unwrap();
+        assert!(!first.not_modified);
+        assert_eq!(first.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(first.last_modified, Some(784_111_777));
+
+        let refreshed = loader.refresh(uri).unwrap();
+        assert!(refreshed.not_modified);
+        assert_eq!(refreshed.data, b"original");
+        assert_eq!(refreshed.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_loader_refresh_replaces_changed_resource() {
+        let (loader, _) = scripted_loader(|attempt, _| {
+            Ok(HttpResponse {
+                status: 200,
+                headers: HashMap::from([("ETag".to_string(), format!("\"v{attempt}\""))]),
+                body: format!("version {attempt}").into_bytes(),
+            })
+        });
+        let mut loader = loader;
+        let uri = "pacha://registry.example.com:9000/data/large";
+
+        loader.load(uri).unwrap();
+        let refreshed = loader.refresh(uri).unwrap();
+
+        assert!(!refreshed.not_modified);
+        assert_eq!(refreshed.data, b"version 2");
+        assert_eq!(
+            loader.get_cached(uri).unwrap().etag.as_deref(),
+            Some("\"v2\"")
+        );
+    }
 }
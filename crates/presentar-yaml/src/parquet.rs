@@ -0,0 +1,1728 @@
+//! Minimal Parquet reader producing [`AldDataset`]s.
+//!
+//! Supports flat schemas (no nested or repeated fields) with `BOOLEAN`,
+//! `INT32`, `INT64`, `FLOAT`, `DOUBLE`, `BYTE_ARRAY` and
+//! `FIXED_LEN_BYTE_ARRAY` columns; `PLAIN` and dictionary encodings; v1 and
+//! v2 data pages; and uncompressed or Snappy-compressed chunks.
+//!
+//! Each column becomes one tensor named after the column:
+//!
+//! | Parquet type            | Tensor                                    |
+//! |-------------------------|-------------------------------------------|
+//! | `BOOLEAN`               | `U8` `[rows]`, 0 or 1                     |
+//! | `INT32` / `INT64`       | `I32` / `I64` `[rows]`                    |
+//! | `FLOAT` / `DOUBLE`      | `F32` / `F64` `[rows]`                    |
+//! | `BYTE_ARRAY`, fixed len | `U8` `[rows, width]`, zero-padded bytes   |
+//!
+//! Each optional column is followed by a `U8` `[rows]` tensor named
+//! `<column>.valid` holding 1 where the row has a value and 0 where it is
+//! null. Null slots in the value tensor hold `NaN` for floats, zero for
+//! integers and booleans, and an empty string for byte arrays, so the mask is
+//! what tells a null apart from a stored zero or `""`.
+
+use crate::formats::{AldDataset, DType, FormatError, Tensor};
+use std::borrow::Cow;
+
+/// Magic bytes at both ends of a Parquet file.
+const PARQUET_MAGIC: &[u8; 4] = b"PAR1";
+
+// Page types
+const DATA_PAGE: i32 = 0;
+const INDEX_PAGE: i32 = 1;
+const DICTIONARY_PAGE: i32 = 2;
+const DATA_PAGE_V2: i32 = 3;
+
+// Encodings
+const PLAIN: i32 = 0;
+const PLAIN_DICTIONARY: i32 = 2;
+const RLE: i32 = 3;
+const RLE_DICTIONARY: i32 = 8;
+
+// Compression codecs
+const UNCOMPRESSED: i32 = 0;
+const SNAPPY: i32 = 1;
+
+// Repetition types
+const OPTIONAL: i32 = 1;
+const REPEATED: i32 = 2;
+
+/// Read a Parquet file into a dataset with one tensor per column.
+///
+/// # Errors
+///
+/// Returns [`FormatError::InvalidMagic`] if the file is not Parquet,
+/// [`FormatError::Unsupported`] for nested schemas, unsupported types,
+/// encodings or codecs, and [`FormatError::InvalidParquet`] when the
+/// metadata and column chunks disagree.
+pub fn read_parquet(bytes: &[u8]) -> Result<AldDataset, FormatError> {
+    let len = bytes.len();
+    if len < 12 || &bytes[..4] != PARQUET_MAGIC || &bytes[len - 4..] != PARQUET_MAGIC {
+        return Err(FormatError::InvalidMagic);
+    }
+    let footer = len - 8;
+    let meta_len = u32::from_le_bytes([
+        bytes[footer],
+        bytes[footer + 1],
+        bytes[footer + 2],
+        bytes[footer + 3],
+    ]) as usize;
+    let meta_start = footer
+        .checked_sub(meta_len)
+        .filter(|&start| start >= 4)
+        .ok_or(FormatError::TruncatedData)?;
+    let meta = FileMeta::read(&mut CompactReader::new(&bytes[meta_start..footer]))?;
+
+    let leaves = leaf_columns(&meta.schema)?;
+    let mut columns: Vec<Column> = leaves
+        .iter()
+        .map(|leaf| Column::new(leaf.physical))
+        .collect();
+    let mut masks: Vec<Option<Vec<u8>>> = leaves
+        .iter()
+        .map(|leaf| leaf.optional.then(Vec::new))
+        .collect();
+
+    for group in &meta.row_groups {
+        if group.columns.len() != leaves.len() {
+            return Err(FormatError::InvalidParquet(format!(
+                "row group has {} column chunks but the schema has {} columns",
+                group.columns.len(),
+                leaves.len()
+            )));
+        }
+        for (((leaf, chunk), column), mask) in leaves
+            .iter()
+            .zip(&group.columns)
+            .zip(&mut columns)
+            .zip(&mut masks)
+        {
+            if chunk.path.len() != 1 || chunk.path[0] != leaf.name {
+                return Err(FormatError::InvalidParquet(format!(
+                    "column chunk `{}` does not match schema column `{}`",
+                    chunk.path.join("."),
+                    leaf.name
+                )));
+            }
+            if chunk.physical != leaf.physical_code {
+                return Err(FormatError::InvalidParquet(format!(
+                    "column `{}` chunk has type {} but the schema declares {}",
+                    leaf.name,
+                    type_name(chunk.physical),
+                    type_name(leaf.physical_code)
+                )));
+            }
+            let before = column.len();
+            read_chunk(bytes, leaf, chunk, column, mask.as_mut())?;
+            if column.len() - before != group.num_rows as usize {
+                return Err(FormatError::InvalidParquet(format!(
+                    "column `{}` has {} values in a row group of {} rows",
+                    leaf.name,
+                    column.len() - before,
+                    group.num_rows
+                )));
+            }
+        }
+    }
+
+    let mut dataset = AldDataset::new();
+    for ((leaf, column), mask) in leaves.iter().zip(columns).zip(masks) {
+        if i64::try_from(column.len()).ok() != Some(meta.num_rows) {
+            return Err(FormatError::InvalidParquet(format!(
+                "column `{}` has {} rows, file declares {}",
+                leaf.name,
+                column.len(),
+                meta.num_rows
+            )));
+        }
+        let rows = u32::try_from(column.len()).map_err(|_| {
+            FormatError::Unsupported(format!(
+                "column `{}` has more rows than a tensor can hold",
+                leaf.name
+            ))
+        })?;
+        dataset.add_tensor(column.into_tensor(&leaf.name, rows)?);
+        if let Some(mask) = mask {
+            dataset.add_tensor(Tensor::new(
+                format!("{}.valid", leaf.name),
+                DType::U8,
+                vec![rows],
+                mask,
+            ));
+        }
+    }
+    Ok(dataset)
+}
+
+// =============================================================================
+// Schema
+// =============================================================================
+
+/// Physical storage type of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Physical {
+    Boolean,
+    Int32,
+    Int64,
+    Float,
+    Double,
+    ByteArray,
+    FixedLenByteArray(usize),
+}
+
+/// A flat (leaf) column of the schema.
+struct Leaf {
+    name: String,
+    physical: Physical,
+    physical_code: i32,
+    optional: bool,
+}
+
+fn leaf_columns(schema: &[SchemaElement]) -> Result<Vec<Leaf>, FormatError> {
+    let Some((root, fields)) = schema.split_first() else {
+        return Err(FormatError::InvalidParquet("empty schema".to_string()));
+    };
+    if root.num_children as usize != fields.len() {
+        return Err(FormatError::Unsupported(
+            "nested Parquet schemas (only flat columns are read)".to_string(),
+        ));
+    }
+
+    fields
+        .iter()
+        .map(|field| {
+            if field.num_children > 0 {
+                return Err(FormatError::Unsupported(format!(
+                    "nested column `{}`",
+                    field.name
+                )));
+            }
+            if field.repetition == REPEATED {
+                return Err(FormatError::Unsupported(format!(
+                    "repeated column `{}`",
+                    field.name
+                )));
+            }
+            let code = field.physical.ok_or_else(|| {
+                FormatError::InvalidParquet(format!("column `{}` has no type", field.name))
+            })?;
+            let physical = match code {
+                0 => Physical::Boolean,
+                1 => Physical::Int32,
+                2 => Physical::Int64,
+                4 => Physical::Float,
+                5 => Physical::Double,
+                6 => Physical::ByteArray,
+                7 if field.type_length > 0 => {
+                    Physical::FixedLenByteArray(field.type_length as usize)
+                }
+                3 => {
+                    return Err(FormatError::Unsupported(format!(
+                        "INT96 column `{}`",
+                        field.name
+                    )))
+                }
+                _ => {
+                    return Err(FormatError::InvalidParquet(format!(
+                        "column `{}` has invalid type {code}",
+                        field.name
+                    )))
+                }
+            };
+            Ok(Leaf {
+                name: field.name.clone(),
+                physical,
+                physical_code: code,
+                optional: field.repetition == OPTIONAL,
+            })
+        })
+        .collect()
+}
+
+const fn type_name(code: i32) -> &'static str {
+    match code {
+        0 => "BOOLEAN",
+        1 => "INT32",
+        2 => "INT64",
+        3 => "INT96",
+        4 => "FLOAT",
+        5 => "DOUBLE",
+        6 => "BYTE_ARRAY",
+        7 => "FIXED_LEN_BYTE_ARRAY",
+        _ => "UNKNOWN",
+    }
+}
+
+const fn encoding_name(code: i32) -> &'static str {
+    match code {
+        0 => "PLAIN",
+        2 => "PLAIN_DICTIONARY",
+        3 => "RLE",
+        4 => "BIT_PACKED",
+        5 => "DELTA_BINARY_PACKED",
+        6 => "DELTA_LENGTH_BYTE_ARRAY",
+        7 => "DELTA_BYTE_ARRAY",
+        8 => "RLE_DICTIONARY",
+        9 => "BYTE_STREAM_SPLIT",
+        _ => "UNKNOWN",
+    }
+}
+
+const fn codec_name(code: i32) -> &'static str {
+    match code {
+        0 => "UNCOMPRESSED",
+        1 => "SNAPPY",
+        2 => "GZIP",
+        3 => "LZO",
+        4 => "BROTLI",
+        5 => "LZ4",
+        6 => "ZSTD",
+        7 => "LZ4_RAW",
+        _ => "UNKNOWN",
+    }
+}
+
+// =============================================================================
+// Column data
+// =============================================================================
+
+/// Decoded values of one column.
+enum Column {
+    Boolean(Vec<u8>),
+    Int32(Vec<i32>),
+    Int64(Vec<i64>),
+    Float(Vec<f32>),
+    Double(Vec<f64>),
+    Bytes(Vec<Vec<u8>>),
+}
+
+impl Column {
+    const fn new(physical: Physical) -> Self {
+        match physical {
+            Physical::Boolean => Self::Boolean(Vec::new()),
+            Physical::Int32 => Self::Int32(Vec::new()),
+            Physical::Int64 => Self::Int64(Vec::new()),
+            Physical::Float => Self::Float(Vec::new()),
+            Physical::Double => Self::Double(Vec::new()),
+            Physical::ByteArray | Physical::FixedLenByteArray(_) => Self::Bytes(Vec::new()),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Boolean(v) => v.len(),
+            Self::Int32(v) => v.len(),
+            Self::Int64(v) => v.len(),
+            Self::Float(v) => v.len(),
+            Self::Double(v) => v.len(),
+            Self::Bytes(v) => v.len(),
+        }
+    }
+
+    /// Decode `count` PLAIN-encoded values.
+    fn decode_plain(physical: Physical, data: &[u8], count: usize) -> Result<Self, FormatError> {
+        let fixed = |width: usize| plain_chunks(data, count, width);
+
+        Ok(match physical {
+            Physical::Boolean => {
+                let bytes = data
+                    .get(..count.div_ceil(8))
+                    .ok_or(FormatError::TruncatedData)?;
+                Self::Boolean((0..count).map(|i| (bytes[i / 8] >> (i % 8)) & 1).collect())
+            }
+            Physical::Int32 => Self::Int32(
+                fixed(4)?
+                    .map(|c| i32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+                    .collect(),
+            ),
+            Physical::Int64 => Self::Int64(
+                fixed(8)?
+                    .map(|c| i64::from_le_bytes(c.try_into().expect("chunk size")))
+                    .collect(),
+            ),
+            Physical::Float => Self::Float(
+                fixed(4)?
+                    .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+                    .collect(),
+            ),
+            Physical::Double => Self::Double(
+                fixed(8)?
+                    .map(|c| f64::from_le_bytes(c.try_into().expect("chunk size")))
+                    .collect(),
+            ),
+            Physical::FixedLenByteArray(width) => {
+                Self::Bytes(fixed(width)?.map(<[u8]>::to_vec).collect())
+            }
+            Physical::ByteArray => {
+                let mut reader = CompactReader::new(data);
+                let mut values = Vec::with_capacity(count.min(data.len() / 4));
+                for _ in 0..count {
+                    let len = reader.bytes(4)?;
+                    let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize;
+                    values.push(reader.bytes(len)?.to_vec());
+                }
+                Self::Bytes(values)
+            }
+        })
+    }
+
+    /// Append `src[index]`.
+    fn push_from(&mut self, src: &Self, index: usize) -> Result<(), FormatError> {
+        let out_of_range =
+            || FormatError::InvalidParquet(format!("value index {index} out of range"));
+        match (self, src) {
+            (Self::Boolean(out), Self::Boolean(src)) => {
+                out.push(*src.get(index).ok_or_else(out_of_range)?);
+            }
+            (Self::Int32(out), Self::Int32(src)) => {
+                out.push(*src.get(index).ok_or_else(out_of_range)?);
+            }
+            (Self::Int64(out), Self::Int64(src)) => {
+                out.push(*src.get(index).ok_or_else(out_of_range)?);
+            }
+            (Self::Float(out), Self::Float(src)) => {
+                out.push(*src.get(index).ok_or_else(out_of_range)?);
+            }
+            (Self::Double(out), Self::Double(src)) => {
+                out.push(*src.get(index).ok_or_else(out_of_range)?);
+            }
+            (Self::Bytes(out), Self::Bytes(src)) => {
+                out.push(src.get(index).ok_or_else(out_of_range)?.clone());
+            }
+            _ => {
+                return Err(FormatError::InvalidParquet(
+                    "page values do not match the column type".to_string(),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Append a null.
+    fn push_null(&mut self) {
+        match self {
+            Self::Boolean(v) => v.push(0),
+            Self::Int32(v) => v.push(0),
+            Self::Int64(v) => v.push(0),
+            Self::Float(v) => v.push(f32::NAN),
+            Self::Double(v) => v.push(f64::NAN),
+            Self::Bytes(v) => v.push(Vec::new()),
+        }
+    }
+
+    fn into_tensor(self, name: &str, rows: u32) -> Result<Tensor, FormatError> {
+        Ok(match self {
+            Self::Boolean(v) => Tensor::new(name, DType::U8, vec![rows], v),
+            Self::Int32(v) => Tensor::new(
+                name,
+                DType::I32,
+                vec![rows],
+                v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            ),
+            Self::Int64(v) => Tensor::new(
+                name,
+                DType::I64,
+                vec![rows],
+                v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            ),
+            Self::Float(v) => Tensor::new(
+                name,
+                DType::F32,
+                vec![rows],
+                v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            ),
+            Self::Double(v) => Tensor::new(
+                name,
+                DType::F64,
+                vec![rows],
+                v.iter().flat_map(|x| x.to_le_bytes()).collect(),
+            ),
+            Self::Bytes(v) => {
+                let width = v.iter().map(Vec::len).max().unwrap_or(0);
+                let too_large = || {
+                    FormatError::Unsupported(format!("column `{name}` is too large for a tensor"))
+                };
+                let size = v.len().checked_mul(width).ok_or_else(too_large)?;
+                let mut data = vec![0u8; size];
+                for (row, value) in data.chunks_exact_mut(width.max(1)).zip(&v) {
+                    row[..value.len()].copy_from_slice(value);
+                }
+                let width = u32::try_from(width).map_err(|_| too_large())?;
+                Tensor::new(name, DType::U8, vec![rows, width], data)
+            }
+        })
+    }
+}
+
+/// The first `count` fixed-width values of a PLAIN page.
+fn plain_chunks(
+    data: &[u8],
+    count: usize,
+    width: usize,
+) -> Result<std::slice::ChunksExact<'_, u8>, FormatError> {
+    let needed = count.checked_mul(width).ok_or(FormatError::TruncatedData)?;
+    let bytes = data.get(..needed).ok_or(FormatError::TruncatedData)?;
+    Ok(bytes.chunks_exact(width))
+}
+
+// =============================================================================
+// Pages
+// =============================================================================
+
+fn read_chunk(
+    file: &[u8],
+    leaf: &Leaf,
+    chunk: &ColumnMeta,
+    column: &mut Column,
+    mut mask: Option<&mut Vec<u8>>,
+) -> Result<(), FormatError> {
+    let start = chunk
+        .dictionary_page_offset
+        .filter(|&offset| offset > 0 && offset < chunk.data_page_offset)
+        .unwrap_or(chunk.data_page_offset);
+    let mut reader = CompactReader::new(file);
+    reader.pos = usize::try_from(start).map_err(|_| FormatError::TruncatedData)?;
+
+    let mut dictionary: Option<Column> = None;
+    let mut remaining = chunk.num_values;
+    while remaining > 0 {
+        let header = PageHeader::read(&mut reader)?;
+        let page = reader.bytes(usize::try_from(header.compressed_size).unwrap_or(usize::MAX))?;
+        match header.page_type {
+            DICTIONARY_PAGE => {
+                let data = decompress(chunk.codec, page, header.uncompressed_size)?;
+                dictionary = Some(Column::decode_plain(
+                    leaf.physical,
+                    &data,
+                    header.num_values as usize,
+                )?);
+            }
+            DATA_PAGE | DATA_PAGE_V2 => {
+                read_data_page(
+                    &header,
+                    page,
+                    leaf,
+                    chunk.codec,
+                    dictionary.as_ref(),
+                    column,
+                    mask.as_deref_mut(),
+                )?;
+                remaining -= i64::from(header.num_values.max(1));
+            }
+            INDEX_PAGE => {}
+            other => {
+                return Err(FormatError::InvalidParquet(format!(
+                    "unknown page type {other} in column `{}`",
+                    leaf.name
+                )))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_data_page(
+    header: &PageHeader,
+    page: &[u8],
+    leaf: &Leaf,
+    codec: i32,
+    dictionary: Option<&Column>,
+    column: &mut Column,
+    mask: Option<&mut Vec<u8>>,
+) -> Result<(), FormatError> {
+    if header.page_type == DATA_PAGE_V2 {
+        // v2: uncompressed repetition and definition levels precede the values.
+        let rep_len = usize::try_from(header.rep_levels_len).unwrap_or(0);
+        let def_len = usize::try_from(header.def_levels_len).unwrap_or(0);
+        let levels_end = rep_len.saturating_add(def_len);
+        let levels = page
+            .get(rep_len..levels_end)
+            .ok_or(FormatError::TruncatedData)?;
+        let rest = &page[levels_end..];
+        let values = if header.is_compressed {
+            let size = header
+                .uncompressed_size
+                .saturating_sub(i32::try_from(levels_end).unwrap_or(i32::MAX));
+            decompress(codec, rest, size)?
+        } else {
+            Cow::Borrowed(rest)
+        };
+        return decode_values(header, levels, &values, leaf, dictionary, column, mask);
+    }
+
+    let data = decompress(codec, page, header.uncompressed_size)?;
+    let (levels, values) = if leaf.optional {
+        if header.def_encoding != RLE {
+            return Err(FormatError::Unsupported(format!(
+                "{} definition levels in column `{}`",
+                encoding_name(header.def_encoding),
+                leaf.name
+            )));
+        }
+        // v1: definition levels carry a 4-byte length prefix.
+        let len = data.get(..4).ok_or(FormatError::TruncatedData)?;
+        let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize;
+        let end = len
+            .checked_add(4)
+            .filter(|&end| end <= data.len())
+            .ok_or(FormatError::TruncatedData)?;
+        (&data[4..end], &data[end..])
+    } else {
+        (&[][..], &data[..])
+    };
+    decode_values(header, levels, values, leaf, dictionary, column, mask)
+}
+
+fn decode_values(
+    header: &PageHeader,
+    levels: &[u8],
+    values: &[u8],
+    leaf: &Leaf,
+    dictionary: Option<&Column>,
+    column: &mut Column,
+    mut mask: Option<&mut Vec<u8>>,
+) -> Result<(), FormatError> {
+    let count = usize::try_from(header.num_values).unwrap_or(0);
+    // Required columns have no levels; decoding the values below checks
+    // `count` against the page before anything is sized by it.
+    let defined: Option<Vec<bool>> = if leaf.optional {
+        Some(
+            decode_hybrid(levels, 1, count)?
+                .into_iter()
+                .map(|level| level == 1)
+                .collect(),
+        )
+    } else {
+        None
+    };
+    let present = defined
+        .as_ref()
+        .map_or(count, |defined| defined.iter().filter(|&&d| d).count());
+
+    let plain;
+    let (source, indices) = match header.encoding {
+        PLAIN => {
+            plain = Column::decode_plain(leaf.physical, values, present)?;
+            (&plain, None)
+        }
+        PLAIN_DICTIONARY | RLE_DICTIONARY => {
+            let dictionary = dictionary.ok_or_else(|| {
+                FormatError::InvalidParquet(format!(
+                    "column `{}` has dictionary-encoded data but no dictionary page",
+                    leaf.name
+                ))
+            })?;
+            let (&bit_width, encoded) = values.split_first().ok_or(FormatError::TruncatedData)?;
+            let indices = decode_hybrid(encoded, bit_width, present)?;
+            (dictionary, Some(indices))
+        }
+        other => {
+            return Err(FormatError::Unsupported(format!(
+                "{} encoding in column `{}`",
+                encoding_name(other),
+                leaf.name
+            )))
+        }
+    };
+
+    let mut next = 0;
+    for row in 0..count {
+        let is_defined = defined.as_ref().map_or(true, |defined| defined[row]);
+        if let Some(mask) = mask.as_deref_mut() {
+            mask.push(u8::from(is_defined));
+        }
+        if is_defined {
+            let index = indices.as_ref().map_or(next, |ix| ix[next] as usize);
+            column.push_from(source, index)?;
+            next += 1;
+        } else {
+            column.push_null();
+        }
+    }
+    Ok(())
+}
+
+/// One run of the RLE / bit-packed hybrid encoding.
+enum HybridRun<'a> {
+    /// `values` values packed at the column's bit width.
+    Packed { bytes: &'a [u8], values: usize },
+    /// `len` repeats of `value`.
+    Repeat { value: u32, len: usize },
+}
+
+/// Decode the RLE / bit-packed hybrid encoding used for levels and
+/// dictionary indices.
+///
+/// The runs are parsed before anything is allocated, so `count` is only
+/// trusted once the data has been shown to encode that many values.
+fn decode_hybrid(data: &[u8], bit_width: u8, count: usize) -> Result<Vec<u32>, FormatError> {
+    if bit_width > 32 {
+        return Err(FormatError::InvalidParquet(format!(
+            "bit width {bit_width} is too large"
+        )));
+    }
+    let width = usize::from(bit_width);
+    let mut reader = CompactReader::new(data);
+    let mut runs = Vec::new();
+    let mut total = 0usize;
+
+    while total < count {
+        let header = reader.varint()?;
+        let run = if header & 1 == 1 {
+            // Bit-packed groups of eight values, least significant bit first.
+            let groups = usize::try_from(header >> 1).map_err(|_| FormatError::TruncatedData)?;
+            let values = groups.checked_mul(8).ok_or(FormatError::TruncatedData)?;
+            let bytes = reader.bytes(
+                groups
+                    .checked_mul(width)
+                    .ok_or(FormatError::TruncatedData)?,
+            )?;
+            HybridRun::Packed {
+                bytes,
+                values: values.min(count - total),
+            }
+        } else {
+            let len = usize::try_from(header >> 1).map_err(|_| FormatError::TruncatedData)?;
+            let bytes = reader.bytes(width.div_ceil(8))?;
+            let value = bytes
+                .iter()
+                .rev()
+                .fold(0u32, |acc, &b| (acc << 8) | u32::from(b));
+            HybridRun::Repeat {
+                value,
+                len: len.min(count - total),
+            }
+        };
+        total += match run {
+            HybridRun::Packed { values, .. } => values,
+            HybridRun::Repeat { len, .. } => len,
+        };
+        runs.push(run);
+    }
+
+    let mut out = Vec::with_capacity(count);
+    for run in runs {
+        match run {
+            HybridRun::Packed { bytes, values } => {
+                for i in 0..values {
+                    let mut value = 0u32;
+                    for bit in 0..width {
+                        let position = i * width + bit;
+                        let set = (bytes[position / 8] >> (position % 8)) & 1;
+                        value |= u32::from(set) << bit;
+                    }
+                    out.push(value);
+                }
+            }
+            HybridRun::Repeat { value, len } => out.resize(out.len() + len, value),
+        }
+    }
+    Ok(out)
+}
+
+fn decompress(
+    codec: i32,
+    data: &[u8],
+    uncompressed_size: i32,
+) -> Result<Cow<'_, [u8]>, FormatError> {
+    match codec {
+        UNCOMPRESSED => Ok(Cow::Borrowed(data)),
+        SNAPPY => {
+            let out = snappy_decompress(data)?;
+            if i32::try_from(out.len()).ok() != Some(uncompressed_size) {
+                return Err(FormatError::InvalidParquet(format!(
+                    "page decompressed to {} bytes, header says {uncompressed_size}",
+                    out.len()
+                )));
+            }
+            Ok(Cow::Owned(out))
+        }
+        other => Err(FormatError::Unsupported(format!(
+            "{} compression",
+            codec_name(other)
+        ))),
+    }
+}
+
+/// Decompress a raw (unframed) Snappy block.
+fn snappy_decompress(data: &[u8]) -> Result<Vec<u8>, FormatError> {
+    let mut reader = CompactReader::new(data);
+    let expected = usize::try_from(reader.varint()?).map_err(|_| FormatError::TruncatedData)?;
+    let mut out: Vec<u8> = Vec::with_capacity(expected.min(data.len().saturating_mul(32)));
+
+    while reader.pos < data.len() {
+        let tag = reader.byte()?;
+        let (len, offset) = match tag & 0b11 {
+            0 => {
+                let mut len = usize::from(tag >> 2);
+                if len >= 60 {
+                    let extra = reader.bytes(len - 59)?;
+                    len = extra
+                        .iter()
+                        .rev()
+                        .fold(0usize, |acc, &b| (acc << 8) | usize::from(b));
+                }
+                out.extend_from_slice(reader.bytes(len + 1)?);
+                continue;
+            }
+            1 => {
+                let low = reader.byte()?;
+                (
+                    4 + usize::from((tag >> 2) & 0b111),
+                    (usize::from(tag >> 5) << 8) | usize::from(low),
+                )
+            }
+            2 => {
+                let b = reader.bytes(2)?;
+                (
+                    usize::from(tag >> 2) + 1,
+                    usize::from(u16::from_le_bytes([b[0], b[1]])),
+                )
+            }
+            _ => {
+                let b = reader.bytes(4)?;
+                (
+                    usize::from(tag >> 2) + 1,
+                    u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize,
+                )
+            }
+        };
+        if offset == 0 || offset > out.len() {
+            return Err(FormatError::InvalidParquet(
+                "invalid Snappy copy offset".to_string(),
+            ));
+        }
+        // Copies may overlap their own output, so go byte by byte.
+        let start = out.len() - offset;
+        for i in 0..len {
+            out.push(out[start + i]);
+        }
+    }
+
+    if out.len() != expected {
+        return Err(FormatError::InvalidParquet(format!(
+            "Snappy block decompressed to {} bytes, expected {expected}",
+            out.len()
+        )));
+    }
+    Ok(out)
+}
+
+// =============================================================================
+// Thrift compact protocol (file and page metadata)
+// =============================================================================
+
+// Compact protocol field types
+const T_BOOL_TRUE: u8 = 1;
+const T_BOOL_FALSE: u8 = 2;
+const T_BYTE: u8 = 3;
+const T_I16: u8 = 4;
+const T_I32: u8 = 5;
+const T_I64: u8 = 6;
+const T_DOUBLE: u8 = 7;
+const T_BINARY: u8 = 8;
+const T_LIST: u8 = 9;
+const T_SET: u8 = 10;
+const T_MAP: u8 = 11;
+const T_STRUCT: u8 = 12;
+
+/// Deepest struct / collection nesting accepted in Thrift metadata.
+///
+/// Parquet's own metadata nests a few levels; the cap keeps crafted input
+/// from exhausting the stack through recursive skips.
+const MAX_THRIFT_DEPTH: usize = 64;
+
+struct CompactReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> CompactReader<'a> {
+    const fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            pos: 0,
+            depth: 0,
+        }
+    }
+
+    /// Run `read` one nesting level deeper, failing past [`MAX_THRIFT_DEPTH`].
+    fn nested<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, FormatError>,
+    ) -> Result<T, FormatError> {
+        if self.depth >= MAX_THRIFT_DEPTH {
+            return Err(FormatError::InvalidParquet(format!(
+                "thrift metadata nested deeper than {MAX_THRIFT_DEPTH} levels"
+            )));
+        }
+        self.depth += 1;
+        let result = read(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn byte(&mut self) -> Result<u8, FormatError> {
+        let byte = *self.data.get(self.pos).ok_or(FormatError::TruncatedData)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], FormatError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .ok_or(FormatError::TruncatedData)?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn varint(&mut self) -> Result<u64, FormatError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(FormatError::InvalidParquet("varint too long".to_string()))
+    }
+
+    /// Zigzag-encoded integer (i16, i32 and i64 share the encoding).
+    fn int(&mut self) -> Result<i64, FormatError> {
+        let raw = self.varint()?;
+        Ok((raw >> 1) as i64 ^ -((raw & 1) as i64))
+    }
+
+    fn i32(&mut self) -> Result<i32, FormatError> {
+        i32::try_from(self.int()?)
+            .map_err(|_| FormatError::InvalidParquet("i32 out of range".to_string()))
+    }
+
+    fn binary(&mut self) -> Result<&'a [u8], FormatError> {
+        let len = usize::try_from(self.varint()?).map_err(|_| FormatError::TruncatedData)?;
+        self.bytes(len)
+    }
+
+    fn string(&mut self) -> Result<String, FormatError> {
+        String::from_utf8(self.binary()?.to_vec())
+            .map_err(|e| FormatError::InvalidParquet(e.to_string()))
+    }
+
+    /// Read a struct, passing each field to `field`; it returns `false` for
+    /// fields it does not consume, which are skipped.
+    fn read_struct(
+        &mut self,
+        mut field: impl FnMut(&mut Self, i16, u8) -> Result<bool, FormatError>,
+    ) -> Result<(), FormatError> {
+        self.nested(|t| {
+            let mut last_id = 0i16;
+            loop {
+                let header = t.byte()?;
+                if header == 0 {
+                    return Ok(());
+                }
+                let field_type = header & 0x0f;
+                let delta = header >> 4;
+                let id = if delta == 0 {
+                    i16::try_from(t.int()?)
+                        .map_err(|_| FormatError::InvalidParquet("bad field id".to_string()))?
+                } else {
+                    last_id.saturating_add(i16::from(delta))
+                };
+                last_id = id;
+                if !field(t, id, field_type)? {
+                    t.skip(field_type)?;
+                }
+            }
+        })
+    }
+
+    fn read_list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, FormatError>,
+    ) -> Result<Vec<T>, FormatError> {
+        let (len, _) = self.list_header()?;
+        let mut items = Vec::with_capacity(len.min(self.data.len() - self.pos));
+        for _ in 0..len {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    fn list_header(&mut self) -> Result<(usize, u8), FormatError> {
+        let header = self.byte()?;
+        let len = match header >> 4 {
+            15 => usize::try_from(self.varint()?).map_err(|_| FormatError::TruncatedData)?,
+            short => usize::from(short),
+        };
+        Ok((len, header & 0x0f))
+    }
+
+    fn skip(&mut self, field_type: u8) -> Result<(), FormatError> {
+        match field_type {
+            // Struct-field booleans live in the type nibble.
+            T_BOOL_TRUE | T_BOOL_FALSE => {}
+            T_BYTE => {
+                self.byte()?;
+            }
+            T_I16 | T_I32 | T_I64 => {
+                self.varint()?;
+            }
+            T_DOUBLE => {
+                self.bytes(8)?;
+            }
+            T_BINARY => {
+                self.binary()?;
+            }
+            T_LIST | T_SET => self.nested(|t| {
+                let (len, element) = t.list_header()?;
+                for _ in 0..len {
+                    t.skip_element(element)?;
+                }
+                Ok(())
+            })?,
+            T_MAP => self.nested(|t| {
+                let len = usize::try_from(t.varint()?).map_err(|_| FormatError::TruncatedData)?;
+                if len > 0 {
+                    let types = t.byte()?;
+                    for _ in 0..len {
+                        t.skip_element(types >> 4)?;
+                        t.skip_element(types & 0x0f)?;
+                    }
+                }
+                Ok(())
+            })?,
+            T_STRUCT => self.read_struct(|_, _, _| Ok(false))?,
+            other => {
+                return Err(FormatError::InvalidParquet(format!(
+                    "unknown thrift type {other}"
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Skip a collection element; booleans take a full byte there.
+    fn skip_element(&mut self, element_type: u8) -> Result<(), FormatError> {
+        if matches!(element_type, T_BOOL_TRUE | T_BOOL_FALSE) {
+            self.byte().map(|_| ())
+        } else {
+            self.skip(element_type)
+        }
+    }
+}
+
+struct SchemaElement {
+    name: String,
+    physical: Option<i32>,
+    type_length: i32,
+    repetition: i32,
+    num_children: i32,
+}
+
+impl SchemaElement {
+    fn read(t: &mut CompactReader<'_>) -> Result<Self, FormatError> {
+        let mut element = Self {
+            name: String::new(),
+            physical: None,
+            type_length: 0,
+            repetition: 0,
+            num_children: 0,
+        };
+        t.read_struct(|t, id, ty| {
+            match (id, ty) {
+                (1, T_I32) => element.physical = Some(t.i32()?),
+                (2, T_I32) => element.type_length = t.i32()?,
+                (3, T_I32) => element.repetition = t.i32()?,
+                (4, T_BINARY) => element.name = t.string()?,
+                (5, T_I32) => element.num_children = t.i32()?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        Ok(element)
+    }
+}
+
+struct ColumnMeta {
+    physical: i32,
+    path: Vec<String>,
+    codec: i32,
+    num_values: i64,
+    data_page_offset: i64,
+    dictionary_page_offset: Option<i64>,
+}
+
+impl ColumnMeta {
+    fn read(t: &mut CompactReader<'_>) -> Result<Self, FormatError> {
+        let mut meta = Self {
+            physical: -1,
+            path: Vec::new(),
+            codec: UNCOMPRESSED,
+            num_values: 0,
+            data_page_offset: 0,
+            dictionary_page_offset: None,
+        };
+        t.read_struct(|t, id, ty| {
+            match (id, ty) {
+                (1, T_I32) => meta.physical = t.i32()?,
+                (3, T_LIST) => meta.path = t.read_list(CompactReader::string)?,
+                (4, T_I32) => meta.codec = t.i32()?,
+                (5, T_I64) => meta.num_values = t.int()?,
+                (9, T_I64) => meta.data_page_offset = t.int()?,
+                (11, T_I64) => meta.dictionary_page_offset = Some(t.int()?),
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        Ok(meta)
+    }
+}
+
+struct RowGroup {
+    columns: Vec<ColumnMeta>,
+    num_rows: i64,
+}
+
+impl RowGroup {
+    fn read(t: &mut CompactReader<'_>) -> Result<Self, FormatError> {
+        let mut group = Self {
+            columns: Vec::new(),
+            num_rows: 0,
+        };
+        t.read_struct(|t, id, ty| {
+            match (id, ty) {
+                (1, T_LIST) => {
+                    group.columns = t.read_list(|t| {
+                        let mut meta = None;
+                        t.read_struct(|t, id, ty| {
+                            if (id, ty) == (3, T_STRUCT) {
+                                meta = Some(ColumnMeta::read(t)?);
+                                return Ok(true);
+                            }
+                            Ok(false)
+                        })?;
+                        meta.ok_or_else(|| {
+                            FormatError::Unsupported(
+                                "column chunks stored in external files".to_string(),
+                            )
+                        })
+                    })?;
+                }
+                (3, T_I64) => group.num_rows = t.int()?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        Ok(group)
+    }
+}
+
+struct FileMeta {
+    schema: Vec<SchemaElement>,
+    num_rows: i64,
+    row_groups: Vec<RowGroup>,
+}
+
+impl FileMeta {
+    fn read(t: &mut CompactReader<'_>) -> Result<Self, FormatError> {
+        let mut meta = Self {
+            schema: Vec::new(),
+            num_rows: 0,
+            row_groups: Vec::new(),
+        };
+        t.read_struct(|t, id, ty| {
+            match (id, ty) {
+                (2, T_LIST) => meta.schema = t.read_list(SchemaElement::read)?,
+                (3, T_I64) => meta.num_rows = t.int()?,
+                (4, T_LIST) => meta.row_groups = t.read_list(RowGroup::read)?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        Ok(meta)
+    }
+}
+
+struct PageHeader {
+    page_type: i32,
+    uncompressed_size: i32,
+    compressed_size: i32,
+    num_values: i32,
+    encoding: i32,
+    def_encoding: i32,
+    def_levels_len: i32,
+    rep_levels_len: i32,
+    is_compressed: bool,
+}
+
+impl PageHeader {
+    fn read(t: &mut CompactReader<'_>) -> Result<Self, FormatError> {
+        let mut header = Self {
+            page_type: -1,
+            uncompressed_size: 0,
+            compressed_size: 0,
+            num_values: 0,
+            encoding: PLAIN,
+            def_encoding: RLE,
+            def_levels_len: 0,
+            rep_levels_len: 0,
+            is_compressed: true,
+        };
+        t.read_struct(|t, id, ty| {
+            match (id, ty) {
+                (1, T_I32) => header.page_type = t.i32()?,
+                (2, T_I32) => header.uncompressed_size = t.i32()?,
+                (3, T_I32) => header.compressed_size = t.i32()?,
+                // DataPageHeader
+                (5, T_STRUCT) => t.read_struct(|t, id, ty| {
+                    match (id, ty) {
+                        (1, T_I32) => header.num_values = t.i32()?,
+                        (2, T_I32) => header.encoding = t.i32()?,
+                        (3, T_I32) => header.def_encoding = t.i32()?,
+                        _ => return Ok(false),
+                    }
+                    Ok(true)
+                })?,
+                // DictionaryPageHeader
+                (7, T_STRUCT) => t.read_struct(|t, id, ty| {
+                    match (id, ty) {
+                        (1, T_I32) => header.num_values = t.i32()?,
+                        (2, T_I32) => header.encoding = t.i32()?,
+                        _ => return Ok(false),
+                    }
+                    Ok(true)
+                })?,
+                // DataPageHeaderV2
+                (8, T_STRUCT) => t.read_struct(|t, id, ty| {
+                    match (id, ty) {
+                        (1, T_I32) => header.num_values = t.i32()?,
+                        (4, T_I32) => header.encoding = t.i32()?,
+                        (5, T_I32) => header.def_levels_len = t.i32()?,
+                        (6, T_I32) => header.rep_levels_len = t.i32()?,
+                        (7, T_BOOL_TRUE) => header.is_compressed = true,
+                        (7, T_BOOL_FALSE) => header.is_compressed = false,
+                        _ => return Ok(false),
+                    }
+                    Ok(true)
+                })?,
+                _ => return Ok(false),
+            }
+            Ok(true)
+        })?;
+        Ok(header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Thrift compact protocol writer, enough to build test files.
+    #[derive(Default)]
+    struct Compact {
+        out: Vec<u8>,
+        last: Vec<i16>,
+    }
+
+    impl Compact {
+        fn begin(&mut self) {
+            self.last.push(0);
+        }
+
+        fn end(&mut self) {
+            self.out.push(0);
+            self.last.pop();
+        }
+
+        fn field(&mut self, id: i16, field_type: u8) {
+            let last = self.last.last_mut().unwrap();
+            self.out.push((((id - *last) as u8) << 4) | field_type);
+            *last = id;
+        }
+
+        fn varint(&mut self, mut value: u64) {
+            while value >= 0x80 {
+                self.out.push((value as u8) | 0x80);
+                value >>= 7;
+            }
+            self.out.push(value as u8);
+        }
+
+        fn zigzag(&mut self, value: i64) {
+            self.varint(((value << 1) ^ (value >> 63)) as u64);
+        }
+
+        fn i32(&mut self, id: i16, value: i32) {
+            self.field(id, T_I32);
+            self.zigzag(i64::from(value));
+        }
+
+        fn i64(&mut self, id: i16, value: i64) {
+            self.field(id, T_I64);
+            self.zigzag(value);
+        }
+
+        fn string(&mut self, id: i16, value: &str) {
+            self.field(id, T_BINARY);
+            self.varint(value.len() as u64);
+            self.out.extend_from_slice(value.as_bytes());
+        }
+
+        fn list(&mut self, id: i16, element_type: u8, len: usize) {
+            self.field(id, T_LIST);
+            self.out.push(((len as u8) << 4) | element_type);
+        }
+
+        fn structure(&mut self, id: i16) {
+            self.field(id, T_STRUCT);
+            self.begin();
+        }
+    }
+
+    struct TestColumn {
+        name: &'static str,
+        physical: i32,
+        optional: bool,
+        encoding: i32,
+        /// Page body after any definition levels
+        values: Vec<u8>,
+        /// v1 definition levels section, including its length prefix
+        levels: Vec<u8>,
+        /// Dictionary page values, if dictionary encoded
+        dictionary: Option<(i32, Vec<u8>)>,
+    }
+
+    impl TestColumn {
+        fn plain(name: &'static str, physical: i32, values: Vec<u8>) -> Self {
+            Self {
+                name,
+                physical,
+                optional: false,
+                encoding: PLAIN,
+                values,
+                levels: Vec::new(),
+                dictionary: None,
+            }
+        }
+    }
+
+    fn page_header(page_type: i32, body_len: usize, num_values: i32, encoding: i32) -> Vec<u8> {
+        let mut w = Compact::default();
+        w.begin();
+        w.i32(1, page_type);
+        w.i32(2, body_len as i32);
+        w.i32(3, body_len as i32);
+        w.structure(if page_type == DICTIONARY_PAGE { 7 } else { 5 });
+        w.i32(1, num_values);
+        w.i32(2, encoding);
+        if page_type == DATA_PAGE {
+            w.i32(3, RLE);
+            w.i32(4, RLE);
+        }
+        w.end();
+        w.end();
+        w.out
+    }
+
+    /// Build an uncompressed single-row-group file.
+    fn parquet_file(columns: &[TestColumn], rows: i32) -> Vec<u8> {
+        let mut file = PARQUET_MAGIC.to_vec();
+        let mut offsets = Vec::new();
+        for column in columns {
+            let dictionary_offset = column.dictionary.as_ref().map(|(count, values)| {
+                let offset = file.len() as i64;
+                file.extend(page_header(DICTIONARY_PAGE, values.len(), *count, PLAIN));
+                file.extend(values);
+                offset
+            });
+            let data_offset = file.len() as i64;
+            let mut body = column.levels.clone();
+            body.extend(&column.values);
+            file.extend(page_header(DATA_PAGE, body.len(), rows, column.encoding));
+            file.extend(body);
+            offsets.push((data_offset, dictionary_offset));
+        }
+
+        let mut w = Compact::default();
+        w.begin();
+        w.i32(1, 1);
+        w.list(2, T_STRUCT, columns.len() + 1);
+        w.begin();
+        w.string(4, "schema");
+        w.i32(5, columns.len() as i32);
+        w.end();
+        for column in columns {
+            w.begin();
+            w.i32(1, column.physical);
+            w.i32(3, i32::from(column.optional));
+            w.string(4, column.name);
+            if column.physical == 6 {
+                w.i32(6, 0); // UTF8
+            }
+            w.end();
+        }
+        w.i64(3, i64::from(rows));
+        w.list(4, T_STRUCT, 1);
+        w.begin();
+        w.list(1, T_STRUCT, columns.len());
+        for (column, (data_offset, dictionary_offset)) in columns.iter().zip(&offsets) {
+            w.begin();
+            w.i64(2, *data_offset);
+            w.structure(3);
+            w.i32(1, column.physical);
+            w.list(2, T_I32, 1);
+            w.zigzag(i64::from(column.encoding));
+            w.list(3, T_BINARY, 1);
+            w.varint(column.name.len() as u64);
+            w.out.extend_from_slice(column.name.as_bytes());
+            w.i32(4, UNCOMPRESSED);
+            w.i64(5, i64::from(rows));
+            w.i64(6, 0);
+            w.i64(7, 0);
+            w.i64(9, *data_offset);
+            if let Some(offset) = dictionary_offset {
+                w.i64(11, *offset);
+            }
+            w.end();
+            w.end();
+        }
+        w.i64(2, 0);
+        w.i64(3, i64::from(rows));
+        w.end();
+        w.string(6, "presentar tests");
+        w.end();
+
+        file.extend(&w.out);
+        file.extend((w.out.len() as u32).to_le_bytes());
+        file.extend(PARQUET_MAGIC);
+        file
+    }
+
+    fn byte_arrays(values: &[&str]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|v| {
+                let mut bytes = (v.len() as u32).to_le_bytes().to_vec();
+                bytes.extend(v.as_bytes());
+                bytes
+            })
+            .collect()
+    }
+
+    fn sample_columns() -> Vec<TestColumn> {
+        vec![
+            TestColumn::plain(
+                "id",
+                2,
+                [1i64, 2, 3].iter().flat_map(|v| v.to_le_bytes()).collect(),
+            ),
+            TestColumn {
+                name: "score",
+                physical: 5,
+                optional: true,
+                encoding: PLAIN,
+                values: [0.5f64, 0.75]
+                    .iter()
+                    .flat_map(|v| v.to_le_bytes())
+                    .collect(),
+                // Bit-packed levels [1, 0, 1] behind a 4-byte length.
+                levels: vec![2, 0, 0, 0, 0b11, 0b101],
+                dictionary: None,
+            },
+            TestColumn::plain("name", 6, byte_arrays(&["alpha", "beta", "gamma"])),
+        ]
+    }
+
+    #[test]
+    fn test_read_parquet_columns() {
+        let dataset = read_parquet(&parquet_file(&sample_columns(), 3)).unwrap();
+
+        let names: Vec<&str> = dataset.tensors.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "score", "score.valid", "name"]);
+        assert!(dataset
+            .tensors
+            .iter()
+            .all(|t| t.shape[0] == 3 && t.is_valid()));
+
+        let id = dataset.get("id").unwrap();
+        assert_eq!(id.dtype, DType::I64);
+        assert_eq!(&id.data[8..16], &2i64.to_le_bytes());
+
+        let score = dataset.get("score").unwrap();
+        assert_eq!(score.dtype, DType::F64);
+        let scores: Vec<f64> = score
+            .data
+            .chunks_exact(8)
+            .map(|c| f64::from_le_bytes(c.try_into().unwrap()))
+            .collect();
+        assert!((scores[0] - 0.5).abs() < f64::EPSILON);
+        assert!(scores[1].is_nan());
+        assert!((scores[2] - 0.75).abs() < f64::EPSILON);
+        assert_eq!(dataset.get("score.valid").unwrap().data, vec![1, 0, 1]);
+
+        let name = dataset.get("name").unwrap();
+        assert_eq!(name.dtype, DType::U8);
+        assert_eq!(name.shape, vec![3, 5]);
+        assert_eq!(&name.data[5..10], b"beta\0");
+    }
+
+    fn f64s(tensor: &Tensor) -> Vec<f64> {
+        tensor
+            .data
+            .chunks_exact(8)
+            .map(|c| f64::from_le_bytes(c.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_read_parquet_fixture_with_nulls() {
+        // Written by scripts/parquet_fixtures.py: one row group, dictionary
+        // encoded v1 pages with statistics, logical types and an offset index.
+        let dataset = read_parquet(include_bytes!(
+            "../tests/fixtures/nullable_dictionary.parquet"
+        ))
+        .unwrap();
+
+        let names: Vec<&str> = dataset.tensors.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "id",
+                "score",
+                "score.valid",
+                "name",
+                "name.valid",
+                "flag",
+                "flag.valid"
+            ]
+        );
+
+        let id = dataset.get("id").unwrap();
+        assert_eq!(id.dtype, DType::I64);
+        assert_eq!(
+            id.data,
+            [1i64, 2, 3, 4]
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect::<Vec<_>>()
+        );
+
+        // A stored 0.0 and a null differ only in the mask.
+        let scores = f64s(dataset.get("score").unwrap());
+        assert_eq!(scores[0].to_bits(), 0.5f64.to_bits());
+        assert!(scores[1].is_nan());
+        assert_eq!(scores[2].to_bits(), 0.0f64.to_bits());
+        assert_eq!(scores[3].to_bits(), 2.25f64.to_bits());
+        assert_eq!(dataset.get("score.valid").unwrap().data, vec![1, 0, 1, 1]);
+
+        // So do a stored "" and a null string.
+        let name = dataset.get("name").unwrap();
+        assert_eq!(name.shape, vec![4, 5]);
+        assert_eq!(name.data, b"alpha\0\0\0\0\0\0\0\0\0\0alpha".to_vec());
+        assert_eq!(dataset.get("name.valid").unwrap().data, vec![1, 0, 1, 1]);
+
+        assert_eq!(dataset.get("flag").unwrap().data, vec![1, 0, 0, 1]);
+        assert_eq!(dataset.get("flag.valid").unwrap().data, vec![1, 1, 0, 1]);
+    }
+
+    #[test]
+    fn test_read_parquet_fixture_snappy_v2() {
+        // Written by scripts/parquet_fixtures.py: two row groups of Snappy
+        // compressed v2 data pages.
+        let dataset = read_parquet(include_bytes!("../tests/fixtures/snappy_v2.parquet")).unwrap();
+
+        let id = dataset.get("id").unwrap();
+        assert_eq!(id.dtype, DType::I32);
+        assert_eq!(
+            id.data,
+            [10i32, 11, 12, 13, 14]
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect::<Vec<_>>()
+        );
+
+        let value = dataset.get("value").unwrap();
+        assert_eq!(value.dtype, DType::F32);
+        let values = value.to_f32_vec().unwrap();
+        assert_eq!(values[0].to_bits(), 1.5f32.to_bits());
+        assert!(values[1].is_nan() && values[3].is_nan());
+        assert_eq!(values[4].to_bits(), 0.0f32.to_bits());
+        assert_eq!(
+            dataset.get("value.valid").unwrap().data,
+            vec![1, 0, 1, 0, 1]
+        );
+
+        let label = dataset.get("label").unwrap();
+        assert_eq!(label.shape, vec![5, 21]);
+        let rows: Vec<&[u8]> = label.data.chunks_exact(21).collect();
+        assert_eq!(&rows[0][..14], b"repeated label");
+        assert!(rows[1].iter().all(|&b| b == 0));
+        assert_eq!(rows[4], b"repeated label, again");
+        assert_eq!(
+            dataset.get("label.valid").unwrap().data,
+            vec![1, 1, 0, 1, 1]
+        );
+    }
+
+    #[test]
+    fn test_read_parquet_fixture_from_parquet_rs() {
+        // Written by parquet-rs (scripts/parquet_rs_fixture.rs): two row
+        // groups of Snappy compressed, dictionary encoded pages with nulls.
+        let dataset = read_parquet(include_bytes!("../tests/fixtures/parquet_rs.parquet")).unwrap();
+
+        let id = dataset.get("id").unwrap();
+        assert_eq!(id.dtype, DType::I64);
+        assert_eq!(
+            id.data,
+            (1i64..=7).flat_map(i64::to_le_bytes).collect::<Vec<_>>()
+        );
+
+        let scores = f64s(dataset.get("score").unwrap());
+        let expected = [0.5, f64::NAN, 0.0, 2.25, f64::NAN, -1.0, 0.5];
+        for (got, want) in scores.iter().zip(expected) {
+            assert!(got.to_bits() == want.to_bits() || (got.is_nan() && want.is_nan()));
+        }
+        assert_eq!(
+            dataset.get("score.valid").unwrap().data,
+            vec![1, 0, 1, 1, 0, 1, 1]
+        );
+
+        let name = dataset.get("name").unwrap();
+        assert_eq!(name.shape, vec![7, 5]);
+        let rows: Vec<&[u8]> = name.data.chunks_exact(5).collect();
+        assert_eq!(
+            rows,
+            vec![
+                &b"alpha"[..],
+                b"\0\0\0\0\0",
+                b"\0\0\0\0\0",
+                b"beta\0",
+                b"alpha",
+                b"\0\0\0\0\0",
+                b"beta\0"
+            ]
+        );
+        assert_eq!(
+            dataset.get("name.valid").unwrap().data,
+            vec![1, 0, 1, 1, 1, 0, 1]
+        );
+
+        assert_eq!(dataset.get("flag").unwrap().data, vec![1, 0, 0, 1, 0, 0, 1]);
+        assert_eq!(
+            dataset.get("flag.valid").unwrap().data,
+            vec![1, 1, 0, 1, 0, 1, 1]
+        );
+    }
+
+    #[test]
+    fn test_read_parquet_dictionary_encoding() {
+        let columns = vec![TestColumn {
+            name: "city",
+            physical: 6,
+            optional: false,
+            encoding: RLE_DICTIONARY,
+            // Bit width 1, then an RLE run of two 1s and a run of one 0.
+            values: vec![1, 2 << 1, 1, 1 << 1, 0],
+            levels: Vec::new(),
+            dictionary: Some((2, byte_arrays(&["paris", "tokyo"]))),
+        }];
+        let dataset = read_parquet(&parquet_file(&columns, 3)).unwrap();
+
+        let city = dataset.get("city").unwrap();
+        assert_eq!(city.shape, vec![3, 5]);
+        assert_eq!(city.data, b"tokyotokyoparis".to_vec());
+    }
+
+    #[test]
+    fn test_read_parquet_errors() {
+        assert!(matches!(
+            read_parquet(b"not parquet"),
+            Err(FormatError::InvalidMagic)
+        ));
+
+        let unsupported = vec![TestColumn {
+            encoding: 5,
+            ..TestColumn::plain("n", 1, vec![0; 12])
+        }];
+        let err = read_parquet(&parquet_file(&unsupported, 3)).unwrap_err();
+        assert_eq!(
+            err,
+            FormatError::Unsupported("DELTA_BINARY_PACKED encoding in column `n`".to_string())
+        );
+
+        // A chunk whose page holds fewer rows than the row group declares.
+        let short = vec![TestColumn::plain("n", 1, vec![0; 8])];
+        let mut file = parquet_file(&short, 2);
+        let declared = file.len() - 8;
+        let meta_len = u32::from_le_bytes(file[declared..declared + 4].try_into().unwrap());
+        let meta_start = declared - meta_len as usize;
+        // Bump the file-level row count (zigzag 4 -> 6) to mismatch.
+        let row_count = file[meta_start..declared]
+            .windows(2)
+            .position(|w| w == [0x16, 4])
+            .unwrap();
+        file[meta_start + row_count + 1] = 6;
+        assert!(matches!(
+            read_parquet(&file),
+            Err(FormatError::InvalidParquet(_))
+        ));
+    }
+
+    #[test]
+    fn test_snappy_decompress() {
+        // "abcabcabcd": literal "abc", copy(len 6, offset 3), literal "d".
+        let block = [10, 2 << 2, b'a', b'b', b'c', 0b001 | (2 << 2), 3, 0, b'd'];
+        assert_eq!(snappy_decompress(&block).unwrap(), b"abcabcabcd");
+
+        assert!(snappy_decompress(&[4, 0b01, 9]).is_err());
+        assert_eq!(
+            decompress(2, &[], 0).unwrap_err().to_string(),
+            "Unsupported: GZIP compression"
+        );
+    }
+
+    #[test]
+    fn test_decode_hybrid() {
+        // Bit-packed group (width 3) of 0..8, then an RLE run of 4 fives.
+        let data = [0b11, 0b1000_1000, 0b1100_0110, 0b1111_1010, 4 << 1, 5];
+        let values = decode_hybrid(&data, 3, 12).unwrap();
+        assert_eq!(values, vec![0, 1, 2, 3, 4, 5, 6, 7, 5, 5, 5, 5]);
+
+        // A bit-packed run too long to count is an error, not an overflow.
+        let mut huge = vec![0xff; 9];
+        huge.push(0x01);
+        assert_eq!(decode_hybrid(&huge, 0, 1), Err(FormatError::TruncatedData));
+
+        // A count the data cannot back is rejected before allocating for it.
+        assert_eq!(
+            decode_hybrid(&[4 << 1, 5], 3, usize::MAX),
+            Err(FormatError::TruncatedData)
+        );
+    }
+
+    #[test]
+    fn test_read_parquet_rejects_overstated_page_count() {
+        // Three INT32 values in the page body, but the header claims i32::MAX.
+        let column = TestColumn::plain("n", 1, vec![0; 12]);
+        assert_eq!(
+            read_parquet(&parquet_file(&[column], i32::MAX)).unwrap_err(),
+            FormatError::TruncatedData
+        );
+    }
+
+    #[test]
+    fn test_thrift_nesting_is_capped() {
+        // A struct field of type list<list<list<...>>> nested past the cap.
+        let mut data = vec![0x19];
+        data.extend(std::iter::repeat(0x19).take(MAX_THRIFT_DEPTH + 1));
+        let err = CompactReader::new(&data)
+            .read_struct(|_, _, _| Ok(false))
+            .unwrap_err();
+        assert!(matches!(err, FormatError::InvalidParquet(ref m) if m.contains("nested")));
+    }
+}
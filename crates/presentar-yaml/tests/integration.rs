@@ -3,7 +3,9 @@
 //!
 //! These tests verify YAML manifest parsing and expression execution end-to-end.
 
-use presentar_yaml::{DataContext, ExpressionExecutor, ExpressionParser, Manifest, Value};
+use presentar_yaml::{
+    DataContext, ExecutionError, ExpressionExecutor, ExpressionParser, Manifest, Value,
+};
 use std::collections::HashMap;
 
 // =============================================================================
@@ -260,6 +262,135 @@ fn test_execute_mean_transform() {
     assert!((mean - expected).abs() < 0.01);
 }
 
+#[test]
+fn test_execute_array_aggregations() {
+    let parser = ExpressionParser::new();
+    let executor = ExpressionExecutor::new();
+    let mut ctx = DataContext::new();
+    ctx.insert(
+        "values",
+        Value::Array(vec![
+            Value::Number(1.0),
+            Value::Number(2.0),
+            Value::Number(3.0),
+        ]),
+    );
+
+    let run = |source: &str| {
+        let expr = parser.parse(source).expect("parse");
+        executor.execute(&expr, &ctx).expect("execute")
+    };
+
+    assert_eq!(run("{{ values | sum }}").as_number(), Some(6.0));
+    assert_eq!(run("{{ values | avg }}").as_number(), Some(2.0));
+    assert_eq!(run("{{ values | min }}").as_number(), Some(1.0));
+    assert_eq!(run("{{ values | max }}").as_number(), Some(3.0));
+    assert_eq!(run("{{ values | count }}").as_number(), Some(3.0));
+}
+
+#[test]
+fn test_execute_aggregation_skips_non_numeric() {
+    let parser = ExpressionParser::new();
+    let executor = ExpressionExecutor::new();
+    let mut ctx = DataContext::new();
+    ctx.insert(
+        "mixed",
+        Value::Array(vec![
+            Value::Number(2.0),
+            Value::String("n/a".to_string()),
+            Value::Null,
+            Value::Number(4.0),
+        ]),
+    );
+
+    let expr = parser.parse("{{ mixed | avg }}").expect("parse");
+    let result = executor.execute(&expr, &ctx).expect("execute");
+    assert_eq!(result.as_number(), Some(3.0));
+
+    // count still covers every element
+    let expr = parser.parse("{{ mixed | count }}").expect("parse");
+    let result = executor.execute(&expr, &ctx).expect("execute");
+    assert_eq!(result.as_number(), Some(4.0));
+}
+
+#[test]
+fn test_execute_format_date() {
+    let parser = ExpressionParser::new();
+    let executor = ExpressionExecutor::new();
+    let ctx = DataContext::new();
+
+    let expr = parser
+        .parse(r#"{{ format_date(0, "%Y") }}"#)
+        .expect("parse");
+    let result = executor.execute(&expr, &ctx).expect("execute");
+    assert_eq!(result.as_str(), Some("1970"));
+
+    let expr = parser
+        .parse(r#"{{ format_date(0, "%F %H:%M", "+05:30") }}"#)
+        .expect("parse");
+    let result = executor.execute(&expr, &ctx).expect("execute");
+    assert_eq!(result.as_str(), Some("1970-01-01 05:30"));
+}
+
+#[test]
+fn test_execute_date_diff() {
+    let parser = ExpressionParser::new();
+    let executor = ExpressionExecutor::new();
+    let mut ctx = DataContext::new();
+    ctx.insert("created", Value::Number(1_000.0));
+
+    let expr = parser
+        .parse("{{ date_diff(now(), now()) }}")
+        .expect("parse");
+    let result = executor.execute(&expr, &ctx).expect("execute");
+    assert_eq!(result.as_number(), Some(0.0));
+
+    let expr = parser
+        .parse(r#"{{ date_diff(1200, created, "minutes") }}"#)
+        .expect("parse");
+    let result = executor.execute(&expr, &ctx).expect("execute");
+    assert_eq!(result.as_number(), Some(3.0));
+}
+
+#[test]
+fn test_execute_date_function_errors() {
+    let parser = ExpressionParser::new();
+    let executor = ExpressionExecutor::new();
+    let ctx = DataContext::new();
+
+    let expr = parser.parse("{{ format_date(0) }}").expect("parse");
+    assert!(executor.execute(&expr, &ctx).is_err());
+
+    let expr = parser
+        .parse(r#"{{ date_diff(0, 0, "fortnights") }}"#)
+        .expect("parse");
+    assert!(executor.execute(&expr, &ctx).is_err());
+
+    // An offset that overflows the timestamp is an error, not a panic
+    let expr = parser
+        .parse(r#"{{ format_date(0, "%F", 1e300) }}"#)
+        .expect("parse");
+    assert!(executor.execute(&expr, &ctx).is_err());
+}
+
+#[test]
+fn test_execute_literal_source_is_a_lookup() {
+    let parser = ExpressionParser::new();
+    let executor = ExpressionExecutor::new();
+    let mut ctx = DataContext::new();
+
+    // Literals are only accepted as function arguments
+    let expr = parser.parse("{{ 42 }}").expect("parse");
+    assert!(matches!(
+        executor.execute(&expr, &ctx),
+        Err(ExecutionError::SourceNotFound(_))
+    ));
+
+    ctx.insert("42", Value::String("answer".to_string()));
+    let result = executor.execute(&expr, &ctx).expect("execute");
+    assert_eq!(result.as_str(), Some("answer"));
+}
+
 // =============================================================================
 // Error Handling Integration Tests
 // =============================================================================
@@ -206,7 +206,7 @@ impl App {
         let bounds = Rect::new(0.0, 0.0, size.width, size.height);
         widget.layout(bounds);
 
-        let mut canvas = RecordingCanvas::new();
+        let mut canvas = RecordingCanvas::new().with_text_measurer(self.renderer.text_measurer());
         widget.paint(&mut canvas);
 
         self.renderer.clear();
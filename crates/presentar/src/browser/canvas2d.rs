@@ -1,7 +1,7 @@
 //! Canvas2D renderer - renders DrawCommands to HTML5 Canvas.
 
 use presentar_core::draw::{BoxStyle, DrawCommand, FillRule, StrokeStyle};
-use presentar_core::{Color, CornerRadius, Point, Rect};
+use presentar_core::{Color, CornerRadius, Point, Rect, Size};
 use std::collections::HashMap;
 use wasm_bindgen::JsCast;
 use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, ImageData};
@@ -70,6 +70,19 @@ impl Canvas2DRenderer {
         self.canvas.height()
     }
 
+    /// Measure text with the context's font metrics.
+    pub fn measure_text(&self, text: &str, style: &presentar_core::widget::TextStyle) -> Size {
+        measure_with(&self.ctx, text, style)
+    }
+
+    /// A measurer for `RecordingCanvas` backed by this context's font metrics.
+    pub fn text_measurer(
+        &self,
+    ) -> impl Fn(&str, &presentar_core::widget::TextStyle) -> Size + 'static {
+        let ctx = self.ctx.clone();
+        move |text, style| measure_with(&ctx, text, style)
+    }
+
     /// Clear the canvas.
     pub fn clear(&self) {
         self.ctx.clear_rect(
@@ -150,6 +163,20 @@ impl Canvas2DRenderer {
                 self.render_command(child);
                 self.ctx.restore();
             }
+            DrawCommand::PushClip { bounds } => {
+                self.ctx.save();
+                self.ctx.begin_path();
+                self.ctx.rect(
+                    f64::from(bounds.x),
+                    f64::from(bounds.y),
+                    f64::from(bounds.width),
+                    f64::from(bounds.height),
+                );
+                self.ctx.clip();
+            }
+            DrawCommand::PopClip => {
+                self.ctx.restore();
+            }
             DrawCommand::Opacity { alpha, child } => {
                 self.ctx.save();
                 self.ctx.set_global_alpha(f64::from(*alpha));
@@ -251,14 +278,7 @@ impl Canvas2DRenderer {
         position: &Point,
         style: &presentar_core::widget::TextStyle,
     ) {
-        let weight = match style.weight {
-            presentar_core::widget::FontWeight::Bold => "bold",
-            presentar_core::widget::FontWeight::Medium => "500",
-            presentar_core::widget::FontWeight::Semibold => "600",
-            _ => "normal",
-        };
-        let font = format!("{} {}px sans-serif", weight, style.size);
-        self.ctx.set_font(&font);
+        self.ctx.set_font(&css_font(style));
         self.ctx.set_fill_style_str(&color_to_css(&style.color));
         self.ctx
             .fill_text(
@@ -369,6 +389,38 @@ impl Canvas2DRenderer {
     }
 }
 
+/// CSS font shorthand used to draw and measure text in `style`.
+fn css_font(style: &presentar_core::widget::TextStyle) -> String {
+    let weight = match style.weight {
+        presentar_core::widget::FontWeight::Bold => "bold",
+        presentar_core::widget::FontWeight::Medium => "500",
+        presentar_core::widget::FontWeight::Semibold => "600",
+        _ => "normal",
+    };
+    format!("{} {}px sans-serif", weight, style.size)
+}
+
+/// Advance width from `measureText`; height from the font's bounding box.
+///
+/// Falls back to the `Canvas` default estimate if the context rejects the call.
+fn measure_with(
+    ctx: &CanvasRenderingContext2d,
+    text: &str,
+    style: &presentar_core::widget::TextStyle,
+) -> Size {
+    ctx.set_font(&css_font(style));
+    match ctx.measure_text(text) {
+        Ok(metrics) => Size::new(
+            metrics.width() as f32,
+            (metrics.font_bounding_box_ascent() + metrics.font_bounding_box_descent()) as f32,
+        ),
+        Err(_) => Size::new(
+            text.chars().count() as f32 * style.size * 0.6,
+            style.size * 1.2,
+        ),
+    }
+}
+
 fn color_to_css(color: &Color) -> String {
     format!(
         "rgba({},{},{},{})",
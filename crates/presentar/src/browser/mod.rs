@@ -25,13 +25,15 @@ pub mod storage;
 pub use app::App;
 #[cfg(target_arch = "wasm32")]
 pub use canvas2d::Canvas2DRenderer;
-pub use router::{BrowserRouter, RouteMatch, RouteMatcher};
+pub use router::{BeforeEnter, BrowserRouter, ParamError, Resolution, RouteMatch, RouteMatcher};
 pub use shell_autocomplete::ShellAutocomplete;
 #[cfg(target_arch = "wasm32")]
 pub use shell_autocomplete::ShellAutocompleteDemo;
 #[cfg(target_arch = "wasm32")]
 pub use showcase::ShowcaseDemo;
-pub use storage::{ScopedStorage, Storage, StorageError, StorageType};
+pub use storage::{
+    Clock, ManualClock, ScopedStorage, Storage, StorageError, StorageType, SystemClock,
+};
 #[cfg(target_arch = "wasm32")]
 pub use websocket::{WebSocketClient, WebSocketError};
 
@@ -10,9 +10,29 @@
 //! let router = BrowserRouter::new();
 //! router.navigate("/dashboard");
 //! ```
+//!
+//! Routes can be guarded and can load data before they render:
+//!
+//! ```ignore
+//! use presentar::browser::router::{BeforeEnter, RouteMatcher};
+//!
+//! let mut matcher = RouteMatcher::new();
+//! matcher
+//!     .add("/login")
+//!     .with_guard("/admin", |_| is_logged_in())
+//!     .with_fallback("/login")
+//!     .before_enter("/admin", |m| Box::pin(async move {
+//!         load_admin_data().await;
+//!         BeforeEnter::Continue
+//!     }));
+//! ```
 
 use presentar_core::Router;
-use std::sync::Mutex;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 
 /// Browser router that uses the History API.
 ///
@@ -344,7 +364,9 @@ pub struct RouteMatch {
     /// The matched route pattern.
     pub pattern: String,
     /// Extracted path parameters.
-    pub params: std::collections::HashMap<String, String>,
+    pub params: HashMap<String, String>,
+    /// URL-decoded query parameters; repeated keys keep every value in order.
+    pub query: HashMap<String, Vec<String>>,
 }
 
 impl RouteMatch {
@@ -353,27 +375,165 @@ impl RouteMatch {
     pub fn new(pattern: impl Into<String>) -> Self {
         Self {
             pattern: pattern.into(),
-            params: std::collections::HashMap::new(),
+            params: HashMap::new(),
+            query: HashMap::new(),
         }
     }
 
-    /// Get a parameter value.
+    /// Get a raw parameter value.
     #[must_use]
-    pub fn param(&self, name: &str) -> Option<&str> {
+    pub fn param_str(&self, name: &str) -> Option<&str> {
         self.params.get(name).map(String::as_str)
     }
+
+    /// Get a parameter parsed as `T`.
+    pub fn param<T: FromStr>(&self, name: &str) -> Result<T, ParamError> {
+        let value = self
+            .param_str(name)
+            .ok_or_else(|| ParamError::Missing(name.to_string()))?;
+        value.parse().map_err(|_| ParamError::Invalid {
+            name: name.to_string(),
+            value: value.to_string(),
+        })
+    }
+
+    /// Get the first value of a query parameter.
+    #[must_use]
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query.get(name)?.first().map(String::as_str)
+    }
+
+    /// Get every value of a repeated query parameter (`?tag=a&tag=b`).
+    #[must_use]
+    pub fn query_all(&self, name: &str) -> &[String] {
+        self.query.get(name).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Error extracting a typed path parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// The route has no parameter with this name.
+    Missing(String),
+    /// The value could not be parsed as the requested type.
+    Invalid {
+        /// Parameter name
+        name: String,
+        /// Raw value
+        value: String,
+    },
+}
+
+impl std::fmt::Display for ParamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(name) => write!(f, "missing route parameter: {name}"),
+            Self::Invalid { name, value } => {
+                write!(f, "invalid value for route parameter {name}: {value:?}")
+            }
+        }
+    }
 }
 
+impl std::error::Error for ParamError {}
+
+/// Parse a query string (without the leading `?`) into decoded key/values.
+fn parse_query(query: &str) -> HashMap<String, Vec<String>> {
+    let mut map: HashMap<String, Vec<String>> = HashMap::new();
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        map.entry(url_decode(key))
+            .or_default()
+            .push(url_decode(value));
+    }
+    map
+}
+
+/// Decode `%XX` escapes and `+` (as space). Malformed escapes are kept as-is.
+fn url_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' => {
+                let hex = s
+                    .get(i + 1..i + 3)
+                    .and_then(|h| u8::from_str_radix(h, 16).ok());
+                if let Some(byte) = hex {
+                    out.push(byte);
+                    i += 2;
+                } else {
+                    out.push(b'%');
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Navigation guard: returns `false` to block entering a route.
+pub type RouteGuard = Arc<dyn Fn(&RouteMatch) -> bool + Send + Sync>;
+
+/// Future returned by a `before_enter` loader.
+pub type LoaderFuture = Pin<Box<dyn Future<Output = BeforeEnter>>>;
+
+/// Loader run before a route is entered.
+pub type RouteLoader = Arc<dyn Fn(RouteMatch) -> LoaderFuture + Send + Sync>;
+
+/// Outcome of a `before_enter` loader.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BeforeEnter {
+    /// Enter the route.
+    Continue,
+    /// Navigate to another path instead.
+    Redirect(String),
+    /// Stay on the current route for now (e.g. data still loading).
+    Defer,
+}
+
+/// Result of resolving a path with `RouteMatcher::resolve`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    /// Route to render.
+    Enter(RouteMatch),
+    /// A loader deferred navigation.
+    Deferred,
+    /// No route matched (or a guard failed with no fallback).
+    NotFound,
+}
+
+/// Maximum loader redirects followed by `resolve` before giving up.
+const MAX_REDIRECTS: usize = 8;
+
 /// Pattern-based route matcher.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct RouteMatcher {
     routes: Vec<RoutePattern>,
+    /// Path resolved instead when a guard rejects a route
+    fallback: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 struct RoutePattern {
     pattern: String,
     segments: Vec<Segment>,
+    guard: Option<RouteGuard>,
+    loader: Option<RouteLoader>,
+}
+
+impl std::fmt::Debug for RoutePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RoutePattern")
+            .field("pattern", &self.pattern)
+            .field("segments", &self.segments)
+            .field("guarded", &self.guard.is_some())
+            .field("has_loader", &self.loader.is_some())
+            .finish()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -387,7 +547,7 @@ impl RouteMatcher {
     /// Create a new route matcher.
     #[must_use]
     pub fn new() -> Self {
-        Self { routes: Vec::new() }
+        Self::default()
     }
 
     /// Add a route pattern.
@@ -414,37 +574,113 @@ impl RouteMatcher {
         self.routes.push(RoutePattern {
             pattern: pattern.to_string(),
             segments,
+            guard: None,
+            loader: None,
         });
         self
     }
 
+    /// Add a route pattern that can only be entered when `guard` passes.
+    ///
+    /// A rejected match resolves to the fallback path (see `with_fallback`),
+    /// or to no match when none is set.
+    pub fn with_guard<F>(&mut self, pattern: &str, guard: F) -> &mut Self
+    where
+        F: Fn(&RouteMatch) -> bool + Send + Sync + 'static,
+    {
+        self.add(pattern);
+        if let Some(route) = self.routes.last_mut() {
+            route.guard = Some(Arc::new(guard));
+        }
+        self
+    }
+
+    /// Set the path resolved when a guard rejects navigation.
+    pub fn with_fallback(&mut self, path: &str) -> &mut Self {
+        self.fallback = Some(path.to_string());
+        self
+    }
+
+    /// Attach an async loader that runs before entering `pattern`.
+    ///
+    /// The loader can continue, redirect, or defer; it only runs via
+    /// `resolve`. Has no effect if `pattern` was not added.
+    pub fn before_enter<F>(&mut self, pattern: &str, loader: F) -> &mut Self
+    where
+        F: Fn(RouteMatch) -> LoaderFuture + Send + Sync + 'static,
+    {
+        if let Some(route) = self.routes.iter_mut().find(|r| r.pattern == pattern) {
+            route.loader = Some(Arc::new(loader));
+        }
+        self
+    }
+
+    /// Match a path and run the route's `before_enter` loader.
+    ///
+    /// Loader redirects are followed up to a small limit to avoid loops.
+    // Loaders typically await browser fetches, which are not `Send` in WASM.
+    #[allow(clippy::future_not_send)]
+    pub async fn resolve(&self, path: &str) -> Resolution {
+        let mut path = path.to_string();
+        for _ in 0..=MAX_REDIRECTS {
+            let Some((route, m)) = self.match_route(&path) else {
+                return Resolution::NotFound;
+            };
+            let Some(loader) = &route.loader else {
+                return Resolution::Enter(m);
+            };
+            match loader(m.clone()).await {
+                BeforeEnter::Continue => return Resolution::Enter(m),
+                BeforeEnter::Defer => return Resolution::Deferred,
+                BeforeEnter::Redirect(to) => path = to,
+            }
+        }
+        Resolution::NotFound
+    }
+
     /// Match a path against registered routes.
+    ///
+    /// A match whose guard fails resolves to the fallback path instead.
     #[must_use]
     pub fn match_path(&self, path: &str) -> Option<RouteMatch> {
-        // Remove query string and hash
-        let path = path.split('?').next().unwrap_or(path);
+        self.match_route(path).map(|(_, m)| m)
+    }
+
+    fn match_route(&self, path: &str) -> Option<(&RoutePattern, RouteMatch)> {
+        let (route, m) = self.match_unguarded(path)?;
+        if route.guard.as_ref().map_or(true, |guard| guard(&m)) {
+            return Some((route, m));
+        }
+        // Fallback routes are not guarded again, so a guarded fallback
+        // cannot loop.
+        self.match_unguarded(self.fallback.as_deref()?)
+    }
+
+    fn match_unguarded(&self, path: &str) -> Option<(&RoutePattern, RouteMatch)> {
+        // Split off hash, then query string
         let path = path.split('#').next().unwrap_or(path);
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
 
         let path_segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
 
         for route in &self.routes {
             if let Some(params) = self.try_match(&route.segments, &path_segments) {
-                return Some(RouteMatch {
-                    pattern: route.pattern.clone(),
-                    params,
-                });
+                return Some((
+                    route,
+                    RouteMatch {
+                        pattern: route.pattern.clone(),
+                        params,
+                        query: parse_query(query),
+                    },
+                ));
             }
         }
 
         None
     }
 
-    fn try_match(
-        &self,
-        pattern: &[Segment],
-        path: &[&str],
-    ) -> Option<std::collections::HashMap<String, String>> {
-        let mut params = std::collections::HashMap::new();
+    fn try_match(&self, pattern: &[Segment], path: &[&str]) -> Option<HashMap<String, String>> {
+        let mut params = HashMap::new();
         let mut path_iter = path.iter();
 
         for segment in pattern {
@@ -477,12 +713,6 @@ impl RouteMatcher {
     }
 }
 
-impl Default for RouteMatcher {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -632,8 +862,8 @@ mod tests {
     fn test_route_match_param() {
         let mut m = RouteMatch::new("/users/:id");
         m.params.insert("id".to_string(), "123".to_string());
-        assert_eq!(m.param("id"), Some("123"));
-        assert_eq!(m.param("other"), None);
+        assert_eq!(m.param_str("id"), Some("123"));
+        assert_eq!(m.param_str("other"), None);
     }
 
     // =========================================================================
@@ -662,7 +892,7 @@ mod tests {
         assert!(result.is_some());
         let m = result.unwrap();
         assert_eq!(m.pattern, "/users/:id");
-        assert_eq!(m.param("id"), Some("123"));
+        assert_eq!(m.param_str("id"), Some("123"));
     }
 
     #[test]
@@ -673,8 +903,8 @@ mod tests {
         let result = matcher.match_path("/users/42/posts/99");
         assert!(result.is_some());
         let m = result.unwrap();
-        assert_eq!(m.param("userId"), Some("42"));
-        assert_eq!(m.param("postId"), Some("99"));
+        assert_eq!(m.param_str("userId"), Some("42"));
+        assert_eq!(m.param_str("postId"), Some("99"));
     }
 
     #[test]
@@ -685,7 +915,7 @@ mod tests {
         let result = matcher.match_path("/files/path/to/file.txt");
         assert!(result.is_some());
         let m = result.unwrap();
-        assert_eq!(m.param("*"), Some("path/to/file.txt"));
+        assert_eq!(m.param_str("*"), Some("path/to/file.txt"));
     }
 
     #[test]
@@ -757,7 +987,124 @@ mod tests {
 
         let result = matcher.match_path("/api/v1/users/456/profile");
         assert!(result.is_some());
-        assert_eq!(result.unwrap().param("id"), Some("456"));
+        assert_eq!(result.unwrap().param_str("id"), Some("456"));
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        struct NoopWake;
+        impl std::task::Wake for NoopWake {
+            fn wake(self: Arc<Self>) {}
+        }
+        let waker = std::task::Waker::from(Arc::new(NoopWake));
+        let mut cx = std::task::Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+        loop {
+            if let std::task::Poll::Ready(out) = future.as_mut().poll(&mut cx) {
+                return out;
+            }
+        }
+    }
+
+    #[test]
+    fn test_matcher_guard_fallback() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        let logged_in = Arc::new(AtomicBool::new(false));
+        let auth = Arc::clone(&logged_in);
+
+        let mut matcher = RouteMatcher::new();
+        matcher
+            .add("/login")
+            .with_guard("/admin", move |_| auth.load(Ordering::SeqCst))
+            .with_fallback("/login");
+
+        let m = matcher.match_path("/admin").unwrap();
+        assert_eq!(m.pattern, "/login");
+
+        logged_in.store(true, Ordering::SeqCst);
+        assert_eq!(matcher.match_path("/admin").unwrap().pattern, "/admin");
+    }
+
+    #[test]
+    fn test_matcher_guard_without_fallback() {
+        let mut matcher = RouteMatcher::new();
+        matcher.with_guard("/users/:id", |m| m.param_str("id") == Some("1"));
+        assert!(matcher.match_path("/users/1").is_some());
+        assert!(matcher.match_path("/users/2").is_none());
+    }
+
+    #[test]
+    fn test_matcher_before_enter() {
+        let mut matcher = RouteMatcher::new();
+        matcher
+            .add("/home")
+            .add("/old")
+            .add("/slow")
+            .add("/loop")
+            .before_enter("/old", |_| {
+                Box::pin(async { BeforeEnter::Redirect("/home".to_string()) })
+            })
+            .before_enter("/slow", |_| Box::pin(async { BeforeEnter::Defer }))
+            .before_enter("/loop", |_| {
+                Box::pin(async { BeforeEnter::Redirect("/loop".to_string()) })
+            });
+
+        match block_on(matcher.resolve("/old")) {
+            Resolution::Enter(m) => assert_eq!(m.pattern, "/home"),
+            other => panic!("expected /home, got {other:?}"),
+        }
+        assert_eq!(block_on(matcher.resolve("/slow")), Resolution::Deferred);
+        assert_eq!(block_on(matcher.resolve("/loop")), Resolution::NotFound);
+        assert_eq!(block_on(matcher.resolve("/none")), Resolution::NotFound);
+    }
+
+    #[test]
+    fn test_matcher_typed_param_and_query() {
+        let mut matcher = RouteMatcher::new();
+        matcher.add("/users/:id");
+
+        let m = matcher.match_path("/users/42?tab=info").unwrap();
+        assert_eq!(m.param::<u32>("id"), Ok(42));
+        assert_eq!(m.query("tab"), Some("info"));
+        assert_eq!(m.query("missing"), None);
+    }
+
+    #[test]
+    fn test_route_match_param_errors() {
+        let mut matcher = RouteMatcher::new();
+        matcher.add("/users/:id");
+        let m = matcher.match_path("/users/abc").unwrap();
+
+        assert_eq!(
+            m.param::<u32>("id"),
+            Err(ParamError::Invalid {
+                name: "id".to_string(),
+                value: "abc".to_string(),
+            })
+        );
+        assert_eq!(
+            m.param::<u32>("page"),
+            Err(ParamError::Missing("page".to_string()))
+        );
+        assert_eq!(m.param::<String>("id").unwrap(), "abc");
+        assert_eq!(
+            ParamError::Missing("page".to_string()).to_string(),
+            "missing route parameter: page"
+        );
+    }
+
+    #[test]
+    fn test_query_repeated_and_decoded() {
+        let mut matcher = RouteMatcher::new();
+        matcher.add("/search");
+
+        let m = matcher
+            .match_path("/search?q=hello+world%21&tag=a&tag=b%2Fc&flag&bad=%zz#top")
+            .unwrap();
+        assert_eq!(m.query("q"), Some("hello world!"));
+        assert_eq!(m.query_all("tag"), ["a", "b/c"]);
+        assert_eq!(m.query("flag"), Some(""));
+        assert_eq!(m.query("bad"), Some("%zz"));
+        assert!(m.query_all("none").is_empty());
     }
 
     #[test]
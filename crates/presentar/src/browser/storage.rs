@@ -6,14 +6,82 @@
 //!
 //! ```ignore
 //! use presentar::browser::storage::{Storage, StorageType};
+//! use std::time::Duration;
 //!
 //! let storage = Storage::new(StorageType::Local);
 //! storage.set("key", "value");
 //! let value = storage.get("key");
+//!
+//! // Expires after five minutes; `get` then returns `None`
+//! storage.set_with_ttl("dashboard", "{...}", Duration::from_secs(300));
 //! ```
 
 use serde::{de::DeserializeOwned, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Prefix marking a stored value that carries an expiry timestamp.
+///
+/// Layout: `"\u{1}ttl:<expires_at_ms>:<value>"`.
+const TTL_MARKER: &str = "\u{1}ttl:";
+
+/// Time source for expiring entries, in milliseconds since the Unix epoch.
+pub trait Clock: std::fmt::Debug + Send + Sync {
+    /// Current time in milliseconds.
+    fn now_ms(&self) -> u64;
+}
+
+/// Wall-clock time (`Date.now()` in WASM, `SystemTime` natively).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        #[cfg(target_arch = "wasm32")]
+        {
+            js_sys::Date::now() as u64
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_millis() as u64)
+        }
+    }
+}
+
+/// Manually advanced clock for tests.
+///
+/// Clones share the same time, so a test can keep one handle and give
+/// another to `Storage::with_clock`.
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now_ms: Arc<AtomicU64>,
+}
+
+impl ManualClock {
+    /// Create a clock starting at `start_ms`.
+    #[must_use]
+    pub fn new(start_ms: u64) -> Self {
+        Self {
+            now_ms: Arc::new(AtomicU64::new(start_ms)),
+        }
+    }
+
+    /// Move the clock forward.
+    pub fn advance(&self, by: Duration) {
+        self.now_ms
+            .fetch_add(by.as_millis() as u64, Ordering::SeqCst);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_ms(&self) -> u64 {
+        self.now_ms.load(Ordering::SeqCst)
+    }
+}
 
 /// Storage type (local or session).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -33,6 +101,8 @@ pub enum StorageType {
 #[derive(Debug)]
 pub struct Storage {
     storage_type: StorageType,
+    /// Time source for TTL expiry
+    clock: Arc<dyn Clock>,
     /// In-memory fallback for non-WASM environments
     #[cfg(not(target_arch = "wasm32"))]
     memory: std::sync::Mutex<HashMap<String, String>>,
@@ -50,11 +120,19 @@ impl Storage {
     pub fn new(storage_type: StorageType) -> Self {
         Self {
             storage_type,
+            clock: Arc::new(SystemClock),
             #[cfg(not(target_arch = "wasm32"))]
             memory: std::sync::Mutex::new(HashMap::new()),
         }
     }
 
+    /// Use `clock` for TTL expiry instead of the system clock.
+    #[must_use]
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
     /// Create localStorage instance.
     #[must_use]
     pub fn local() -> Self {
@@ -74,8 +152,25 @@ impl Storage {
     }
 
     /// Get a value from storage.
+    ///
+    /// Values stored with `set_with_ttl` are evicted and return `None` once
+    /// their TTL has passed.
     #[must_use]
     pub fn get(&self, key: &str) -> Option<String> {
+        let raw = self.get_raw(key)?;
+        let Some(rest) = raw.strip_prefix(TTL_MARKER) else {
+            return Some(raw);
+        };
+        let (expires_at, value) = rest.split_once(':')?;
+        let expires_at: u64 = expires_at.parse().ok()?;
+        if self.clock.now_ms() >= expires_at {
+            let _ = self.remove(key);
+            return None;
+        }
+        Some(value.to_string())
+    }
+
+    fn get_raw(&self, key: &str) -> Option<String> {
         #[cfg(target_arch = "wasm32")]
         {
             self.get_wasm(key)
@@ -86,6 +181,12 @@ impl Storage {
         }
     }
 
+    /// Set a value that expires `ttl` from now.
+    pub fn set_with_ttl(&self, key: &str, value: &str, ttl: Duration) -> Result<(), StorageError> {
+        let expires_at = self.clock.now_ms().saturating_add(ttl.as_millis() as u64);
+        self.set(key, &format!("{TTL_MARKER}{expires_at}:{value}"))
+    }
+
     /// Set a value in storage.
     pub fn set(&self, key: &str, value: &str) -> Result<(), StorageError> {
         #[cfg(target_arch = "wasm32")]
@@ -327,6 +428,11 @@ impl ScopedStorage {
         self.inner.set(&self.prefixed_key(key), value)
     }
 
+    /// Set a value that expires `ttl` from now.
+    pub fn set_with_ttl(&self, key: &str, value: &str, ttl: Duration) -> Result<(), StorageError> {
+        self.inner.set_with_ttl(&self.prefixed_key(key), value, ttl)
+    }
+
     /// Remove a value from storage.
     pub fn remove(&self, key: &str) -> Result<(), StorageError> {
         self.inner.remove(&self.prefixed_key(key))
@@ -533,6 +639,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_storage_ttl_expires() {
+        let clock = ManualClock::new(1_000);
+        let storage = Storage::local().with_clock(clock.clone());
+        storage
+            .set_with_ttl("cached", "data", Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(storage.get("cached"), Some("data".to_string()));
+
+        clock.advance(Duration::from_millis(999));
+        assert_eq!(storage.get("cached"), Some("data".to_string()));
+
+        clock.advance(Duration::from_millis(2));
+        assert_eq!(storage.get("cached"), None);
+        // Evicted, not just hidden
+        assert!(storage.is_empty());
+    }
+
+    #[test]
+    fn test_storage_ttl_json_and_plain_values() {
+        let clock = ManualClock::new(0);
+        let storage = Storage::local().with_clock(clock.clone());
+        storage.set("plain", "a:b").unwrap();
+        storage
+            .set_with_ttl("json", "[1,2]", Duration::from_secs(60))
+            .unwrap();
+
+        let loaded: Option<Vec<i32>> = storage.get_json("json").unwrap();
+        assert_eq!(loaded, Some(vec![1, 2]));
+
+        clock.advance(Duration::from_secs(3600));
+        assert_eq!(storage.get("plain"), Some("a:b".to_string()));
+        assert_eq!(storage.get_json::<Vec<i32>>("json").unwrap(), None);
+    }
+
+    #[test]
+    fn test_scoped_storage_ttl() {
+        let clock = ManualClock::new(0);
+        let scoped = ScopedStorage::new(Storage::local().with_clock(clock.clone()), "dash");
+        scoped
+            .set_with_ttl("cpu", "42", Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(scoped.get("cpu"), Some("42".to_string()));
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(scoped.get("cpu"), None);
+    }
+
     #[test]
     fn test_storage_default() {
         let storage = Storage::default();
@@ -84,5 +84,6 @@ pub use browser::{BrowserRouter, RouteMatch, RouteMatcher};
 mod webgpu;
 #[cfg(feature = "gpu")]
 pub use webgpu::{
-    commands_to_instances, Instance as GpuInstance, Uniforms as GpuUniforms, Vertex as GpuVertex,
+    commands_to_instances, commands_to_instances_with_atlas, Instance as GpuInstance,
+    Uniforms as GpuUniforms, Vertex as GpuVertex,
 };
@@ -9,8 +9,12 @@
 //! 1. Glyphs are rasterized and cached in a GPU texture atlas
 //! 2. Text is rendered as instanced quads sampling from the atlas
 //! 3. SDF (Signed Distance Field) allows smooth scaling
+//!
+//! Glyph quads share the shape instance buffer (`shape_type` 3), so shapes
+//! and text runs are drawn with a single instanced draw call.
 
 use presentar_core::draw::DrawCommand;
+use presentar_core::widget::FontWeight;
 use presentar_core::{Color, Point, Rect};
 use std::collections::HashMap;
 
@@ -43,16 +47,26 @@ impl Vertex {
 pub struct Instance {
     pub bounds: [f32; 4], // x, y, width, height
     pub color: [f32; 4],  // r, g, b, a
+    pub uv: [f32; 4],     // u0, v0, u1, v1 (glyphs only)
+    pub clip: [f32; 4],   // x, y, width, height (negative width = unclipped)
     pub corner_radius: f32,
-    pub shape_type: u32, // 0=rect, 1=circle, 2=rounded_rect
+    pub shape_type: u32, // 0=rect, 1=circle, 2=rounded_rect, 3=glyph
     pub _padding: [f32; 2],
 }
 
 impl Instance {
+    /// Shape type for glyph quads sampling the atlas.
+    pub const SHAPE_GLYPH: u32 = 3;
+
+    /// Clip value for instances outside any clip rectangle.
+    pub const NO_CLIP: [f32; 4] = [0.0, 0.0, -1.0, -1.0];
+
     pub fn rect(bounds: &Rect, color: &Color) -> Self {
         Self {
             bounds: [bounds.x, bounds.y, bounds.width, bounds.height],
             color: [color.r, color.g, color.b, color.a],
+            uv: [0.0; 4],
+            clip: Self::NO_CLIP,
             corner_radius: 0.0,
             shape_type: 0,
             _padding: [0.0; 2],
@@ -63,12 +77,28 @@ impl Instance {
         Self {
             bounds: [bounds.x, bounds.y, bounds.width, bounds.height],
             color: [color.r, color.g, color.b, color.a],
+            uv: [0.0; 4],
+            clip: Self::NO_CLIP,
             corner_radius: radius,
             shape_type: 2,
             _padding: [0.0; 2],
         }
     }
 
+    /// Glyph quad sampling the atlas UV rect of a laid-out glyph.
+    #[must_use]
+    pub const fn glyph(glyph: &GlyphInstance) -> Self {
+        Self {
+            bounds: glyph.bounds,
+            color: glyph.color,
+            uv: glyph.uvs,
+            clip: Self::NO_CLIP,
+            corner_radius: 0.0,
+            shape_type: Self::SHAPE_GLYPH,
+            _padding: [0.0; 2],
+        }
+    }
+
     pub fn circle(center: &Point, radius: f32, color: &Color) -> Self {
         Self {
             bounds: [
@@ -78,11 +108,20 @@ impl Instance {
                 radius * 2.0,
             ],
             color: [color.r, color.g, color.b, color.a],
+            uv: [0.0; 4],
+            clip: Self::NO_CLIP,
             corner_radius: radius,
             shape_type: 1,
             _padding: [0.0; 2],
         }
     }
+
+    /// Restrict this instance to `clip`; fragments outside it are discarded.
+    #[must_use]
+    pub const fn with_clip(mut self, clip: &Rect) -> Self {
+        self.clip = [clip.x, clip.y, clip.width, clip.height];
+        self
+    }
 }
 
 /// Uniforms for the shader.
@@ -90,7 +129,8 @@ impl Instance {
 #[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Uniforms {
     pub viewport: [f32; 2],
-    pub _padding: [f32; 2],
+    /// Glyph atlas size in pixels.
+    pub atlas_size: [f32; 2],
 }
 
 /// Unit quad vertices for instanced rendering.
@@ -116,9 +156,88 @@ pub const QUAD_VERTICES: &[Vertex] = &[
 pub const QUAD_INDICES: &[u16] = &[0, 1, 2, 0, 2, 3];
 
 /// Convert DrawCommands to GPU instances for batched rendering.
+///
+/// Text is skipped; use `commands_to_instances_with_atlas` to include it.
 pub fn commands_to_instances(commands: &[DrawCommand]) -> Vec<Instance> {
     let mut instances = Vec::with_capacity(commands.len());
+    collect_instances(commands, None, &mut instances);
+    instances
+}
+
+/// Convert DrawCommands to GPU instances, laying out text as glyph quads.
+///
+/// Each glyph cached in `glyphs` becomes one instance referencing its atlas
+/// UV rect, so a text run is drawn in the same instanced call as shapes.
+/// Glyphs missing from the cache advance the cursor but are not drawn.
+pub fn commands_to_instances_with_atlas(
+    commands: &[DrawCommand],
+    glyphs: &GlyphCache,
+) -> Vec<Instance> {
+    let mut instances = Vec::with_capacity(commands.len());
+    collect_instances(commands, Some(glyphs), &mut instances);
+    instances
+}
 
+/// Numeric CSS weight for glyph cache keys.
+const fn font_weight_value(weight: FontWeight) -> u16 {
+    match weight {
+        FontWeight::Thin => 100,
+        FontWeight::Light => 300,
+        FontWeight::Normal => 400,
+        FontWeight::Medium => 500,
+        FontWeight::Semibold => 600,
+        FontWeight::Bold => 700,
+        FontWeight::Black => 900,
+    }
+}
+
+/// Instance collection state threaded through nested commands.
+struct InstanceSink<'a> {
+    instances: &'a mut Vec<Instance>,
+    /// Effective (already intersected) clip rectangles, innermost last.
+    clips: Vec<Rect>,
+}
+
+impl InstanceSink<'_> {
+    fn push(&mut self, instance: Instance) {
+        match self.clips.last() {
+            // Fully clipped away
+            Some(clip) if clip.width <= 0.0 || clip.height <= 0.0 => {}
+            Some(clip) => self.instances.push(instance.with_clip(clip)),
+            None => self.instances.push(instance),
+        }
+    }
+
+    fn push_clip(&mut self, bounds: &Rect) {
+        let clip = match self.clips.last() {
+            Some(outer) => outer.intersection(bounds).unwrap_or_default(),
+            None => *bounds,
+        };
+        self.clips.push(clip);
+    }
+
+    fn pop_clip(&mut self) {
+        self.clips.pop();
+    }
+}
+
+fn collect_instances(
+    commands: &[DrawCommand],
+    glyphs: Option<&GlyphCache>,
+    instances: &mut Vec<Instance>,
+) {
+    let mut sink = InstanceSink {
+        instances,
+        clips: Vec::new(),
+    };
+    collect_into(commands, glyphs, &mut sink);
+}
+
+fn collect_into(
+    commands: &[DrawCommand],
+    glyphs: Option<&GlyphCache>,
+    sink: &mut InstanceSink<'_>,
+) {
     for cmd in commands {
         match cmd {
             DrawCommand::Rect {
@@ -128,9 +247,9 @@ pub fn commands_to_instances(commands: &[DrawCommand]) -> Vec<Instance> {
             } => {
                 if let Some(fill) = style.fill {
                     if radius.is_zero() {
-                        instances.push(Instance::rect(bounds, &fill));
+                        sink.push(Instance::rect(bounds, &fill));
                     } else {
-                        instances.push(Instance::rounded_rect(bounds, radius.top_left, &fill));
+                        sink.push(Instance::rounded_rect(bounds, radius.top_left, &fill));
                     }
                 }
             }
@@ -140,20 +259,44 @@ pub fn commands_to_instances(commands: &[DrawCommand]) -> Vec<Instance> {
                 style,
             } => {
                 if let Some(fill) = style.fill {
-                    instances.push(Instance::circle(center, *radius, &fill));
+                    sink.push(Instance::circle(center, *radius, &fill));
+                }
+            }
+            DrawCommand::Text {
+                content,
+                position,
+                style,
+            } => {
+                if let Some(cache) = glyphs {
+                    let options =
+                        TextOptions::new(style.size).with_weight(font_weight_value(style.weight));
+                    let layout = layout_text(
+                        content,
+                        position.x,
+                        position.y,
+                        cache,
+                        &options,
+                        &style.color,
+                    );
+                    for glyph in &layout.glyphs {
+                        sink.push(Instance::glyph(glyph));
+                    }
                 }
             }
-            // Text requires a separate text rendering pipeline
-            DrawCommand::Text { .. } => {}
             // Groups recurse
             DrawCommand::Group { children, .. } => {
-                instances.extend(commands_to_instances(children));
+                collect_into(children, glyphs, sink);
+            }
+            DrawCommand::Clip { bounds, child } => {
+                sink.push_clip(bounds);
+                collect_into(std::slice::from_ref(child), glyphs, sink);
+                sink.pop_clip();
             }
+            DrawCommand::PushClip { bounds } => sink.push_clip(bounds),
+            DrawCommand::PopClip => sink.pop_clip(),
             _ => {}
         }
     }
-
-    instances
 }
 
 /// Check if WebGPU is available in the current environment.
@@ -787,6 +930,10 @@ pub struct GpuResources {
     pub bind_group_layout: wgpu::BindGroupLayout,
     /// Shape render pipeline.
     pub shape_pipeline: wgpu::RenderPipeline,
+    /// Glyph atlas texture (single-channel coverage).
+    pub atlas_texture: wgpu::Texture,
+    /// Glyph atlas size in pixels.
+    pub atlas_size: (u32, u32),
     /// Max instances.
     pub max_instances: usize,
     /// Current surface format.
@@ -799,6 +946,7 @@ pub struct GpuResourceBuilder {
     queue: wgpu::Queue,
     format: wgpu::TextureFormat,
     max_instances: usize,
+    atlas_size: (u32, u32),
 }
 
 impl GpuResourceBuilder {
@@ -810,6 +958,7 @@ impl GpuResourceBuilder {
             queue,
             format,
             max_instances: 10_000,
+            atlas_size: (1024, 1024),
         }
     }
 
@@ -820,6 +969,13 @@ impl GpuResourceBuilder {
         self
     }
 
+    /// Set glyph atlas size (should match the `GlyphCache` atlas).
+    #[must_use]
+    pub const fn with_atlas_size(mut self, width: u32, height: u32) -> Self {
+        self.atlas_size = (width, height);
+        self
+    }
+
     /// Build the GPU resources.
     ///
     /// # Errors
@@ -861,31 +1017,82 @@ impl GpuResourceBuilder {
             mapped_at_creation: false,
         });
 
+        // Create glyph atlas texture and sampler
+        let atlas_texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Glyph Atlas"),
+            size: wgpu::Extent3d {
+                width: self.atlas_size.0,
+                height: self.atlas_size.1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::R8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let atlas_sampler = self.device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Glyph Atlas Sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
         // Create bind group layout
         let bind_group_layout =
             self.device
                 .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                     label: Some("Uniform Bind Group Layout"),
-                    entries: &[wgpu::BindGroupLayoutEntry {
-                        binding: 0,
-                        visibility: wgpu::ShaderStages::VERTEX,
-                        ty: wgpu::BindingType::Buffer {
-                            ty: wgpu::BufferBindingType::Uniform,
-                            has_dynamic_offset: false,
-                            min_binding_size: None,
+                    entries: &[
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 0,
+                            visibility: wgpu::ShaderStages::VERTEX,
+                            ty: wgpu::BindingType::Buffer {
+                                ty: wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
                         },
-                        count: None,
-                    }],
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: wgpu::ShaderStages::FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                                view_dimension: wgpu::TextureViewDimension::D2,
+                                multisampled: false,
+                            },
+                            count: None,
+                        },
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 2,
+                            visibility: wgpu::ShaderStages::FRAGMENT,
+                            ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                            count: None,
+                        },
+                    ],
                 });
 
         // Create bind group
         let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Uniform Bind Group"),
             layout: &bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: uniform_buffer.as_entire_binding(),
-            }],
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&atlas_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(&atlas_sampler),
+                },
+            ],
         });
 
         // Compile shader
@@ -919,14 +1126,26 @@ impl GpuResourceBuilder {
                 shader_location: 3,
                 format: wgpu::VertexFormat::Float32x4,
             },
-            // corner_radius + shape_type (packed as float + u32)
+            // uv (vec4)
             wgpu::VertexAttribute {
                 offset: 32,
+                shader_location: 6,
+                format: wgpu::VertexFormat::Float32x4,
+            },
+            // clip (vec4)
+            wgpu::VertexAttribute {
+                offset: 48,
+                shader_location: 7,
+                format: wgpu::VertexFormat::Float32x4,
+            },
+            // corner_radius + shape_type (packed as float + u32)
+            wgpu::VertexAttribute {
+                offset: 64,
                 shader_location: 4,
                 format: wgpu::VertexFormat::Float32,
             },
             wgpu::VertexAttribute {
-                offset: 36,
+                offset: 68,
                 shader_location: 5,
                 format: wgpu::VertexFormat::Uint32,
             },
@@ -986,12 +1205,50 @@ impl GpuResourceBuilder {
             bind_group,
             bind_group_layout,
             shape_pipeline,
+            atlas_texture,
+            atlas_size: self.atlas_size,
             max_instances: self.max_instances,
             format: self.format,
         })
     }
 }
 
+impl GpuResources {
+    /// Upload a rasterized glyph (one coverage byte per pixel) into the atlas.
+    pub fn write_atlas_region(&self, region: AtlasRegion, pixels: &[u8]) {
+        if region.is_empty() {
+            return;
+        }
+        debug_assert_eq!(
+            pixels.len(),
+            usize::from(region.width) * usize::from(region.height)
+        );
+        self.queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: &self.atlas_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: u32::from(region.x),
+                    y: u32::from(region.y),
+                    z: 0,
+                },
+                aspect: wgpu::TextureAspect::All,
+            },
+            pixels,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(u32::from(region.width)),
+                rows_per_image: Some(u32::from(region.height)),
+            },
+            wgpu::Extent3d {
+                width: u32::from(region.width),
+                height: u32::from(region.height),
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+}
+
 // DeviceExt provides buffer creation methods with initial data
 use wgpu::util::DeviceExt;
 
@@ -999,12 +1256,18 @@ use wgpu::util::DeviceExt;
 pub const PRIMITIVE_SHADER: &str = r#"
 struct Uniforms {
     viewport: vec2<f32>,
-    _padding: vec2<f32>,
+    atlas_size: vec2<f32>,
 }
 
 @group(0) @binding(0)
 var<uniform> uniforms: Uniforms;
 
+@group(0) @binding(1)
+var glyph_atlas: texture_2d<f32>;
+
+@group(0) @binding(2)
+var glyph_sampler: sampler;
+
 struct VertexInput {
     @location(0) position: vec2<f32>,
     @location(1) uv: vec2<f32>,
@@ -1014,7 +1277,9 @@ struct InstanceInput {
     @location(2) bounds: vec4<f32>,      // x, y, width, height
     @location(3) color: vec4<f32>,       // r, g, b, a
     @location(4) corner_radius: f32,
-    @location(5) shape_type: u32,        // 0=rect, 1=circle, 2=rounded_rect
+    @location(5) shape_type: u32,        // 0=rect, 1=circle, 2=rounded_rect, 3=glyph
+    @location(6) uv: vec4<f32>,          // u0, v0, u1, v1
+    @location(7) clip: vec4<f32>,        // x, y, width, height (width < 0 = none)
 }
 
 struct VertexOutput {
@@ -1024,6 +1289,9 @@ struct VertexOutput {
     @location(2) @interpolate(flat) shape_type: u32,
     @location(3) @interpolate(flat) corner_radius: f32,
     @location(4) size: vec2<f32>,
+    @location(5) uv: vec2<f32>,
+    @location(6) @interpolate(flat) clip: vec4<f32>,
+    @location(7) world_pos: vec2<f32>,
 }
 
 @vertex
@@ -1043,6 +1311,9 @@ fn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {
     out.shape_type = instance.shape_type;
     out.corner_radius = instance.corner_radius;
     out.size = instance.bounds.zw;
+    out.uv = mix(instance.uv.xy, instance.uv.zw, vertex.position);
+    out.clip = instance.clip;
+    out.world_pos = world_pos;
 
     return out;
 }
@@ -1063,7 +1334,22 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
 
     var alpha = in.color.a;
 
-    if in.shape_type == 1u {
+    // Sampled unconditionally: textureSample requires uniform control flow
+    let coverage = textureSample(glyph_atlas, glyph_sampler, in.uv).r;
+
+    // Discard fragments outside the clip rect (in viewport units, like bounds)
+    if in.clip.z >= 0.0 {
+        let p = in.world_pos;
+        let clip_max = in.clip.xy + in.clip.zw;
+        if p.x < in.clip.x || p.y < in.clip.y || p.x >= clip_max.x || p.y >= clip_max.y {
+            discard;
+        }
+    }
+
+    if in.shape_type == 3u {
+        // Glyph: atlas coverage is the alpha mask
+        alpha *= coverage;
+    } else if in.shape_type == 1u {
         // Circle: use SDF
         let d = sdf_circle(centered, 1.0);
         let aa = fwidth(d);
@@ -1119,7 +1405,7 @@ pub fn render_instances(
     // Update uniform buffer
     let uniforms = Uniforms {
         viewport: [viewport.0, viewport.1],
-        _padding: [0.0, 0.0],
+        atlas_size: [resources.atlas_size.0 as f32, resources.atlas_size.1 as f32],
     };
     resources
         .queue
@@ -1202,7 +1488,7 @@ mod tests {
 
     #[test]
     fn test_instance_size() {
-        assert_eq!(std::mem::size_of::<Instance>(), 48);
+        assert_eq!(std::mem::size_of::<Instance>(), 80);
     }
 
     #[test]
@@ -1250,6 +1536,92 @@ mod tests {
         assert_eq!(instances.len(), 2);
     }
 
+    #[test]
+    fn test_commands_to_instances_text_run() {
+        let mut cache = GlyphCache::new(256, 256);
+        for (i, ch) in "abcd".chars().enumerate() {
+            let region = cache.allocate(8, 12).unwrap();
+            assert_eq!(region.x, i as u16 * 9);
+            cache.insert(
+                GlyphKey::new(ch, 16, 400),
+                CachedGlyph::new(region, 9.0, 0.0, 10.0),
+            );
+        }
+        let style = presentar_core::widget::TextStyle::default();
+        let commands = vec![
+            DrawCommand::filled_rect(Rect::new(0.0, 0.0, 100.0, 20.0), Color::WHITE),
+            DrawCommand::Text {
+                content: "abcd".to_string(),
+                position: Point::new(10.0, 14.0),
+                style,
+            },
+        ];
+
+        let instances = commands_to_instances_with_atlas(&commands, &cache);
+        let glyphs: Vec<&Instance> = instances
+            .iter()
+            .filter(|i| i.shape_type == Instance::SHAPE_GLYPH)
+            .collect();
+        assert_eq!(instances.len(), 5);
+        assert_eq!(glyphs.len(), 4);
+        for pair in glyphs.windows(2) {
+            assert!(pair[1].uv[0] > pair[0].uv[0], "UVs must differ");
+            assert!(pair[1].bounds[0] > pair[0].bounds[0]);
+        }
+        assert_eq!(
+            glyphs[0].uv,
+            cache
+                .get(&GlyphKey::new('a', 16, 400))
+                .unwrap()
+                .region
+                .to_uvs(256, 256)
+        );
+
+        // Without an atlas, text is skipped as before
+        assert_eq!(commands_to_instances(&commands).len(), 1);
+    }
+
+    #[test]
+    fn test_commands_to_instances_nested_clips_intersect() {
+        let commands = vec![
+            DrawCommand::filled_rect(Rect::new(0.0, 0.0, 10.0, 10.0), Color::WHITE),
+            DrawCommand::PushClip {
+                bounds: Rect::new(10.0, 10.0, 100.0, 100.0),
+            },
+            DrawCommand::PushClip {
+                bounds: Rect::new(50.0, 0.0, 100.0, 80.0),
+            },
+            // Partially outside both clips
+            DrawCommand::filled_rect(Rect::new(40.0, 40.0, 100.0, 100.0), Color::RED),
+            DrawCommand::PopClip,
+            DrawCommand::filled_circle(Point::new(20.0, 20.0), 5.0, Color::BLUE),
+            DrawCommand::PopClip,
+            DrawCommand::filled_rect(Rect::new(0.0, 0.0, 10.0, 10.0), Color::WHITE),
+        ];
+
+        let instances = commands_to_instances(&commands);
+        assert_eq!(instances.len(), 4);
+        assert_eq!(instances[0].clip, Instance::NO_CLIP);
+        assert_eq!(instances[1].clip, [50.0, 10.0, 60.0, 70.0]);
+        assert_eq!(instances[1].bounds, [40.0, 40.0, 100.0, 100.0]);
+        assert_eq!(instances[2].clip, [10.0, 10.0, 100.0, 100.0]);
+        assert_eq!(instances[3].clip, Instance::NO_CLIP);
+    }
+
+    #[test]
+    fn test_commands_to_instances_disjoint_clip_drops_instances() {
+        let inner = DrawCommand::filled_rect(Rect::new(0.0, 0.0, 10.0, 10.0), Color::RED)
+            .with_clip(Rect::new(20.0, 20.0, 5.0, 5.0));
+        let commands = vec![
+            DrawCommand::PushClip {
+                bounds: Rect::new(0.0, 0.0, 10.0, 10.0),
+            },
+            inner,
+            DrawCommand::PopClip,
+        ];
+        assert!(commands_to_instances(&commands).is_empty());
+    }
+
     #[test]
     fn test_quad_vertices() {
         assert_eq!(QUAD_VERTICES.len(), 4);
@@ -1688,6 +2060,9 @@ mod tests {
         assert!(PRIMITIVE_SHADER.contains("fn fs_main"));
         assert!(PRIMITIVE_SHADER.contains("sdf_circle"));
         assert!(PRIMITIVE_SHADER.contains("sdf_rounded_rect"));
+        // Clip rects share the viewport space of instance bounds, not
+        // framebuffer pixels, so HiDPI scaling cannot shift them.
+        assert!(PRIMITIVE_SHADER.contains("let p = in.world_pos;"));
     }
 
     #[test]
@@ -0,0 +1,436 @@
+#!/usr/bin/env python3
+"""Regenerate the Parquet fixtures used by presentar-yaml's reader tests.
+
+The files follow the layout parquet-cpp (pyarrow) writes: dictionary pages,
+page and chunk statistics, encoding stats, logical types, key/value metadata,
+column orders and an offset index after the row groups. The reader skips
+most of that metadata, which is exactly what these fixtures exercise.
+
+Usage: python3 scripts/parquet_fixtures.py
+"""
+
+import struct
+from pathlib import Path
+
+OUT = Path(__file__).resolve().parent.parent / "crates/presentar-yaml/tests/fixtures"
+
+# Thrift compact types
+BOOL_TRUE, BOOL_FALSE, I16, I32, I64, BINARY, LIST, STRUCT = 1, 2, 4, 5, 6, 8, 9, 12
+
+# Parquet enums
+BOOLEAN, INT32, INT64, FLOAT, DOUBLE, BYTE_ARRAY = 0, 1, 2, 4, 5, 6
+REQUIRED, OPTIONAL = 0, 1
+PLAIN, PLAIN_DICTIONARY, RLE, RLE_DICTIONARY = 0, 2, 3, 8
+UNCOMPRESSED, SNAPPY = 0, 1
+DATA_PAGE, DICTIONARY_PAGE, DATA_PAGE_V2 = 0, 2, 3
+UTF8 = 0
+
+
+def varint(value):
+    out = bytearray()
+    while value >= 0x80:
+        out.append((value & 0x7F) | 0x80)
+        value >>= 7
+    out.append(value)
+    return bytes(out)
+
+
+def zigzag(value):
+    return varint((value << 1) ^ (value >> 63))
+
+
+class Compact:
+    """Thrift compact protocol writer."""
+
+    def __init__(self):
+        self.out = bytearray()
+        self.last = [0]
+
+    def field(self, fid, ftype):
+        delta = fid - self.last[-1]
+        if 0 < delta <= 15:
+            self.out.append((delta << 4) | ftype)
+        else:
+            self.out.append(ftype)
+            self.out += zigzag(fid)
+        self.last[-1] = fid
+
+    def i32(self, fid, value):
+        self.field(fid, I32)
+        self.out += zigzag(value)
+
+    def i16(self, fid, value):
+        self.field(fid, I16)
+        self.out += zigzag(value)
+
+    def i64(self, fid, value):
+        self.field(fid, I64)
+        self.out += zigzag(value)
+
+    def boolean(self, fid, value):
+        self.field(fid, BOOL_TRUE if value else BOOL_FALSE)
+
+    def binary(self, fid, value):
+        self.field(fid, BINARY)
+        self.raw_binary(value)
+
+    def raw_binary(self, value):
+        if isinstance(value, str):
+            value = value.encode()
+        self.out += varint(len(value))
+        self.out += value
+
+    def list(self, fid, etype, size):
+        self.field(fid, LIST)
+        if size < 15:
+            self.out.append((size << 4) | etype)
+        else:
+            self.out.append(0xF0 | etype)
+            self.out += varint(size)
+
+    def begin(self, fid=None):
+        if fid is not None:
+            self.field(fid, STRUCT)
+        self.last.append(0)
+
+    def end(self):
+        self.out.append(0)
+        self.last.pop()
+
+
+def hybrid(values, bit_width):
+    """RLE / bit-packed hybrid as parquet-cpp's RleEncoder emits it for short
+    runs: one bit-packed run, zero-padded to a multiple of eight values."""
+    groups = (len(values) + 7) // 8
+    padded = values + [0] * (groups * 8 - len(values))
+    bits = 0
+    for i, value in enumerate(padded):
+        bits |= value << (i * bit_width)
+    packed = bits.to_bytes(groups * bit_width, "little")
+    return varint((groups << 1) | 1) + packed
+
+
+def snappy(data):
+    """Raw Snappy block compressor (greedy, 4-byte hash matches)."""
+    out = bytearray(varint(len(data)))
+    table = {}
+    literal_start = 0
+    i = 0
+
+    def emit_literal(start, end):
+        n = end - start - 1
+        if n < 60:
+            out.append(n << 2)
+        elif n < 256:
+            out.extend([60 << 2, n])
+        else:
+            out.extend(bytes([61 << 2]) + n.to_bytes(2, "little"))
+        out.extend(data[start:end])
+
+    while i + 4 <= len(data):
+        key = data[i : i + 4]
+        candidate = table.get(key)
+        table[key] = i
+        if candidate is None or i - candidate > 0xFFFF:
+            i += 1
+            continue
+        length = 4
+        while i + length < len(data) and data[candidate + length] == data[i + length] and length < 64:
+            length += 1
+        if literal_start < i:
+            emit_literal(literal_start, i)
+        offset = i - candidate
+        if length <= 11 and offset < 2048:
+            out.append(((offset >> 8) << 5) | ((length - 4) << 2) | 0b01)
+            out.append(offset & 0xFF)
+        else:
+            out.append(((length - 1) << 2) | 0b10)
+            out += offset.to_bytes(2, "little")
+        i += length
+        literal_start = i
+    if literal_start < len(data):
+        emit_literal(literal_start, len(data))
+    return bytes(out)
+
+
+def plain(physical, values):
+    if physical == BOOLEAN:
+        bits = 0
+        for i, value in enumerate(values):
+            bits |= int(value) << i
+        return bits.to_bytes((len(values) + 7) // 8, "little")
+    if physical == INT32:
+        return b"".join(struct.pack("<i", v) for v in values)
+    if physical == INT64:
+        return b"".join(struct.pack("<q", v) for v in values)
+    if physical == FLOAT:
+        return b"".join(struct.pack("<f", v) for v in values)
+    if physical == DOUBLE:
+        return b"".join(struct.pack("<d", v) for v in values)
+    return b"".join(struct.pack("<I", len(v)) + v.encode() for v in values)
+
+
+def statistics(w, fid, physical, values):
+    present = [v for v in values if v is not None]
+    w.begin(fid)
+    w.i64(3, len(values) - len(present))
+    if present:
+        w.binary(5, plain(physical, [max(present)]) if physical != BYTE_ARRAY else max(present))
+        w.binary(6, plain(physical, [min(present)]) if physical != BYTE_ARRAY else min(present))
+    w.end()
+
+
+class Column:
+    def __init__(self, name, physical, optional, dictionary, string=False):
+        self.name = name
+        self.physical = physical
+        self.optional = optional
+        self.dictionary = dictionary
+        self.string = string
+
+
+def page_header(page_type, uncompressed, compressed, body):
+    w = Compact()
+    w.i32(1, page_type)
+    w.i32(2, uncompressed)
+    w.i32(3, compressed)
+    body(w)
+    w.end()
+    return bytes(w.out)
+
+
+def write_chunk(file, column, values, codec, v2):
+    """Append one column chunk; returns its metadata."""
+    compress = snappy if codec == SNAPPY else (lambda data: data)
+    present = [v for v in values if v is not None]
+    defined = [int(v is not None) for v in values]
+    start = len(file)
+    dictionary_offset = None
+    encodings = []
+    stats = []
+
+    if column.dictionary:
+        entries = sorted(set(present), key=present.index)
+        raw = plain(column.physical, entries)
+        body = compress(raw)
+        dictionary_offset = len(file)
+
+        def dict_header(w):
+            w.begin(7)
+            w.i32(1, len(entries))
+            w.i32(2, PLAIN_DICTIONARY if not v2 else PLAIN)
+            w.boolean(3, False)
+            w.end()
+
+        file += page_header(DICTIONARY_PAGE, len(raw), len(body), dict_header) + body
+        bit_width = max(1, (len(entries) - 1).bit_length())
+        raw_values = bytes([bit_width]) + hybrid([entries.index(v) for v in present], bit_width)
+        encoding = RLE_DICTIONARY if v2 else PLAIN_DICTIONARY
+        encodings += [PLAIN_DICTIONARY if not v2 else PLAIN]
+        stats.append((DICTIONARY_PAGE, encodings[0]))
+    else:
+        raw_values = plain(column.physical, present)
+        encoding = PLAIN
+    encodings += [RLE, encoding]
+    stats.append((DATA_PAGE_V2 if v2 else DATA_PAGE, encoding))
+
+    data_offset = len(file)
+    levels = hybrid(defined, 1) if column.optional else b""
+    if v2:
+        body_values = compress(raw_values)
+        uncompressed = len(levels) + len(raw_values)
+        compressed = len(levels) + len(body_values)
+
+        def data_header(w):
+            w.begin(8)
+            w.i32(1, len(values))
+            w.i32(2, len(values) - len(present))
+            w.i32(3, len(values))
+            w.i32(4, encoding)
+            w.i32(5, len(levels))
+            w.i32(6, 0)
+            w.boolean(7, codec != UNCOMPRESSED)
+            statistics(w, 8, column.physical, values)
+            w.end()
+
+        file += page_header(DATA_PAGE_V2, uncompressed, compressed, data_header)
+        file += levels + body_values
+    else:
+        raw = (struct.pack("<I", len(levels)) + levels if column.optional else b"") + raw_values
+        body = compress(raw)
+
+        def data_header(w):
+            w.begin(5)
+            w.i32(1, len(values))
+            w.i32(2, encoding)
+            w.i32(3, RLE)
+            w.i32(4, RLE)
+            statistics(w, 5, column.physical, values)
+            w.end()
+
+        file += page_header(DATA_PAGE, len(raw), len(body), data_header) + body
+
+    return {
+        "column": column,
+        "values": values,
+        "codec": codec,
+        "start": start,
+        "size": len(file) - start,
+        "data_offset": data_offset,
+        "dictionary_offset": dictionary_offset,
+        "encodings": sorted(set(encodings)),
+        "stats": stats,
+    }
+
+
+def write_file(path, columns, groups, codec, v2):
+    file = bytearray(b"PAR1")
+    row_groups = []
+    for rows in groups:
+        chunks = [write_chunk(file, c, rows[c.name], codec, v2) for c in columns]
+        row_groups.append((len(next(iter(rows.values()))), chunks))
+
+    # Offset index for every chunk, as parquet-cpp writes after the data.
+    for _, chunks in row_groups:
+        for chunk in chunks:
+            w = Compact()
+            w.list(1, STRUCT, 1)
+            w.begin()
+            w.i64(1, chunk["data_offset"])
+            w.i32(2, chunk["start"] + chunk["size"] - chunk["data_offset"])
+            w.i64(3, 0)
+            w.end()
+            w.end()
+            chunk["offset_index"] = (len(file), len(w.out))
+            file += w.out
+
+    total_rows = sum(rows for rows, _ in row_groups)
+    w = Compact()
+    w.i32(1, 2)
+    w.list(2, STRUCT, len(columns) + 1)
+    w.begin()
+    w.binary(4, "schema")
+    w.i32(5, len(columns))
+    w.end()
+    for column in columns:
+        w.begin()
+        w.i32(1, column.physical)
+        w.i32(3, OPTIONAL if column.optional else REQUIRED)
+        w.binary(4, column.name)
+        if column.string:
+            w.i32(6, UTF8)
+            w.begin(10)
+            w.begin(1)
+            w.end()
+            w.end()
+        w.end()
+    w.i64(3, total_rows)
+    w.list(4, STRUCT, len(row_groups))
+    for ordinal, (rows, chunks) in enumerate(row_groups):
+        w.begin()
+        w.list(1, STRUCT, len(chunks))
+        for chunk in chunks:
+            column = chunk["column"]
+            w.begin()
+            w.i64(2, chunk["dictionary_offset"] or chunk["data_offset"])
+            w.begin(3)
+            w.i32(1, column.physical)
+            w.list(2, I32, len(chunk["encodings"]))
+            for encoding in chunk["encodings"]:
+                w.out += zigzag(encoding)
+            w.list(3, BINARY, 1)
+            w.raw_binary(column.name)
+            w.i32(4, chunk["codec"])
+            w.i64(5, len(chunk["values"]))
+            w.i64(6, chunk["size"])
+            w.i64(7, chunk["size"])
+            w.i64(9, chunk["data_offset"])
+            if chunk["dictionary_offset"] is not None:
+                w.i64(11, chunk["dictionary_offset"])
+            statistics(w, 12, column.physical, chunk["values"])
+            w.list(13, STRUCT, len(chunk["stats"]))
+            for page_type, encoding in chunk["stats"]:
+                w.begin()
+                w.i32(1, page_type)
+                w.i32(2, encoding)
+                w.i32(3, 1)
+                w.end()
+            w.end()
+            w.i64(4, chunk["offset_index"][0])
+            w.i32(5, chunk["offset_index"][1])
+            w.end()
+        w.i64(2, sum(c["size"] for c in chunks))
+        w.i64(3, rows)
+        w.i64(5, chunks[0]["start"])
+        w.i64(6, sum(c["size"] for c in chunks))
+        w.i16(7, ordinal)
+        w.end()
+    w.list(5, STRUCT, 1)
+    w.begin()
+    w.binary(1, "presentar.fixture")
+    w.binary(2, path.name)
+    w.end()
+    w.binary(6, "presentar parquet_fixtures.py")
+    w.list(7, STRUCT, len(columns))
+    for _ in columns:
+        w.begin()
+        w.begin(1)
+        w.end()
+        w.end()
+    w.end()
+
+    file += w.out
+    file += struct.pack("<I", len(w.out))
+    file += b"PAR1"
+    path.write_bytes(bytes(file))
+
+
+def main():
+    OUT.mkdir(parents=True, exist_ok=True)
+
+    write_file(
+        OUT / "nullable_dictionary.parquet",
+        [
+            Column("id", INT64, False, True),
+            Column("score", DOUBLE, True, True),
+            Column("name", BYTE_ARRAY, True, True, string=True),
+            Column("flag", BOOLEAN, True, False),
+        ],
+        [
+            {
+                "id": [1, 2, 3, 4],
+                "score": [0.5, None, 0.0, 2.25],
+                "name": ["alpha", None, "", "alpha"],
+                "flag": [True, False, None, True],
+            }
+        ],
+        UNCOMPRESSED,
+        v2=False,
+    )
+
+    write_file(
+        OUT / "snappy_v2.parquet",
+        [
+            Column("id", INT32, False, False),
+            Column("value", FLOAT, True, False),
+            Column("label", BYTE_ARRAY, True, True, string=True),
+        ],
+        [
+            {
+                "id": [10, 11, 12],
+                "value": [1.5, None, -2.0],
+                "label": ["repeated label", "", None],
+            },
+            {
+                "id": [13, 14],
+                "value": [None, 0.0],
+                "label": ["repeated label", "repeated label, again"],
+            },
+        ],
+        SNAPPY,
+        v2=True,
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,113 @@
+//! Regenerate `crates/presentar-yaml/tests/fixtures/parquet_rs.parquet`.
+//!
+//! Unlike the fixtures from `parquet_fixtures.py`, this file is written by
+//! parquet-rs, so the reader is checked against an independent writer:
+//! Snappy compressed, dictionary encoded v1 pages (two per column chunk),
+//! nulls in every optional column, two row groups and a page index.
+//!
+//! Usage: copy this file to `src/main.rs` of a scratch crate depending on
+//! `parquet = { version = "=54.3.1", default-features = false, features = ["snap"] }`,
+//! then `cargo run -- crates/presentar-yaml/tests/fixtures/parquet_rs.parquet`.
+
+use std::fs::File;
+use std::sync::Arc;
+
+use parquet::basic::Compression;
+use parquet::data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+
+const SCHEMA: &str = "
+message fixture {
+    required int64 id;
+    optional double score;
+    optional binary name (STRING);
+    optional boolean flag;
+}
+";
+
+fn main() {
+    let path = std::env::args().nth(1).expect("output path");
+    let schema = Arc::new(parse_message_type(SCHEMA).unwrap());
+    let props = Arc::new(
+        WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .set_max_row_group_size(4)
+            .set_write_batch_size(2)
+            .set_data_page_row_count_limit(2)
+            .build(),
+    );
+    let mut writer = SerializedFileWriter::new(File::create(path).unwrap(), schema, props).unwrap();
+
+    let ids: [i64; 7] = [1, 2, 3, 4, 5, 6, 7];
+    let scores = [
+        Some(0.5),
+        None,
+        Some(0.0),
+        Some(2.25),
+        None,
+        Some(-1.0),
+        Some(0.5),
+    ];
+    let names = [
+        Some("alpha"),
+        None,
+        Some(""),
+        Some("beta"),
+        Some("alpha"),
+        None,
+        Some("beta"),
+    ];
+    let flags = [
+        Some(true),
+        Some(false),
+        None,
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+    ];
+
+    for rows in [0..4, 4..7] {
+        let mut group = writer.next_row_group().unwrap();
+
+        let mut col = group.next_column().unwrap().unwrap();
+        col.typed::<Int64Type>()
+            .write_batch(&ids[rows.clone()], None, None)
+            .unwrap();
+        col.close().unwrap();
+
+        let (values, levels) = split(&scores[rows.clone()]);
+        let mut col = group.next_column().unwrap().unwrap();
+        col.typed::<DoubleType>()
+            .write_batch(&values, Some(&levels), None)
+            .unwrap();
+        col.close().unwrap();
+
+        let (values, levels) = split(&names[rows.clone()]);
+        let values: Vec<ByteArray> = values.into_iter().map(ByteArray::from).collect();
+        let mut col = group.next_column().unwrap().unwrap();
+        col.typed::<ByteArrayType>()
+            .write_batch(&values, Some(&levels), None)
+            .unwrap();
+        col.close().unwrap();
+
+        let (values, levels) = split(&flags[rows.clone()]);
+        let mut col = group.next_column().unwrap().unwrap();
+        col.typed::<BoolType>()
+            .write_batch(&values, Some(&levels), None)
+            .unwrap();
+        col.close().unwrap();
+
+        group.close().unwrap();
+    }
+    writer.close().unwrap();
+}
+
+/// Non-null values and definition levels of an optional column.
+fn split<T: Clone>(column: &[Option<T>]) -> (Vec<T>, Vec<i16>) {
+    let values = column.iter().flatten().cloned().collect();
+    let levels = column.iter().map(|v| i16::from(v.is_some())).collect();
+    (values, levels)
+}